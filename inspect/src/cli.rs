@@ -60,4 +60,19 @@ pub enum InspectSubCmd {
 		#[structopt(value_name = "BLOCK:INDEX or BYTES")]
 		input: String,
 	},
+	/// Decode a call or an extrinsic with the runtime metadata and print out
+	/// the pallet, the call and the arguments.
+	DecodeExtrinsic {
+		/// A 0x-prefixed bytes hex string, representing SCALE encoding of a
+		/// call, like the payload of XCM `Transact`, or an extrinsic.
+		#[structopt(value_name = "BYTES")]
+		input: String,
+	},
+	/// Decode a storage key with the runtime metadata and print out the
+	/// storage item and the key arguments.
+	DecodeKey {
+		/// A 0x-prefixed bytes hex string of a full or partial storage key.
+		#[structopt(value_name = "BYTES")]
+		input: String,
+	},
 }
@@ -19,11 +19,13 @@
 //! Command ran by the CLI
 
 use crate::cli::{InspectCmd, InspectSubCmd};
-use crate::Inspector;
+use crate::{decode, decode::MetadataDecoder, Inspector};
+use codec::{Decode, Encode};
 use sc_cli::{CliConfiguration, ImportParams, Result, SharedParams};
 use sc_client_api::BlockBackend;
 use sp_blockchain::HeaderBackend;
-use sp_runtime::traits::Block;
+use sp_runtime::traits::{Block, SignedExtension};
+use std::fmt::{Debug, Display};
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -52,12 +54,54 @@ impl InspectCmd {
 						println!("{}", res);
 						Ok(())
 					}
+					InspectSubCmd::DecodeExtrinsic { .. } | InspectSubCmd::DecodeKey { .. } => {
+						Err("Decoding with the runtime metadata is run by `run_decode`".into())
+					}
 				}
 			}
 
 			Err(_) => Err("Client try_unwrap failed".into()),
 		}
 	}
+
+	/// Whether the command only decodes raw data with the runtime metadata and
+	/// needs no client.
+	pub fn is_decode(&self) -> bool {
+		matches!(
+			self.command,
+			InspectSubCmd::DecodeExtrinsic { .. } | InspectSubCmd::DecodeKey { .. }
+		)
+	}
+
+	/// Run the decode command with the runtime types and the SCALE encoded
+	/// runtime metadata.
+	pub fn run_decode<AccountId, CurrencyId, Address, Call, Signature, Extra>(&self, metadata: &[u8]) -> Result<()>
+	where
+		AccountId: Decode + Display,
+		CurrencyId: Decode + Debug,
+		Address: Decode + Debug,
+		Call: Decode + Encode + Debug,
+		Signature: Decode,
+		Extra: SignedExtension,
+	{
+		let decoder = MetadataDecoder::new(metadata).map_err(|e| format!("{}", e))?;
+
+		let res = match &self.command {
+			InspectSubCmd::DecodeExtrinsic { input } => {
+				let bytes = sp_core::bytes::from_hex(input).map_err(|e| format!("{}", e))?;
+				decoder.decode_extrinsic::<Address, Call, Signature, Extra>(&bytes)
+			}
+			InspectSubCmd::DecodeKey { input } => {
+				let bytes = sp_core::bytes::from_hex(input).map_err(|e| format!("{}", e))?;
+				decoder.decode_key(&bytes, decode::decode_key_arg::<AccountId, CurrencyId>)
+			}
+			_ => return Err("Not a decode command".into()),
+		}
+		.map_err(|e| format!("{}", e))?;
+
+		println!("{}", res);
+		Ok(())
+	}
 }
 
 impl CliConfiguration for InspectCmd {
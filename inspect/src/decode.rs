@@ -0,0 +1,261 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Decode raw calls, extrinsics and storage keys with the runtime metadata.
+
+use crate::Error;
+use codec::{Decode, DecodeAll, Encode};
+use frame_metadata::{
+	DecodeDifferent, ModuleMetadata, RuntimeMetadata, RuntimeMetadataPrefixed, StorageEntryType, StorageHasher,
+};
+use sp_core::{
+	hashing::{blake2_128, twox_128, twox_64},
+	hexdisplay::HexDisplay,
+	H160, H256,
+};
+use sp_runtime::{generic::UncheckedExtrinsic, traits::SignedExtension};
+use std::fmt::{Debug, Display};
+
+/// Runtime metadata used to decode raw data.
+pub struct MetadataDecoder {
+	modules: Vec<ModuleMetadata>,
+}
+
+impl MetadataDecoder {
+	/// Create a new decoder from the SCALE encoded `RuntimeMetadataPrefixed`.
+	pub fn new(metadata: &[u8]) -> Result<Self, Error> {
+		match RuntimeMetadataPrefixed::decode(&mut &*metadata)?.1 {
+			RuntimeMetadata::V13(metadata) => Ok(Self {
+				modules: decoded(&metadata.modules)?.to_vec(),
+			}),
+			_ => Err(Error::Metadata("Unsupported metadata version".into())),
+		}
+	}
+
+	/// Decode a call, like the payload of XCM `Transact`, or an extrinsic and
+	/// print out the signer, the pallet, the call and its arguments.
+	pub fn decode_extrinsic<Address, Call, Signature, Extra>(&self, bytes: &[u8]) -> Result<String, Error>
+	where
+		Address: Decode + Debug,
+		Call: Decode + Encode + Debug,
+		Signature: Decode,
+		Extra: SignedExtension,
+	{
+		// try the bare call first, then the full extrinsic
+		let (call, signer) = match Call::decode_all(bytes) {
+			Ok(call) => (call, None),
+			Err(_) => {
+				let xt = UncheckedExtrinsic::<Address, Call, Signature, Extra>::decode_all(bytes)?;
+				(xt.function, xt.signature.map(|(address, _, _)| address))
+			}
+		};
+
+		// the encoded call starts with the pallet and call indexes
+		let (pallet_index, call_index) = match call.encode()[..] {
+			[pallet_index, call_index, ..] => (pallet_index, call_index),
+			_ => return Err(Error::NotFound("Call indexes are missing".into())),
+		};
+		let module = self.module(pallet_index)?;
+		let calls = match &module.calls {
+			Some(calls) => decoded(calls)?,
+			None => &[][..],
+		};
+		let function = calls.get(call_index as usize).ok_or_else(|| {
+			Error::NotFound(format!(
+				"Could not find call {} in pallet {}",
+				call_index,
+				decoded(&module.name).map(String::as_str).unwrap_or_default()
+			))
+		})?;
+
+		let mut lines = Vec::new();
+		if let Some(signer) = signer {
+			lines.push(format!("Signer: {:?}", signer));
+		}
+		lines.push(format!("Pallet: {} ({})", decoded(&module.name)?, pallet_index));
+		lines.push(format!("Call: {} ({})", decoded(&function.name)?, call_index));
+		lines.push("Arguments:".into());
+		for argument in decoded(&function.arguments)? {
+			lines.push(format!(" - {}: {}", decoded(&argument.name)?, decoded(&argument.ty)?));
+		}
+		lines.push(format!("Decoded: {:?}", call));
+
+		Ok(lines.join("\n"))
+	}
+
+	/// Decode a storage key and print out the storage item and its key
+	/// arguments. `decode_arg` decodes a raw key argument by its type name.
+	pub fn decode_key(&self, key: &[u8], decode_arg: impl Fn(&str, &[u8]) -> Option<String>) -> Result<String, Error> {
+		if key.len() < 32 {
+			return Err(Error::NotFound("Storage key is shorter than the storage prefix".into()));
+		}
+		let (prefix_hash, rest) = key.split_at(16);
+		let (entry_hash, rest) = rest.split_at(16);
+
+		for module in &self.modules {
+			let storage = match &module.storage {
+				Some(storage) => decoded(storage)?,
+				None => continue,
+			};
+			let prefix = decoded(&storage.prefix)?;
+			if twox_128(prefix.as_bytes()) != prefix_hash {
+				continue;
+			}
+
+			for entry in decoded(&storage.entries)? {
+				let name = decoded(&entry.name)?;
+				if twox_128(name.as_bytes()) != entry_hash {
+					continue;
+				}
+
+				let (keys, hashers) = match &entry.ty {
+					StorageEntryType::Plain(_) => (vec![], vec![]),
+					StorageEntryType::Map { hasher, key, .. } => (vec![decoded(key)?.clone()], vec![hasher.clone()]),
+					StorageEntryType::DoubleMap {
+						hasher,
+						key1,
+						key2,
+						key2_hasher,
+						..
+					} => (
+						vec![decoded(key1)?.clone(), decoded(key2)?.clone()],
+						vec![hasher.clone(), key2_hasher.clone()],
+					),
+					StorageEntryType::NMap { keys, hashers, .. } => (decoded(keys)?.clone(), decoded(hashers)?.clone()),
+				};
+
+				let mut lines = vec![
+					format!("Pallet: {} ({})", decoded(&module.name)?, module.index),
+					format!("Storage: {}::{}", prefix, name),
+					"Keys:".into(),
+				];
+				for (ty, key) in keys.iter().zip(split_key(&hashers, rest)) {
+					let value = match key {
+						KeyArg::Hash(hash) => format!("hash 0x{}", HexDisplay::from(&hash)),
+						KeyArg::Raw(raw) => {
+							decode_arg(ty, raw).unwrap_or_else(|| format!("0x{}", HexDisplay::from(&raw)))
+						}
+					};
+					lines.push(format!(" - {}: {}", ty, value));
+				}
+
+				return Ok(lines.join("\n"));
+			}
+		}
+
+		Err(Error::NotFound("Could not find the storage item of the key".into()))
+	}
+
+	fn module(&self, index: u8) -> Result<&ModuleMetadata, Error> {
+		self.modules
+			.iter()
+			.find(|module| module.index == index)
+			.ok_or_else(|| Error::NotFound(format!("Could not find pallet {}", index)))
+	}
+}
+
+/// A storage key argument split off from the storage key.
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeyArg<'a> {
+	/// The hash of an opaque hasher, the key is unknown.
+	Hash(&'a [u8]),
+	/// The raw SCALE encoded key of a transparent hasher.
+	Raw(&'a [u8]),
+}
+
+/// Split the key arguments off `bytes` by `hashers`. The length of the
+/// transparent keys is found by checking the hash against the key, the last
+/// key takes the rest. Stops at the end of `bytes` to support partial keys.
+pub fn split_key<'a>(hashers: &[StorageHasher], mut bytes: &'a [u8]) -> Vec<KeyArg<'a>> {
+	let mut keys = Vec::new();
+
+	for (i, hasher) in hashers.iter().enumerate() {
+		let is_last = i + 1 == hashers.len();
+		let (hash_len, transparent) = match hasher {
+			StorageHasher::Blake2_128 | StorageHasher::Twox128 => (16, false),
+			StorageHasher::Blake2_256 | StorageHasher::Twox256 => (32, false),
+			StorageHasher::Blake2_128Concat => (16, true),
+			StorageHasher::Twox64Concat => (8, true),
+			StorageHasher::Identity => (0, true),
+		};
+		if bytes.len() < hash_len || (bytes.is_empty() && !is_last) {
+			break;
+		}
+		let (hash, rest) = bytes.split_at(hash_len);
+
+		if !transparent {
+			keys.push(KeyArg::Hash(hash));
+			bytes = rest;
+			continue;
+		}
+
+		let key_len = if is_last {
+			rest.len()
+		} else {
+			match hasher {
+				StorageHasher::Blake2_128Concat => (0..=rest.len()).find(|&len| blake2_128(&rest[..len]) == hash),
+				StorageHasher::Twox64Concat => (0..=rest.len()).find(|&len| twox_64(&rest[..len]) == hash),
+				// the identity key has no hash to check, it takes the rest
+				_ => None,
+			}
+			.unwrap_or_else(|| rest.len())
+		};
+		let (key, rest) = rest.split_at(key_len);
+		keys.push(KeyArg::Raw(key));
+		bytes = rest;
+	}
+
+	keys
+}
+
+/// Decode a storage key argument of the common types by its type name in the
+/// metadata.
+pub fn decode_key_arg<AccountId, CurrencyId>(ty: &str, raw: &[u8]) -> Option<String>
+where
+	AccountId: Decode + Display,
+	CurrencyId: Decode + Debug,
+{
+	// `T::AccountId` and `CurrencyIdOf<T>` are matched by `AccountId` and `CurrencyId`
+	let name = ty.split('<').next().unwrap_or(ty);
+	let name = name.rsplit("::").next().unwrap_or(name);
+
+	match name {
+		"AccountId" | "AccountIdOf" | "LookupSource" => AccountId::decode_all(raw).ok().map(|x| x.to_string()),
+		"CurrencyId" | "CurrencyIdOf" => CurrencyId::decode_all(raw).ok().map(|x| format!("{:?}", x)),
+		"TradingPair" => <(CurrencyId, CurrencyId)>::decode_all(raw)
+			.ok()
+			.map(|x| format!("{:?}", x)),
+		"u32" | "BlockNumber" | "EraIndex" | "ProposalIndex" => u32::decode_all(raw).ok().map(|x| x.to_string()),
+		"u64" | "Moment" => u64::decode_all(raw).ok().map(|x| x.to_string()),
+		"u128" | "Balance" | "BalanceOf" => u128::decode_all(raw).ok().map(|x| x.to_string()),
+		"H160" | "EvmAddress" => H160::decode_all(raw).ok().map(|x| format!("{:?}", x)),
+		"H256" | "Hash" => H256::decode_all(raw).ok().map(|x| format!("{:?}", x)),
+		_ => None,
+	}
+}
+
+/// The decoded value of the metadata decoded from bytes.
+fn decoded<B, O>(value: &DecodeDifferent<B, O>) -> Result<&O, Error>
+where
+	B: 'static,
+	O: 'static,
+{
+	match value {
+		DecodeDifferent::Decoded(value) => Ok(value),
+		DecodeDifferent::Encode(_) => Err(Error::Metadata("Metadata is not decoded".into())),
+	}
+}
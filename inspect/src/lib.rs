@@ -18,6 +18,7 @@
 
 pub mod cli;
 pub mod command;
+pub mod decode;
 
 use codec::{Decode, Encode};
 use sc_client_api::BlockBackend;
@@ -73,6 +74,9 @@ pub enum Error {
 	Blockchain(sp_blockchain::Error),
 	/// Given block has not been found.
 	NotFound(String),
+	/// Runtime metadata is not supported.
+	#[from(ignore)]
+	Metadata(String),
 }
 
 impl std::error::Error for Error {
@@ -80,7 +84,7 @@ impl std::error::Error for Error {
 		match *self {
 			Self::Codec(ref e) => Some(e),
 			Self::Blockchain(ref e) => Some(e),
-			Self::NotFound(_) => None,
+			Self::NotFound(_) | Self::Metadata(_) => None,
 		}
 	}
 }
@@ -309,4 +313,50 @@ mod tests {
 		assert_eq!(b2, Ok(ExtrinsicAddress::Block(BlockAddress::Number(0), 0)));
 		assert_eq!(b3, Ok(ExtrinsicAddress::Bytes(vec![0, 0x12, 0x34, 0x5f])));
 	}
+
+	#[test]
+	fn should_split_storage_keys() {
+		use codec::Encode;
+		use decode::{split_key, KeyArg};
+		use frame_metadata::StorageHasher;
+		use sp_core::hashing::{blake2_128, twox_128, twox_64};
+
+		let (key1, key2) = (7u32.encode(), (1u8, 2u128).encode());
+		let bytes = [&blake2_128(&key1)[..], &key1, &twox_64(&key2), &key2, &twox_128(&key1)].concat();
+
+		let hashers = [
+			StorageHasher::Blake2_128Concat,
+			StorageHasher::Twox64Concat,
+			StorageHasher::Twox128,
+		];
+		assert_eq!(
+			split_key(&hashers, &bytes),
+			vec![KeyArg::Raw(&key1), KeyArg::Raw(&key2), KeyArg::Hash(&twox_128(&key1)),]
+		);
+
+		// partial key
+		assert_eq!(split_key(&hashers, &bytes[..20]), vec![KeyArg::Raw(&key1)]);
+		assert_eq!(split_key(&hashers, &[]), vec![]);
+
+		// the last key takes the rest
+		assert_eq!(split_key(&[StorageHasher::Identity], &key2), vec![KeyArg::Raw(&key2)]);
+	}
+
+	#[test]
+	fn should_decode_key_args() {
+		use codec::Encode;
+		use decode::decode_key_arg;
+		use sp_core::crypto::AccountId32;
+
+		type DecodeArg = fn(&str, &[u8]) -> Option<String>;
+		let decode_arg: DecodeArg = decode_key_arg::<AccountId32, u8>;
+
+		let account = AccountId32::new([1u8; 32]);
+		assert_eq!(decode_arg("T::AccountId", &account.encode()), Some(account.to_string()));
+		assert_eq!(decode_arg("CurrencyIdOf<T>", &[5]), Some("5".into()));
+		assert_eq!(decode_arg("T::BlockNumber", &10u32.encode()), Some("10".into()));
+		assert_eq!(decode_arg("BalanceOf<T>", &10u128.encode()), Some("10".into()));
+		assert_eq!(decode_arg("BalanceOf<T>", &10u32.encode()), None);
+		assert_eq!(decode_arg("PoolId", &[0]), None);
+	}
 }
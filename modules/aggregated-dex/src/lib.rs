@@ -0,0 +1,407 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Aggregated DEX Module
+//!
+//! ## Overview
+//!
+//! Aggregated DEX routes a swap through both the AMM pools of module-dex and
+//! the stable swap pools of Taiga, so that trades between assets of similar
+//! value, e.g. aUSD and kUSD, can take the venue with the better price. A
+//! swap path is a list of hops on either venue executed atomically, and
+//! governance can register alternative swap paths of a currency pair, from
+//! which `swap_with_best_path` picks the one with the largest target amount.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use nutsfinance_stable_asset::PoolId;
+use orml_traits::MultiCurrency;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{One, Saturating, Zero},
+	DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
+};
+use sp_std::prelude::*;
+use support::{DEXManager, Ratio, StableAssetManager, SwapHop, SwapMode, SwapQuote, SwapVenue};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// A hop of an aggregated swap.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub enum AggregatedSwapPath {
+	/// Swap through the AMM pools of module-dex along the path.
+	Dex(Vec<CurrencyId>),
+	/// Swap through the Taiga stable swap pool. \[pool_id,
+	/// supply_asset_index, target_asset_index\]
+	Taiga(PoolId, u32, u32),
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency to query the swapped amounts of the Taiga pools.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// AMM pools of module-dex.
+		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
+
+		/// Taiga stable swap pools.
+		type StableAsset: StableAssetManager<Self::AccountId, CurrencyId, Balance, PoolId>;
+
+		/// The origin which may update the alternative swap paths.
+		type GovernanceOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The swap path is empty or its hops don't connect
+		InvalidSwapPath,
+		/// The Taiga pool or asset index is invalid
+		InvalidPoolAsset,
+		/// The swap path doesn't start with the supply currency or end with
+		/// the target currency
+		InvalidCurrencyId,
+		/// None of the swap paths can swap
+		CannotSwap,
+		/// The target amount is less than the minimum target amount
+		BelowMinTargetAmount,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	pub enum Event<T: Config> {
+		/// Swapped through an aggregated swap path. \[who, swap_path,
+		/// supply_amount, target_amount\]
+		Swap(T::AccountId, Vec<AggregatedSwapPath>, Balance, Balance),
+		/// The alternative swap paths of a currency pair updated.
+		/// \[supply_currency_id, target_currency_id\]
+		AlternativeSwapPathsUpdated(CurrencyId, CurrencyId),
+	}
+
+	/// The alternative swap paths from a supply currency to a target
+	/// currency, which are compared with the direct module-dex path by
+	/// `swap_with_best_path`.
+	///
+	/// AlternativeSwapPaths: map (CurrencyId, CurrencyId) =>
+	/// Option<Vec<Vec<AggregatedSwapPath>>>
+	#[pallet::storage]
+	#[pallet::getter(fn alternative_swap_paths)]
+	pub type AlternativeSwapPaths<T: Config> =
+		StorageMap<_, Twox64Concat, (CurrencyId, CurrencyId), Vec<Vec<AggregatedSwapPath>>, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Swap with exact supply amount through `swap_path`, hops are
+		/// executed in order and the target of a hop is the supply of the
+		/// next one.
+		///
+		/// - `swap_path`: the hops to swap through.
+		/// - `supply_amount`: exact supply amount.
+		/// - `min_target_amount`: acceptable minimum target amount.
+		#[pallet::weight(<T as Config>::WeightInfo::swap_with_exact_supply(swap_path.len() as u32))]
+		#[transactional]
+		pub fn swap_with_exact_supply(
+			origin: OriginFor<T>,
+			swap_path: Vec<AggregatedSwapPath>,
+			#[pallet::compact] supply_amount: Balance,
+			#[pallet::compact] min_target_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_aggregated_swap(&who, &swap_path, supply_amount, min_target_amount)?;
+			Ok(())
+		}
+
+		/// Swap with exact supply amount through the direct module-dex path
+		/// or one of the alternative swap paths, whichever has the largest
+		/// target amount.
+		///
+		/// - `supply_currency_id`: the currency to supply.
+		/// - `target_currency_id`: the currency to receive.
+		/// - `supply_amount`: exact supply amount.
+		/// - `min_target_amount`: acceptable minimum target amount.
+		#[pallet::weight(<T as Config>::WeightInfo::swap_with_best_path())]
+		#[transactional]
+		pub fn swap_with_best_path(
+			origin: OriginFor<T>,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			#[pallet::compact] supply_amount: Balance,
+			#[pallet::compact] min_target_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (swap_path, _) = Self::get_best_swap_path(supply_currency_id, target_currency_id, supply_amount)
+				.ok_or(Error::<T>::CannotSwap)?;
+			Self::do_aggregated_swap(&who, &swap_path, supply_amount, min_target_amount)?;
+			Ok(())
+		}
+
+		/// Update the alternative swap paths from `supply_currency_id` to
+		/// `target_currency_id`, `None` removes them.
+		#[pallet::weight((<T as Config>::WeightInfo::update_alternative_swap_paths(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn update_alternative_swap_paths(
+			origin: OriginFor<T>,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			swap_paths: Option<Vec<Vec<AggregatedSwapPath>>>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			match swap_paths {
+				Some(swap_paths) => {
+					for swap_path in swap_paths.iter() {
+						let (supply, target) = Self::check_swap_path(swap_path)?;
+						ensure!(
+							supply == supply_currency_id && target == target_currency_id,
+							Error::<T>::InvalidCurrencyId
+						);
+					}
+					AlternativeSwapPaths::<T>::insert((supply_currency_id, target_currency_id), swap_paths);
+				}
+				None => AlternativeSwapPaths::<T>::remove((supply_currency_id, target_currency_id)),
+			}
+
+			Self::deposit_event(Event::AlternativeSwapPathsUpdated(
+				supply_currency_id,
+				target_currency_id,
+			));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Checks that the hops of `swap_path` connect, returns the supply and
+	/// target currency of the whole path.
+	fn check_swap_path(swap_path: &[AggregatedSwapPath]) -> Result<(CurrencyId, CurrencyId), DispatchError> {
+		let mut currency_ids: Option<(CurrencyId, CurrencyId)> = None;
+
+		for hop in swap_path {
+			let (supply, target) = match hop {
+				AggregatedSwapPath::Dex(path) => {
+					ensure!(path.len() >= 2, Error::<T>::InvalidSwapPath);
+					(path[0], path[path.len() - 1])
+				}
+				AggregatedSwapPath::Taiga(pool_id, supply_index, target_index) => {
+					ensure!(supply_index != target_index, Error::<T>::InvalidPoolAsset);
+					(
+						T::StableAsset::pool_asset(*pool_id, *supply_index).ok_or(Error::<T>::InvalidPoolAsset)?,
+						T::StableAsset::pool_asset(*pool_id, *target_index).ok_or(Error::<T>::InvalidPoolAsset)?,
+					)
+				}
+			};
+
+			currency_ids = match currency_ids {
+				None => Some((supply, target)),
+				Some((first_supply, last_target)) => {
+					ensure!(last_target == supply, Error::<T>::InvalidSwapPath);
+					Some((first_supply, target))
+				}
+			};
+		}
+
+		currency_ids.ok_or_else(|| Error::<T>::InvalidSwapPath.into())
+	}
+
+	/// The target amount of swapping `supply_amount` through `swap_path`.
+	pub fn get_aggregated_target_amount(swap_path: &[AggregatedSwapPath], supply_amount: Balance) -> Option<Balance> {
+		Self::check_swap_path(swap_path).ok()?;
+
+		swap_path.iter().try_fold(supply_amount, |amount, hop| match hop {
+			AggregatedSwapPath::Dex(path) => T::DEX::get_swap_target_amount(path, amount),
+			AggregatedSwapPath::Taiga(pool_id, supply_index, target_index) => {
+				T::StableAsset::get_swap_output_amount(*pool_id, *supply_index, *target_index, amount)
+			}
+		})
+	}
+
+	/// The swap path with the largest target amount among the direct
+	/// module-dex path and the alternative swap paths, and its target amount.
+	pub fn get_best_swap_path(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+	) -> Option<(Vec<AggregatedSwapPath>, Balance)> {
+		let mut swap_paths = vec![vec![AggregatedSwapPath::Dex(vec![
+			supply_currency_id,
+			target_currency_id,
+		])]];
+		swap_paths.extend(Self::alternative_swap_paths((supply_currency_id, target_currency_id)).unwrap_or_default());
+
+		swap_paths
+			.into_iter()
+			.filter_map(|swap_path| {
+				Self::get_aggregated_target_amount(&swap_path, supply_amount)
+					.filter(|target_amount| !target_amount.is_zero())
+					.map(|target_amount| (swap_path, target_amount))
+			})
+			.fold(
+				None,
+				|best: Option<(Vec<AggregatedSwapPath>, Balance)>, candidate| match best {
+					Some(best) if best.1 >= candidate.1 => Some(best),
+					_ => Some(candidate),
+				},
+			)
+	}
+
+	/// Quote of swapping `amount` from `supply_currency_id` to
+	/// `target_currency_id`. Exact supply swaps take the best one of the
+	/// module-dex paths and the alternative swap paths, while exact target swaps
+	/// only go through module-dex as Taiga pools can't be quoted by target.
+	pub fn get_best_swap_quote(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		amount: Balance,
+		mode: SwapMode,
+	) -> Option<SwapQuote<CurrencyId, Balance>> {
+		let dex_quote = T::DEX::get_best_swap_quote(supply_currency_id, target_currency_id, amount, mode);
+		if mode == SwapMode::ExactTarget {
+			return dex_quote;
+		}
+
+		let alternative_quotes = Self::alternative_swap_paths((supply_currency_id, target_currency_id))
+			.unwrap_or_default()
+			.into_iter()
+			.filter_map(|swap_path| Self::get_aggregated_swap_quote(&swap_path, amount));
+
+		dex_quote
+			.into_iter()
+			.chain(alternative_quotes)
+			.fold(None, |best, quote| match best {
+				Some(best) if !quote.is_better_than(&best, mode) => Some(best),
+				_ => Some(quote),
+			})
+	}
+
+	/// Quote of swapping `supply_amount` through `swap_path`.
+	pub fn get_aggregated_swap_quote(
+		swap_path: &[AggregatedSwapPath],
+		supply_amount: Balance,
+	) -> Option<SwapQuote<CurrencyId, Balance>> {
+		Self::check_swap_path(swap_path).ok()?;
+
+		let mut hops = vec![];
+		let mut amount = supply_amount;
+		for hop in swap_path {
+			match hop {
+				AggregatedSwapPath::Dex(path) => {
+					let quote = T::DEX::get_swap_quote(path, amount, SwapMode::ExactSupply)?;
+					amount = quote.target_amount;
+					hops.extend(quote.hops);
+				}
+				AggregatedSwapPath::Taiga(pool_id, supply_index, target_index) => {
+					let target_amount =
+						T::StableAsset::get_swap_output_amount(*pool_id, *supply_index, *target_index, amount)?;
+					hops.push(SwapHop {
+						venue: SwapVenue::StableAsset(*pool_id),
+						supply_currency_id: T::StableAsset::pool_asset(*pool_id, *supply_index)?,
+						target_currency_id: T::StableAsset::pool_asset(*pool_id, *target_index)?,
+						supply_amount: amount,
+						target_amount,
+						fee: Zero::zero(),
+						price_impact: Self::get_stable_swap_price_impact(
+							*pool_id,
+							*supply_index,
+							*target_index,
+							amount,
+							target_amount,
+						),
+					});
+					amount = target_amount;
+				}
+			}
+		}
+
+		SwapQuote::from_hops(hops)
+	}
+
+	/// The shortfall of `target_amount` to the marginal price of the Taiga
+	/// pool, which is sampled by swapping a thousandth of `supply_amount`.
+	fn get_stable_swap_price_impact(
+		pool_id: PoolId,
+		supply_index: u32,
+		target_index: u32,
+		supply_amount: Balance,
+		target_amount: Balance,
+	) -> Ratio {
+		let sample_amount = supply_amount / 1_000;
+		if sample_amount.is_zero() {
+			return Zero::zero();
+		}
+
+		T::StableAsset::get_swap_output_amount(pool_id, supply_index, target_index, sample_amount)
+			.and_then(|sample_target_amount| Ratio::checked_from_rational(sample_target_amount, sample_amount))
+			.and_then(|marginal_price| {
+				Ratio::checked_from_rational(target_amount, marginal_price.saturating_mul_int(supply_amount))
+			})
+			.map(|ratio| Ratio::one().saturating_sub(ratio))
+			.unwrap_or_default()
+	}
+
+	#[transactional]
+	fn do_aggregated_swap(
+		who: &T::AccountId,
+		swap_path: &[AggregatedSwapPath],
+		supply_amount: Balance,
+		min_target_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		Self::check_swap_path(swap_path)?;
+
+		let mut amount = supply_amount;
+		for hop in swap_path {
+			amount = match hop {
+				// the slippage is limited by `min_target_amount` of the whole path
+				AggregatedSwapPath::Dex(path) => T::DEX::swap_with_exact_supply(who, path, amount, Zero::zero())?,
+				AggregatedSwapPath::Taiga(pool_id, supply_index, target_index) => {
+					let target_currency_id =
+						T::StableAsset::pool_asset(*pool_id, *target_index).ok_or(Error::<T>::InvalidPoolAsset)?;
+					let balance_before = T::Currency::free_balance(target_currency_id, who);
+					T::StableAsset::swap(who, *pool_id, *supply_index, *target_index, amount, Zero::zero())?;
+					T::Currency::free_balance(target_currency_id, who).saturating_sub(balance_before)
+				}
+			};
+		}
+		ensure!(amount >= min_target_amount, Error::<T>::BelowMinTargetAmount);
+
+		Self::deposit_event(Event::Swap(who.clone(), swap_path.to_vec(), supply_amount, amount));
+		Ok(amount)
+	}
+}
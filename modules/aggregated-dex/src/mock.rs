@@ -0,0 +1,244 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the aggregated dex module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{construct_runtime, ord_parameter_types, parameter_types, PalletId};
+use frame_system::EnsureSignedBy;
+use orml_traits::parameter_type_with_key;
+use primitives::{Amount, TokenSymbol, TradingPair};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+
+pub type BlockNumber = u64;
+pub type AccountId = u128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
+pub const KUSD: CurrencyId = CurrencyId::Token(TokenSymbol::KUSD);
+pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+pub const STABLE_POOL_ID: PoolId = 0;
+
+mod aggregated_dex {
+	pub use super::super::*;
+}
+
+parameter_types! {
+	pub const BlockHashCount: BlockNumber = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = ();
+	type DustRemovalWhitelist = ();
+}
+
+ord_parameter_types! {
+	pub const ListingOrigin: AccountId = 3;
+}
+
+parameter_types! {
+	pub const GetExchangeFee: (u32, u32) = (0, 100);
+	pub const TradingPathLimit: u32 = 3;
+	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
+}
+
+impl dex::Config for Runtime {
+	type Event = Event;
+	type Currency = Tokens;
+	type GetExchangeFee = GetExchangeFee;
+	type TradingPathLimit = TradingPathLimit;
+	type RoutingCurrencies = ();
+	type TWAPObservationPeriod = ();
+	type MaxTWAPObservations = ();
+	type PalletId = DEXPalletId;
+	type CurrencyIdMapping = ();
+	type WeightInfo = ();
+	type DEXIncentives = ();
+	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
+}
+
+/// A stable swap pool of AUSD and KUSD which swaps 1:1 with 1% fee.
+pub struct MockStableAsset;
+impl MockStableAsset {
+	fn pool_assets(pool_id: PoolId) -> Vec<CurrencyId> {
+		match pool_id {
+			STABLE_POOL_ID => vec![AUSD, KUSD],
+			_ => vec![],
+		}
+	}
+}
+impl StableAssetManager<AccountId, CurrencyId, Balance, PoolId> for MockStableAsset {
+	fn pool_asset(pool_id: PoolId, i: u32) -> Option<CurrencyId> {
+		Self::pool_assets(pool_id).get(i as usize).copied()
+	}
+
+	fn get_swap_output_amount(pool_id: PoolId, i: u32, j: u32, dx: Balance) -> Option<Balance> {
+		Self::pool_asset(pool_id, i)?;
+		Self::pool_asset(pool_id, j)?;
+		Some(dx - dx / 100)
+	}
+
+	fn swap(who: &AccountId, pool_id: PoolId, i: u32, j: u32, dx: Balance, min_dy: Balance) -> DispatchResult {
+		let dy = Self::get_swap_output_amount(pool_id, i, j, dx).ok_or(DispatchError::Other("invalid asset index"))?;
+		ensure!(dy >= min_dy, DispatchError::Other("swap under min"));
+
+		Tokens::withdraw(Self::pool_assets(pool_id)[i as usize], who, dx)?;
+		Tokens::deposit(Self::pool_assets(pool_id)[j as usize], who, dy)
+	}
+
+	fn mint(_who: &AccountId, _pool_id: PoolId, _amounts: Vec<Balance>, _min_mint_amount: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn redeem_single(
+		_who: &AccountId,
+		_pool_id: PoolId,
+		_amount: Balance,
+		_i: u32,
+		_min_redeem_amount: Balance,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+}
+
+ord_parameter_types! {
+	pub const GovernanceOrigin: AccountId = 4;
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type Currency = Tokens;
+	type DEX = DEXModule;
+	type StableAsset = MockStableAsset;
+	type GovernanceOrigin = EnsureSignedBy<GovernanceOrigin, AccountId>;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+		AggregatedDexModule: aggregated_dex::{Pallet, Storage, Call, Event<T>},
+		DEXModule: dex::{Pallet, Storage, Call, Event<T>, Config<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+	}
+);
+
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, CurrencyId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			balances: vec![
+				(ALICE, AUSD, 10_000_000),
+				(ALICE, KUSD, 10_000_000),
+				(ALICE, DOT, 10_000_000),
+				(BOB, KUSD, 1_000_000),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		dex::GenesisConfig::<Runtime> {
+			initial_listing_trading_pairs: vec![],
+			initial_enabled_trading_pairs: vec![
+				TradingPair::from_currency_ids(AUSD, KUSD).unwrap(),
+				TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
+			],
+			initial_added_liquidity_pools: vec![(
+				ALICE,
+				vec![
+					(
+						TradingPair::from_currency_ids(AUSD, KUSD).unwrap(),
+						(1_000_000, 1_000_000),
+					),
+					(TradingPair::from_currency_ids(AUSD, DOT).unwrap(), (100_000, 10_000)),
+				],
+			)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,286 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the aggregated dex module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn update_alternative_swap_paths_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AggregatedDexModule::update_alternative_swap_paths(
+				Origin::signed(ALICE),
+				KUSD,
+				AUSD,
+				Some(vec![vec![AggregatedSwapPath::Taiga(STABLE_POOL_ID, 1, 0)]])
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			AggregatedDexModule::update_alternative_swap_paths(
+				Origin::signed(GovernanceOrigin::get()),
+				KUSD,
+				AUSD,
+				Some(vec![vec![]])
+			),
+			Error::<Runtime>::InvalidSwapPath
+		);
+		assert_noop!(
+			AggregatedDexModule::update_alternative_swap_paths(
+				Origin::signed(GovernanceOrigin::get()),
+				KUSD,
+				AUSD,
+				Some(vec![vec![AggregatedSwapPath::Dex(vec![KUSD])]])
+			),
+			Error::<Runtime>::InvalidSwapPath
+		);
+		assert_noop!(
+			AggregatedDexModule::update_alternative_swap_paths(
+				Origin::signed(GovernanceOrigin::get()),
+				KUSD,
+				AUSD,
+				Some(vec![vec![AggregatedSwapPath::Taiga(1, 1, 0)]])
+			),
+			Error::<Runtime>::InvalidPoolAsset
+		);
+		assert_noop!(
+			AggregatedDexModule::update_alternative_swap_paths(
+				Origin::signed(GovernanceOrigin::get()),
+				KUSD,
+				AUSD,
+				Some(vec![vec![AggregatedSwapPath::Taiga(STABLE_POOL_ID, 1, 1)]])
+			),
+			Error::<Runtime>::InvalidPoolAsset
+		);
+		assert_noop!(
+			AggregatedDexModule::update_alternative_swap_paths(
+				Origin::signed(GovernanceOrigin::get()),
+				KUSD,
+				AUSD,
+				Some(vec![vec![AggregatedSwapPath::Taiga(STABLE_POOL_ID, 0, 1)]])
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+		assert_noop!(
+			AggregatedDexModule::update_alternative_swap_paths(
+				Origin::signed(GovernanceOrigin::get()),
+				KUSD,
+				DOT,
+				Some(vec![vec![
+					AggregatedSwapPath::Taiga(STABLE_POOL_ID, 1, 0),
+					AggregatedSwapPath::Dex(vec![KUSD, DOT])
+				]])
+			),
+			Error::<Runtime>::InvalidSwapPath
+		);
+
+		assert_ok!(AggregatedDexModule::update_alternative_swap_paths(
+			Origin::signed(GovernanceOrigin::get()),
+			KUSD,
+			DOT,
+			Some(vec![vec![
+				AggregatedSwapPath::Taiga(STABLE_POOL_ID, 1, 0),
+				AggregatedSwapPath::Dex(vec![AUSD, DOT])
+			]])
+		));
+		System::assert_last_event(Event::AggregatedDexModule(crate::Event::AlternativeSwapPathsUpdated(
+			KUSD, DOT,
+		)));
+		assert_eq!(
+			AggregatedDexModule::alternative_swap_paths((KUSD, DOT)),
+			Some(vec![vec![
+				AggregatedSwapPath::Taiga(STABLE_POOL_ID, 1, 0),
+				AggregatedSwapPath::Dex(vec![AUSD, DOT])
+			]])
+		);
+
+		assert_ok!(AggregatedDexModule::update_alternative_swap_paths(
+			Origin::signed(GovernanceOrigin::get()),
+			KUSD,
+			DOT,
+			None
+		));
+		assert_eq!(AggregatedDexModule::alternative_swap_paths((KUSD, DOT)), None);
+	});
+}
+
+#[test]
+fn swap_with_exact_supply_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let swap_path = vec![
+			AggregatedSwapPath::Taiga(STABLE_POOL_ID, 1, 0),
+			AggregatedSwapPath::Dex(vec![AUSD, DOT]),
+		];
+		assert_eq!(
+			AggregatedDexModule::get_aggregated_target_amount(&swap_path, 10_000),
+			Some(900)
+		);
+
+		assert_noop!(
+			AggregatedDexModule::swap_with_exact_supply(Origin::signed(BOB), vec![], 10_000, 0),
+			Error::<Runtime>::InvalidSwapPath
+		);
+		assert_noop!(
+			AggregatedDexModule::swap_with_exact_supply(Origin::signed(BOB), swap_path.clone(), 10_000, 901),
+			Error::<Runtime>::BelowMinTargetAmount
+		);
+
+		assert_ok!(AggregatedDexModule::swap_with_exact_supply(
+			Origin::signed(BOB),
+			swap_path.clone(),
+			10_000,
+			900
+		));
+		System::assert_last_event(Event::AggregatedDexModule(crate::Event::Swap(
+			BOB, swap_path, 10_000, 900,
+		)));
+		assert_eq!(Tokens::free_balance(KUSD, &BOB), 990_000);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 0);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 900);
+		assert_eq!(DEXModule::get_liquidity_pool(AUSD, DOT), (109_900, 9_100));
+	});
+}
+
+#[test]
+fn swap_with_best_path_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AggregatedDexModule::swap_with_best_path(Origin::signed(BOB), KUSD, DOT, 10_000, 0),
+			Error::<Runtime>::CannotSwap
+		);
+		assert_eq!(
+			AggregatedDexModule::get_best_swap_path(KUSD, AUSD, 100_000),
+			Some((vec![AggregatedSwapPath::Dex(vec![KUSD, AUSD])], 90_909))
+		);
+
+		assert_ok!(AggregatedDexModule::update_alternative_swap_paths(
+			Origin::signed(GovernanceOrigin::get()),
+			KUSD,
+			AUSD,
+			Some(vec![vec![AggregatedSwapPath::Taiga(STABLE_POOL_ID, 1, 0)]])
+		));
+		assert_eq!(
+			AggregatedDexModule::get_best_swap_path(KUSD, AUSD, 100_000),
+			Some((vec![AggregatedSwapPath::Taiga(STABLE_POOL_ID, 1, 0)], 99_000))
+		);
+
+		// the AMM pool is cheaper for small amounts
+		assert_ok!(AggregatedDexModule::swap_with_best_path(
+			Origin::signed(BOB),
+			KUSD,
+			AUSD,
+			1_000,
+			0
+		));
+		System::assert_last_event(Event::AggregatedDexModule(crate::Event::Swap(
+			BOB,
+			vec![AggregatedSwapPath::Dex(vec![KUSD, AUSD])],
+			1_000,
+			999,
+		)));
+
+		// the stable swap pool is cheaper for large amounts
+		assert_ok!(AggregatedDexModule::swap_with_best_path(
+			Origin::signed(BOB),
+			KUSD,
+			AUSD,
+			100_000,
+			0
+		));
+		System::assert_last_event(Event::AggregatedDexModule(crate::Event::Swap(
+			BOB,
+			vec![AggregatedSwapPath::Taiga(STABLE_POOL_ID, 1, 0)],
+			100_000,
+			99_000,
+		)));
+		assert_eq!(Tokens::free_balance(KUSD, &BOB), 899_000);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 99_999);
+		assert_eq!(DEXModule::get_liquidity_pool(KUSD, AUSD), (1_001_000, 999_001));
+	});
+}
+
+#[test]
+fn get_best_swap_quote_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let dex_quote = SwapQuote {
+			supply_amount: 1_000,
+			target_amount: 999,
+			hops: vec![SwapHop {
+				venue: SwapVenue::Dex,
+				supply_currency_id: KUSD,
+				target_currency_id: AUSD,
+				supply_amount: 1_000,
+				target_amount: 999,
+				fee: 0,
+				price_impact: Ratio::saturating_from_rational(1, 1_000),
+			}],
+			price_impact: Ratio::saturating_from_rational(1, 1_000),
+		};
+		let taiga_quote = SwapQuote {
+			supply_amount: 100_000,
+			target_amount: 99_000,
+			hops: vec![SwapHop {
+				venue: SwapVenue::StableAsset(STABLE_POOL_ID),
+				supply_currency_id: KUSD,
+				target_currency_id: AUSD,
+				supply_amount: 100_000,
+				target_amount: 99_000,
+				fee: 0,
+				price_impact: Ratio::zero(),
+			}],
+			price_impact: Ratio::zero(),
+		};
+
+		assert_eq!(
+			AggregatedDexModule::get_best_swap_quote(KUSD, DOT, 10_000, SwapMode::ExactSupply),
+			None
+		);
+		assert_eq!(
+			AggregatedDexModule::get_best_swap_quote(KUSD, AUSD, 1_000, SwapMode::ExactSupply),
+			Some(dex_quote.clone())
+		);
+
+		assert_ok!(AggregatedDexModule::update_alternative_swap_paths(
+			Origin::signed(GovernanceOrigin::get()),
+			KUSD,
+			AUSD,
+			Some(vec![vec![AggregatedSwapPath::Taiga(STABLE_POOL_ID, 1, 0)]])
+		));
+		// the AMM pool is cheaper for small amounts
+		assert_eq!(
+			AggregatedDexModule::get_best_swap_quote(KUSD, AUSD, 1_000, SwapMode::ExactSupply),
+			Some(dex_quote.clone())
+		);
+		// the stable swap pool is cheaper for large amounts
+		assert_eq!(
+			AggregatedDexModule::get_best_swap_quote(KUSD, AUSD, 100_000, SwapMode::ExactSupply),
+			Some(taiga_quote)
+		);
+		// exact target swaps only go through module-dex
+		assert_eq!(
+			AggregatedDexModule::get_best_swap_quote(KUSD, AUSD, 999, SwapMode::ExactTarget),
+			Some(dex_quote)
+		);
+	});
+}
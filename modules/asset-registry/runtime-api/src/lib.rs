@@ -0,0 +1,190 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for asset registry module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `too_many_arguments` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::{Codec, Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+use sp_std::prelude::*;
+
+/// Balance of a currency held by an account.
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TokenBalance<CurrencyId, Balance> {
+	pub currency_id: CurrencyId,
+	/// Transferable balance, including the frozen part.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub free: Balance,
+	/// Balance reserved by modules.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub reserved: Balance,
+	/// Part of the free balance locked by modules.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub frozen: Balance,
+}
+
+/// An amount of currency.
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct CurrencyAmount<CurrencyId, Balance> {
+	pub currency_id: CurrencyId,
+	/// The amount.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub amount: Balance,
+}
+
+/// DEX share held by an account, along with the pool reserves it is worth.
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct DexShareBalance<CurrencyId, Balance> {
+	/// The dex share currency.
+	pub currency_id: CurrencyId,
+	/// Dex share held by the account, including the reserved part.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub balance: Balance,
+	/// The reserves of the liquidity pool which the share can be removed for.
+	pub underlying: Vec<CurrencyAmount<CurrencyId, Balance>>,
+}
+
+/// Liquid currency held by an account, along with its value in staking currency.
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct LiquidStakingBalance<CurrencyId, Balance> {
+	/// The liquid currency held by the account, including the reserved part.
+	pub liquid: CurrencyAmount<CurrencyId, Balance>,
+	/// The value of the liquid currency at the current exchange rate.
+	pub staking: CurrencyAmount<CurrencyId, Balance>,
+}
+
+/// Loan position of an account.
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct LoanPosition<CurrencyId, Balance> {
+	pub collateral_currency_id: CurrencyId,
+	/// Collateral locked in the position.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub collateral: Balance,
+	/// Debit of the position, before applying the debit exchange rate.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub debit: Balance,
+	/// Value of the debit in stable currency.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub debit_value: Balance,
+}
+
+/// Shares of an account in an incentive pool.
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct IncentiveStake<PoolId, Balance> {
+	pub pool_id: PoolId,
+	/// Shares of the account in the pool.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub share: Balance,
+}
+
+/// All non-zero holdings of an account.
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Portfolio<CurrencyId, PoolId, Balance> {
+	/// Native and token balances, excluding dex shares.
+	pub balances: Vec<TokenBalance<CurrencyId, Balance>>,
+	/// Dex shares held by the account.
+	pub dex_shares: Vec<DexShareBalance<CurrencyId, Balance>>,
+	/// Liquid staking currency held by the account, `None` if there is none.
+	pub liquid_staking: Option<LiquidStakingBalance<CurrencyId, Balance>>,
+	/// Loan positions of the account.
+	pub loans: Vec<LoanPosition<CurrencyId, Balance>>,
+	/// Shares of the account in incentive pools.
+	pub incentive_stakes: Vec<IncentiveStake<PoolId, Balance>>,
+}
+
+#[cfg(feature = "std")]
+fn serialize_as_string<S: Serializer, T: std::fmt::Display>(t: &T, serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.serialize_str(&t.to_string())
+}
+
+#[cfg(feature = "std")]
+fn deserialize_from_string<'de, D: Deserializer<'de>, T: std::str::FromStr>(deserializer: D) -> Result<T, D::Error> {
+	let s = String::deserialize(deserializer)?;
+	s.parse::<T>()
+		.map_err(|_| serde::de::Error::custom("Parse from string failed"))
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait AssetRegistryApi<CurrencyId, Balance> where
+		CurrencyId: Codec,
+		Balance: Codec,
+	{
+		/// The fee in `currency_id` to buy `weight` for XCM execution, `None`
+		/// if `currency_id` can't pay for XCM execution.
+		fn xcm_execution_fee(currency_id: CurrencyId, weight: u64) -> Option<Balance>;
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait PortfolioApi<AccountId, CurrencyId, PoolId, Balance> where
+		AccountId: Codec,
+		CurrencyId: Codec,
+		PoolId: Codec,
+		Balance: Codec + MaybeDisplay + MaybeFromStr,
+	{
+		/// All non-zero holdings of `account`.
+		fn get_portfolio(account: AccountId) -> Portfolio<CurrencyId, PoolId, Balance>;
+	}
+}
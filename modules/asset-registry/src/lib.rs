@@ -0,0 +1,119 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Asset Registry Module
+//!
+//! ## Overview
+//!
+//! Keep the XCM execution fee rates of cross-chain assets in storage, so
+//! governance can update them without a runtime upgrade. An asset without an
+//! on-chain rate is charged with its default rate in the runtime.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional, weights::constants::WEIGHT_PER_SECOND};
+use frame_system::pallet_prelude::*;
+use sp_std::{boxed::Box, marker::PhantomData};
+use xcm::v0::MultiLocation;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin which may update the fee rates.
+		type RegisterOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The XCM execution fee rate of an asset has been updated.
+		/// \[location, fee_per_second\]
+		FeePerSecondUpdated(MultiLocation, Option<u128>),
+	}
+
+	/// The XCM execution fee of assets per second of weight, overriding the
+	/// default rates of the runtime.
+	///
+	/// FeePerSecond: map MultiLocation => Option<u128>
+	#[pallet::storage]
+	#[pallet::getter(fn fee_per_second)]
+	pub type FeePerSecond<T: Config> = StorageMap<_, Twox64Concat, MultiLocation, u128, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the XCM execution fee rate of the asset at `location`, `None`
+		/// to restore the default rate of the runtime.
+		#[pallet::weight(T::WeightInfo::set_fee_per_second())]
+		#[transactional]
+		pub fn set_fee_per_second(
+			origin: OriginFor<T>,
+			location: Box<MultiLocation>,
+			fee_per_second: Option<u128>,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			FeePerSecond::<T>::mutate_exists(&*location, |maybe_fee_per_second| {
+				*maybe_fee_per_second = fee_per_second;
+			});
+			Self::deposit_event(Event::FeePerSecondUpdated(*location, fee_per_second));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The XCM execution fee to buy `weight` with `fee_per_second`.
+	pub fn execution_fee(fee_per_second: u128, weight: Weight) -> u128 {
+		fee_per_second.saturating_mul(weight as u128) / (WEIGHT_PER_SECOND as u128)
+	}
+}
+
+/// The fee rate of the asset of `DefaultFeePerSecond`, the on-chain rate if
+/// set, otherwise the default rate.
+pub struct FeePerSecondOf<T, DefaultFeePerSecond>(PhantomData<(T, DefaultFeePerSecond)>);
+impl<T: Config, DefaultFeePerSecond: Get<(MultiLocation, u128)>> Get<(MultiLocation, u128)>
+	for FeePerSecondOf<T, DefaultFeePerSecond>
+{
+	fn get() -> (MultiLocation, u128) {
+		let (location, default_fee_per_second) = DefaultFeePerSecond::get();
+		let fee_per_second = Pallet::<T>::fee_per_second(&location).unwrap_or(default_fee_per_second);
+		(location, fee_per_second)
+	}
+}
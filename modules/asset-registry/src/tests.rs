@@ -0,0 +1,86 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the asset registry module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use sp_runtime::traits::BadOrigin;
+use xcm::v0::{Junction::Parent, MultiLocation::X1};
+
+#[test]
+fn set_fee_per_second_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetRegistry::set_fee_per_second(Origin::signed(BOB), Box::new(X1(Parent)), Some(2_000)),
+			BadOrigin
+		);
+
+		assert_ok!(AssetRegistry::set_fee_per_second(
+			Origin::signed(ALICE),
+			Box::new(X1(Parent)),
+			Some(2_000)
+		));
+		System::assert_last_event(Event::AssetRegistry(crate::Event::FeePerSecondUpdated(
+			X1(Parent),
+			Some(2_000),
+		)));
+		assert_eq!(AssetRegistry::fee_per_second(X1(Parent)), Some(2_000));
+
+		assert_ok!(AssetRegistry::set_fee_per_second(
+			Origin::signed(ALICE),
+			Box::new(X1(Parent)),
+			None
+		));
+		System::assert_last_event(Event::AssetRegistry(crate::Event::FeePerSecondUpdated(
+			X1(Parent),
+			None,
+		)));
+		assert_eq!(AssetRegistry::fee_per_second(X1(Parent)), None);
+	});
+}
+
+#[test]
+fn fee_per_second_of_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			FeePerSecondOf::<Runtime, RelayChainFeePerSecond>::get(),
+			(X1(Parent), 1_000)
+		);
+
+		assert_ok!(AssetRegistry::set_fee_per_second(
+			Origin::signed(ALICE),
+			Box::new(X1(Parent)),
+			Some(2_000)
+		));
+		assert_eq!(
+			FeePerSecondOf::<Runtime, RelayChainFeePerSecond>::get(),
+			(X1(Parent), 2_000)
+		);
+	});
+}
+
+#[test]
+fn execution_fee_works() {
+	assert_eq!(AssetRegistry::execution_fee(1_000, WEIGHT_PER_SECOND), 1_000);
+	assert_eq!(AssetRegistry::execution_fee(1_000, WEIGHT_PER_SECOND / 4), 250);
+	assert_eq!(AssetRegistry::execution_fee(1_000, 0), 0);
+}
@@ -24,6 +24,12 @@
 //! business. Auction types include:
 //!   - `collateral auction`: sell collateral assets for getting stable currency to eliminate the
 //!     system's bad debit by auction
+//!
+//! Collateral auctions are English auctions by default, a collateral type can
+//! also be configured to be sold by Dutch auctions, whose price decays every
+//! block from a premium over the feed price, and the first bidder wins. Dutch
+//! auctions not taken by the floor price are settled as English auctions
+//! without bid.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -34,7 +40,7 @@ use frame_system::{
 	offchain::{SendTransactionTypes, SubmitTransaction},
 	pallet_prelude::*,
 };
-use orml_traits::{Auction, AuctionHandler, Change, MultiCurrency, OnNewBidResult};
+use orml_traits::{Auction, AuctionHandler, AuctionInfo, Change, GetByKey, MultiCurrency, OnNewBidResult};
 use orml_utilities::OffchainErr;
 use primitives::{AuctionId, Balance, CurrencyId};
 use sp_runtime::{
@@ -43,14 +49,17 @@ use sp_runtime::{
 		storage_lock::{StorageLock, Time},
 		Duration,
 	},
-	traits::{CheckedDiv, Saturating, Zero},
+	traits::{CheckedDiv, One, Saturating, UniqueSaturatedInto, Zero},
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
 	},
 	DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
 };
 use sp_std::prelude::*;
-use support::{AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, EmergencyShutdown, PriceProvider, Rate};
+use support::{
+	AuctionManager, CDPTreasury, CDPTreasuryExtended, CollateralAuctionType, DEXManager, EmergencyShutdown, Price,
+	PriceProvider, Rate, RiskParameterKey, RiskParameterProvider,
+};
 
 mod mock;
 mod tests;
@@ -179,6 +188,24 @@ pub mod module {
 		#[pallet::constant]
 		type DefaultSwapParitalPathList: Get<Vec<Vec<CurrencyId>>>;
 
+		/// The auction type to sell specific collateral type
+		type CollateralAuctionTypes: GetByKey<CurrencyId, CollateralAuctionType>;
+
+		/// The premium over the feed price of the collateral to start Dutch
+		/// auctions at
+		#[pallet::constant]
+		type DutchAuctionStartingPremium: Get<Rate>;
+
+		/// The rate at which the price of Dutch auctions decays every block,
+		/// the price stops decaying once the auction lasts for
+		/// `AuctionDurationSoftCap`
+		#[pallet::constant]
+		type DutchAuctionPriceDecay: Get<Rate>;
+
+		/// The risk parameters set by governance with enactment delay, which
+		/// override `AuctionTimeToClose` and `AuctionDurationSoftCap`.
+		type RiskParameters: RiskParameterProvider;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -197,6 +224,18 @@ pub mod module {
 		InvalidBidPrice,
 		/// Invalid input amount
 		InvalidAmount,
+		/// The collateral auction is not a Dutch auction
+		NotDutchAuction,
+		/// Dutch auctions can only be bid by `bid_dutch_auction`
+		IsDutchAuction,
+		/// The payment of the Dutch auction is above the max payment
+		PaymentAboveMaximum,
+		/// System has already been shutdown
+		AlreadyShutdown,
+		/// The payment currency can't be the stable currency
+		InvalidPaymentCurrency,
+		/// No valid swap path from the payment currency to stable currency
+		InvalidSwapPath,
 	}
 
 	#[pallet::event]
@@ -214,6 +253,10 @@ pub mod module {
 		/// Dex take collateral auction. \[auction_id, collateral_type,
 		/// collateral_amount, turnover\]
 		DEXTakeCollateralAuction(AuctionId, CurrencyId, Balance, Balance),
+		/// Collateral auction bid with the stable currency swapped from other
+		/// currency. \[auction_id, bidder, payment_currency_id,
+		/// payment_amount, bid_price\]
+		BidWithSwap(AuctionId, T::AccountId, CurrencyId, Balance, Balance),
 	}
 
 	/// Mapping from auction id to collateral auction info
@@ -224,6 +267,13 @@ pub mod module {
 	pub type CollateralAuctions<T: Config> =
 		StorageMap<_, Twox64Concat, AuctionId, CollateralAuctionItem<T::AccountId, T::BlockNumber>, OptionQuery>;
 
+	/// Mapping from Dutch auction id to its starting price
+	///
+	/// DutchAuctionStartingPrice: map AuctionId => Option<Price>
+	#[pallet::storage]
+	#[pallet::getter(fn dutch_auction_starting_price)]
+	pub type DutchAuctionStartingPrice<T: Config> = StorageMap<_, Twox64Concat, AuctionId, Price, OptionQuery>;
+
 	/// Record of the total collateral amount of all active collateral auctions
 	/// under specific collateral type CollateralType -> TotalAmount
 	///
@@ -285,6 +335,54 @@ pub mod module {
 			Self::deposit_event(Event::CancelAuction(id));
 			Ok(())
 		}
+
+		/// Take a Dutch collateral auction at its current price, the auction
+		/// is dealt immediately.
+		///
+		/// - `id`: the Dutch auction id.
+		/// - `max_payment`: the max stable currency amount to pay.
+		#[pallet::weight(T::WeightInfo::bid_dutch_auction())]
+		#[transactional]
+		pub fn bid_dutch_auction(
+			origin: OriginFor<T>,
+			id: AuctionId,
+			#[pallet::compact] max_payment: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			Self::dutch_auction_bid_handler(<frame_system::Pallet<T>>::block_number(), id, who, max_payment)
+		}
+
+		/// Bid a collateral auction with other currency, which is swapped to
+		/// stable currency through DEX in the same call.
+		///
+		/// - `id`: the collateral auction id.
+		/// - `max_payment_currency`: the currency to pay with.
+		/// - `max_amount`: the max amount of `max_payment_currency` to pay.
+		///   English auctions are bid with all the stable currency swapped
+		///   from it, Dutch auctions are taken at their current price.
+		/// - `min_target_amount`: the min stable currency amount swapped from
+		///   `max_amount` to bid English auctions, ignored by Dutch auctions.
+		#[pallet::weight(T::WeightInfo::bid_with_swap())]
+		#[transactional]
+		pub fn bid_with_swap(
+			origin: OriginFor<T>,
+			id: AuctionId,
+			max_payment_currency: CurrencyId,
+			#[pallet::compact] max_amount: Balance,
+			#[pallet::compact] min_target_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			Self::bid_with_swap_handler(
+				<frame_system::Pallet<T>>::block_number(),
+				id,
+				who,
+				max_payment_currency,
+				max_amount,
+				min_target_amount,
+			)
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -485,8 +583,24 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The extended time of auctions after each bid, `AuctionTimeToClose` if
+	/// it's not overridden by the risk parameters.
+	pub fn auction_time_to_close() -> T::BlockNumber {
+		T::RiskParameters::get_block_number(RiskParameterKey::AuctionTimeToClose)
+			.map(Into::into)
+			.unwrap_or_else(T::AuctionTimeToClose::get)
+	}
+
+	/// The soft cap of auction durations, `AuctionDurationSoftCap` if it's not
+	/// overridden by the risk parameters.
+	pub fn auction_duration_soft_cap() -> T::BlockNumber {
+		T::RiskParameters::get_block_number(RiskParameterKey::AuctionDurationSoftCap)
+			.map(Into::into)
+			.unwrap_or_else(T::AuctionDurationSoftCap::get)
+	}
+
 	fn get_minimum_increment_size(now: T::BlockNumber, start_block: T::BlockNumber) -> Rate {
-		if now >= start_block + T::AuctionDurationSoftCap::get() {
+		if now >= start_block + Self::auction_duration_soft_cap() {
 			// double the minimum increment size when reach soft cap
 			T::MinimumIncrementSize::get().saturating_mul(Rate::saturating_from_integer(2))
 		} else {
@@ -495,14 +609,180 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn get_auction_time_to_close(now: T::BlockNumber, start_block: T::BlockNumber) -> T::BlockNumber {
-		if now >= start_block + T::AuctionDurationSoftCap::get() {
+		if now >= start_block + Self::auction_duration_soft_cap() {
 			// halve the extended time of bid when reach soft cap
-			T::AuctionTimeToClose::get()
+			Self::auction_time_to_close()
 				.checked_div(&2u32.into())
 				.expect("cannot overflow with positive divisor; qed")
 		} else {
-			T::AuctionTimeToClose::get()
+			Self::auction_time_to_close()
+		}
+	}
+
+	/// The current price of the collateral in a Dutch auction, in stable
+	/// currency.
+	///
+	/// Formula: starting_price * (1 - price_decay) ^ min(now - start_time,
+	///     auction_duration_soft_cap)
+	pub fn get_dutch_auction_price(id: AuctionId, now: T::BlockNumber) -> Option<Price> {
+		let starting_price = Self::dutch_auction_starting_price(id)?;
+		let start_time = Self::collateral_auctions(id)?.start_time;
+		let elapsed: u32 = now
+			.saturating_sub(start_time)
+			.min(Self::auction_duration_soft_cap())
+			.unique_saturated_into();
+
+		Some(
+			Rate::one()
+				.saturating_sub(T::DutchAuctionPriceDecay::get())
+				.saturating_pow(elapsed as usize)
+				.saturating_mul(starting_price),
+		)
+	}
+
+	/// The stable currency payment to take a Dutch auction at `now`, and
+	/// the collateral amount sold for it. Returns `(payment,
+	/// collateral_amount)`.
+	pub fn get_dutch_auction_payment(id: AuctionId, now: T::BlockNumber) -> Option<(Balance, Balance)> {
+		let collateral_auction = Self::collateral_auctions(id)?;
+		let price = Self::get_dutch_auction_price(id, now)?;
+		let collateral_value = price.saturating_mul_int(collateral_auction.amount);
+
+		if collateral_auction.in_reverse_stage(collateral_value) {
+			let collateral_amount = Rate::checked_from_rational(collateral_auction.target, collateral_value)
+				.and_then(|n| n.checked_mul_int(collateral_auction.amount))
+				.map_or(collateral_auction.amount, |amount| {
+					amount.min(collateral_auction.amount)
+				});
+			Some((collateral_auction.target, collateral_amount))
+		} else {
+			Some((collateral_value, collateral_auction.amount))
+		}
+	}
+
+	/// Swap paths from `currency_id` to stable currency, formed by
+	/// `DefaultSwapParitalPathList`.
+	fn get_swap_paths_to_stable(currency_id: CurrencyId) -> Vec<Vec<CurrencyId>> {
+		T::DefaultSwapParitalPathList::get()
+			.into_iter()
+			.filter(|partial_path| !partial_path.is_empty() && partial_path[0] != currency_id)
+			.map(|partial_path| {
+				let mut swap_path = vec![currency_id];
+				swap_path.extend(partial_path);
+				swap_path
+			})
+			.collect()
+	}
+
+	/// Swap `payment_currency_id` of `bidder` to stable currency through DEX
+	/// by the best swap path and bid the auction. Bids Dutch auctions at
+	/// their current payment, the supply amount is limited by `max_amount`.
+	/// Bids English auctions with all the stable currency swapped from
+	/// `max_amount`, which must be at least `min_target_amount`.
+	fn bid_with_swap_handler(
+		now: T::BlockNumber,
+		id: AuctionId,
+		bidder: T::AccountId,
+		payment_currency_id: CurrencyId,
+		max_amount: Balance,
+		min_target_amount: Balance,
+	) -> DispatchResult {
+		ensure!(
+			payment_currency_id != T::GetStableCurrencyId::get(),
+			Error::<T>::InvalidPaymentCurrency
+		);
+		ensure!(Self::collateral_auctions(id).is_some(), Error::<T>::AuctionNotExists);
+		let swap_paths = Self::get_swap_paths_to_stable(payment_currency_id);
+
+		let (supply_amount, bid_price) = if let Some((payment, _)) = Self::get_dutch_auction_payment(id, now) {
+			let swap_path = swap_paths
+				.into_iter()
+				.filter_map(|path| T::DEX::get_swap_supply_amount(&path, payment).map(|supply| (supply, path)))
+				.min_by_key(|(supply, _)| *supply)
+				.map(|(_, path)| path)
+				.ok_or(Error::<T>::InvalidSwapPath)?;
+			let supply_amount = T::DEX::swap_with_exact_target(&bidder, &swap_path, payment, max_amount)?;
+			Self::dutch_auction_bid_handler(now, id, bidder.clone(), payment)?;
+			(supply_amount, payment)
+		} else {
+			let swap_path = swap_paths
+				.into_iter()
+				.filter_map(|path| T::DEX::get_swap_target_amount(&path, max_amount).map(|target| (target, path)))
+				.max_by_key(|(target, _)| *target)
+				.map(|(_, path)| path)
+				.ok_or(Error::<T>::InvalidSwapPath)?;
+			let bid_price = T::DEX::swap_with_exact_supply(&bidder, &swap_path, max_amount, min_target_amount)?;
+
+			let auction = T::Auction::auction_info(id).ok_or(Error::<T>::AuctionNotExists)?;
+			let new_end = Self::collateral_auction_bid_handler(now, id, (bidder.clone(), bid_price), auction.bid)?;
+			T::Auction::update_auction(
+				id,
+				AuctionInfo {
+					bid: Some((bidder.clone(), bid_price)),
+					start: auction.start,
+					end: Some(new_end),
+				},
+			)?;
+			(max_amount, bid_price)
+		};
+
+		Self::deposit_event(Event::BidWithSwap(
+			id,
+			bidder,
+			payment_currency_id,
+			supply_amount,
+			bid_price,
+		));
+		Ok(())
+	}
+
+	/// Deals the Dutch auction to `bidder` at the current price. If the
+	/// collateral is worth more than the target, only the collateral to
+	/// reach the target is sold and the rest is refunded.
+	fn dutch_auction_bid_handler(
+		now: T::BlockNumber,
+		id: AuctionId,
+		bidder: T::AccountId,
+		max_payment: Balance,
+	) -> DispatchResult {
+		let collateral_auction = Self::collateral_auctions(id).ok_or(Error::<T>::AuctionNotExists)?;
+		let (payment, collateral_amount) =
+			Self::get_dutch_auction_payment(id, now).ok_or(Error::<T>::NotDutchAuction)?;
+		ensure!(!payment.is_zero(), Error::<T>::InvalidBidPrice);
+		ensure!(payment <= max_payment, Error::<T>::PaymentAboveMaximum);
+
+		T::CDPTreasury::deposit_surplus(&bidder, payment)?;
+		T::CDPTreasury::withdraw_collateral(&bidder, collateral_auction.currency_id, collateral_amount)?;
+		let refund_collateral_amount = collateral_auction.amount.saturating_sub(collateral_amount);
+		if !refund_collateral_amount.is_zero() {
+			T::CDPTreasury::withdraw_collateral(
+				&collateral_auction.refund_recipient,
+				collateral_auction.currency_id,
+				refund_collateral_amount,
+			)?;
 		}
+
+		<CollateralAuctions<T>>::remove(id);
+		DutchAuctionStartingPrice::<T>::remove(id);
+		T::Auction::remove_auction(id);
+
+		// decrement recipient account reference
+		frame_system::Pallet::<T>::dec_consumers(&collateral_auction.refund_recipient);
+
+		// update auction records
+		TotalCollateralInAuction::<T>::mutate(collateral_auction.currency_id, |balance| {
+			*balance = balance.saturating_sub(collateral_auction.amount)
+		});
+		TotalTargetInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(collateral_auction.target));
+
+		Self::deposit_event(Event::CollateralAuctionDealt(
+			id,
+			collateral_auction.currency_id,
+			collateral_amount,
+			bidder,
+			payment,
+		));
+		Ok(())
 	}
 
 	/// Handles collateral auction new bid. Returns
@@ -518,6 +798,10 @@ impl<T: Config> Pallet<T> {
 	) -> sp_std::result::Result<T::BlockNumber, DispatchError> {
 		let (new_bidder, new_bid_price) = new_bid;
 		ensure!(!new_bid_price.is_zero(), Error::<T>::InvalidBidPrice);
+		ensure!(
+			!DutchAuctionStartingPrice::<T>::contains_key(id),
+			Error::<T>::IsDutchAuction
+		);
 
 		<CollateralAuctions<T>>::try_mutate_exists(
 			id,
@@ -761,6 +1045,7 @@ impl<T: Config> AuctionHandler<T::AccountId, Balance, T::BlockNumber, AuctionId>
 
 	fn on_auction_ended(id: AuctionId, winner: Option<(T::AccountId, Balance)>) {
 		if let Some(collateral_auction) = <CollateralAuctions<T>>::take(id) {
+			DutchAuctionStartingPrice::<T>::remove(id);
 			Self::collateral_auction_end_handler(id, collateral_auction, winner.clone());
 		}
 
@@ -797,9 +1082,29 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 		}
 
 		let start_time = <frame_system::Pallet<T>>::block_number();
-		// use start_time + AuctionDurationSoftCap as the initial end-time of collateral auction.
-		let end_time = start_time.saturating_add(T::AuctionDurationSoftCap::get());
+
+		// Dutch auction starts at a premium over the feed price, fallback to English auction
+		// if the price is unavailable.
+		let dutch_auction_starting_price = match T::CollateralAuctionTypes::get(&currency_id) {
+			CollateralAuctionType::Dutch => {
+				T::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get()).map(|price| {
+					price.saturating_mul(T::DutchAuctionStartingPremium::get().saturating_add(Rate::one()))
+				})
+			}
+			CollateralAuctionType::English => None,
+		};
+
+		// use start_time + AuctionDurationSoftCap as the initial end-time of English collateral
+		// auction. Dutch auction ends AuctionTimeToClose after its price decays to the floor, and
+		// is settled as an English auction without bid.
+		let mut end_time = start_time.saturating_add(Self::auction_duration_soft_cap());
+		if dutch_auction_starting_price.is_some() {
+			end_time = end_time.saturating_add(Self::auction_time_to_close());
+		}
 		let auction_id = T::Auction::new_auction(start_time, Some(end_time))?;
+		if let Some(starting_price) = dutch_auction_starting_price {
+			DutchAuctionStartingPrice::<T>::insert(auction_id, starting_price);
+		}
 
 		<CollateralAuctions<T>>::insert(
 			auction_id,
@@ -833,6 +1138,7 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 	fn cancel_auction(id: Self::AuctionId) -> DispatchResult {
 		let collateral_auction = <CollateralAuctions<T>>::take(id).ok_or(Error::<T>::AuctionNotExists)?;
 		Self::cancel_collateral_auction(id, collateral_auction)?;
+		DutchAuctionStartingPrice::<T>::remove(id);
 		T::Auction::remove_auction(id);
 		Ok(())
 	}
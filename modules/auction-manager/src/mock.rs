@@ -41,6 +41,7 @@ pub type Amount = i64;
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
 pub const CAROL: AccountId = 3;
+pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 pub const BTC: CurrencyId = CurrencyId::Token(TokenSymbol::RENBTC);
 pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
@@ -111,6 +112,7 @@ ord_parameter_types! {
 
 parameter_types! {
 	pub const GetStableCurrencyId: CurrencyId = AUSD;
+	pub const GetNativeCurrencyId: CurrencyId = ACA;
 	pub const MaxAuctionsCount: u32 = 10_000;
 	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"aca/cdpt");
 	pub TreasuryAccount: AccountId = PalletId(*b"aca/hztr").into_account();
@@ -120,12 +122,14 @@ impl cdp_treasury::Config for Runtime {
 	type Event = Event;
 	type Currency = Tokens;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type AuctionManagerHandler = AuctionManagerModule;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type DEX = DEXModule;
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type IdleScheduler = ();
 	type WeightInfo = ();
 }
 
@@ -165,6 +169,9 @@ impl module_dex::Config for Runtime {
 	type Currency = Tokens;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type RoutingCurrencies = ();
+	type TWAPObservationPeriod = ();
+	type MaxTWAPObservations = ();
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = ();
 	type DEXIncentives = ();
@@ -196,6 +203,17 @@ parameter_types! {
 		vec![AUSD],
 		vec![DOT, AUSD],
 	];
+	pub DutchAuctionStartingPremium: Rate = Rate::saturating_from_rational(1, 10);
+	pub DutchAuctionPriceDecay: Rate = Rate::saturating_from_rational(1, 100);
+}
+
+parameter_type_with_key! {
+	pub CollateralAuctionTypes: |currency_id: CurrencyId| -> CollateralAuctionType {
+		match currency_id {
+			&DOT => CollateralAuctionType::Dutch,
+			_ => CollateralAuctionType::English,
+		}
+	};
 }
 
 impl Config for Runtime {
@@ -212,6 +230,10 @@ impl Config for Runtime {
 	type UnsignedPriority = UnsignedPriority;
 	type EmergencyShutdown = MockEmergencyShutdown;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type CollateralAuctionTypes = CollateralAuctionTypes;
+	type DutchAuctionStartingPremium = DutchAuctionStartingPremium;
+	type DutchAuctionPriceDecay = DutchAuctionPriceDecay;
+	type RiskParameters = ();
 	type WeightInfo = ();
 }
 
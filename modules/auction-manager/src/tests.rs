@@ -483,6 +483,241 @@ fn cancel_collateral_auction_work() {
 	});
 }
 
+#[test]
+fn new_dutch_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, DOT, 10, 100));
+		assert_eq!(
+			AuctionManagerModule::dutch_auction_starting_price(0),
+			Some(Price::saturating_from_rational(11, 10))
+		);
+		assert_eq!(AuctionModule::auction_info(0).unwrap().end, Some(2101));
+		assert_eq!(
+			AuctionManagerModule::get_dutch_auction_price(0, 1),
+			Some(Price::saturating_from_rational(11, 10))
+		);
+		assert_eq!(
+			AuctionManagerModule::get_dutch_auction_price(0, 3),
+			Some(Price::saturating_from_rational(107811, 100000))
+		);
+		assert_eq!(
+			AuctionManagerModule::get_dutch_auction_price(0, 10_000),
+			AuctionManagerModule::get_dutch_auction_price(0, 2001)
+		);
+
+		// Dutch auction can't be bid through orml auction
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(BOB), 0, 20),
+			orml_auction::Error::<Runtime>::BidNotAccepted,
+		);
+
+		// fallback to English auction if the price is unavailable
+		MockPriceSource::set_relative_price(None);
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, DOT, 10, 100));
+		assert_eq!(AuctionManagerModule::dutch_auction_starting_price(1), None);
+		assert_eq!(AuctionModule::auction_info(1).unwrap().end, Some(2001));
+	});
+}
+
+#[test]
+fn bid_dutch_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, DOT, 10));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, DOT, 10, 100));
+		assert_noop!(
+			AuctionManagerModule::bid_dutch_auction(Origin::signed(BOB), 1, 11),
+			Error::<Runtime>::AuctionNotExists,
+		);
+		assert_noop!(
+			AuctionManagerModule::bid_dutch_auction(Origin::signed(BOB), 0, 10),
+			Error::<Runtime>::PaymentAboveMaximum,
+		);
+
+		let alice_ref_count_0 = System::consumers(&ALICE);
+		assert_ok!(AuctionManagerModule::bid_dutch_auction(Origin::signed(BOB), 0, 11));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CollateralAuctionDealt(
+			0, DOT, 10, BOB, 11,
+		)));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 989);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 1010);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 11);
+		assert_eq!(CDPTreasuryModule::total_collaterals(DOT), 0);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(DOT), 0);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 0);
+		assert_eq!(AuctionManagerModule::collateral_auctions(0), None);
+		assert_eq!(AuctionManagerModule::dutch_auction_starting_price(0), None);
+		assert_eq!(AuctionModule::auction_info(0), None);
+		assert_eq!(System::consumers(&ALICE), alice_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn bid_dutch_auction_in_reverse_stage_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, DOT, 100));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, DOT, 100, 55));
+
+		// the collateral worth 110 at first, only 50 collateral is sold to reach the target
+		assert_ok!(AuctionManagerModule::bid_dutch_auction(Origin::signed(BOB), 0, 100));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CollateralAuctionDealt(
+			0, DOT, 50, BOB, 55,
+		)));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 945);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 1050);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 1050);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 55);
+		assert_eq!(CDPTreasuryModule::total_collaterals(DOT), 0);
+	});
+}
+
+#[test]
+fn bid_dutch_auction_after_price_decay_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, DOT, 100));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, DOT, 100, 200));
+
+		System::set_block_number(11);
+		assert_noop!(
+			AuctionManagerModule::bid_dutch_auction(Origin::signed(BOB), 0, 98),
+			Error::<Runtime>::PaymentAboveMaximum,
+		);
+		assert_ok!(AuctionManagerModule::bid_dutch_auction(Origin::signed(BOB), 0, 99));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CollateralAuctionDealt(
+			0, DOT, 100, BOB, 99,
+		)));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 901);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 1100);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 99);
+	});
+}
+
+#[test]
+fn dutch_auction_end_at_floor_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, DOT, 10));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, DOT, 10, 100));
+		let alice_ref_count_0 = System::consumers(&ALICE);
+
+		// no DEX liquidity to take it, the collateral is kept by the CDP treasury
+		AuctionManagerModule::on_auction_ended(0, None);
+		assert_eq!(AuctionManagerModule::collateral_auctions(0), None);
+		assert_eq!(AuctionManagerModule::dutch_auction_starting_price(0), None);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(DOT), 0);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 0);
+		assert_eq!(CDPTreasuryModule::total_collaterals(DOT), 10);
+		assert_eq!(System::consumers(&ALICE), alice_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn bid_dutch_auction_failed_when_shutdown() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, DOT, 10));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, DOT, 10, 100));
+		mock_shutdown();
+		assert_noop!(
+			AuctionManagerModule::bid_dutch_auction(Origin::signed(BOB), 0, 11),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+
+		assert_ok!(AuctionManagerModule::cancel(Origin::none(), 0));
+		assert_eq!(AuctionManagerModule::dutch_auction_starting_price(0), None);
+		assert_eq!(CDPTreasuryModule::total_collaterals(DOT), 10);
+	});
+}
+
+#[test]
+fn bid_with_swap_english_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(CAROL),
+			DOT,
+			AUSD,
+			100,
+			1000,
+			0,
+			false
+		));
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 10));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+
+		assert_noop!(
+			AuctionManagerModule::bid_with_swap(Origin::signed(BOB), 1, DOT, 10, 0),
+			Error::<Runtime>::AuctionNotExists,
+		);
+		assert_noop!(
+			AuctionManagerModule::bid_with_swap(Origin::signed(BOB), 0, AUSD, 10, 0),
+			Error::<Runtime>::InvalidPaymentCurrency,
+		);
+
+		assert_noop!(
+			AuctionManagerModule::bid_with_swap(Origin::signed(BOB), 0, DOT, 10, 91),
+			module_dex::Error::<Runtime>::InsufficientTargetAmount,
+		);
+		assert_ok!(AuctionManagerModule::bid_with_swap(Origin::signed(BOB), 0, DOT, 10, 90));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::BidWithSwap(
+			0, BOB, DOT, 10, 90,
+		)));
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 990);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 90);
+		assert_eq!(
+			AuctionModule::auction_info(0),
+			Some(orml_traits::AuctionInfo {
+				bid: Some((BOB, 90)),
+				start: 1,
+				end: Some(101),
+			})
+		);
+
+		// the next bid must reach the minimum increment
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(CAROL), 0, 91),
+			orml_auction::Error::<Runtime>::BidNotAccepted,
+		);
+	});
+}
+
+#[test]
+fn bid_with_swap_dutch_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(CAROL),
+			BTC,
+			AUSD,
+			100,
+			1000,
+			0,
+			false
+		));
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, DOT, 10));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, DOT, 10, 100));
+
+		assert_noop!(
+			AuctionManagerModule::bid_with_swap(Origin::signed(BOB), 0, BTC, 1, 0),
+			module_dex::Error::<Runtime>::ExcessiveSupplyAmount,
+		);
+		assert_ok!(AuctionManagerModule::bid_with_swap(Origin::signed(BOB), 0, BTC, 2, 0));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::BidWithSwap(
+			0, BOB, BTC, 2, 11,
+		)));
+		assert_eq!(Tokens::free_balance(BTC, &BOB), 998);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 1010);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 11);
+		assert_eq!(AuctionManagerModule::collateral_auctions(0), None);
+		assert_eq!(AuctionModule::auction_info(0), None);
+	});
+}
+
 #[test]
 fn offchain_worker_cancels_auction_in_shutdown() {
 	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
@@ -48,6 +48,8 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for module_auction_manager.
 pub trait WeightInfo {
 	fn cancel_collateral_auction() -> Weight;
+	fn bid_dutch_auction() -> Weight;
+	fn bid_with_swap() -> Weight;
 }
 
 /// Weights for module_auction_manager using the Acala node and recommended hardware.
@@ -58,6 +60,16 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(9 as Weight))
 			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
+	fn bid_dutch_auction() -> Weight {
+		(96_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	fn bid_with_swap() -> Weight {
+		(164_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(19 as Weight))
+			.saturating_add(T::DbWeight::get().writes(14 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -67,4 +79,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
 	}
+	fn bid_dutch_auction() -> Weight {
+		(96_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	fn bid_with_swap() -> Weight {
+		(164_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(19 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(14 as Weight))
+	}
 }
@@ -0,0 +1,258 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Buyback Module
+//!
+//! ## Overview
+//!
+//! Periodically spends stable currency to buy the native currency on DEX, and
+//! burns the bought native currency or sends it to the incentives rewards
+//! source. The stable currency comes from a governance-set proportion of the
+//! CDP treasury surplus pulled each period, and anything else sent to the
+//! account of this module, e.g. fees.
+//!
+//! The stable currency spent each period is limited, and the buyback is
+//! skipped when the native currency is priced above the governance-set
+//! ceiling on DEX, or the swap would slip more than the max slippage.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional, PalletId};
+use frame_system::pallet_prelude::*;
+use orml_traits::MultiCurrency;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{AccountIdConversion, One, Saturating, Zero},
+	FixedPointNumber, Permill,
+};
+use support::{CDPTreasury, DEXManager, Price, Ratio};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// Where the bought native currency goes.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum BuybackTarget {
+	/// Burn the bought native currency.
+	Burn,
+	/// Send the bought native currency to the incentives rewards source.
+	Incentives,
+}
+
+/// The governance-set parameters of the buyback.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub struct BuybackParams {
+	/// The proportion of the CDP treasury surplus pulled each period.
+	pub surplus_proportion: Permill,
+	/// The max amount of stable currency spent each period.
+	pub max_supply_per_period: Balance,
+	/// The max price of the native currency in stable currency to buy at.
+	pub max_price: Price,
+	/// The max slippage of the swap from the DEX price.
+	pub max_slippage: Ratio,
+	/// Where the bought native currency goes.
+	pub target: BuybackTarget,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency for buyback.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The CDP treasury to pull surplus from.
+		type CDPTreasury: CDPTreasury<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
+
+		/// The DEX to buy the native currency on.
+		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
+
+		/// The native currency id.
+		#[pallet::constant]
+		type GetNativeCurrencyId: Get<CurrencyId>;
+
+		/// The stable currency id.
+		#[pallet::constant]
+		type GetStableCurrencyId: Get<CurrencyId>;
+
+		/// The number of blocks between buybacks.
+		#[pallet::constant]
+		type BuybackPeriod: Get<Self::BlockNumber>;
+
+		/// The account the bought native currency is sent to with
+		/// `BuybackTarget::Incentives`.
+		type IncentivesAccount: Get<Self::AccountId>;
+
+		/// The buyback module id, keep the stable currency to spend.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The origin which may update the buyback parameters.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The max slippage is more than 100%.
+		InvalidSlippage,
+		/// No stable currency to spend.
+		NothingToBuyback,
+		/// The DEX has no liquidity for the buyback.
+		InvalidPool,
+		/// The native currency price on DEX is above the max price.
+		PriceAboveMax,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The buyback parameters have been updated. \[params\]
+		BuybackParamsUpdated(Option<BuybackParams>),
+		/// Bought native currency with stable currency.
+		/// \[stable_amount, native_amount, target\]
+		Buyback(Balance, Balance, BuybackTarget),
+	}
+
+	/// The buyback parameters, no buyback if `None`.
+	///
+	/// Params: BuybackParams
+	#[pallet::storage]
+	#[pallet::getter(fn params)]
+	pub type Params<T: Config> = StorageValue<_, BuybackParams, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			if !(now % T::BuybackPeriod::get()).is_zero() {
+				return T::WeightInfo::on_initialize();
+			}
+
+			if let Some(params) = Self::params() {
+				if let Err(e) = Self::do_buyback(params) {
+					log::debug!(
+						target: "buyback",
+						"buyback at #{:?} skipped: {:?}",
+						now,
+						e,
+					);
+				}
+				T::WeightInfo::on_initialize_with_buyback()
+			} else {
+				T::WeightInfo::on_initialize()
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Update the buyback parameters, `None` to stop the buyback.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		#[pallet::weight(T::WeightInfo::set_buyback_params())]
+		#[transactional]
+		pub fn set_buyback_params(origin: OriginFor<T>, params: Option<BuybackParams>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if let Some(params) = params {
+				ensure!(params.max_slippage <= Ratio::one(), Error::<T>::InvalidSlippage);
+			}
+
+			Params::<T>::set(params);
+			Self::deposit_event(Event::BuybackParamsUpdated(params));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Get account of buyback module.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account()
+	}
+
+	/// Pull the surplus, buy native currency with up to
+	/// `max_supply_per_period` stable currency and burn or send it.
+	#[transactional]
+	fn do_buyback(params: BuybackParams) -> DispatchResult {
+		let account = Self::account_id();
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let native_currency_id = T::GetNativeCurrencyId::get();
+
+		// only pull the surplus that can be spent in this period.
+		let balance = T::Currency::free_balance(stable_currency_id, &account);
+		let surplus = params
+			.surplus_proportion
+			.mul_floor(T::CDPTreasury::get_surplus_pool())
+			.min(params.max_supply_per_period.saturating_sub(balance));
+		if !surplus.is_zero() {
+			T::CDPTreasury::withdraw_surplus(&account, surplus)?;
+		}
+
+		let supply_amount = T::Currency::free_balance(stable_currency_id, &account).min(params.max_supply_per_period);
+		ensure!(!supply_amount.is_zero(), Error::<T>::NothingToBuyback);
+
+		let (stable_pool, native_pool) = T::DEX::get_liquidity_pool(stable_currency_id, native_currency_id);
+		let price = Price::checked_from_rational(stable_pool, native_pool)
+			.filter(|price| !price.is_zero())
+			.ok_or(Error::<T>::InvalidPool)?;
+		ensure!(price <= params.max_price, Error::<T>::PriceAboveMax);
+
+		let expected_target_amount = price
+			.reciprocal()
+			.and_then(|rate| rate.checked_mul_int(supply_amount))
+			.ok_or(Error::<T>::InvalidPool)?;
+		let min_target_amount = Ratio::one()
+			.saturating_sub(params.max_slippage)
+			.saturating_mul_int(expected_target_amount);
+
+		let target_amount = T::DEX::swap_with_exact_supply(
+			&account,
+			&[stable_currency_id, native_currency_id],
+			supply_amount,
+			min_target_amount,
+		)?;
+
+		match params.target {
+			BuybackTarget::Burn => T::Currency::withdraw(native_currency_id, &account, target_amount)?,
+			BuybackTarget::Incentives => T::Currency::transfer(
+				native_currency_id,
+				&account,
+				&T::IncentivesAccount::get(),
+				target_amount,
+			)?,
+		}
+
+		Self::deposit_event(Event::Buyback(supply_amount, target_amount, params.target));
+		Ok(())
+	}
+}
@@ -0,0 +1,296 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the buyback module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{construct_runtime, ord_parameter_types, parameter_types};
+use frame_system::EnsureSignedBy;
+use orml_traits::parameter_type_with_key;
+use primitives::{Amount, TokenSymbol};
+use sp_core::{H160, H256};
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+use support::Ratio;
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const INCENTIVES: AccountId = 3;
+pub const CDP_TREASURY: AccountId = 10;
+pub const DEX_POOL: AccountId = 11;
+pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
+pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
+
+mod buyback {
+	pub use super::super::*;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = ();
+	type DustRemovalWhitelist = ();
+}
+
+/// Keeps the surplus in the `CDP_TREASURY` account.
+pub struct MockCDPTreasury;
+impl CDPTreasury<AccountId> for MockCDPTreasury {
+	type Balance = Balance;
+	type CurrencyId = CurrencyId;
+
+	fn get_surplus_pool() -> Balance {
+		Tokens::free_balance(AUSD, &CDP_TREASURY)
+	}
+
+	fn get_debit_pool() -> Balance {
+		unimplemented!()
+	}
+
+	fn get_total_collaterals(_: CurrencyId) -> Balance {
+		unimplemented!()
+	}
+
+	fn get_debit_proportion(_: Balance) -> Ratio {
+		unimplemented!()
+	}
+
+	fn on_system_debit(_: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn on_system_surplus(_: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn issue_debit(_: &AccountId, _: Balance, _: bool) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn burn_debit(_: &AccountId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn deposit_surplus(from: &AccountId, surplus: Balance) -> DispatchResult {
+		Tokens::transfer(AUSD, from, &CDP_TREASURY, surplus)
+	}
+
+	fn withdraw_surplus(to: &AccountId, surplus: Balance) -> DispatchResult {
+		Tokens::transfer(AUSD, &CDP_TREASURY, to, surplus)
+	}
+
+	fn deposit_collateral(_: &AccountId, _: CurrencyId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn withdraw_collateral(_: &AccountId, _: CurrencyId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+}
+
+/// A constant product AMM without fee, keeping the liquidity in the `DEX_POOL`
+/// account.
+pub struct MockDEX;
+impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
+	fn get_liquidity_pool(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
+		(
+			Tokens::free_balance(currency_id_a, &DEX_POOL),
+			Tokens::free_balance(currency_id_b, &DEX_POOL),
+		)
+	}
+
+	fn get_liquidity_token_address(_: CurrencyId, _: CurrencyId) -> Option<H160> {
+		unimplemented!()
+	}
+
+	fn get_swap_target_amount(path: &[CurrencyId], supply_amount: Balance) -> Option<Balance> {
+		let (supply_pool, target_pool) = Self::get_liquidity_pool(path[0], path[1]);
+		target_pool
+			.checked_mul(supply_amount)?
+			.checked_div(supply_pool.checked_add(supply_amount)?)
+	}
+
+	fn get_swap_supply_amount(_: &[CurrencyId], _: Balance) -> Option<Balance> {
+		unimplemented!()
+	}
+
+	fn swap_with_exact_supply(
+		who: &AccountId,
+		path: &[CurrencyId],
+		supply_amount: Balance,
+		min_target_amount: Balance,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		let target_amount = Self::get_swap_target_amount(path, supply_amount).ok_or("invalid pool")?;
+		ensure!(target_amount >= min_target_amount, "slippage");
+		Tokens::transfer(path[0], who, &DEX_POOL, supply_amount)?;
+		Tokens::transfer(path[1], &DEX_POOL, who, target_amount)?;
+		Ok(target_amount)
+	}
+
+	fn swap_with_exact_target(
+		_: &AccountId,
+		_: &[CurrencyId],
+		_: Balance,
+		_: Balance,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		unimplemented!()
+	}
+
+	fn add_liquidity(
+		_: &AccountId,
+		_: CurrencyId,
+		_: CurrencyId,
+		_: Balance,
+		_: Balance,
+		_: Balance,
+		_: bool,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn remove_liquidity(
+		_: &AccountId,
+		_: CurrencyId,
+		_: CurrencyId,
+		_: Balance,
+		_: Balance,
+		_: Balance,
+		_: bool,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+}
+
+ord_parameter_types! {
+	pub const One: AccountId = ALICE;
+}
+
+parameter_types! {
+	pub const GetNativeCurrencyId: CurrencyId = ACA;
+	pub const GetStableCurrencyId: CurrencyId = AUSD;
+	pub const BuybackPeriod: BlockNumber = 10;
+	pub const IncentivesAccount: AccountId = INCENTIVES;
+	pub const BuybackPalletId: PalletId = PalletId(*b"aca/bybk");
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type Currency = Tokens;
+	type CDPTreasury = MockCDPTreasury;
+	type DEX = MockDEX;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type BuybackPeriod = BuybackPeriod;
+	type IncentivesAccount = IncentivesAccount;
+	type PalletId = BuybackPalletId;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Buyback: buyback::{Pallet, Call, Storage, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+	}
+);
+
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, CurrencyId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			balances: vec![
+				(CDP_TREASURY, AUSD, 1_000),
+				(DEX_POOL, AUSD, 10_000),
+				(DEX_POOL, ACA, 100_000),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
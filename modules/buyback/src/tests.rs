@@ -0,0 +1,170 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the buyback module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok, traits::OnInitialize};
+use mock::{Event, *};
+use sp_runtime::traits::BadOrigin;
+
+fn params(
+	max_supply_per_period: Balance,
+	max_price: Price,
+	max_slippage: Ratio,
+	target: BuybackTarget,
+) -> BuybackParams {
+	BuybackParams {
+		surplus_proportion: Permill::from_percent(50),
+		max_supply_per_period,
+		max_price,
+		max_slippage,
+		target,
+	}
+}
+
+#[test]
+fn set_buyback_params_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let buyback_params = params(
+			100,
+			Price::saturating_from_rational(1, 5),
+			Ratio::saturating_from_rational(5, 100),
+			BuybackTarget::Burn,
+		);
+
+		assert_noop!(
+			Buyback::set_buyback_params(Origin::signed(BOB), Some(buyback_params)),
+			BadOrigin
+		);
+		assert_noop!(
+			Buyback::set_buyback_params(
+				Origin::signed(ALICE),
+				Some(params(
+					100,
+					Price::one(),
+					Ratio::saturating_from_integer(2),
+					BuybackTarget::Burn
+				))
+			),
+			Error::<Runtime>::InvalidSlippage
+		);
+
+		assert_ok!(Buyback::set_buyback_params(Origin::signed(ALICE), Some(buyback_params)));
+		System::assert_last_event(Event::Buyback(crate::Event::BuybackParamsUpdated(Some(buyback_params))));
+		assert_eq!(Buyback::params(), Some(buyback_params));
+
+		assert_ok!(Buyback::set_buyback_params(Origin::signed(ALICE), None));
+		System::assert_last_event(Event::Buyback(crate::Event::BuybackParamsUpdated(None)));
+		assert_eq!(Buyback::params(), None);
+	});
+}
+
+#[test]
+fn buyback_and_burn_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Buyback::set_buyback_params(
+			Origin::signed(ALICE),
+			Some(params(
+				100,
+				Price::saturating_from_rational(1, 5),
+				Ratio::saturating_from_rational(5, 100),
+				BuybackTarget::Burn
+			))
+		));
+
+		// not the end of a period
+		Buyback::on_initialize(9);
+		assert_eq!(Tokens::free_balance(AUSD, &CDP_TREASURY), 1_000);
+
+		// pull 100 surplus, limited by the max supply per period, and buy 990 ACA
+		Buyback::on_initialize(10);
+		System::assert_last_event(Event::Buyback(crate::Event::Buyback(100, 990, BuybackTarget::Burn)));
+		assert_eq!(Tokens::free_balance(AUSD, &CDP_TREASURY), 900);
+		assert_eq!(Tokens::free_balance(AUSD, &DEX_POOL), 10_100);
+		assert_eq!(Tokens::free_balance(ACA, &Buyback::account_id()), 0);
+		assert_eq!(Tokens::total_issuance(ACA), 100_000 - 990);
+
+		// stable currency sent to the buyback account is spent first
+		assert_ok!(Tokens::deposit(AUSD, &Buyback::account_id(), 60));
+		Buyback::on_initialize(20);
+		assert_eq!(Tokens::free_balance(AUSD, &CDP_TREASURY), 860);
+		assert_eq!(Tokens::free_balance(AUSD, &Buyback::account_id()), 0);
+		assert_eq!(Tokens::free_balance(AUSD, &DEX_POOL), 10_200);
+	});
+}
+
+#[test]
+fn buyback_to_incentives_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Buyback::set_buyback_params(
+			Origin::signed(ALICE),
+			Some(params(
+				100,
+				Price::saturating_from_rational(1, 5),
+				Ratio::saturating_from_rational(5, 100),
+				BuybackTarget::Incentives
+			))
+		));
+
+		Buyback::on_initialize(10);
+		System::assert_last_event(Event::Buyback(crate::Event::Buyback(
+			100,
+			990,
+			BuybackTarget::Incentives,
+		)));
+		assert_eq!(Tokens::free_balance(ACA, &INCENTIVES), 990);
+		assert_eq!(Tokens::total_issuance(ACA), 100_000);
+	});
+}
+
+#[test]
+fn buyback_skipped_outside_bands() {
+	ExtBuilder::default().build().execute_with(|| {
+		// the price of ACA is 0.1 aUSD, above the max price
+		assert_ok!(Buyback::set_buyback_params(
+			Origin::signed(ALICE),
+			Some(params(
+				100,
+				Price::saturating_from_rational(1, 20),
+				Ratio::saturating_from_rational(5, 100),
+				BuybackTarget::Burn
+			))
+		));
+		Buyback::on_initialize(10);
+		assert_eq!(Tokens::free_balance(AUSD, &CDP_TREASURY), 1_000);
+		assert_eq!(Tokens::free_balance(AUSD, &DEX_POOL), 10_000);
+
+		// swapping 500 aUSD only gets 4761 ACA, more than 1% slippage from 5000 ACA
+		assert_ok!(Buyback::set_buyback_params(
+			Origin::signed(ALICE),
+			Some(params(
+				1_000,
+				Price::saturating_from_rational(1, 5),
+				Ratio::saturating_from_rational(1, 100),
+				BuybackTarget::Burn
+			))
+		));
+		Buyback::on_initialize(20);
+		assert_eq!(Tokens::free_balance(AUSD, &CDP_TREASURY), 1_000);
+		assert_eq!(Tokens::free_balance(AUSD, &Buyback::account_id()), 0);
+		assert_eq!(Tokens::free_balance(AUSD, &DEX_POOL), 10_000);
+	});
+}
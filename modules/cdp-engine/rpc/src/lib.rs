@@ -0,0 +1,140 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the cdp engine module.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use module_cdp_engine_rpc_runtime_api::{InterestInfo, PositionRisk};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+};
+use std::sync::Arc;
+
+pub use self::gen_client::Client as CDPEngineClient;
+pub use module_cdp_engine_rpc_runtime_api::CDPEngineApi as CDPEngineRuntimeApi;
+
+#[rpc]
+pub trait CDPEngineApi<BlockHash, AccountId, CurrencyId, PositionRiskType, InterestType> {
+	#[rpc(name = "honzon_getPositionRisk")]
+	fn get_position_risk(
+		&self,
+		account: AccountId,
+		currency_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> Result<PositionRiskType>;
+
+	#[rpc(name = "honzon_getInterestAccrued")]
+	fn get_interest_accrued(
+		&self,
+		account: AccountId,
+		currency_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> Result<InterestType>;
+}
+
+/// A struct that implements the [`CDPEngineApi`].
+pub struct CDPEngine<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> CDPEngine<C, B> {
+	/// Create new `CDPEngine` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		CDPEngine {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+	InvalidFeedPrice,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::InvalidFeedPrice => 2,
+		}
+	}
+}
+
+impl<C, Block, AccountId, CurrencyId, Balance>
+	CDPEngineApi<<Block as BlockT>::Hash, AccountId, CurrencyId, PositionRisk<Balance>, InterestInfo<Balance>>
+	for CDPEngine<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: CDPEngineRuntimeApi<Block, AccountId, CurrencyId, Balance>,
+	AccountId: Codec,
+	CurrencyId: Codec,
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+{
+	fn get_position_risk(
+		&self,
+		account: AccountId,
+		currency_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<PositionRisk<Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_position_risk(&at, account, currency_id)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to get position risk.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})?
+			.ok_or(RpcError {
+				code: ErrorCode::ServerError(Error::InvalidFeedPrice.into()),
+				message: "No feed price for the collateral.".into(),
+				data: None,
+			})
+	}
+
+	fn get_interest_accrued(
+		&self,
+		account: AccountId,
+		currency_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<InterestInfo<Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_interest_accrued(&at, account, currency_id)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to get interest accrued.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+}
@@ -28,13 +28,18 @@
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
-use frame_support::{log, pallet_prelude::*, traits::UnixTime, transactional};
+use frame_support::{
+	log,
+	pallet_prelude::*,
+	traits::{StorageVersion, UnixTime},
+	transactional,
+};
 use frame_system::{
 	offchain::{SendTransactionTypes, SubmitTransaction},
 	pallet_prelude::*,
 };
 use loans::Position;
-use orml_traits::Change;
+use orml_traits::{Change, GetByKey};
 use orml_utilities::OffchainErr;
 use primitives::{Amount, Balance, CurrencyId};
 use rand_chacha::{
@@ -55,10 +60,13 @@ use sp_runtime::{
 };
 use sp_std::prelude::*;
 use support::{
-	CDPTreasury, CDPTreasuryExtended, EmergencyShutdown, ExchangeRate, Price, PriceProvider, Rate, Ratio, RiskManager,
+	CDPTreasury, CDPTreasuryExtended, CollateralAuctionType, EmergencyShutdown, ExchangeRate, Price,
+	PriceCircuitBreaker, PriceProvider, Rate, Ratio, RiskManager, RiskParameterKey, RiskParameterProvider,
+	StabilityPool,
 };
 
 mod debit_exchange_rate_convertor;
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -70,6 +78,9 @@ pub use weights::WeightInfo;
 pub const OFFCHAIN_WORKER_DATA: &[u8] = b"acala/cdp-engine/data/";
 pub const OFFCHAIN_WORKER_LOCK: &[u8] = b"acala/cdp-engine/lock/";
 pub const OFFCHAIN_WORKER_MAX_ITERATIONS: &[u8] = b"acala/cdp-engine/max-iterations/";
+/// Set to `true` in the persistent local storage of a node, e.g. with the
+/// `offchain_localStorageSet` RPC, to turn off the offchain worker of the node.
+pub const OFFCHAIN_WORKER_DISABLED: &[u8] = b"acala/cdp-engine/disabled/";
 pub const LOCK_DURATION: u64 = 100;
 pub const DEFAULT_MAX_ITERATIONS: u32 = 1000;
 
@@ -100,6 +111,49 @@ pub struct RiskManagementParams {
 	/// of CDP so that the current collateral ratio is lower than the
 	/// required collateral ratio. `None` value means not set
 	pub required_collateral_ratio: Option<Ratio>,
+
+	/// The auction type to sell the collateral of unsafe CDPs under this
+	/// collateral type.
+	pub auction_type: CollateralAuctionType,
+}
+
+/// Tuning of the offchain worker checking the CDPs
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct OffchainWorkerParams {
+	/// Maximum number of CDPs checked by the offchain worker in a block, the
+	/// following CDPs are checked in the next blocks. It can be overridden
+	/// by the local storage of a node.
+	pub max_iterations: u32,
+
+	/// Expiration of the lock of the offchain worker in milliseconds, extended
+	/// after every checked CDP.
+	pub lock_expiration: u64,
+
+	/// Start iterating at a random collateral type when the offchain worker
+	/// has no progress record, e.g. after the node restarted, so that the
+	/// keepers don't check the same CDPs at the same time. Otherwise start at
+	/// the first collateral type.
+	pub shuffle_start: bool,
+}
+
+impl Default for OffchainWorkerParams {
+	fn default() -> Self {
+		Self {
+			max_iterations: DEFAULT_MAX_ITERATIONS,
+			lock_expiration: LOCK_DURATION,
+			shuffle_start: true,
+		}
+	}
+}
+
+/// Stability fee accrued by a position since it was opened
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, Default)]
+pub struct InterestAccrual {
+	/// Debit value accrued by interest until the last update
+	pub accrued: Balance,
+
+	/// The debit exchange rate at the last update
+	pub debit_exchange_rate: ExchangeRate,
 }
 
 // typedef to help polkadot.js disambiguate Change with different generic
@@ -115,6 +169,8 @@ pub enum LiquidationStrategy {
 	Auction,
 	/// Liquidation CDP's collateral by swap with DEX
 	Exchange,
+	/// Liquidation CDP's collateral by the stability pool
+	StabilityPool,
 }
 
 /// Status of CDP
@@ -165,12 +221,36 @@ pub mod module {
 		#[pallet::constant]
 		type MaxSwapSlippageCompareToOracle: Get<Ratio>;
 
+		/// The buffer above the liquidation ratio which partial liquidation
+		/// restores unsafe CDPs to, only the collateral and debit needed to
+		/// reach it are liquidated. `None` means unsafe CDPs are liquidated
+		/// in full.
+		#[pallet::constant]
+		type PartialLiquidationTarget: Get<Option<Ratio>>;
+
+		/// The share of the liquidation penalty rewarded to the keeper who
+		/// submits a signed `liquidate` for an unsafe CDP.
+		#[pallet::constant]
+		type KeeperIncentiveRate: Get<Rate>;
+
 		/// The CDP treasury to maintain bad debts and surplus generated by CDPs
 		type CDPTreasury: CDPTreasuryExtended<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
 
+		/// The stability pool absorbing the collateral of liquidated CDPs
+		/// before falling back to collateral auctions.
+		type StabilityPool: StabilityPool<CurrencyId, Balance>;
+
+		/// The risk parameters set by governance with enactment delay, which
+		/// override the risk management params of collaterals.
+		type RiskParameters: RiskParameterProvider;
+
 		/// The price source of all types of currencies related to CDP
 		type PriceSource: PriceProvider<CurrencyId>;
 
+		/// The circuit breaker of prices, the liquidation of collateral is
+		/// paused while its price is frozen.
+		type PriceCircuitBreaker: PriceCircuitBreaker<CurrencyId>;
+
 		/// A configuration for base priority of unsigned transactions.
 		///
 		/// This is exposed so that it can be tuned for particular runtime, when
@@ -224,11 +304,24 @@ pub mod module {
 		MustAfterShutdown,
 		/// Failed to swap debit by default path list
 		SwapDebitFailed,
+		/// The debit value of the CDP exceed the per-account hard cap of
+		/// specific collateral type
+		ExceedDebitValuePerAccountCap,
+		/// The price of collateral is frozen by the circuit breaker
+		PriceFrozen,
+		/// The max iterations or the lock expiration of the offchain worker
+		/// is zero
+		InvalidOffchainWorkerParams,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
-	#[pallet::metadata(T::AccountId = "AccountId", Option<Rate> = "OptionRate", Option<Ratio> = "OptionRatio")]
+	#[pallet::metadata(
+		T::AccountId = "AccountId",
+		Option<Rate> = "OptionRate",
+		Option<Ratio> = "OptionRatio",
+		Option<Balance> = "OptionBalance"
+	)]
 	pub enum Event<T: Config> {
 		/// Liquidate the unsafe CDP. \[collateral_type, owner,
 		/// collateral_amount, bad_debt_value, liquidation_strategy\]
@@ -257,6 +350,17 @@ pub mod module {
 		/// The global interest rate per sec for all types of collateral
 		/// updated. \[new_global_interest_rate_per_sec\]
 		GlobalInterestRatePerSecUpdated(Rate),
+		/// The auction type for specific collateral type updated.
+		/// \[collateral_type, new_auction_type\]
+		CollateralAuctionTypeUpdated(CurrencyId, CollateralAuctionType),
+		/// The hard cap of debit value per account for specific collateral
+		/// type updated. \[collateral_type, new_maximum_debit_value\]
+		MaximumDebitValuePerAccountUpdated(CurrencyId, Option<Balance>),
+		/// The keeper who liquidated the unsafe CDP is rewarded.
+		/// \[collateral_type, owner, keeper, reward_amount\]
+		KeeperRewarded(CurrencyId, T::AccountId, T::AccountId, Balance),
+		/// The tuning of the offchain worker updated. \[new_params\]
+		OffchainWorkerParamsUpdated(OffchainWorkerParams),
 	}
 
 	/// Mapping from collateral type to its exchange rate of debit units and
@@ -281,6 +385,23 @@ pub mod module {
 	#[pallet::getter(fn collateral_params)]
 	pub type CollateralParams<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, RiskManagementParams, ValueQuery>;
 
+	/// Mapping from collateral type to the hard cap of debit value of a
+	/// single CDP, `None` means no cap
+	///
+	/// MaximumDebitValuePerAccount: CurrencyId => Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn maximum_debit_value_per_account)]
+	pub type MaximumDebitValuePerAccount<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Balance, OptionQuery>;
+
+	/// The stability fee accrued by positions, updated whenever their debit
+	/// changes
+	///
+	/// PositionInterest: double_map CurrencyId, AccountId => Option<InterestAccrual>
+	#[pallet::storage]
+	#[pallet::getter(fn position_interest)]
+	pub type PositionInterest<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CurrencyId, Twox64Concat, T::AccountId, InterestAccrual, OptionQuery>;
+
 	/// Timestamp in seconds of the last interest accumulation
 	///
 	/// LastAccumulationSecs: u64
@@ -288,6 +409,13 @@ pub mod module {
 	#[pallet::getter(fn last_accumulation_secs)]
 	pub type LastAccumulationSecs<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+	/// The tuning of the offchain worker checking the CDPs
+	///
+	/// OffchainWorkerConfig: OffchainWorkerParams
+	#[pallet::storage]
+	#[pallet::getter(fn offchain_worker_config)]
+	pub type OffchainWorkerConfig<T: Config> = StorageValue<_, OffchainWorkerParams, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig {
 		#[allow(clippy::type_complexity)]
@@ -332,6 +460,7 @@ pub mod module {
 							liquidation_ratio: *liquidation_ratio,
 							liquidation_penalty: *liquidation_penalty,
 							required_collateral_ratio: *required_collateral_ratio,
+							auction_type: Default::default(),
 						},
 					);
 				},
@@ -340,7 +469,11 @@ pub mod module {
 		}
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -392,7 +525,10 @@ pub mod module {
 	impl<T: Config> Pallet<T> {
 		/// Liquidate unsafe CDP
 		///
-		/// The dispatch origin of this call must be _None_.
+		/// The dispatch origin of this call must be _None_ or _Signed_. The
+		/// signed origin is rewarded with `KeeperIncentiveRate` of the
+		/// liquidation penalty, if it's collected by the swap or the stability
+		/// pool.
 		///
 		/// - `currency_id`: CDP's collateral type.
 		/// - `who`: CDP's owner.
@@ -403,10 +539,14 @@ pub mod module {
 			currency_id: CurrencyId,
 			who: <T::Lookup as StaticLookup>::Source,
 		) -> DispatchResult {
-			ensure_none(origin)?;
+			let keeper = match origin.into() {
+				Ok(frame_system::RawOrigin::Signed(keeper)) => Some(keeper),
+				Ok(frame_system::RawOrigin::None) => None,
+				_ => return Err(DispatchError::BadOrigin),
+			};
 			let who = T::Lookup::lookup(who)?;
 			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
-			Self::liquidate_unsafe_cdp(who, currency_id)?;
+			Self::do_liquidate_unsafe_cdp(who, currency_id, keeper)?;
 			Ok(())
 		}
 
@@ -500,6 +640,81 @@ pub mod module {
 			CollateralParams::<T>::insert(currency_id, collateral_params);
 			Ok(())
 		}
+
+		/// Update the auction type used to sell the collateral of unsafe CDPs
+		/// under specific collateral type
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `auction_type`: English or Dutch auction.
+		#[pallet::weight((<T as Config>::WeightInfo::set_collateral_params(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_collateral_auction_type(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			auction_type: CollateralAuctionType,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				T::CollateralCurrencyIds::get().contains(&currency_id),
+				Error::<T>::InvalidCollateralType,
+			);
+
+			CollateralParams::<T>::mutate(currency_id, |collateral_params| {
+				collateral_params.auction_type = auction_type;
+			});
+			Self::deposit_event(Event::CollateralAuctionTypeUpdated(currency_id, auction_type));
+			Ok(())
+		}
+
+		/// Update the hard cap of debit value of a single CDP under specific
+		/// collateral type
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `maximum_debit_value`: maximum debit value per account, `None` means no cap.
+		#[pallet::weight((<T as Config>::WeightInfo::set_maximum_debit_value_per_account(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_maximum_debit_value_per_account(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			maximum_debit_value: Option<Balance>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				T::CollateralCurrencyIds::get().contains(&currency_id),
+				Error::<T>::InvalidCollateralType,
+			);
+
+			MaximumDebitValuePerAccount::<T>::mutate_exists(currency_id, |maybe_cap| *maybe_cap = maximum_debit_value);
+			Self::deposit_event(Event::MaximumDebitValuePerAccountUpdated(
+				currency_id,
+				maximum_debit_value,
+			));
+			Ok(())
+		}
+
+		/// Update the tuning of the offchain worker checking the CDPs
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `params`: the max iterations per block, the lock expiration and
+		///   whether to start at a random collateral type.
+		#[pallet::weight((<T as Config>::WeightInfo::set_offchain_worker_params(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_offchain_worker_params(origin: OriginFor<T>, params: OffchainWorkerParams) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				!params.max_iterations.is_zero() && !params.lock_expiration.is_zero(),
+				Error::<T>::InvalidOffchainWorkerParams,
+			);
+
+			OffchainWorkerConfig::<T>::put(params.clone());
+			Self::deposit_event(Event::OffchainWorkerParamsUpdated(params));
+			Ok(())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -515,6 +730,7 @@ pub mod module {
 						Self::check_cdp_status(*currency_id, collateral, debit),
 						CDPStatus::Unsafe
 					) || T::EmergencyShutdown::is_shutdown()
+						|| T::PriceCircuitBreaker::is_frozen(*currency_id)
 					{
 						return InvalidTransaction::Stale.into();
 					}
@@ -626,8 +842,15 @@ impl<T: Config> Pallet<T> {
 			return Err(OffchainErr::NotValidator);
 		}
 
+		// check if the offchain worker is turned off on this node by the local storage
+		if let Ok(Some(true)) = StorageValueRef::persistent(OFFCHAIN_WORKER_DISABLED).get::<bool>() {
+			return Ok(());
+		}
+
+		let params = Self::offchain_worker_config();
+
 		// acquire offchain worker lock
-		let lock_expiration = Duration::from_millis(LOCK_DURATION);
+		let lock_expiration = Duration::from_millis(params.lock_expiration);
 		let mut lock = StorageLock::<'_, Time>::with_deadline(OFFCHAIN_WORKER_LOCK, lock_expiration);
 		let mut guard = lock.try_lock().map_err(|_| OffchainErr::OffchainLock)?;
 
@@ -640,19 +863,22 @@ impl<T: Config> Pallet<T> {
 				to_be_continue.get::<(u32, Option<Vec<u8>>)>()
 			{
 				(last_collateral_position, maybe_last_iterator_previous_key)
-			} else {
+			} else if params.shuffle_start {
 				let mut rng = ChaChaRng::from_seed(sp_io::offchain::random_seed());
 				(pick_u32(&mut rng, collateral_currency_ids.len() as u32), None)
+			} else {
+				(0, None)
 			};
 
-		// get the max iterationns config
+		// get the max iterationns config, the local storage overrides the on-chain params
 		let max_iterations = StorageValueRef::persistent(OFFCHAIN_WORKER_MAX_ITERATIONS)
 			.get::<u32>()
-			.unwrap_or(Some(DEFAULT_MAX_ITERATIONS))
-			.unwrap_or(DEFAULT_MAX_ITERATIONS);
+			.unwrap_or(None)
+			.unwrap_or(params.max_iterations);
 
 		let currency_id = collateral_currency_ids[collateral_position as usize];
 		let is_shutdown = T::EmergencyShutdown::is_shutdown();
+		let is_price_frozen = T::PriceCircuitBreaker::is_frozen(currency_id);
 
 		// If start key is Some(value) continue iterating from that point in storage otherwise start
 		// iterating from the beginning of <loans::Positons<T>>
@@ -668,6 +894,7 @@ impl<T: Config> Pallet<T> {
 		#[allow(clippy::while_let_on_iterator)]
 		while let Some((who, Position { collateral, debit })) = map_iterator.next() {
 			if !is_shutdown
+				&& !is_price_frozen
 				&& matches!(
 					Self::check_cdp_status(currency_id, collateral, debit),
 					CDPStatus::Unsafe
@@ -721,8 +948,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub fn check_cdp_status(currency_id: CurrencyId, collateral_amount: Balance, debit_amount: Balance) -> CDPStatus {
-		let stable_currency_id = T::GetStableCurrencyId::get();
-		if let Some(feed_price) = T::PriceSource::get_relative_price(currency_id, stable_currency_id) {
+		if let Some(feed_price) = Self::get_collateral_price(currency_id) {
 			let collateral_ratio =
 				Self::calculate_collateral_ratio(currency_id, collateral_amount, debit_amount, feed_price);
 			if collateral_ratio < Self::get_liquidation_ratio(currency_id) {
@@ -735,17 +961,28 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The feed price of the collateral, relative to the stable currency.
+	pub fn get_collateral_price(currency_id: CurrencyId) -> Option<Price> {
+		T::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get())
+	}
+
 	pub fn maximum_total_debit_value(currency_id: CurrencyId) -> Balance {
-		Self::collateral_params(currency_id).maximum_total_debit_value
+		T::RiskParameters::get_balance(RiskParameterKey::MaximumTotalDebitValue(currency_id))
+			.unwrap_or_else(|| Self::collateral_params(currency_id).maximum_total_debit_value)
 	}
 
 	pub fn required_collateral_ratio(currency_id: CurrencyId) -> Option<Ratio> {
-		Self::collateral_params(currency_id).required_collateral_ratio
+		T::RiskParameters::get_ratio(RiskParameterKey::RequiredCollateralRatio(currency_id))
+			.or_else(|| Self::collateral_params(currency_id).required_collateral_ratio)
+	}
+
+	pub fn get_auction_type(currency_id: CurrencyId) -> CollateralAuctionType {
+		Self::collateral_params(currency_id).auction_type
 	}
 
 	pub fn get_interest_rate_per_sec(currency_id: CurrencyId) -> Rate {
-		Self::collateral_params(currency_id)
-			.interest_rate_per_sec
+		T::RiskParameters::get_rate(RiskParameterKey::InterestRatePerSec(currency_id))
+			.or_else(|| Self::collateral_params(currency_id).interest_rate_per_sec)
 			.unwrap_or_default()
 			.saturating_add(Self::global_interest_rate_per_sec())
 	}
@@ -758,14 +995,14 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub fn get_liquidation_ratio(currency_id: CurrencyId) -> Ratio {
-		Self::collateral_params(currency_id)
-			.liquidation_ratio
+		T::RiskParameters::get_ratio(RiskParameterKey::LiquidationRatio(currency_id))
+			.or_else(|| Self::collateral_params(currency_id).liquidation_ratio)
 			.unwrap_or_else(T::DefaultLiquidationRatio::get)
 	}
 
 	pub fn get_liquidation_penalty(currency_id: CurrencyId) -> Rate {
-		Self::collateral_params(currency_id)
-			.liquidation_penalty
+		T::RiskParameters::get_rate(RiskParameterKey::LiquidationPenalty(currency_id))
+			.or_else(|| Self::collateral_params(currency_id).liquidation_penalty)
 			.unwrap_or_else(T::DefaultLiquidationPenalty::get)
 	}
 
@@ -789,6 +1026,46 @@ impl<T: Config> Pallet<T> {
 		Ratio::checked_from_rational(locked_collateral_value, debit_value).unwrap_or_else(Ratio::max_value)
 	}
 
+	/// The collateral price at which the position reaches the liquidation
+	/// ratio, `None` if there is no collateral.
+	pub fn calculate_liquidation_price(
+		currency_id: CurrencyId,
+		collateral_balance: Balance,
+		debit_balance: Balance,
+	) -> Option<Price> {
+		let debit_value = Self::get_debit_value(currency_id, debit_balance);
+		let liquidation_value = Self::get_liquidation_ratio(currency_id).saturating_mul_int(debit_value);
+
+		Price::checked_from_rational(liquidation_value, collateral_balance)
+	}
+
+	/// The collateral to add to the position to reach the required collateral
+	/// ratio, or the liquidation ratio if it's not set.
+	pub fn calculate_required_collateral(
+		currency_id: CurrencyId,
+		collateral_balance: Balance,
+		debit_balance: Balance,
+		price: Price,
+	) -> Balance {
+		let debit_value = Self::get_debit_value(currency_id, debit_balance);
+		let safe_ratio =
+			Self::required_collateral_ratio(currency_id).unwrap_or_else(|| Self::get_liquidation_ratio(currency_id));
+		let safe_collateral_value = safe_ratio.saturating_mul_int(debit_value);
+
+		price
+			.reciprocal()
+			.unwrap_or_else(Price::max_value)
+			.saturating_mul_int(safe_collateral_value)
+			.saturating_sub(collateral_balance)
+	}
+
+	/// The debit value accrued by interest, i.e. above the value at the
+	/// default debit exchange rate.
+	pub fn get_accumulated_interest(currency_id: CurrencyId, debit_balance: Balance) -> Balance {
+		Self::get_debit_value(currency_id, debit_balance)
+			.saturating_sub(T::DefaultDebitExchangeRate::get().saturating_mul_int(debit_balance))
+	}
+
 	pub fn adjust_position(
 		who: &T::AccountId,
 		currency_id: CurrencyId,
@@ -799,10 +1076,89 @@ impl<T: Config> Pallet<T> {
 			T::CollateralCurrencyIds::get().contains(&currency_id),
 			Error::<T>::InvalidCollateralType,
 		);
+		Self::accrue_position_interest(who, currency_id);
 		<LoansOf<T>>::adjust_position(who, currency_id, collateral_adjustment, debit_adjustment)?;
+		if debit_adjustment.is_positive() {
+			Self::check_debit_cap_per_account(currency_id, <LoansOf<T>>::positions(currency_id, who).debit)?;
+		}
 		Ok(())
 	}
 
+	/// Transfer the whole loan of `from` to `to`, the interest accrued by the
+	/// loan stays with `from`.
+	pub fn transfer_loan(from: &T::AccountId, to: &T::AccountId, currency_id: CurrencyId) -> DispatchResult {
+		Self::accrue_position_interest(from, currency_id);
+		Self::accrue_position_interest(to, currency_id);
+		<LoansOf<T>>::transfer_loan(from, to, currency_id)?;
+
+		// the interest accrued by the position moves along with it
+		if let Some(InterestAccrual { accrued, .. }) = PositionInterest::<T>::take(currency_id, from) {
+			PositionInterest::<T>::mutate(currency_id, to, |maybe_accrual| {
+				if let Some(accrual) = maybe_accrual {
+					accrual.accrued = accrual.accrued.saturating_add(accrued);
+				}
+			});
+		}
+
+		Self::check_debit_cap_per_account(currency_id, <LoansOf<T>>::positions(currency_id, to).debit)
+	}
+
+	/// Check the debit value of a single CDP against the per-account hard cap
+	/// of `currency_id`.
+	pub fn check_debit_cap_per_account(currency_id: CurrencyId, debit_balance: Balance) -> DispatchResult {
+		if let Some(hard_cap) = Self::maximum_debit_value_per_account(currency_id) {
+			ensure!(
+				Self::get_debit_value(currency_id, debit_balance) <= hard_cap,
+				Error::<T>::ExceedDebitValuePerAccountCap
+			);
+		}
+		Ok(())
+	}
+
+	/// Stability fee accrued by the position of `who` since it was opened,
+	/// including the interest already repaid.
+	///
+	/// Positions opened before the accrual was recorded are accounted from
+	/// the default debit exchange rate.
+	pub fn get_interest_accrued(who: &T::AccountId, currency_id: CurrencyId) -> Balance {
+		let InterestAccrual {
+			accrued,
+			debit_exchange_rate,
+		} = Self::position_interest(currency_id, who).unwrap_or_else(|| InterestAccrual {
+			accrued: Zero::zero(),
+			debit_exchange_rate: T::DefaultDebitExchangeRate::get(),
+		});
+		let debit = <LoansOf<T>>::positions(currency_id, who).debit;
+
+		Self::get_debit_exchange_rate(currency_id)
+			.saturating_sub(debit_exchange_rate)
+			.saturating_mul_int(debit)
+			.saturating_add(accrued)
+	}
+
+	/// Record the interest accrued by the position of `who` at the current
+	/// debit exchange rate, must be called before its debit changes.
+	fn accrue_position_interest(who: &T::AccountId, currency_id: CurrencyId) {
+		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, who);
+		let debit_exchange_rate = Self::get_debit_exchange_rate(currency_id);
+
+		// a new position starts with no accrued interest
+		let accrued = if collateral.is_zero() && debit.is_zero() {
+			Zero::zero()
+		} else {
+			Self::get_interest_accrued(who, currency_id)
+		};
+
+		PositionInterest::<T>::insert(
+			currency_id,
+			who,
+			InterestAccrual {
+				accrued,
+				debit_exchange_rate,
+			},
+		);
+	}
+
 	// settle cdp has debit when emergency shutdown
 	pub fn settle_cdp_has_debit(who: T::AccountId, currency_id: CurrencyId) -> DispatchResult {
 		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
@@ -817,6 +1173,7 @@ impl<T: Config> Pallet<T> {
 			sp_std::cmp::min(settle_price.saturating_mul_int(bad_debt_value), collateral);
 
 		// confiscate collateral and all debit
+		Self::accrue_position_interest(&who, currency_id);
 		<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, confiscate_collateral_amount, debit)?;
 
 		Self::deposit_event(Event::SettleCDPInDebit(currency_id, who));
@@ -839,6 +1196,7 @@ impl<T: Config> Pallet<T> {
 		);
 
 		// confiscate all collateral and debit of unsafe cdp to cdp treasury
+		Self::accrue_position_interest(&who, currency_id);
 		<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, collateral, debit)?;
 
 		// swap exact stable with DEX in limit of price impact
@@ -900,10 +1258,85 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// The collateral and debit to liquidate for the unsafe position to
+	/// reach the partial liquidation target, the whole position if it cannot
+	/// be restored or partial liquidation is disabled.
+	pub fn calculate_liquidation_amounts(
+		currency_id: CurrencyId,
+		collateral_balance: Balance,
+		debit_balance: Balance,
+	) -> (Balance, Balance) {
+		let full_liquidation = (collateral_balance, debit_balance);
+		let buffer = match T::PartialLiquidationTarget::get() {
+			Some(buffer) => buffer,
+			None => return full_liquidation,
+		};
+		let price = match Self::get_collateral_price(currency_id) {
+			Some(price) => price,
+			None => return full_liquidation,
+		};
+
+		let target_ratio = Self::get_liquidation_ratio(currency_id).saturating_add(buffer);
+		let penalty_ratio = Self::get_liquidation_penalty(currency_id).saturating_add(Rate::one());
+		// selling collateral worth `penalty_ratio` times the liquidated debit value
+		// only raises the collateral ratio if the target is above the penalty ratio
+		if target_ratio <= penalty_ratio {
+			return full_liquidation;
+		}
+
+		// the debit value `x` to liquidate solves
+		// (collateral_value - penalty_ratio * x) / (debit_value - x) = target_ratio
+		let debit_value = Self::get_debit_value(currency_id, debit_balance);
+		let collateral_value = price.saturating_mul_int(collateral_balance);
+		let liquidated_debit_value = target_ratio
+			.saturating_sub(penalty_ratio)
+			.reciprocal()
+			.unwrap_or_else(Ratio::max_value)
+			.saturating_mul_int(
+				target_ratio
+					.saturating_mul_int(debit_value)
+					.saturating_sub(collateral_value),
+			);
+		let remain_debit_value = debit_value.saturating_sub(liquidated_debit_value);
+		if remain_debit_value < T::MinimumDebitValue::get() {
+			return full_liquidation;
+		}
+
+		let liquidated_debit = Ratio::checked_from_rational(liquidated_debit_value, debit_value)
+			.unwrap_or_else(Ratio::one)
+			.saturating_mul_int(debit_balance);
+		let liquidated_collateral = price
+			.reciprocal()
+			.unwrap_or_else(Price::max_value)
+			.saturating_mul_int(penalty_ratio.saturating_mul_int(liquidated_debit_value));
+		if liquidated_debit.is_zero()
+			|| liquidated_debit >= debit_balance
+			|| liquidated_collateral >= collateral_balance
+		{
+			return full_liquidation;
+		}
+
+		(liquidated_collateral, liquidated_debit)
+	}
+
 	// liquidate unsafe cdp
 	pub fn liquidate_unsafe_cdp(who: T::AccountId, currency_id: CurrencyId) -> DispatchResult {
+		Self::do_liquidate_unsafe_cdp(who, currency_id, None)
+	}
+
+	/// Liquidate the unsafe CDP, `keeper` is rewarded with
+	/// `KeeperIncentiveRate` of the liquidation penalty collected by the swap
+	/// or the stability pool.
+	fn do_liquidate_unsafe_cdp(
+		who: T::AccountId,
+		currency_id: CurrencyId,
+		keeper: Option<T::AccountId>,
+	) -> DispatchResult {
 		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
 
+		// pause liquidation while the price is frozen
+		ensure!(!T::PriceCircuitBreaker::is_frozen(currency_id), Error::<T>::PriceFrozen);
+
 		// ensure the cdp is unsafe
 		ensure!(
 			matches!(
@@ -913,7 +1346,9 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::MustBeUnsafe
 		);
 
-		// confiscate all collateral and debit of unsafe cdp to cdp treasury
+		// confiscate the collateral and debit to liquidate to cdp treasury
+		let (collateral, debit) = Self::calculate_liquidation_amounts(currency_id, collateral, debit);
+		Self::accrue_position_interest(&who, currency_id);
 		<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, collateral, debit)?;
 
 		let bad_debt_value = Self::get_debit_value(currency_id, debit);
@@ -961,7 +1396,19 @@ impl<T: Config> Pallet<T> {
 				}
 			}
 
-			// if cannot liquidate by swap, create collateral auctions by cdp treasury
+			// if cannot liquidate by swap, try to be absorbed by the stability pool
+			if let Ok(absorbed_collateral) = T::StabilityPool::absorb(currency_id, collateral, target_stable_amount) {
+				// refund remain collateral to CDP owner
+				let refund_collateral_amount = collateral
+					.checked_sub(absorbed_collateral)
+					.expect("stability pool absorbs at most the liquidated collateral; qed");
+
+				<T as Config>::CDPTreasury::withdraw_collateral(&who, currency_id, refund_collateral_amount)?;
+
+				return Ok(LiquidationStrategy::StabilityPool);
+			}
+
+			// if the stability pool is depleted, create collateral auctions by cdp treasury
 			<T as Config>::CDPTreasury::create_collateral_auctions(
 				currency_id,
 				collateral,
@@ -973,6 +1420,23 @@ impl<T: Config> Pallet<T> {
 			Ok(LiquidationStrategy::Auction)
 		})()?;
 
+		// reward the keeper with a share of the penalty, only if the penalty is already
+		// collected to the surplus pool by the swap or the stability pool. The penalty of
+		// collateral auctions is not collected until they are settled.
+		if let Some(keeper) = keeper {
+			if matches!(
+				liquidation_strategy,
+				LiquidationStrategy::Exchange | LiquidationStrategy::StabilityPool
+			) {
+				let reward = T::KeeperIncentiveRate::get()
+					.saturating_mul_int(target_stable_amount.saturating_sub(bad_debt_value));
+				if !reward.is_zero() {
+					<T as Config>::CDPTreasury::withdraw_surplus(&keeper, reward)?;
+					Self::deposit_event(Event::KeeperRewarded(currency_id, who.clone(), keeper, reward));
+				}
+			}
+		}
+
 		Self::deposit_event(Event::LiquidateUnsafeCDP(
 			currency_id,
 			who,
@@ -1042,3 +1506,12 @@ impl<T: Config> RiskManager<T::AccountId, CurrencyId, Balance, Balance> for Pall
 fn pick_u32<R: RngCore>(rng: &mut R, max: u32) -> u32 {
 	rng.next_u32() % max
 }
+
+/// The auction type of collateral types, configured in `CollateralParams`.
+pub struct CollateralAuctionTypeOf<T>(PhantomData<T>);
+
+impl<T: Config> GetByKey<CurrencyId, CollateralAuctionType> for CollateralAuctionTypeOf<T> {
+	fn get(currency_id: &CurrencyId) -> CollateralAuctionType {
+		Pallet::<T>::get_auction_type(*currency_id)
+	}
+}
@@ -0,0 +1,89 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CollateralParams, Config, Pallet, RiskManagementParams, Weight};
+use codec::{Decode, Encode};
+use frame_support::{
+	log,
+	traits::{Get, StorageVersion},
+};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::RuntimeDebug;
+use support::{Rate, Ratio};
+
+pub mod v1 {
+	use super::*;
+
+	/// `RiskManagementParams` before the auction type was added.
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, Default)]
+	pub struct OldRiskManagementParams {
+		pub maximum_total_debit_value: Balance,
+		pub interest_rate_per_sec: Option<Rate>,
+		pub liquidation_ratio: Option<Ratio>,
+		pub liquidation_penalty: Option<Rate>,
+		pub required_collateral_ratio: Option<Ratio>,
+	}
+
+	/// Migrate the collateral params once, if the on-chain storage version
+	/// is still 0.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() != 0 {
+			log::info!(target: "cdp-engine", "cdp-engine v1 migration is already applied, skip");
+			return T::DbWeight::get().reads(1);
+		}
+		log::info!(target: "cdp-engine", "Migrating cdp-engine v1");
+
+		let mut count: Weight = 0;
+		CollateralParams::<T>::translate::<OldRiskManagementParams, _>(|_: CurrencyId, old| {
+			count += 1;
+			Some(RiskManagementParams {
+				maximum_total_debit_value: old.maximum_total_debit_value,
+				interest_rate_per_sec: old.interest_rate_per_sec,
+				liquidation_ratio: old.liquidation_ratio,
+				liquidation_penalty: old.liquidation_penalty,
+				required_collateral_ratio: old.required_collateral_ratio,
+				auction_type: Default::default(),
+			})
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+		log::info!(target: "cdp-engine", "Completed cdp-engine migration to v1");
+
+		T::DbWeight::get().reads_writes(count + 1, count + 1)
+	}
+
+	/// The number of collateral params to migrate.
+	#[cfg(feature = "try-runtime")]
+	pub fn pre_migrate<T: Config>() -> Result<u32, &'static str> {
+		Ok(CollateralParams::<T>::iter_keys().count() as u32)
+	}
+
+	/// Ensure all the `count` collateral params decode as the new
+	/// `RiskManagementParams` and the storage version is bumped.
+	#[cfg(feature = "try-runtime")]
+	pub fn post_migrate<T: Config>(count: u32) -> Result<(), &'static str> {
+		frame_support::ensure!(
+			StorageVersion::get::<Pallet<T>>() == 1,
+			"cdp-engine v1: storage version is not bumped"
+		);
+		frame_support::ensure!(
+			CollateralParams::<T>::iter_values().count() as u32 == count,
+			"cdp-engine v1: not all collateral params are migrated"
+		);
+		Ok(())
+	}
+}
@@ -31,7 +31,7 @@ use sp_runtime::{
 	traits::{AccountIdConversion, IdentityLookup, One as OneT},
 };
 use sp_std::cell::RefCell;
-use support::{AuctionManager, EmergencyShutdown};
+use support::{AuctionManager, CDPTreasury, EmergencyShutdown, RiskParameterValue};
 
 pub type AccountId = u128;
 pub type BlockNumber = u64;
@@ -204,12 +204,14 @@ impl cdp_treasury::Config for Runtime {
 	type Event = Event;
 	type Currency = Currencies;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type AuctionManagerHandler = MockAuctionManager;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type DEX = DEXModule;
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type IdleScheduler = ();
 	type WeightInfo = ();
 }
 
@@ -231,6 +233,9 @@ impl dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type RoutingCurrencies = ();
+	type TWAPObservationPeriod = ();
+	type MaxTWAPObservations = ();
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = ();
 	type DEXIncentives = ();
@@ -250,6 +255,7 @@ impl pallet_timestamp::Config for Runtime {
 
 thread_local! {
 	static IS_SHUTDOWN: RefCell<bool> = RefCell::new(false);
+	static IS_PRICE_FROZEN: RefCell<bool> = RefCell::new(false);
 }
 
 pub fn mock_shutdown() {
@@ -263,6 +269,50 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	}
 }
 
+pub fn mock_price_frozen(frozen: bool) {
+	IS_PRICE_FROZEN.with(|v| *v.borrow_mut() = frozen)
+}
+
+pub struct MockPriceCircuitBreaker;
+impl PriceCircuitBreaker<CurrencyId> for MockPriceCircuitBreaker {
+	fn is_frozen(_currency_id: CurrencyId) -> bool {
+		IS_PRICE_FROZEN.with(|v| *v.borrow_mut())
+	}
+}
+
+parameter_types! {
+	pub static StabilityPoolAbsorbedCollateral: Option<Balance> = None;
+}
+
+/// Absorbs `StabilityPoolAbsorbedCollateral` with the stable currency of
+/// CAROL, or fails if it's `None` or more than the liquidated collateral.
+pub struct MockStabilityPool;
+impl StabilityPool<CurrencyId, Balance> for MockStabilityPool {
+	fn absorb(currency_id: CurrencyId, collateral: Balance, target: Balance) -> Result<Balance, DispatchError> {
+		let absorbed = StabilityPoolAbsorbedCollateral::get()
+			.filter(|absorbed| *absorbed <= collateral)
+			.ok_or(DispatchError::Other("depleted"))?;
+		CDPTreasuryModule::deposit_surplus(&CAROL, target)?;
+		CDPTreasuryModule::withdraw_collateral(&CAROL, currency_id, absorbed)?;
+		Ok(absorbed)
+	}
+}
+
+parameter_types! {
+	pub static RiskParameterOverrides: Vec<(RiskParameterKey, RiskParameterValue)> = vec![];
+}
+
+/// Provides the risk parameters in `RiskParameterOverrides`.
+pub struct MockRiskParameters;
+impl RiskParameterProvider for MockRiskParameters {
+	fn get(key: RiskParameterKey) -> Option<RiskParameterValue> {
+		RiskParameterOverrides::get()
+			.into_iter()
+			.find(|(k, _)| *k == key)
+			.map(|(_, value)| value)
+	}
+}
+
 ord_parameter_types! {
 	pub const One: AccountId = 1;
 }
@@ -273,6 +323,8 @@ parameter_types! {
 	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(10, 100);
 	pub const MinimumDebitValue: Balance = 2;
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(50, 100);
+	pub static PartialLiquidationTarget: Option<Ratio> = None;
+	pub KeeperIncentiveRate: Rate = Rate::saturating_from_rational(1, 2);
 	pub const UnsignedPriority: u64 = 1 << 20;
 	pub CollateralCurrencyIds: Vec<CurrencyId> = vec![BTC, DOT];
 	pub DefaultSwapParitalPathList: Vec<Vec<CurrencyId>> = vec![
@@ -284,6 +336,7 @@ parameter_types! {
 impl Config for Runtime {
 	type Event = Event;
 	type PriceSource = MockPriceSource;
+	type PriceCircuitBreaker = MockPriceCircuitBreaker;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -291,8 +344,12 @@ impl Config for Runtime {
 	type MinimumDebitValue = MinimumDebitValue;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CDPTreasuryModule;
+	type StabilityPool = MockStabilityPool;
+	type RiskParameters = MockRiskParameters;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
+	type PartialLiquidationTarget = PartialLiquidationTarget;
+	type KeeperIncentiveRate = KeeperIncentiveRate;
 	type UnsignedPriority = UnsignedPriority;
 	type EmergencyShutdown = MockEmergencyShutdown;
 	type UnixTime = Timestamp;
@@ -30,7 +30,7 @@ use sp_runtime::{
 	offchain::{DbExternalities, StorageKind},
 	traits::BadOrigin,
 };
-use support::DEXManager;
+use support::{DEXManager, RiskParameterValue};
 
 pub const INIT_TIMESTAMP: u64 = 30_000;
 pub const BLOCK_TIME: u64 = 1000;
@@ -140,6 +140,69 @@ fn get_liquidation_ratio_work() {
 	});
 }
 
+#[test]
+fn risk_parameters_override_collateral_params() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(5, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+
+		RiskParameterOverrides::set(vec![
+			(
+				RiskParameterKey::LiquidationRatio(BTC),
+				RiskParameterValue::Ratio(Ratio::saturating_from_rational(2, 1)),
+			),
+			(
+				RiskParameterKey::InterestRatePerSec(BTC),
+				RiskParameterValue::Rate(Rate::saturating_from_rational(2, 100000)),
+			),
+			(
+				RiskParameterKey::LiquidationPenalty(BTC),
+				RiskParameterValue::Rate(Rate::saturating_from_rational(1, 10)),
+			),
+			(
+				RiskParameterKey::RequiredCollateralRatio(BTC),
+				RiskParameterValue::Ratio(Ratio::saturating_from_rational(5, 2)),
+			),
+			(
+				RiskParameterKey::MaximumTotalDebitValue(BTC),
+				RiskParameterValue::Balance(20000),
+			),
+		]);
+		assert_eq!(
+			CDPEngineModule::get_liquidation_ratio(BTC),
+			Ratio::saturating_from_rational(2, 1)
+		);
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Rate::saturating_from_rational(2, 100000)
+		);
+		assert_eq!(
+			CDPEngineModule::get_liquidation_penalty(BTC),
+			Rate::saturating_from_rational(1, 10)
+		);
+		assert_eq!(
+			CDPEngineModule::required_collateral_ratio(BTC),
+			Some(Ratio::saturating_from_rational(5, 2))
+		);
+		assert_eq!(CDPEngineModule::maximum_total_debit_value(BTC), 20000);
+
+		// fall back to the collateral params
+		RiskParameterOverrides::set(vec![]);
+		assert_eq!(
+			CDPEngineModule::get_liquidation_ratio(BTC),
+			Ratio::saturating_from_rational(5, 2)
+		);
+		assert_eq!(CDPEngineModule::maximum_total_debit_value(BTC), 10000);
+	});
+}
+
 #[test]
 fn set_global_params_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -162,6 +225,126 @@ fn set_global_params_work() {
 	});
 }
 
+#[test]
+fn set_offchain_worker_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let params = OffchainWorkerParams {
+			max_iterations: 100,
+			lock_expiration: 500,
+			shuffle_start: false,
+		};
+		assert_eq!(
+			CDPEngineModule::offchain_worker_config(),
+			OffchainWorkerParams::default()
+		);
+
+		assert_noop!(
+			CDPEngineModule::set_offchain_worker_params(Origin::signed(5), params.clone()),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_offchain_worker_params(
+				Origin::signed(1),
+				OffchainWorkerParams {
+					max_iterations: 0,
+					..params.clone()
+				}
+			),
+			Error::<Runtime>::InvalidOffchainWorkerParams
+		);
+		assert_noop!(
+			CDPEngineModule::set_offchain_worker_params(
+				Origin::signed(1),
+				OffchainWorkerParams {
+					lock_expiration: 0,
+					..params.clone()
+				}
+			),
+			Error::<Runtime>::InvalidOffchainWorkerParams
+		);
+
+		assert_ok!(CDPEngineModule::set_offchain_worker_params(
+			Origin::signed(1),
+			params.clone()
+		));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::OffchainWorkerParamsUpdated(
+			params.clone(),
+		)));
+		assert_eq!(CDPEngineModule::offchain_worker_config(), params);
+	});
+}
+
+#[test]
+fn set_collateral_auction_type_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPEngineModule::set_collateral_auction_type(Origin::signed(1), LDOT, CollateralAuctionType::Dutch),
+			Error::<Runtime>::InvalidCollateralType
+		);
+		assert_noop!(
+			CDPEngineModule::set_collateral_auction_type(Origin::signed(5), BTC, CollateralAuctionType::Dutch),
+			BadOrigin
+		);
+		assert_eq!(CDPEngineModule::get_auction_type(BTC), CollateralAuctionType::English);
+
+		assert_ok!(CDPEngineModule::set_collateral_auction_type(
+			Origin::signed(1),
+			BTC,
+			CollateralAuctionType::Dutch
+		));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::CollateralAuctionTypeUpdated(
+			BTC,
+			CollateralAuctionType::Dutch,
+		)));
+		assert_eq!(CDPEngineModule::get_auction_type(BTC), CollateralAuctionType::Dutch);
+		assert_eq!(
+			CollateralAuctionTypeOf::<Runtime>::get(&BTC),
+			CollateralAuctionType::Dutch
+		);
+		assert_eq!(
+			CollateralAuctionTypeOf::<Runtime>::get(&DOT),
+			CollateralAuctionType::English
+		);
+	});
+}
+
+#[test]
+fn migrate_collateral_params_to_v1_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let old_params = migrations::v1::OldRiskManagementParams {
+			maximum_total_debit_value: 10000,
+			liquidation_ratio: Some(Ratio::saturating_from_rational(3, 2)),
+			..Default::default()
+		};
+		frame_support::storage::unhashed::put(&CollateralParams::<Runtime>::hashed_key_for(BTC), &old_params);
+		StorageVersion::new(0).put::<CDPEngineModule>();
+
+		migrations::v1::migrate::<Runtime>();
+		assert_eq!(StorageVersion::get::<CDPEngineModule>(), 1);
+		assert_eq!(
+			CDPEngineModule::collateral_params(BTC),
+			RiskManagementParams {
+				maximum_total_debit_value: 10000,
+				interest_rate_per_sec: None,
+				liquidation_ratio: Some(Ratio::saturating_from_rational(3, 2)),
+				liquidation_penalty: None,
+				required_collateral_ratio: None,
+				auction_type: CollateralAuctionType::English,
+			}
+		);
+
+		// the migration is applied only once
+		frame_support::storage::unhashed::put(&CollateralParams::<Runtime>::hashed_key_for(BTC), &old_params);
+		migrations::v1::migrate::<Runtime>();
+		assert_eq!(
+			frame_support::storage::unhashed::get_raw(&CollateralParams::<Runtime>::hashed_key_for(BTC)),
+			Some(old_params.encode())
+		);
+	});
+}
+
 #[test]
 fn set_collateral_params_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -271,6 +454,119 @@ fn calculate_collateral_ratio_work() {
 	});
 }
 
+#[test]
+fn calculate_liquidation_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_eq!(
+			CDPEngineModule::calculate_liquidation_price(BTC, 100, 500),
+			Some(Price::saturating_from_rational(3, 4))
+		);
+		assert_eq!(CDPEngineModule::calculate_liquidation_price(BTC, 0, 500), None);
+	});
+}
+
+#[test]
+fn calculate_required_collateral_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_eq!(
+			CDPEngineModule::calculate_required_collateral(BTC, 50, 500, Price::saturating_from_rational(1, 1)),
+			40
+		);
+		assert_eq!(
+			CDPEngineModule::calculate_required_collateral(BTC, 100, 500, Price::saturating_from_rational(1, 1)),
+			0
+		);
+
+		// falls back to the liquidation ratio
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(None),
+			Change::NoChange,
+		));
+		assert_eq!(
+			CDPEngineModule::calculate_required_collateral(BTC, 50, 500, Price::saturating_from_rational(1, 2)),
+			100
+		);
+	});
+}
+
+#[test]
+fn get_accumulated_interest_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(CDPEngineModule::get_accumulated_interest(BTC, 500), 0);
+		DebitExchangeRate::<Runtime>::insert(BTC, ExchangeRate::saturating_from_rational(2, 10));
+		assert_eq!(CDPEngineModule::get_accumulated_interest(BTC, 500), 50);
+	});
+}
+
+#[test]
+fn get_interest_accrued_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_eq!(
+			CDPEngineModule::position_interest(BTC, ALICE),
+			Some(InterestAccrual {
+				accrued: 0,
+				debit_exchange_rate: ExchangeRate::saturating_from_rational(1, 10),
+			})
+		);
+		assert_eq!(CDPEngineModule::get_interest_accrued(&ALICE, BTC), 0);
+
+		DebitExchangeRate::<Runtime>::insert(BTC, ExchangeRate::saturating_from_rational(2, 10));
+		assert_eq!(CDPEngineModule::get_interest_accrued(&ALICE, BTC), 50);
+
+		// repaid interest is still accounted
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, -250));
+		assert_eq!(
+			CDPEngineModule::position_interest(BTC, ALICE),
+			Some(InterestAccrual {
+				accrued: 50,
+				debit_exchange_rate: ExchangeRate::saturating_from_rational(2, 10),
+			})
+		);
+		DebitExchangeRate::<Runtime>::insert(BTC, ExchangeRate::saturating_from_rational(3, 10));
+		assert_eq!(CDPEngineModule::get_interest_accrued(&ALICE, BTC), 75);
+
+		// a reopened position starts over
+		assert_ok!(Currencies::deposit(AUSD, &ALICE, 75));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, -100, -250));
+		assert_eq!(CDPEngineModule::get_interest_accrued(&ALICE, BTC), 75);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 100));
+		assert_eq!(CDPEngineModule::get_interest_accrued(&ALICE, BTC), 0);
+	});
+}
+
 #[test]
 fn check_debit_cap_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -407,6 +703,81 @@ fn adjust_position_work() {
 	});
 }
 
+#[test]
+fn set_maximum_debit_value_per_account_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPEngineModule::set_maximum_debit_value_per_account(Origin::signed(5), BTC, Some(40)),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_maximum_debit_value_per_account(Origin::signed(1), ACA, Some(40)),
+			Error::<Runtime>::InvalidCollateralType
+		);
+		assert_ok!(CDPEngineModule::set_maximum_debit_value_per_account(
+			Origin::signed(1),
+			BTC,
+			Some(40)
+		));
+		System::assert_last_event(Event::CDPEngineModule(
+			crate::Event::MaximumDebitValuePerAccountUpdated(BTC, Some(40)),
+		));
+		assert_eq!(CDPEngineModule::maximum_debit_value_per_account(BTC), Some(40));
+		assert_ok!(CDPEngineModule::set_maximum_debit_value_per_account(
+			Origin::signed(1),
+			BTC,
+			None
+		));
+		assert_eq!(CDPEngineModule::maximum_debit_value_per_account(BTC), None);
+	});
+}
+
+#[test]
+fn adjust_position_with_debit_cap_per_account_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::set_maximum_debit_value_per_account(
+			Origin::signed(1),
+			BTC,
+			Some(40)
+		));
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500),
+			Error::<Runtime>::ExceedDebitValuePerAccountCap,
+		);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 400));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 400);
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, 0, 10),
+			Error::<Runtime>::ExceedDebitValuePerAccountCap,
+		);
+
+		// lowering the cap does not prevent repaying
+		assert_ok!(CDPEngineModule::set_maximum_debit_value_per_account(
+			Origin::signed(1),
+			BTC,
+			Some(20)
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, -100));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 300);
+
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 200));
+		assert_noop!(
+			CDPEngineModule::transfer_loan(&ALICE, &BOB, BTC),
+			Error::<Runtime>::ExceedDebitValuePerAccountCap,
+		);
+	});
+}
+
 #[test]
 fn remain_debit_value_too_small_check() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -478,6 +849,190 @@ fn liquidate_unsafe_cdp_by_collateral_auction() {
 	});
 }
 
+#[test]
+fn liquidate_unsafe_cdp_paused_when_price_frozen() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+
+		mock_price_frozen(true);
+		assert_noop!(
+			CDPEngineModule::liquidate(Origin::none(), BTC, ALICE),
+			Error::<Runtime>::PriceFrozen
+		);
+
+		mock_price_frozen(false);
+		assert_ok!(CDPEngineModule::liquidate(Origin::none(), BTC, ALICE));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+	});
+}
+
+#[test]
+fn liquidate_by_keeper_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 500));
+		assert_noop!(
+			CDPEngineModule::liquidate(Origin::signed(BOB), BTC, ALICE),
+			Error::<Runtime>::MustBeUnsafe,
+		);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_noop!(
+			CDPEngineModule::liquidate(Origin::root(), BTC, ALICE),
+			DispatchError::BadOrigin,
+		);
+
+		// the penalty of collateral auctions is not collected yet, no reward
+		let bob_stable_balance = Currencies::free_balance(AUSD, &BOB);
+		assert_ok!(CDPEngineModule::liquidate(Origin::signed(BOB), BTC, ALICE));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			100,
+			50,
+			LiquidationStrategy::Auction,
+		)));
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), bob_stable_balance);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+
+		// rewarded from the penalty collected by the stability pool
+		StabilityPoolAbsorbedCollateral::set(Some(80));
+		let alice_stable_balance = Currencies::free_balance(AUSD, &ALICE);
+		assert_ok!(CDPEngineModule::liquidate(Origin::signed(ALICE), BTC, BOB));
+		System::assert_has_event(Event::CDPEngineModule(crate::Event::KeeperRewarded(BTC, BOB, ALICE, 5)));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			BOB,
+			100,
+			50,
+			LiquidationStrategy::StabilityPool,
+		)));
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), alice_stable_balance + 5);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 55);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+	});
+}
+
+#[test]
+fn calculate_liquidation_amounts_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+
+		// partial liquidation disabled
+		assert_eq!(
+			CDPEngineModule::calculate_liquidation_amounts(BTC, 100, 500),
+			(100, 500)
+		);
+
+		PartialLiquidationTarget::set(Some(Ratio::saturating_from_rational(1, 2)));
+		assert_eq!(CDPEngineModule::calculate_liquidation_amounts(BTC, 100, 500), (38, 320));
+
+		// the remaining debit value is below the minimum debit value
+		assert_eq!(CDPEngineModule::calculate_liquidation_amounts(BTC, 5, 33), (5, 33));
+
+		// the target ratio is not above the liquidation penalty
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(1, 2))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_eq!(
+			CDPEngineModule::calculate_liquidation_amounts(BTC, 100, 500),
+			(100, 500)
+		);
+	});
+}
+
+#[test]
+fn liquidate_unsafe_cdp_partially_by_collateral_auction() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		PartialLiquidationTarget::set(Some(Ratio::saturating_from_rational(1, 2)));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			38,
+			32,
+			LiquidationStrategy::Auction,
+		)));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 32);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 180);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 62);
+		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 62, 180), CDPStatus::Safe);
+	});
+}
+
 #[test]
 fn liquidate_unsafe_cdp_by_collateral_auction_when_limited_by_slippage() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -598,6 +1153,65 @@ fn liquidate_unsafe_cdp_by_swap() {
 	});
 }
 
+#[test]
+fn liquidate_unsafe_cdp_by_stability_pool() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::max_value())),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+
+		// no liquidity in DEX, absorbed by the stability pool
+		StabilityPoolAbsorbedCollateral::set(Some(80));
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			100,
+			50,
+			LiquidationStrategy::StabilityPool,
+		)));
+
+		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 60);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 0);
+		assert_eq!(Currencies::free_balance(BTC, &CAROL), 10080);
+		assert_eq!(Currencies::free_balance(AUSD, &CAROL), 9940);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 920);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+
+		// falls back to collateral auctions once the stability pool is depleted
+		StabilityPoolAbsorbedCollateral::set(None);
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(BOB, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			BOB,
+			100,
+			50,
+			LiquidationStrategy::Auction,
+		)));
+		assert_eq!(Currencies::free_balance(AUSD, &CAROL), 9940);
+	});
+}
+
 #[test]
 fn get_interest_rate_per_sec_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -1082,6 +1696,98 @@ fn offchain_worker_iteration_limit_works() {
 	});
 }
 
+#[test]
+fn offchain_worker_params_works() {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain));
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		// check 1 CDP per block, starting at the first collateral type
+		assert_ok!(CDPEngineModule::set_offchain_worker_params(
+			Origin::signed(1),
+			OffchainWorkerParams {
+				max_iterations: 1,
+				lock_expiration: LOCK_DURATION,
+				shuffle_start: false,
+			}
+		));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 500));
+		// make both positions unsafe
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+
+		run_to_block_offchain(2);
+		assert_eq!(pool_state.write().transactions.len(), 1);
+		run_to_block_offchain(3);
+		assert_eq!(pool_state.write().transactions.len(), 2);
+	});
+}
+
+#[test]
+fn offchain_worker_disabled_by_local_storage() {
+	let (mut offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		offchain.local_storage_set(StorageKind::PERSISTENT, OFFCHAIN_WORKER_DISABLED, &true.encode());
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		// make the position unsafe
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+
+		run_to_block_offchain(3);
+		assert!(pool_state.write().transactions.is_empty());
+
+		// turned on again
+		offchain.local_storage_set(StorageKind::PERSISTENT, OFFCHAIN_WORKER_DISABLED, &false.encode());
+		run_to_block_offchain(5);
+		assert_eq!(pool_state.write().transactions.len(), 1);
+	});
+}
+
 #[test]
 fn offchain_default_max_iterator_works() {
 	let (mut offchain, _offchain_state) = testing::TestOffchainExt::new();
@@ -50,6 +50,8 @@ pub trait WeightInfo {
 	fn on_initialize(c: u32) -> Weight;
 	fn set_collateral_params() -> Weight;
 	fn set_global_params() -> Weight;
+	fn set_maximum_debit_value_per_account() -> Weight;
+	fn set_offchain_worker_params() -> Weight;
 	fn liquidate_by_auction() -> Weight;
 	fn liquidate_by_dex() -> Weight;
 	fn settle() -> Weight;
@@ -73,6 +75,14 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		(11_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_maximum_debit_value_per_account() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_offchain_worker_params() -> Weight {
+		(11_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn liquidate_by_auction() -> Weight {
 		(203_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(28 as Weight))
@@ -107,6 +117,14 @@ impl WeightInfo for () {
 		(11_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn set_maximum_debit_value_per_account() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_offchain_worker_params() -> Weight {
+		(11_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 	fn liquidate_by_auction() -> Weight {
 		(203_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(28 as Weight))
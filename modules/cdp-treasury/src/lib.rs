@@ -24,6 +24,14 @@
 //! CDPs, and handle excessive surplus or debits timely in order to keep the
 //! system healthy with low risk. It's the only entry for issuing/burning stable
 //! coin for whole system.
+//!
+//! Instead of leaving them in the pools, the excess surplus and debits can be
+//! disposed via DEX once the governance sets the DEX disposal parameters. The
+//! excess surplus is swapped to native currency which is burned, and the
+//! excess debit is bought back with newly issued native currency. Large
+//! amounts are split into lots swapped in the idle time of following blocks
+//! to reduce the price impact, and each swap is bounded by the max slippage
+//! from the spot price of DEX.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -33,10 +41,10 @@ use frame_system::pallet_prelude::*;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::{Balance, CurrencyId};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Zero},
+	traits::{AccountIdConversion, Bounded, One, Saturating, Zero},
 	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber,
 };
-use support::{AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, Ratio};
+use support::{AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, IdleScheduler, Ratio, TaskResult};
 
 mod mock;
 mod tests;
@@ -45,6 +53,29 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// Parameters of disposing the excess surplus and debit via DEX.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, Default)]
+pub struct DexDisposalParams {
+	/// The surplus kept in the surplus pool, only the surplus above it is
+	/// disposed.
+	pub surplus_buffer: Balance,
+	/// The max amount of stable currency swapped in a lot, one lot is
+	/// swapped per block.
+	pub max_lot_size: Balance,
+	/// The acceptable max slippage for the spot price of DEX.
+	pub max_slippage: Ratio,
+}
+
+/// The background task disposing the excess surplus or debit via DEX.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum DisposalTask {
+	/// Swap the excess surplus to native currency and burn it.
+	Surplus,
+	/// Swap newly issued native currency to stable currency to offset the
+	/// excess debit.
+	Debit,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -64,6 +95,12 @@ pub mod module {
 		#[pallet::constant]
 		type GetStableCurrencyId: Get<CurrencyId>;
 
+		/// Native currency id, which is bought and burned with the excess
+		/// surplus, or issued to offset the excess debit when disposing via
+		/// DEX.
+		#[pallet::constant]
+		type GetNativeCurrencyId: Get<CurrencyId>;
+
 		/// Auction manager creates auction to handle system surplus and debit
 		type AuctionManagerHandler: AuctionManager<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
 
@@ -85,6 +122,9 @@ pub mod module {
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// Schedules the lots of DEX disposal to be swapped in idle time.
+		type IdleScheduler: IdleScheduler<DisposalTask>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -99,6 +139,10 @@ pub mod module {
 		DebitPoolNotEnough,
 		/// The swap path is invalid
 		InvalidSwapPath,
+		/// The DEX disposal parameters are invalid
+		InvalidDexDisposalParams,
+		/// The liquidity of DEX is not enough to dispose
+		InsufficientLiquidity,
 	}
 
 	#[pallet::event]
@@ -107,6 +151,16 @@ pub mod module {
 		/// The expected amount size for per lot collateral auction of specific
 		/// collateral type updated. \[collateral_type, new_size\]
 		ExpectedCollateralAuctionSizeUpdated(CurrencyId, Balance),
+		/// The parameters of disposing via DEX updated, `None` disables it.
+		/// \[new_params\]
+		DexDisposalParamsUpdated(Option<DexDisposalParams>),
+		/// A lot of the excess surplus has been swapped to native currency
+		/// and burned. \[surplus_amount, burned_native_amount\]
+		SurplusDisposed(Balance, Balance),
+		/// A lot of the excess debit has been offset with stable currency
+		/// swapped from newly issued native currency. \[issued_native_amount,
+		/// offset_debit_amount\]
+		DebitDisposed(Balance, Balance),
 	}
 
 	/// The expected amount size for per lot collateral auction of specific
@@ -125,6 +179,21 @@ pub mod module {
 	#[pallet::getter(fn debit_pool)]
 	pub type DebitPool<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// The parameters of disposing the excess surplus and debit via DEX,
+	/// disabled if `None`.
+	///
+	/// DexDisposal: Option<DexDisposalParams>
+	#[pallet::storage]
+	#[pallet::getter(fn dex_disposal)]
+	pub type DexDisposal<T: Config> = StorageValue<_, DexDisposalParams, OptionQuery>;
+
+	/// Whether a DEX disposal task is queued in the idle scheduler.
+	///
+	/// DexDisposalScheduled: bool
+	#[pallet::storage]
+	#[pallet::getter(fn dex_disposal_scheduled)]
+	pub type DexDisposalScheduled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig {
 		pub expected_collateral_auction_size: Vec<(CurrencyId, Balance)>,
@@ -159,6 +228,9 @@ pub mod module {
 		fn on_finalize(_now: T::BlockNumber) {
 			// offset the same amount between debit pool and surplus pool
 			Self::offset_surplus_and_debit();
+
+			// dispose the remaining excess surplus or debit via DEX if enabled
+			Self::schedule_dex_disposal();
 		}
 	}
 
@@ -216,6 +288,26 @@ pub mod module {
 			Self::deposit_event(Event::ExpectedCollateralAuctionSizeUpdated(currency_id, size));
 			Ok(())
 		}
+
+		/// Enable or disable disposing the excess surplus and debit via DEX.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `params`: the parameters of DEX disposal, `None` to disable it
+		#[pallet::weight((T::WeightInfo::set_dex_disposal_params(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_dex_disposal_params(origin: OriginFor<T>, params: Option<DexDisposalParams>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if let Some(params) = params {
+				ensure!(
+					!params.max_lot_size.is_zero() && params.max_slippage < Ratio::one(),
+					Error::<T>::InvalidDexDisposalParams
+				);
+			}
+			DexDisposal::<T>::set(params);
+			Self::deposit_event(Event::DexDisposalParamsUpdated(params));
+			Ok(())
+		}
 	}
 }
 
@@ -265,6 +357,156 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 	}
+
+	fn schedule_dex_disposal() {
+		let params = match Self::dex_disposal() {
+			Some(params) => params,
+			None => return,
+		};
+		if Self::dex_disposal_scheduled() {
+			return;
+		}
+
+		let task = if !Self::debit_pool().is_zero() {
+			DisposalTask::Debit
+		} else if Self::surplus_pool() > params.surplus_buffer {
+			DisposalTask::Surplus
+		} else {
+			return;
+		};
+
+		match T::IdleScheduler::schedule(task) {
+			Ok(_) => DexDisposalScheduled::<T>::put(true),
+			Err(e) => {
+				log::warn!(
+					target: "cdp-treasury",
+					"schedule_dex_disposal: Attempt to schedule {:?} failed: {:?}",
+					task, e
+				);
+			}
+		}
+	}
+
+	/// Dispose a lot of the excess surplus or debit via DEX. The task is
+	/// finished once nothing is left to dispose, the swap fails or the DEX
+	/// disposal is disabled.
+	pub fn dispatch_disposal(task: DisposalTask, weight: Weight) -> TaskResult {
+		let used_weight = T::WeightInfo::dispose_lot();
+		if used_weight > weight {
+			return TaskResult {
+				result: Ok(()),
+				used_weight: 0,
+				finished: false,
+			};
+		}
+
+		let (result, finished) = match Self::dex_disposal() {
+			Some(params) => {
+				let res = match task {
+					DisposalTask::Surplus => Self::dispose_surplus_lot(&params),
+					DisposalTask::Debit => Self::dispose_debit_lot(&params),
+				};
+				match res {
+					Ok(remaining) => (Ok(()), !remaining),
+					Err(e) => (Err(e), true),
+				}
+			}
+			None => (Ok(()), true),
+		};
+
+		if finished {
+			DexDisposalScheduled::<T>::kill();
+		}
+		TaskResult {
+			result,
+			used_weight,
+			finished,
+		}
+	}
+
+	/// Swap a lot of the excess surplus to native currency and burn it,
+	/// returns whether some excess surplus remains.
+	#[transactional]
+	fn dispose_surplus_lot(params: &DexDisposalParams) -> Result<bool, DispatchError> {
+		let excess_surplus = Self::surplus_pool()
+			.saturating_sub(Self::debit_pool())
+			.saturating_sub(params.surplus_buffer);
+		if excess_surplus.is_zero() {
+			return Ok(false);
+		}
+
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let native_currency_id = T::GetNativeCurrencyId::get();
+		let supply_amount = excess_surplus.min(params.max_lot_size);
+
+		// calculate the target limit by slippage limit for the spot price of DEX
+		let min_target_amount = Ratio::one().saturating_sub(params.max_slippage).saturating_mul_int(
+			Self::dex_spot_price(stable_currency_id, native_currency_id)?.saturating_mul_int(supply_amount),
+		);
+		let target_amount = T::DEX::swap_with_exact_supply(
+			&Self::account_id(),
+			&[stable_currency_id, native_currency_id],
+			supply_amount,
+			min_target_amount,
+		)?;
+		T::Currency::withdraw(native_currency_id, &Self::account_id(), target_amount)?;
+
+		Self::deposit_event(Event::SurplusDisposed(supply_amount, target_amount));
+		Ok(excess_surplus > supply_amount)
+	}
+
+	/// Swap newly issued native currency to stable currency to offset a lot
+	/// of the excess debit, returns whether some excess debit remains.
+	#[transactional]
+	fn dispose_debit_lot(params: &DexDisposalParams) -> Result<bool, DispatchError> {
+		let excess_debit = Self::debit_pool().saturating_sub(Self::surplus_pool());
+		if excess_debit.is_zero() {
+			return Ok(false);
+		}
+
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let native_currency_id = T::GetNativeCurrencyId::get();
+		let target_amount = excess_debit.min(params.max_lot_size);
+
+		// calculate the supply limit by slippage limit for the spot price of DEX
+		let max_supply_amount = Ratio::one()
+			.saturating_sub(params.max_slippage)
+			.reciprocal()
+			.unwrap_or_else(Ratio::max_value)
+			.saturating_mul_int(
+				Self::dex_spot_price(stable_currency_id, native_currency_id)?.saturating_mul_int(target_amount),
+			);
+		T::Currency::deposit(native_currency_id, &Self::account_id(), max_supply_amount)?;
+		let supply_amount = T::DEX::swap_with_exact_target(
+			&Self::account_id(),
+			&[native_currency_id, stable_currency_id],
+			target_amount,
+			max_supply_amount,
+		)?;
+		T::Currency::withdraw(
+			native_currency_id,
+			&Self::account_id(),
+			max_supply_amount.saturating_sub(supply_amount),
+		)?;
+
+		// offset immediately, otherwise the next lot swapped before block end
+		// would buy back the same debit again
+		Self::offset_surplus_and_debit();
+
+		Self::deposit_event(Event::DebitDisposed(supply_amount, target_amount));
+		Ok(excess_debit > target_amount)
+	}
+
+	/// The amount of `target` per unit of `supply` in the liquidity pool of
+	/// DEX.
+	fn dex_spot_price(supply: CurrencyId, target: CurrencyId) -> Result<Ratio, DispatchError> {
+		let (supply_pool, target_pool) = T::DEX::get_liquidity_pool(supply, target);
+		ensure!(
+			!supply_pool.is_zero() && !target_pool.is_zero(),
+			Error::<T>::InsufficientLiquidity
+		);
+		Ok(Ratio::checked_from_rational(target_pool, supply_pool).unwrap_or_else(Ratio::max_value))
+	}
 }
 
 impl<T: Config> CDPTreasury<T::AccountId> for Pallet<T> {
@@ -317,6 +559,10 @@ impl<T: Config> CDPTreasury<T::AccountId> for Pallet<T> {
 		T::Currency::transfer(T::GetStableCurrencyId::get(), from, &Self::account_id(), surplus)
 	}
 
+	fn withdraw_surplus(to: &T::AccountId, surplus: Self::Balance) -> DispatchResult {
+		T::Currency::transfer(T::GetStableCurrencyId::get(), &Self::account_id(), to, surplus)
+	}
+
 	fn deposit_collateral(from: &T::AccountId, currency_id: Self::CurrencyId, amount: Self::Balance) -> DispatchResult {
 		T::Currency::transfer(currency_id, from, &Self::account_id(), amount)
 	}
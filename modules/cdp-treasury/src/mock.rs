@@ -127,6 +127,7 @@ parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (0, 100);
 	pub const TradingPathLimit: u32 = 3;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
+		TradingPair::from_currency_ids(AUSD, ACA).unwrap(),
 		TradingPair::from_currency_ids(AUSD, BTC).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
 		TradingPair::from_currency_ids(BTC, DOT).unwrap(),
@@ -139,6 +140,9 @@ impl module_dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type RoutingCurrencies = ();
+	type TWAPObservationPeriod = ();
+	type MaxTWAPObservations = ();
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = ();
 	type DEXIncentives = ();
@@ -191,6 +195,18 @@ parameter_types! {
 	pub const TreasuryAccount: AccountId = 10;
 }
 
+thread_local! {
+	pub static SCHEDULED_TASKS: RefCell<Vec<DisposalTask>> = RefCell::new(vec![]);
+}
+
+pub struct MockIdleScheduler;
+impl IdleScheduler<DisposalTask> for MockIdleScheduler {
+	fn schedule(task: DisposalTask) -> Result<u32, DispatchError> {
+		SCHEDULED_TASKS.with(|v| v.borrow_mut().push(task));
+		Ok(0)
+	}
+}
+
 thread_local! {
 	static IS_SHUTDOWN: RefCell<bool> = RefCell::new(false);
 }
@@ -199,12 +215,14 @@ impl Config for Runtime {
 	type Event = Event;
 	type Currency = Currencies;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type AuctionManagerHandler = MockAuctionManager;
 	type UpdateOrigin = EnsureOneOf<AccountId, EnsureRoot<AccountId>, EnsureSignedBy<One, AccountId>>;
 	type DEX = DEXModule;
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type IdleScheduler = MockIdleScheduler;
 	type WeightInfo = ();
 }
 
@@ -139,6 +139,19 @@ fn deposit_surplus_work() {
 	});
 }
 
+#[test]
+fn withdraw_surplus_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::deposit_surplus(&ALICE, 300));
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 300);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 1000);
+		assert!(!CDPTreasuryModule::withdraw_surplus(&BOB, 301).is_ok());
+		assert_ok!(CDPTreasuryModule::withdraw_surplus(&BOB, 200));
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 1200);
+	});
+}
+
 #[test]
 fn deposit_collateral_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -429,3 +442,195 @@ fn extract_surplus_to_treasury_work() {
 		assert_eq!(Currencies::free_balance(AUSD, &TreasuryAccount::get()), 200);
 	});
 }
+
+fn dex_disposal_params(max_slippage: Ratio) -> DexDisposalParams {
+	DexDisposalParams {
+		surplus_buffer: 100,
+		max_lot_size: 200,
+		max_slippage,
+	}
+}
+
+fn inject_native_liquidity() {
+	assert_ok!(Currencies::deposit(AUSD, &ALICE, 10_000));
+	assert_ok!(Currencies::deposit(ACA, &ALICE, 10_000));
+	assert_ok!(DEXModule::add_liquidity(
+		Origin::signed(ALICE),
+		AUSD,
+		ACA,
+		10_000,
+		10_000,
+		0,
+		false
+	));
+	assert_eq!(DEXModule::get_liquidity_pool(AUSD, ACA), (10_000, 10_000));
+}
+
+#[test]
+fn set_dex_disposal_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let params = dex_disposal_params(Ratio::saturating_from_rational(10, 100));
+		assert_noop!(
+			CDPTreasuryModule::set_dex_disposal_params(Origin::signed(5), Some(params)),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPTreasuryModule::set_dex_disposal_params(Origin::signed(1), Some(dex_disposal_params(Ratio::one()))),
+			Error::<Runtime>::InvalidDexDisposalParams
+		);
+		assert_noop!(
+			CDPTreasuryModule::set_dex_disposal_params(
+				Origin::signed(1),
+				Some(DexDisposalParams {
+					max_lot_size: 0,
+					..params
+				})
+			),
+			Error::<Runtime>::InvalidDexDisposalParams
+		);
+
+		assert_ok!(CDPTreasuryModule::set_dex_disposal_params(
+			Origin::signed(1),
+			Some(params)
+		));
+		assert_eq!(CDPTreasuryModule::dex_disposal(), Some(params));
+		System::assert_last_event(Event::CDPTreasuryModule(crate::Event::DexDisposalParamsUpdated(Some(
+			params,
+		))));
+
+		assert_ok!(CDPTreasuryModule::set_dex_disposal_params(Origin::signed(1), None));
+		assert_eq!(CDPTreasuryModule::dex_disposal(), None);
+	});
+}
+
+#[test]
+fn schedule_dex_disposal_on_finalize_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::on_system_surplus(1000));
+		CDPTreasuryModule::on_finalize(1);
+		assert!(SCHEDULED_TASKS.with(|v| v.borrow().is_empty()));
+
+		assert_ok!(CDPTreasuryModule::set_dex_disposal_params(
+			Origin::signed(1),
+			Some(DexDisposalParams {
+				surplus_buffer: 1000,
+				..dex_disposal_params(Ratio::saturating_from_rational(10, 100))
+			})
+		));
+		CDPTreasuryModule::on_finalize(2);
+		assert!(SCHEDULED_TASKS.with(|v| v.borrow().is_empty()));
+
+		assert_ok!(CDPTreasuryModule::on_system_surplus(1));
+		CDPTreasuryModule::on_finalize(3);
+		assert_eq!(
+			SCHEDULED_TASKS.with(|v| v.borrow().clone()),
+			vec![DisposalTask::Surplus]
+		);
+		assert!(CDPTreasuryModule::dex_disposal_scheduled());
+
+		// not scheduled again until the queued task finished
+		assert_ok!(CDPTreasuryModule::on_system_debit(2000));
+		CDPTreasuryModule::on_finalize(4);
+		assert_eq!(
+			SCHEDULED_TASKS.with(|v| v.borrow().clone()),
+			vec![DisposalTask::Surplus]
+		);
+
+		assert!(CDPTreasuryModule::dispatch_disposal(DisposalTask::Surplus, Weight::max_value()).finished);
+		assert!(!CDPTreasuryModule::dex_disposal_scheduled());
+		CDPTreasuryModule::on_finalize(5);
+		assert_eq!(
+			SCHEDULED_TASKS.with(|v| v.borrow().clone()),
+			vec![DisposalTask::Surplus, DisposalTask::Debit]
+		);
+	});
+}
+
+#[test]
+fn dispose_surplus_via_dex_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		inject_native_liquidity();
+		let native_issuance = Currencies::total_issuance(ACA);
+		assert_ok!(CDPTreasuryModule::set_dex_disposal_params(
+			Origin::signed(1),
+			Some(dex_disposal_params(Ratio::saturating_from_rational(10, 100)))
+		));
+		assert_ok!(CDPTreasuryModule::on_system_surplus(500));
+		DexDisposalScheduled::<Runtime>::put(true);
+
+		// wait for enough weight
+		assert_eq!(
+			CDPTreasuryModule::dispatch_disposal(DisposalTask::Surplus, 0),
+			TaskResult {
+				result: Ok(()),
+				used_weight: 0,
+				finished: false,
+			}
+		);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 500);
+
+		let result = CDPTreasuryModule::dispatch_disposal(DisposalTask::Surplus, Weight::max_value());
+		assert_eq!(result.result, Ok(()));
+		assert!(!result.finished);
+		System::assert_last_event(Event::CDPTreasuryModule(crate::Event::SurplusDisposed(200, 196)));
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 300);
+		assert_eq!(Currencies::free_balance(ACA, &CDPTreasuryModule::account_id()), 0);
+		assert_eq!(Currencies::total_issuance(ACA), native_issuance - 196);
+		assert!(CDPTreasuryModule::dex_disposal_scheduled());
+
+		// the surplus buffer is kept
+		let result = CDPTreasuryModule::dispatch_disposal(DisposalTask::Surplus, Weight::max_value());
+		assert_eq!(result.result, Ok(()));
+		assert!(result.finished);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert!(!CDPTreasuryModule::dex_disposal_scheduled());
+	});
+}
+
+#[test]
+fn dispose_debit_via_dex_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		inject_native_liquidity();
+		let native_issuance = Currencies::total_issuance(ACA);
+		assert_ok!(CDPTreasuryModule::set_dex_disposal_params(
+			Origin::signed(1),
+			Some(dex_disposal_params(Ratio::saturating_from_rational(10, 100)))
+		));
+		assert_ok!(CDPTreasuryModule::on_system_debit(300));
+
+		let result = CDPTreasuryModule::dispatch_disposal(DisposalTask::Debit, Weight::max_value());
+		assert_eq!(result.result, Ok(()));
+		assert!(!result.finished);
+		System::assert_last_event(Event::CDPTreasuryModule(crate::Event::DebitDisposed(205, 200)));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 0);
+		assert_eq!(Currencies::free_balance(ACA, &CDPTreasuryModule::account_id()), 0);
+		assert_eq!(Currencies::total_issuance(ACA), native_issuance + 205);
+	});
+}
+
+#[test]
+fn dispose_via_dex_fails_above_max_slippage() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::set_dex_disposal_params(
+			Origin::signed(1),
+			Some(dex_disposal_params(Ratio::saturating_from_rational(1, 100)))
+		));
+		assert_ok!(CDPTreasuryModule::on_system_surplus(500));
+		DexDisposalScheduled::<Runtime>::put(true);
+
+		let result = CDPTreasuryModule::dispatch_disposal(DisposalTask::Surplus, Weight::max_value());
+		assert_eq!(result.result, Err(Error::<Runtime>::InsufficientLiquidity.into()));
+		assert!(result.finished);
+		assert!(!CDPTreasuryModule::dex_disposal_scheduled());
+
+		inject_native_liquidity();
+		let result = CDPTreasuryModule::dispatch_disposal(DisposalTask::Surplus, Weight::max_value());
+		assert!(result.result.is_err());
+		assert!(result.finished);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 500);
+	});
+}
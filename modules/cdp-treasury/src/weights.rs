@@ -51,6 +51,8 @@ pub trait WeightInfo {
 	fn extract_surplus_to_treasury() -> Weight;
 	fn auction_collateral() -> Weight;
 	fn set_expected_collateral_auction_size() -> Weight;
+	fn set_dex_disposal_params() -> Weight;
+	fn dispose_lot() -> Weight;
 }
 
 /// Weights for module_cdp_treasury using the Acala node and recommended hardware.
@@ -70,6 +72,15 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		(14_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_dex_disposal_params() -> Weight {
+		(15_841_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn dispose_lot() -> Weight {
+		(213_476_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -88,4 +99,13 @@ impl WeightInfo for () {
 		(14_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn set_dex_disposal_params() -> Weight {
+		(15_841_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn dispose_lot() -> Weight {
+		(213_476_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
 }
@@ -41,8 +41,13 @@
 //! 2. [`Candidates`]: these are *candidates to the collation task* and may or may not be elected as
 //!    a final collator.
 //!
-//! The current implementation resolves congestion of [`Candidates`] in a first-come-first-serve
-//! manner.
+//! Anyone with session keys can register as a candidate by bonding at least [`CandidacyBond`].
+//! When the candidates are full, a new candidate bonding more than the lowest bonded candidate
+//! evicts it. Candidates registered by governance without a bond are never evicted.
+//!
+//! Candidates producing less blocks than [`Config::CollatorKickThreshold`] of the average in
+//! [`Config::CollatorKickMissedSessions`] consecutive sessions are kicked, and
+//! [`Config::CollatorKickSlash`] of their bond is slashed to the Pot.
 //!
 //! ### Rewards
 //!
@@ -80,10 +85,10 @@ pub mod pallet {
 		pallet_prelude::*,
 		storage::bounded_btree_set::BoundedBTreeSet,
 		traits::{
-			Currency, EnsureOrigin, ExistenceRequirement::KeepAlive, NamedReservableCurrency, ValidatorRegistration,
-			ValidatorSet,
+			Currency, EnsureOrigin, ExistenceRequirement::KeepAlive, Imbalance, NamedReservableCurrency,
+			ValidatorRegistration, ValidatorSet,
 		},
-		BoundedVec, PalletId,
+		transactional, BoundedVec, PalletId,
 	};
 	use frame_support::{
 		sp_runtime::{
@@ -158,6 +163,15 @@ pub mod pallet {
 		#[pallet::constant]
 		type CollatorKickThreshold: Get<Permill>;
 
+		/// Will be kicked if block is not produced in threshold in this number of consecutive
+		/// sessions.
+		#[pallet::constant]
+		type CollatorKickMissedSessions: Get<u32>;
+
+		/// The proportion of the bond slashed from the kicked candidate.
+		#[pallet::constant]
+		type CollatorKickSlash: Get<Permill>;
+
 		/// The weight information of this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -209,6 +223,13 @@ pub mod pallet {
 	#[pallet::getter(fn non_candidates)]
 	pub type NonCandidates<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, SessionIndex, ValueQuery>;
 
+	/// The number of consecutive sessions each candidate produced blocks below threshold.
+	///
+	/// MissedSessions: map AccountId => u32
+	#[pallet::storage]
+	#[pallet::getter(fn missed_sessions)]
+	pub type MissedSessions<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub invulnerables: Vec<T::AccountId>,
@@ -267,6 +288,10 @@ pub mod pallet {
 		CandidateAdded(T::AccountId, BalanceOf<T>),
 		/// A candidate was removed. \[who\]
 		CandidateRemoved(T::AccountId),
+		/// A candidate was evicted by a candidate with higher bond. \[who, bond\]
+		CandidateEvicted(T::AccountId, BalanceOf<T>),
+		/// The bond of a kicked candidate was slashed. \[who, amount\]
+		CandidateSlashed(T::AccountId, BalanceOf<T>),
 	}
 
 	// Errors inform users that something went wrong.
@@ -285,6 +310,7 @@ pub mod pallet {
 		AlreadyInvulnerable,
 		InvalidProof,
 		MaxInvulnerablesExceeded,
+		BondTooLow,
 	}
 
 	#[pallet::hooks]
@@ -325,12 +351,7 @@ pub mod pallet {
 		pub fn register_as_candidate(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			<NonCandidates<T>>::try_mutate_exists(&who, |maybe_index| -> DispatchResult {
-				if let Some(index) = maybe_index.take() {
-					ensure!(T::ValidatorSet::session_index() >= index, Error::<T>::StillLocked);
-				}
-				Ok(())
-			})?;
+			Self::ensure_not_locked(&who)?;
 
 			let deposit = Self::candidacy_bond();
 			let bounded_candidates_len = Self::do_register_candidate(&who, deposit)?;
@@ -339,6 +360,30 @@ pub mod pallet {
 			Ok(Some(T::WeightInfo::register_as_candidate(bounded_candidates_len as u32)).into())
 		}
 
+		/// Register as a candidate with `bond`, which must be at least the candidacy bond. If the
+		/// candidates are full, the candidate with the lowest bond is evicted if `bond` is higher.
+		#[pallet::weight(T::WeightInfo::register_as_candidate_with_bond(T::MaxCandidates::get()))]
+		#[transactional]
+		pub fn register_as_candidate_with_bond(origin: OriginFor<T>, bond: BalanceOf<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(bond >= Self::candidacy_bond(), Error::<T>::BondTooLow);
+			Self::ensure_not_locked(&who)?;
+
+			let length = <Candidates<T>>::decode_len().unwrap_or_default();
+			if (length as u32) >= Self::desired_candidates() {
+				Self::evict_lowest_bonded_candidate(&who, bond)?;
+			}
+
+			let bounded_candidates_len = Self::do_register_candidate(&who, bond)?;
+			Self::deposit_event(Event::CandidateAdded(who, bond));
+
+			Ok(Some(T::WeightInfo::register_as_candidate_with_bond(
+				bounded_candidates_len as u32,
+			))
+			.into())
+		}
+
 		#[pallet::weight(T::WeightInfo::register_candidate(T::MaxCandidates::get()))]
 		pub fn register_candidate(origin: OriginFor<T>, new_candidate: T::AccountId) -> DispatchResultWithPostInfo {
 			T::UpdateOrigin::ensure_origin(origin)?;
@@ -393,10 +438,63 @@ pub mod pallet {
 				candidates.take(who).ok_or(Error::<T>::NotCandidate)?;
 				Ok(candidates.len())
 			})?;
+			<MissedSessions<T>>::remove(who);
 			Self::deposit_event(Event::CandidateRemoved(who.clone()));
 			Ok(current_count)
 		}
 
+		/// Ensure `who` is not in the lock period after leaving or being kicked.
+		fn ensure_not_locked(who: &T::AccountId) -> DispatchResult {
+			<NonCandidates<T>>::try_mutate_exists(who, |maybe_index| -> DispatchResult {
+				if let Some(index) = maybe_index.take() {
+					ensure!(T::ValidatorSet::session_index() >= index, Error::<T>::StillLocked);
+				}
+				Ok(())
+			})
+		}
+
+		/// Evict the candidate with the lowest bond to make room for `who` bonding `bond`. The
+		/// evicted candidate can withdraw its bond after `SESSION_DELAY` sessions.
+		fn evict_lowest_bonded_candidate(who: &T::AccountId, bond: BalanceOf<T>) -> DispatchResult {
+			// candidates registered by governance have no bond and can't be evicted.
+			let (lowest, lowest_bond) = Self::candidates()
+				.into_iter()
+				.filter(|candidate| candidate != who)
+				.map(|candidate| {
+					let bond = T::Currency::reserved_balance_named(&RESERVE_ID, &candidate);
+					(candidate, bond)
+				})
+				.filter(|(_, bond)| !bond.is_zero())
+				.min_by_key(|(_, bond)| *bond)
+				.ok_or(Error::<T>::MaxCandidatesExceeded)?;
+			ensure!(bond > lowest_bond, Error::<T>::MaxCandidatesExceeded);
+
+			<Candidates<T>>::mutate(|candidates| candidates.take(&lowest));
+			<SessionPoints<T>>::remove(&lowest);
+			<MissedSessions<T>>::remove(&lowest);
+			<NonCandidates<T>>::insert(&lowest, T::ValidatorSet::session_index().saturating_add(SESSION_DELAY));
+			Self::deposit_event(Event::CandidateEvicted(lowest, lowest_bond));
+			Ok(())
+		}
+
+		/// Kick the candidate `who`, slashing `CollatorKickSlash` of its bond to the Pot.
+		fn kick_candidate(who: T::AccountId) -> DispatchResult {
+			Self::try_remove_candidate(&who)?;
+			<NonCandidates<T>>::insert(
+				&who,
+				T::ValidatorSet::session_index().saturating_add(T::KickPenaltySessionLength::get()),
+			);
+
+			let slash = T::CollatorKickSlash::get().mul_floor(T::Currency::reserved_balance_named(&RESERVE_ID, &who));
+			if !slash.is_zero() {
+				let (imbalance, _) = T::Currency::slash_reserved_named(&RESERVE_ID, &who, slash);
+				let slashed = imbalance.peek();
+				T::Currency::resolve_creating(&Self::account_id(), imbalance);
+				Self::deposit_event(Event::CandidateSlashed(who, slashed));
+			}
+			Ok(())
+		}
+
 		/// Assemble the current set of candidates and invulnerables into the next collator set.
 		///
 		/// This is done on the fly, as frequent as we are told to do so, as the session manager.
@@ -525,6 +623,14 @@ pub mod pallet {
 			for (who, point) in session_points {
 				// required_point maybe is zero
 				if point <= required_point {
+					let missed_sessions = <MissedSessions<T>>::mutate(&who, |missed| {
+						*missed = missed.saturating_add(1);
+						*missed
+					});
+					if missed_sessions < T::CollatorKickMissedSessions::get() {
+						continue;
+					}
+
 					log::debug!(
 						target: "collator-selection",
 						"end session {:?} at #{:?}, remove candidate: {:?}, point: {:?}, required_point: {:?}",
@@ -536,18 +642,15 @@ pub mod pallet {
 					);
 					removed_len += 1;
 
-					let outcome = Self::try_remove_candidate(&who);
+					let outcome = Self::kick_candidate(who);
 					if let Err(why) = outcome {
 						log::warn!(
 							target: "collator-selection",
 							"Failed to remove candidate {:?}", why);
 						debug_assert!(false, "failed to remove candidate {:?}", why);
-					} else {
-						<NonCandidates<T>>::insert(
-							who,
-							T::ValidatorSet::session_index().saturating_add(T::KickPenaltySessionLength::get()),
-						);
 					}
+				} else {
+					<MissedSessions<T>>::remove(&who);
 				}
 			}
 
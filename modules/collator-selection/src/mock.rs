@@ -197,6 +197,8 @@ parameter_types! {
 	pub const MaxInvulnerables: u32 = 4;
 	pub const KickPenaltySessionLength: u32 = 8;
 	pub const CollatorKickThreshold: Permill = Permill::from_percent(100);
+	pub static CollatorKickMissedSessions: u32 = 1;
+	pub const CollatorKickSlash: Permill = Permill::from_percent(10);
 }
 
 impl Config for Test {
@@ -210,6 +212,8 @@ impl Config for Test {
 	type MaxInvulnerables = MaxInvulnerables;
 	type KickPenaltySessionLength = KickPenaltySessionLength;
 	type CollatorKickThreshold = CollatorKickThreshold;
+	type CollatorKickMissedSessions = CollatorKickMissedSessions;
+	type CollatorKickSlash = CollatorKickSlash;
 	type WeightInfo = ();
 }
 
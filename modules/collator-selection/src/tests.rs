@@ -17,7 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate as collator_selection;
-use crate::{mock::*, Error, NonCandidates, RESERVE_ID};
+use crate::{mock::*, Error, MissedSessions, NonCandidates, RESERVE_ID};
 use frame_support::{
 	assert_noop, assert_ok,
 	storage::bounded_btree_set::BoundedBTreeSet,
@@ -505,6 +505,8 @@ fn kick_mechanism() {
 		assert_eq!(CollatorSelection::candidates(), collators);
 		// kicked collator without funds back
 		assert_eq!(Balances::free_balance(3), 90);
+		// and 10% of the bond is slashed
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &3), 9);
 		assert_noop!(
 			CollatorSelection::register_as_candidate(Origin::signed(3)),
 			Error::<Test>::StillLocked
@@ -512,6 +514,131 @@ fn kick_mechanism() {
 	});
 }
 
+#[test]
+fn kick_after_missed_sessions() {
+	new_test_ext().execute_with(|| {
+		CollatorKickMissedSessions::set(2);
+
+		assert_ok!(Session::set_keys(
+			Origin::signed(3),
+			MockSessionKeys {
+				aura: UintAuthorityId(3)
+			},
+			vec![]
+		));
+		assert_ok!(Session::set_keys(
+			Origin::signed(4),
+			MockSessionKeys {
+				aura: UintAuthorityId(4)
+			},
+			vec![]
+		));
+		assert_ok!(CollatorSelection::register_as_candidate(Origin::signed(3)));
+		assert_ok!(CollatorSelection::register_as_candidate(Origin::signed(4)));
+		initialize_to_block(21);
+		assert_eq!(SessionHandlerCollators::get(), vec![1, 2, 3, 4]);
+
+		// 3 missed one session, gets to stay
+		initialize_to_block(31);
+		assert_eq!(CollatorSelection::candidates().len(), 2);
+		assert_eq!(MissedSessions::<Test>::get(3), 1);
+		assert_eq!(MissedSessions::<Test>::get(4), 0);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &3), 10);
+
+		// 3 missed two sessions, is kicked and slashed
+		initialize_to_block(41);
+		let mut collators = BoundedBTreeSet::new();
+		assert_ok!(collators.try_insert(4));
+		assert_eq!(CollatorSelection::candidates(), collators);
+		assert_eq!(MissedSessions::<Test>::get(3), 0);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &3), 9);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &4), 10);
+	});
+}
+
+#[test]
+fn register_as_candidate_with_bond_works() {
+	new_test_ext().execute_with(|| {
+		for who in 3..=5 {
+			assert_ok!(Session::set_keys(
+				Origin::signed(who),
+				MockSessionKeys {
+					aura: UintAuthorityId(who)
+				},
+				vec![]
+			));
+		}
+
+		assert_noop!(
+			CollatorSelection::register_as_candidate_with_bond(Origin::signed(3), 9),
+			Error::<Test>::BondTooLow
+		);
+		assert_ok!(CollatorSelection::register_as_candidate_with_bond(
+			Origin::signed(3),
+			20
+		));
+		assert_ok!(CollatorSelection::register_as_candidate(Origin::signed(4)));
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &3), 20);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &4), 10);
+
+		// candidates are full, bond must be higher than the lowest bond
+		assert_noop!(
+			CollatorSelection::register_as_candidate_with_bond(Origin::signed(5), 10),
+			Error::<Test>::MaxCandidatesExceeded
+		);
+
+		// 4 is evicted and can withdraw its bond after the delay
+		assert_ok!(CollatorSelection::register_as_candidate_with_bond(
+			Origin::signed(5),
+			15
+		));
+		let mut collators = BoundedBTreeSet::new();
+		assert_ok!(collators.try_insert(3));
+		assert_ok!(collators.try_insert(5));
+		assert_eq!(CollatorSelection::candidates(), collators);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &5), 15);
+		assert!(NonCandidates::<Test>::contains_key(4));
+		assert_noop!(
+			CollatorSelection::withdraw_bond(Origin::signed(4)),
+			Error::<Test>::StillLocked
+		);
+		initialize_to_block(2 * Period::get());
+		assert_ok!(CollatorSelection::withdraw_bond(Origin::signed(4)));
+		assert_eq!(Balances::free_balance(4), 100);
+	});
+}
+
+#[test]
+fn candidates_without_bond_are_not_evicted() {
+	new_test_ext().execute_with(|| {
+		for who in 3..=5 {
+			assert_ok!(Session::set_keys(
+				Origin::signed(who),
+				MockSessionKeys {
+					aura: UintAuthorityId(who)
+				},
+				vec![]
+			));
+		}
+
+		assert_ok!(CollatorSelection::register_candidate(
+			Origin::signed(RootAccount::get()),
+			3
+		));
+		assert_ok!(CollatorSelection::register_as_candidate(Origin::signed(4)));
+
+		assert_ok!(CollatorSelection::register_as_candidate_with_bond(
+			Origin::signed(5),
+			15
+		));
+		let mut collators = BoundedBTreeSet::new();
+		assert_ok!(collators.try_insert(3));
+		assert_ok!(collators.try_insert(5));
+		assert_eq!(CollatorSelection::candidates(), collators);
+		assert!(NonCandidates::<Test>::contains_key(4));
+	});
+}
+
 #[test]
 fn exceeding_max_invulnerables_should_fail() {
 	new_test_ext().execute_with(|| {
@@ -51,6 +51,7 @@ pub trait WeightInfo {
 	fn set_desired_candidates() -> Weight;
 	fn set_candidacy_bond() -> Weight;
 	fn register_as_candidate(c: u32, ) -> Weight;
+	fn register_as_candidate_with_bond(c: u32, ) -> Weight;
 	fn register_candidate(c: u32, ) -> Weight;
 	fn leave_intent(c: u32, ) -> Weight;
 	fn withdraw_bond() -> Weight;
@@ -84,6 +85,14 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(7 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	fn register_as_candidate_with_bond(c: u32, ) -> Weight {
+		(88_778_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((885_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 	fn register_candidate(c: u32, ) -> Weight {
 		(44_267_000 as Weight)
 			// Standard Error: 0
@@ -155,6 +164,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
+	fn register_as_candidate_with_bond(c: u32, ) -> Weight {
+		(88_778_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((885_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
 	fn register_candidate(c: u32, ) -> Weight {
 		(44_267_000 as Weight)
 			// Standard Error: 0
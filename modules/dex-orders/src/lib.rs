@@ -0,0 +1,248 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # DEX Orders Module
+//!
+//! ## Overview
+//!
+//! Limit orders on top of the DEX module. An order sells an exact amount of
+//! supply currency for at least a minimum amount of target currency, the
+//! supply amount is reserved until the order is filled or cancelled. Anyone
+//! can fill an order once the pool price crosses its limit, by providing a
+//! trading path through DEX liquidity. The filler receives a small maker fee
+//! from the target amount as compensation for watching the prices.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::MaxEncodedLen;
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{One, Zero},
+	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
+};
+use sp_std::prelude::*;
+use support::{DEXManager, Rate};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub type OrderId = u64;
+
+/// A limit order, sell `supply_amount` of `supply_currency_id` for at least
+/// `min_target_amount` of `target_currency_id`.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, MaxEncodedLen)]
+pub struct Order<AccountId> {
+	/// The owner of the order.
+	pub owner: AccountId,
+	/// The currency to sell.
+	pub supply_currency_id: CurrencyId,
+	/// The currency to buy.
+	pub target_currency_id: CurrencyId,
+	/// The reserved amount of supply currency.
+	pub supply_amount: Balance,
+	/// The minimum amount of target currency the owner receives.
+	pub min_target_amount: Balance,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency to reserve the supply amount of orders.
+		type Currency: MultiReservableCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// DEX to fill orders against.
+		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
+
+		/// The share of the target amount of a filled order paid to the filler.
+		#[pallet::constant]
+		type MakerFee: Get<Rate>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The order does not exist
+		OrderNotFound,
+		/// Only the owner can cancel the order
+		NotOrderOwner,
+		/// Supply and target currency are the same
+		InvalidCurrencyId,
+		/// The supply amount or the minimum target amount is zero
+		ZeroAmount,
+		/// The trading path does not start with the supply currency and end with
+		/// the target currency of the order
+		InvalidTradingPath,
+		/// The swap did not reach the limit of the order
+		BelowLimitPrice,
+		/// No order id available
+		NoAvailableOrderId,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	pub enum Event<T: Config> {
+		/// Place an order. \[order_id, owner, supply_currency_id, supply_amount,
+		/// target_currency_id, min_target_amount\]
+		OrderPlaced(OrderId, T::AccountId, CurrencyId, Balance, CurrencyId, Balance),
+		/// Cancel an order. \[order_id, owner\]
+		OrderCancelled(OrderId, T::AccountId),
+		/// Fill an order. \[order_id, owner, filler, target_amount, maker_fee\]
+		OrderFilled(OrderId, T::AccountId, T::AccountId, Balance, Balance),
+	}
+
+	/// The next order id.
+	///
+	/// NextOrderId: OrderId
+	#[pallet::storage]
+	#[pallet::getter(fn next_order_id)]
+	pub type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	/// The open orders.
+	///
+	/// Orders: map OrderId => Option<Order>
+	#[pallet::storage]
+	#[pallet::getter(fn orders)]
+	pub type Orders<T: Config> = StorageMap<_, Twox64Concat, OrderId, Order<T::AccountId>, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Place a limit order, the supply amount is reserved until the order is
+		/// filled or cancelled.
+		///
+		/// - `supply_currency_id`: the currency to sell.
+		/// - `target_currency_id`: the currency to buy.
+		/// - `supply_amount`: exact supply amount.
+		/// - `min_target_amount`: minimum amount of target currency to receive.
+		#[pallet::weight(<T as Config>::WeightInfo::place_order())]
+		#[transactional]
+		pub fn place_order(
+			origin: OriginFor<T>,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			#[pallet::compact] supply_amount: Balance,
+			#[pallet::compact] min_target_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(supply_currency_id != target_currency_id, Error::<T>::InvalidCurrencyId);
+			ensure!(
+				!supply_amount.is_zero() && !min_target_amount.is_zero(),
+				Error::<T>::ZeroAmount
+			);
+
+			let order_id = NextOrderId::<T>::try_mutate(|id| -> sp_std::result::Result<OrderId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableOrderId)?;
+				Ok(current_id)
+			})?;
+
+			T::Currency::reserve(supply_currency_id, &who, supply_amount)?;
+			Orders::<T>::insert(
+				order_id,
+				Order {
+					owner: who.clone(),
+					supply_currency_id,
+					target_currency_id,
+					supply_amount,
+					min_target_amount,
+				},
+			);
+
+			Self::deposit_event(Event::OrderPlaced(
+				order_id,
+				who,
+				supply_currency_id,
+				supply_amount,
+				target_currency_id,
+				min_target_amount,
+			));
+			Ok(())
+		}
+
+		/// Cancel an order and unreserve its supply amount.
+		///
+		/// - `order_id`: the order to cancel.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_order())]
+		#[transactional]
+		pub fn cancel_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let order = Self::orders(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(order.owner == who, Error::<T>::NotOrderOwner);
+
+			T::Currency::unreserve(order.supply_currency_id, &order.owner, order.supply_amount);
+			Orders::<T>::remove(order_id);
+
+			Self::deposit_event(Event::OrderCancelled(order_id, who));
+			Ok(())
+		}
+
+		/// Fill an order against DEX liquidity. The supply amount is swapped
+		/// through `path`, the filler receives the maker fee out of the target
+		/// amount and the owner the rest, which must reach the order limit.
+		///
+		/// - `order_id`: the order to fill.
+		/// - `path`: trading path from the supply to the target currency.
+		#[pallet::weight(<T as Config>::WeightInfo::fill_order(path.len() as u32))]
+		#[transactional]
+		pub fn fill_order(origin: OriginFor<T>, order_id: OrderId, path: Vec<CurrencyId>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let order = Self::orders(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(
+				path.first() == Some(&order.supply_currency_id) && path.last() == Some(&order.target_currency_id),
+				Error::<T>::InvalidTradingPath
+			);
+
+			T::Currency::unreserve(order.supply_currency_id, &order.owner, order.supply_amount);
+			let target_amount = T::DEX::swap_with_exact_supply(&order.owner, &path, order.supply_amount, Zero::zero())?;
+
+			let maker_fee = T::MakerFee::get()
+				.checked_mul_int(target_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(
+				target_amount.saturating_sub(maker_fee) >= order.min_target_amount,
+				Error::<T>::BelowLimitPrice
+			);
+			T::Currency::transfer(order.target_currency_id, &order.owner, &who, maker_fee)?;
+			Orders::<T>::remove(order_id);
+
+			Self::deposit_event(Event::OrderFilled(order_id, order.owner, who, target_amount, maker_fee));
+			Ok(())
+		}
+	}
+}
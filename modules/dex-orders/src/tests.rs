@@ -0,0 +1,125 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the dex orders module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+
+#[test]
+fn place_order_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DexOrdersModule::place_order(Origin::signed(BOB), DOT, DOT, 100, 900),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+		assert_noop!(
+			DexOrdersModule::place_order(Origin::signed(BOB), DOT, AUSD, 0, 900),
+			Error::<Runtime>::ZeroAmount
+		);
+		assert_noop!(
+			DexOrdersModule::place_order(Origin::signed(BOB), DOT, AUSD, 100, 0),
+			Error::<Runtime>::ZeroAmount
+		);
+		assert_noop!(
+			DexOrdersModule::place_order(Origin::signed(BOB), DOT, AUSD, 1_001, 900),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+
+		assert_ok!(DexOrdersModule::place_order(Origin::signed(BOB), DOT, AUSD, 100, 900));
+		System::assert_last_event(Event::DexOrdersModule(crate::Event::OrderPlaced(
+			0, BOB, DOT, 100, AUSD, 900,
+		)));
+		assert_eq!(DexOrdersModule::next_order_id(), 1);
+		assert_eq!(
+			DexOrdersModule::orders(0),
+			Some(Order {
+				owner: BOB,
+				supply_currency_id: DOT,
+				target_currency_id: AUSD,
+				supply_amount: 100,
+				min_target_amount: 900,
+			})
+		);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 900);
+		assert_eq!(Tokens::reserved_balance(DOT, &BOB), 100);
+	});
+}
+
+#[test]
+fn cancel_order_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DexOrdersModule::cancel_order(Origin::signed(BOB), 0),
+			Error::<Runtime>::OrderNotFound
+		);
+
+		assert_ok!(DexOrdersModule::place_order(Origin::signed(BOB), DOT, AUSD, 100, 900));
+		assert_noop!(
+			DexOrdersModule::cancel_order(Origin::signed(ALICE), 0),
+			Error::<Runtime>::NotOrderOwner
+		);
+
+		assert_ok!(DexOrdersModule::cancel_order(Origin::signed(BOB), 0));
+		System::assert_last_event(Event::DexOrdersModule(crate::Event::OrderCancelled(0, BOB)));
+		assert_eq!(DexOrdersModule::orders(0), None);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000);
+		assert_eq!(Tokens::reserved_balance(DOT, &BOB), 0);
+	});
+}
+
+#[test]
+fn fill_order_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DexOrdersModule::fill_order(Origin::signed(CAROL), 0, vec![DOT, AUSD]),
+			Error::<Runtime>::OrderNotFound
+		);
+
+		// 100 DOT swap to 990 AUSD, 9 AUSD is paid as maker fee
+		assert_ok!(DexOrdersModule::place_order(Origin::signed(BOB), DOT, AUSD, 100, 990));
+		assert_noop!(
+			DexOrdersModule::fill_order(Origin::signed(CAROL), 0, vec![DOT, AUSD]),
+			Error::<Runtime>::BelowLimitPrice
+		);
+		assert_eq!(Tokens::reserved_balance(DOT, &BOB), 100);
+		assert_ok!(DexOrdersModule::cancel_order(Origin::signed(BOB), 0));
+
+		assert_ok!(DexOrdersModule::place_order(Origin::signed(BOB), DOT, AUSD, 100, 900));
+		assert_noop!(
+			DexOrdersModule::fill_order(Origin::signed(CAROL), 1, vec![AUSD, DOT]),
+			Error::<Runtime>::InvalidTradingPath
+		);
+		assert_noop!(
+			DexOrdersModule::fill_order(Origin::signed(CAROL), 1, vec![DOT, BTC, AUSD]),
+			dex::Error::<Runtime>::MustBeEnabled
+		);
+
+		assert_ok!(DexOrdersModule::fill_order(Origin::signed(CAROL), 1, vec![DOT, AUSD]));
+		System::assert_last_event(Event::DexOrdersModule(crate::Event::OrderFilled(1, BOB, CAROL, 990, 9)));
+		assert_eq!(DexOrdersModule::orders(1), None);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 900);
+		assert_eq!(Tokens::reserved_balance(DOT, &BOB), 0);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_981);
+		assert_eq!(Tokens::free_balance(AUSD, &CAROL), 9);
+		assert_eq!(DEXModule::get_liquidity_pool(AUSD, DOT), (99_010, 10_100));
+	});
+}
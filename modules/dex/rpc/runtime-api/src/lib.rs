@@ -0,0 +1,54 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for dex module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `too_many_arguments` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use support::{ExchangeRate, SwapMode, SwapQuote};
+
+sp_api::decl_runtime_apis! {
+	pub trait DexApi<CurrencyId, BlockNumber, Balance> where
+		CurrencyId: Codec,
+		BlockNumber: Codec,
+		Balance: Codec,
+	{
+		/// Time weighted average price of `currency_id_a` in `currency_id_b`
+		/// over at least the last `window` blocks, `None` if the trading pair
+		/// has no observation old enough.
+		fn get_twap(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			window: BlockNumber,
+		) -> Option<ExchangeRate>;
+
+		/// Quote of swapping `amount` from `supply_currency_id` to
+		/// `target_currency_id` along the best route, `None` if there's no
+		/// available route.
+		fn get_swap_quote(
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			amount: Balance,
+			mode: SwapMode,
+		) -> Option<SwapQuote<CurrencyId, Balance>>;
+	}
+}
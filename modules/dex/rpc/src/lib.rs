@@ -0,0 +1,152 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the dex module.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+};
+use std::sync::Arc;
+use support::{ExchangeRate, SwapMode, SwapQuote};
+
+pub use self::gen_client::Client as DexClient;
+pub use module_dex_rpc_runtime_api::DexApi as DexRuntimeApi;
+
+#[rpc]
+pub trait DexApi<BlockHash, CurrencyId, BlockNumber, Balance, SwapQuoteType> {
+	#[rpc(name = "dex_getTWAP")]
+	fn get_twap(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		window: BlockNumber,
+		at: Option<BlockHash>,
+	) -> Result<ExchangeRate>;
+
+	#[rpc(name = "dex_getSwapQuote")]
+	fn get_swap_quote(
+		&self,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		amount: Balance,
+		mode: SwapMode,
+		at: Option<BlockHash>,
+	) -> Result<SwapQuoteType>;
+}
+
+/// A struct that implements the [`DexApi`].
+pub struct Dex<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Dex<C, B> {
+	/// Create new `Dex` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Dex {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+	NoObservation,
+	NoAvailableRoute,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::NoObservation => 2,
+			Error::NoAvailableRoute => 3,
+		}
+	}
+}
+
+impl<C, Block, CurrencyId, BlockNumber, Balance>
+	DexApi<<Block as BlockT>::Hash, CurrencyId, BlockNumber, Balance, SwapQuote<CurrencyId, Balance>> for Dex<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: DexRuntimeApi<Block, CurrencyId, BlockNumber, Balance>,
+	CurrencyId: Codec,
+	BlockNumber: Codec,
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+{
+	fn get_twap(
+		&self,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		window: BlockNumber,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<ExchangeRate> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_twap(&at, currency_id_a, currency_id_b, window)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to get TWAP.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})?
+			.ok_or(RpcError {
+				code: ErrorCode::ServerError(Error::NoObservation.into()),
+				message: "No price observation old enough for the window.".into(),
+				data: None,
+			})
+	}
+
+	fn get_swap_quote(
+		&self,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		amount: Balance,
+		mode: SwapMode,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<SwapQuote<CurrencyId, Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_swap_quote(&at, supply_currency_id, target_currency_id, amount, mode)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to get swap quote.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})?
+			.ok_or(RpcError {
+				code: ErrorCode::ServerError(Error::NoAvailableRoute.into()),
+				message: "No available route for the swap.".into(),
+				data: None,
+			})
+	}
+}
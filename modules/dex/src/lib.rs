@@ -40,11 +40,14 @@ use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::{Balance, CurrencyId, TradingPair};
 use sp_core::{H160, U256};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Zero},
-	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug, SaturatedConversion,
+	traits::{AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, Saturating, Zero},
+	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, FixedU128, Permill, RuntimeDebug,
+	SaturatedConversion,
 };
 use sp_std::{convert::TryInto, prelude::*, vec};
-use support::{CurrencyIdMapping, DEXIncentives, DEXManager, ExchangeRate, Ratio};
+use support::{
+	CurrencyIdMapping, DEXIncentives, DEXManager, ExchangeRate, Ratio, SwapHop, SwapMode, SwapQuote, SwapVenue,
+};
 
 mod mock;
 mod tests;
@@ -86,6 +89,46 @@ impl<Balance, BlockNumber> Default for TradingPairStatus<Balance, BlockNumber> {
 	}
 }
 
+/// Prices of TradingPair accumulated over blocks, the inner values of
+/// `ExchangeRate` multiplied by the number of blocks they lasted. The
+/// accumulators wrap on overflow, only differences between two of them are
+/// meaningful.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, Default)]
+pub struct CumulativePrice<BlockNumber> {
+	/// accumulated price of token_0 in token_1.
+	pub price_0_cumulative: U256,
+	/// accumulated price of token_1 in token_0.
+	pub price_1_cumulative: U256,
+	/// The block number of the last accumulation.
+	pub block_number: BlockNumber,
+}
+
+/// Parameters of a liquidity bootstrapping pool (LBP). The weights of the
+/// pool shift linearly from `start_weight` to `end_weight` between `start` and
+/// `end`, the pool prices the trading pair as a weighted pool meanwhile.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, MaxEncodedLen)]
+pub struct LBPParameters<AccountId, BlockNumber> {
+	/// The creator who supplied the liquidity of the pool and gets it back
+	/// when the pool is ended.
+	pub creator: AccountId,
+	/// The weight of token_0 when the pool starts.
+	pub start_weight: Permill,
+	/// The weight of token_0 when the pool ends.
+	pub end_weight: Permill,
+	/// The block number at which the weights start shifting.
+	pub start: BlockNumber,
+	/// The block number at which the weights stop shifting.
+	pub end: BlockNumber,
+}
+
+/// The lowest weight of one side of a liquidity bootstrapping pool.
+pub const MIN_LBP_WEIGHT: Permill = Permill::from_parts(10_000);
+
+/// The max ratio of the supply amount to the supply pool, and of the target
+/// amount to the target pool, of a swap with a liquidity bootstrapping pool,
+/// which keeps the weighted pool math accurate.
+pub const MAX_LBP_TRADE_RATIO: Permill = Permill::from_parts(300_000);
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -109,6 +152,21 @@ pub mod module {
 		#[pallet::constant]
 		type TradingPathLimit: Get<u32>;
 
+		/// The currencies which can be intermediate hops of the trading paths
+		/// found by `swap_with_best_path`, e.g. the stable currency.
+		#[pallet::constant]
+		type RoutingCurrencies: Get<Vec<CurrencyId>>;
+
+		/// The minimum number of blocks between two recorded observations of
+		/// the cumulative price of a trading pair.
+		#[pallet::constant]
+		type TWAPObservationPeriod: Get<Self::BlockNumber>;
+
+		/// The maximum number of cumulative price observations kept for a
+		/// trading pair, the oldest one is dropped first.
+		#[pallet::constant]
+		type MaxTWAPObservations: Get<u32>;
+
 		/// The DEX's module id, keep all assets in DEX.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -167,11 +225,43 @@ pub mod module {
 		UnqualifiedProvision,
 		/// Trading pair is still provisioning
 		StillProvisioning,
+		/// No trading path between the supply and target currency
+		NoAvailableTradingPath,
+		/// The exchange fee numerator must be less than the denominator
+		InvalidExchangeFee,
+		/// The provision can't be refunded, the trading pair is still
+		/// provisioning or the provisioning has succeeded
+		NotAllowedRefund,
+		/// The provisioning was aborted, the provision can only be refunded
+		ProvisioningAborted,
+		/// The provisioning deadline must be later than the current deadline
+		/// and block number
+		InvalidDeadline,
+		/// The liquidity can't be migrated to the same trading pair
+		InvalidMigration,
+		/// The caller is not a whitelisted creator of liquidity bootstrapping
+		/// pools
+		NotLBPCreator,
+		/// The weights of the liquidity bootstrapping pool are invalid
+		InvalidLBPWeight,
+		/// The end of the liquidity bootstrapping pool must be later than the
+		/// current block number
+		InvalidLBPPeriod,
+		/// The trading pair is not a liquidity bootstrapping pool
+		LBPNotFound,
+		/// The liquidity bootstrapping pool has not ended yet
+		LBPNotEnded,
+		/// The liquidity of a liquidity bootstrapping pool can only be managed
+		/// by its creator
+		LiquidityBootstrapping,
+		/// The swap amount exceeds the max trade ratio of the liquidity
+		/// bootstrapping pool
+		ExceedMaxLBPTradeRatio,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
-	#[pallet::metadata(T::AccountId = "AccountId")]
+	#[pallet::metadata(T::AccountId = "AccountId", T::BlockNumber = "BlockNumber")]
 	pub enum Event<T: Config> {
 		/// add provision success \[who, currency_id_0, contribution_0,
 		/// currency_id_1, contribution_1\]
@@ -195,6 +285,29 @@ pub mod module {
 		/// Provisioning trading pair convert to Enabled. \[trading_pair,
 		/// pool_0_amount, pool_1_amount, total_share_amount\]
 		ProvisioningToEnabled(TradingPair, Balance, Balance, Balance),
+		/// Exchange fee of trading pair updated. \[trading_pair, exchange_fee\]
+		ExchangeFeeUpdated(TradingPair, (u32, u32)),
+		/// Provisioning trading pair aborted and converted to Disabled.
+		/// \[trading_pair, accumulated_provision_0, accumulated_provision_1\]
+		ProvisioningAborted(TradingPair, Balance, Balance),
+		/// Provisioning deadline of trading pair extended. \[trading_pair,
+		/// deadline\]
+		ProvisioningDeadlineExtended(TradingPair, T::BlockNumber),
+		/// Refund provision success. \[who, currency_id_0, contribution_0,
+		/// currency_id_1, contribution_1\]
+		RefundProvision(T::AccountId, CurrencyId, Balance, CurrencyId, Balance),
+		/// Migrate liquidity between trading pairs success. \[who,
+		/// from_trading_pair, to_trading_pair, remove_share\]
+		MigrateLiquidity(T::AccountId, TradingPair, TradingPair, Balance),
+		/// The whitelist status of liquidity bootstrapping pool creator
+		/// updated. \[who, is_allowed\]
+		LBPCreatorUpdated(T::AccountId, bool),
+		/// Liquidity bootstrapping pool created. \[creator, trading_pair,
+		/// pool_0_amount, pool_1_amount\]
+		LBPCreated(T::AccountId, TradingPair, Balance, Balance),
+		/// Liquidity bootstrapping pool ended and the liquidity returned to the
+		/// creator. \[creator, trading_pair, pool_0_amount, pool_1_amount\]
+		LBPEnded(T::AccountId, TradingPair, Balance, Balance),
 	}
 
 	/// Liquidity pool for TradingPair.
@@ -229,6 +342,55 @@ pub mod module {
 	pub type InitialShareExchangeRates<T: Config> =
 		StorageMap<_, Twox64Concat, TradingPair, (ExchangeRate, ExchangeRate), ValueQuery>;
 
+	/// Cumulative prices of TradingPair, accumulated before the first
+	/// liquidity change of every block.
+	///
+	/// CumulativePrices: map TradingPair => CumulativePrice
+	#[pallet::storage]
+	#[pallet::getter(fn cumulative_prices)]
+	pub type CumulativePrices<T: Config> =
+		StorageMap<_, Twox64Concat, TradingPair, CumulativePrice<T::BlockNumber>, ValueQuery>;
+
+	/// Recorded cumulative prices of TradingPair, oldest first, at most one per
+	/// `TWAPObservationPeriod`.
+	///
+	/// PriceObservations: map TradingPair => Vec<CumulativePrice>
+	#[pallet::storage]
+	#[pallet::getter(fn price_observations)]
+	pub type PriceObservations<T: Config> =
+		StorageMap<_, Twox64Concat, TradingPair, Vec<CumulativePrice<T::BlockNumber>>, ValueQuery>;
+
+	/// Deadline of Provisioning TradingPair, after which the provisioning is
+	/// aborted on the first refund claim.
+	///
+	/// ProvisioningDeadlines: map TradingPair => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn provisioning_deadlines)]
+	pub type ProvisioningDeadlines<T: Config> = StorageMap<_, Twox64Concat, TradingPair, T::BlockNumber, OptionQuery>;
+
+	/// Exchange fee of TradingPair, overrides `GetExchangeFee`.
+	///
+	/// TradingPairExchangeFees: map TradingPair => Option<(u32, u32)>
+	#[pallet::storage]
+	#[pallet::getter(fn trading_pair_exchange_fees)]
+	pub type TradingPairExchangeFees<T: Config> = StorageMap<_, Twox64Concat, TradingPair, (u32, u32), OptionQuery>;
+
+	/// The accounts whitelisted by governance to create liquidity
+	/// bootstrapping pools.
+	///
+	/// LBPCreators: map AccountId => bool
+	#[pallet::storage]
+	#[pallet::getter(fn lbp_creators)]
+	pub type LBPCreators<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, bool, ValueQuery>;
+
+	/// Parameters of the liquidity bootstrapping pool of TradingPair.
+	///
+	/// LiquidityBootstrappingPools: map TradingPair => Option<LBPParameters>
+	#[pallet::storage]
+	#[pallet::getter(fn liquidity_bootstrapping_pools)]
+	pub type LiquidityBootstrappingPools<T: Config> =
+		StorageMap<_, Twox64Concat, TradingPair, LBPParameters<T::AccountId, T::BlockNumber>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub initial_listing_trading_pairs: Vec<(TradingPair, (Balance, Balance), (Balance, Balance), T::BlockNumber)>,
@@ -337,6 +499,50 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Trading with DEX, swap with exact target amount. The transaction
+		/// fee can be paid in the supply currency of `path`, if the runtime
+		/// configures transaction payment to swap it to the native currency.
+		///
+		/// - `path`: trading path.
+		/// - `target_amount`: exact target amount.
+		/// - `max_supply_amount`: acceptable maximum supply amount, excluding
+		///   the supply amount swapped for the fee.
+		#[pallet::weight(<T as Config>::WeightInfo::swap_with_exact_target(path.len() as u32))]
+		#[transactional]
+		pub fn swap_with_exact_target_and_fee_in_supply(
+			origin: OriginFor<T>,
+			path: Vec<CurrencyId>,
+			#[pallet::compact] target_amount: Balance,
+			#[pallet::compact] max_supply_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_swap_with_exact_target(&who, &path, target_amount, max_supply_amount)?;
+			Ok(())
+		}
+
+		/// Trading with DEX, swap with exact supply amount along the trading
+		/// path which gets the most target amount.
+		///
+		/// - `supply_currency_id`: supply currency id.
+		/// - `target_currency_id`: target currency id.
+		/// - `supply_amount`: exact supply amount.
+		/// - `min_target_amount`: acceptable minimum target amount.
+		#[pallet::weight(<T as Config>::WeightInfo::swap_with_best_path())]
+		#[transactional]
+		pub fn swap_with_best_path(
+			origin: OriginFor<T>,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			#[pallet::compact] supply_amount: Balance,
+			#[pallet::compact] min_target_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (path, _) = Self::get_best_price_swap_path(supply_currency_id, target_currency_id, supply_amount)
+				.ok_or(Error::<T>::NoAvailableTradingPath)?;
+			Self::do_swap_with_exact_supply(&who, &path, supply_amount, min_target_amount)?;
+			Ok(())
+		}
+
 		/// Add liquidity to Enabled trading pair.
 		/// - Add provision success will record the provision, issue shares to caller in the initial
 		///   exchange rate when trading pair convert to Enabled.
@@ -378,6 +584,42 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Add liquidity to Enabled trading pair with only one of the currencies,
+		/// part of the deposit is swapped to the other currency first so that both
+		/// sides are injected at the pool price.
+		///
+		/// - `currency_id`: the deposited currency id.
+		/// - `paired_currency_id`: the other currency id of the trading pair.
+		/// - `amount`: amount of currency_id to deposit.
+		/// - `min_share_increment`: minimum acceptable share amount.
+		/// - `stake_increment_share`: indicates whether to stake increased dex share to earn
+		///   incentives
+		#[pallet::weight(if *stake_increment_share {
+			<T as Config>::WeightInfo::add_liquidity_single_and_stake()
+		} else {
+			<T as Config>::WeightInfo::add_liquidity_single()
+		})]
+		#[transactional]
+		pub fn add_liquidity_single(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			paired_currency_id: CurrencyId,
+			#[pallet::compact] amount: Balance,
+			#[pallet::compact] min_share_increment: Balance,
+			stake_increment_share: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_add_liquidity_single(
+				&who,
+				currency_id,
+				paired_currency_id,
+				amount,
+				min_share_increment,
+				stake_increment_share,
+			)?;
+			Ok(())
+		}
+
 		/// Add provision to Provisioning trading pair.
 		/// If succecced, will record the provision, but shares issuing will happen after the
 		/// trading pair convert to Enabled status.
@@ -418,6 +660,25 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Claim back the provision of founders, after the provisioning of the
+		/// trading pair was aborted or its deadline has passed.
+		///
+		/// - `owner`: founder account.
+		/// - `currency_id_a`: currency id A.
+		/// - `currency_id_b`: currency id B.
+		#[pallet::weight(<T as Config>::WeightInfo::claim_provisioning_refund())]
+		#[transactional]
+		pub fn claim_provisioning_refund(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			Self::do_claim_provisioning_refund(&owner, currency_id_a, currency_id_b)?;
+			Ok(())
+		}
+
 		/// Remove liquidity from specific liquidity pool in the form of burning
 		/// shares, and withdrawing currencies in trading pairs from liquidity
 		/// pool in proportion, and withdraw liquidity incentive interest.
@@ -456,6 +717,47 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Migrate liquidity from the trading pair of `common_currency_id` and
+		/// `from_currency_id` to the trading pair of `common_currency_id` and
+		/// `to_currency_id`. The withdrawn `from_currency_id` is swapped to
+		/// `to_currency_id` by the best price path, and the unused amounts are
+		/// left to caller.
+		///
+		/// - `common_currency_id`: the currency id shared by both trading pairs.
+		/// - `from_currency_id`: the other currency id of the trading pair to migrate from.
+		/// - `to_currency_id`: the other currency id of the trading pair to migrate to.
+		/// - `remove_share`: liquidity amount to migrate.
+		/// - `min_share_increment`: minimum acceptable share amount of the new trading pair.
+		/// - `by_unstake`: this flag indicates whether to withdraw share which is on incentives,
+		///   and stake the increased share of the new trading pair.
+		#[pallet::weight(if *by_unstake {
+			<T as Config>::WeightInfo::migrate_liquidity_by_unstake()
+		} else {
+			<T as Config>::WeightInfo::migrate_liquidity()
+		})]
+		#[transactional]
+		pub fn migrate_liquidity(
+			origin: OriginFor<T>,
+			common_currency_id: CurrencyId,
+			from_currency_id: CurrencyId,
+			to_currency_id: CurrencyId,
+			#[pallet::compact] remove_share: Balance,
+			#[pallet::compact] min_share_increment: Balance,
+			by_unstake: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_migrate_liquidity(
+				&who,
+				common_currency_id,
+				from_currency_id,
+				to_currency_id,
+				remove_share,
+				min_share_increment,
+				by_unstake,
+			)?;
+			Ok(())
+		}
+
 		/// List a new provisioning trading pair.
 		#[pallet::weight((<T as Config>::WeightInfo::list_provisioning(), DispatchClass::Operational))]
 		#[transactional]
@@ -482,7 +784,8 @@ pub mod module {
 			);
 			ensure!(
 				T::Currency::total_issuance(trading_pair.dex_share_currency_id()).is_zero()
-					&& ProvisioningPool::<T>::iter_prefix(trading_pair).next().is_none(),
+					&& ProvisioningPool::<T>::iter_prefix(trading_pair).next().is_none()
+					&& !LiquidityBootstrappingPools::<T>::contains_key(trading_pair),
 				Error::<T>::NotAllowedList
 			);
 
@@ -615,6 +918,7 @@ pub mod module {
 
 					// inject provision to liquidity pool
 					LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+						Self::update_cumulative_price(trading_pair, *pool_0, *pool_1);
 						*pool_0 = pool_0.checked_add(total_provision_0).ok_or(ArithmeticError::Overflow)?;
 						*pool_1 = pool_1.checked_add(total_provision_1).ok_or(ArithmeticError::Overflow)?;
 						Ok(())
@@ -622,6 +926,7 @@ pub mod module {
 
 					// update trading_pair to Enabled status
 					TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Enabled);
+					ProvisioningDeadlines::<T>::remove(trading_pair);
 
 					// record initial exchange rate so that founders can use it to calculate their own shares
 					InitialShareExchangeRates::<T>::insert(
@@ -668,6 +973,7 @@ pub mod module {
 			}
 
 			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Enabled);
+			ProvisioningDeadlines::<T>::remove(trading_pair);
 			Self::deposit_event(Event::EnableTradingPair(trading_pair));
 			Ok(())
 		}
@@ -695,6 +1001,214 @@ pub mod module {
 			Self::deposit_event(Event::DisableTradingPair(trading_pair));
 			Ok(())
 		}
+
+		/// Abort a Provisioning trading pair, convert it to Disabled so that
+		/// founders can claim back their provision.
+		#[pallet::weight((<T as Config>::WeightInfo::abort_provisioning(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn abort_provisioning(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			Self::do_abort_provisioning(trading_pair)
+		}
+
+		/// Set or extend the deadline of a Provisioning trading pair. Founders
+		/// can claim back their provision once the deadline has passed.
+		#[pallet::weight((<T as Config>::WeightInfo::extend_provisioning_deadline(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn extend_provisioning_deadline(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			deadline: T::BlockNumber,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			ensure!(
+				matches!(
+					Self::trading_pair_statuses(trading_pair),
+					TradingPairStatus::<_, _>::Provisioning(_)
+				),
+				Error::<T>::MustBeProvisioning
+			);
+			ensure!(
+				deadline > frame_system::Pallet::<T>::block_number()
+					&& Self::provisioning_deadlines(trading_pair).map_or(true, |current| deadline > current),
+				Error::<T>::InvalidDeadline
+			);
+
+			ProvisioningDeadlines::<T>::insert(trading_pair, deadline);
+			Self::deposit_event(Event::ProvisioningDeadlineExtended(trading_pair, deadline));
+			Ok(())
+		}
+
+		/// Set the exchange fee of a trading pair, `None` falls back to
+		/// `GetExchangeFee`.
+		///
+		/// - `exchange_fee`: (numerator, denominator) of the fee rate.
+		#[pallet::weight((<T as Config>::WeightInfo::set_exchange_fee(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_exchange_fee(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			exchange_fee: Option<(u32, u32)>,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			if let Some((fee_numerator, fee_denominator)) = exchange_fee {
+				ensure!(fee_numerator < fee_denominator, Error::<T>::InvalidExchangeFee);
+			}
+
+			TradingPairExchangeFees::<T>::set(trading_pair, exchange_fee);
+			Self::deposit_event(Event::ExchangeFeeUpdated(
+				trading_pair,
+				Self::get_exchange_fee(trading_pair),
+			));
+			Ok(())
+		}
+
+		/// Add or remove an account from the whitelist of liquidity
+		/// bootstrapping pool creators.
+		#[pallet::weight((<T as Config>::WeightInfo::set_lbp_creator(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_lbp_creator(origin: OriginFor<T>, who: T::AccountId, is_allowed: bool) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			if is_allowed {
+				LBPCreators::<T>::insert(&who, true);
+			} else {
+				LBPCreators::<T>::remove(&who);
+			}
+			Self::deposit_event(Event::LBPCreatorUpdated(who, is_allowed));
+			Ok(())
+		}
+
+		/// Launch a new token by a liquidity bootstrapping pool. The liquidity
+		/// of the creator is put into the pool of a `Disabled` trading pair and
+		/// the trading pair is enabled, the weight of `currency_id_a` shifts
+		/// from `start_weight_a` to `end_weight_a` until `end`.
+		///
+		/// - `amount_a`, `amount_b`: the initial liquidity of the pool.
+		/// - `start_weight_a`, `end_weight_a`: the weights of `currency_id_a`.
+		/// - `end`: the block number at which the weights stop shifting.
+		#[pallet::weight(<T as Config>::WeightInfo::create_lbp())]
+		#[transactional]
+		pub fn create_lbp(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			#[pallet::compact] amount_a: Balance,
+			#[pallet::compact] amount_b: Balance,
+			start_weight_a: Permill,
+			end_weight_a: Permill,
+			end: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::lbp_creators(&who), Error::<T>::NotLBPCreator);
+
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			ensure!(
+				matches!(
+					Self::trading_pair_statuses(trading_pair),
+					TradingPairStatus::<_, _>::Disabled
+				),
+				Error::<T>::MustBeDisabled
+			);
+			ensure!(
+				T::Currency::total_issuance(trading_pair.dex_share_currency_id()).is_zero()
+					&& ProvisioningPool::<T>::iter_prefix(trading_pair).next().is_none()
+					&& Self::liquidity_pool(trading_pair) == (Zero::zero(), Zero::zero())
+					&& !LiquidityBootstrappingPools::<T>::contains_key(trading_pair),
+				Error::<T>::NotAllowedList
+			);
+			ensure!(
+				!amount_a.is_zero() && !amount_b.is_zero(),
+				Error::<T>::InvalidLiquidityIncrement
+			);
+			let max_weight = Permill::one().saturating_sub(MIN_LBP_WEIGHT);
+			ensure!(
+				start_weight_a >= MIN_LBP_WEIGHT
+					&& start_weight_a <= max_weight
+					&& end_weight_a >= MIN_LBP_WEIGHT
+					&& end_weight_a <= max_weight,
+				Error::<T>::InvalidLBPWeight
+			);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(end > now, Error::<T>::InvalidLBPPeriod);
+
+			let (pool_0, pool_1, start_weight, end_weight) = if currency_id_a == trading_pair.first() {
+				(amount_a, amount_b, start_weight_a, end_weight_a)
+			} else {
+				(
+					amount_b,
+					amount_a,
+					Permill::one().saturating_sub(start_weight_a),
+					Permill::one().saturating_sub(end_weight_a),
+				)
+			};
+
+			let module_account_id = Self::account_id();
+			T::Currency::transfer(trading_pair.first(), &who, &module_account_id, pool_0)?;
+			T::Currency::transfer(trading_pair.second(), &who, &module_account_id, pool_1)?;
+
+			LiquidityPool::<T>::mutate(trading_pair, |pool| {
+				Self::update_cumulative_price(trading_pair, pool.0, pool.1);
+				*pool = (pool_0, pool_1);
+			});
+			LiquidityBootstrappingPools::<T>::insert(
+				trading_pair,
+				LBPParameters {
+					creator: who.clone(),
+					start_weight,
+					end_weight,
+					start: now,
+					end,
+				},
+			);
+			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Enabled);
+
+			Self::deposit_event(Event::LBPCreated(who, trading_pair, pool_0, pool_1));
+			Ok(())
+		}
+
+		/// End a liquidity bootstrapping pool after its weights stopped
+		/// shifting, the remaining liquidity is returned to the creator and
+		/// the trading pair is disabled.
+		#[pallet::weight(<T as Config>::WeightInfo::end_lbp())]
+		#[transactional]
+		pub fn end_lbp(origin: OriginFor<T>, currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			let lbp_parameters = Self::liquidity_bootstrapping_pools(trading_pair).ok_or(Error::<T>::LBPNotFound)?;
+			ensure!(lbp_parameters.creator == who, Error::<T>::NotLBPCreator);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= lbp_parameters.end,
+				Error::<T>::LBPNotEnded
+			);
+
+			let (pool_0, pool_1) = LiquidityPool::<T>::mutate(trading_pair, |pool| {
+				Self::update_cumulative_price(trading_pair, pool.0, pool.1);
+				sp_std::mem::take(pool)
+			});
+			let module_account_id = Self::account_id();
+			T::Currency::transfer(trading_pair.first(), &module_account_id, &who, pool_0)?;
+			T::Currency::transfer(trading_pair.second(), &module_account_id, &who, pool_1)?;
+
+			LiquidityBootstrappingPools::<T>::remove(trading_pair);
+			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Disabled);
+
+			Self::deposit_event(Event::LBPEnded(who, trading_pair, pool_0, pool_1));
+			Ok(())
+		}
 	}
 }
 
@@ -716,6 +1230,11 @@ impl<T: Config> Pallet<T> {
 
 		ProvisioningPool::<T>::try_mutate_exists(trading_pair, who, |maybe_contribution| -> DispatchResult {
 			if let Some((contribution_0, contribution_1)) = maybe_contribution.take() {
+				// the provision of an aborted provisioning has no share exchange rate
+				ensure!(
+					InitialShareExchangeRates::<T>::contains_key(trading_pair),
+					Error::<T>::ProvisioningAborted
+				);
 				let (exchange_rate_0, exchange_rate_1) = Self::initial_share_exchange_rates(trading_pair);
 				let shares_from_provision_0 = exchange_rate_0
 					.checked_mul_int(contribution_0)
@@ -748,6 +1267,68 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn do_abort_provisioning(trading_pair: TradingPair) -> DispatchResult {
+		let accumulated_provision = match Self::trading_pair_statuses(trading_pair) {
+			TradingPairStatus::<_, _>::Provisioning(provisioning_parameters) => {
+				provisioning_parameters.accumulated_provision
+			}
+			_ => return Err(Error::<T>::MustBeProvisioning.into()),
+		};
+
+		TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Disabled);
+		ProvisioningDeadlines::<T>::remove(trading_pair);
+		Self::deposit_event(Event::ProvisioningAborted(
+			trading_pair,
+			accumulated_provision.0,
+			accumulated_provision.1,
+		));
+		Ok(())
+	}
+
+	fn do_claim_provisioning_refund(
+		who: &T::AccountId,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+	) -> DispatchResult {
+		let trading_pair =
+			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+
+		// abort the provisioning on the first refund claim after its deadline
+		if let TradingPairStatus::<_, _>::Provisioning(_) = Self::trading_pair_statuses(trading_pair) {
+			ensure!(
+				Self::provisioning_deadlines(trading_pair)
+					.map_or(false, |deadline| frame_system::Pallet::<T>::block_number() > deadline),
+				Error::<T>::NotAllowedRefund
+			);
+			Self::do_abort_provisioning(trading_pair)?;
+		}
+		// the provision of a succeeded provisioning is claimed as dex share
+		ensure!(
+			!InitialShareExchangeRates::<T>::contains_key(trading_pair),
+			Error::<T>::NotAllowedRefund
+		);
+
+		ProvisioningPool::<T>::try_mutate_exists(trading_pair, who, |maybe_contribution| -> DispatchResult {
+			if let Some((contribution_0, contribution_1)) = maybe_contribution.take() {
+				let module_account_id = Self::account_id();
+				T::Currency::transfer(trading_pair.first(), &module_account_id, who, contribution_0)?;
+				T::Currency::transfer(trading_pair.second(), &module_account_id, who, contribution_1)?;
+
+				// decrease ref count
+				frame_system::Pallet::<T>::dec_consumers(who);
+
+				Self::deposit_event(Event::RefundProvision(
+					who.clone(),
+					trading_pair.first(),
+					contribution_0,
+					trading_pair.second(),
+					contribution_1,
+				));
+			}
+			Ok(())
+		})
+	}
+
 	fn do_add_provision(
 		who: &T::AccountId,
 		currency_id_a: CurrencyId,
@@ -842,6 +1423,10 @@ impl<T: Config> Pallet<T> {
 			),
 			Error::<T>::MustBeEnabled,
 		);
+		ensure!(
+			!LiquidityBootstrappingPools::<T>::contains_key(trading_pair),
+			Error::<T>::LiquidityBootstrapping
+		);
 
 		ensure!(
 			!max_amount_a.is_zero() && !max_amount_b.is_zero(),
@@ -849,6 +1434,7 @@ impl<T: Config> Pallet<T> {
 		);
 
 		LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+			Self::update_cumulative_price(trading_pair, *pool_0, *pool_1);
 			let dex_share_currency_id = trading_pair.dex_share_currency_id();
 			let total_shares = T::Currency::total_issuance(dex_share_currency_id);
 			let (max_amount_0, max_amount_1) = if currency_id_a == trading_pair.first() {
@@ -938,6 +1524,63 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Get how much of `amount` should be swapped, so that the rest and the
+	/// swapped target amount match the pool price after the swap.
+	fn get_single_side_swap_amount(supply_pool: Balance, amount: Balance, exchange_fee: (u32, u32)) -> Balance {
+		// the positive root of `r * swap^2 + pool * (1 + r) * swap - pool * amount = 0`,
+		// r = 1 - fee_rate, scaled by fee_denominator to stay in integers:
+		// `(sqrt((pool * (2d - n))^2 + 4 * (d - n) * d * pool * amount) - pool * (2d - n)) / 2(d - n)`
+		let (fee_numerator, fee_denominator) = exchange_fee;
+		let pool = U256::from(supply_pool);
+		let fee_sum = U256::from(fee_denominator.saturating_mul(2).saturating_sub(fee_numerator));
+		let fee_rate = U256::from(fee_denominator.saturating_sub(fee_numerator));
+
+		let pool_fee_sum = pool.saturating_mul(fee_sum);
+		let root = pool_fee_sum
+			.saturating_mul(pool_fee_sum)
+			.saturating_add(
+				U256::from(4u8)
+					.saturating_mul(fee_rate)
+					.saturating_mul(U256::from(fee_denominator))
+					.saturating_mul(pool)
+					.saturating_mul(U256::from(amount)),
+			)
+			.integer_sqrt();
+
+		root.saturating_sub(pool_fee_sum)
+			.checked_div(U256::from(2u8).saturating_mul(fee_rate))
+			.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+			.unwrap_or_else(Zero::zero)
+	}
+
+	#[transactional]
+	fn do_add_liquidity_single(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		paired_currency_id: CurrencyId,
+		amount: Balance,
+		min_share_increment: Balance,
+		stake_increment_share: bool,
+	) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::InvalidLiquidityIncrement);
+		let (supply_pool, _) = Self::get_liquidity(currency_id, paired_currency_id);
+		let trading_pair =
+			TradingPair::from_currency_ids(currency_id, paired_currency_id).ok_or(Error::<T>::InvalidCurrencyId)?;
+		let swap_amount = Self::get_single_side_swap_amount(supply_pool, amount, Self::get_exchange_fee(trading_pair));
+		let paired_amount =
+			Self::do_swap_with_exact_supply(who, &[currency_id, paired_currency_id], swap_amount, Zero::zero())?;
+
+		Self::do_add_liquidity(
+			who,
+			currency_id,
+			paired_currency_id,
+			amount.saturating_sub(swap_amount),
+			paired_amount,
+			min_share_increment,
+			stake_increment_share,
+		)
+	}
+
 	#[transactional]
 	fn do_remove_liquidity(
 		who: &T::AccountId,
@@ -947,15 +1590,16 @@ impl<T: Config> Pallet<T> {
 		min_withdrawn_a: Balance,
 		min_withdrawn_b: Balance,
 		by_unstake: bool,
-	) -> DispatchResult {
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
 		if remove_share.is_zero() {
-			return Ok(());
+			return Ok((Zero::zero(), Zero::zero()));
 		}
 		let trading_pair =
 			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
 		let dex_share_currency_id = trading_pair.dex_share_currency_id();
 
-		LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+		LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> Result<_, DispatchError> {
+			Self::update_cumulative_price(trading_pair, *pool_0, *pool_1);
 			let (min_withdrawn_0, min_withdrawn_1) = if currency_id_a == trading_pair.first() {
 				(min_withdrawn_a, min_withdrawn_b)
 			} else {
@@ -991,10 +1635,163 @@ impl<T: Config> Pallet<T> {
 				pool_1_decrement,
 				remove_share,
 			));
-			Ok(())
+
+			if currency_id_a == trading_pair.first() {
+				Ok((pool_0_decrement, pool_1_decrement))
+			} else {
+				Ok((pool_1_decrement, pool_0_decrement))
+			}
 		})
 	}
 
+	#[transactional]
+	fn do_migrate_liquidity(
+		who: &T::AccountId,
+		common_currency_id: CurrencyId,
+		from_currency_id: CurrencyId,
+		to_currency_id: CurrencyId,
+		remove_share: Balance,
+		min_share_increment: Balance,
+		by_unstake: bool,
+	) -> DispatchResult {
+		ensure!(from_currency_id != to_currency_id, Error::<T>::InvalidMigration);
+		let from_trading_pair = TradingPair::from_currency_ids(common_currency_id, from_currency_id)
+			.ok_or(Error::<T>::InvalidCurrencyId)?;
+		let to_trading_pair =
+			TradingPair::from_currency_ids(common_currency_id, to_currency_id).ok_or(Error::<T>::InvalidCurrencyId)?;
+		ensure!(!remove_share.is_zero(), Error::<T>::InvalidLiquidityIncrement);
+
+		let (common_amount, from_amount) = Self::do_remove_liquidity(
+			who,
+			common_currency_id,
+			from_currency_id,
+			remove_share,
+			Zero::zero(),
+			Zero::zero(),
+			by_unstake,
+		)?;
+		let (path, _) = Self::get_best_price_swap_path(from_currency_id, to_currency_id, from_amount)
+			.ok_or(Error::<T>::NoAvailableTradingPath)?;
+		let to_amount = Self::do_swap_with_exact_supply(who, &path, from_amount, Zero::zero())?;
+
+		// the slippage of the swap is guarded by `min_share_increment`
+		Self::do_add_liquidity(
+			who,
+			common_currency_id,
+			to_currency_id,
+			common_amount,
+			to_amount,
+			min_share_increment,
+			by_unstake,
+		)?;
+
+		Self::deposit_event(Event::MigrateLiquidity(
+			who.clone(),
+			from_trading_pair,
+			to_trading_pair,
+			remove_share,
+		));
+		Ok(())
+	}
+
+	/// Accumulate the prices of `pool_0` and `pool_1` from the last
+	/// accumulation of `cumulative_price` up to block `now`.
+	fn accumulate_price(
+		cumulative_price: CumulativePrice<T::BlockNumber>,
+		pool_0: Balance,
+		pool_1: Balance,
+		now: T::BlockNumber,
+	) -> CumulativePrice<T::BlockNumber> {
+		let elapsed = U256::from(
+			now.saturating_sub(cumulative_price.block_number)
+				.saturated_into::<u128>(),
+		);
+		let mut accumulated = CumulativePrice {
+			block_number: now,
+			..cumulative_price
+		};
+
+		if let (Some(price_0), Some(price_1)) = (
+			ExchangeRate::checked_from_rational(pool_1, pool_0),
+			ExchangeRate::checked_from_rational(pool_0, pool_1),
+		) {
+			accumulated.price_0_cumulative = cumulative_price
+				.price_0_cumulative
+				.overflowing_add(U256::from(price_0.into_inner()).overflowing_mul(elapsed).0)
+				.0;
+			accumulated.price_1_cumulative = cumulative_price
+				.price_1_cumulative
+				.overflowing_add(U256::from(price_1.into_inner()).overflowing_mul(elapsed).0)
+				.0;
+		}
+
+		accumulated
+	}
+
+	/// Accumulate the prices of `trading_pair` before its pools change for the
+	/// first time in this block, and record an observation if one is due.
+	fn update_cumulative_price(trading_pair: TradingPair, pool_0: Balance, pool_1: Balance) {
+		let now = frame_system::Pallet::<T>::block_number();
+		let cumulative_price = Self::cumulative_prices(trading_pair);
+		if cumulative_price.block_number == now {
+			return;
+		}
+
+		let cumulative_price = Self::accumulate_price(cumulative_price, pool_0, pool_1, now);
+		CumulativePrices::<T>::insert(trading_pair, cumulative_price);
+
+		let max_observations: usize = T::MaxTWAPObservations::get().saturated_into();
+		if max_observations.is_zero() {
+			return;
+		}
+		PriceObservations::<T>::mutate(trading_pair, |observations| {
+			let due = observations.last().map_or(true, |last| {
+				now.saturating_sub(last.block_number) >= T::TWAPObservationPeriod::get()
+			});
+			if due {
+				observations.push(cumulative_price);
+				if observations.len() > max_observations {
+					observations.remove(0);
+				}
+			}
+		});
+	}
+
+	/// Get the time weighted average price of `currency_id_a` in
+	/// `currency_id_b` over at least the last `window` blocks, starting from
+	/// the latest recorded observation old enough. Returns `None` if there's
+	/// no such observation.
+	pub fn get_twap(
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		window: T::BlockNumber,
+	) -> Option<ExchangeRate> {
+		let trading_pair = TradingPair::from_currency_ids(currency_id_a, currency_id_b)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let start = Self::price_observations(trading_pair)
+			.into_iter()
+			.rev()
+			.find(|observation| {
+				observation.block_number < now && observation.block_number.saturating_add(window) <= now
+			})?;
+		let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+		let end = Self::accumulate_price(Self::cumulative_prices(trading_pair), pool_0, pool_1, now);
+
+		let (end_cumulative, start_cumulative) = if currency_id_a == trading_pair.first() {
+			(end.price_0_cumulative, start.price_0_cumulative)
+		} else {
+			(end.price_1_cumulative, start.price_1_cumulative)
+		};
+		let elapsed = U256::from(now.saturating_sub(start.block_number).saturated_into::<u128>());
+		let average = end_cumulative
+			.overflowing_sub(start_cumulative)
+			.0
+			.checked_div(elapsed)
+			.and_then(|n| TryInto::<u128>::try_into(n).ok())?;
+
+		Some(ExchangeRate::from_inner(average))
+	}
+
 	fn get_liquidity(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
 		if let Some(trading_pair) = TradingPair::from_currency_ids(currency_id_a, currency_id_b) {
 			let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
@@ -1008,12 +1805,22 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Get the exchange fee of trading pair, the `GetExchangeFee` if not set.
+	pub fn get_exchange_fee(trading_pair: TradingPair) -> (u32, u32) {
+		Self::trading_pair_exchange_fees(trading_pair).unwrap_or_else(T::GetExchangeFee::get)
+	}
+
 	/// Get how much target amount will be got for specific supply amount.
-	fn get_target_amount(supply_pool: Balance, target_pool: Balance, supply_amount: Balance) -> Balance {
+	fn get_target_amount(
+		supply_pool: Balance,
+		target_pool: Balance,
+		supply_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
 		if supply_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
 			Zero::zero()
 		} else {
-			let (fee_numerator, fee_denominator) = T::GetExchangeFee::get();
+			let (fee_numerator, fee_denominator) = exchange_fee;
 			let supply_amount_with_fee: U256 =
 				U256::from(supply_amount).saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)));
 			let numerator: U256 = supply_amount_with_fee.saturating_mul(U256::from(target_pool));
@@ -1029,11 +1836,16 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Get how much supply amount will be paid for specific target amount.
-	fn get_supply_amount(supply_pool: Balance, target_pool: Balance, target_amount: Balance) -> Balance {
+	fn get_supply_amount(
+		supply_pool: Balance,
+		target_pool: Balance,
+		target_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
 		if target_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
 			Zero::zero()
 		} else {
-			let (fee_numerator, fee_denominator) = T::GetExchangeFee::get();
+			let (fee_numerator, fee_denominator) = exchange_fee;
 			let numerator: U256 = U256::from(supply_pool)
 				.saturating_mul(U256::from(target_amount))
 				.saturating_mul(U256::from(fee_denominator));
@@ -1049,6 +1861,191 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Get the current weights of `supply_currency_id` and
+	/// `target_currency_id` if their trading pair is a liquidity bootstrapping
+	/// pool.
+	pub fn get_lbp_weights(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+	) -> Option<(Permill, Permill)> {
+		let trading_pair = TradingPair::from_currency_ids(supply_currency_id, target_currency_id)?;
+		let lbp_parameters = Self::liquidity_bootstrapping_pools(trading_pair)?;
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let weight_0 = if now >= lbp_parameters.end {
+			lbp_parameters.end_weight
+		} else {
+			let elapsed: u128 = now.saturating_sub(lbp_parameters.start).saturated_into();
+			let duration: u128 = lbp_parameters.end.saturating_sub(lbp_parameters.start).saturated_into();
+			let (start_weight, end_weight): (u128, u128) = (
+				lbp_parameters.start_weight.deconstruct().into(),
+				lbp_parameters.end_weight.deconstruct().into(),
+			);
+			let parts = if end_weight >= start_weight {
+				start_weight.saturating_add(end_weight.saturating_sub(start_weight).saturating_mul(elapsed) / duration)
+			} else {
+				start_weight.saturating_sub(start_weight.saturating_sub(end_weight).saturating_mul(elapsed) / duration)
+			};
+			Permill::from_parts(parts.saturated_into())
+		};
+		let weight_1 = Permill::one().saturating_sub(weight_0);
+
+		if supply_currency_id == trading_pair.first() {
+			Some((weight_0, weight_1))
+		} else {
+			Some((weight_1, weight_0))
+		}
+	}
+
+	/// Calculate `base ^ exp`, the fractional part of `exp` is approximated by
+	/// the binomial series, which converges fast for `base` close to one.
+	fn weighted_pow(base: FixedU128, exp: FixedU128) -> Option<FixedU128> {
+		let accuracy = FixedU128::accuracy();
+		let whole_exp: usize = (exp.into_inner() / accuracy).saturated_into();
+		let remain_exp = FixedU128::from_inner(exp.into_inner() % accuracy);
+		let whole_pow = base.saturating_pow(whole_exp);
+		if remain_exp.is_zero() {
+			return Some(whole_pow);
+		}
+
+		// base ^ remain_exp = sum of C(remain_exp, k) * (base - 1) ^ k
+		let one = FixedU128::one();
+		let (x, x_negative) = if base >= one {
+			(base - one, false)
+		} else {
+			(one - base, true)
+		};
+		let precision = FixedU128::from_inner(accuracy / 1_000_000_000_000);
+		let mut term = one;
+		let mut sum = one;
+		let mut negative = false;
+		for k in 1..=100u128 {
+			let big_k = FixedU128::saturating_from_integer(k);
+			let k_minus_one = FixedU128::saturating_from_integer(k - 1);
+			let (c, c_negative) = if remain_exp >= k_minus_one {
+				(remain_exp - k_minus_one, false)
+			} else {
+				(k_minus_one - remain_exp, true)
+			};
+			term = term.checked_mul(&c.checked_mul(&x)?)?.checked_div(&big_k)?;
+			if term < precision {
+				break;
+			}
+			if x_negative {
+				negative = !negative;
+			}
+			if c_negative {
+				negative = !negative;
+			}
+			sum = if negative {
+				sum.checked_sub(&term)?
+			} else {
+				sum.checked_add(&term)?
+			};
+		}
+
+		whole_pow.checked_mul(&sum)
+	}
+
+	/// Get how much target amount will be got for specific supply amount
+	/// from a weighted pool:
+	/// target_amount = target_pool * (1 - (supply_pool / (supply_pool +
+	/// supply_amount_with_fee)) ^ (supply_weight / target_weight))
+	fn get_lbp_target_amount(
+		supply_pool: Balance,
+		target_pool: Balance,
+		(supply_weight, target_weight): (Permill, Permill),
+		supply_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
+		if supply_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() || target_weight.is_zero() {
+			return Zero::zero();
+		}
+
+		let (fee_numerator, fee_denominator) = exchange_fee;
+		let supply_amount_with_fee = U256::from(supply_amount)
+			.saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)))
+			.checked_div(U256::from(fee_denominator))
+			.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+			.unwrap_or_else(Zero::zero);
+
+		FixedU128::checked_from_rational(supply_pool, supply_pool.saturating_add(supply_amount_with_fee))
+			.zip(FixedU128::checked_from_rational(
+				supply_weight.deconstruct(),
+				target_weight.deconstruct(),
+			))
+			.and_then(|(base, exp)| Self::weighted_pow(base, exp))
+			.map(|pow| FixedU128::one().saturating_sub(pow).saturating_mul_int(target_pool))
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Get how much supply amount will be paid for specific target amount
+	/// from a weighted pool:
+	/// supply_amount = supply_pool * ((target_pool / (target_pool -
+	/// target_amount)) ^ (target_weight / supply_weight) - 1) / (1 - fee_rate)
+	fn get_lbp_supply_amount(
+		supply_pool: Balance,
+		target_pool: Balance,
+		(supply_weight, target_weight): (Permill, Permill),
+		target_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
+		if target_amount.is_zero() || supply_pool.is_zero() || target_pool <= target_amount || supply_weight.is_zero() {
+			return Zero::zero();
+		}
+
+		let (fee_numerator, fee_denominator) = exchange_fee;
+		FixedU128::checked_from_rational(target_pool, target_pool - target_amount)
+			.zip(FixedU128::checked_from_rational(
+				target_weight.deconstruct(),
+				supply_weight.deconstruct(),
+			))
+			.and_then(|(base, exp)| Self::weighted_pow(base, exp))
+			.map(|pow| pow.saturating_sub(FixedU128::one()).saturating_mul_int(supply_pool))
+			.and_then(|supply_amount_with_fee| {
+				U256::from(supply_amount_with_fee)
+					.saturating_mul(U256::from(fee_denominator))
+					.checked_div(U256::from(fee_denominator.saturating_sub(fee_numerator)))
+			})
+			.and_then(|r| r.checked_add(U256::one())) // add 1 to result so that correct the possible losses caused by remainder discarding in
+			.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Ensure the weighted pool invariant `supply_pool ^ supply_weight *
+	/// target_pool ^ target_weight` doesn't decrease by a swap, that is the
+	/// supply increment covers the supply amount without fee for the target
+	/// decrement.
+	fn ensure_lbp_invariant(
+		supply_pool: Balance,
+		target_pool: Balance,
+		(supply_weight, target_weight): (Permill, Permill),
+		supply_increment: Balance,
+		target_decrement: Balance,
+	) -> DispatchResult {
+		if target_decrement.is_zero() {
+			return Ok(());
+		}
+		ensure!(
+			target_decrement < target_pool && !supply_weight.is_zero(),
+			Error::<T>::InvariantCheckFailed
+		);
+
+		let required_supply_amount = FixedU128::checked_from_rational(target_pool, target_pool - target_decrement)
+			.zip(FixedU128::checked_from_rational(
+				target_weight.deconstruct(),
+				supply_weight.deconstruct(),
+			))
+			.and_then(|(base, exp)| Self::weighted_pow(base, exp))
+			.map(|pow| pow.saturating_sub(FixedU128::one()).saturating_mul_int(supply_pool))
+			.ok_or(Error::<T>::InvariantCheckFailed)?;
+		ensure!(
+			supply_increment >= required_supply_amount,
+			Error::<T>::InvariantCheckFailed
+		);
+		Ok(())
+	}
+
 	fn get_target_amounts(
 		path: &[CurrencyId],
 		supply_amount: Balance,
@@ -1077,7 +2074,27 @@ impl<T: Config> Pallet<T> {
 				!supply_pool.is_zero() && !target_pool.is_zero(),
 				Error::<T>::InsufficientLiquidity
 			);
-			let target_amount = Self::get_target_amount(supply_pool, target_pool, target_amounts[i]);
+			let target_amount = match Self::get_lbp_weights(path[i], path[i + 1]) {
+				Some(weights) => {
+					ensure!(
+						target_amounts[i] <= MAX_LBP_TRADE_RATIO * supply_pool,
+						Error::<T>::ExceedMaxLBPTradeRatio
+					);
+					Self::get_lbp_target_amount(
+						supply_pool,
+						target_pool,
+						weights,
+						target_amounts[i],
+						Self::get_exchange_fee(trading_pair),
+					)
+				}
+				None => Self::get_target_amount(
+					supply_pool,
+					target_pool,
+					target_amounts[i],
+					Self::get_exchange_fee(trading_pair),
+				),
+			};
 			ensure!(!target_amount.is_zero(), Error::<T>::ZeroTargetAmount);
 
 			target_amounts[i + 1] = target_amount;
@@ -1115,7 +2132,27 @@ impl<T: Config> Pallet<T> {
 				!supply_pool.is_zero() && !target_pool.is_zero(),
 				Error::<T>::InsufficientLiquidity
 			);
-			let supply_amount = Self::get_supply_amount(supply_pool, target_pool, supply_amounts[i]);
+			let supply_amount = match Self::get_lbp_weights(path[i - 1], path[i]) {
+				Some(weights) => {
+					ensure!(
+						supply_amounts[i] <= MAX_LBP_TRADE_RATIO * target_pool,
+						Error::<T>::ExceedMaxLBPTradeRatio
+					);
+					Self::get_lbp_supply_amount(
+						supply_pool,
+						target_pool,
+						weights,
+						supply_amounts[i],
+						Self::get_exchange_fee(trading_pair),
+					)
+				}
+				None => Self::get_supply_amount(
+					supply_pool,
+					target_pool,
+					supply_amounts[i],
+					Self::get_exchange_fee(trading_pair),
+				),
+			};
 			ensure!(!supply_amount.is_zero(), Error::<T>::ZeroSupplyAmount);
 
 			supply_amounts[i - 1] = supply_amount;
@@ -1125,6 +2162,125 @@ impl<T: Config> Pallet<T> {
 		Ok(supply_amounts)
 	}
 
+	/// Get the trading path from `supply_currency_id` to `target_currency_id`
+	/// which gets the most target amount for `supply_amount`, and that target
+	/// amount. Paths may hop through `RoutingCurrencies` within the
+	/// `TradingPathLimit`, the shorter path is preferred at the same price.
+	pub fn get_best_price_swap_path(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+	) -> Option<(Vec<CurrencyId>, Balance)> {
+		Self::get_candidate_swap_paths(supply_currency_id, target_currency_id)
+			.into_iter()
+			.filter_map(|path| {
+				Self::get_target_amounts(&path, supply_amount)
+					.ok()
+					.map(|amounts| (path, amounts[amounts.len() - 1]))
+			})
+			.fold(None, |best, (path, target_amount)| match best {
+				Some((_, best_target_amount)) if best_target_amount >= target_amount => best,
+				_ => Some((path, target_amount)),
+			})
+	}
+
+	/// The trading paths from `supply_currency_id` to `target_currency_id`
+	/// hopping through `RoutingCurrencies` within the `TradingPathLimit`.
+	fn get_candidate_swap_paths(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+	) -> Vec<Vec<CurrencyId>> {
+		let routing_currencies = T::RoutingCurrencies::get();
+		let path_limit: usize = T::TradingPathLimit::get().saturated_into();
+		let is_intermediate =
+			|currency_id: &CurrencyId| *currency_id != supply_currency_id && *currency_id != target_currency_id;
+
+		let mut paths: Vec<Vec<CurrencyId>> = vec![vec![supply_currency_id, target_currency_id]];
+		for hop_0 in routing_currencies.iter().filter(|c| is_intermediate(*c)) {
+			paths.push(vec![supply_currency_id, *hop_0, target_currency_id]);
+			for hop_1 in routing_currencies.iter().filter(|c| is_intermediate(*c) && *c != hop_0) {
+				paths.push(vec![supply_currency_id, *hop_0, *hop_1, target_currency_id]);
+			}
+		}
+		paths.retain(|path| path.len() <= path_limit);
+		paths
+	}
+
+	/// Quote of swapping `amount` along `path`.
+	pub fn get_swap_quote(
+		path: &[CurrencyId],
+		amount: Balance,
+		mode: SwapMode,
+	) -> Option<SwapQuote<CurrencyId, Balance>> {
+		let amounts = match mode {
+			SwapMode::ExactSupply => Self::get_target_amounts(path, amount).ok()?,
+			SwapMode::ExactTarget => Self::get_supply_amounts(path, amount).ok()?,
+		};
+		SwapQuote::from_hops(Self::get_swap_hops(path, &amounts))
+	}
+
+	/// Quote of swapping `amount` from `supply_currency_id` to
+	/// `target_currency_id` along the trading path with the best price.
+	pub fn get_best_swap_quote(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		amount: Balance,
+		mode: SwapMode,
+	) -> Option<SwapQuote<CurrencyId, Balance>> {
+		Self::get_candidate_swap_paths(supply_currency_id, target_currency_id)
+			.into_iter()
+			.filter_map(|path| Self::get_swap_quote(&path, amount, mode))
+			.fold(None, |best, quote| match best {
+				Some(best) if !quote.is_better_than(&best, mode) => Some(best),
+				_ => Some(quote),
+			})
+	}
+
+	/// The hops of swapping along `path`, where `amounts` are the amounts of
+	/// the currencies of the path.
+	fn get_swap_hops(path: &[CurrencyId], amounts: &[Balance]) -> Vec<SwapHop<CurrencyId, Balance>> {
+		path.windows(2)
+			.zip(amounts.windows(2))
+			.map(|(currency_ids, hop_amounts)| {
+				let (supply_currency_id, target_currency_id) = (currency_ids[0], currency_ids[1]);
+				let (supply_amount, target_amount) = (hop_amounts[0], hop_amounts[1]);
+				let (fee_numerator, fee_denominator) =
+					TradingPair::from_currency_ids(supply_currency_id, target_currency_id)
+						.map(Self::get_exchange_fee)
+						.unwrap_or_else(T::GetExchangeFee::get);
+				let fee = Ratio::checked_from_rational(fee_numerator, fee_denominator)
+					.unwrap_or_default()
+					.saturating_mul_int(supply_amount);
+
+				// the spot price of the weighted pool is scaled by the weights
+				let (supply_pool, target_pool) = Self::get_liquidity(supply_currency_id, target_currency_id);
+				let mut spot_price = Ratio::checked_from_rational(target_pool, supply_pool).unwrap_or_default();
+				if let Some((supply_weight, target_weight)) =
+					Self::get_lbp_weights(supply_currency_id, target_currency_id)
+				{
+					spot_price = spot_price.saturating_mul(
+						Ratio::checked_from_rational(supply_weight.deconstruct(), target_weight.deconstruct())
+							.unwrap_or_default(),
+					);
+				}
+				let spot_target_amount = spot_price.saturating_mul_int(supply_amount.saturating_sub(fee));
+				let price_impact = Ratio::checked_from_rational(target_amount, spot_target_amount)
+					.map(|ratio| Ratio::one().saturating_sub(ratio))
+					.unwrap_or_default();
+
+				SwapHop {
+					venue: SwapVenue::Dex,
+					supply_currency_id,
+					target_currency_id,
+					supply_amount,
+					target_amount,
+					fee,
+					price_impact,
+				}
+			})
+			.collect()
+	}
+
 	fn _swap(
 		supply_currency_id: CurrencyId,
 		target_currency_id: CurrencyId,
@@ -1133,7 +2289,13 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		if let Some(trading_pair) = TradingPair::from_currency_ids(supply_currency_id, target_currency_id) {
 			LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+				Self::update_cumulative_price(trading_pair, *pool_0, *pool_1);
 				let invariant_before_swap: U256 = U256::from(*pool_0).saturating_mul(U256::from(*pool_1));
+				let (supply_pool, target_pool) = if supply_currency_id == trading_pair.first() {
+					(*pool_0, *pool_1)
+				} else {
+					(*pool_1, *pool_0)
+				};
 
 				if supply_currency_id == trading_pair.first() {
 					*pool_0 = pool_0.checked_add(supply_increment).ok_or(ArithmeticError::Overflow)?;
@@ -1143,12 +2305,17 @@ impl<T: Config> Pallet<T> {
 					*pool_1 = pool_1.checked_add(supply_increment).ok_or(ArithmeticError::Overflow)?;
 				}
 
-				// invariant check to ensure the constant product formulas (k = x * y)
-				let invariant_after_swap: U256 = U256::from(*pool_0).saturating_mul(U256::from(*pool_1));
-				ensure!(
-					invariant_after_swap >= invariant_before_swap,
-					Error::<T>::InvariantCheckFailed,
-				);
+				// invariant check to ensure the constant product formulas (k = x * y), or the
+				// weighted pool formulas (k = x ^ wx * y ^ wy) of liquidity bootstrapping pools.
+				if let Some(weights) = Self::get_lbp_weights(supply_currency_id, target_currency_id) {
+					Self::ensure_lbp_invariant(supply_pool, target_pool, weights, supply_increment, target_decrement)?;
+				} else {
+					let invariant_after_swap: U256 = U256::from(*pool_0).saturating_mul(U256::from(*pool_1));
+					ensure!(
+						invariant_after_swap >= invariant_before_swap,
+						Error::<T>::InvariantCheckFailed,
+					);
+				}
 				Ok(())
 			})?;
 		}
@@ -1299,6 +2466,20 @@ impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 			min_withdrawn_a,
 			min_withdrawn_b,
 			by_unstake,
-		)
+		)?;
+		Ok(())
+	}
+
+	fn get_swap_quote(path: &[CurrencyId], amount: Balance, mode: SwapMode) -> Option<SwapQuote<CurrencyId, Balance>> {
+		Self::get_swap_quote(path, amount, mode)
+	}
+
+	fn get_best_swap_quote(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		amount: Balance,
+		mode: SwapMode,
+	) -> Option<SwapQuote<CurrencyId, Balance>> {
+		Self::get_best_swap_quote(supply_currency_id, target_currency_id, amount, mode)
 	}
 }
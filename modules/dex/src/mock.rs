@@ -115,6 +115,9 @@ ord_parameter_types! {
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 100);
 	pub const TradingPathLimit: u32 = 3;
+	pub RoutingCurrencies: Vec<CurrencyId> = vec![AUSD, DOT];
+	pub const TWAPObservationPeriod: BlockNumber = 10;
+	pub const MaxTWAPObservations: u32 = 3;
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 }
 
@@ -123,6 +126,9 @@ impl Config for Runtime {
 	type Currency = Tokens;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type RoutingCurrencies = RoutingCurrencies;
+	type TWAPObservationPeriod = TWAPObservationPeriod;
+	type MaxTWAPObservations = MaxTWAPObservations;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = ();
 	type WeightInfo = ();
@@ -23,8 +23,8 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-	AUSDBTCPair, AUSDDOTPair, DexModule, Event, ExtBuilder, ListingOrigin, Origin, Runtime, System, Tokens, ACA, ALICE,
-	AUSD, BOB, BTC, DOT,
+	AUSDBTCPair, AUSDDOTPair, DexModule, Event, ExtBuilder, GetExchangeFee, ListingOrigin, Origin, Runtime, System,
+	Tokens, ACA, ALICE, AUSD, BOB, BTC, DOT,
 };
 use orml_traits::MultiReservableCurrency;
 use sp_runtime::traits::BadOrigin;
@@ -426,6 +426,68 @@ fn disable_trading_pair_work() {
 	});
 }
 
+#[test]
+fn set_exchange_fee_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			LiquidityPool::<Runtime>::insert(AUSDDOTPair::get(), (50000, 10000));
+			assert_eq!(DexModule::get_exchange_fee(AUSDDOTPair::get()), (1, 100));
+
+			assert_noop!(
+				DexModule::set_exchange_fee(Origin::signed(ALICE), AUSD, DOT, Some((2, 100))),
+				BadOrigin
+			);
+			assert_noop!(
+				DexModule::set_exchange_fee(Origin::signed(ListingOrigin::get()), AUSD, AUSD, Some((2, 100))),
+				Error::<Runtime>::InvalidCurrencyId
+			);
+			assert_noop!(
+				DexModule::set_exchange_fee(Origin::signed(ListingOrigin::get()), AUSD, DOT, Some((100, 100))),
+				Error::<Runtime>::InvalidExchangeFee
+			);
+
+			assert_ok!(DexModule::set_exchange_fee(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				Some((2, 100))
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::ExchangeFeeUpdated(
+				AUSDDOTPair::get(),
+				(2, 100),
+			)));
+			assert_eq!(
+				DexModule::trading_pair_exchange_fees(AUSDDOTPair::get()),
+				Some((2, 100))
+			);
+			assert_eq!(DexModule::get_exchange_fee(AUSDDOTPair::get()), (2, 100));
+			assert_eq!(DexModule::get_exchange_fee(AUSDBTCPair::get()), (1, 100));
+			assert_eq!(
+				DexModule::get_target_amounts(&[DOT, AUSD], 10000),
+				Ok(vec![10000, 24747])
+			);
+
+			assert_ok!(DexModule::set_exchange_fee(
+				Origin::signed(ListingOrigin::get()),
+				DOT,
+				AUSD,
+				None
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::ExchangeFeeUpdated(
+				AUSDDOTPair::get(),
+				(1, 100),
+			)));
+			assert_eq!(DexModule::trading_pair_exchange_fees(AUSDDOTPair::get()), None);
+			assert_eq!(
+				DexModule::get_target_amounts(&[DOT, AUSD], 10000),
+				Ok(vec![10000, 24874])
+			);
+		});
+}
+
 #[test]
 fn add_provision_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -610,6 +672,201 @@ fn claim_dex_share_work() {
 	});
 }
 
+#[test]
+fn abort_provisioning_and_claim_refund_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(DexModule::list_provisioning(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			5_000_000_000_000u128,
+			1_000_000_000_000u128,
+			5_000_000_000_000_000u128,
+			1_000_000_000_000_000u128,
+			0,
+		));
+		assert_ok!(DexModule::add_provision(
+			Origin::signed(ALICE),
+			AUSD,
+			DOT,
+			1_000_000_000_000_000u128,
+			200_000_000_000_000u128,
+		));
+
+		assert_noop!(
+			DexModule::claim_provisioning_refund(Origin::signed(BOB), ALICE, AUSD, DOT),
+			Error::<Runtime>::NotAllowedRefund
+		);
+		assert_noop!(
+			DexModule::abort_provisioning(Origin::signed(ALICE), AUSD, DOT),
+			BadOrigin
+		);
+
+		assert_ok!(DexModule::abort_provisioning(
+			Origin::signed(ListingOrigin::get()),
+			DOT,
+			AUSD
+		));
+		assert_eq!(
+			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
+			TradingPairStatus::<_, _>::Disabled
+		);
+		System::assert_last_event(Event::DexModule(crate::Event::ProvisioningAborted(
+			AUSDDOTPair::get(),
+			1_000_000_000_000_000u128,
+			200_000_000_000_000u128,
+		)));
+		assert_noop!(
+			DexModule::abort_provisioning(Origin::signed(ListingOrigin::get()), AUSD, DOT),
+			Error::<Runtime>::MustBeProvisioning
+		);
+		assert_noop!(
+			DexModule::claim_dex_share(Origin::signed(BOB), ALICE, AUSD, DOT),
+			Error::<Runtime>::ProvisioningAborted
+		);
+		assert_noop!(
+			DexModule::list_provisioning(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				5_000_000_000_000u128,
+				1_000_000_000_000u128,
+				5_000_000_000_000_000u128,
+				1_000_000_000_000_000u128,
+				0,
+			),
+			Error::<Runtime>::NotAllowedList
+		);
+
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 999_000_000_000_000_000u128);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 999_800_000_000_000_000u128);
+		let alice_ref_count_0 = System::consumers(&ALICE);
+
+		assert_ok!(DexModule::claim_provisioning_refund(
+			Origin::signed(BOB),
+			ALICE,
+			AUSD,
+			DOT
+		));
+		System::assert_last_event(Event::DexModule(crate::Event::RefundProvision(
+			ALICE,
+			AUSD,
+			1_000_000_000_000_000u128,
+			DOT,
+			200_000_000_000_000u128,
+		)));
+		assert_eq!(DexModule::provisioning_pool(AUSDDOTPair::get(), ALICE), (0, 0));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1_000_000_000_000_000_000u128);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 1_000_000_000_000_000_000u128);
+		assert_eq!(Tokens::free_balance(AUSD, &DexModule::account_id()), 0);
+		assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 0);
+		assert_eq!(System::consumers(&ALICE), alice_ref_count_0 - 1);
+
+		assert_ok!(DexModule::list_provisioning(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			5_000_000_000_000u128,
+			1_000_000_000_000u128,
+			5_000_000_000_000_000u128,
+			1_000_000_000_000_000u128,
+			0,
+		));
+	});
+}
+
+#[test]
+fn extend_provisioning_deadline_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			DexModule::extend_provisioning_deadline(Origin::signed(ListingOrigin::get()), AUSD, DOT, 10),
+			Error::<Runtime>::MustBeProvisioning
+		);
+
+		assert_ok!(DexModule::list_provisioning(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			5_000_000_000_000u128,
+			1_000_000_000_000u128,
+			5_000_000_000_000_000u128,
+			1_000_000_000_000_000u128,
+			0,
+		));
+		assert_ok!(DexModule::add_provision(
+			Origin::signed(ALICE),
+			AUSD,
+			DOT,
+			1_000_000_000_000_000u128,
+			200_000_000_000_000u128,
+		));
+
+		assert_noop!(
+			DexModule::extend_provisioning_deadline(Origin::signed(ALICE), AUSD, DOT, 10),
+			BadOrigin
+		);
+		assert_noop!(
+			DexModule::extend_provisioning_deadline(Origin::signed(ListingOrigin::get()), AUSD, DOT, 1),
+			Error::<Runtime>::InvalidDeadline
+		);
+
+		assert_ok!(DexModule::extend_provisioning_deadline(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			10
+		));
+		System::assert_last_event(Event::DexModule(crate::Event::ProvisioningDeadlineExtended(
+			AUSDDOTPair::get(),
+			10,
+		)));
+		assert_eq!(DexModule::provisioning_deadlines(AUSDDOTPair::get()), Some(10));
+
+		assert_noop!(
+			DexModule::extend_provisioning_deadline(Origin::signed(ListingOrigin::get()), AUSD, DOT, 10),
+			Error::<Runtime>::InvalidDeadline
+		);
+		assert_ok!(DexModule::extend_provisioning_deadline(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			20
+		));
+		assert_eq!(DexModule::provisioning_deadlines(AUSDDOTPair::get()), Some(20));
+
+		System::set_block_number(20);
+		assert_noop!(
+			DexModule::claim_provisioning_refund(Origin::signed(BOB), ALICE, AUSD, DOT),
+			Error::<Runtime>::NotAllowedRefund
+		);
+
+		System::set_block_number(21);
+		assert_ok!(DexModule::claim_provisioning_refund(
+			Origin::signed(BOB),
+			ALICE,
+			AUSD,
+			DOT
+		));
+		System::assert_has_event(Event::DexModule(crate::Event::ProvisioningAborted(
+			AUSDDOTPair::get(),
+			1_000_000_000_000_000u128,
+			200_000_000_000_000u128,
+		)));
+		assert_eq!(
+			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
+			TradingPairStatus::<_, _>::Disabled
+		);
+		assert_eq!(DexModule::provisioning_deadlines(AUSDDOTPair::get()), None);
+		assert_eq!(DexModule::provisioning_pool(AUSDDOTPair::get(), ALICE), (0, 0));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1_000_000_000_000_000_000u128);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 1_000_000_000_000_000_000u128);
+	});
+}
+
 #[test]
 fn get_liquidity_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -623,26 +880,47 @@ fn get_liquidity_work() {
 #[test]
 fn get_target_amount_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(DexModule::get_target_amount(10000, 0, 1000), 0);
-		assert_eq!(DexModule::get_target_amount(0, 20000, 1000), 0);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 0), 0);
-		assert_eq!(DexModule::get_target_amount(10000, 1, 1000000), 0);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 10000), 9949);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 1000), 1801);
+		assert_eq!(DexModule::get_target_amount(10000, 0, 1000, GetExchangeFee::get()), 0);
+		assert_eq!(DexModule::get_target_amount(0, 20000, 1000, GetExchangeFee::get()), 0);
+		assert_eq!(DexModule::get_target_amount(10000, 20000, 0, GetExchangeFee::get()), 0);
+		assert_eq!(
+			DexModule::get_target_amount(10000, 1, 1000000, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_target_amount(10000, 20000, 10000, GetExchangeFee::get()),
+			9949
+		);
+		assert_eq!(
+			DexModule::get_target_amount(10000, 20000, 1000, GetExchangeFee::get()),
+			1801
+		);
 	});
 }
 
 #[test]
 fn get_supply_amount_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(DexModule::get_supply_amount(10000, 0, 1000), 0);
-		assert_eq!(DexModule::get_supply_amount(0, 20000, 1000), 0);
-		assert_eq!(DexModule::get_supply_amount(10000, 20000, 0), 0);
-		assert_eq!(DexModule::get_supply_amount(10000, 1, 1), 0);
-		assert_eq!(DexModule::get_supply_amount(10000, 20000, 9949), 9999);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 9999), 9949);
-		assert_eq!(DexModule::get_supply_amount(10000, 20000, 1801), 1000);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 1000), 1801);
+		assert_eq!(DexModule::get_supply_amount(10000, 0, 1000, GetExchangeFee::get()), 0);
+		assert_eq!(DexModule::get_supply_amount(0, 20000, 1000, GetExchangeFee::get()), 0);
+		assert_eq!(DexModule::get_supply_amount(10000, 20000, 0, GetExchangeFee::get()), 0);
+		assert_eq!(DexModule::get_supply_amount(10000, 1, 1, GetExchangeFee::get()), 0);
+		assert_eq!(
+			DexModule::get_supply_amount(10000, 20000, 9949, GetExchangeFee::get()),
+			9999
+		);
+		assert_eq!(
+			DexModule::get_target_amount(10000, 20000, 9999, GetExchangeFee::get()),
+			9949
+		);
+		assert_eq!(
+			DexModule::get_supply_amount(10000, 20000, 1801, GetExchangeFee::get()),
+			1000
+		);
+		assert_eq!(
+			DexModule::get_target_amount(10000, 20000, 1000, GetExchangeFee::get()),
+			1801
+		);
 	});
 }
 
@@ -696,7 +974,8 @@ fn calculate_amount_for_big_number_work() {
 			DexModule::get_supply_amount(
 				171_000_000_000_000_000_000_000,
 				56_000_000_000_000_000_000_000,
-				1_000_000_000_000_000_000_000
+				1_000_000_000_000_000_000_000,
+				GetExchangeFee::get()
 			),
 			3_140_495_867_768_595_041_323
 		);
@@ -704,7 +983,8 @@ fn calculate_amount_for_big_number_work() {
 			DexModule::get_target_amount(
 				171_000_000_000_000_000_000_000,
 				56_000_000_000_000_000_000_000,
-				3_140_495_867_768_595_041_323
+				3_140_495_867_768_595_041_323,
+				GetExchangeFee::get()
 			),
 			1_000_000_000_000_000_000_000
 		);
@@ -918,6 +1198,68 @@ fn add_liquidity_work() {
 		});
 }
 
+#[test]
+fn add_liquidity_single_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_noop!(
+				DexModule::add_liquidity_single(Origin::signed(BOB), AUSD, DOT, 10_000, 0, false),
+				Error::<Runtime>::InsufficientLiquidity
+			);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				2_000_000,
+				0,
+				false,
+			));
+			assert_noop!(
+				DexModule::add_liquidity_single(Origin::signed(BOB), AUSD, DOT, 0, 0, false),
+				Error::<Runtime>::InvalidLiquidityIncrement
+			);
+			assert_noop!(
+				DexModule::add_liquidity_single(Origin::signed(BOB), AUSD, ACA, 10_000, 0, false),
+				Error::<Runtime>::MustBeEnabled
+			);
+			assert_noop!(
+				DexModule::add_liquidity_single(Origin::signed(BOB), AUSD, DOT, 10_000, 9_923, false),
+				Error::<Runtime>::UnacceptableShareIncrement
+			);
+
+			// 5_012 AUSD is swapped to 9_874 DOT, 2 AUSD is left over
+			assert_ok!(DexModule::add_liquidity_single(
+				Origin::signed(BOB),
+				AUSD,
+				DOT,
+				10_000,
+				9_922,
+				true
+			));
+			System::assert_has_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![AUSD, DOT],
+				vec![5_012, 9_874],
+			)));
+			System::assert_last_event(Event::DexModule(crate::Event::AddLiquidity(
+				BOB, AUSD, 4_986, DOT, 9_874, 9_922,
+			)));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (1_009_998, 2_000_000));
+			assert_eq!(Tokens::free_balance(AUSD, &BOB), 999_999_999_999_990_002);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000_000_000_000_000);
+			assert_eq!(
+				Tokens::reserved_balance(AUSDDOTPair::get().dex_share_currency_id(), &BOB),
+				9_922
+			);
+		});
+}
+
 #[test]
 fn remove_liquidity_work() {
 	ExtBuilder::default()
@@ -1080,7 +1422,7 @@ fn remove_liquidity_work() {
 }
 
 #[test]
-fn do_swap_with_exact_supply_work() {
+fn migrate_liquidity_work() {
 	ExtBuilder::default()
 		.initialize_enabled_trading_pairs()
 		.build()
@@ -1091,7 +1433,113 @@ fn do_swap_with_exact_supply_work() {
 				Origin::signed(ALICE),
 				AUSD,
 				DOT,
-				500_000_000_000_000,
+				1_000_000,
+				2_000_000,
+				0,
+				true
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(BOB),
+				AUSD,
+				BTC,
+				1_000_000,
+				1_000_000,
+				0,
+				false
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(BOB),
+				DOT,
+				BTC,
+				1_000_000,
+				1_000_000,
+				0,
+				false
+			));
+
+			assert_noop!(
+				DexModule::migrate_liquidity(Origin::signed(ALICE), AUSD, DOT, DOT, 1_000_000, 0, true),
+				Error::<Runtime>::InvalidMigration
+			);
+			assert_noop!(
+				DexModule::migrate_liquidity(Origin::signed(ALICE), AUSD, AUSD, BTC, 1_000_000, 0, true),
+				Error::<Runtime>::InvalidCurrencyId
+			);
+			assert_noop!(
+				DexModule::migrate_liquidity(Origin::signed(ALICE), AUSD, DOT, BTC, 0, 0, true),
+				Error::<Runtime>::InvalidLiquidityIncrement
+			);
+			assert_noop!(
+				DexModule::migrate_liquidity(Origin::signed(ALICE), AUSD, DOT, ACA, 1_000_000, 0, true),
+				Error::<Runtime>::NoAvailableTradingPath
+			);
+			assert_noop!(
+				DexModule::migrate_liquidity(Origin::signed(ALICE), AUSD, DOT, BTC, 1_000_000, 994_975, true),
+				Error::<Runtime>::UnacceptableShareIncrement
+			);
+
+			// 500_000 AUSD and 1_000_000 DOT are withdrawn, the DOT is swapped to
+			// 497_487 BTC directly, and 2_513 AUSD is left over
+			assert_ok!(DexModule::migrate_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				BTC,
+				1_000_000,
+				994_974,
+				true
+			));
+			System::assert_has_event(Event::DexModule(crate::Event::RemoveLiquidity(
+				ALICE, AUSD, 500_000, DOT, 1_000_000, 1_000_000,
+			)));
+			System::assert_has_event(Event::DexModule(crate::Event::Swap(
+				ALICE,
+				vec![DOT, BTC],
+				vec![1_000_000, 497_487],
+			)));
+			System::assert_has_event(Event::DexModule(crate::Event::AddLiquidity(
+				ALICE, AUSD, 497_487, BTC, 497_487, 994_974,
+			)));
+			System::assert_last_event(Event::DexModule(crate::Event::MigrateLiquidity(
+				ALICE,
+				AUSDDOTPair::get(),
+				AUSDBTCPair::get(),
+				1_000_000,
+			)));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (500_000, 1_000_000));
+			assert_eq!(DexModule::get_liquidity(DOT, BTC), (2_000_000, 502_513));
+			assert_eq!(DexModule::get_liquidity(AUSD, BTC), (1_497_487, 1_497_487));
+			assert_eq!(Tokens::free_balance(AUSD, &ALICE), 999_999_999_999_002_513);
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), 999_999_999_998_000_000);
+			assert_eq!(Tokens::free_balance(BTC, &ALICE), 1_000_000_000_000_000_000);
+			assert_eq!(
+				Tokens::reserved_balance(AUSDDOTPair::get().dex_share_currency_id(), &ALICE),
+				1_000_000
+			);
+			assert_eq!(
+				Tokens::free_balance(AUSDBTCPair::get().dex_share_currency_id(), &ALICE),
+				0
+			);
+			assert_eq!(
+				Tokens::reserved_balance(AUSDBTCPair::get().dex_share_currency_id(), &ALICE),
+				994_974
+			);
+		});
+}
+
+#[test]
+fn do_swap_with_exact_supply_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
 				100_000_000_000_000,
 				0,
 				false,
@@ -1320,6 +1768,282 @@ fn do_swap_with_exact_target_work() {
 		});
 }
 
+#[test]
+fn swap_with_best_path_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				BTC,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				BTC,
+				1_000,
+				1_000,
+				0,
+				false,
+			));
+
+			assert_eq!(
+				DexModule::get_best_price_swap_path(AUSD, DOT, 1_000),
+				Some((vec![AUSD, DOT], 989))
+			);
+			// the deep pools through AUSD are better than the direct pool
+			assert_eq!(
+				DexModule::get_best_price_swap_path(DOT, BTC, 1_000),
+				Some((vec![DOT, AUSD, BTC], 978))
+			);
+			assert_eq!(DexModule::get_best_price_swap_path(DOT, BTC, 1), None);
+			assert_eq!(DexModule::get_best_price_swap_path(DOT, ACA, 1_000), None);
+
+			assert_noop!(
+				DexModule::swap_with_best_path(Origin::signed(BOB), DOT, ACA, 1_000, 0),
+				Error::<Runtime>::NoAvailableTradingPath
+			);
+			assert_noop!(
+				DexModule::swap_with_best_path(Origin::signed(BOB), DOT, BTC, 1_000, 979),
+				Error::<Runtime>::InsufficientTargetAmount
+			);
+
+			assert_ok!(DexModule::swap_with_best_path(
+				Origin::signed(BOB),
+				DOT,
+				BTC,
+				1_000,
+				978
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![DOT, AUSD, BTC],
+				vec![1_000, 989, 978],
+			)));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (999_011, 1_001_000));
+			assert_eq!(DexModule::get_liquidity(AUSD, BTC), (1_000_989, 999_022));
+			assert_eq!(DexModule::get_liquidity(DOT, BTC), (1_000, 1_000));
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_999_999_999_999_000);
+			assert_eq!(Tokens::free_balance(BTC, &BOB), 1_000_000_000_000_000_978);
+		});
+}
+
+#[test]
+fn get_best_swap_quote_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				BTC,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				BTC,
+				1_000,
+				1_000,
+				0,
+				false,
+			));
+
+			let hops = vec![
+				SwapHop {
+					venue: SwapVenue::Dex,
+					supply_currency_id: DOT,
+					target_currency_id: AUSD,
+					supply_amount: 1_000,
+					target_amount: 989,
+					fee: 10,
+					price_impact: Ratio::from_inner(1_010_101_010_101_011),
+				},
+				SwapHop {
+					venue: SwapVenue::Dex,
+					supply_currency_id: AUSD,
+					target_currency_id: BTC,
+					supply_amount: 989,
+					target_amount: 978,
+					fee: 9,
+					price_impact: Ratio::from_inner(2_040_816_326_530_613),
+				},
+			];
+			let quote = SwapQuote {
+				supply_amount: 1_000,
+				target_amount: 978,
+				hops,
+				price_impact: Ratio::from_inner(3_048_855_905_998_765),
+			};
+			assert_eq!(
+				DexModule::get_best_swap_quote(DOT, BTC, 1_000, SwapMode::ExactSupply),
+				Some(quote.clone())
+			);
+			assert_eq!(
+				DexModule::get_best_swap_quote(DOT, BTC, 978, SwapMode::ExactTarget),
+				Some(quote.clone())
+			);
+			assert_eq!(
+				DexModule::get_swap_quote(&[DOT, AUSD, BTC], 1_000, SwapMode::ExactSupply),
+				Some(quote)
+			);
+			assert_eq!(
+				DexModule::get_best_swap_quote(DOT, ACA, 1_000, SwapMode::ExactSupply),
+				None
+			);
+			assert_eq!(
+				DexModule::get_best_swap_quote(DOT, BTC, 1_000_000, SwapMode::ExactTarget),
+				None
+			);
+		});
+}
+
+#[test]
+fn twap_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				2_000_000,
+				0,
+				false,
+			));
+			assert_eq!(
+				DexModule::cumulative_prices(AUSDDOTPair::get()),
+				CumulativePrice {
+					price_0_cumulative: U256::zero(),
+					price_1_cumulative: U256::zero(),
+					block_number: 1,
+				}
+			);
+			assert_eq!(DexModule::get_twap(AUSD, DOT, 0), None);
+
+			System::set_block_number(11);
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				2_000_000,
+				0,
+				false,
+			));
+			assert_eq!(
+				DexModule::cumulative_prices(AUSDDOTPair::get()),
+				CumulativePrice {
+					price_0_cumulative: U256::from(20_000_000_000_000_000_000u128),
+					price_1_cumulative: U256::from(5_000_000_000_000_000_000u128),
+					block_number: 11,
+				}
+			);
+			assert_eq!(
+				DexModule::get_twap(AUSD, DOT, 5),
+				Some(ExchangeRate::saturating_from_integer(2))
+			);
+			assert_eq!(
+				DexModule::get_twap(DOT, AUSD, 5),
+				Some(ExchangeRate::saturating_from_rational(1, 2))
+			);
+
+			// observations are recorded at most once per period
+			System::set_block_number(16);
+			assert_ok!(DexModule::swap_with_exact_supply(
+				Origin::signed(BOB),
+				vec![AUSD, DOT],
+				2_000_000,
+				0
+			));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (4_000_000, 2_010_051));
+			assert_eq!(
+				DexModule::price_observations(AUSDDOTPair::get())
+					.iter()
+					.map(|observation| observation.block_number)
+					.collect::<Vec<_>>(),
+				vec![1, 11]
+			);
+
+			// the current price is accumulated up to the current block
+			System::set_block_number(21);
+			assert_eq!(
+				DexModule::get_twap(AUSD, DOT, 10),
+				Some(ExchangeRate::from_inner(1_251_256_375_000_000_000))
+			);
+			assert_eq!(
+				DexModule::get_twap(AUSD, DOT, 11),
+				Some(ExchangeRate::from_inner(1_625_628_187_500_000_000))
+			);
+			assert_eq!(DexModule::get_twap(AUSD, DOT, 21), None);
+			assert_eq!(DexModule::get_twap(AUSD, BTC, 1), None);
+
+			// the oldest observation is dropped
+			System::set_block_number(31);
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000,
+				1_000,
+				0,
+				false,
+			));
+			System::set_block_number(41);
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000,
+				1_000,
+				0,
+				false,
+			));
+			assert_eq!(
+				DexModule::price_observations(AUSDDOTPair::get())
+					.iter()
+					.map(|observation| observation.block_number)
+					.collect::<Vec<_>>(),
+				vec![11, 31, 41]
+			);
+		});
+}
+
 #[test]
 fn initialize_added_liquidity_pools_genesis_work() {
 	ExtBuilder::default()
@@ -1338,3 +2062,310 @@ fn initialize_added_liquidity_pools_genesis_work() {
 			);
 		});
 }
+
+#[test]
+fn set_lbp_creator_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			DexModule::set_lbp_creator(Origin::signed(ALICE), ALICE, true),
+			BadOrigin
+		);
+
+		assert!(!DexModule::lbp_creators(ALICE));
+		assert_ok!(DexModule::set_lbp_creator(
+			Origin::signed(ListingOrigin::get()),
+			ALICE,
+			true
+		));
+		assert!(DexModule::lbp_creators(ALICE));
+		System::assert_last_event(Event::DexModule(crate::Event::LBPCreatorUpdated(ALICE, true)));
+
+		assert_ok!(DexModule::set_lbp_creator(
+			Origin::signed(ListingOrigin::get()),
+			ALICE,
+			false
+		));
+		assert!(!DexModule::lbp_creators(ALICE));
+		System::assert_last_event(Event::DexModule(crate::Event::LBPCreatorUpdated(ALICE, false)));
+	});
+}
+
+#[test]
+fn create_lbp_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			DexModule::create_lbp(
+				Origin::signed(ALICE),
+				DOT,
+				AUSD,
+				100_000_000_000_000u128,
+				10_000_000_000_000u128,
+				Permill::from_percent(90),
+				Permill::from_percent(50),
+				101,
+			),
+			Error::<Runtime>::NotLBPCreator
+		);
+
+		assert_ok!(DexModule::set_lbp_creator(
+			Origin::signed(ListingOrigin::get()),
+			ALICE,
+			true
+		));
+		assert_noop!(
+			DexModule::create_lbp(
+				Origin::signed(ALICE),
+				DOT,
+				AUSD,
+				100_000_000_000_000u128,
+				10_000_000_000_000u128,
+				Permill::from_percent(100),
+				Permill::from_percent(50),
+				101,
+			),
+			Error::<Runtime>::InvalidLBPWeight
+		);
+		assert_noop!(
+			DexModule::create_lbp(
+				Origin::signed(ALICE),
+				DOT,
+				AUSD,
+				100_000_000_000_000u128,
+				10_000_000_000_000u128,
+				Permill::from_percent(90),
+				Permill::zero(),
+				101,
+			),
+			Error::<Runtime>::InvalidLBPWeight
+		);
+		assert_noop!(
+			DexModule::create_lbp(
+				Origin::signed(ALICE),
+				DOT,
+				AUSD,
+				100_000_000_000_000u128,
+				10_000_000_000_000u128,
+				Permill::from_percent(90),
+				Permill::from_percent(50),
+				1,
+			),
+			Error::<Runtime>::InvalidLBPPeriod
+		);
+
+		assert_ok!(DexModule::create_lbp(
+			Origin::signed(ALICE),
+			DOT,
+			AUSD,
+			100_000_000_000_000u128,
+			10_000_000_000_000u128,
+			Permill::from_percent(90),
+			Permill::from_percent(50),
+			101,
+		));
+		System::assert_last_event(Event::DexModule(crate::Event::LBPCreated(
+			ALICE,
+			AUSDDOTPair::get(),
+			10_000_000_000_000u128,
+			100_000_000_000_000u128,
+		)));
+		assert_eq!(
+			DexModule::liquidity_bootstrapping_pools(AUSDDOTPair::get()),
+			Some(LBPParameters {
+				creator: ALICE,
+				start_weight: Permill::from_percent(10),
+				end_weight: Permill::from_percent(50),
+				start: 1,
+				end: 101,
+			})
+		);
+		assert_eq!(
+			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
+			TradingPairStatus::<_, _>::Enabled
+		);
+		assert_eq!(
+			DexModule::get_liquidity(AUSD, DOT),
+			(10_000_000_000_000u128, 100_000_000_000_000u128)
+		);
+		assert_eq!(
+			Tokens::free_balance(AUSD, &DexModule::account_id()),
+			10_000_000_000_000u128
+		);
+		assert_eq!(
+			Tokens::free_balance(DOT, &DexModule::account_id()),
+			100_000_000_000_000u128
+		);
+		assert_eq!(
+			DexModule::get_lbp_weights(DOT, AUSD),
+			Some((Permill::from_percent(90), Permill::from_percent(10)))
+		);
+
+		assert_noop!(
+			DexModule::create_lbp(
+				Origin::signed(ALICE),
+				DOT,
+				AUSD,
+				100_000_000_000_000u128,
+				10_000_000_000_000u128,
+				Permill::from_percent(90),
+				Permill::from_percent(50),
+				101,
+			),
+			Error::<Runtime>::MustBeDisabled
+		);
+		assert_noop!(
+			DexModule::add_liquidity(
+				Origin::signed(BOB),
+				AUSD,
+				DOT,
+				1_000_000_000_000u128,
+				10_000_000_000_000u128,
+				0,
+				false,
+			),
+			Error::<Runtime>::LiquidityBootstrapping
+		);
+	});
+}
+
+#[test]
+fn lbp_swap_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(DexModule::set_lbp_creator(
+			Origin::signed(ListingOrigin::get()),
+			ALICE,
+			true
+		));
+		assert_ok!(DexModule::create_lbp(
+			Origin::signed(ALICE),
+			DOT,
+			AUSD,
+			100_000_000_000_000u128,
+			10_000_000_000_000u128,
+			Permill::from_percent(90),
+			Permill::from_percent(50),
+			101,
+		));
+
+		assert_noop!(
+			DexModule::swap_with_exact_supply(Origin::signed(BOB), vec![AUSD, DOT], 4_000_000_000_000u128, 0),
+			Error::<Runtime>::ExceedMaxLBPTradeRatio
+		);
+		assert_ok!(DexModule::swap_with_exact_supply(
+			Origin::signed(BOB),
+			vec![AUSD, DOT],
+			1_000_000_000_000u128,
+			0,
+		));
+		System::assert_last_event(Event::DexModule(crate::Event::Swap(
+			BOB,
+			vec![AUSD, DOT],
+			vec![1_000_000_000_000u128, 1_043_414_657_968u128],
+		)));
+		assert_eq!(
+			DexModule::get_liquidity(AUSD, DOT),
+			(11_000_000_000_000u128, 98_956_585_342_032u128)
+		);
+
+		// the constant product increases, but the weighted product decreases
+		assert_noop!(
+			DexModule::_swap(AUSD, DOT, 1_000_000_000_000u128, 1_100_000_000_000u128),
+			Error::<Runtime>::InvariantCheckFailed
+		);
+
+		// the weight of DOT shifts from 90% to 50%
+		System::set_block_number(51);
+		assert_eq!(
+			DexModule::get_lbp_weights(DOT, AUSD),
+			Some((Permill::from_percent(70), Permill::from_percent(30)))
+		);
+		assert_ok!(DexModule::swap_with_exact_target(
+			Origin::signed(BOB),
+			vec![AUSD, DOT],
+			2_000_000_000_000u128,
+			1_000_000_000_000u128,
+		));
+		System::assert_last_event(Event::DexModule(crate::Event::Swap(
+			BOB,
+			vec![AUSD, DOT],
+			vec![542_165_751_606u128, 2_000_000_000_000u128],
+		)));
+		assert_eq!(
+			DexModule::get_liquidity(AUSD, DOT),
+			(11_542_165_751_606u128, 96_956_585_342_032u128)
+		);
+
+		System::set_block_number(200);
+		assert_eq!(
+			DexModule::get_lbp_weights(DOT, AUSD),
+			Some((Permill::from_percent(50), Permill::from_percent(50)))
+		);
+	});
+}
+
+#[test]
+fn end_lbp_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			DexModule::end_lbp(Origin::signed(ALICE), AUSD, DOT),
+			Error::<Runtime>::LBPNotFound
+		);
+
+		assert_ok!(DexModule::set_lbp_creator(
+			Origin::signed(ListingOrigin::get()),
+			ALICE,
+			true
+		));
+		assert_ok!(DexModule::create_lbp(
+			Origin::signed(ALICE),
+			DOT,
+			AUSD,
+			100_000_000_000_000u128,
+			10_000_000_000_000u128,
+			Permill::from_percent(90),
+			Permill::from_percent(50),
+			101,
+		));
+		assert_ok!(DexModule::swap_with_exact_supply(
+			Origin::signed(BOB),
+			vec![AUSD, DOT],
+			1_000_000_000_000u128,
+			0,
+		));
+
+		assert_noop!(
+			DexModule::end_lbp(Origin::signed(BOB), AUSD, DOT),
+			Error::<Runtime>::NotLBPCreator
+		);
+		assert_noop!(
+			DexModule::end_lbp(Origin::signed(ALICE), AUSD, DOT),
+			Error::<Runtime>::LBPNotEnded
+		);
+
+		System::set_block_number(101);
+		let ausd_before = Tokens::free_balance(AUSD, &ALICE);
+		let dot_before = Tokens::free_balance(DOT, &ALICE);
+		assert_ok!(DexModule::end_lbp(Origin::signed(ALICE), AUSD, DOT));
+		System::assert_last_event(Event::DexModule(crate::Event::LBPEnded(
+			ALICE,
+			AUSDDOTPair::get(),
+			11_000_000_000_000u128,
+			98_956_585_342_032u128,
+		)));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), ausd_before + 11_000_000_000_000u128);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), dot_before + 98_956_585_342_032u128);
+		assert_eq!(DexModule::get_liquidity(AUSD, DOT), (0, 0));
+		assert_eq!(DexModule::liquidity_bootstrapping_pools(AUSDDOTPair::get()), None);
+		assert_eq!(
+			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
+			TradingPairStatus::<_, _>::Disabled
+		);
+	});
+}
@@ -49,17 +49,32 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn enable_trading_pair() -> Weight;
 	fn disable_trading_pair() -> Weight;
+	fn set_exchange_fee() -> Weight;
+	fn set_lbp_creator() -> Weight;
+	fn create_lbp() -> Weight;
+	fn end_lbp() -> Weight;
+	fn set_lbp_creator() -> Weight;
+	fn create_lbp() -> Weight;
+	fn end_lbp() -> Weight;
 	fn list_provisioning() -> Weight;
 	fn update_provisioning_parameters() -> Weight;
 	fn end_provisioning() -> Weight;
+	fn abort_provisioning() -> Weight;
+	fn extend_provisioning_deadline() -> Weight;
 	fn add_liquidity() -> Weight;
 	fn add_liquidity_and_stake() -> Weight;
+	fn add_liquidity_single() -> Weight;
+	fn add_liquidity_single_and_stake() -> Weight;
 	fn add_provision() -> Weight;
 	fn claim_dex_share() -> Weight;
+	fn claim_provisioning_refund() -> Weight;
 	fn remove_liquidity() -> Weight;
 	fn remove_liquidity_by_unstake() -> Weight;
+	fn migrate_liquidity() -> Weight;
+	fn migrate_liquidity_by_unstake() -> Weight;
 	fn swap_with_exact_supply(u: u32, ) -> Weight;
 	fn swap_with_exact_target(u: u32, ) -> Weight;
+	fn swap_with_best_path() -> Weight;
 }
 
 /// Weights for module_dex using the Acala node and recommended hardware.
@@ -75,6 +90,24 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_exchange_fee() -> Weight {
+		(20_117_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_lbp_creator() -> Weight {
+		(19_871_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn create_lbp() -> Weight {
+		(98_746_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	fn end_lbp() -> Weight {
+		(86_213_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
 	fn list_provisioning() -> Weight {
 		(36_413_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
@@ -90,6 +123,16 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn abort_provisioning() -> Weight {
+		(27_842_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn extend_provisioning_deadline() -> Weight {
+		(24_315_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn add_liquidity() -> Weight {
 		(197_944_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(8 as Weight))
@@ -100,6 +143,16 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(13 as Weight))
 			.saturating_add(T::DbWeight::get().writes(12 as Weight))
 	}
+	fn add_liquidity_single() -> Weight {
+		(241_385_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	fn add_liquidity_single_and_stake() -> Weight {
+		(316_204_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+	}
 	fn add_provision() -> Weight {
 		(197_944_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(8 as Weight))
@@ -110,6 +163,11 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(8 as Weight))
 			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
+	fn claim_provisioning_refund() -> Weight {
+		(86_370_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
 	fn remove_liquidity() -> Weight {
 		(205_562_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(7 as Weight))
@@ -120,6 +178,16 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(12 as Weight))
 			.saturating_add(T::DbWeight::get().writes(12 as Weight))
 	}
+	fn migrate_liquidity() -> Weight {
+		(598_273_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(21 as Weight))
+			.saturating_add(T::DbWeight::get().writes(20 as Weight))
+	}
+	fn migrate_liquidity_by_unstake() -> Weight {
+		(731_886_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(25 as Weight))
+			.saturating_add(T::DbWeight::get().writes(24 as Weight))
+	}
 	fn swap_with_exact_supply(u: u32, ) -> Weight {
 		(156_409_000 as Weight)
 			// Standard Error: 185_000
@@ -134,6 +202,11 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(10 as Weight))
 			.saturating_add(T::DbWeight::get().writes(9 as Weight))
 	}
+	fn swap_with_best_path() -> Weight {
+		(212_548_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -148,6 +221,24 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn set_exchange_fee() -> Weight {
+		(20_117_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_lbp_creator() -> Weight {
+		(19_871_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn create_lbp() -> Weight {
+		(98_746_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn end_lbp() -> Weight {
+		(86_213_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
 	fn list_provisioning() -> Weight {
 		(36_413_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
@@ -163,6 +254,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn abort_provisioning() -> Weight {
+		(27_842_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn extend_provisioning_deadline() -> Weight {
+		(24_315_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 	fn add_liquidity() -> Weight {
 		(197_944_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
@@ -173,6 +274,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
 	}
+	fn add_liquidity_single() -> Weight {
+		(241_385_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	fn add_liquidity_single_and_stake() -> Weight {
+		(316_204_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
+	}
 	fn add_provision() -> Weight {
 		(197_944_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
@@ -183,6 +294,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
 	}
+	fn claim_provisioning_refund() -> Weight {
+		(86_370_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
 	fn remove_liquidity() -> Weight {
 		(205_562_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
@@ -193,6 +309,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
 	}
+	fn migrate_liquidity() -> Weight {
+		(598_273_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(21 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(20 as Weight))
+	}
+	fn migrate_liquidity_by_unstake() -> Weight {
+		(731_886_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(25 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(24 as Weight))
+	}
 	fn swap_with_exact_supply(u: u32, ) -> Weight {
 		(156_409_000 as Weight)
 			// Standard Error: 185_000
@@ -207,4 +333,9 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
 	}
+	fn swap_with_best_path() -> Weight {
+		(212_548_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
 }
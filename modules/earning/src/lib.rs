@@ -0,0 +1,589 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Earning Module
+//!
+//! ## Overview
+//!
+//! Users bond native currency to gain the weight for governance and
+//! incentives. The bonded amount is locked, and it can be unbonded after the
+//! `UnbondingPeriod`, or unbonded instantly by paying `InstantUnbondFee` which
+//! is burned.
+//!
+//! Other modules can consume the bonded amount by `OnBonded` and `OnUnbonded`
+//! hooks, or query it by `bonded`.
+//!
+//! Bonders can commit to keep their bond until a block number, the bond can't
+//! be unbonded meanwhile. Every whole `ConvictionPeriod` of the remaining
+//! commitment multiplies the voting power of the bonded amount, up to
+//! `MaxConviction` times. `ConvictionVotingCurrency` exposes the voting power
+//! as the free balance of a currency, so that it can be used as the currency
+//! of the democracy.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{
+		BalanceStatus, Currency, ExistenceRequirement, LockableCurrency, ReservableCurrency, SignedImbalance,
+		WithdrawReasons,
+	},
+	transactional,
+};
+use frame_system::pallet_prelude::*;
+use orml_traits::{BasicCurrency, BasicLockableCurrency, Happened, LockIdentifier};
+use primitives::Balance;
+use sp_runtime::{
+	traits::{One, Saturating, Zero},
+	DispatchError, DispatchResult, Permill, RuntimeDebug, SaturatedConversion,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub const EARNING_LOCK_ID: LockIdentifier = *b"aca/earn";
+
+/// A chunk of the bonded amount which is unlocking.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct UnlockChunk<BlockNumber> {
+	/// The amount to be unlocked
+	pub value: Balance,
+	/// The block number at which it can be withdrawn
+	pub unlock_at: BlockNumber,
+}
+
+/// The bonding ledger of an account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default)]
+pub struct BondingLedger<BlockNumber> {
+	/// The total amount locked, including the unlocking chunks
+	pub total: Balance,
+	/// The amount bonded which counts as weight
+	pub active: Balance,
+	/// The chunks which are unlocking
+	pub unlocking: Vec<UnlockChunk<BlockNumber>>,
+}
+
+impl<BlockNumber: PartialOrd> BondingLedger<BlockNumber> {
+	/// Remove the chunks which are unlocked at `now`.
+	fn consolidate_unlocked(mut self, now: BlockNumber) -> Self {
+		let mut total = self.total;
+		self.unlocking.retain(|chunk| {
+			if chunk.unlock_at > now {
+				true
+			} else {
+				total = total.saturating_sub(chunk.value);
+				false
+			}
+		});
+		self.total = total;
+		self
+	}
+
+	/// Rebond the unlocking chunks, starting from the latest one.
+	fn rebond(mut self, value: Balance) -> (Self, Balance) {
+		let mut remains = value;
+		while let Some(last) = self.unlocking.last_mut() {
+			let rebonded = remains.min(last.value);
+			last.value = last.value.saturating_sub(rebonded);
+			self.active = self.active.saturating_add(rebonded);
+			remains = remains.saturating_sub(rebonded);
+
+			if last.value.is_zero() {
+				self.unlocking.pop();
+			}
+			if remains.is_zero() {
+				break;
+			}
+		}
+		(self, value.saturating_sub(remains))
+	}
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The native currency to be bonded.
+		type Currency: BasicLockableCurrency<Self::AccountId, Balance = Balance>;
+
+		/// The minimum active bonded amount.
+		#[pallet::constant]
+		type MinBond: Get<Balance>;
+
+		/// The blocks the unbonded amount keeps locked.
+		#[pallet::constant]
+		type UnbondingPeriod: Get<Self::BlockNumber>;
+
+		/// The fee rate of instant unbond, the fee is burned.
+		#[pallet::constant]
+		type InstantUnbondFee: Get<Permill>;
+
+		/// The maximum count of unlocking chunks of an account.
+		#[pallet::constant]
+		type MaxUnbondingChunks: Get<u32>;
+
+		/// The remaining commitment of every this many blocks adds the bonded
+		/// amount once more to the voting power.
+		#[pallet::constant]
+		type ConvictionPeriod: Get<Self::BlockNumber>;
+
+		/// The maximum count of `ConvictionPeriod` which count for the voting
+		/// power.
+		#[pallet::constant]
+		type MaxConviction: Get<u32>;
+
+		/// Callback to be called when the bonded amount of an account increases.
+		type OnBonded: Happened<(Self::AccountId, Balance)>;
+
+		/// Callback to be called when the bonded amount of an account decreases.
+		type OnUnbonded: Happened<(Self::AccountId, Balance)>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The active bonded amount is below the minimum bond
+		BelowMinBond,
+		/// The count of unlocking chunks exceeds the limit
+		MaxUnbondingChunksExceeded,
+		/// The account has no bonding
+		NotBonded,
+		/// The bond is committed and can't be unbonded yet
+		BondCommitted,
+		/// The commitment must be later than the current one and the block
+		/// number, and not later than `MaxConviction` periods
+		InvalidCommitment,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", T::BlockNumber = "BlockNumber")]
+	pub enum Event<T: Config> {
+		/// Bonded. \[who, amount\]
+		Bonded(T::AccountId, Balance),
+		/// Unbonded and unlocking. \[who, amount, unlock_at\]
+		Unbonded(T::AccountId, Balance, T::BlockNumber),
+		/// Unbonded instantly with fee. \[who, amount, fee\]
+		InstantUnbonded(T::AccountId, Balance, Balance),
+		/// Rebonded the unlocking amount. \[who, amount\]
+		Rebonded(T::AccountId, Balance),
+		/// Withdrawn the unlocked amount. \[who, amount\]
+		Withdrawn(T::AccountId, Balance),
+		/// Committed to keep the bond. \[who, until\]
+		Committed(T::AccountId, T::BlockNumber),
+	}
+
+	/// The bonding ledgers of accounts.
+	///
+	/// Ledger: map AccountId => Option<BondingLedger>
+	#[pallet::storage]
+	#[pallet::getter(fn ledger)]
+	pub type Ledger<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BondingLedger<T::BlockNumber>, OptionQuery>;
+
+	/// The block numbers until which accounts committed to keep their bond.
+	///
+	/// Commitments: map AccountId => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn commitments)]
+	pub type Commitments<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, T::BlockNumber, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Bond native currency, the amount is capped by the free balance which
+		/// is not bonded yet.
+		///
+		/// - `amount`: the amount to bond
+		#[pallet::weight(T::WeightInfo::bond())]
+		#[transactional]
+		pub fn bond(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::update_ledger(&who, |ledger| -> DispatchResult {
+				let amount = amount.min(T::Currency::free_balance(&who).saturating_sub(ledger.total));
+				ledger.total = ledger.total.saturating_add(amount);
+				ledger.active = ledger.active.saturating_add(amount);
+				ensure!(ledger.active >= T::MinBond::get(), Error::<T>::BelowMinBond);
+
+				if !amount.is_zero() {
+					T::OnBonded::happened(&(who.clone(), amount));
+					Self::deposit_event(Event::Bonded(who.clone(), amount));
+				}
+				Ok(())
+			})
+		}
+
+		/// Unbond the bonded amount, which can be withdrawn after
+		/// `UnbondingPeriod`.
+		///
+		/// - `amount`: the amount to unbond
+		#[pallet::weight(T::WeightInfo::unbond())]
+		#[transactional]
+		pub fn unbond(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_committed(&who)?;
+
+			Self::update_ledger(&who, |ledger| -> DispatchResult {
+				let amount = amount.min(ledger.active);
+				if amount.is_zero() {
+					return Ok(());
+				}
+				ensure!(
+					(ledger.unlocking.len() as u32) < T::MaxUnbondingChunks::get(),
+					Error::<T>::MaxUnbondingChunksExceeded
+				);
+				ledger.active = ledger.active.saturating_sub(amount);
+				Self::ensure_min_bond(ledger.active)?;
+
+				let unlock_at = frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingPeriod::get());
+				ledger.unlocking.push(UnlockChunk {
+					value: amount,
+					unlock_at,
+				});
+
+				T::OnUnbonded::happened(&(who.clone(), amount));
+				Self::deposit_event(Event::Unbonded(who.clone(), amount, unlock_at));
+				Ok(())
+			})
+		}
+
+		/// Unbond the bonded amount instantly, `InstantUnbondFee` of the amount
+		/// is burned.
+		///
+		/// - `amount`: the amount to unbond
+		#[pallet::weight(T::WeightInfo::unbond_instant())]
+		#[transactional]
+		pub fn unbond_instant(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_committed(&who)?;
+
+			let mut unbonded: Balance = Zero::zero();
+			Self::update_ledger(&who, |ledger| -> DispatchResult {
+				unbonded = amount.min(ledger.active);
+				ledger.active = ledger.active.saturating_sub(unbonded);
+				ledger.total = ledger.total.saturating_sub(unbonded);
+				Self::ensure_min_bond(ledger.active)
+			})?;
+
+			if !unbonded.is_zero() {
+				// burn the fee after the lock is updated
+				let fee = T::InstantUnbondFee::get().mul_ceil(unbonded);
+				T::Currency::withdraw(&who, fee)?;
+
+				T::OnUnbonded::happened(&(who.clone(), unbonded));
+				Self::deposit_event(Event::InstantUnbonded(who, unbonded, fee));
+			}
+			Ok(())
+		}
+
+		/// Rebond the unlocking amount, starting from the latest unbonded one.
+		///
+		/// - `amount`: the amount to rebond
+		#[pallet::weight(T::WeightInfo::rebond())]
+		#[transactional]
+		pub fn rebond(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::update_ledger(&who, |ledger| -> DispatchResult {
+				let (new_ledger, rebonded) = ledger.clone().rebond(amount);
+				*ledger = new_ledger;
+				Self::ensure_min_bond(ledger.active)?;
+
+				if !rebonded.is_zero() {
+					T::OnBonded::happened(&(who.clone(), rebonded));
+					Self::deposit_event(Event::Rebonded(who.clone(), rebonded));
+				}
+				Ok(())
+			})
+		}
+
+		/// Withdraw the unlocked amount.
+		#[pallet::weight(T::WeightInfo::withdraw_unbonded())]
+		#[transactional]
+		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Ledger::<T>::contains_key(&who), Error::<T>::NotBonded);
+
+			Self::update_ledger(&who, |ledger| -> DispatchResult {
+				let old_total = ledger.total;
+				*ledger = ledger
+					.clone()
+					.consolidate_unlocked(frame_system::Pallet::<T>::block_number());
+				let withdrawn = old_total.saturating_sub(ledger.total);
+
+				if !withdrawn.is_zero() {
+					Self::deposit_event(Event::Withdrawn(who.clone(), withdrawn));
+				}
+				Ok(())
+			})
+		}
+
+		/// Commit to keep the bond until `until`, which multiplies the voting
+		/// power of the bonded amount. The commitment can only be extended.
+		///
+		/// - `until`: the block number until which the bond can't be unbonded
+		#[pallet::weight(T::WeightInfo::commit())]
+		#[transactional]
+		pub fn commit(origin: OriginFor<T>, until: T::BlockNumber) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!Self::bonded(&who).is_zero(), Error::<T>::NotBonded);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let max_until =
+				now.saturating_add(T::ConvictionPeriod::get().saturating_mul(T::MaxConviction::get().into()));
+			ensure!(
+				until > now && until <= max_until && Self::commitments(&who).map_or(true, |current| until > current),
+				Error::<T>::InvalidCommitment
+			);
+
+			Commitments::<T>::insert(&who, until);
+			Self::deposit_event(Event::Committed(who, until));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The active bonded amount of `who`.
+	pub fn bonded(who: &T::AccountId) -> Balance {
+		Self::ledger(who).map(|ledger| ledger.active).unwrap_or_default()
+	}
+
+	/// The voting power of `who`, the active bonded amount multiplied by one
+	/// plus the count of whole `ConvictionPeriod` of the remaining commitment,
+	/// which is capped by `MaxConviction`.
+	pub fn voting_power(who: &T::AccountId) -> Balance {
+		let bonded = Self::bonded(who);
+		let now = frame_system::Pallet::<T>::block_number();
+		let conviction = Self::commitments(who)
+			.filter(|until| *until > now)
+			.map(|until| {
+				let periods: u32 =
+					(until.saturating_sub(now) / T::ConvictionPeriod::get().max(One::one())).saturated_into();
+				periods.min(T::MaxConviction::get())
+			})
+			.unwrap_or_default();
+		bonded.saturating_mul(Balance::from(conviction).saturating_add(1))
+	}
+
+	fn ensure_not_committed(who: &T::AccountId) -> DispatchResult {
+		ensure!(
+			Self::commitments(who).map_or(true, |until| until <= frame_system::Pallet::<T>::block_number()),
+			Error::<T>::BondCommitted
+		);
+		Ok(())
+	}
+
+	fn ensure_min_bond(active: Balance) -> DispatchResult {
+		ensure!(
+			active.is_zero() || active >= T::MinBond::get(),
+			Error::<T>::BelowMinBond
+		);
+		Ok(())
+	}
+
+	fn update_ledger(
+		who: &T::AccountId,
+		f: impl FnOnce(&mut BondingLedger<T::BlockNumber>) -> DispatchResult,
+	) -> DispatchResult {
+		Ledger::<T>::try_mutate_exists(who, |maybe_ledger| -> DispatchResult {
+			let mut ledger = maybe_ledger.take().unwrap_or_default();
+			let old_total = ledger.total;
+			f(&mut ledger)?;
+
+			if ledger.total != old_total {
+				if ledger.total.is_zero() {
+					T::Currency::remove_lock(EARNING_LOCK_ID, who)?;
+				} else {
+					T::Currency::set_lock(EARNING_LOCK_ID, who, ledger.total)?;
+				}
+			}
+
+			if !ledger.total.is_zero() {
+				*maybe_ledger = Some(ledger);
+			}
+			Ok(())
+		})
+	}
+}
+
+/// A currency whose free balance is the voting power of the bonders of the
+/// earning module, the other operations are delegated to `C`. Used as the
+/// currency of the democracy so that votes are weighted by the committed bond,
+/// the locks and deposits of the democracy still apply to `C`.
+pub struct ConvictionVotingCurrency<T, C>(PhantomData<(T, C)>);
+
+impl<T, C> Currency<T::AccountId> for ConvictionVotingCurrency<T, C>
+where
+	T: Config,
+	C: Currency<T::AccountId, Balance = Balance>,
+{
+	type Balance = Balance;
+	type PositiveImbalance = C::PositiveImbalance;
+	type NegativeImbalance = C::NegativeImbalance;
+
+	fn total_balance(who: &T::AccountId) -> Self::Balance {
+		C::total_balance(who)
+	}
+
+	fn can_slash(who: &T::AccountId, value: Self::Balance) -> bool {
+		C::can_slash(who, value)
+	}
+
+	fn total_issuance() -> Self::Balance {
+		C::total_issuance()
+	}
+
+	fn minimum_balance() -> Self::Balance {
+		C::minimum_balance()
+	}
+
+	fn burn(amount: Self::Balance) -> Self::PositiveImbalance {
+		C::burn(amount)
+	}
+
+	fn issue(amount: Self::Balance) -> Self::NegativeImbalance {
+		C::issue(amount)
+	}
+
+	fn free_balance(who: &T::AccountId) -> Self::Balance {
+		Pallet::<T>::voting_power(who)
+	}
+
+	fn ensure_can_withdraw(
+		who: &T::AccountId,
+		amount: Self::Balance,
+		reasons: WithdrawReasons,
+		new_balance: Self::Balance,
+	) -> DispatchResult {
+		C::ensure_can_withdraw(who, amount, reasons, new_balance)
+	}
+
+	fn transfer(
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		value: Self::Balance,
+		existence_requirement: ExistenceRequirement,
+	) -> DispatchResult {
+		C::transfer(source, dest, value, existence_requirement)
+	}
+
+	fn slash(who: &T::AccountId, value: Self::Balance) -> (Self::NegativeImbalance, Self::Balance) {
+		C::slash(who, value)
+	}
+
+	fn deposit_into_existing(
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> sp_std::result::Result<Self::PositiveImbalance, DispatchError> {
+		C::deposit_into_existing(who, value)
+	}
+
+	fn deposit_creating(who: &T::AccountId, value: Self::Balance) -> Self::PositiveImbalance {
+		C::deposit_creating(who, value)
+	}
+
+	fn withdraw(
+		who: &T::AccountId,
+		value: Self::Balance,
+		reasons: WithdrawReasons,
+		liveness: ExistenceRequirement,
+	) -> sp_std::result::Result<Self::NegativeImbalance, DispatchError> {
+		C::withdraw(who, value, reasons, liveness)
+	}
+
+	fn make_free_balance_be(
+		who: &T::AccountId,
+		balance: Self::Balance,
+	) -> SignedImbalance<Self::Balance, Self::PositiveImbalance> {
+		C::make_free_balance_be(who, balance)
+	}
+}
+
+impl<T, C> ReservableCurrency<T::AccountId> for ConvictionVotingCurrency<T, C>
+where
+	T: Config,
+	C: ReservableCurrency<T::AccountId, Balance = Balance>,
+{
+	fn can_reserve(who: &T::AccountId, value: Self::Balance) -> bool {
+		C::can_reserve(who, value)
+	}
+
+	fn slash_reserved(who: &T::AccountId, value: Self::Balance) -> (Self::NegativeImbalance, Self::Balance) {
+		C::slash_reserved(who, value)
+	}
+
+	fn reserved_balance(who: &T::AccountId) -> Self::Balance {
+		C::reserved_balance(who)
+	}
+
+	fn reserve(who: &T::AccountId, value: Self::Balance) -> DispatchResult {
+		C::reserve(who, value)
+	}
+
+	fn unreserve(who: &T::AccountId, value: Self::Balance) -> Self::Balance {
+		C::unreserve(who, value)
+	}
+
+	fn repatriate_reserved(
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
+	) -> sp_std::result::Result<Self::Balance, DispatchError> {
+		C::repatriate_reserved(slashed, beneficiary, value, status)
+	}
+}
+
+impl<T, C> LockableCurrency<T::AccountId> for ConvictionVotingCurrency<T, C>
+where
+	T: Config,
+	C: LockableCurrency<T::AccountId, Balance = Balance>,
+{
+	type Moment = C::Moment;
+	type MaxLocks = C::MaxLocks;
+
+	fn set_lock(id: LockIdentifier, who: &T::AccountId, amount: Self::Balance, reasons: WithdrawReasons) {
+		C::set_lock(id, who, amount, reasons)
+	}
+
+	fn extend_lock(id: LockIdentifier, who: &T::AccountId, amount: Self::Balance, reasons: WithdrawReasons) {
+		C::extend_lock(id, who, amount, reasons)
+	}
+
+	fn remove_lock(id: LockIdentifier, who: &T::AccountId) {
+		C::remove_lock(id, who)
+	}
+}
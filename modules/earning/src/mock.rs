@@ -0,0 +1,226 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the earning module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{construct_runtime, parameter_types};
+use orml_traits::parameter_type_with_key;
+use primitives::{Amount, CurrencyId, TokenSymbol};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+use sp_std::cell::RefCell;
+use std::collections::HashMap;
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
+
+mod earning {
+	pub use super::super::*;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Default::default()
+	};
+}
+
+parameter_types! {
+	pub const MaxLocks: u32 = 100;
+}
+
+impl orml_tokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = MaxLocks;
+	type DustRemovalWhitelist = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const GetNativeCurrencyId: CurrencyId = ACA;
+}
+
+pub type NativeCurrency = orml_currencies::BasicCurrencyAdapter<Runtime, PalletBalances, Amount, BlockNumber>;
+pub type VotingCurrency = ConvictionVotingCurrency<Runtime, PalletBalances>;
+
+impl orml_currencies::Config for Runtime {
+	type Event = Event;
+	type MultiCurrency = OrmlTokens;
+	type NativeCurrency = NativeCurrency;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type WeightInfo = ();
+}
+
+thread_local! {
+	pub static SHARES: RefCell<HashMap<AccountId, Balance>> = RefCell::new(HashMap::new());
+}
+
+pub struct MockOnBonded;
+impl Happened<(AccountId, Balance)> for MockOnBonded {
+	fn happened(info: &(AccountId, Balance)) {
+		let (who, amount) = info;
+		SHARES.with(|v| {
+			let mut shares = v.borrow_mut();
+			let share = shares.entry(*who).or_default();
+			*share = share.saturating_add(*amount);
+		});
+	}
+}
+
+pub struct MockOnUnbonded;
+impl Happened<(AccountId, Balance)> for MockOnUnbonded {
+	fn happened(info: &(AccountId, Balance)) {
+		let (who, amount) = info;
+		SHARES.with(|v| {
+			let mut shares = v.borrow_mut();
+			let share = shares.entry(*who).or_default();
+			*share = share.saturating_sub(*amount);
+		});
+	}
+}
+
+pub fn shares(who: AccountId) -> Balance {
+	SHARES.with(|v| v.borrow().get(&who).copied().unwrap_or_default())
+}
+
+parameter_types! {
+	pub const MinBond: Balance = 100;
+	pub const UnbondingPeriod: BlockNumber = 3;
+	pub const InstantUnbondFee: Permill = Permill::from_percent(10);
+	pub const MaxUnbondingChunks: u32 = 2;
+	pub const ConvictionPeriod: BlockNumber = 10;
+	pub const MaxConviction: u32 = 3;
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type Currency = NativeCurrency;
+	type MinBond = MinBond;
+	type UnbondingPeriod = UnbondingPeriod;
+	type InstantUnbondFee = InstantUnbondFee;
+	type MaxUnbondingChunks = MaxUnbondingChunks;
+	type ConvictionPeriod = ConvictionPeriod;
+	type MaxConviction = MaxConviction;
+	type OnBonded = MockOnBonded;
+	type OnUnbonded = MockOnUnbonded;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		OrmlTokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+		PalletBalances: pallet_balances::{Pallet, Call, Storage, Event<T>, Config<T>},
+		OrmlCurrencies: orml_currencies::{Pallet, Call, Event<T>},
+		EarningModule: earning::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			balances: vec![(ALICE, 1000), (BOB, 1000)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
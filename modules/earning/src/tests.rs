@@ -0,0 +1,280 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the earning module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+
+#[test]
+fn bond_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EarningModule::bond(Origin::signed(ALICE), 50),
+			Error::<Runtime>::BelowMinBond
+		);
+
+		assert_ok!(EarningModule::bond(Origin::signed(ALICE), 200));
+		System::assert_last_event(Event::EarningModule(crate::Event::Bonded(ALICE, 200)));
+		assert_eq!(
+			EarningModule::ledger(ALICE),
+			Some(BondingLedger {
+				total: 200,
+				active: 200,
+				unlocking: vec![],
+			})
+		);
+		assert_eq!(EarningModule::bonded(&ALICE), 200);
+		assert_eq!(PalletBalances::usable_balance(&ALICE), 800);
+		assert_eq!(shares(ALICE), 200);
+
+		// capped by the free balance
+		assert_ok!(EarningModule::bond(Origin::signed(ALICE), 2000));
+		System::assert_last_event(Event::EarningModule(crate::Event::Bonded(ALICE, 800)));
+		assert_eq!(EarningModule::bonded(&ALICE), 1000);
+		assert_eq!(PalletBalances::usable_balance(&ALICE), 0);
+		assert_eq!(shares(ALICE), 1000);
+	});
+}
+
+#[test]
+fn unbond_and_withdraw_unbonded_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EarningModule::withdraw_unbonded(Origin::signed(ALICE)),
+			Error::<Runtime>::NotBonded
+		);
+		assert_ok!(EarningModule::bond(Origin::signed(ALICE), 500));
+		assert_noop!(
+			EarningModule::unbond(Origin::signed(ALICE), 450),
+			Error::<Runtime>::BelowMinBond
+		);
+
+		assert_ok!(EarningModule::unbond(Origin::signed(ALICE), 100));
+		System::assert_last_event(Event::EarningModule(crate::Event::Unbonded(ALICE, 100, 4)));
+		assert_ok!(EarningModule::unbond(Origin::signed(ALICE), 100));
+		assert_noop!(
+			EarningModule::unbond(Origin::signed(ALICE), 100),
+			Error::<Runtime>::MaxUnbondingChunksExceeded
+		);
+		assert_eq!(
+			EarningModule::ledger(ALICE),
+			Some(BondingLedger {
+				total: 500,
+				active: 300,
+				unlocking: vec![
+					UnlockChunk {
+						value: 100,
+						unlock_at: 4
+					},
+					UnlockChunk {
+						value: 100,
+						unlock_at: 4
+					}
+				],
+			})
+		);
+		assert_eq!(EarningModule::bonded(&ALICE), 300);
+		assert_eq!(PalletBalances::usable_balance(&ALICE), 500);
+		assert_eq!(shares(ALICE), 300);
+
+		// still locked
+		System::set_block_number(3);
+		assert_ok!(EarningModule::withdraw_unbonded(Origin::signed(ALICE)));
+		assert_eq!(PalletBalances::usable_balance(&ALICE), 500);
+
+		System::set_block_number(4);
+		assert_ok!(EarningModule::withdraw_unbonded(Origin::signed(ALICE)));
+		System::assert_last_event(Event::EarningModule(crate::Event::Withdrawn(ALICE, 200)));
+		assert_eq!(
+			EarningModule::ledger(ALICE),
+			Some(BondingLedger {
+				total: 300,
+				active: 300,
+				unlocking: vec![],
+			})
+		);
+		assert_eq!(PalletBalances::usable_balance(&ALICE), 700);
+
+		// unbond all
+		assert_ok!(EarningModule::unbond(Origin::signed(ALICE), 300));
+		System::set_block_number(7);
+		assert_ok!(EarningModule::withdraw_unbonded(Origin::signed(ALICE)));
+		assert_eq!(EarningModule::ledger(ALICE), None);
+		assert_eq!(PalletBalances::usable_balance(&ALICE), 1000);
+		assert_eq!(shares(ALICE), 0);
+	});
+}
+
+#[test]
+fn unbond_instant_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EarningModule::bond(Origin::signed(ALICE), 500));
+		assert_noop!(
+			EarningModule::unbond_instant(Origin::signed(ALICE), 450),
+			Error::<Runtime>::BelowMinBond
+		);
+
+		assert_ok!(EarningModule::unbond_instant(Origin::signed(ALICE), 200));
+		System::assert_last_event(Event::EarningModule(crate::Event::InstantUnbonded(ALICE, 200, 20)));
+		assert_eq!(
+			EarningModule::ledger(ALICE),
+			Some(BondingLedger {
+				total: 300,
+				active: 300,
+				unlocking: vec![],
+			})
+		);
+		assert_eq!(shares(ALICE), 300);
+
+		// the fee is burned
+		assert_eq!(PalletBalances::free_balance(&ALICE), 980);
+		assert_eq!(PalletBalances::usable_balance(&ALICE), 680);
+		assert_eq!(PalletBalances::total_issuance(), 1980);
+	});
+}
+
+#[test]
+fn rebond_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EarningModule::bond(Origin::signed(ALICE), 500));
+		assert_ok!(EarningModule::unbond(Origin::signed(ALICE), 100));
+		System::set_block_number(2);
+		assert_ok!(EarningModule::unbond(Origin::signed(ALICE), 200));
+		assert_eq!(shares(ALICE), 200);
+
+		// rebond from the latest unlocking chunk
+		assert_ok!(EarningModule::rebond(Origin::signed(ALICE), 250));
+		System::assert_last_event(Event::EarningModule(crate::Event::Rebonded(ALICE, 250)));
+		assert_eq!(
+			EarningModule::ledger(ALICE),
+			Some(BondingLedger {
+				total: 500,
+				active: 450,
+				unlocking: vec![UnlockChunk {
+					value: 50,
+					unlock_at: 4
+				}],
+			})
+		);
+		assert_eq!(shares(ALICE), 450);
+
+		// capped by the unlocking amount
+		assert_ok!(EarningModule::rebond(Origin::signed(ALICE), 1000));
+		System::assert_last_event(Event::EarningModule(crate::Event::Rebonded(ALICE, 50)));
+		assert_eq!(
+			EarningModule::ledger(ALICE),
+			Some(BondingLedger {
+				total: 500,
+				active: 500,
+				unlocking: vec![],
+			})
+		);
+		assert_eq!(shares(ALICE), 500);
+	});
+}
+
+#[test]
+fn commit_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EarningModule::commit(Origin::signed(ALICE), 11),
+			Error::<Runtime>::NotBonded
+		);
+
+		assert_ok!(EarningModule::bond(Origin::signed(ALICE), 200));
+		assert_noop!(
+			EarningModule::commit(Origin::signed(ALICE), 1),
+			Error::<Runtime>::InvalidCommitment
+		);
+		assert_noop!(
+			EarningModule::commit(Origin::signed(ALICE), 32),
+			Error::<Runtime>::InvalidCommitment
+		);
+
+		assert_ok!(EarningModule::commit(Origin::signed(ALICE), 21));
+		System::assert_last_event(Event::EarningModule(crate::Event::Committed(ALICE, 21)));
+		assert_eq!(EarningModule::commitments(ALICE), Some(21));
+		assert_noop!(
+			EarningModule::commit(Origin::signed(ALICE), 21),
+			Error::<Runtime>::InvalidCommitment
+		);
+
+		assert_noop!(
+			EarningModule::unbond(Origin::signed(ALICE), 100),
+			Error::<Runtime>::BondCommitted
+		);
+		assert_noop!(
+			EarningModule::unbond_instant(Origin::signed(ALICE), 100),
+			Error::<Runtime>::BondCommitted
+		);
+
+		System::set_block_number(21);
+		assert_ok!(EarningModule::unbond(Origin::signed(ALICE), 100));
+		assert_eq!(EarningModule::bonded(&ALICE), 100);
+	});
+}
+
+#[test]
+fn voting_power_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(EarningModule::voting_power(&ALICE), 0);
+
+		assert_ok!(EarningModule::bond(Origin::signed(ALICE), 200));
+		assert_eq!(EarningModule::voting_power(&ALICE), 200);
+
+		// 2 whole periods remain
+		assert_ok!(EarningModule::commit(Origin::signed(ALICE), 25));
+		assert_eq!(EarningModule::voting_power(&ALICE), 600);
+
+		// capped by `MaxConviction`
+		System::set_block_number(10);
+		assert_ok!(EarningModule::commit(Origin::signed(ALICE), 40));
+		assert_eq!(EarningModule::voting_power(&ALICE), 800);
+
+		System::set_block_number(31);
+		assert_eq!(EarningModule::voting_power(&ALICE), 200);
+
+		System::set_block_number(40);
+		assert_eq!(EarningModule::voting_power(&ALICE), 200);
+	});
+}
+
+#[test]
+fn conviction_voting_currency_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EarningModule::bond(Origin::signed(ALICE), 200));
+		assert_ok!(EarningModule::commit(Origin::signed(ALICE), 31));
+
+		assert_eq!(VotingCurrency::free_balance(&ALICE), 800);
+		assert_eq!(VotingCurrency::total_balance(&ALICE), 1000);
+		assert_eq!(VotingCurrency::total_issuance(), 2000);
+
+		assert_ok!(VotingCurrency::reserve(&ALICE, 100));
+		assert_eq!(PalletBalances::reserved_balance(&ALICE), 100);
+		assert_eq!(VotingCurrency::free_balance(&ALICE), 800);
+
+		VotingCurrency::set_lock(*b"democrac", &ALICE, 800, WithdrawReasons::all());
+		assert_eq!(PalletBalances::usable_balance(&ALICE), 100);
+		VotingCurrency::remove_lock(*b"democrac", &ALICE);
+		assert_eq!(PalletBalances::usable_balance(&ALICE), 700);
+	});
+}
@@ -27,7 +27,8 @@
 //! some user entry, freeze feed prices, run offchain worker to settle
 //! CDPs has debit, cancel all active auctions module, when debits and gaps are
 //! settled, the stable currency holder are allowed to refund a basket of
-//! remaining collateral assets.
+//! remaining collateral assets, or a single type of collateral assets of the
+//! same value at the locked prices.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -37,7 +38,7 @@ use frame_system::{ensure_signed, pallet_prelude::*};
 use primitives::{Balance, CurrencyId};
 use sp_runtime::{traits::Zero, FixedPointNumber};
 use sp_std::prelude::*;
-use support::{AuctionManager, CDPTreasury, EmergencyShutdown, LockablePrice, Ratio};
+use support::{AuctionManager, CDPTreasury, EmergencyShutdown, LockablePrice, PriceProvider, Ratio};
 
 mod mock;
 mod tests;
@@ -61,6 +62,10 @@ pub mod module {
 		/// Price source to freeze currencies' price
 		type PriceSource: LockablePrice<CurrencyId>;
 
+		/// Price source of the locked prices to value collaterals when
+		/// refunding a single type of collateral
+		type LockedPriceSource: PriceProvider<CurrencyId>;
+
 		/// CDP treasury to escrow collateral assets after settlement
 		type CDPTreasury: CDPTreasury<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
 
@@ -88,6 +93,12 @@ pub mod module {
 		ExistPotentialSurplus,
 		/// Exist unhandled debit, means settlement has not been completed
 		ExistUnhandledDebit,
+		/// Invalid collateral type
+		InvalidCollateralType,
+		/// Feed price is invalid
+		InvalidFeedPrice,
+		/// The remaining collateral is not enough to refund
+		NotEnoughCollateral,
 	}
 
 	#[pallet::event]
@@ -210,6 +221,60 @@ pub mod module {
 			Self::deposit_event(Event::Refund(who, amount, refund_assets));
 			Ok(())
 		}
+
+		/// Refund a single type of remaining collateral assets to caller.
+		/// The stable currency is valued at the same share of all remaining
+		/// collateral assets as `refund_collaterals`, at the locked prices.
+		///
+		/// - `currency_id`: collateral type to refund.
+		/// - `amount`: stable currency amount used to refund.
+		#[pallet::weight(T::WeightInfo::refund_collateral(T::CollateralCurrencyIds::get().len() as u32))]
+		#[transactional]
+		pub fn refund_collateral(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			#[pallet::compact] amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::can_refund(), Error::<T>::CanNotRefund);
+			let collateral_currency_ids = T::CollateralCurrencyIds::get();
+			ensure!(
+				collateral_currency_ids.contains(&currency_id),
+				Error::<T>::InvalidCollateralType
+			);
+
+			let refund_ratio: Ratio = <T as Config>::CDPTreasury::get_debit_proportion(amount);
+
+			// the total value of all remaining collaterals at the locked prices
+			let mut total_collateral_value: Balance = Zero::zero();
+			for collateral_currency_id in collateral_currency_ids {
+				let total_collateral = <T as Config>::CDPTreasury::get_total_collaterals(collateral_currency_id);
+				if !total_collateral.is_zero() {
+					let price =
+						T::LockedPriceSource::get_price(collateral_currency_id).ok_or(Error::<T>::InvalidFeedPrice)?;
+					total_collateral_value =
+						total_collateral_value.saturating_add(price.saturating_mul_int(total_collateral));
+				}
+			}
+
+			let refund_amount = T::LockedPriceSource::get_price(currency_id)
+				.and_then(|price| price.reciprocal())
+				.and_then(|n| n.checked_mul_int(refund_ratio.saturating_mul_int(total_collateral_value)))
+				.ok_or(Error::<T>::InvalidFeedPrice)?;
+			ensure!(
+				refund_amount <= <T as Config>::CDPTreasury::get_total_collaterals(currency_id),
+				Error::<T>::NotEnoughCollateral
+			);
+
+			// burn caller's stable currency by CDP treasury
+			<T as Config>::CDPTreasury::burn_debit(&who, amount)?;
+
+			// refund collateral to caller by CDP treasury
+			<T as Config>::CDPTreasury::withdraw_collateral(&who, currency_id, refund_amount)?;
+
+			Self::deposit_event(Event::Refund(who, amount, vec![(currency_id, refund_amount)]));
+			Ok(())
+		}
 	}
 }
 
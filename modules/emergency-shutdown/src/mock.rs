@@ -31,7 +31,7 @@ use sp_runtime::{
 	traits::{AccountIdConversion, Convert, IdentityLookup},
 	DispatchResult,
 };
-use support::{AuctionManager, LockablePrice};
+use support::{AuctionManager, LockablePrice, Price, PriceProvider};
 
 pub type AccountId = u128;
 pub type AuctionId = u32;
@@ -158,6 +158,17 @@ impl LockablePrice<CurrencyId> for MockLockablePrice {
 	}
 }
 
+pub struct MockLockedPriceSource;
+impl PriceProvider<CurrencyId> for MockLockedPriceSource {
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		match currency_id {
+			BTC => Some(Price::saturating_from_integer(10)),
+			DOT => Some(Price::saturating_from_integer(1)),
+			_ => None,
+		}
+	}
+}
+
 pub struct MockAuctionManager;
 impl AuctionManager<AccountId> for MockAuctionManager {
 	type Balance = Balance;
@@ -201,12 +212,14 @@ impl cdp_treasury::Config for Runtime {
 	type Event = Event;
 	type Currency = Currencies;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type AuctionManagerHandler = MockAuctionManager;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type DEX = ();
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type IdleScheduler = ();
 	type WeightInfo = ();
 }
 
@@ -218,6 +231,7 @@ impl Config for Runtime {
 	type Event = Event;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type PriceSource = MockLockablePrice;
+	type LockedPriceSource = MockLockedPriceSource;
 	type CDPTreasury = CDPTreasuryModule;
 	type AuctionManagerHandler = MockAuctionManager;
 	type ShutdownOrigin = EnsureSignedBy<One, AccountId>;
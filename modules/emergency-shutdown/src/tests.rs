@@ -23,6 +23,7 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{Event, *};
+use orml_traits::MultiCurrency;
 use sp_runtime::traits::BadOrigin;
 
 #[test]
@@ -80,3 +81,61 @@ fn refund_collaterals_fail() {
 		);
 	});
 }
+
+#[test]
+fn refund_collateral_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&BOB, BTC, 100));
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&BOB, DOT, 1000));
+		assert_ok!(CDPTreasuryModule::issue_debit(&ALICE, 400, true));
+		assert_ok!(CDPTreasuryModule::issue_debit(&BOB, 600, true));
+		assert_noop!(
+			EmergencyShutdownModule::refund_collateral(Origin::signed(ALICE), BTC, 100),
+			Error::<Runtime>::CanNotRefund,
+		);
+
+		assert_ok!(EmergencyShutdownModule::emergency_shutdown(Origin::signed(1)));
+		assert_ok!(EmergencyShutdownModule::open_collateral_refund(Origin::signed(1)));
+		assert_noop!(
+			EmergencyShutdownModule::refund_collateral(Origin::signed(ALICE), AUSD, 100),
+			Error::<Runtime>::InvalidCollateralType,
+		);
+
+		// 100 of 1000 stable currency is worth 200 of the total collateral value 2000
+		assert_ok!(EmergencyShutdownModule::refund_collateral(
+			Origin::signed(ALICE),
+			BTC,
+			100
+		));
+		System::assert_last_event(Event::EmergencyShutdownModule(crate::Event::Refund(
+			ALICE,
+			100,
+			vec![(BTC, 20)],
+		)));
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 300);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 1020);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 80);
+
+		// 600 of 900 stable currency is worth 1199 DOT, more than the remaining DOT
+		assert_noop!(
+			EmergencyShutdownModule::refund_collateral(Origin::signed(BOB), DOT, 600),
+			Error::<Runtime>::NotEnoughCollateral,
+		);
+
+		// 450 of 900 stable currency is worth 900 of the total collateral value 1800
+		assert_ok!(EmergencyShutdownModule::refund_collateral(
+			Origin::signed(BOB),
+			DOT,
+			450
+		));
+		System::assert_last_event(Event::EmergencyShutdownModule(crate::Event::Refund(
+			BOB,
+			450,
+			vec![(DOT, 900)],
+		)));
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 150);
+		assert_eq!(Currencies::free_balance(DOT, &BOB), 900);
+		assert_eq!(CDPTreasuryModule::total_collaterals(DOT), 100);
+	});
+}
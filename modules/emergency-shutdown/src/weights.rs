@@ -51,6 +51,7 @@ pub trait WeightInfo {
 	fn emergency_shutdown(c: u32, ) -> Weight;
 	fn open_collateral_refund() -> Weight;
 	fn refund_collaterals(c: u32, ) -> Weight;
+	fn refund_collateral(c: u32, ) -> Weight;
 }
 
 /// Weights for module_emergency_shutdown using the Acala node and recommended hardware.
@@ -78,6 +79,14 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
 	}
+	fn refund_collateral(c: u32, ) -> Weight {
+		(126_000_000 as Weight)
+			// Standard Error: 215_000
+			.saturating_add((9_900_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -104,4 +113,12 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
 	}
+	fn refund_collateral(c: u32, ) -> Weight {
+		(126_000_000 as Weight)
+			// Standard Error: 215_000
+			.saturating_add((9_900_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
 }
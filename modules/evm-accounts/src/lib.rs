@@ -30,7 +30,7 @@ use codec::Encode;
 use frame_support::{
 	ensure,
 	pallet_prelude::*,
-	traits::{Currency, IsType, OnKilledAccount},
+	traits::{Currency, IsType, OnKilledAccount, StorageVersion},
 	transactional,
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
@@ -48,6 +48,7 @@ use sp_runtime::{
 };
 use sp_std::{marker::PhantomData, vec::Vec};
 
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -79,7 +80,7 @@ pub mod module {
 	}
 
 	#[pallet::event]
-	#[pallet::generate_deposit(fn deposit_event)]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	#[pallet::metadata(T::AccountId = "AccountId")]
 	pub enum Event<T: Config> {
 		/// Mapping between Substrate accounts and EVM accounts
@@ -116,7 +117,11 @@ pub mod module {
 	#[pallet::getter(fn evm_addresses)]
 	pub type EvmAddresses<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, EvmAddress, OptionQuery>;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -125,7 +130,8 @@ pub mod module {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Claim account mapping between Substrate accounts and EVM accounts.
-		/// Ensure eth_address has not been mapped.
+		/// Ensure eth_address has not been mapped. The default address claimed
+		/// automatically remains linked to the caller's account.
 		///
 		/// - `eth_address`: The address to bind to the caller's account
 		/// - `eth_signature`: A signature generated by the address to prove ownership
@@ -138,8 +144,13 @@ pub mod module {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			// ensure account_id and eth_address has not been mapped
-			ensure!(!EvmAddresses::<T>::contains_key(&who), Error::<T>::AccountIdHasMapped);
+			// ensure account_id has not been mapped, except to its default address,
+			// and eth_address has not been mapped
+			let default_address = account_to_default_evm_address(&who);
+			ensure!(
+				EvmAddresses::<T>::get(&who).map_or(true, |address| address == default_address),
+				Error::<T>::AccountIdHasMapped
+			);
 			ensure!(
 				!Accounts::<T>::contains_key(eth_address),
 				Error::<T>::EthAddressHasMapped
@@ -157,6 +168,15 @@ pub mod module {
 				T::TransferAll::transfer_all(&account_id, &who)?;
 			}
 
+			// keep the default address linked to `origin`
+			if !Accounts::<T>::contains_key(default_address) {
+				let default_account_id = T::AddressMapping::get_account_id(&default_address);
+				if frame_system::Pallet::<T>::account_exists(&default_account_id) {
+					T::TransferAll::transfer_all(&default_account_id, &who)?;
+				}
+				Accounts::<T>::insert(default_address, &who);
+			}
+
 			Accounts::<T>::insert(eth_address, &who);
 			EvmAddresses::<T>::insert(&who, eth_address);
 
@@ -236,7 +256,7 @@ impl<T: Config> Pallet<T> {
 
 // Creates a an EvmAddress from an AccountId by appending the bytes "evm:" to
 // the account_id and hashing it.
-fn account_to_default_evm_address(account_id: &impl Encode) -> EvmAddress {
+pub(crate) fn account_to_default_evm_address(account_id: &impl Encode) -> EvmAddress {
 	let payload = (b"evm:", account_id);
 	EvmAddress::from_slice(&payload.using_encoded(blake2_256)[0..20])
 }
@@ -276,8 +296,8 @@ where
 		})
 	}
 
-	// Returns the EVM address associated with an account ID and generates an
-	// account mapping if no association exists.
+	// Returns the EVM address associated with an account ID and claims the
+	// default EVM address if no association exists.
 	fn get_or_create_evm_address(account_id: &T::AccountId) -> EvmAddress {
 		Self::get_evm_address(account_id).unwrap_or_else(|| {
 			let addr = account_to_default_evm_address(account_id);
@@ -286,6 +306,8 @@ where
 			Accounts::<T>::insert(&addr, &account_id);
 			EvmAddresses::<T>::insert(&account_id, &addr);
 
+			Pallet::<T>::deposit_event(Event::ClaimAccount(account_id.clone(), addr));
+
 			addr
 		})
 	}
@@ -307,7 +329,7 @@ pub struct CallKillAccount<T>(PhantomData<T>);
 impl<T: Config> OnKilledAccount<T::AccountId> for CallKillAccount<T> {
 	fn on_killed_account(who: &T::AccountId) {
 		// remove the reserve mapping that could be created by
-		// `get_or_create_evm_address`
+		// `get_or_create_evm_address` or `claim_account`
 		Accounts::<T>::remove(account_to_default_evm_address(who.into_ref()));
 
 		// remove mapping created by `claim_account`
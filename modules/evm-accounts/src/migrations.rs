@@ -0,0 +1,91 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{account_to_default_evm_address, Accounts, Config, EvmAddresses, Pallet};
+use frame_support::{
+	log,
+	traits::{Get, StorageVersion},
+	weights::Weight,
+};
+use module_support::AddressMapping;
+
+pub mod v1 {
+	use super::*;
+
+	/// Link the default address of accounts which claimed a custom address
+	/// before `claim_account` kept the default address linked. Applied once,
+	/// if the on-chain storage version is still 0.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() != 0 {
+			log::info!(target: "evm-accounts", "evm-accounts v1 migration is already applied, skip");
+			return T::DbWeight::get().reads(1);
+		}
+		log::info!(target: "evm-accounts", "Migrating evm-accounts v1");
+
+		let mut reads: Weight = 1;
+		let mut writes: Weight = 1;
+		for (account_id, address) in EvmAddresses::<T>::iter() {
+			reads += 1;
+			let default_address = account_to_default_evm_address(&account_id);
+			if address == default_address || Accounts::<T>::contains_key(default_address) {
+				continue;
+			}
+
+			// skip if the default address already holds an account, to avoid stranding its funds
+			reads += 2;
+			let default_account_id = T::AddressMapping::get_account_id(&default_address);
+			if frame_system::Pallet::<T>::account_exists(&default_account_id) {
+				continue;
+			}
+
+			Accounts::<T>::insert(default_address, &account_id);
+			writes += 1;
+		}
+		StorageVersion::new(1).put::<Pallet<T>>();
+		log::info!(target: "evm-accounts", "Completed evm-accounts migration to v1");
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	/// The number of claimed addresses, which are not changed by the
+	/// migration.
+	#[cfg(feature = "try-runtime")]
+	pub fn pre_migrate<T: Config>() -> Result<u32, &'static str> {
+		Ok(EvmAddresses::<T>::iter_keys().count() as u32)
+	}
+
+	/// Ensure the `count` claimed addresses are kept and still linked to
+	/// their accounts, and the storage version is bumped.
+	#[cfg(feature = "try-runtime")]
+	pub fn post_migrate<T: Config>(count: u32) -> Result<(), &'static str> {
+		frame_support::ensure!(
+			StorageVersion::get::<Pallet<T>>() == 1,
+			"evm-accounts v1: storage version is not bumped"
+		);
+		let mut claimed: u32 = 0;
+		for (account_id, address) in EvmAddresses::<T>::iter() {
+			claimed += 1;
+			frame_support::ensure!(
+				Accounts::<T>::get(address) == Some(account_id),
+				"evm-accounts v1: claimed address is not linked to its account"
+			);
+		}
+		frame_support::ensure!(claimed == count, "evm-accounts v1: claimed addresses are changed");
+		Ok(())
+	}
+}
@@ -156,6 +156,10 @@ fn account_to_evm() {
 
 		assert!(EvmAddressMapping::<Runtime>::is_linked(&ALICE, &alice_evm_account));
 		assert!(EvmAddressMapping::<Runtime>::is_linked(&ALICE, &default_evm_account));
+		assert_eq!(
+			EvmAddressMapping::<Runtime>::get_account_id(&default_evm_account),
+			ALICE
+		);
 	});
 }
 
@@ -181,6 +185,22 @@ fn account_to_evm_with_create_default() {
 
 		let alice_evm_account = EvmAccountsModule::eth_address(&alice());
 
+		// the default address can be replaced by a custom keypair
+		assert_ok!(EvmAccountsModule::claim_account(
+			Origin::signed(ALICE),
+			alice_evm_account,
+			EvmAccountsModule::eth_sign(&alice(), &ALICE.encode(), &[][..])
+		));
+		assert_eq!(
+			EvmAddressMapping::<Runtime>::get_evm_address(&ALICE),
+			Some(alice_evm_account)
+		);
+		assert_eq!(EvmAddressMapping::<Runtime>::get_account_id(&alice_evm_account), ALICE);
+		assert_eq!(
+			EvmAddressMapping::<Runtime>::get_account_id(&default_evm_account),
+			ALICE
+		);
+
 		assert_noop!(
 			EvmAccountsModule::claim_account(
 				Origin::signed(ALICE),
@@ -191,3 +211,53 @@ fn account_to_evm_with_create_default() {
 		);
 	});
 }
+
+#[test]
+fn get_or_create_evm_address_claims_default_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		let default_evm_account = EvmAddress::from_str("f0bd9ffde7f9f4394d8cc1d86bf24d87e5d5a9a9").unwrap();
+		assert_eq!(
+			EvmAddressMapping::<Runtime>::get_or_create_evm_address(&ALICE),
+			default_evm_account
+		);
+		System::assert_last_event(Event::EvmAccountsModule(crate::Event::ClaimAccount(
+			ALICE,
+			default_evm_account,
+		)));
+	});
+}
+
+#[test]
+fn migrate_to_v1_links_default_address() {
+	ExtBuilder::default().build().execute_with(|| {
+		let default_evm_account = EvmAddress::from_str("f0bd9ffde7f9f4394d8cc1d86bf24d87e5d5a9a9").unwrap();
+		let alice_evm_account = EvmAccountsModule::eth_address(&alice());
+
+		// mapping claimed before the default address was kept linked
+		Accounts::<Runtime>::insert(alice_evm_account, ALICE);
+		EvmAddresses::<Runtime>::insert(ALICE, alice_evm_account);
+		assert_ne!(
+			EvmAddressMapping::<Runtime>::get_account_id(&default_evm_account),
+			ALICE
+		);
+
+		StorageVersion::new(0).put::<EvmAccountsModule>();
+
+		migrations::v1::migrate::<Runtime>();
+		assert_eq!(StorageVersion::get::<EvmAccountsModule>(), 1);
+
+		assert_eq!(
+			EvmAddressMapping::<Runtime>::get_account_id(&default_evm_account),
+			ALICE
+		);
+		assert_eq!(
+			EvmAddressMapping::<Runtime>::get_evm_address(&ALICE),
+			Some(alice_evm_account)
+		);
+
+		// the migration is applied only once
+		Accounts::<Runtime>::remove(default_evm_account);
+		migrations::v1::migrate::<Runtime>();
+		assert!(!Accounts::<Runtime>::contains_key(default_evm_account));
+	});
+}
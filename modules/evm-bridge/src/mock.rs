@@ -107,6 +107,8 @@ ord_parameter_types! {
 	pub const StorageDepositPerByte: u128 = 10;
 	pub const DeveloperDeposit: u64 = 1000;
 	pub const DeploymentFee: u64 = 200;
+	pub const PublicationFee: u64 = 200;
+	pub const BaseFeeHistoryLength: u32 = 10;
 }
 
 impl module_evm::Config for Runtime {
@@ -125,11 +127,13 @@ impl module_evm::Config for Runtime {
 
 	type DeveloperDeposit = DeveloperDeposit;
 	type DeploymentFee = DeploymentFee;
+	type PublicationFee = PublicationFee;
 	type TreasuryAccount = TreasuryAccount;
 	type FreeDeploymentOrigin = EnsureSignedBy<CouncilAccount, AccountId32>;
 
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
+	type BaseFeeHistoryLength = BaseFeeHistoryLength;
 	type WeightInfo = ();
 }
 
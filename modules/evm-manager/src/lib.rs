@@ -22,17 +22,28 @@
 //!
 //! Evm manager module provides common support features for Evm, including:
 //! - A two way mapping between `u32` and `Erc20 address` so user can use Erc20 address as LP token.
+//! - Permissionless registration of deployed Erc20 contracts as `CurrencyId::Erc20`. The registrant
+//!   reserves a deposit, the name, symbol and decimals are read from the contract at registration,
+//!   and the veto origin can remove a listing and slash its deposit.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::{ensure, pallet_prelude::*, require_transactional, traits::Currency};
+use frame_support::{
+	ensure,
+	pallet_prelude::*,
+	require_transactional,
+	traits::{Currency, ReservableCurrency},
+	transactional,
+};
+use frame_system::pallet_prelude::*;
 use module_support::{CurrencyIdMapping, EVMBridge, InvokeContext};
 use primitives::{
 	currency::TokenInfo,
 	evm::{Erc20Info, EvmAddress},
 	*,
 };
+use sp_runtime::traits::Saturating;
 use sp_std::{
 	convert::{TryFrom, TryInto},
 	vec::Vec,
@@ -40,8 +51,10 @@ use sp_std::{
 
 mod mock;
 mod tests;
+pub mod weights;
 
 pub use module::*;
+pub use weights::WeightInfo;
 
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
@@ -51,8 +64,20 @@ pub mod module {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
-		type Currency: Currency<Self::AccountId>;
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		type Currency: ReservableCurrency<Self::AccountId>;
 		type EVMBridge: EVMBridge<Self::AccountId, BalanceOf<Self>>;
+
+		/// The deposit reserved from the registrant of an Erc20.
+		#[pallet::constant]
+		type Erc20RegistrationDeposit: Get<BalanceOf<Self>>;
+
+		/// The origin which may veto registered Erc20.
+		type VetoOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
 	}
 
 	/// Error for evm accounts module.
@@ -60,6 +85,20 @@ pub mod module {
 	pub enum Error<T> {
 		/// CurrencyId existed
 		CurrencyIdExisted,
+		/// The Erc20 has already been mapped
+		Erc20AlreadyRegistered,
+		/// The Erc20 was not registered
+		Erc20NotRegistered,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An Erc20 has been registered. \[registrant, currency_id\]
+		Erc20Registered(T::AccountId, CurrencyId),
+		/// A registered Erc20 has been vetoed. \[registrant, currency_id,
+		/// slashed_deposit\]
+		Erc20Vetoed(T::AccountId, CurrencyId, BalanceOf<T>),
 	}
 
 	/// Mapping between u32 and Erc20 address.
@@ -71,6 +110,15 @@ pub mod module {
 	#[pallet::getter(fn currency_id_map)]
 	pub type CurrencyIdMap<T: Config> = StorageMap<_, Twox64Concat, u32, Erc20Info, OptionQuery>;
 
+	/// The registrant and reserved deposit of permissionlessly registered
+	/// Erc20.
+	///
+	/// Erc20Registrations: map EvmAddress => Option<(AccountId, Balance)>
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_registrations)]
+	pub type Erc20Registrations<T: Config> =
+		StorageMap<_, Twox64Concat, EvmAddress, (T::AccountId, BalanceOf<T>), OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -78,7 +126,44 @@ pub mod module {
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	impl<T: Config> Pallet<T> {
+		/// Register the deployed Erc20 at `address` as `CurrencyId::Erc20`,
+		/// reserving the registration deposit. The name, symbol and decimals
+		/// are read from the contract.
+		#[pallet::weight(T::WeightInfo::register_erc20())]
+		#[transactional]
+		pub fn register_erc20(origin: OriginFor<T>, address: EvmAddress) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				Self::currency_id_map(Into::<u32>::into(DexShare::Erc20(address))).is_none(),
+				Error::<T>::Erc20AlreadyRegistered
+			);
+
+			let deposit = T::Erc20RegistrationDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			EvmCurrencyIdMapping::<T>::set_erc20_mapping(address)?;
+			Erc20Registrations::<T>::insert(address, (&who, deposit));
+
+			Self::deposit_event(Event::Erc20Registered(who, CurrencyId::Erc20(address)));
+			Ok(())
+		}
+
+		/// Remove the registered Erc20 at `address`, slashing the deposit of
+		/// the registrant.
+		#[pallet::weight(T::WeightInfo::veto_erc20())]
+		#[transactional]
+		pub fn veto_erc20(origin: OriginFor<T>, address: EvmAddress) -> DispatchResult {
+			T::VetoOrigin::ensure_origin(origin)?;
+			let (registrant, deposit) = Erc20Registrations::<T>::take(address).ok_or(Error::<T>::Erc20NotRegistered)?;
+
+			CurrencyIdMap::<T>::remove(Into::<u32>::into(DexShare::Erc20(address)));
+			let (_, unslashed) = T::Currency::slash_reserved(&registrant, deposit);
+			let slashed = deposit.saturating_sub(unslashed);
+
+			Self::deposit_event(Event::Erc20Vetoed(registrant, CurrencyId::Erc20(address), slashed));
+			Ok(())
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {}
@@ -22,10 +22,14 @@
 
 use super::*;
 use frame_support::{assert_noop, assert_ok};
-use mock::{alice, deploy_contracts, erc20_address, erc20_address_not_exists, ExtBuilder, Runtime};
+use mock::{
+	alice, deploy_contracts, erc20_address, erc20_address_not_exists, Balances, CouncilAccount, Event, EvmManager,
+	ExtBuilder, Origin, Runtime, System,
+};
 use orml_utilities::with_transaction_result;
 use primitives::TokenSymbol;
 use sp_core::H160;
+use sp_runtime::traits::BadOrigin;
 use std::str::FromStr;
 
 #[test]
@@ -456,3 +460,80 @@ fn decode_evm_address_works() {
 			);
 		});
 }
+
+#[test]
+fn register_erc20_works() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+
+			assert_noop!(
+				EvmManager::register_erc20(Origin::signed(alice()), erc20_address_not_exists()),
+				module_evm_bridge::Error::<Runtime>::InvalidReturnValue,
+			);
+
+			assert_ok!(EvmManager::register_erc20(Origin::signed(alice()), erc20_address()));
+			System::assert_last_event(Event::EvmManager(crate::Event::Erc20Registered(
+				alice(),
+				CurrencyId::Erc20(erc20_address()),
+			)));
+			assert_eq!(Balances::reserved_balance(alice()), 1_000);
+			assert_eq!(EvmManager::erc20_registrations(erc20_address()), Some((alice(), 1_000)));
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::name(CurrencyId::Erc20(erc20_address())),
+				Some(
+					b"long string name, long string name, long string name, long string name, long string name"[..32]
+						.to_vec()
+				)
+			);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::decimals(CurrencyId::Erc20(erc20_address())),
+				Some(17)
+			);
+
+			assert_noop!(
+				EvmManager::register_erc20(Origin::signed(alice()), erc20_address()),
+				Error::<Runtime>::Erc20AlreadyRegistered,
+			);
+		});
+}
+
+#[test]
+fn veto_erc20_works() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+
+			assert_noop!(
+				EvmManager::veto_erc20(Origin::signed(CouncilAccount::get()), erc20_address()),
+				Error::<Runtime>::Erc20NotRegistered,
+			);
+
+			assert_ok!(EvmManager::register_erc20(Origin::signed(alice()), erc20_address()));
+			assert_noop!(
+				EvmManager::veto_erc20(Origin::signed(alice()), erc20_address()),
+				BadOrigin,
+			);
+
+			assert_ok!(EvmManager::veto_erc20(
+				Origin::signed(CouncilAccount::get()),
+				erc20_address()
+			));
+			System::assert_last_event(Event::EvmManager(crate::Event::Erc20Vetoed(
+				alice(),
+				CurrencyId::Erc20(erc20_address()),
+				1_000,
+			)));
+			assert_eq!(Balances::reserved_balance(alice()), 0);
+			assert_eq!(Balances::free_balance(alice()), 1_000_000_000_000 - 1_000);
+			assert_eq!(EvmManager::erc20_registrations(erc20_address()), None);
+			assert_eq!(
+				EvmCurrencyIdMapping::<Runtime>::get_evm_address(DexShare::Erc20(erc20_address()).into()),
+				None
+			);
+		});
+}
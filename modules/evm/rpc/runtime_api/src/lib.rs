@@ -20,17 +20,31 @@
 #![allow(clippy::all)]
 
 use ethereum_types::H160;
-use primitives::evm::{CallInfo, CreateInfo, EstimateResourcesRequest};
+use primitives::evm::{
+	CallInfo, ContractStorageStatement, CreateInfo, EstimateResourcesRequest, StateOverride, TraceOutcome, TracerConfig,
+};
 use sp_runtime::{
 	codec::Codec,
-	traits::{MaybeDisplay, MaybeFromStr},
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
 };
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait EVMRuntimeRPCApi<Balance> where
 		Balance: Codec + MaybeDisplay + MaybeFromStr,
 	{
+		#[changed_in(2)]
+		fn call(
+			from: H160,
+			to: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			estimate: bool,
+		) -> Result<CallInfo, sp_runtime::DispatchError>;
+
 		fn call(
 			from: H160,
 			to: H160,
@@ -39,8 +53,19 @@ sp_api::decl_runtime_apis! {
 			gas_limit: u64,
 			storage_limit: u32,
 			estimate: bool,
+			state_overrides: Vec<(H160, StateOverride)>,
 		) -> Result<CallInfo, sp_runtime::DispatchError>;
 
+		#[changed_in(2)]
+		fn create(
+			from: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			estimate: bool,
+		) -> Result<CreateInfo, sp_runtime::DispatchError>;
+
 		fn create(
 			from: H160,
 			data: Vec<u8>,
@@ -48,8 +73,23 @@ sp_api::decl_runtime_apis! {
 			gas_limit: u64,
 			storage_limit: u32,
 			estimate: bool,
+			state_overrides: Vec<(H160, StateOverride)>,
 		) -> Result<CreateInfo, sp_runtime::DispatchError>;
 
 		fn get_estimate_resources_request(data: Vec<u8>) -> Result<EstimateResourcesRequest, sp_runtime::DispatchError>;
+
+		fn base_fee_per_gas() -> Balance;
+
+		fn base_fee_history(block_count: u32) -> Vec<Balance>;
+
+		fn storage_statement(contract: H160) -> Option<ContractStorageStatement<Balance>>;
+
+		/// Apply `extrinsics` on top of the initialized block, then trace the EVM
+		/// executions of `extrinsic`.
+		fn trace_extrinsic(
+			extrinsics: Vec<<Block as BlockT>::Extrinsic>,
+			extrinsic: <Block as BlockT>::Extrinsic,
+			tracer: TracerConfig,
+		) -> Result<TraceOutcome, sp_runtime::DispatchError>;
 	}
 }
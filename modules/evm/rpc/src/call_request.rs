@@ -16,10 +16,11 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use ethereum_types::{H160, U256};
+use ethereum_types::{H160, H256, U256};
 use serde::{Deserialize, Serialize};
 use sp_core::Bytes;
 use sp_rpc::number::NumberOrHex;
+use std::collections::BTreeMap;
 
 /// Call request
 #[derive(Debug, Default, PartialEq, Deserialize, Clone)]
@@ -40,6 +41,19 @@ pub struct CallRequest {
 	pub data: Option<Bytes>,
 }
 
+/// State override of an account for the call
+#[derive(Debug, Default, PartialEq, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct CallStateOverride {
+	/// Balance
+	pub balance: Option<NumberOrHex>,
+	/// Code
+	pub code: Option<Bytes>,
+	/// Storage slots
+	pub state_diff: Option<BTreeMap<H256, H256>>,
+}
+
 /// EstimateResources response
 #[derive(Debug, Eq, PartialEq, Default, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
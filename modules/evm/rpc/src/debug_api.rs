@@ -0,0 +1,39 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! EVM debug rpc interface.
+
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use module_evm::{TraceOutcome, TracerConfig};
+
+pub use rpc_impl_EVMDebugApi::gen_server::EVMDebugApi as EVMDebugApiServer;
+
+/// EVM debug rpc interface.
+#[rpc(server)]
+pub trait EVMDebugApi<BlockHash> {
+	/// Re-execute the extrinsic of `extrinsic_hash` in the block `at`, returning
+	/// the call frames or opcodes of its EVM executions.
+	#[rpc(name = "debug_traceTransaction")]
+	fn trace_transaction(
+		&self,
+		extrinsic_hash: BlockHash,
+		at: BlockHash,
+		tracer: Option<TracerConfig>,
+	) -> Result<TraceOutcome>;
+}
@@ -22,17 +22,24 @@ use ethereum_types::H160;
 use jsonrpc_core::Result;
 use jsonrpc_derive::rpc;
 use sp_core::Bytes;
+use std::collections::BTreeMap;
 
 pub use rpc_impl_EVMApi::gen_server::EVMApi as EVMApiServer;
 
-use crate::call_request::{CallRequest, EstimateResourcesResponse};
+use crate::call_request::{CallRequest, CallStateOverride, EstimateResourcesResponse};
 
 /// EVM rpc interface.
 #[rpc(server)]
 pub trait EVMApi<BlockHash> {
-	/// Call contract, returning the output data.
+	/// Call contract, returning the output data. The state of accounts can
+	/// be overridden before the call.
 	#[rpc(name = "evm_call")]
-	fn call(&self, _: CallRequest, at: Option<BlockHash>) -> Result<Bytes>;
+	fn call(
+		&self,
+		_: CallRequest,
+		at: Option<BlockHash>,
+		state_overrides: Option<BTreeMap<H160, CallStateOverride>>,
+	) -> Result<Bytes>;
 
 	/// Estimate resources needed for execution of given contract.
 	#[rpc(name = "evm_estimateResources")]
@@ -41,5 +48,6 @@ pub trait EVMApi<BlockHash> {
 		from: H160,
 		unsigned_extrinsic: Bytes,
 		at: Option<BlockHash>,
+		state_overrides: Option<BTreeMap<H160, CallStateOverride>>,
 	) -> Result<EstimateResourcesResponse>;
 }
@@ -23,27 +23,30 @@ use frame_support::log;
 use jsonrpc_core::{Error, ErrorCode, Result, Value};
 use pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi;
 use rustc_hex::ToHex;
+use sc_client_api::BlockBackend;
 use sc_rpc_api::DenyUnsafe;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, Core, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_core::{Bytes, Decode};
 use sp_rpc::number::NumberOrHex;
 use sp_runtime::{
 	codec::Codec,
 	generic::BlockId,
-	traits::{self, Block as BlockT, MaybeDisplay, MaybeFromStr},
+	traits::{self, Block as BlockT, Hash as HashT, Header as HeaderT, MaybeDisplay, MaybeFromStr},
 	SaturatedConversion,
 };
 use std::convert::{TryFrom, TryInto};
-use std::{marker::PhantomData, sync::Arc};
+use std::{collections::BTreeMap, marker::PhantomData, sync::Arc};
 
-use call_request::{CallRequest, EstimateResourcesResponse};
-pub use module_evm::{ExitError, ExitReason};
+use call_request::{CallRequest, CallStateOverride, EstimateResourcesResponse};
+pub use module_evm::{CallInfo, CreateInfo, ExitError, ExitReason, StateOverride, TraceOutcome, TracerConfig};
 pub use module_evm_rpc_runtime_api::EVMRuntimeRPCApi;
 
+pub use crate::debug_api::{EVMDebugApi as EVMDebugApiT, EVMDebugApiServer};
 pub use crate::evm_api::{EVMApi as EVMApiT, EVMApiServer};
 
 mod call_request;
+mod debug_api;
 mod evm_api;
 
 fn internal_err<T: ToString>(message: T) -> Error {
@@ -126,6 +129,132 @@ fn to_u128(val: NumberOrHex) -> std::result::Result<u128, ()> {
 	val.into_u256().try_into().map_err(|_| ())
 }
 
+fn to_state_overrides(
+	state_overrides: Option<BTreeMap<H160, CallStateOverride>>,
+) -> Result<Vec<(H160, StateOverride)>> {
+	state_overrides
+		.unwrap_or_default()
+		.into_iter()
+		.map(|(address, state_override)| {
+			let balance = state_override
+				.balance
+				.map(|balance| {
+					to_u128(balance.clone()).map_err(|_| Error {
+						code: ErrorCode::InvalidParams,
+						message: format!("Invalid parameter balance: {:?}", balance),
+						data: None,
+					})
+				})
+				.transpose()?;
+
+			Ok((
+				address,
+				StateOverride {
+					balance,
+					code: state_override.code.map(|code| code.0),
+					state_diff: state_override
+						.state_diff
+						.map(|state_diff| state_diff.into_iter().collect()),
+				},
+			))
+		})
+		.collect()
+}
+
+impl<B, C, Balance> EVMApi<B, C, Balance>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + Send + Sync + 'static,
+	C::Api: EVMRuntimeRPCApi<B, Balance>,
+	Balance: Codec + MaybeDisplay + MaybeFromStr + Default + Send + Sync + 'static + TryFrom<u128> + Into<U256>,
+{
+	/// Whether to pass the state overrides to the runtime, they are only
+	/// supported since version 2 of the runtime api. The calls without state
+	/// overrides fall back to the version before on older runtimes.
+	fn supports_state_overrides(&self, at: &BlockId<B>, state_overrides: &[(H160, StateOverride)]) -> Result<bool> {
+		let supported = self
+			.client
+			.runtime_api()
+			.has_api_with::<dyn EVMRuntimeRPCApi<B, Balance>, _>(at, |version| version >= 2)
+			.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?;
+
+		if !supported && !state_overrides.is_empty() {
+			return Err(Error {
+				code: ErrorCode::InvalidParams,
+				message: "State overrides are not supported by the runtime".into(),
+				data: None,
+			});
+		}
+		Ok(supported)
+	}
+
+	#[allow(clippy::too_many_arguments, deprecated)]
+	fn runtime_call(
+		&self,
+		at: &BlockId<B>,
+		from: H160,
+		to: H160,
+		data: Vec<u8>,
+		value: Balance,
+		gas_limit: u64,
+		storage_limit: u32,
+		estimate: bool,
+		state_overrides: Vec<(H160, StateOverride)>,
+	) -> Result<CallInfo> {
+		let api = self.client.runtime_api();
+		let info = if self.supports_state_overrides(at, &state_overrides)? {
+			api.call(
+				at,
+				from,
+				to,
+				data,
+				value,
+				gas_limit,
+				storage_limit,
+				estimate,
+				state_overrides,
+			)
+		} else {
+			api.call_before_version_2(at, from, to, data, value, gas_limit, storage_limit, estimate)
+		};
+
+		info.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
+			.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))
+	}
+
+	#[allow(clippy::too_many_arguments, deprecated)]
+	fn runtime_create(
+		&self,
+		at: &BlockId<B>,
+		from: H160,
+		data: Vec<u8>,
+		value: Balance,
+		gas_limit: u64,
+		storage_limit: u32,
+		estimate: bool,
+		state_overrides: Vec<(H160, StateOverride)>,
+	) -> Result<CreateInfo> {
+		let api = self.client.runtime_api();
+		let info = if self.supports_state_overrides(at, &state_overrides)? {
+			api.create(
+				at,
+				from,
+				data,
+				value,
+				gas_limit,
+				storage_limit,
+				estimate,
+				state_overrides,
+			)
+		} else {
+			api.create_before_version_2(at, from, data, value, gas_limit, storage_limit, estimate)
+		};
+
+		info.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
+			.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))
+	}
+}
+
 impl<B, C, Balance> EVMApiT<<B as BlockT>::Hash> for EVMApi<B, C, Balance>
 where
 	B: BlockT,
@@ -134,10 +263,16 @@ where
 	C::Api: TransactionPaymentApi<B, Balance>,
 	Balance: Codec + MaybeDisplay + MaybeFromStr + Default + Send + Sync + 'static + TryFrom<u128> + Into<U256>,
 {
-	fn call(&self, request: CallRequest, at: Option<<B as BlockT>::Hash>) -> Result<Bytes> {
+	fn call(
+		&self,
+		request: CallRequest,
+		at: Option<<B as BlockT>::Hash>,
+		state_overrides: Option<BTreeMap<H160, CallStateOverride>>,
+	) -> Result<Bytes> {
 		self.deny_unsafe.check_if_safe()?;
 
 		let hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let state_overrides = to_state_overrides(state_overrides)?;
 
 		let CallRequest {
 			from,
@@ -152,8 +287,6 @@ where
 		let storage_limit = storage_limit.unwrap_or_else(u32::max_value); // TODO: set a limit
 		let data = data.map(|d| d.0).unwrap_or_default();
 
-		let api = self.client.runtime_api();
-
 		let balance_value = if let Some(value) = value {
 			to_u128(value).and_then(|v| TryInto::<Balance>::try_into(v).map_err(|_| ()))
 		} else {
@@ -168,37 +301,33 @@ where
 
 		match to {
 			Some(to) => {
-				let info = api
-					.call(
-						&BlockId::Hash(hash),
-						from.unwrap_or_default(),
-						to,
-						data,
-						balance_value,
-						gas_limit,
-						storage_limit,
-						false,
-					)
-					.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
-					.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))?;
+				let info = self.runtime_call(
+					&BlockId::Hash(hash),
+					from.unwrap_or_default(),
+					to,
+					data,
+					balance_value,
+					gas_limit,
+					storage_limit,
+					false,
+					state_overrides,
+				)?;
 
 				error_on_execution_failure(&info.exit_reason, &info.value)?;
 
 				Ok(Bytes(info.value))
 			}
 			None => {
-				let info = api
-					.create(
-						&BlockId::Hash(hash),
-						from.unwrap_or_default(),
-						data,
-						balance_value,
-						gas_limit,
-						storage_limit,
-						false,
-					)
-					.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
-					.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))?;
+				let info = self.runtime_create(
+					&BlockId::Hash(hash),
+					from.unwrap_or_default(),
+					data,
+					balance_value,
+					gas_limit,
+					storage_limit,
+					false,
+					state_overrides,
+				)?;
 
 				error_on_execution_failure(&info.exit_reason, &[])?;
 
@@ -212,10 +341,12 @@ where
 		from: H160,
 		unsigned_extrinsic: Bytes,
 		at: Option<<B as BlockT>::Hash>,
+		state_overrides: Option<BTreeMap<H160, CallStateOverride>>,
 	) -> Result<EstimateResourcesResponse> {
 		self.deny_unsafe.check_if_safe()?;
 
 		let hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let state_overrides = to_state_overrides(state_overrides)?;
 		let request = self
 			.client
 			.runtime_api()
@@ -262,41 +393,33 @@ where
 
 			let (used_gas, used_storage) = match to {
 				Some(to) => {
-					let info = self
-						.client
-						.runtime_api()
-						.call(
-							&BlockId::Hash(hash),
-							from.unwrap_or_default(),
-							to,
-							data,
-							balance_value,
-							gas_limit,
-							storage_limit,
-							true,
-						)
-						.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
-						.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))?;
+					let info = self.runtime_call(
+						&BlockId::Hash(hash),
+						from.unwrap_or_default(),
+						to,
+						data,
+						balance_value,
+						gas_limit,
+						storage_limit,
+						true,
+						state_overrides.clone(),
+					)?;
 
 					error_on_execution_failure(&info.exit_reason, &info.value)?;
 
 					(info.used_gas, info.used_storage)
 				}
 				None => {
-					let info = self
-						.client
-						.runtime_api()
-						.create(
-							&BlockId::Hash(hash),
-							from.unwrap_or_default(),
-							data,
-							balance_value,
-							gas_limit,
-							storage_limit,
-							true,
-						)
-						.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
-						.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))?;
+					let info = self.runtime_create(
+						&BlockId::Hash(hash),
+						from.unwrap_or_default(),
+						data,
+						balance_value,
+						gas_limit,
+						storage_limit,
+						true,
+						state_overrides.clone(),
+					)?;
 
 					error_on_execution_failure(&info.exit_reason, &[])?;
 
@@ -426,6 +549,67 @@ where
 	}
 }
 
+pub struct EVMDebugApi<B, C, Balance> {
+	client: Arc<C>,
+	deny_unsafe: DenyUnsafe,
+	_marker: PhantomData<(B, Balance)>,
+}
+
+impl<B, C, Balance> EVMDebugApi<B, C, Balance> {
+	pub fn new(client: Arc<C>, deny_unsafe: DenyUnsafe) -> Self {
+		Self {
+			client,
+			deny_unsafe,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<B, C, Balance> EVMDebugApiT<<B as BlockT>::Hash> for EVMDebugApi<B, C, Balance>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + BlockBackend<B> + HeaderBackend<B> + Send + Sync + 'static,
+	C::Api: EVMRuntimeRPCApi<B, Balance>,
+	Balance: Codec + MaybeDisplay + MaybeFromStr + Send + Sync + 'static,
+{
+	fn trace_transaction(
+		&self,
+		extrinsic_hash: <B as BlockT>::Hash,
+		at: <B as BlockT>::Hash,
+		tracer: Option<TracerConfig>,
+	) -> Result<TraceOutcome> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let block = self
+			.client
+			.block(&BlockId::Hash(at))
+			.map_err(|err| internal_err(format!("blockchain error: {:?}", err)))?
+			.ok_or_else(|| internal_err(format!("block not found: {:?}", at)))?
+			.block;
+		let (header, extrinsics) = block.deconstruct();
+
+		let index = extrinsics
+			.iter()
+			.position(|extrinsic| <B::Header as HeaderT>::Hashing::hash_of(extrinsic) == extrinsic_hash)
+			.ok_or_else(|| internal_err(format!("extrinsic not found: {:?}", extrinsic_hash)))?;
+
+		// re-execute the block on top of its parent
+		let parent = BlockId::Hash(*header.parent_hash());
+		let api = self.client.runtime_api();
+		api.initialize_block(&parent, &header)
+			.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?;
+
+		api.trace_extrinsic(
+			&parent,
+			extrinsics[..index].to_vec(),
+			extrinsics[index].clone(),
+			tracer.unwrap_or(TracerConfig::CallTracer),
+		)
+		.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
+		.map_err(|err| internal_err(format!("execution fatal: {:?}", err)))
+	}
+}
+
 #[test]
 fn decode_revert_message_should_work() {
 	use sp_core::bytes::from_hex;
@@ -56,7 +56,10 @@ pub use module_support::{
 pub use orml_traits::currency::TransferAll;
 use primitive_types::{H160, H256, U256};
 pub use primitives::{
-	evm::{Account, CallInfo, CreateInfo, EvmAddress, ExecutionInfo, Log, TransactionAction, Vicinity},
+	evm::{
+		Account, CallInfo, ContractStorageStatement, CreateInfo, EvmAddress, ExecutionInfo, Log, StateOverride,
+		TraceOutcome, TracerConfig, TransactionAction, Vicinity,
+	},
 	ReserveIdentifier, H160_PREFIX_DEXSHARE, H160_PREFIX_TOKEN, MIRRORED_NFT_ADDRESS_START, PRECOMPILE_ADDRESS_START,
 	SYSTEM_CONTRACT_ADDRESS_PREFIX,
 };
@@ -198,6 +201,11 @@ pub mod module {
 		#[pallet::constant]
 		type DeploymentFee: Get<BalanceOf<Self>>;
 
+		/// The fee for publishing the contract, which makes it callable by
+		/// everyone.
+		#[pallet::constant]
+		type PublicationFee: Get<BalanceOf<Self>>;
+
 		#[pallet::constant]
 		type TreasuryAccount: Get<Self::AccountId>;
 
@@ -209,6 +217,10 @@ pub mod module {
 		/// Find author for the current block.
 		type FindAuthor: FindAuthor<H160>;
 
+		/// The number of recent blocks to keep the base fee per gas of.
+		#[pallet::constant]
+		type BaseFeeHistoryLength: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -298,6 +310,22 @@ pub mod module {
 	#[pallet::getter(fn network_contract_index)]
 	pub type NetworkContractIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+	/// The proposed new maintainers of contracts, which become the maintainers
+	/// once they accept.
+	///
+	/// PendingMaintainers: map EvmAddress => Option<EvmAddress>
+	#[pallet::storage]
+	#[pallet::getter(fn pending_maintainers)]
+	pub type PendingMaintainers<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, EvmAddress, OptionQuery>;
+
+	/// The base fee per gas at the end of recent blocks, pruned after
+	/// `BaseFeeHistoryLength` blocks.
+	///
+	/// BaseFeePerGasHistory: map BlockNumber => Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn base_fee_per_gas_history)]
+	pub type BaseFeePerGasHistory<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, BalanceOf<T>, OptionQuery>;
+
 	/// Extrinsics origin for the current transaction.
 	///
 	/// ExtrinsicOrigin: Option<AccountId>
@@ -430,6 +458,12 @@ pub mod module {
 		ContractSetCode(EvmAddress),
 		/// Selfdestructed contract code. \[contract\]
 		ContractSelfdestructed(EvmAddress),
+		/// Proposed the new maintainer. \[contract, address\]
+		ProposedMaintainer(EvmAddress, EvmAddress),
+		/// Published contract. \[contract\]
+		ContractPublished(EvmAddress),
+		/// Charged the storage deposit of a contract. \[who, contract, amount\]
+		StorageDepositCharged(T::AccountId, EvmAddress, BalanceOf<T>),
 	}
 
 	#[pallet::error]
@@ -472,13 +506,29 @@ pub mod module {
 		UnreserveStorageFailed,
 		/// Charge storage failed
 		ChargeStorageFailed,
+		/// The caller is not the proposed maintainer
+		NotPendingMaintainer,
 	}
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// `on_initialize` to return the weight used in `on_finalize`.
+		fn on_initialize(_: T::BlockNumber) -> Weight {
+			T::DbWeight::get().writes(2)
+		}
+
+		fn on_finalize(now: T::BlockNumber) {
+			BaseFeePerGasHistory::<T>::insert(now, Self::base_fee_per_gas());
+
+			let history_length: T::BlockNumber = T::BaseFeeHistoryLength::get().into();
+			if now >= history_length {
+				BaseFeePerGasHistory::<T>::remove(now - history_length);
+			}
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -801,6 +851,75 @@ pub mod module {
 			Ok(().into())
 		}
 
+		/// Propose a new maintainer of a contract, who becomes the maintainer once
+		/// they call `accept_maintainer`. A new proposal replaces the pending one.
+		///
+		/// - `contract`: the contract whose maintainership is being transferred, the caller must be
+		///   the contract's maintainer
+		/// - `new_maintainer`: the address of the proposed maintainer
+		#[pallet::weight(<T as Config>::WeightInfo::propose_maintainer())]
+		#[transactional]
+		pub fn propose_maintainer(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			new_maintainer: EvmAddress,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let maintainer = T::AddressMapping::get_evm_address(&who).ok_or(Error::<T>::AddressNotMapped)?;
+			let contract_info = Self::accounts(contract)
+				.and_then(|account_info| account_info.contract_info)
+				.ok_or(Error::<T>::ContractNotFound)?;
+			ensure!(contract_info.maintainer == maintainer, Error::<T>::NoPermission);
+
+			PendingMaintainers::<T>::insert(contract, new_maintainer);
+
+			Pallet::<T>::deposit_event(Event::<T>::ProposedMaintainer(contract, new_maintainer));
+
+			Ok(().into())
+		}
+
+		/// Accept the maintainership of a contract proposed by `propose_maintainer`.
+		///
+		/// - `contract`: the contract whose maintainership is being transferred, the caller must be
+		///   the proposed maintainer
+		#[pallet::weight(<T as Config>::WeightInfo::accept_maintainer())]
+		#[transactional]
+		pub fn accept_maintainer(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let new_maintainer = T::AddressMapping::get_evm_address(&who).ok_or(Error::<T>::AddressNotMapped)?;
+			ensure!(
+				Self::pending_maintainers(contract) == Some(new_maintainer),
+				Error::<T>::NotPendingMaintainer
+			);
+
+			Accounts::<T>::mutate(contract, |maybe_account_info| -> DispatchResult {
+				let contract_info = maybe_account_info
+					.as_mut()
+					.and_then(|account_info| account_info.contract_info.as_mut())
+					.ok_or(Error::<T>::ContractNotFound)?;
+				contract_info.maintainer = new_maintainer;
+				Ok(())
+			})?;
+			PendingMaintainers::<T>::remove(contract);
+
+			Pallet::<T>::deposit_event(Event::<T>::ConfirmedTransferMaintainer(contract, new_maintainer));
+
+			Ok(().into())
+		}
+
+		/// Publish a contract by paying the publication fee, which makes it callable
+		/// by everyone without the approval of governance.
+		///
+		/// - `contract`: The contract to publish, the caller must be the contract's maintainer
+		#[pallet::weight(<T as Config>::WeightInfo::publish_contract())]
+		#[transactional]
+		pub fn publish_contract(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_publish(&who, contract, T::PublicationFee::get())?;
+			Pallet::<T>::deposit_event(Event::<T>::ContractPublished(contract));
+			Ok(().into())
+		}
+
 		/// Mark a given contract as deployed.
 		///
 		/// - `contract`: The contract to mark as deployed, the caller must the contract's
@@ -809,14 +928,7 @@ pub mod module {
 		#[transactional]
 		pub fn deploy(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			let address = T::AddressMapping::get_evm_address(&who).ok_or(Error::<T>::AddressNotMapped)?;
-			T::Currency::transfer(
-				&who,
-				&T::TreasuryAccount::get(),
-				T::DeploymentFee::get(),
-				ExistenceRequirement::AllowDeath,
-			)?;
-			Self::mark_deployed(contract, Some(address))?;
+			Self::do_publish(&who, contract, T::DeploymentFee::get())?;
 			Pallet::<T>::deposit_event(Event::<T>::ContractDeployed(contract));
 			Ok(().into())
 		}
@@ -893,10 +1005,61 @@ pub mod module {
 
 			Ok(().into())
 		}
+
+		/// Top up the storage deposit of a contract, so its storage can still be
+		/// changed after the reserved deposit is used up. Anyone can top up any contract.
+		///
+		/// - `contract`: The contract to top up
+		/// - `amount`: The amount to reserve for the contract's storage
+		#[pallet::weight(<T as Config>::WeightInfo::charge_storage_deposit())]
+		#[transactional]
+		pub fn charge_storage_deposit(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::accounts(contract)
+				.and_then(|account_info| account_info.contract_info)
+				.ok_or(Error::<T>::ContractNotFound)?;
+
+			// transfer then reserve, as the contract account could be a new account
+			let contract_account = T::AddressMapping::get_account_id(&contract);
+			T::Currency::transfer(&who, &contract_account, amount, ExistenceRequirement::AllowDeath)?;
+			T::Currency::reserve_named(&RESERVE_ID_STORAGE_DEPOSIT, &contract_account, amount)?;
+
+			Pallet::<T>::deposit_event(Event::<T>::StorageDepositCharged(who, contract, amount));
+
+			Ok(().into())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// The fee of one gas adjusted by the current fee multiplier, which
+	/// eth transactions pay on top of their priority fee.
+	pub fn base_fee_per_gas() -> BalanceOf<T> {
+		T::ChargeTransactionPayment::weight_to_fee(T::GasToWeight::convert(1))
+	}
+
+	/// The base fee per gas of at most `block_count` recent blocks, ordered
+	/// from the oldest to the current block.
+	pub fn base_fee_history(block_count: u32) -> Vec<BalanceOf<T>> {
+		let block_count = block_count.min(T::BaseFeeHistoryLength::get());
+		let now = frame_system::Pallet::<T>::block_number();
+		let start = now.saturating_sub(block_count.into()).saturating_add(One::one());
+
+		let mut history = vec![];
+		let mut block_number = start;
+		while block_number <= now {
+			if let Some(base_fee) = Self::base_fee_per_gas_history(block_number) {
+				history.push(base_fee);
+			}
+			block_number = block_number.saturating_add(One::one());
+		}
+		history
+	}
+
 	/// Check whether an account is empty.
 	pub fn is_account_empty(address: &H160) -> bool {
 		let account_id = T::AddressMapping::get_account_id(address);
@@ -945,6 +1108,7 @@ impl<T: Config> Pallet<T> {
 			});
 
 			AccountStorages::<T>::remove_prefix(address, None);
+			PendingMaintainers::<T>::remove(address);
 
 			let size = ContractStorageSizes::<T>::take(address);
 
@@ -1054,6 +1218,60 @@ impl<T: Config> Pallet<T> {
 		frame_system::Pallet::<T>::inc_providers(&T::AddressMapping::get_account_id(&address));
 	}
 
+	/// Override the balance, code and storage slots of accounts before
+	/// simulating a call. Only used by the runtime API, whose changes are
+	/// never committed.
+	pub fn apply_state_overrides(state_overrides: Vec<(EvmAddress, StateOverride)>) -> DispatchResult {
+		for (address, state_override) in state_overrides {
+			if let Some(balance) = state_override.balance {
+				let account_id = T::AddressMapping::get_account_id(&address);
+				T::Currency::make_free_balance_be(&account_id, balance.unique_saturated_into());
+			}
+
+			if let Some(code) = state_override.code {
+				let bounded_code: BoundedVec<u8, MaxCodeSize> =
+					code.try_into().map_err(|_| Error::<T>::ContractExceedsMaxCodeSize)?;
+				let code_hash = code_hash(bounded_code.as_slice());
+				let code_size = bounded_code.len() as u32;
+
+				CodeInfos::<T>::mutate_exists(&code_hash, |maybe_code_info| {
+					if let Some(code_info) = maybe_code_info.as_mut() {
+						code_info.ref_count = code_info.ref_count.saturating_add(1);
+					} else {
+						let new = CodeInfo {
+							code_size,
+							ref_count: 1,
+						};
+						*maybe_code_info = Some(new);
+
+						Codes::<T>::insert(&code_hash, bounded_code);
+					}
+				});
+
+				// the overridden contract can be called by everyone
+				Accounts::<T>::mutate(address, |maybe_account_info| {
+					let account_info =
+						maybe_account_info.get_or_insert_with(|| AccountInfo::<T>::new(Default::default(), None));
+					let contract_info = account_info.contract_info.get_or_insert(ContractInfo {
+						code_hash,
+						maintainer: address,
+						deployed: true,
+					});
+					contract_info.code_hash = code_hash;
+					contract_info.deployed = true;
+				});
+			}
+
+			if let Some(state_diff) = state_override.state_diff {
+				for (index, value) in state_diff {
+					AccountStorages::<T>::insert(address, index, value);
+				}
+			}
+		}
+
+		Ok(())
+	}
+
 	/// Get the account basic in EVM format.
 	pub fn account_basic(address: &EvmAddress) -> Account {
 		let account_id = T::AddressMapping::get_account_id(address);
@@ -1091,6 +1309,21 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The storage usage and deposits of the contract at `address`.
+	pub fn storage_statement(address: &EvmAddress) -> Option<ContractStorageStatement<BalanceOf<T>>> {
+		let contract_info = Self::accounts(address)?.contract_info?;
+		let used_storage = ContractStorageSizes::<T>::get(address);
+		let contract_account = T::AddressMapping::get_account_id(address);
+		let maintainer_account = T::AddressMapping::get_account_id(&contract_info.maintainer);
+
+		Some(ContractStorageStatement {
+			used_storage,
+			storage_deposit: T::Currency::reserved_balance_named(&RESERVE_ID_STORAGE_DEPOSIT, &contract_account),
+			required_deposit: T::StorageDepositPerByte::get().saturating_mul(used_storage.into()),
+			developer_deposit: T::Currency::reserved_balance_named(&RESERVE_ID_DEVELOPER_DEPOSIT, &maintainer_account),
+		})
+	}
+
 	/// Get code at given address.
 	pub fn code_at_address(address: &EvmAddress) -> BoundedVec<u8, MaxCodeSize> {
 		Self::codes(&Self::code_hash_at_address(address))
@@ -1130,6 +1363,7 @@ impl<T: Config> Pallet<T> {
 			contract_info.maintainer = new_maintainer;
 			Ok(())
 		})?;
+		PendingMaintainers::<T>::remove(contract);
 
 		Ok(())
 	}
@@ -1156,6 +1390,14 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Publish the contract of the maintainer `who`, paying `fee` to the
+	/// treasury.
+	fn do_publish(who: &T::AccountId, contract: EvmAddress, fee: BalanceOf<T>) -> DispatchResult {
+		let address = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::AddressNotMapped)?;
+		T::Currency::transfer(who, &T::TreasuryAccount::get(), fee, ExistenceRequirement::AllowDeath)?;
+		Self::mark_deployed(contract, Some(address))
+	}
+
 	/// Set the code of a contract at a given address.
 	///
 	/// - Ensures signer is maintainer or root.
@@ -154,6 +154,8 @@ ord_parameter_types! {
 	pub const StorageDepositPerByte: u64 = 10;
 	pub const DeveloperDeposit: u64 = 1000;
 	pub const DeploymentFee: u64 = 200;
+	pub const PublicationFee: u64 = 200;
+	pub const BaseFeeHistoryLength: u32 = 10;
 	pub const ChainId: u64 = 1;
 }
 
@@ -174,11 +176,13 @@ impl Config for Runtime {
 	type NetworkContractSource = NetworkContractSource;
 	type DeveloperDeposit = DeveloperDeposit;
 	type DeploymentFee = DeploymentFee;
+	type PublicationFee = PublicationFee;
 	type TreasuryAccount = TreasuryAccount;
 	type FreeDeploymentOrigin = EnsureSignedBy<CouncilAccount, AccountId32>;
 
 	type Runner = crate::runner::stack::Runner<Self>;
 	type FindAuthor = FindAuthorTruncated;
+	type BaseFeeHistoryLength = BaseFeeHistoryLength;
 	type WeightInfo = ();
 }
 
@@ -36,24 +36,36 @@ pub use primitives::{
 use sha3::{Digest, Keccak256};
 use sp_std::{rc::Rc, vec::Vec};
 
+#[cfg(not(feature = "tracing"))]
 macro_rules! event {
 	($x:expr) => {};
 }
 
 #[cfg(feature = "tracing")]
-mod tracing {
-	pub struct Tracer;
-	impl evm_runtime::tracing::EventListener for Tracer {
-		fn event(&mut self, event: evm_runtime::tracing::Event) {
-			frame_support::log::debug!(
-				target: "evm", "evm_runtime tracing: {:?}", event
-			);
-		}
-	}
+macro_rules! event {
+	($x:expr) => {
+		use crate::runner::tracing::Event::*;
+		crate::runner::tracing::with(|tracer| tracer.event($x));
+	};
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! emit_exit {
+	($capture:expr) => {
+		$capture
+	};
 }
 
 #[cfg(feature = "tracing")]
-use tracing::*;
+macro_rules! emit_exit {
+	($capture:expr) => {{
+		let capture = $capture;
+		if let Capture::Exit((ref reason, .., ref return_value)) = capture {
+			event!(Exit { reason, return_value });
+		}
+		capture
+	}};
+}
 
 pub enum StackExitKind {
 	Succeeded,
@@ -264,14 +276,14 @@ impl<'config, S: StackState<'config>> StackExecutor<'config, S> {
 			Err(e) => return e.into(),
 		}
 
-		match self.create_inner(
+		match emit_exit!(self.create_inner(
 			caller,
 			CreateScheme::Legacy { caller },
 			value,
 			init_code,
 			Some(gas_limit),
 			false,
-		) {
+		)) {
 			Capture::Exit((s, _, _)) => s,
 			Capture::Trap(_) => unreachable!(),
 		}
@@ -298,7 +310,7 @@ impl<'config, S: StackState<'config>> StackExecutor<'config, S> {
 		}
 		let code_hash = H256::from_slice(Keccak256::digest(&init_code).as_slice());
 
-		match self.create_inner(
+		match emit_exit!(self.create_inner(
 			caller,
 			CreateScheme::Create2 {
 				caller,
@@ -309,7 +321,7 @@ impl<'config, S: StackState<'config>> StackExecutor<'config, S> {
 			init_code,
 			Some(gas_limit),
 			false,
-		) {
+		)) {
 			Capture::Exit((s, _, _)) => s,
 			Capture::Trap(_) => unreachable!(),
 		}
@@ -335,14 +347,14 @@ impl<'config, S: StackState<'config>> StackExecutor<'config, S> {
 			Err(e) => return e.into(),
 		}
 
-		match self.create_inner(
+		match emit_exit!(self.create_inner(
 			caller,
 			CreateScheme::Fixed(address),
 			value,
 			init_code,
 			Some(gas_limit),
 			false,
-		) {
+		)) {
 			Capture::Exit((s, _, _)) => s,
 			Capture::Trap(_) => unreachable!(),
 		}
@@ -376,7 +388,7 @@ impl<'config, S: StackState<'config>> StackExecutor<'config, S> {
 			apparent_value: value,
 		};
 
-		match self.call_inner(
+		match emit_exit!(self.call_inner(
 			address,
 			Some(Transfer {
 				source: caller,
@@ -389,7 +401,7 @@ impl<'config, S: StackState<'config>> StackExecutor<'config, S> {
 			false,
 			false,
 			context,
-		) {
+		)) {
 			Capture::Exit((s, v)) => (s, v),
 			Capture::Trap(_) => unreachable!(),
 		}
@@ -503,6 +515,15 @@ impl<'config, S: StackState<'config>> StackExecutor<'config, S> {
 
 		let address = match self.create_address(scheme) {
 			Err(e) => {
+				event!(Create {
+					caller,
+					address: H160::default(),
+					scheme,
+					value,
+					init_code: &init_code,
+					target_gas
+				});
+
 				return Capture::Exit((ExitReason::Error(e), None, Vec::new()));
 			}
 			Ok(address) => address,
@@ -590,7 +611,11 @@ impl<'config, S: StackState<'config>> StackExecutor<'config, S> {
 
 		let mut runtime = Runtime::new(Rc::new(init_code), Rc::new(Vec::new()), context, self.config);
 
+		#[cfg(not(feature = "tracing"))]
 		let reason = self.execute(&mut runtime);
+		#[cfg(feature = "tracing")]
+		let reason = evm_runtime::tracing::using(&mut crate::runner::tracing::StepListener, || self.execute(&mut runtime));
+
 		log::debug!(target: "evm", "Create execution using address {}: {:?}", address, reason);
 
 		match reason {
@@ -756,7 +781,7 @@ impl<'config, S: StackState<'config>> StackExecutor<'config, S> {
 		#[cfg(not(feature = "tracing"))]
 		let reason = self.execute(&mut runtime);
 		#[cfg(feature = "tracing")]
-		let reason = evm_runtime::tracing::using(&mut Tracer, || self.execute(&mut runtime));
+		let reason = evm_runtime::tracing::using(&mut crate::runner::tracing::StepListener, || self.execute(&mut runtime));
 
 		log::debug!(target: "evm", "Call execution using address {}: {:?}", code_address, reason);
 
@@ -902,7 +927,7 @@ impl<'config, S: StackState<'config>> Handler for StackExecutor<'config, S> {
 		init_code: Vec<u8>,
 		target_gas: Option<u64>,
 	) -> Capture<(ExitReason, Option<H160>, Vec<u8>), Self::CreateInterrupt> {
-		self.create_inner(caller, scheme, value, init_code, target_gas, true)
+		emit_exit!(self.create_inner(caller, scheme, value, init_code, target_gas, true))
 	}
 
 	fn call(
@@ -914,7 +939,7 @@ impl<'config, S: StackState<'config>> Handler for StackExecutor<'config, S> {
 		is_static: bool,
 		context: Context,
 	) -> Capture<(ExitReason, Vec<u8>), Self::CallInterrupt> {
-		self.call_inner(
+		emit_exit!(self.call_inner(
 			code_address,
 			transfer,
 			input,
@@ -923,7 +948,7 @@ impl<'config, S: StackState<'config>> Handler for StackExecutor<'config, S> {
 			true,
 			true,
 			context,
-		)
+		))
 	}
 
 	#[inline]
@@ -0,0 +1,209 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Tracing of EVM executions, used by the tracing runtime API.
+
+use evm::{Context, CreateScheme, ExitReason, Transfer};
+use primitive_types::{H160, U256};
+pub use primitives::evm::{CallTrace, CallType, Step, TraceOutcome, TracerConfig};
+use sp_std::vec::Vec;
+
+/// Events emitted by the stack executor.
+pub enum Event<'a> {
+	Call {
+		code_address: H160,
+		transfer: &'a Option<Transfer>,
+		input: &'a [u8],
+		target_gas: Option<u64>,
+		is_static: bool,
+		context: &'a Context,
+	},
+	Create {
+		caller: H160,
+		address: H160,
+		scheme: CreateScheme,
+		value: U256,
+		init_code: &'a [u8],
+		target_gas: Option<u64>,
+	},
+	Suicide {
+		address: H160,
+		target: H160,
+		balance: U256,
+	},
+	Exit {
+		reason: &'a ExitReason,
+		return_value: &'a [u8],
+	},
+}
+
+pub struct Tracer {
+	config: TracerConfig,
+	/// The call frames which have not exited yet.
+	stack: Vec<CallTrace>,
+	calls: Vec<CallTrace>,
+	steps: Vec<Step>,
+}
+
+impl Tracer {
+	pub fn new(config: TracerConfig) -> Self {
+		Self {
+			config,
+			stack: Vec::new(),
+			calls: Vec::new(),
+			steps: Vec::new(),
+		}
+	}
+
+	pub fn event(&mut self, event: Event) {
+		match event {
+			Event::Call {
+				code_address,
+				transfer,
+				input,
+				target_gas,
+				is_static,
+				context,
+			} => {
+				let call_type = if is_static {
+					CallType::StaticCall
+				} else if code_address != context.address {
+					CallType::DelegateCall
+				} else {
+					CallType::Call
+				};
+
+				self.stack.push(CallTrace {
+					call_type,
+					from: context.caller,
+					to: context.address,
+					input: input.to_vec(),
+					value: transfer.as_ref().map_or_else(U256::zero, |transfer| transfer.value),
+					gas: target_gas.unwrap_or_default(),
+					output: Vec::new(),
+					exit_reason: None,
+					calls: Vec::new(),
+				});
+			}
+			Event::Create {
+				caller,
+				address,
+				value,
+				init_code,
+				target_gas,
+				..
+			} => {
+				self.stack.push(CallTrace {
+					call_type: CallType::Create,
+					from: caller,
+					to: address,
+					input: init_code.to_vec(),
+					value,
+					gas: target_gas.unwrap_or_default(),
+					output: Vec::new(),
+					exit_reason: None,
+					calls: Vec::new(),
+				});
+			}
+			Event::Suicide {
+				address,
+				target,
+				balance,
+			} => {
+				if let Some(trace) = self.stack.last_mut() {
+					trace.calls.push(CallTrace {
+						call_type: CallType::SelfDestruct,
+						from: address,
+						to: target,
+						input: Vec::new(),
+						value: balance,
+						gas: 0,
+						output: Vec::new(),
+						exit_reason: None,
+						calls: Vec::new(),
+					});
+				}
+			}
+			Event::Exit { reason, return_value } => {
+				if let Some(mut trace) = self.stack.pop() {
+					trace.output = return_value.to_vec();
+					trace.exit_reason = Some(reason.clone());
+
+					if let Some(parent) = self.stack.last_mut() {
+						parent.calls.push(trace);
+					} else {
+						self.calls.push(trace);
+					}
+				}
+			}
+		}
+	}
+
+	fn step(&mut self, event: evm_runtime::tracing::Event) {
+		if self.config != TracerConfig::OpcodeTracer {
+			return;
+		}
+
+		if let evm_runtime::tracing::Event::Step {
+			opcode,
+			position,
+			stack,
+			memory,
+			..
+		} = event
+		{
+			self.steps.push(Step {
+				pc: position.as_ref().map_or(0, |pc| *pc as u64),
+				op: opcode.0,
+				depth: self.stack.len() as u32,
+				stack: stack.data().clone(),
+				memory: memory.data().clone(),
+			});
+		}
+	}
+
+	pub fn finalize(self) -> TraceOutcome {
+		match self.config {
+			TracerConfig::CallTracer => TraceOutcome::Calls(self.calls),
+			TracerConfig::OpcodeTracer => TraceOutcome::Steps(self.steps),
+		}
+	}
+}
+
+environmental::environmental!(tracer: Tracer);
+
+/// Forwards the opcode steps of `evm_runtime` to the current tracer.
+pub struct StepListener;
+
+impl evm_runtime::tracing::EventListener for StepListener {
+	fn event(&mut self, event: evm_runtime::tracing::Event) {
+		tracer::with(|tracer| tracer.step(event));
+	}
+}
+
+/// Run `f` with the current tracer, if any.
+pub fn with<F: FnOnce(&mut Tracer)>(f: F) {
+	tracer::with(f);
+}
+
+/// Trace the EVM executions of `f`.
+pub fn trace<R, F: FnOnce() -> R>(config: TracerConfig, f: F) -> (R, TraceOutcome) {
+	let mut tracer = Tracer::new(config);
+	let result = tracer::using(&mut tracer, f);
+	(result, tracer.finalize())
+}
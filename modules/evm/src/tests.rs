@@ -970,6 +970,151 @@ fn should_deploy_free() {
 	});
 }
 
+#[test]
+fn should_propose_and_accept_maintainer() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		let bob_account_id = <Runtime as Config>::AddressMapping::get_account_id(&bob());
+
+		// contract not created yet
+		assert_noop!(
+			EVM::propose_maintainer(Origin::signed(alice_account_id.clone()), H160::default(), bob()),
+			Error::<Runtime>::ContractNotFound
+		);
+
+		// create contract
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			21_000_000,
+			21_000_000,
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.value;
+
+		// not maintainer
+		assert_noop!(
+			EVM::propose_maintainer(Origin::signed(bob_account_id.clone()), contract_address, bob()),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(EVM::propose_maintainer(
+			Origin::signed(alice_account_id.clone()),
+			contract_address,
+			bob()
+		));
+		System::assert_last_event(Event::EVM(crate::Event::ProposedMaintainer(contract_address, bob())));
+		assert_eq!(EVM::pending_maintainers(contract_address), Some(bob()));
+
+		// not the proposed maintainer
+		assert_noop!(
+			EVM::accept_maintainer(Origin::signed(alice_account_id.clone()), contract_address),
+			Error::<Runtime>::NotPendingMaintainer
+		);
+
+		assert_ok!(EVM::accept_maintainer(
+			Origin::signed(bob_account_id.clone()),
+			contract_address
+		));
+		System::assert_last_event(Event::EVM(crate::Event::ConfirmedTransferMaintainer(
+			contract_address,
+			bob(),
+		)));
+		assert_eq!(EVM::pending_maintainers(contract_address), None);
+		assert_eq!(EVM::query_maintainer(contract_address), Ok(bob()));
+
+		// the proposal can only be accepted once
+		assert_noop!(
+			EVM::accept_maintainer(Origin::signed(bob_account_id), contract_address),
+			Error::<Runtime>::NotPendingMaintainer
+		);
+
+		// alice is not the maintainer any more
+		assert_noop!(
+			EVM::propose_maintainer(Origin::signed(alice_account_id), contract_address, alice()),
+			Error::<Runtime>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn should_publish_contract() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		let bob_account_id = <Runtime as Config>::AddressMapping::get_account_id(&bob());
+
+		// contract not created yet
+		assert_noop!(EVM::publish_contract(Origin::signed(alice_account_id.clone()), H160::default()), Error::<Runtime>::ContractNotFound);
+
+		// create contract
+		let result = <Runtime as Config>::Runner::create(alice(), contract, 0, 21_000_000, 21_000_000, <Runtime as Config>::config()).unwrap();
+		let contract_address = result.value;
+
+		// multiply(2, 3)
+		let multiply = from_hex(
+			"0x165c4a1600000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000003"
+		).unwrap();
+
+		// call method `multiply` will fail, not published yet
+		assert_noop!(EVM::call(
+			Origin::signed(bob_account_id.clone()),
+			contract_address,
+			multiply.clone(),
+			0,
+			1000000,
+			1000000,
+		), Error::<Runtime>::NoPermission);
+
+		// not maintainer
+		assert_noop!(EVM::publish_contract(Origin::signed(bob_account_id), contract_address), Error::<Runtime>::NoPermission);
+
+		let alice_balance = balance(alice());
+		assert_ok!(EVM::publish_contract(Origin::signed(alice_account_id.clone()), contract_address));
+		System::assert_last_event(Event::EVM(crate::Event::ContractPublished(contract_address)));
+		assert_eq!(balance(alice()), alice_balance - PublicationFee::get());
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), INITIAL_BALANCE + PublicationFee::get());
+
+		// call method `multiply` will work
+		assert_ok!(<Runtime as Config>::Runner::call(
+			bob(),
+			bob(),
+			contract_address,
+			multiply,
+			0,
+			1000000,
+			1000000,
+			<Runtime as Config>::config(),
+		));
+
+		// contract already published
+		assert_noop!(EVM::publish_contract(Origin::signed(alice_account_id), contract_address), Error::<Runtime>::ContractAlreadyDeployed);
+	});
+}
+
 #[test]
 fn should_enable_contract_development() {
 	new_test_ext().execute_with(|| {
@@ -1634,3 +1779,162 @@ fn code_hash_with_non_existent_address_should_work() {
 		);
 	});
 }
+
+#[test]
+fn should_record_base_fee_history() {
+	new_test_ext().execute_with(|| {
+		for i in 1..=12 {
+			System::set_block_number(i);
+			EVM::on_finalize(i);
+		}
+
+		// pruned after `BaseFeeHistoryLength` blocks
+		assert_eq!(EVM::base_fee_per_gas_history(2), None);
+		assert_eq!(EVM::base_fee_per_gas_history(3), Some(EVM::base_fee_per_gas()));
+		assert_eq!(EVM::base_fee_per_gas_history(12), Some(EVM::base_fee_per_gas()));
+
+		assert_eq!(EVM::base_fee_history(0), vec![]);
+		assert_eq!(EVM::base_fee_history(5), vec![EVM::base_fee_per_gas(); 5]);
+		assert_eq!(EVM::base_fee_history(20), vec![EVM::base_fee_per_gas(); 10]);
+	});
+}
+
+#[test]
+fn should_apply_state_overrides() {
+	// runtime code of
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let code = from_hex(
+		"0x6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+	let contract_address = H160::from_str("1000000000000000000000000000000000000001").unwrap();
+
+	new_test_ext().execute_with(|| {
+		let slot = H256::from_low_u64_be(1);
+
+		assert_noop!(
+			EVM::apply_state_overrides(vec![(contract_address, StateOverride {
+				balance: None,
+				code: Some(vec![0u8; MaxCodeSize::get() as usize + 1]),
+				state_diff: None,
+			})]),
+			Error::<Runtime>::ContractExceedsMaxCodeSize
+		);
+
+		assert_ok!(EVM::apply_state_overrides(vec![
+			(bob(), StateOverride {
+				balance: Some(1_000),
+				code: None,
+				state_diff: None,
+			}),
+			(contract_address, StateOverride {
+				balance: None,
+				code: Some(code.clone()),
+				state_diff: Some(vec![(slot, H256::from_low_u64_be(2))]),
+			}),
+		]));
+
+		assert_eq!(balance(bob()), 1_000);
+		assert_eq!(EVM::code_at_address(&contract_address).into_inner(), code);
+		assert_eq!(EVM::account_storages(contract_address, slot), H256::from_low_u64_be(2));
+
+		// multiply(2, 3)
+		let multiply = from_hex(
+			"0x165c4a1600000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000003"
+		).unwrap();
+
+		// the overridden contract can be called by everyone
+		let result = <Runtime as Config>::Runner::call(
+			bob(),
+			bob(),
+			contract_address,
+			multiply,
+			0,
+			1000000,
+			1000000,
+			<Runtime as Config>::config(),
+		).unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(U256::from(result.value.as_slice()), 6.into());
+	});
+}
+
+#[test]
+fn should_charge_storage_deposit() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		let bob_account_id = <Runtime as Config>::AddressMapping::get_account_id(&bob());
+
+		// contract not created yet
+		assert_eq!(EVM::storage_statement(&H160::default()), None);
+		assert_noop!(
+			EVM::charge_storage_deposit(Origin::signed(bob_account_id.clone()), H160::default(), 100),
+			Error::<Runtime>::ContractNotFound
+		);
+
+		// create contract
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			21_000_000,
+			21_000_000,
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.value;
+		let code_size = EVM::code_infos(EVM::code_hash_at_address(&contract_address))
+			.unwrap()
+			.code_size;
+		let used_storage = code_size + NewContractExtraBytes::get();
+
+		assert_ok!(EVM::enable_contract_development(Origin::signed(alice_account_id)));
+		assert_eq!(
+			EVM::storage_statement(&contract_address),
+			Some(ContractStorageStatement {
+				used_storage,
+				storage_deposit: used_storage as u64 * StorageDepositPerByte::get(),
+				required_deposit: used_storage as u64 * StorageDepositPerByte::get(),
+				developer_deposit: DeveloperDeposit::get(),
+			})
+		);
+
+		// anyone can top up the storage deposit
+		let bob_balance = balance(bob());
+		assert_ok!(EVM::charge_storage_deposit(
+			Origin::signed(bob_account_id.clone()),
+			contract_address,
+			100
+		));
+		System::assert_last_event(Event::EVM(crate::Event::StorageDepositCharged(
+			bob_account_id,
+			contract_address,
+			100,
+		)));
+		assert_eq!(balance(bob()), bob_balance - 100);
+		assert_eq!(
+			reserved_balance(contract_address),
+			used_storage as u64 * StorageDepositPerByte::get() + 100
+		);
+		assert_eq!(
+			EVM::storage_statement(&contract_address).unwrap().storage_deposit,
+			used_storage as u64 * StorageDepositPerByte::get() + 100
+		);
+	});
+}
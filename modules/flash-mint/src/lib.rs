@@ -0,0 +1,244 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Flash Mint Module
+//!
+//! ## Overview
+//!
+//! Flash mint module allows accounts to mint stable currency without any
+//! collateral, as long as the minted amount is burned and a fee is paid
+//! within the same transaction. The minted stable currency can be used by
+//! the dispatched call, e.g. to liquidate unsafe CDPs or do arbitrage
+//! through DEX or EVM contracts, without locking up capital. The total
+//! amount minted at the same time is limited by a cap, and the fee is
+//! deposited to CDP treasury as surplus.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	pallet_prelude::*,
+	transactional,
+};
+use frame_system::pallet_prelude::*;
+use orml_traits::Change;
+use primitives::Balance;
+use sp_runtime::{traits::Zero, FixedPointNumber};
+use sp_std::prelude::*;
+use support::{CDPTreasury, FlashMint, Rate};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+// typedef to help polkadot.js disambiguate Change with different generic
+// parameters
+type ChangeBalance = Change<Balance>;
+type ChangeRate = Change<Rate>;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The call dispatched with the minted stable currency
+		type Call: Parameter + Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo> + GetDispatchInfo;
+
+		/// CDP treasury to issue and burn stable currency and receive fees
+		type CDPTreasury: CDPTreasury<Self::AccountId, Balance = Balance>;
+
+		/// The origin which may update flash mint params. Root can always do
+		/// this.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The flash mint amount is zero
+		ZeroMintAmount,
+		/// The minted amount exceed the cap
+		ExceedMaximumMintAmount,
+		/// The minted amount can not be burned
+		MintNotRepaid,
+		/// The fee can not be paid
+		FeeNotPaid,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	pub enum Event<T: Config> {
+		/// Flash minted stable currency and repaid. \[who, amount, fee\]
+		FlashMinted(T::AccountId, Balance, Balance),
+		/// The cap of flash mint amount updated. \[new_maximum_mint_amount\]
+		MaximumMintAmountUpdated(Balance),
+		/// The flash mint fee rate updated. \[new_fee_rate\]
+		FeeRateUpdated(Rate),
+	}
+
+	/// The cap of stable currency minted at the same time
+	///
+	/// MaximumMintAmount: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn maximum_mint_amount)]
+	pub type MaximumMintAmount<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The fee rate of flash mint
+	///
+	/// FeeRate: Rate
+	#[pallet::storage]
+	#[pallet::getter(fn fee_rate)]
+	pub type FeeRate<T: Config> = StorageValue<_, Rate, ValueQuery>;
+
+	/// The stable currency minted and not burned yet, only non-zero during a
+	/// flash mint
+	///
+	/// MintedAmount: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn minted_amount)]
+	pub type MintedAmount<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig {
+		pub maximum_mint_amount: Balance,
+		pub fee_rate: Rate,
+	}
+
+	#[cfg(feature = "std")]
+	impl Default for GenesisConfig {
+		fn default() -> Self {
+			GenesisConfig {
+				maximum_mint_amount: Zero::zero(),
+				fee_rate: Default::default(),
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig {
+		fn build(&self) {
+			MaximumMintAmount::<T>::put(self.maximum_mint_amount);
+			FeeRate::<T>::put(self.fee_rate);
+		}
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Mint `amount` stable currency to the caller and dispatch `call`
+		/// with the caller as signed origin, then burn `amount` and charge the
+		/// fee from the caller. Fails and reverts everything if the caller
+		/// can not repay.
+		///
+		/// - `amount`: stable currency amount to mint.
+		/// - `call`: the call to dispatch with the minted stable currency.
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(T::WeightInfo::flash_mint().saturating_add(dispatch_info.weight), dispatch_info.class)
+		})]
+		#[transactional]
+		pub fn flash_mint(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: Balance,
+			call: Box<<T as Config>::Call>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			<Self as FlashMint<T::AccountId>>::flash_mint(&who, amount, || {
+				call.dispatch(frame_system::RawOrigin::Signed(who.clone()).into())
+					.map(|_| ())
+					.map_err(|e| e.error)
+			})
+		}
+
+		/// Update the flash mint params
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `maximum_mint_amount`: the cap of stable currency minted at the same time.
+		/// - `fee_rate`: the fee rate of flash mint.
+		#[pallet::weight((T::WeightInfo::set_flash_mint_params(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_flash_mint_params(
+			origin: OriginFor<T>,
+			maximum_mint_amount: ChangeBalance,
+			fee_rate: ChangeRate,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if let Change::NewValue(update) = maximum_mint_amount {
+				MaximumMintAmount::<T>::put(update);
+				Self::deposit_event(Event::MaximumMintAmountUpdated(update));
+			}
+			if let Change::NewValue(update) = fee_rate {
+				FeeRate::<T>::put(update);
+				Self::deposit_event(Event::FeeRateUpdated(update));
+			}
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> FlashMint<T::AccountId> for Pallet<T> {
+	type Balance = Balance;
+
+	#[transactional]
+	fn flash_mint<F: FnOnce() -> DispatchResult>(who: &T::AccountId, amount: Balance, f: F) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::ZeroMintAmount);
+		let minted_amount = Self::minted_amount()
+			.checked_add(amount)
+			.ok_or(Error::<T>::ExceedMaximumMintAmount)?;
+		ensure!(
+			minted_amount <= Self::maximum_mint_amount(),
+			Error::<T>::ExceedMaximumMintAmount
+		);
+		let fee = Self::flash_mint_fee(amount);
+
+		// the minted stable currency is backed by the repayment within this transaction
+		MintedAmount::<T>::put(minted_amount);
+		T::CDPTreasury::issue_debit(who, amount, true)?;
+
+		f()?;
+
+		T::CDPTreasury::burn_debit(who, amount).map_err(|_| Error::<T>::MintNotRepaid)?;
+		if !fee.is_zero() {
+			T::CDPTreasury::deposit_surplus(who, fee).map_err(|_| Error::<T>::FeeNotPaid)?;
+		}
+		MintedAmount::<T>::mutate(|minted| *minted = minted.saturating_sub(amount));
+
+		Self::deposit_event(Event::FlashMinted(who.clone(), amount, fee));
+		Ok(())
+	}
+
+	fn flash_mint_fee(amount: Balance) -> Balance {
+		Self::fee_rate().saturating_mul_int(amount)
+	}
+}
@@ -0,0 +1,147 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the flash mint module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Call as MockCall, Event, *};
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::BadOrigin;
+
+fn remark_call() -> Box<MockCall> {
+	Box::new(MockCall::System(frame_system::Call::remark(vec![])))
+}
+
+#[test]
+fn set_flash_mint_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			FlashMintModule::set_flash_mint_params(Origin::signed(BOB), Change::NewValue(100), Change::NoChange),
+			BadOrigin
+		);
+		assert_ok!(FlashMintModule::set_flash_mint_params(
+			Origin::signed(ALICE),
+			Change::NewValue(100),
+			Change::NewValue(Rate::saturating_from_rational(2, 100))
+		));
+		System::assert_has_event(Event::FlashMintModule(crate::Event::MaximumMintAmountUpdated(100)));
+		System::assert_last_event(Event::FlashMintModule(crate::Event::FeeRateUpdated(
+			Rate::saturating_from_rational(2, 100),
+		)));
+		assert_eq!(FlashMintModule::maximum_mint_amount(), 100);
+		assert_eq!(FlashMintModule::fee_rate(), Rate::saturating_from_rational(2, 100));
+
+		assert_ok!(FlashMintModule::set_flash_mint_params(
+			Origin::signed(ALICE),
+			Change::NoChange,
+			Change::NoChange
+		));
+		assert_eq!(FlashMintModule::maximum_mint_amount(), 100);
+	});
+}
+
+#[test]
+fn flash_mint_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(FlashMintModule::flash_mint_fee(1000), 10);
+		assert_ok!(FlashMintModule::flash_mint(Origin::signed(ALICE), 1000, remark_call()));
+		System::assert_last_event(Event::FlashMintModule(crate::Event::FlashMinted(ALICE, 1000, 10)));
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 90);
+		assert_eq!(Currencies::total_issuance(AUSD), 200);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 10);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
+		assert_eq!(FlashMintModule::minted_amount(), 0);
+	});
+}
+
+#[test]
+fn flash_mint_fails_with_invalid_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			FlashMintModule::flash_mint(Origin::signed(ALICE), 0, remark_call()),
+			Error::<Runtime>::ZeroMintAmount
+		);
+		assert_noop!(
+			FlashMintModule::flash_mint(Origin::signed(ALICE), 10_001, remark_call()),
+			Error::<Runtime>::ExceedMaximumMintAmount
+		);
+	});
+}
+
+#[test]
+fn flash_mint_reverts_if_not_repaid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			FlashMintModule::flash_mint(
+				Origin::signed(ALICE),
+				1000,
+				Box::new(MockCall::Currencies(orml_currencies::Call::transfer(BOB, AUSD, 1000)))
+			),
+			Error::<Runtime>::MintNotRepaid
+		);
+
+		// the minted amount can be repaid, but not the fee
+		assert_noop!(
+			FlashMintModule::flash_mint(
+				Origin::signed(ALICE),
+				1000,
+				Box::new(MockCall::Currencies(orml_currencies::Call::transfer(BOB, AUSD, 100)))
+			),
+			Error::<Runtime>::FeeNotPaid
+		);
+
+		// the error of the dispatched call is returned
+		assert_noop!(
+			FlashMintModule::flash_mint(
+				Origin::signed(ALICE),
+				1000,
+				Box::new(MockCall::Currencies(orml_currencies::Call::transfer(BOB, AUSD, 2000)))
+			),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn flash_mint_by_other_pallets_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(<FlashMintModule as FlashMint<AccountId>>::flash_mint(
+			&ALICE,
+			6000,
+			|| {
+				assert_eq!(Currencies::free_balance(AUSD, &ALICE), 6100);
+				assert_eq!(FlashMintModule::minted_amount(), 6000);
+
+				// the cap is shared by nested flash mints
+				assert_noop!(
+					<FlashMintModule as FlashMint<AccountId>>::flash_mint(&ALICE, 5000, || Ok(())),
+					Error::<Runtime>::ExceedMaximumMintAmount
+				);
+				<FlashMintModule as FlashMint<AccountId>>::flash_mint(&ALICE, 4000, || Ok(()))
+			}
+		));
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 0);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(FlashMintModule::minted_amount(), 0);
+	});
+}
@@ -39,6 +39,29 @@ benchmarks! {
 		module::Pallet::<T>::set_minting_cap(RawOrigin::Root.into(), amount)?;
 	}: _(RawOrigin::Signed(caller), amount)
 
+	mint_for_redeem_requests {
+		let r in 0 .. T::MaximumRedeemRequestMatchesForMint::get();
+		let amount = 1_000_000_000_000;
+		for i in 0 .. r {
+			let redeemer: T::AccountId = account("redeemer", i, SEED);
+			<T as module::Config>::Currency::deposit(T::LiquidCurrencyId::get(), &redeemer, amount)?;
+			module::Pallet::<T>::request_redeem_fast(
+				RawOrigin::Signed(redeemer).into(),
+				amount / 1_000,
+				Permill::from_percent(1),
+			)?;
+		}
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as module::Config>::Currency::deposit(T::StakingCurrencyId::get(), &caller, amount)?;
+		module::Pallet::<T>::set_minting_cap(RawOrigin::Root.into(), amount)?;
+	}: mint(RawOrigin::Signed(caller), amount)
+
+	request_redeem_fast {
+		let amount = 1_000_000_000_000;
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as module::Config>::Currency::deposit(T::LiquidCurrencyId::get(), &caller, amount)?;
+	}: _(RawOrigin::Signed(caller), amount, Permill::from_percent(1))
+
 	set_total_staking_currency {}: _(RawOrigin::Root, 1_000_000_000_000)
 
 	adjust_total_staking_currency {}: _(RawOrigin::Root, AmountOf::<T>::default())
@@ -48,6 +71,12 @@ benchmarks! {
 
 	set_xcm_dest_weight {
 	}: _(RawOrigin::Root, 1_000_000_000)
+
+	compound_rewards {
+		module::Pallet::<T>::set_total_staking_currency(RawOrigin::Root.into(), 1_000_000_000_000)?;
+	}: {
+		module::Pallet::<T>::compound_rewards(1);
+	}
 }
 
 #[cfg(test)]
@@ -160,6 +189,11 @@ mod benchmark_mock {
 		pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(1, 10);
 		pub MaxRewardPerEra: Permill = Permill::from_rational(411u32, 1_000_000u32);
 		pub const MintFee: Balance = 10_000_000;
+		pub const TreasuryAccount: AccountId = AccountId32::new([253u8; 32]);
+		pub const FastRedeemFeeShareToMinter: Permill = Permill::from_percent(50);
+		pub const MaximumRedeemRequestMatchesForMint: u32 = 10;
+		pub const EraLength: BlockNumber = 10;
+		pub const EstimatedRewardRatePerEra: Permill = Permill::from_percent(1);
 	}
 	ord_parameter_types! {
 		pub const Root: AccountId = ROOT;
@@ -178,6 +212,12 @@ mod benchmark_mock {
 		type DefaultExchangeRate = DefaultExchangeRate;
 		type MaxRewardPerEra = MaxRewardPerEra;
 		type MintFee = MintFee;
+		type TreasuryAccount = TreasuryAccount;
+		type FastRedeemFeeShareToMinter = FastRedeemFeeShareToMinter;
+		type MaximumRedeemRequestMatchesForMint = MaximumRedeemRequestMatchesForMint;
+		type RewardsClaimer = ();
+		type EraLength = EraLength;
+		type EstimatedRewardRatePerEra = EstimatedRewardRatePerEra;
 	}
 
 	type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -231,6 +271,18 @@ mod tests {
 		});
 	}
 	#[test]
+	fn test_mint_for_redeem_requests() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<Runtime>::test_benchmark_mint_for_redeem_requests());
+		});
+	}
+	#[test]
+	fn test_request_redeem_fast() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<Runtime>::test_benchmark_request_redeem_fast());
+		});
+	}
+	#[test]
 	fn test_set_total_staking_currency() {
 		ExtBuilder::default().build().execute_with(|| {
 			assert_ok!(Pallet::<Runtime>::test_benchmark_set_total_staking_currency());
@@ -254,4 +306,10 @@ mod tests {
 			assert_ok!(Pallet::<Runtime>::test_benchmark_set_xcm_dest_weight());
 		});
 	}
+	#[test]
+	fn test_compound_rewards() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<Runtime>::test_benchmark_compound_rewards());
+		});
+	}
 }
@@ -24,14 +24,16 @@ mod mock;
 mod tests;
 pub mod weights;
 
-use frame_support::{pallet_prelude::*, transactional};
+use frame_support::{log, pallet_prelude::*, transactional};
 use frame_system::{ensure_signed, pallet_prelude::*};
-use module_support::{ExchangeRate, ExchangeRateProvider, Ratio};
-use orml_traits::{arithmetic::Signed, MultiCurrency, MultiCurrencyExtended, XcmTransfer};
-use primitives::{Balance, CurrencyId};
+use module_support::{ExchangeRate, ExchangeRateProvider, HomaManager, Ratio, RelaychainRewardsClaimer};
+use orml_traits::{
+	arithmetic::Signed, BalanceStatus, MultiCurrency, MultiCurrencyExtended, MultiReservableCurrency, XcmTransfer,
+};
+use primitives::{Balance, CurrencyId, EraIndex};
 use sp_runtime::{
-	traits::{Bounded, Zero},
-	ArithmeticError, FixedPointNumber, Permill,
+	traits::{Bounded, CheckedDiv, Saturating, Zero},
+	ArithmeticError, FixedPointNumber, PerThing, Permill, SaturatedConversion,
 };
 use sp_std::{convert::TryInto, ops::Mul, prelude::*};
 use xcm::opaque::v0::MultiLocation;
@@ -54,7 +56,8 @@ pub mod module {
 		type WeightInfo: WeightInfo;
 
 		/// Multi-currency support for asset management
-		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>
+			+ MultiReservableCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
 
 		/// The Currency ID for the Staking asset
 		#[pallet::constant]
@@ -89,6 +92,31 @@ pub mod module {
 		/// The fixed cost of transaction fee for XCM transfers.
 		#[pallet::constant]
 		type MintFee: Get<Balance>;
+
+		/// The account to receive the treasury share of the fast redeem fee.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// The share of the fast redeem fee that goes to the matched minter, the
+		/// rest goes to the treasury.
+		#[pallet::constant]
+		type FastRedeemFeeShareToMinter: Get<Permill>;
+
+		/// The maximum number of fast redeem requests to be matched in a mint.
+		#[pallet::constant]
+		type MaximumRedeemRequestMatchesForMint: Get<u32>;
+
+		/// The interface to claim the staking rewards on the relaychain.
+		type RewardsClaimer: RelaychainRewardsClaimer<EraIndex>;
+
+		/// The number of blocks of an era on the relaychain.
+		#[pallet::constant]
+		type EraLength: Get<Self::BlockNumber>;
+
+		/// The estimated staking rewards earned on the relaychain per era, used to compound
+		/// the total staking currency before it's corrected by governance.
+		#[pallet::constant]
+		type EstimatedRewardRatePerEra: Get<Permill>;
 	}
 
 	#[pallet::error]
@@ -99,6 +127,8 @@ pub mod module {
 		MintAmountBelowMinimumThreshold,
 		/// The amount of Staking currency used has exceeded the cap allowed.
 		ExceededStakingCurrencyMintCap,
+		/// Liquid currency can only be redeemed by matching with minters.
+		RedeemByUnbondNotSupported,
 	}
 
 	#[pallet::event]
@@ -118,6 +148,21 @@ pub mod module {
 
 		/// A new weight for XCM transfers has been set.\[new_weight\]
 		XcmDestWeightSet(Weight),
+
+		/// The user has requested to redeem Liquid currency by matching with minters.
+		/// \[user, liquid_amount, fee\]
+		FastRedeemRequested(T::AccountId, Balance, Permill),
+
+		/// The fast redeem request has been cancelled.\[user\]
+		FastRedeemRequestCancelled(T::AccountId),
+
+		/// The fast redeem request has been matched with the minter.
+		/// \[redeemer, minter, liquid_amount, staking_amount\]
+		FastRedeemMatched(T::AccountId, T::AccountId, Balance, Balance),
+
+		/// The staking rewards have been claimed and compounded into the total staking currency.
+		/// \[era, reward_amount, total_staking_currency\]
+		RewardsCompounded(EraIndex, Balance, Balance),
 	}
 
 	/// The total amount of the staking currency on the relaychain.
@@ -139,9 +184,40 @@ pub mod module {
 	#[pallet::getter(fn xcm_dest_weight)]
 	pub type XcmDestWeight<T: Config> = StorageValue<_, Weight, ValueQuery>;
 
+	/// The requests to redeem Liquid currency by matching with minters, and the fee rate the
+	/// redeemer is willing to pay.
+	/// RedeemRequests: map AccountId => Option<(liquid_amount: Balance, fee: Permill)>
+	#[pallet::storage]
+	#[pallet::getter(fn redeem_requests)]
+	pub type RedeemRequests<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (Balance, Permill), OptionQuery>;
+
+	/// The last era at which the staking rewards were compounded.
+	/// LastCompoundedEra: value: EraIndex
+	#[pallet::storage]
+	#[pallet::getter(fn last_compounded_era)]
+	pub type LastCompoundedEra<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let weight = <T as Config>::WeightInfo::compound_rewards();
+			if remaining_weight < weight {
+				return 0;
+			}
+
+			let era = Self::era_at(now);
+			if era > Self::last_compounded_era() {
+				Self::compound_rewards(era);
+				weight
+			} else {
+				T::DbWeight::get().reads(1)
+			}
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Mint some Liquid currency, by locking up the given amount of Staking currency.
@@ -149,67 +225,54 @@ pub mod module {
 		/// liquid currency. A portion is reducted (defined as T::MaxRewardPerEra) to make up for
 		/// the fact that staking is only effective from the next era on (on the relaychain).
 		///
+		/// The fast redeem requests are matched first, the Staking currency is paid to the
+		/// redeemers directly in exchange for their Liquid currency. The remaining Staking currency
+		/// is minted if it's above the minimum threshold.
+		///
 		/// Parameters:
 		/// - `amount`: The amount of Staking currency to be exchanged.
-		#[pallet::weight(< T as Config >::WeightInfo::mint())]
+		#[pallet::weight(
+			< T as Config >::WeightInfo::mint_for_redeem_requests(T::MaximumRedeemRequestMatchesForMint::get())
+		)]
 		#[transactional]
 		pub fn mint(origin: OriginFor<T>, amount: Balance) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			// Ensure the amount is above the minimum, after the MintFee is deducted.
-			ensure!(
-				amount > T::MinimumMintThreshold::get().saturating_add(T::MintFee::get()),
-				Error::<T>::MintAmountBelowMinimumThreshold
-			);
-
-			// Ensure the total amount staked doesn't exceed the cap.
-			let new_total_staked = Self::total_staking_currency()
-				.checked_add(amount)
-				.ok_or(ArithmeticError::Overflow)?;
-			ensure!(
-				new_total_staked <= Self::staking_currency_mint_cap(),
-				Error::<T>::ExceededStakingCurrencyMintCap
-			);
-
-			let staking_currency = T::StakingCurrencyId::get();
-
-			// ensure the user has enough funds on their account.
-			T::Currency::ensure_can_withdraw(staking_currency, &who, amount)?;
-
-			// Gets the current exchange rate
-			let exchange_rate = Self::get_staking_exchange_rate();
-
-			// Calculate how much Liquid currency is to be minted.
-			// liquid_to_mint = ( (staked_amount - MintFee) * liquid_total / staked_total ) * (1 -
-			// MaxRewardPerEra)
-			let mut liquid_to_mint = exchange_rate
-				.checked_mul_int(
-					amount
-						.checked_sub(T::MintFee::get())
-						.expect("Mint amount is ensured to be greater than T::MintFee; qed"),
-				)
-				.ok_or(ArithmeticError::Overflow)?;
-
-			liquid_to_mint = liquid_to_mint
-				.checked_sub(T::MaxRewardPerEra::get().mul(liquid_to_mint))
-				.expect("Max rewards cannot be above 100%; qed");
-
-			// All checks pass. Proceed with Xcm transfer.
-			T::XcmTransfer::transfer(
-				who.clone(),
-				staking_currency,
-				amount,
-				T::SovereignSubAccountLocation::get(),
-				Self::xcm_dest_weight(),
-			)?;
-
-			// Mint the liquid currency into the user's account.
-			T::Currency::deposit(T::LiquidCurrencyId::get(), &who, liquid_to_mint)?;
-
-			TotalStakingCurrency::<T>::put(new_total_staked);
+			Self::do_mint_with_requests(&who, amount)
+		}
 
-			Self::deposit_event(Event::<T>::Minted(who, amount, liquid_to_mint));
+		/// Mint some Liquid currency for `beneficiary`, paid with the Staking currency of the
+		/// caller. The Staking currency is transferred to `beneficiary` first, then minted in the
+		/// same way as `mint`. Used by the remote mint XCM, which transacts from the relaychain
+		/// sovereign account.
+		///
+		/// Parameters:
+		/// - `beneficiary`: The account to receive the Liquid currency.
+		/// - `amount`: The amount of Staking currency to be exchanged.
+		#[pallet::weight(
+			< T as Config >::WeightInfo::mint_for_redeem_requests(T::MaximumRedeemRequestMatchesForMint::get())
+				// transfer the Staking currency to `beneficiary`
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+		)]
+		#[transactional]
+		pub fn mint_for(origin: OriginFor<T>, beneficiary: T::AccountId, amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			T::Currency::transfer(T::StakingCurrencyId::get(), &who, &beneficiary, amount)?;
+			Self::do_mint_with_requests(&beneficiary, amount)
+		}
 
-			Ok(())
+		/// Request to redeem Liquid currency immediately by matching with the Staking currency of
+		/// minters, bypassing the unbonding period. The redeemer pays `fee` of the redeemed
+		/// Staking currency, which is shared by the matched minter and the treasury.
+		/// The new request replaces the existing one, zero `liquid_amount` cancels the request.
+		///
+		/// Parameters:
+		/// - `liquid_amount`: The amount of Liquid currency to be redeemed.
+		/// - `fee`: The fee rate the redeemer is willing to pay.
+		#[pallet::weight(< T as Config >::WeightInfo::request_redeem_fast())]
+		#[transactional]
+		pub fn request_redeem_fast(origin: OriginFor<T>, liquid_amount: Balance, fee: Permill) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_request_redeem_fast(who, liquid_amount, fee)
 		}
 
 		/// Sets the total amount of the Staking currency that are currently on the relaychain.
@@ -301,11 +364,221 @@ pub mod module {
 }
 
 impl<T: Config> Pallet<T> {
+	/// The relaychain era at block `now`.
+	fn era_at(now: T::BlockNumber) -> EraIndex {
+		now.checked_div(&T::EraLength::get())
+			.unwrap_or_default()
+			.saturated_into()
+	}
+
+	/// Claim the staking rewards of the last era on the relaychain, and compound the estimated
+	/// rewards of the eras passed into the total staking currency.
+	fn compound_rewards(era: EraIndex) {
+		let passed_eras = era.saturating_sub(Self::last_compounded_era());
+		LastCompoundedEra::<T>::put(era);
+
+		if let Err(e) = T::RewardsClaimer::claim_rewards(era.saturating_sub(1)) {
+			log::warn!(
+				target: "homa-lite",
+				"claim_rewards: failed to claim rewards of era {:?}: {:?}",
+				era.saturating_sub(1), e
+			);
+			return;
+		}
+
+		let staking_total = Self::total_staking_currency();
+		let reward = T::EstimatedRewardRatePerEra::get()
+			.mul(staking_total)
+			.saturating_mul(passed_eras.into());
+		let new_staking_total = staking_total.saturating_add(reward);
+
+		TotalStakingCurrency::<T>::put(new_staking_total);
+		Self::deposit_event(Event::<T>::RewardsCompounded(era, reward, new_staking_total));
+	}
+
 	pub fn get_staking_exchange_rate() -> ExchangeRate {
 		let staking_total = Self::total_staking_currency();
 		let liquid_total = T::Currency::total_issuance(T::LiquidCurrencyId::get());
 		Ratio::checked_from_rational(liquid_total, staking_total).unwrap_or_else(T::DefaultExchangeRate::get)
 	}
+
+	fn do_request_redeem_fast(who: T::AccountId, liquid_amount: Balance, fee: Permill) -> DispatchResult {
+		let liquid_currency = T::LiquidCurrencyId::get();
+
+		if let Some((existing_amount, _)) = RedeemRequests::<T>::take(&who) {
+			T::Currency::unreserve(liquid_currency, &who, existing_amount);
+		}
+
+		if liquid_amount.is_zero() {
+			Self::deposit_event(Event::<T>::FastRedeemRequestCancelled(who));
+		} else {
+			T::Currency::reserve(liquid_currency, &who, liquid_amount)?;
+			RedeemRequests::<T>::insert(&who, (liquid_amount, fee));
+			Self::deposit_event(Event::<T>::FastRedeemRequested(who, liquid_amount, fee));
+		}
+
+		Ok(())
+	}
+
+	/// Match the fast redeem requests with `amount` of Staking currency from `who`, and mint the
+	/// remaining if it's above the minimum threshold.
+	fn do_mint_with_requests(who: &T::AccountId, amount: Balance) -> DispatchResult {
+		// Ensure the amount is above the minimum, after the MintFee is deducted.
+		ensure!(
+			amount > T::MinimumMintThreshold::get().saturating_add(T::MintFee::get()),
+			Error::<T>::MintAmountBelowMinimumThreshold
+		);
+
+		// ensure the user has enough funds on their account.
+		T::Currency::ensure_can_withdraw(T::StakingCurrencyId::get(), who, amount)?;
+
+		let remaining = Self::match_redeem_requests(who, amount)?;
+		if remaining > T::MinimumMintThreshold::get().saturating_add(T::MintFee::get()) {
+			Self::do_mint(who, remaining)?;
+		}
+
+		Ok(())
+	}
+
+	/// Mint Liquid currency by transferring `amount` of Staking currency to the relaychain.
+	fn do_mint(who: &T::AccountId, amount: Balance) -> DispatchResult {
+		// Ensure the total amount staked doesn't exceed the cap.
+		let new_total_staked = Self::total_staking_currency()
+			.checked_add(amount)
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(
+			new_total_staked <= Self::staking_currency_mint_cap(),
+			Error::<T>::ExceededStakingCurrencyMintCap
+		);
+
+		let staking_currency = T::StakingCurrencyId::get();
+
+		// Gets the current exchange rate
+		let exchange_rate = Self::get_staking_exchange_rate();
+
+		// Calculate how much Liquid currency is to be minted.
+		// liquid_to_mint = ( (staked_amount - MintFee) * liquid_total / staked_total ) * (1 -
+		// MaxRewardPerEra)
+		let mut liquid_to_mint = exchange_rate
+			.checked_mul_int(
+				amount
+					.checked_sub(T::MintFee::get())
+					.expect("Mint amount is ensured to be greater than T::MintFee; qed"),
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		liquid_to_mint = liquid_to_mint
+			.checked_sub(T::MaxRewardPerEra::get().mul(liquid_to_mint))
+			.expect("Max rewards cannot be above 100%; qed");
+
+		// All checks pass. Proceed with Xcm transfer.
+		T::XcmTransfer::transfer(
+			who.clone(),
+			staking_currency,
+			amount,
+			T::SovereignSubAccountLocation::get(),
+			Self::xcm_dest_weight(),
+		)?;
+
+		// Mint the liquid currency into the user's account.
+		T::Currency::deposit(T::LiquidCurrencyId::get(), who, liquid_to_mint)?;
+
+		TotalStakingCurrency::<T>::put(new_total_staked);
+
+		Self::deposit_event(Event::<T>::Minted(who.clone(), amount, liquid_to_mint));
+
+		Ok(())
+	}
+
+	/// Match the fast redeem requests with `amount` of Staking currency of `minter`. The
+	/// redeemers get the Staking currency and `minter` gets their Liquid currency, less the
+	/// treasury share of the fee. Returns the remaining Staking currency of `minter`.
+	fn match_redeem_requests(minter: &T::AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+		let staking_currency = T::StakingCurrencyId::get();
+		let liquid_currency = T::LiquidCurrencyId::get();
+		let liquid_to_staking_rate = Self::get_staking_exchange_rate().reciprocal().unwrap_or_default();
+		let treasury_fee_share = T::FastRedeemFeeShareToMinter::get().left_from_one();
+		let mut remaining = amount;
+
+		let requests = RedeemRequests::<T>::iter()
+			.filter(|(redeemer, _)| redeemer != minter)
+			.take(T::MaximumRedeemRequestMatchesForMint::get() as usize)
+			.collect::<Vec<_>>();
+		for (redeemer, (liquid_amount, fee)) in requests {
+			if remaining.is_zero() {
+				break;
+			}
+
+			// The Staking currency the redeemer gets for the whole request, after the fee.
+			let staking_amount = fee
+				.left_from_one()
+				.mul(liquid_to_staking_rate.saturating_mul_int(liquid_amount));
+			if staking_amount.is_zero() {
+				continue;
+			}
+
+			let (liquid_matched, staking_matched) = if remaining >= staking_amount {
+				(liquid_amount, staking_amount)
+			} else {
+				(
+					Ratio::saturating_from_rational(remaining, staking_amount).saturating_mul_int(liquid_amount),
+					remaining,
+				)
+			};
+			let treasury_fee = fee.mul(treasury_fee_share.mul(liquid_matched));
+
+			T::Currency::transfer(staking_currency, minter, &redeemer, staking_matched)?;
+			T::Currency::repatriate_reserved(
+				liquid_currency,
+				&redeemer,
+				minter,
+				liquid_matched.saturating_sub(treasury_fee),
+				BalanceStatus::Free,
+			)?;
+			T::Currency::repatriate_reserved(
+				liquid_currency,
+				&redeemer,
+				&T::TreasuryAccount::get(),
+				treasury_fee,
+				BalanceStatus::Free,
+			)?;
+
+			if liquid_matched < liquid_amount {
+				RedeemRequests::<T>::insert(&redeemer, (liquid_amount.saturating_sub(liquid_matched), fee));
+			} else {
+				RedeemRequests::<T>::remove(&redeemer);
+			}
+			remaining = remaining.saturating_sub(staking_matched);
+
+			Self::deposit_event(Event::<T>::FastRedeemMatched(
+				redeemer,
+				minter.clone(),
+				liquid_matched,
+				staking_matched,
+			));
+		}
+
+		Ok(remaining)
+	}
+}
+
+impl<T: Config> HomaManager<T::AccountId, Balance> for Pallet<T> {
+	#[transactional]
+	fn mint(who: &T::AccountId, amount: Balance) -> DispatchResult {
+		Self::do_mint_with_requests(who, amount)
+	}
+
+	/// Homa Lite can't unbond on the relaychain, so the redemption is always a fast redeem
+	/// request, which pays no fee.
+	#[transactional]
+	fn request_redeem(who: &T::AccountId, amount: Balance, fast_match: bool) -> DispatchResult {
+		ensure!(fast_match, Error::<T>::RedeemByUnbondNotSupported);
+		Self::do_request_redeem_fast(who.clone(), amount, Permill::zero())
+	}
+
+	fn get_exchange_rate() -> ExchangeRate {
+		LiquidExchangeProvider::<T>::get_exchange_rate()
+	}
 }
 
 pub struct LiquidExchangeProvider<T>(sp_std::marker::PhantomData<T>);
@@ -42,6 +42,7 @@ pub const ROOT: AccountId = AccountId32::new([255u8; 32]);
 pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
 pub const BOB: AccountId = AccountId32::new([2u8; 32]);
 pub const INVALID_CALLER: AccountId = AccountId32::new([254u8; 32]);
+pub const TREASURY: AccountId = AccountId32::new([253u8; 32]);
 pub const ACALA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
 pub const LKSM: CurrencyId = CurrencyId::Token(TokenSymbol::LKSM);
@@ -179,6 +180,11 @@ parameter_types! {
 	pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(10, 1);
 	pub const MaxRewardPerEra: Permill = Permill::from_percent(1);
 	pub MintFee: Balance = millicent(1000);
+	pub const TreasuryAccount: AccountId = TREASURY;
+	pub const FastRedeemFeeShareToMinter: Permill = Permill::from_percent(50);
+	pub const MaximumRedeemRequestMatchesForMint: u32 = 10;
+	pub const EraLength: BlockNumber = 10;
+	pub const EstimatedRewardRatePerEra: Permill = Permill::from_percent(1);
 }
 ord_parameter_types! {
 	pub const Root: AccountId = ROOT;
@@ -197,6 +203,12 @@ impl Config for Runtime {
 	type DefaultExchangeRate = DefaultExchangeRate;
 	type MaxRewardPerEra = MaxRewardPerEra;
 	type MintFee = MintFee;
+	type TreasuryAccount = TreasuryAccount;
+	type FastRedeemFeeShareToMinter = FastRedeemFeeShareToMinter;
+	type MaximumRedeemRequestMatchesForMint = MaximumRedeemRequestMatchesForMint;
+	type RewardsClaimer = ();
+	type EraLength = EraLength;
+	type EstimatedRewardRatePerEra = EstimatedRewardRatePerEra;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -24,7 +24,7 @@ use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
 	dollar, Currencies, Event, ExtBuilder, HomaLite, Origin, Runtime, System, ACALA, ALICE, BOB, INITIAL_BALANCE,
-	INVALID_CALLER, KSM, LKSM, ROOT,
+	INVALID_CALLER, KSM, LKSM, ROOT, TREASURY,
 };
 use sp_runtime::traits::BadOrigin;
 
@@ -169,6 +169,38 @@ fn repeated_mints_have_similar_exchange_rate() {
 	});
 }
 
+#[test]
+fn mint_for_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let amount = dollar(1000);
+		assert_ok!(HomaLite::set_minting_cap(
+			Origin::signed(ROOT),
+			5 * dollar(INITIAL_BALANCE)
+		));
+
+		assert_noop!(
+			HomaLite::mint_for(Origin::signed(ROOT), ALICE, amount),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+		assert_noop!(
+			HomaLite::mint_for(Origin::signed(ALICE), ROOT, dollar(1) / 100),
+			Error::<Runtime>::MintAmountBelowMinimumThreshold
+		);
+
+		// ALICE pays the Staking currency, ROOT receives the Liquid currency.
+		let liquid = 9_899_901_000_000_000;
+		assert_ok!(HomaLite::mint_for(Origin::signed(ALICE), ROOT, amount));
+		assert_eq!(Currencies::free_balance(KSM, &ALICE), dollar(INITIAL_BALANCE) - amount);
+		assert_eq!(Currencies::free_balance(KSM, &ROOT), 0);
+		assert_eq!(Currencies::free_balance(LKSM, &ROOT), dollar(INITIAL_BALANCE) + liquid);
+		assert_eq!(Currencies::free_balance(LKSM, &ALICE), 0);
+		assert_eq!(
+			System::events().iter().last().unwrap().event,
+			Event::HomaLite(crate::Event::Minted(ROOT, amount, liquid))
+		);
+	});
+}
+
 #[test]
 fn mint_fails_when_cap_is_exceeded() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -313,3 +345,179 @@ fn can_set_xcm_dest_weight() {
 		);
 	});
 }
+
+#[test]
+fn request_redeem_fast_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			HomaLite::request_redeem_fast(Origin::signed(ALICE), dollar(1_000), Permill::from_percent(1)),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+
+		assert_ok!(HomaLite::request_redeem_fast(
+			Origin::signed(ROOT),
+			dollar(1_000),
+			Permill::from_percent(1)
+		));
+		assert_eq!(
+			HomaLite::redeem_requests(ROOT),
+			Some((dollar(1_000), Permill::from_percent(1)))
+		);
+		assert_eq!(Currencies::reserved_balance(LKSM, &ROOT), dollar(1_000));
+		assert_eq!(
+			System::events().iter().last().unwrap().event,
+			Event::HomaLite(crate::Event::FastRedeemRequested(
+				ROOT,
+				dollar(1_000),
+				Permill::from_percent(1)
+			))
+		);
+
+		// The new request replaces the existing one.
+		assert_ok!(HomaLite::request_redeem_fast(
+			Origin::signed(ROOT),
+			dollar(500),
+			Permill::from_percent(2)
+		));
+		assert_eq!(
+			HomaLite::redeem_requests(ROOT),
+			Some((dollar(500), Permill::from_percent(2)))
+		);
+		assert_eq!(Currencies::reserved_balance(LKSM, &ROOT), dollar(500));
+
+		// Zero amount cancels the request.
+		assert_ok!(HomaLite::request_redeem_fast(
+			Origin::signed(ROOT),
+			0,
+			Permill::from_percent(2)
+		));
+		assert_eq!(HomaLite::redeem_requests(ROOT), None);
+		assert_eq!(Currencies::reserved_balance(LKSM, &ROOT), 0);
+		assert_eq!(Currencies::free_balance(LKSM, &ROOT), dollar(INITIAL_BALANCE));
+		assert_eq!(
+			System::events().iter().last().unwrap().event,
+			Event::HomaLite(crate::Event::FastRedeemRequestCancelled(ROOT))
+		);
+	});
+}
+
+#[test]
+fn mint_matches_redeem_requests() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_minting_cap(
+			Origin::signed(ROOT),
+			5 * dollar(INITIAL_BALANCE)
+		));
+		assert_ok!(HomaLite::request_redeem_fast(
+			Origin::signed(ROOT),
+			dollar(1_000),
+			Permill::from_percent(10)
+		));
+
+		// With the default 1:10 ratio, the redeemer gets (1000 / 10) * 0.9 = 90 KSM, and the
+		// fee is shared by the minter and the treasury: 1000 * 0.1 * 0.5 = 50 LKSM to treasury.
+		assert_ok!(HomaLite::mint(Origin::signed(ALICE), dollar(90)));
+		assert_eq!(
+			System::events().iter().last().unwrap().event,
+			Event::HomaLite(crate::Event::FastRedeemMatched(ROOT, ALICE, dollar(1_000), dollar(90)))
+		);
+		assert_eq!(HomaLite::redeem_requests(ROOT), None);
+		assert_eq!(Currencies::free_balance(KSM, &ROOT), dollar(90));
+		assert_eq!(Currencies::reserved_balance(LKSM, &ROOT), 0);
+		assert_eq!(Currencies::free_balance(LKSM, &ROOT), dollar(INITIAL_BALANCE - 1_000));
+		assert_eq!(Currencies::free_balance(KSM, &ALICE), dollar(INITIAL_BALANCE - 90));
+		assert_eq!(Currencies::free_balance(LKSM, &ALICE), dollar(950));
+		assert_eq!(Currencies::free_balance(LKSM, &TREASURY), dollar(50));
+		// Nothing is minted.
+		assert_eq!(TotalStakingCurrency::<Runtime>::get(), 0);
+
+		// Partially match the request.
+		assert_ok!(HomaLite::request_redeem_fast(
+			Origin::signed(ROOT),
+			dollar(1_000),
+			Permill::from_percent(10)
+		));
+		assert_ok!(HomaLite::mint(Origin::signed(BOB), dollar(45)));
+		assert_eq!(
+			HomaLite::redeem_requests(ROOT),
+			Some((dollar(500), Permill::from_percent(10)))
+		);
+		assert_eq!(Currencies::reserved_balance(LKSM, &ROOT), dollar(500));
+		assert_eq!(Currencies::free_balance(LKSM, &BOB), dollar(475));
+		assert_eq!(Currencies::free_balance(LKSM, &TREASURY), dollar(75));
+
+		// The remaining staking currency is minted.
+		// liquid = (55 - 0.01) * 10 * 0.99 = 544.401
+		assert_ok!(HomaLite::mint(Origin::signed(BOB), dollar(100)));
+		assert_eq!(
+			System::events().iter().last().unwrap().event,
+			Event::HomaLite(crate::Event::Minted(BOB, dollar(55), 544_401_000_000_000))
+		);
+		assert_eq!(HomaLite::redeem_requests(ROOT), None);
+		assert_eq!(Currencies::free_balance(KSM, &ROOT), dollar(180));
+		assert_eq!(Currencies::free_balance(LKSM, &BOB), dollar(950) + 544_401_000_000_000);
+		assert_eq!(Currencies::free_balance(LKSM, &TREASURY), dollar(100));
+		assert_eq!(TotalStakingCurrency::<Runtime>::get(), dollar(55));
+	});
+}
+
+#[test]
+fn mint_does_not_match_own_redeem_request() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_minting_cap(
+			Origin::signed(ROOT),
+			5 * dollar(INITIAL_BALANCE)
+		));
+		assert_ok!(Currencies::transfer(Origin::signed(ROOT), ALICE, LKSM, dollar(1_000)));
+		assert_ok!(HomaLite::request_redeem_fast(
+			Origin::signed(ALICE),
+			dollar(1_000),
+			Permill::from_percent(10)
+		));
+
+		assert_ok!(HomaLite::mint(Origin::signed(ALICE), dollar(90)));
+		assert_eq!(
+			HomaLite::redeem_requests(ALICE),
+			Some((dollar(1_000), Permill::from_percent(10)))
+		);
+		assert_eq!(TotalStakingCurrency::<Runtime>::get(), dollar(90));
+	});
+}
+
+#[test]
+fn compound_rewards_on_idle_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_total_staking_currency(Origin::signed(ROOT), 1_000_000));
+		let weight = <Runtime as Config>::WeightInfo::compound_rewards();
+
+		// Nothing to compound in the same era.
+		HomaLite::on_idle(5, weight);
+		assert_eq!(HomaLite::last_compounded_era(), 0);
+		assert_eq!(HomaLite::total_staking_currency(), 1_000_000);
+
+		// Not enough weight left
+		assert_eq!(HomaLite::on_idle(10, weight - 1), 0);
+		assert_eq!(HomaLite::last_compounded_era(), 0);
+
+		assert_eq!(HomaLite::on_idle(10, weight), weight);
+		assert_eq!(HomaLite::last_compounded_era(), 1);
+		assert_eq!(HomaLite::total_staking_currency(), 1_010_000);
+		assert_eq!(
+			System::events().iter().last().unwrap().event,
+			Event::HomaLite(crate::Event::RewardsCompounded(1, 10_000, 1_010_000))
+		);
+
+		// Only compound once per era.
+		HomaLite::on_idle(15, weight);
+		assert_eq!(HomaLite::total_staking_currency(), 1_010_000);
+
+		// Compound the rewards of all the eras passed.
+		HomaLite::on_idle(35, weight);
+		assert_eq!(HomaLite::last_compounded_era(), 3);
+		assert_eq!(HomaLite::total_staking_currency(), 1_030_200);
+		assert_eq!(
+			System::events().iter().last().unwrap().event,
+			Event::HomaLite(crate::Event::RewardsCompounded(3, 20_200, 1_030_200))
+		);
+	});
+}
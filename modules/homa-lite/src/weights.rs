@@ -48,10 +48,13 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for module_homa_lite.
 pub trait WeightInfo {
 	fn mint() -> Weight;
+	fn mint_for_redeem_requests(r: u32) -> Weight;
+	fn request_redeem_fast() -> Weight;
 	fn set_total_staking_currency() -> Weight;
 	fn adjust_total_staking_currency() -> Weight;
 	fn set_minting_cap() -> Weight;
 	fn set_xcm_dest_weight() -> Weight;
+	fn compound_rewards() -> Weight;
 }
 
 /// Weights for module_homa_lite using the Acala node and recommended hardware.
@@ -62,6 +65,20 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(13 as Weight))
 			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
+	fn mint_for_redeem_requests(r: u32) -> Weight {
+		(243_172_000 as Weight)
+			// Standard Error: 62_000
+			.saturating_add((96_411_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(r as Weight)))
+	}
+	fn request_redeem_fast() -> Weight {
+		(52_938_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 	fn set_total_staking_currency() -> Weight {
 		(19_651_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
@@ -79,6 +96,11 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		(19_306_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn compound_rewards() -> Weight {
+		(31_482_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -88,6 +110,20 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
 	}
+	fn mint_for_redeem_requests(r: u32) -> Weight {
+		(243_172_000 as Weight)
+			// Standard Error: 62_000
+			.saturating_add((96_411_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(r as Weight)))
+	}
+	fn request_redeem_fast() -> Weight {
+		(52_938_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
 	fn set_total_staking_currency() -> Weight {
 		(19_651_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
@@ -105,4 +141,9 @@ impl WeightInfo for () {
 		(19_306_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn compound_rewards() -> Weight {
+		(31_482_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
 }
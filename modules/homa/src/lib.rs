@@ -40,6 +40,9 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+pub type ReceiptIdOf<T> =
+	<<T as Config>::Homa as HomaProtocol<<T as frame_system::Config>::AccountId, Balance, EraIndex>>::ReceiptId;
+
 /// Redemption modes:
 /// 1. Immediately: User will immediately get back DOT from the free pool,
 /// which is a liquid pool operated by staking pool, but they have to pay
@@ -50,6 +53,10 @@ pub use weights::WeightInfo;
 /// 3. WaitFor Unbonding: User request unbond, the staking
 /// pool will process unbonding in the next era, and user needs to wait for
 /// the complete unbonding era which determined by Polkadot.
+///
+/// The pending unbonding of strategy `Target` and `WaitForUnbonding` can be
+/// moved into a transferable receipt by `issue_unbonding_receipt`, the owner
+/// of the receipt can withdraw the DOT after the unbonding expired.
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, MaxEncodedLen)]
 pub enum RedeemStrategy {
 	Immediately,
@@ -128,5 +135,28 @@ pub mod module {
 			T::Homa::withdraw_redemption(&who)?;
 			Ok(())
 		}
+
+		/// Move the pending unbonding of specific era into a transferable
+		/// unbonding receipt.
+		///
+		/// - `era`: the era at which the unbonding expires.
+		#[pallet::weight(<T as Config>::WeightInfo::issue_unbonding_receipt())]
+		#[transactional]
+		pub fn issue_unbonding_receipt(origin: OriginFor<T>, era: EraIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			T::Homa::issue_unbonding_receipt(&who, era)?;
+			Ok(())
+		}
+
+		/// Burn the unbonding receipt and get back the DOT it represents.
+		///
+		/// - `receipt_id`: the id of the unbonding receipt.
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_unbonding_receipt())]
+		#[transactional]
+		pub fn withdraw_unbonding_receipt(origin: OriginFor<T>, receipt_id: ReceiptIdOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			T::Homa::withdraw_unbonding_receipt(&who, receipt_id)?;
+			Ok(())
+		}
 	}
 }
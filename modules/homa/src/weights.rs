@@ -53,6 +53,8 @@ pub trait WeightInfo {
 	fn redeem_wait_for_unbonding() -> Weight;
 	fn redeem_by_claim_unbonding() -> Weight;
 	fn withdraw_redemption() -> Weight;
+	fn issue_unbonding_receipt() -> Weight;
+	fn withdraw_unbonding_receipt() -> Weight;
 }
 
 /// Weights for module_homa using the Acala node and recommended hardware.
@@ -83,6 +85,16 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	fn issue_unbonding_receipt() -> Weight {
+		(72_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	fn withdraw_unbonding_receipt() -> Weight {
+		(78_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -112,4 +124,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
+	fn issue_unbonding_receipt() -> Weight {
+		(72_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn withdraw_unbonding_receipt() -> Weight {
+		(78_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
 }
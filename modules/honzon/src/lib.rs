@@ -31,13 +31,14 @@
 
 use frame_support::{pallet_prelude::*, traits::NamedReservableCurrency, transactional};
 use frame_system::pallet_prelude::*;
+use orml_traits::MultiCurrency;
 use primitives::{Amount, Balance, CurrencyId, ReserveIdentifier};
 use sp_runtime::{
-	traits::{StaticLookup, Zero},
-	DispatchResult,
+	traits::{Bounded, One, Saturating, StaticLookup, Zero},
+	DispatchResult, FixedPointNumber,
 };
-use sp_std::vec::Vec;
-use support::EmergencyShutdown;
+use sp_std::{convert::TryInto, vec::Vec};
+use support::{EmergencyShutdown, ExchangeRate, HonzonManager, Rate, Ratio};
 
 mod mock;
 mod tests;
@@ -67,6 +68,11 @@ pub mod module {
 		#[pallet::constant]
 		type DepositPerAuthorization: Get<Balance>;
 
+		/// The share of an auto top-up paid by the CDP owner to the keeper
+		/// as reward.
+		#[pallet::constant]
+		type TopUpRewardRate: Get<Rate>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -81,6 +87,20 @@ pub mod module {
 		AuthorizationNotExists,
 		// Have authorized already
 		AlreadyAuthorized,
+		// Auto top-up can only use the collateral or the stable currency
+		InvalidTopUpSource,
+		// Auto top-up not authorized
+		AutoTopUpNotAuthorized,
+		// The collateral ratio is not below the required collateral ratio
+		PositionNotNearLiquidation,
+		// Nothing to top up
+		ZeroTopUpAmount,
+		// Failed to convert a balance to an amount
+		AmountConvertFailed,
+		// The loan can't be offered to the owner itself
+		InvalidNewOwner,
+		// Loan transfer offer not exists
+		LoanTransferOfferNotExists,
 	}
 
 	#[pallet::event]
@@ -95,6 +115,23 @@ pub mod module {
 		UnAuthorization(T::AccountId, T::AccountId, CurrencyId),
 		/// Cancel all authorization. \[authorizer\]
 		UnAuthorizationAll(T::AccountId),
+		/// Authorize keepers to top up the loan of specific collateral.
+		/// \[owner, collateral_type, source_currency, max_amount\]
+		AutoTopUpAuthorized(T::AccountId, CurrencyId, CurrencyId, Balance),
+		/// Cancel the auto top-up of specific collateral. \[owner, collateral_type\]
+		AutoTopUpCancelled(T::AccountId, CurrencyId),
+		/// A keeper topped up a loan. \[owner, collateral_type, keeper,
+		/// source_currency, top_up_amount, reward\]
+		PositionToppedUp(T::AccountId, CurrencyId, T::AccountId, CurrencyId, Balance, Balance),
+		/// Offer the loan of specific collateral to a new owner.
+		/// \[owner, new_owner, collateral_type\]
+		LoanTransferOffered(T::AccountId, T::AccountId, CurrencyId),
+		/// Cancel the loan transfer offer of specific collateral.
+		/// \[owner, new_owner, collateral_type\]
+		LoanTransferCancelled(T::AccountId, T::AccountId, CurrencyId),
+		/// The new owner accepted the loan transfer offer.
+		/// \[owner, new_owner, collateral_type\]
+		LoanTransferAccepted(T::AccountId, T::AccountId, CurrencyId),
 	}
 
 	/// The authorization relationship map from
@@ -113,6 +150,24 @@ pub mod module {
 		OptionQuery,
 	>;
 
+	/// The auto top-up authorizations, map from
+	/// Owner -> CollateralType -> (SourceCurrency, RemainingAmount)
+	///
+	/// AutoTopUp: double_map AccountId, CurrencyId => Option<(CurrencyId, Balance)>
+	#[pallet::storage]
+	#[pallet::getter(fn auto_top_up)]
+	pub type AutoTopUp<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, CurrencyId, (CurrencyId, Balance), OptionQuery>;
+
+	/// The pending loan transfer offers, map from
+	/// Owner -> CollateralType -> NewOwner
+	///
+	/// LoanTransferOffers: double_map AccountId, CurrencyId => Option<AccountId>
+	#[pallet::storage]
+	#[pallet::getter(fn loan_transfer_offers)]
+	pub type LoanTransferOffers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, CurrencyId, T::AccountId, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -139,12 +194,24 @@ pub mod module {
 			debit_adjustment: Amount,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			Self::do_adjust_loan(&who, currency_id, collateral_adjustment, debit_adjustment)
+		}
 
-			// not allowed to adjust the debit after system shutdown
-			if !debit_adjustment.is_zero() {
-				ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		/// Adjust the loans of several collateral types atomically, if any
+		/// adjustment fails, none of them is applied.
+		///
+		/// - `adjustments`: list of `(currency_id, collateral_adjustment, debit_adjustment)`, see
+		///   `adjust_loan`.
+		#[pallet::weight(<T as Config>::WeightInfo::adjust_loan().saturating_mul(adjustments.len() as Weight))]
+		#[transactional]
+		pub fn adjust_loans_batch(
+			origin: OriginFor<T>,
+			adjustments: Vec<(CurrencyId, Amount, Amount)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			for (currency_id, collateral_adjustment, debit_adjustment) in adjustments {
+				Self::do_adjust_loan(&who, currency_id, collateral_adjustment, debit_adjustment)?;
 			}
-			<cdp_engine::Pallet<T>>::adjust_position(&who, currency_id, collateral_adjustment, debit_adjustment)?;
 			Ok(())
 		}
 
@@ -193,7 +260,7 @@ pub mod module {
 			let from = T::Lookup::lookup(from)?;
 			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
 			Self::check_authorization(&from, &to, currency_id)?;
-			<loans::Pallet<T>>::transfer_loan(&from, &to, currency_id)?;
+			<cdp_engine::Pallet<T>>::transfer_loan(&from, &to, currency_id)?;
 			Ok(())
 		}
 
@@ -258,10 +325,228 @@ pub mod module {
 			Self::deposit_event(Event::UnAuthorizationAll(from));
 			Ok(())
 		}
+
+		/// Authorize anyone to top up caller's loan of `currency_id` when its
+		/// collateral ratio is below the required collateral ratio, spending at
+		/// most `max_amount` of `source_currency_id` including the keeper reward.
+		///
+		/// - `currency_id`: collateral currency id.
+		/// - `source_currency_id`: the collateral currency to deposit collateral, or the stable
+		///   currency to payback debit.
+		/// - `max_amount`: the max amount of `source_currency_id` to spend.
+		#[pallet::weight(<T as Config>::WeightInfo::authorize_auto_top_up())]
+		#[transactional]
+		pub fn authorize_auto_top_up(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			source_currency_id: CurrencyId,
+			max_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				source_currency_id == currency_id
+					|| source_currency_id == <T as cdp_engine::Config>::GetStableCurrencyId::get(),
+				Error::<T>::InvalidTopUpSource
+			);
+
+			AutoTopUp::<T>::insert(&who, currency_id, (source_currency_id, max_amount));
+			Self::deposit_event(Event::AutoTopUpAuthorized(
+				who,
+				currency_id,
+				source_currency_id,
+				max_amount,
+			));
+			Ok(())
+		}
+
+		/// Cancel the auto top-up of caller's loan of `currency_id`
+		///
+		/// - `currency_id`: collateral currency id.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_auto_top_up())]
+		#[transactional]
+		pub fn cancel_auto_top_up(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			AutoTopUp::<T>::take(&who, currency_id).ok_or(Error::<T>::AutoTopUpNotAuthorized)?;
+			Self::deposit_event(Event::AutoTopUpCancelled(who, currency_id));
+			Ok(())
+		}
+
+		/// Top up the loan of `owner` under `currency_id` back to the required
+		/// collateral ratio with the funds authorized by `owner`, the caller
+		/// receives `TopUpRewardRate` of the top-up amount as reward.
+		///
+		/// - `owner`: the CDP owner.
+		/// - `currency_id`: collateral currency id.
+		#[pallet::weight(<T as Config>::WeightInfo::top_up_unsafe_position())]
+		#[transactional]
+		pub fn top_up_unsafe_position(
+			origin: OriginFor<T>,
+			owner: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+		) -> DispatchResult {
+			let keeper = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			Self::do_top_up_position(&owner, currency_id, &keeper)
+		}
+
+		/// Offer the whole CDP of caller under `currency_id` to `new_owner`,
+		/// the CDP is transferred after `new_owner` accepts the offer. A new
+		/// offer replaces the pending one.
+		///
+		/// - `new_owner`: the account to offer the CDP to.
+		/// - `currency_id`: collateral currency id.
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_loan_to())]
+		#[transactional]
+		pub fn transfer_loan_to(
+			origin: OriginFor<T>,
+			new_owner: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let new_owner = T::Lookup::lookup(new_owner)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			ensure!(owner != new_owner, Error::<T>::InvalidNewOwner);
+
+			LoanTransferOffers::<T>::insert(&owner, currency_id, &new_owner);
+			Self::deposit_event(Event::LoanTransferOffered(owner, new_owner, currency_id));
+			Ok(())
+		}
+
+		/// Cancel the pending loan transfer offer of caller under
+		/// `currency_id`.
+		///
+		/// - `currency_id`: collateral currency id.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_loan_transfer())]
+		#[transactional]
+		pub fn cancel_loan_transfer(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let new_owner =
+				LoanTransferOffers::<T>::take(&owner, currency_id).ok_or(Error::<T>::LoanTransferOfferNotExists)?;
+			Self::deposit_event(Event::LoanTransferCancelled(owner, new_owner, currency_id));
+			Ok(())
+		}
+
+		/// Accept the loan transfer offer of `owner` under `currency_id`, the
+		/// whole CDP of `owner` is transferred to caller's CDP under the same
+		/// `currency_id`.
+		///
+		/// - `owner`: the account which offered the CDP.
+		/// - `currency_id`: collateral currency id.
+		#[pallet::weight(<T as Config>::WeightInfo::accept_loan_transfer())]
+		#[transactional]
+		pub fn accept_loan_transfer(
+			origin: OriginFor<T>,
+			owner: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+		) -> DispatchResult {
+			let new_owner = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			ensure!(
+				Self::loan_transfer_offers(&owner, currency_id).as_ref() == Some(&new_owner),
+				Error::<T>::LoanTransferOfferNotExists
+			);
+
+			LoanTransferOffers::<T>::remove(&owner, currency_id);
+			<cdp_engine::Pallet<T>>::transfer_loan(&owner, &new_owner, currency_id)?;
+			Self::deposit_event(Event::LoanTransferAccepted(owner, new_owner, currency_id));
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	fn do_adjust_loan(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		collateral_adjustment: Amount,
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		// not allowed to adjust the debit after system shutdown
+		if !debit_adjustment.is_zero() {
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		}
+		<cdp_engine::Pallet<T>>::adjust_position(who, currency_id, collateral_adjustment, debit_adjustment)
+	}
+
+	fn do_top_up_position(owner: &T::AccountId, currency_id: CurrencyId, keeper: &T::AccountId) -> DispatchResult {
+		let (source_currency_id, remaining) =
+			Self::auto_top_up(owner, currency_id).ok_or(Error::<T>::AutoTopUpNotAuthorized)?;
+		let loans::Position { collateral, debit } = <loans::Pallet<T>>::positions(currency_id, owner);
+		let price = <cdp_engine::Pallet<T>>::get_collateral_price(currency_id)
+			.ok_or(cdp_engine::Error::<T>::InvalidFeedPrice)?;
+		let required_ratio = <cdp_engine::Pallet<T>>::required_collateral_ratio(currency_id)
+			.ok_or(Error::<T>::PositionNotNearLiquidation)?;
+		ensure!(
+			<cdp_engine::Pallet<T>>::calculate_collateral_ratio(currency_id, collateral, debit, price) < required_ratio,
+			Error::<T>::PositionNotNearLiquidation
+		);
+
+		let is_collateral = source_currency_id == currency_id;
+		let needed = if is_collateral {
+			<cdp_engine::Pallet<T>>::calculate_required_collateral(currency_id, collateral, debit, price)
+		} else {
+			// the debit value to payback so that the collateral ratio reaches the required one
+			let safe_debit_value = required_ratio
+				.reciprocal()
+				.unwrap_or_else(Ratio::max_value)
+				.saturating_mul_int(price.saturating_mul_int(collateral));
+			<cdp_engine::Pallet<T>>::get_debit_value(currency_id, debit).saturating_sub(safe_debit_value)
+		};
+
+		// the keeper reward is paid on top of the top-up amount, both within the remaining allowance
+		let reward_rate = T::TopUpRewardRate::get();
+		let (top_up_amount, reward) = if needed.saturating_add(reward_rate.saturating_mul_int(needed)) <= remaining {
+			(needed, reward_rate.saturating_mul_int(needed))
+		} else {
+			let top_up_amount = reward_rate
+				.saturating_add(Rate::one())
+				.reciprocal()
+				.unwrap_or_else(Rate::max_value)
+				.saturating_mul_int(remaining);
+			(top_up_amount, remaining.saturating_sub(top_up_amount))
+		};
+		ensure!(!top_up_amount.is_zero(), Error::<T>::ZeroTopUpAmount);
+
+		if is_collateral {
+			let collateral_adjustment: Amount =
+				top_up_amount.try_into().map_err(|_| Error::<T>::AmountConvertFailed)?;
+			<cdp_engine::Pallet<T>>::adjust_position(owner, currency_id, collateral_adjustment, Zero::zero())?;
+		} else {
+			let debit_decrease = <cdp_engine::Pallet<T>>::get_debit_exchange_rate(currency_id)
+				.reciprocal()
+				.unwrap_or_else(Ratio::max_value)
+				.saturating_mul_int(top_up_amount)
+				.min(debit);
+			let debit_adjustment: Amount = debit_decrease.try_into().map_err(|_| Error::<T>::AmountConvertFailed)?;
+			<cdp_engine::Pallet<T>>::adjust_position(
+				owner,
+				currency_id,
+				Zero::zero(),
+				debit_adjustment.saturating_neg(),
+			)?;
+		}
+		<T as loans::Config>::Currency::transfer(source_currency_id, owner, keeper, reward)?;
+
+		let remaining = remaining.saturating_sub(top_up_amount.saturating_add(reward));
+		if remaining.is_zero() {
+			AutoTopUp::<T>::remove(owner, currency_id);
+		} else {
+			AutoTopUp::<T>::insert(owner, currency_id, (source_currency_id, remaining));
+		}
+
+		Self::deposit_event(Event::PositionToppedUp(
+			owner.clone(),
+			currency_id,
+			keeper.clone(),
+			source_currency_id,
+			top_up_amount,
+			reward,
+		));
+		Ok(())
+	}
+
 	/// Check if `from` has the authorization of `to` under `currency_id`
 	fn check_authorization(from: &T::AccountId, to: &T::AccountId, currency_id: CurrencyId) -> DispatchResult {
 		ensure!(
@@ -271,3 +556,39 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 }
+
+impl<T: Config> HonzonManager<T::AccountId, CurrencyId, Amount, Balance> for Pallet<T> {
+	#[transactional]
+	fn adjust_loan(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		collateral_adjustment: Amount,
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		Self::do_adjust_loan(who, currency_id, collateral_adjustment, debit_adjustment)
+	}
+
+	fn get_position(who: &T::AccountId, currency_id: CurrencyId) -> (Balance, Balance) {
+		let loans::Position { collateral, debit } = <loans::Pallet<T>>::positions(currency_id, who);
+		(collateral, debit)
+	}
+
+	fn get_liquidation_ratio(currency_id: CurrencyId) -> Ratio {
+		<cdp_engine::Pallet<T>>::get_liquidation_ratio(currency_id)
+	}
+
+	fn get_current_collateral_ratio(who: &T::AccountId, currency_id: CurrencyId) -> Option<Ratio> {
+		let loans::Position { collateral, debit } = <loans::Pallet<T>>::positions(currency_id, who);
+		let price = <cdp_engine::Pallet<T>>::get_collateral_price(currency_id)?;
+		Some(<cdp_engine::Pallet<T>>::calculate_collateral_ratio(
+			currency_id,
+			collateral,
+			debit,
+			price,
+		))
+	}
+
+	fn get_debit_exchange_rate(currency_id: CurrencyId) -> ExchangeRate {
+		<cdp_engine::Pallet<T>>::get_debit_exchange_rate(currency_id)
+	}
+}
@@ -211,12 +211,14 @@ impl cdp_treasury::Config for Runtime {
 	type Event = Event;
 	type Currency = Currencies;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type AuctionManagerHandler = MockAuctionManager;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type DEX = ();
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type IdleScheduler = ();
 	type WeightInfo = ();
 }
 
@@ -237,6 +239,8 @@ parameter_types! {
 	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(10, 100);
 	pub const MinimumDebitValue: Balance = 2;
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(50, 100);
+	pub const PartialLiquidationTarget: Option<Ratio> = None;
+	pub KeeperIncentiveRate: Rate = Rate::saturating_from_rational(1, 10);
 	pub const UnsignedPriority: u64 = 1 << 20;
 	pub DefaultSwapParitalPathList: Vec<Vec<CurrencyId>> = vec![
 		vec![AUSD],
@@ -246,6 +250,7 @@ parameter_types! {
 impl cdp_engine::Config for Runtime {
 	type Event = Event;
 	type PriceSource = MockPriceSource;
+	type PriceCircuitBreaker = ();
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -253,8 +258,12 @@ impl cdp_engine::Config for Runtime {
 	type MinimumDebitValue = MinimumDebitValue;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CDPTreasuryModule;
+	type StabilityPool = ();
+	type RiskParameters = ();
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
+	type PartialLiquidationTarget = PartialLiquidationTarget;
+	type KeeperIncentiveRate = KeeperIncentiveRate;
 	type UnsignedPriority = UnsignedPriority;
 	type EmergencyShutdown = MockEmergencyShutdown;
 	type UnixTime = Timestamp;
@@ -267,12 +276,14 @@ type Block = frame_system::mocking::MockBlock<Runtime>;
 
 parameter_types! {
 	pub const DepositPerAuthorization: Balance = 100;
+	pub TopUpRewardRate: Rate = Rate::saturating_from_rational(1, 10);
 }
 
 impl Config for Runtime {
 	type Event = Event;
 	type Currency = PalletBalances;
 	type DepositPerAuthorization = DepositPerAuthorization;
+	type TopUpRewardRate = TopUpRewardRate;
 	type WeightInfo = ();
 }
 
@@ -25,7 +25,7 @@ use frame_support::{assert_noop, assert_ok};
 use mock::{Event, *};
 use orml_traits::Change;
 use sp_runtime::FixedPointNumber;
-use support::{Rate, Ratio};
+use support::{ExchangeRate, HonzonManager, Rate, Ratio};
 
 #[test]
 fn authorize_should_work() {
@@ -117,6 +117,70 @@ fn transfer_unauthorization_loans_should_not_work() {
 	});
 }
 
+#[test]
+fn transfer_loan_to_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50));
+		assert_noop!(
+			HonzonModule::transfer_loan_to(Origin::signed(ALICE), ALICE, BTC),
+			Error::<Runtime>::InvalidNewOwner,
+		);
+
+		assert_ok!(HonzonModule::transfer_loan_to(Origin::signed(ALICE), BOB, BTC));
+		System::assert_last_event(Event::HonzonModule(crate::Event::LoanTransferOffered(ALICE, BOB, BTC)));
+		assert_eq!(HonzonModule::loan_transfer_offers(ALICE, BTC), Some(BOB));
+
+		// only the new owner can accept the offer
+		assert_noop!(
+			HonzonModule::accept_loan_transfer(Origin::signed(CAROL), ALICE, BTC),
+			Error::<Runtime>::LoanTransferOfferNotExists,
+		);
+		assert_noop!(
+			HonzonModule::accept_loan_transfer(Origin::signed(BOB), ALICE, DOT),
+			Error::<Runtime>::LoanTransferOfferNotExists,
+		);
+
+		assert_ok!(HonzonModule::accept_loan_transfer(Origin::signed(BOB), ALICE, BTC));
+		System::assert_last_event(Event::HonzonModule(crate::Event::LoanTransferAccepted(ALICE, BOB, BTC)));
+		assert_eq!(HonzonModule::loan_transfer_offers(ALICE, BTC), None);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, BOB).collateral, 100);
+		assert_eq!(LoansModule::positions(BTC, BOB).debit, 50);
+	});
+}
+
+#[test]
+fn cancel_loan_transfer_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			HonzonModule::cancel_loan_transfer(Origin::signed(ALICE), BTC),
+			Error::<Runtime>::LoanTransferOfferNotExists,
+		);
+		assert_ok!(HonzonModule::transfer_loan_to(Origin::signed(ALICE), BOB, BTC));
+		assert_ok!(HonzonModule::cancel_loan_transfer(Origin::signed(ALICE), BTC));
+		System::assert_last_event(Event::HonzonModule(crate::Event::LoanTransferCancelled(
+			ALICE, BOB, BTC,
+		)));
+		assert_eq!(HonzonModule::loan_transfer_offers(ALICE, BTC), None);
+		assert_noop!(
+			HonzonModule::accept_loan_transfer(Origin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::LoanTransferOfferNotExists,
+		);
+	});
+}
+
 #[test]
 fn adjust_loan_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -135,6 +199,72 @@ fn adjust_loan_should_work() {
 	});
 }
 
+#[test]
+fn honzon_manager_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(<HonzonModule as HonzonManager<_, _, _, _>>::adjust_loan(
+			&ALICE, BTC, 100, 50
+		));
+		assert_eq!(
+			<HonzonModule as HonzonManager<_, _, _, _>>::get_position(&ALICE, BTC),
+			(100, 50)
+		);
+		assert_eq!(
+			<HonzonModule as HonzonManager<_, _, _, _>>::get_liquidation_ratio(BTC),
+			Ratio::saturating_from_rational(3, 2)
+		);
+		assert_eq!(
+			<HonzonModule as HonzonManager<_, _, _, _>>::get_current_collateral_ratio(&ALICE, BTC),
+			Some(Ratio::saturating_from_rational(2, 1))
+		);
+		assert_eq!(
+			<HonzonModule as HonzonManager<_, _, _, _>>::get_debit_exchange_rate(BTC),
+			ExchangeRate::one()
+		);
+	});
+}
+
+#[test]
+fn adjust_loans_batch_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		for currency_id in vec![BTC, DOT] {
+			assert_ok!(CDPEngineModule::set_collateral_params(
+				Origin::signed(1),
+				currency_id,
+				Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+				Change::NewValue(10000),
+			));
+		}
+		assert_ok!(HonzonModule::adjust_loans_batch(
+			Origin::signed(ALICE),
+			vec![(BTC, 100, 50), (DOT, 200, 100)]
+		));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 50);
+		assert_eq!(LoansModule::positions(DOT, ALICE).collateral, 200);
+		assert_eq!(LoansModule::positions(DOT, ALICE).debit, 100);
+
+		// a failed adjustment reverts the whole batch
+		assert_noop!(
+			HonzonModule::adjust_loans_batch(Origin::signed(ALICE), vec![(BTC, 100, 0), (DOT, 0, 1000)]),
+			cdp_engine::Error::<Runtime>::BelowRequiredCollateralRatio,
+		);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
+	});
+}
+
 #[test]
 fn on_emergency_shutdown_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -151,6 +281,10 @@ fn on_emergency_shutdown_should_work() {
 			HonzonModule::close_loan_has_debit_by_dex(Origin::signed(ALICE), BTC, 100, None),
 			Error::<Runtime>::AlreadyShutdown,
 		);
+		assert_noop!(
+			HonzonModule::top_up_unsafe_position(Origin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::AlreadyShutdown,
+		);
 	});
 }
 
@@ -180,3 +314,130 @@ fn close_loan_has_debit_by_dex_work() {
 		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
 	});
 }
+
+#[test]
+fn authorize_auto_top_up_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			HonzonModule::authorize_auto_top_up(Origin::signed(ALICE), BTC, DOT, 100),
+			Error::<Runtime>::InvalidTopUpSource
+		);
+		assert_noop!(
+			HonzonModule::cancel_auto_top_up(Origin::signed(ALICE), BTC),
+			Error::<Runtime>::AutoTopUpNotAuthorized
+		);
+
+		assert_ok!(HonzonModule::authorize_auto_top_up(
+			Origin::signed(ALICE),
+			BTC,
+			BTC,
+			100
+		));
+		assert_eq!(HonzonModule::auto_top_up(ALICE, BTC), Some((BTC, 100)));
+		System::assert_last_event(Event::HonzonModule(crate::Event::AutoTopUpAuthorized(
+			ALICE, BTC, BTC, 100,
+		)));
+
+		assert_ok!(HonzonModule::authorize_auto_top_up(
+			Origin::signed(ALICE),
+			BTC,
+			AUSD,
+			50
+		));
+		assert_eq!(HonzonModule::auto_top_up(ALICE, BTC), Some((AUSD, 50)));
+
+		assert_ok!(HonzonModule::cancel_auto_top_up(Origin::signed(ALICE), BTC));
+		assert_eq!(HonzonModule::auto_top_up(ALICE, BTC), None);
+		System::assert_last_event(Event::HonzonModule(crate::Event::AutoTopUpCancelled(ALICE, BTC)));
+	});
+}
+
+#[test]
+fn top_up_unsafe_position_with_collateral_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50));
+		assert_noop!(
+			HonzonModule::top_up_unsafe_position(Origin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::AutoTopUpNotAuthorized
+		);
+		assert_ok!(HonzonModule::authorize_auto_top_up(Origin::signed(ALICE), BTC, BTC, 55));
+		assert_noop!(
+			HonzonModule::top_up_unsafe_position(Origin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::PositionNotNearLiquidation
+		);
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+		));
+		assert_ok!(HonzonModule::top_up_unsafe_position(Origin::signed(BOB), ALICE, BTC));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 150);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 50);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 845);
+		assert_eq!(Currencies::free_balance(BTC, &BOB), 1005);
+		assert_eq!(HonzonModule::auto_top_up(ALICE, BTC), None);
+		System::assert_last_event(Event::HonzonModule(crate::Event::PositionToppedUp(
+			ALICE, BTC, BOB, BTC, 50, 5,
+		)));
+	});
+}
+
+#[test]
+fn top_up_unsafe_position_with_stable_currency_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50));
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
+		assert_ok!(HonzonModule::authorize_auto_top_up(
+			Origin::signed(ALICE),
+			BTC,
+			AUSD,
+			30
+		));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(5, 2))),
+			Change::NoChange,
+		));
+
+		// 10 aUSD is repaid so that the collateral ratio reaches 250%
+		assert_ok!(HonzonModule::top_up_unsafe_position(Origin::signed(BOB), ALICE, BTC));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 40);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 39);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 1);
+		assert_eq!(HonzonModule::auto_top_up(ALICE, BTC), Some((AUSD, 19)));
+		System::assert_last_event(Event::HonzonModule(crate::Event::PositionToppedUp(
+			ALICE, BTC, BOB, AUSD, 10, 1,
+		)));
+	});
+}
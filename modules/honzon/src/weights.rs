@@ -54,6 +54,12 @@ pub trait WeightInfo {
 	fn adjust_loan() -> Weight;
 	fn transfer_loan_from() -> Weight;
 	fn close_loan_has_debit_by_dex(u: u32, ) -> Weight;
+	fn authorize_auto_top_up() -> Weight;
+	fn cancel_auto_top_up() -> Weight;
+	fn top_up_unsafe_position() -> Weight;
+	fn transfer_loan_to() -> Weight;
+	fn cancel_loan_transfer() -> Weight;
+	fn accept_loan_transfer() -> Weight;
 }
 
 /// Weights for module_honzon using the Acala node and recommended hardware.
@@ -90,6 +96,34 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(21 as Weight))
 			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
+	fn authorize_auto_top_up() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_auto_top_up() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn top_up_unsafe_position() -> Weight {
+		(175_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(27 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+	}
+	fn transfer_loan_to() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_loan_transfer() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn accept_loan_transfer() -> Weight {
+		(198_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(21 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -125,4 +159,32 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(21 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
 	}
+	fn authorize_auto_top_up() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_auto_top_up() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn top_up_unsafe_position() -> Weight {
+		(175_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(27 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
+	}
+	fn transfer_loan_to() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_loan_transfer() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn accept_loan_transfer() -> Weight {
+		(198_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(21 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
 }
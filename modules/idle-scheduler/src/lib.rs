@@ -0,0 +1,181 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Idle Scheduler Module
+//!
+//! ## Overview
+//!
+//! Queue weight-bounded background tasks, such as storage sweeps of deleted
+//! EVM contracts, large storage migrations or the settlement of CDPs, and
+//! dispatch them in `on_idle` across multiple blocks. A task which is not
+//! finished within the given weight stays queued and is dispatched again in
+//! later blocks. Queued tasks can be queried by id and cancelled by the
+//! update origin.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use sp_std::prelude::*;
+use support::{DispatchableTask, IdleScheduler};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub type TaskId = u32;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The background tasks to be dispatched.
+		type Task: DispatchableTask + Parameter;
+
+		/// The weight to be left for other work in the block, tasks are
+		/// dispatched only with the idle weight above it.
+		#[pallet::constant]
+		type MinimumWeightRemainInBlock: Get<Weight>;
+
+		/// The origin which may schedule and cancel tasks.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The task does not exist
+		TaskNotFound,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A task has been scheduled. \[task_id, task\]
+		TaskAdded(TaskId, T::Task),
+		/// A task has finished. \[task_id, result\]
+		TaskDispatched(TaskId, DispatchResult),
+		/// A task has been cancelled. \[task_id\]
+		TaskCancelled(TaskId),
+	}
+
+	/// The queued tasks, removed once finished or cancelled.
+	///
+	/// Tasks: map TaskId => Option<Task>
+	#[pallet::storage]
+	#[pallet::getter(fn tasks)]
+	pub type Tasks<T: Config> = StorageMap<_, Twox64Concat, TaskId, T::Task, OptionQuery>;
+
+	/// The id of the next scheduled task.
+	///
+	/// NextTaskId: TaskId
+	#[pallet::storage]
+	#[pallet::getter(fn next_task_id)]
+	pub type NextTaskId<T: Config> = StorageValue<_, TaskId, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_idle(_now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::dispatch_tasks(remaining_weight.saturating_sub(T::MinimumWeightRemainInBlock::get()))
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Queue `task` to be dispatched in idle time.
+		#[pallet::weight(T::WeightInfo::schedule_task())]
+		#[transactional]
+		pub fn schedule_task(origin: OriginFor<T>, task: T::Task) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::do_schedule_task(task);
+			Ok(())
+		}
+
+		/// Remove the queued task `task_id`.
+		#[pallet::weight(T::WeightInfo::cancel_task())]
+		#[transactional]
+		pub fn cancel_task(origin: OriginFor<T>, task_id: TaskId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Tasks::<T>::take(task_id).ok_or(Error::<T>::TaskNotFound)?;
+			Self::deposit_event(Event::TaskCancelled(task_id));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn do_schedule_task(task: T::Task) -> TaskId {
+		let task_id = NextTaskId::<T>::mutate(|id| {
+			let current = *id;
+			*id = id.wrapping_add(1);
+			current
+		});
+		Tasks::<T>::insert(task_id, &task);
+		Self::deposit_event(Event::TaskAdded(task_id, task));
+		task_id
+	}
+
+	/// Dispatch the queued tasks with at most `weight`, returns the used
+	/// weight.
+	fn dispatch_tasks(weight: Weight) -> Weight {
+		// reading and removing a task
+		let task_overhead = T::DbWeight::get().reads_writes(1, 1);
+		let mut remaining_weight = weight;
+		let mut finished_tasks = vec![];
+
+		for (task_id, task) in Tasks::<T>::iter() {
+			if remaining_weight <= task_overhead {
+				break;
+			}
+			remaining_weight -= task_overhead;
+
+			let result = task.dispatch(remaining_weight);
+			remaining_weight = remaining_weight.saturating_sub(result.used_weight);
+			if result.finished {
+				finished_tasks.push((task_id, result.result));
+			}
+		}
+
+		// tasks must not be removed while iterating the map
+		for (task_id, result) in finished_tasks {
+			Tasks::<T>::remove(task_id);
+			Self::deposit_event(Event::TaskDispatched(task_id, result));
+		}
+
+		weight.saturating_sub(remaining_weight)
+	}
+}
+
+impl<T: Config, Task: Into<T::Task>> IdleScheduler<Task> for Pallet<T> {
+	fn schedule(task: Task) -> Result<TaskId, DispatchError> {
+		Ok(Self::do_schedule_task(task.into()))
+	}
+}
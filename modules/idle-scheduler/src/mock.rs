@@ -0,0 +1,155 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the idle scheduler module.
+
+#![cfg(test)]
+
+use super::*;
+use codec::{Decode, Encode};
+use frame_support::{construct_runtime, ord_parameter_types, parameter_types};
+use frame_system::EnsureSignedBy;
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup, RuntimeDebug};
+use support::TaskResult;
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+pub const MINIMUM_WEIGHT_REMAIN_IN_BLOCK: Weight = 1_000;
+
+mod idle_scheduler {
+	pub use super::super::*;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
+pub enum MockTask {
+	/// Finishes with the result once given at least the weight.
+	Finish(Weight, bool),
+	/// Never finishes, using all the given weight.
+	Endless,
+}
+
+impl DispatchableTask for MockTask {
+	fn dispatch(self, weight: Weight) -> TaskResult {
+		match self {
+			MockTask::Finish(used_weight, _) if used_weight > weight => TaskResult {
+				result: Ok(()),
+				used_weight: 0,
+				finished: false,
+			},
+			MockTask::Finish(used_weight, ok) => TaskResult {
+				result: if ok {
+					Ok(())
+				} else {
+					Err(DispatchError::Other("task failed"))
+				},
+				used_weight,
+				finished: true,
+			},
+			MockTask::Endless => TaskResult {
+				result: Ok(()),
+				used_weight: weight,
+				finished: false,
+			},
+		}
+	}
+}
+
+ord_parameter_types! {
+	pub const One: AccountId = ALICE;
+}
+
+parameter_types! {
+	pub const MinimumWeightRemainInBlock: Weight = MINIMUM_WEIGHT_REMAIN_IN_BLOCK;
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type Task = MockTask;
+	type MinimumWeightRemainInBlock = MinimumWeightRemainInBlock;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		IdleScheduler: idle_scheduler::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+pub struct ExtBuilder;
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,113 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the idle scheduler module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn schedule_task_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			IdleScheduler::schedule_task(Origin::signed(BOB), MockTask::Endless),
+			BadOrigin
+		);
+
+		assert_ok!(IdleScheduler::schedule_task(Origin::signed(ALICE), MockTask::Endless));
+		System::assert_last_event(Event::IdleScheduler(crate::Event::TaskAdded(0, MockTask::Endless)));
+		assert_eq!(IdleScheduler::tasks(0), Some(MockTask::Endless));
+
+		assert_eq!(
+			<IdleScheduler as support::IdleScheduler<MockTask>>::schedule(MockTask::Finish(10, true)),
+			Ok(1)
+		);
+		assert_eq!(IdleScheduler::tasks(1), Some(MockTask::Finish(10, true)));
+		assert_eq!(IdleScheduler::next_task_id(), 2);
+	});
+}
+
+#[test]
+fn cancel_task_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IdleScheduler::schedule_task(Origin::signed(ALICE), MockTask::Endless));
+
+		assert_noop!(IdleScheduler::cancel_task(Origin::signed(BOB), 0), BadOrigin);
+		assert_noop!(
+			IdleScheduler::cancel_task(Origin::signed(ALICE), 1),
+			Error::<Runtime>::TaskNotFound
+		);
+
+		assert_ok!(IdleScheduler::cancel_task(Origin::signed(ALICE), 0));
+		System::assert_last_event(Event::IdleScheduler(crate::Event::TaskCancelled(0)));
+		assert_eq!(IdleScheduler::tasks(0), None);
+	});
+}
+
+#[test]
+fn on_idle_dispatches_tasks() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IdleScheduler::schedule_task(
+			Origin::signed(ALICE),
+			MockTask::Finish(100, true)
+		));
+		assert_ok!(IdleScheduler::schedule_task(
+			Origin::signed(ALICE),
+			MockTask::Finish(200, false)
+		));
+
+		// no weight above the minimum remain
+		assert_eq!(IdleScheduler::on_idle(1, MINIMUM_WEIGHT_REMAIN_IN_BLOCK), 0);
+		assert_eq!(IdleScheduler::tasks(0), Some(MockTask::Finish(100, true)));
+		assert_eq!(IdleScheduler::tasks(1), Some(MockTask::Finish(200, false)));
+
+		assert_eq!(IdleScheduler::on_idle(1, MINIMUM_WEIGHT_REMAIN_IN_BLOCK + 1_000), 300);
+		assert_eq!(IdleScheduler::tasks(0), None);
+		assert_eq!(IdleScheduler::tasks(1), None);
+		System::assert_has_event(Event::IdleScheduler(crate::Event::TaskDispatched(0, Ok(()))));
+		System::assert_has_event(Event::IdleScheduler(crate::Event::TaskDispatched(
+			1,
+			Err(DispatchError::Other("task failed")),
+		)));
+	});
+}
+
+#[test]
+fn unfinished_tasks_stay_queued() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IdleScheduler::schedule_task(
+			Origin::signed(ALICE),
+			MockTask::Finish(2_000, true)
+		));
+
+		// not enough weight to finish the task
+		assert_eq!(IdleScheduler::on_idle(1, MINIMUM_WEIGHT_REMAIN_IN_BLOCK + 1_000), 0);
+		assert_eq!(IdleScheduler::tasks(0), Some(MockTask::Finish(2_000, true)));
+
+		assert_eq!(IdleScheduler::on_idle(2, MINIMUM_WEIGHT_REMAIN_IN_BLOCK + 2_000), 2_000);
+		assert_eq!(IdleScheduler::tasks(0), None);
+
+		assert_ok!(IdleScheduler::schedule_task(Origin::signed(ALICE), MockTask::Endless));
+		assert_eq!(IdleScheduler::on_idle(3, MINIMUM_WEIGHT_REMAIN_IN_BLOCK + 500), 500);
+		assert_eq!(IdleScheduler::tasks(1), Some(MockTask::Endless));
+	});
+}
@@ -47,27 +47,81 @@ use frame_system::pallet_prelude::*;
 use orml_traits::{Happened, MultiCurrency, RewardHandler};
 use primitives::{Amount, Balance, CurrencyId};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, UniqueSaturatedInto, Zero},
+	helpers_128bit::multiply_by_rational,
+	traits::{AccountIdConversion, AtLeast32BitUnsigned, One, Saturating, UniqueSaturatedInto, Zero},
 	DispatchResult, FixedPointNumber, RuntimeDebug,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
-use support::{CDPTreasury, DEXIncentives, DEXManager, EmergencyShutdown, Rate};
+use support::{CDPTreasury, DEXIncentives, DEXManager, EmergencyShutdown, IncentivesManager, Rate, VestedTransfer};
 
 mod mock;
 mod tests;
 pub mod weights;
 
 pub use module::*;
+pub use support::PoolId;
 pub use weights::WeightInfo;
 
-/// PoolId for various rewards pools
+/// Reward multiplier applied to the incentive reward amounts of a pool, which
+/// decays by `decay_rate` every `decay_period`.
 #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
-pub enum PoolId {
-	/// Rewards and shares pool for users who open CDP(CollateralCurrencyId)
-	Loans(CurrencyId),
+pub struct RewardMultiplier<BlockNumber> {
+	/// The current multiplier
+	pub multiplier: Rate,
+	/// The ratio decayed from the multiplier every period
+	pub decay_rate: Rate,
+	/// The period of decay, zero means never decay
+	pub decay_period: BlockNumber,
+	/// The block number of the next decay
+	pub next_decay_at: BlockNumber,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> RewardMultiplier<BlockNumber> {
+	/// The reward multiplier after the decay periods passed at `now`.
+	pub fn decayed(self, now: BlockNumber) -> Self {
+		if self.decay_period.is_zero() || now < self.next_decay_at {
+			return self;
+		}
+
+		let passed_periods: u32 = ((now - self.next_decay_at) / self.decay_period)
+			.saturating_add(One::one())
+			.unique_saturated_into();
+		RewardMultiplier {
+			multiplier: self.multiplier.saturating_mul(
+				Rate::one()
+					.saturating_sub(self.decay_rate)
+					.saturating_pow(passed_periods as usize),
+			),
+			next_decay_at: self
+				.next_decay_at
+				.saturating_add(self.decay_period.saturating_mul(passed_periods.into())),
+			..self
+		}
+	}
+}
 
-	/// Rewards and shares pool for DEX makers who stake LP token(LPCurrencyId)
-	Dex(CurrencyId),
+/// The vesting payout option of a pool, the rewards in native currency can be
+/// claimed as a vesting schedule instead of being deducted.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct VestingPayoutConfig<BlockNumber> {
+	/// The ratio of the rewards paid immediately
+	pub upfront_rate: Rate,
+	/// The blocks of every vesting period
+	pub period: BlockNumber,
+	/// The count of vesting periods
+	pub period_count: u32,
+}
+
+/// The loyalty bonus of a finished loyalty period, shared pro rata by the
+/// stakers who haven't claimed rewards or increased shares within the period.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default)]
+pub struct LoyaltyBonus<BlockNumber> {
+	/// The index of the finished loyalty period
+	pub period: BlockNumber,
+	/// The total shares of the eligible stakers at the end of the period
+	pub eligible_shares: Balance,
+	/// The remaining bonus amounts of multi currencies
+	pub rewards: BTreeMap<CurrencyId, Balance>,
 }
 
 #[frame_support::pallet]
@@ -85,6 +139,14 @@ pub mod module {
 		#[pallet::constant]
 		type AccumulatePeriod: Get<Self::BlockNumber>;
 
+		/// The period to settle the loyalty bonus from the forfeited rewards
+		#[pallet::constant]
+		type LoyaltyPeriod: Get<Self::BlockNumber>;
+
+		/// The native currency id, which can be paid as vesting schedule.
+		#[pallet::constant]
+		type NativeCurrencyId: Get<CurrencyId>;
+
 		/// The reward type for dex saving.
 		#[pallet::constant]
 		type StableCurrencyId: Get<CurrencyId>;
@@ -108,6 +170,9 @@ pub mod module {
 		/// Emergency shutdown.
 		type EmergencyShutdown: EmergencyShutdown;
 
+		/// Pay the rewards in native currency as vesting schedule.
+		type VestedTransfer: VestedTransfer<Self::AccountId, Self::BlockNumber, Balance>;
+
 		/// The module id, keep DexShare LP.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -126,6 +191,10 @@ pub mod module {
 		InvalidPoolId,
 		/// Invalid rate
 		InvalidRate,
+		/// Invalid vesting payout config
+		InvalidVestingPayoutConfig,
+		/// The pool doesn't enable vesting payout
+		VestingPayoutNotEnabled,
 	}
 
 	#[pallet::event]
@@ -145,6 +214,17 @@ pub mod module {
 		SavingRewardRateUpdated(PoolId, Rate),
 		/// Payout deduction rate updated. \[pool_id, deduction_rate\]
 		ClaimRewardDeductionRateUpdated(PoolId, Rate),
+		/// Reward multiplier updated or decayed. \[pool_id, multiplier\]
+		RewardMultiplierUpdated(PoolId, Rate),
+		/// The loyalty bonus of a period settled. \[pool_id, period, eligible_shares\]
+		LoyaltyBonusSettled(PoolId, T::BlockNumber, Balance),
+		/// Claim loyalty bonus. \[who, pool_id, reward_currency_id, amount\]
+		ClaimLoyaltyBonus(T::AccountId, PoolId, CurrencyId, Balance),
+		/// Vesting payout config updated. \[pool_id, config\]
+		VestingPayoutConfigUpdated(PoolId, Option<VestingPayoutConfig<T::BlockNumber>>),
+		/// Claim rewards as vesting schedule. \[who, pool_id, reward_currency_id, upfront_amount,
+		/// vested_amount\]
+		ClaimRewardsVested(T::AccountId, PoolId, CurrencyId, Balance, Balance),
 	}
 
 	/// Mapping from pool to its fixed incentive amounts of multi currencies per period.
@@ -169,6 +249,65 @@ pub mod module {
 	#[pallet::getter(fn claim_reward_deduction_rates)]
 	pub type ClaimRewardDeductionRates<T: Config> = StorageMap<_, Twox64Concat, PoolId, Rate, ValueQuery>;
 
+	/// Mapping from pool to its reward multiplier of incentive rewards, 1x if not exists.
+	///
+	/// RewardMultipliers: map Pool => RewardMultiplier
+	#[pallet::storage]
+	#[pallet::getter(fn reward_multipliers)]
+	pub type RewardMultipliers<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, RewardMultiplier<T::BlockNumber>, OptionQuery>;
+
+	/// The vesting payout option of the pool.
+	///
+	/// VestingPayoutConfigs: map Pool => VestingPayoutConfig
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_payout_configs)]
+	pub type VestingPayoutConfigs<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, VestingPayoutConfig<T::BlockNumber>, OptionQuery>;
+
+	/// The rewards forfeited by claim reward deduction in the current loyalty period.
+	///
+	/// LoyaltyBonusBuckets: map Pool => BTreeMap<CurrencyId, Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn loyalty_bonus_buckets)]
+	pub type LoyaltyBonusBuckets<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, BTreeMap<CurrencyId, Balance>, ValueQuery>;
+
+	/// The loyalty bonus of the last finished loyalty period, the unclaimed part
+	/// rolls into the bucket when the next period finishes.
+	///
+	/// LoyaltyBonuses: map Pool => LoyaltyBonus
+	#[pallet::storage]
+	#[pallet::getter(fn loyalty_bonuses)]
+	pub type LoyaltyBonuses<T: Config> = StorageMap<_, Twox64Concat, PoolId, LoyaltyBonus<T::BlockNumber>, OptionQuery>;
+
+	/// The shares of the stakers who are not eligible for the loyalty bonus of
+	/// the current period.
+	///
+	/// DisloyalShares: map Pool => Share
+	#[pallet::storage]
+	#[pallet::getter(fn disloyal_shares)]
+	pub type DisloyalShares<T: Config> = StorageMap<_, Twox64Concat, PoolId, Balance, ValueQuery>;
+
+	/// The last loyalty period in which the staker claimed rewards or increased
+	/// shares.
+	///
+	/// LastDisloyalPeriods: double_map Pool, AccountId => Period
+	#[pallet::storage]
+	#[pallet::getter(fn last_disloyal_periods)]
+	pub type LastDisloyalPeriods<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, PoolId, Twox64Concat, T::AccountId, T::BlockNumber, OptionQuery>;
+
+	/// The shares of the staker eligible for the loyalty bonus of the previous
+	/// period, taken before the shares or the loyalty of the staker first
+	/// change in a period, zero once the bonus is claimed. \[period, shares\]
+	///
+	/// LoyaltyShareSnapshots: double_map Pool, AccountId => (Period, Share)
+	#[pallet::storage]
+	#[pallet::getter(fn loyalty_share_snapshots)]
+	pub type LoyaltyShareSnapshots<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, PoolId, Twox64Concat, T::AccountId, (T::BlockNumber, Balance), OptionQuery>;
+
 	/// The pending rewards amount, actual available rewards amount may be deducted
 	///
 	/// PendingMultiRewards: double_map PoolId, AccountId => BTreeMap<CurrencyId, Balance>
@@ -190,6 +329,14 @@ pub mod module {
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
 		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let mut weight: Weight = 0;
+
+			// settle loyalty bonus periodically
+			if now % T::LoyaltyPeriod::get() == Zero::zero() {
+				let count = Self::settle_loyalty_bonus(now);
+				weight = weight.saturating_add(T::WeightInfo::on_initialize(count));
+			}
+
 			// accumulate reward periodically
 			if now % T::AccumulatePeriod::get() == Zero::zero() {
 				let mut count: u32 = 0;
@@ -201,7 +348,7 @@ pub mod module {
 							// do not accumulate incentives for PoolId::Loans after shutdown
 							PoolId::Loans(_) if !shutdown => {
 								count += 1;
-								Self::accumulate_incentives(pool_id);
+								Self::accumulate_incentives(pool_id, now);
 							}
 							PoolId::Dex(lp_currency_id) => {
 								// do not accumulate dex saving any more after shutdown
@@ -209,16 +356,16 @@ pub mod module {
 									Self::accumulate_dex_saving(lp_currency_id, pool_id);
 								}
 								count += 1;
-								Self::accumulate_incentives(pool_id);
+								Self::accumulate_incentives(pool_id, now);
 							}
 							_ => {}
 						}
 					}
 				}
 
-				T::WeightInfo::on_initialize(count)
+				weight.saturating_add(T::WeightInfo::on_initialize(count))
 			} else {
-				0
+				weight
 			}
 		}
 	}
@@ -262,42 +409,25 @@ pub mod module {
 		#[transactional]
 		pub fn claim_rewards(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			Self::do_claim_rewards(&who, pool_id, false)
+		}
 
-			// orml_rewards will claim rewards for all currencies rewards
-			<orml_rewards::Pallet<T>>::claim_rewards(&who, &pool_id);
-
-			let pending_multi_rewards: BTreeMap<CurrencyId, Balance> = PendingMultiRewards::<T>::take(&pool_id, &who);
-			let deduction_rate = Self::claim_reward_deduction_rates(&pool_id);
-
-			for (currency_id, pending_reward) in pending_multi_rewards {
-				if pending_reward.is_zero() {
-					continue;
-				}
-				// calculate actual rewards and deduction amount
-				let (actual_amount, deduction_amount) = {
-					let deduction_amount = deduction_rate.saturating_mul_int(pending_reward).min(pending_reward);
-					if !deduction_amount.is_zero() {
-						// re-accumulate deduction to rewards pool if deduction amount is not zero
-						<orml_rewards::Pallet<T>>::accumulate_reward(&pool_id, currency_id, deduction_amount)?;
-					}
-					(pending_reward.saturating_sub(deduction_amount), deduction_amount)
-				};
-
-				// transfer the actual reward(pending reward exclude deduction) to user from the pool. it should not
-				// affect the process, ignore the result to continue. if it fails, just the user will not
-				// be rewarded, there will not increase user balance.
-				T::Currency::transfer(currency_id, &Self::account_id(), &who, actual_amount)?;
-
-				Self::deposit_event(Event::ClaimRewards(
-					who.clone(),
-					pool_id,
-					currency_id,
-					actual_amount,
-					deduction_amount,
-				));
-			}
-
-			Ok(())
+		/// Claim all available multi currencies rewards for specific PoolId, the
+		/// rewards in native currency are paid as vesting schedule without
+		/// deduction, the others are paid as `claim_rewards`.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		///
+		/// - `pool_id`: pool type
+		#[pallet::weight(<T as Config>::WeightInfo::claim_rewards_vested())]
+		#[transactional]
+		pub fn claim_rewards_vested(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				VestingPayoutConfigs::<T>::contains_key(&pool_id),
+				Error::<T>::VestingPayoutNotEnabled
+			);
+			Self::do_claim_rewards(&who, pool_id, true)
 		}
 
 		/// Update incentive reward amount for specific PoolId
@@ -338,6 +468,44 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Update reward multiplier of incentive rewards for specific PoolId, which
+		/// decays by `decay_rate` every `decay_period` blocks from now on.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `updates`: Vec<(PoolId, Multiplier, DecayRate, DecayPeriod)>
+		#[pallet::weight(<T as Config>::WeightInfo::update_reward_multipliers(updates.len() as u32))]
+		#[transactional]
+		pub fn update_reward_multipliers(
+			origin: OriginFor<T>,
+			updates: Vec<(PoolId, Rate, Rate, T::BlockNumber)>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			for (pool_id, multiplier, decay_rate, decay_period) in updates {
+				if let PoolId::Dex(currency_id) = pool_id {
+					ensure!(currency_id.is_dex_share_currency_id(), Error::<T>::InvalidPoolId);
+				}
+				ensure!(decay_rate <= Rate::one(), Error::<T>::InvalidRate);
+
+				if multiplier == Rate::one() && (decay_rate.is_zero() || decay_period.is_zero()) {
+					RewardMultipliers::<T>::remove(&pool_id);
+				} else {
+					RewardMultipliers::<T>::insert(
+						&pool_id,
+						RewardMultiplier {
+							multiplier,
+							decay_rate,
+							decay_period,
+							next_decay_at: now.saturating_add(decay_period),
+						},
+					);
+				}
+				Self::deposit_event(Event::RewardMultiplierUpdated(pool_id, multiplier));
+			}
+			Ok(())
+		}
+
 		/// Update DEX saving reward rate for specific PoolId
 		///
 		/// The dispatch origin of this call must be `UpdateOrigin`.
@@ -404,6 +572,36 @@ pub mod module {
 			}
 			Ok(())
 		}
+
+		/// Update vesting payout configs for specific PoolId, `None` disables
+		/// vesting payout of the pool.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `updates`: Vec<(PoolId, Option<VestingPayoutConfig>)>
+		#[pallet::weight(<T as Config>::WeightInfo::update_vesting_payout_configs(updates.len() as u32))]
+		#[transactional]
+		pub fn update_vesting_payout_configs(
+			origin: OriginFor<T>,
+			updates: Vec<(PoolId, Option<VestingPayoutConfig<T::BlockNumber>>)>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			for (pool_id, maybe_config) in updates {
+				if let PoolId::Dex(currency_id) = pool_id {
+					ensure!(currency_id.is_dex_share_currency_id(), Error::<T>::InvalidPoolId);
+				}
+				if let Some(config) = maybe_config {
+					ensure!(config.upfront_rate <= Rate::one(), Error::<T>::InvalidRate);
+					ensure!(
+						!config.period.is_zero() && !config.period_count.is_zero(),
+						Error::<T>::InvalidVestingPayoutConfig
+					);
+				}
+				VestingPayoutConfigs::<T>::set(&pool_id, maybe_config);
+				Self::deposit_event(Event::VestingPayoutConfigUpdated(pool_id, maybe_config));
+			}
+			Ok(())
+		}
 	}
 }
 
@@ -412,9 +610,331 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account()
 	}
 
+	fn do_claim_rewards(who: &T::AccountId, pool_id: PoolId, vested: bool) -> DispatchResult {
+		// orml_rewards will claim rewards for all currencies rewards
+		<orml_rewards::Pallet<T>>::claim_rewards(who, &pool_id);
+
+		let pending_multi_rewards: BTreeMap<CurrencyId, Balance> = PendingMultiRewards::<T>::take(&pool_id, who);
+		let deduction_rate = Self::claim_reward_deduction_rates(&pool_id);
+
+		// pay the loyalty bonus of the last period, then the claimer is not eligible
+		// for the bonus of the current period.
+		Self::claim_loyalty_bonus(who, pool_id)?;
+		Self::mark_disloyal(who, pool_id, Zero::zero());
+
+		let vesting_payout_config = if vested {
+			Self::vesting_payout_configs(&pool_id)
+		} else {
+			None
+		};
+
+		for (currency_id, pending_reward) in pending_multi_rewards {
+			if pending_reward.is_zero() {
+				continue;
+			}
+
+			if let Some(config) = vesting_payout_config.filter(|_| currency_id == T::NativeCurrencyId::get()) {
+				Self::payout_vested(who, pool_id, currency_id, pending_reward, config)?;
+				continue;
+			}
+
+			// calculate actual rewards and deduction amount
+			let (actual_amount, deduction_amount) = {
+				let deduction_amount = deduction_rate.saturating_mul_int(pending_reward).min(pending_reward);
+				if !deduction_amount.is_zero() {
+					// forfeited deduction goes to the loyalty bonus bucket of the pool
+					LoyaltyBonusBuckets::<T>::mutate(&pool_id, |bucket| {
+						bucket
+							.entry(currency_id)
+							.and_modify(|current| *current = current.saturating_add(deduction_amount))
+							.or_insert(deduction_amount);
+					});
+				}
+				(pending_reward.saturating_sub(deduction_amount), deduction_amount)
+			};
+
+			// transfer the actual reward(pending reward exclude deduction) to user from the pool. it should not
+			// affect the process, ignore the result to continue. if it fails, just the user will not
+			// be rewarded, there will not increase user balance.
+			T::Currency::transfer(currency_id, &Self::account_id(), who, actual_amount)?;
+
+			Self::deposit_event(Event::ClaimRewards(
+				who.clone(),
+				pool_id,
+				currency_id,
+				actual_amount,
+				deduction_amount,
+			));
+		}
+
+		Ok(())
+	}
+
+	// pay `amount` with `upfront_rate` of it immediately and the rest as vesting schedule, the
+	// remainder which can't be divided into periods is paid immediately too.
+	fn payout_vested(
+		who: &T::AccountId,
+		pool_id: PoolId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		config: VestingPayoutConfig<T::BlockNumber>,
+	) -> DispatchResult {
+		let upfront_amount = config.upfront_rate.saturating_mul_int(amount).min(amount);
+		let per_period = amount.saturating_sub(upfront_amount) / Balance::from(config.period_count);
+		let vested_amount = per_period.saturating_mul(Balance::from(config.period_count));
+		let upfront_amount = amount.saturating_sub(vested_amount);
+
+		T::Currency::transfer(currency_id, &Self::account_id(), who, upfront_amount)?;
+		if !vested_amount.is_zero() {
+			T::VestedTransfer::vested_transfer(
+				&Self::account_id(),
+				who,
+				config.period,
+				config.period_count,
+				per_period,
+			)?;
+		}
+
+		Self::deposit_event(Event::ClaimRewardsVested(
+			who.clone(),
+			pool_id,
+			currency_id,
+			upfront_amount,
+			vested_amount,
+		));
+		Ok(())
+	}
+
+	fn current_loyalty_period() -> T::BlockNumber {
+		frame_system::Pallet::<T>::block_number() / T::LoyaltyPeriod::get()
+	}
+
+	/// The loyalty bonus of the last finished period which `who` can claim in
+	/// `pool_id`.
+	pub fn pending_loyalty_bonus(pool_id: &PoolId, who: &T::AccountId) -> Vec<(CurrencyId, Balance)> {
+		match Self::loyalty_bonuses(pool_id) {
+			Some(bonus) => {
+				let share = match Self::loyalty_share_snapshots(pool_id, who) {
+					// the shares have changed since the period finished
+					Some((period, share)) if period == bonus.period.saturating_add(One::one()) => share,
+					_ => Self::loyal_shares(
+						pool_id,
+						who,
+						bonus.period,
+						<orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(pool_id, who).0,
+					),
+				};
+				let proportion = Rate::checked_from_rational(share, bonus.eligible_shares).unwrap_or_default();
+				bonus
+					.rewards
+					.into_iter()
+					.map(|(currency_id, amount)| (currency_id, proportion.saturating_mul_int(amount).min(amount)))
+					.filter(|(_, amount)| !amount.is_zero())
+					.collect()
+			}
+			None => vec![],
+		}
+	}
+
+	// `shares` of `who` if `who` stayed loyal within `period`, otherwise zero.
+	fn loyal_shares(pool_id: &PoolId, who: &T::AccountId, period: T::BlockNumber, shares: Balance) -> Balance {
+		if Self::last_disloyal_periods(pool_id, who).map_or(false, |disloyal_period| disloyal_period >= period) {
+			Zero::zero()
+		} else {
+			shares
+		}
+	}
+
+	// snapshot the shares of `who` eligible for the loyalty bonus of the previous period before the
+	// shares or the loyalty of `who` first change in the current period, `shares` is the amount
+	// before the change.
+	fn snapshot_loyalty_shares(who: &T::AccountId, pool_id: PoolId, shares: Balance) {
+		let current_period = Self::current_loyalty_period();
+		if Self::loyalty_share_snapshots(&pool_id, who).map_or(false, |(period, _)| period == current_period) {
+			return;
+		}
+		let previous_period = current_period.saturating_sub(One::one());
+		let loyal_shares = Self::loyal_shares(&pool_id, who, previous_period, shares);
+		LoyaltyShareSnapshots::<T>::insert(&pool_id, who, (current_period, loyal_shares));
+	}
+
+	/// The rewards amount of `reward_currency` which `who` can claim in
+	/// `pool_id`, including the rewards not yet withdrawn from the shares.
+	pub fn pending_rewards(pool_id: &PoolId, who: &T::AccountId, reward_currency: CurrencyId) -> Balance {
+		let pending_reward = Self::pending_multi_rewards(pool_id, who)
+			.get(&reward_currency)
+			.copied()
+			.unwrap_or_default();
+
+		let pool_info = <orml_rewards::Pallet<T>>::pool_infos(pool_id);
+		let (share, withdrawn_rewards) = <orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(pool_id, who);
+		let unwithdrawn_reward = pool_info
+			.rewards
+			.get(&reward_currency)
+			.and_then(|(total_reward, _)| multiply_by_rational(*total_reward, share, pool_info.total_shares).ok())
+			.unwrap_or_default()
+			.saturating_sub(withdrawn_rewards.get(&reward_currency).copied().unwrap_or_default());
+
+		pending_reward.saturating_add(unwithdrawn_reward)
+	}
+
+	fn claim_loyalty_bonus(who: &T::AccountId, pool_id: PoolId) -> DispatchResult {
+		let loyalty_bonus = Self::pending_loyalty_bonus(&pool_id, who);
+		if loyalty_bonus.is_empty() {
+			return Ok(());
+		}
+
+		LoyaltyBonuses::<T>::mutate(&pool_id, |maybe_bonus| {
+			if let Some(bonus) = maybe_bonus {
+				for (currency_id, amount) in loyalty_bonus.iter() {
+					bonus
+						.rewards
+						.entry(*currency_id)
+						.and_modify(|remaining| *remaining = remaining.saturating_sub(*amount));
+				}
+			}
+		});
+		// the bonus can only be claimed once
+		LoyaltyShareSnapshots::<T>::insert(&pool_id, who, (Self::current_loyalty_period(), Balance::zero()));
+		for (currency_id, amount) in loyalty_bonus {
+			T::Currency::transfer(currency_id, &Self::account_id(), who, amount)?;
+			Self::deposit_event(Event::ClaimLoyaltyBonus(who.clone(), pool_id, currency_id, amount));
+		}
+		Ok(())
+	}
+
+	// `who` is not eligible for the loyalty bonus of the current period after claiming rewards or
+	// increasing shares, `added` is the amount of shares which have just been added.
+	fn mark_disloyal(who: &T::AccountId, pool_id: PoolId, added: Balance) {
+		let shares = <orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(&pool_id, who).0;
+		Self::snapshot_loyalty_shares(who, pool_id, shares.saturating_sub(added));
+
+		let current_period = Self::current_loyalty_period();
+		LastDisloyalPeriods::<T>::mutate(&pool_id, who, |maybe_period| {
+			let increment = if *maybe_period == Some(current_period) {
+				added
+			} else {
+				*maybe_period = Some(current_period);
+				shares
+			};
+			DisloyalShares::<T>::mutate(&pool_id, |shares| *shares = shares.saturating_add(increment));
+		});
+	}
+
+	// keep the disloyal shares consistent when the disloyal staker removes shares.
+	fn on_share_removed(who: &T::AccountId, pool_id: PoolId, amount: Balance) {
+		let shares = <orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(&pool_id, who).0;
+		Self::snapshot_loyalty_shares(who, pool_id, shares.saturating_add(amount));
+
+		if Self::last_disloyal_periods(&pool_id, who) == Some(Self::current_loyalty_period()) {
+			DisloyalShares::<T>::mutate(&pool_id, |shares| *shares = shares.saturating_sub(amount));
+		}
+	}
+
+	// settle the bucket and the unclaimed bonus of each pool as the loyalty bonus of the finished
+	// period, return the count of pools.
+	fn settle_loyalty_bonus(now: T::BlockNumber) -> u32 {
+		let finished_period = (now / T::LoyaltyPeriod::get()).saturating_sub(One::one());
+		let disloyal_shares: Vec<(PoolId, Balance)> = DisloyalShares::<T>::drain().collect();
+		let mut pool_ids: Vec<PoolId> = LoyaltyBonusBuckets::<T>::iter_keys().collect();
+		for pool_id in LoyaltyBonuses::<T>::iter_keys() {
+			if !pool_ids.contains(&pool_id) {
+				pool_ids.push(pool_id);
+			}
+		}
+
+		for pool_id in pool_ids.iter() {
+			let mut rewards = LoyaltyBonusBuckets::<T>::take(pool_id);
+			if let Some(bonus) = LoyaltyBonuses::<T>::take(pool_id) {
+				for (currency_id, amount) in bonus.rewards {
+					rewards
+						.entry(currency_id)
+						.and_modify(|current| *current = current.saturating_add(amount))
+						.or_insert(amount);
+				}
+			}
+			rewards.retain(|_, amount| !amount.is_zero());
+			if rewards.is_empty() {
+				continue;
+			}
+
+			let disloyal = disloyal_shares
+				.iter()
+				.find(|(id, _)| id == pool_id)
+				.map(|(_, shares)| *shares)
+				.unwrap_or_default();
+			let eligible_shares = <orml_rewards::Pallet<T>>::pool_infos(pool_id)
+				.total_shares
+				.saturating_sub(disloyal);
+
+			if eligible_shares.is_zero() {
+				// nobody is eligible, keep the bonus for the next period
+				LoyaltyBonusBuckets::<T>::insert(pool_id, rewards);
+			} else {
+				LoyaltyBonuses::<T>::insert(
+					pool_id,
+					LoyaltyBonus {
+						period: finished_period,
+						eligible_shares,
+						rewards,
+					},
+				);
+				Self::deposit_event(Event::LoyaltyBonusSettled(*pool_id, finished_period, eligible_shares));
+			}
+		}
+
+		pool_ids.len() as u32
+	}
+
+	// decay the reward multiplier of the pool if the decay periods have passed, and return it
+	fn decayed_reward_multiplier(pool_id: PoolId, now: T::BlockNumber) -> Rate {
+		RewardMultipliers::<T>::mutate(pool_id, |maybe_multiplier| match maybe_multiplier {
+			Some(reward_multiplier) => {
+				let decayed = reward_multiplier.decayed(now);
+				if decayed != *reward_multiplier {
+					*reward_multiplier = decayed;
+					Self::deposit_event(Event::RewardMultiplierUpdated(pool_id, decayed.multiplier));
+				}
+				decayed.multiplier
+			}
+			None => Rate::one(),
+		})
+	}
+
+	/// The reward amounts of multi currencies accumulated to `pool_id` every
+	/// `AccumulatePeriod` at `now`, including the DEX saving reward.
+	pub fn reward_amounts_per_period(pool_id: PoolId, now: T::BlockNumber) -> Vec<(CurrencyId, Balance)> {
+		let shutdown = T::EmergencyShutdown::is_shutdown();
+		let mut amounts: BTreeMap<CurrencyId, Balance> = BTreeMap::new();
+
+		// do not accumulate incentives for PoolId::Loans after shutdown
+		if !(shutdown && matches!(pool_id, PoolId::Loans(_))) {
+			let multiplier = Self::reward_multipliers(pool_id).map_or_else(Rate::one, |x| x.decayed(now).multiplier);
+			for (reward_currency_id, reward_amount) in IncentiveRewardAmounts::<T>::iter_prefix(pool_id) {
+				amounts.insert(reward_currency_id, multiplier.saturating_mul_int(reward_amount));
+			}
+		}
+
+		// do not accumulate dex saving any more after shutdown
+		match pool_id {
+			PoolId::Dex(lp_currency_id) if !shutdown => {
+				let dex_saving_reward_amount = Self::dex_saving_reward_amount(lp_currency_id, pool_id);
+				amounts
+					.entry(T::StableCurrencyId::get())
+					.and_modify(|current| *current = current.saturating_add(dex_saving_reward_amount))
+					.or_insert(dex_saving_reward_amount);
+			}
+			_ => {}
+		}
+
+		amounts.into_iter().filter(|(_, amount)| !amount.is_zero()).collect()
+	}
+
 	// accumulate incentive rewards of multi currencies
-	fn accumulate_incentives(pool_id: PoolId) {
+	fn accumulate_incentives(pool_id: PoolId, now: T::BlockNumber) {
+		let multiplier = Self::decayed_reward_multiplier(pool_id, now);
 		for (reward_currency_id, reward_amount) in IncentiveRewardAmounts::<T>::iter_prefix(pool_id) {
+			let reward_amount = multiplier.saturating_mul_int(reward_amount);
 			if reward_amount.is_zero() {
 				continue;
 			}
@@ -453,13 +973,13 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	// accumulate DEX saving reward(stable currency) for Dex Pool
-	fn accumulate_dex_saving(lp_currency_id: CurrencyId, pool_id: PoolId) {
+	// the DEX saving reward(stable currency) of the Dex Pool per period
+	fn dex_saving_reward_amount(lp_currency_id: CurrencyId, pool_id: PoolId) -> Balance {
 		let stable_currency_id = T::StableCurrencyId::get();
 		let dex_saving_reward_rate = Self::dex_saving_reward_rates(&pool_id);
 
-		if !dex_saving_reward_rate.is_zero() {
-			if let Some((currency_id_a, currency_id_b)) = lp_currency_id.split_dex_share_currency_id() {
+		match lp_currency_id.split_dex_share_currency_id() {
+			Some((currency_id_a, currency_id_b)) if !dex_saving_reward_rate.is_zero() => {
 				// accumulate saving reward only for liquidity pool of stable currency id
 				let dex_saving_reward_base = if currency_id_a == stable_currency_id {
 					T::DEX::get_liquidity_pool(stable_currency_id, currency_id_b).0
@@ -468,14 +988,23 @@ impl<T: Config> Pallet<T> {
 				} else {
 					Zero::zero()
 				};
-				let dex_saving_reward_amount = dex_saving_reward_rate.saturating_mul_int(dex_saving_reward_base);
-
-				// issue stable currency without backing.
-				if !dex_saving_reward_amount.is_zero() {
-					let res = T::CDPTreasury::issue_debit(&Self::account_id(), dex_saving_reward_amount, false);
-					match res {
-						Ok(_) => {
-							let _ = <orml_rewards::Pallet<T>>::accumulate_reward(
+				dex_saving_reward_rate.saturating_mul_int(dex_saving_reward_base)
+			}
+			_ => Zero::zero(),
+		}
+	}
+
+	// accumulate DEX saving reward(stable currency) for Dex Pool
+	fn accumulate_dex_saving(lp_currency_id: CurrencyId, pool_id: PoolId) {
+		let stable_currency_id = T::StableCurrencyId::get();
+		let dex_saving_reward_amount = Self::dex_saving_reward_amount(lp_currency_id, pool_id);
+
+		// issue stable currency without backing.
+		if !dex_saving_reward_amount.is_zero() {
+			let res = T::CDPTreasury::issue_debit(&Self::account_id(), dex_saving_reward_amount, false);
+			match res {
+				Ok(_) => {
+					let _ = <orml_rewards::Pallet<T>>::accumulate_reward(
 								&pool_id,
 								stable_currency_id,
 								dex_saving_reward_amount,
@@ -487,16 +1016,14 @@ impl<T: Config> Pallet<T> {
 									pool_id, stable_currency_id, dex_saving_reward_amount, e
 								);
 							});
-						}
-						Err(e) => {
-							log::warn!(
-								target: "incentives",
-								"issue_debit: failed to issue {:?} unbacked stable to {:?}: {:?}. \
-								This is unexpected but should be safe",
-								dex_saving_reward_amount, Self::account_id(), e
-							);
-						}
-					}
+				}
+				Err(e) => {
+					log::warn!(
+						target: "incentives",
+						"issue_debit: failed to issue {:?} unbacked stable to {:?}: {:?}. \
+						This is unexpected but should be safe",
+						dex_saving_reward_amount, Self::account_id(), e
+					);
 				}
 			}
 		}
@@ -509,6 +1036,7 @@ impl<T: Config> DEXIncentives<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 
 		T::Currency::transfer(lp_currency_id, who, &Self::account_id(), amount)?;
 		<orml_rewards::Pallet<T>>::add_share(who, &PoolId::Dex(lp_currency_id), amount.unique_saturated_into());
+		Self::mark_disloyal(who, PoolId::Dex(lp_currency_id), amount);
 
 		Self::deposit_event(Event::DepositDexShare(who.clone(), lp_currency_id, amount));
 		Ok(())
@@ -523,12 +1051,34 @@ impl<T: Config> DEXIncentives<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 
 		T::Currency::transfer(lp_currency_id, &Self::account_id(), who, amount)?;
 		<orml_rewards::Pallet<T>>::remove_share(who, &PoolId::Dex(lp_currency_id), amount.unique_saturated_into());
+		Self::on_share_removed(who, PoolId::Dex(lp_currency_id), amount);
 
 		Self::deposit_event(Event::WithdrawDexShare(who.clone(), lp_currency_id, amount));
 		Ok(())
 	}
 }
 
+impl<T: Config> IncentivesManager<T::AccountId, Balance, CurrencyId, PoolId> for Pallet<T> {
+	#[transactional]
+	fn deposit_dex_share(who: &T::AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+		Self::do_deposit_dex_share(who, lp_currency_id, amount)
+	}
+
+	#[transactional]
+	fn withdraw_dex_share(who: &T::AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+		Self::do_withdraw_dex_share(who, lp_currency_id, amount)
+	}
+
+	#[transactional]
+	fn claim_rewards(who: &T::AccountId, pool_id: PoolId) -> DispatchResult {
+		Self::do_claim_rewards(who, pool_id, false)
+	}
+
+	fn get_pending_rewards(pool_id: PoolId, who: &T::AccountId, reward_currency: CurrencyId) -> Balance {
+		Self::pending_rewards(&pool_id, who, reward_currency)
+	}
+}
+
 pub struct OnUpdateLoan<T>(sp_std::marker::PhantomData<T>);
 impl<T: Config> Happened<(T::AccountId, CurrencyId, Amount, Balance)> for OnUpdateLoan<T> {
 	fn happened(info: &(T::AccountId, CurrencyId, Amount, Balance)) {
@@ -543,6 +1093,11 @@ impl<T: Config> Happened<(T::AccountId, CurrencyId, Amount, Balance)> for OnUpda
 		};
 
 		<orml_rewards::Pallet<T>>::set_share(who, &PoolId::Loans(*currency_id), new_share_amount);
+		if adjustment.is_positive() {
+			Pallet::<T>::mark_disloyal(who, PoolId::Loans(*currency_id), adjustment_abs);
+		} else {
+			Pallet::<T>::on_share_removed(who, PoolId::Loans(*currency_id), adjustment_abs);
+		}
 	}
 }
 
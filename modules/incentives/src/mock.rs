@@ -149,6 +149,10 @@ impl CDPTreasury<AccountId> for MockCDPTreasury {
 		unimplemented!()
 	}
 
+	fn withdraw_surplus(_: &AccountId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
 	fn deposit_collateral(_: &AccountId, _: CurrencyId, _: Balance) -> DispatchResult {
 		unimplemented!()
 	}
@@ -233,6 +237,28 @@ pub fn mock_shutdown() {
 	IS_SHUTDOWN.with(|v| *v.borrow_mut() = true)
 }
 
+thread_local! {
+	pub static VESTED_TRANSFERS: RefCell<Vec<(AccountId, AccountId, BlockNumber, u32, Balance)>> = RefCell::new(vec![]);
+}
+
+pub struct MockVestedTransfer;
+impl VestedTransfer<AccountId, BlockNumber, Balance> for MockVestedTransfer {
+	fn vested_transfer(
+		from: &AccountId,
+		to: &AccountId,
+		period: BlockNumber,
+		period_count: u32,
+		per_period: Balance,
+	) -> DispatchResult {
+		<TokensModule as MultiCurrency<AccountId>>::transfer(ACA, from, to, per_period * Balance::from(period_count))?;
+		VESTED_TRANSFERS.with(|v| {
+			v.borrow_mut()
+				.push((from.clone(), to.clone(), period, period_count, per_period))
+		});
+		Ok(())
+	}
+}
+
 pub struct MockEmergencyShutdown;
 impl EmergencyShutdown for MockEmergencyShutdown {
 	fn is_shutdown() -> bool {
@@ -250,6 +276,8 @@ impl orml_rewards::Config for Runtime {
 
 parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = 10;
+	pub const LoyaltyPeriod: BlockNumber = 100;
+	pub const NativeCurrencyId: CurrencyId = ACA;
 	pub const StableCurrencyId: CurrencyId = AUSD;
 	pub const IncentivesPalletId: PalletId = PalletId(*b"aca/inct");
 }
@@ -262,12 +290,15 @@ impl Config for Runtime {
 	type Event = Event;
 	type RewardsSource = RewardsSource;
 	type AccumulatePeriod = AccumulatePeriod;
+	type LoyaltyPeriod = LoyaltyPeriod;
+	type NativeCurrencyId = NativeCurrencyId;
 	type StableCurrencyId = StableCurrencyId;
 	type UpdateOrigin = EnsureSignedBy<ROOT, AccountId>;
 	type CDPTreasury = MockCDPTreasury;
 	type Currency = TokensModule;
 	type DEX = MockDEX;
 	type EmergencyShutdown = MockEmergencyShutdown;
+	type VestedTransfer = MockVestedTransfer;
 	type PalletId = IncentivesPalletId;
 	type WeightInfo = ();
 }
@@ -410,6 +410,249 @@ fn update_claim_reward_deduction_rates_works() {
 	});
 }
 
+#[test]
+fn update_reward_multipliers_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			IncentivesModule::update_reward_multipliers(Origin::signed(ALICE::get()), vec![]),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::update_reward_multipliers(
+				Origin::signed(ROOT::get()),
+				vec![(PoolId::Dex(DOT), Rate::one(), Rate::zero(), 0)]
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_noop!(
+			IncentivesModule::update_reward_multipliers(
+				Origin::signed(ROOT::get()),
+				vec![(
+					PoolId::Loans(DOT),
+					Rate::one(),
+					Rate::saturating_from_rational(101, 100),
+					30
+				)]
+			),
+			Error::<Runtime>::InvalidRate
+		);
+
+		assert_eq!(IncentivesModule::reward_multipliers(PoolId::Loans(DOT)), None);
+		assert_ok!(IncentivesModule::update_reward_multipliers(
+			Origin::signed(ROOT::get()),
+			vec![(
+				PoolId::Loans(DOT),
+				Rate::saturating_from_rational(2, 1),
+				Rate::saturating_from_rational(1, 10),
+				30
+			)]
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::RewardMultiplierUpdated(
+			PoolId::Loans(DOT),
+			Rate::saturating_from_rational(2, 1),
+		)));
+		assert_eq!(
+			IncentivesModule::reward_multipliers(PoolId::Loans(DOT)),
+			Some(RewardMultiplier {
+				multiplier: Rate::saturating_from_rational(2, 1),
+				decay_rate: Rate::saturating_from_rational(1, 10),
+				decay_period: 30,
+				next_decay_at: 31,
+			})
+		);
+
+		// 1x without decay is the default
+		assert_ok!(IncentivesModule::update_reward_multipliers(
+			Origin::signed(ROOT::get()),
+			vec![(PoolId::Loans(DOT), Rate::one(), Rate::zero(), 0)]
+		));
+		assert_eq!(IncentivesModule::reward_multipliers(PoolId::Loans(DOT)), None);
+	});
+}
+
+#[test]
+fn update_vesting_payout_configs_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let config = VestingPayoutConfig {
+			upfront_rate: Rate::saturating_from_rational(30, 100),
+			period: 10,
+			period_count: 8,
+		};
+		assert_noop!(
+			IncentivesModule::update_vesting_payout_configs(Origin::signed(ALICE::get()), vec![]),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::update_vesting_payout_configs(
+				Origin::signed(ROOT::get()),
+				vec![(PoolId::Dex(DOT), Some(config))]
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_noop!(
+			IncentivesModule::update_vesting_payout_configs(
+				Origin::signed(ROOT::get()),
+				vec![(
+					PoolId::Loans(DOT),
+					Some(VestingPayoutConfig {
+						upfront_rate: Rate::saturating_from_rational(101, 100),
+						..config
+					})
+				)]
+			),
+			Error::<Runtime>::InvalidRate
+		);
+		assert_noop!(
+			IncentivesModule::update_vesting_payout_configs(
+				Origin::signed(ROOT::get()),
+				vec![(
+					PoolId::Loans(DOT),
+					Some(VestingPayoutConfig {
+						period_count: 0,
+						..config
+					})
+				)]
+			),
+			Error::<Runtime>::InvalidVestingPayoutConfig
+		);
+
+		assert_eq!(IncentivesModule::vesting_payout_configs(PoolId::Loans(DOT)), None);
+		assert_ok!(IncentivesModule::update_vesting_payout_configs(
+			Origin::signed(ROOT::get()),
+			vec![(PoolId::Loans(DOT), Some(config))]
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::VestingPayoutConfigUpdated(
+			PoolId::Loans(DOT),
+			Some(config),
+		)));
+		assert_eq!(
+			IncentivesModule::vesting_payout_configs(PoolId::Loans(DOT)),
+			Some(config)
+		);
+
+		assert_ok!(IncentivesModule::update_vesting_payout_configs(
+			Origin::signed(ROOT::get()),
+			vec![(PoolId::Loans(DOT), None)]
+		));
+		assert_eq!(IncentivesModule::vesting_payout_configs(PoolId::Loans(DOT)), None);
+	});
+}
+
+#[test]
+fn incentive_rewards_decay_with_reward_multiplier() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TokensModule::deposit(ACA, &RewardsSource::get(), 10000));
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(ROOT::get()),
+			vec![(PoolId::Loans(BTC), vec![(ACA, 1000)])],
+		));
+		// decay 10% every 20 blocks
+		assert_ok!(IncentivesModule::update_reward_multipliers(
+			Origin::signed(ROOT::get()),
+			vec![(
+				PoolId::Loans(BTC),
+				Rate::one(),
+				Rate::saturating_from_rational(1, 10),
+				20
+			)]
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::Loans(BTC), 1);
+
+		IncentivesModule::on_initialize(10);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 1000);
+
+		// decayed once at block 20
+		IncentivesModule::on_initialize(20);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 1000 + 900);
+		assert_eq!(
+			IncentivesModule::reward_multipliers(PoolId::Loans(BTC))
+				.unwrap()
+				.next_decay_at,
+			40
+		);
+
+		IncentivesModule::on_initialize(30);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 1900 + 900);
+
+		// decayed twice at block 60
+		IncentivesModule::on_initialize(60);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 2800 + 729);
+		assert_eq!(
+			IncentivesModule::reward_multipliers(PoolId::Loans(BTC)),
+			Some(RewardMultiplier {
+				multiplier: Rate::saturating_from_rational(729, 1000),
+				decay_rate: Rate::saturating_from_rational(1, 10),
+				decay_period: 20,
+				next_decay_at: 80,
+			})
+		);
+	});
+}
+
+#[test]
+fn reward_amounts_per_period_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(ROOT::get()),
+			vec![
+				(PoolId::Loans(BTC), vec![(ACA, 1000)]),
+				(PoolId::Dex(BTC_AUSD_LP), vec![(ACA, 100), (AUSD, 20)]),
+			],
+		));
+		assert_ok!(IncentivesModule::update_dex_saving_rewards(
+			Origin::signed(ROOT::get()),
+			vec![(PoolId::Dex(BTC_AUSD_LP), Rate::saturating_from_rational(1, 100))],
+		));
+		// decay 10% every 20 blocks
+		assert_ok!(IncentivesModule::update_reward_multipliers(
+			Origin::signed(ROOT::get()),
+			vec![(
+				PoolId::Loans(BTC),
+				Rate::one(),
+				Rate::saturating_from_rational(1, 10),
+				20
+			)]
+		));
+
+		assert_eq!(
+			IncentivesModule::reward_amounts_per_period(PoolId::Loans(BTC), 10),
+			vec![(ACA, 1000)]
+		);
+		// the decay is not written to storage
+		assert_eq!(
+			IncentivesModule::reward_amounts_per_period(PoolId::Loans(BTC), 20),
+			vec![(ACA, 900)]
+		);
+		assert_eq!(
+			IncentivesModule::reward_multipliers(PoolId::Loans(BTC))
+				.unwrap()
+				.next_decay_at,
+			20
+		);
+		// 5 AUSD is dex saving reward
+		assert_eq!(
+			IncentivesModule::reward_amounts_per_period(PoolId::Dex(BTC_AUSD_LP), 10),
+			vec![(ACA, 100), (AUSD, 25)]
+		);
+		assert_eq!(
+			IncentivesModule::reward_amounts_per_period(PoolId::Loans(DOT), 10),
+			vec![]
+		);
+
+		mock_shutdown();
+		assert_eq!(
+			IncentivesModule::reward_amounts_per_period(PoolId::Loans(BTC), 10),
+			vec![]
+		);
+		assert_eq!(
+			IncentivesModule::reward_amounts_per_period(PoolId::Dex(BTC_AUSD_LP), 10),
+			vec![(ACA, 100), (AUSD, 20)]
+		);
+	});
+}
+
 #[test]
 fn on_update_loan_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -513,6 +756,54 @@ fn payout_works() {
 	});
 }
 
+#[test]
+fn pending_rewards_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 10000));
+
+		RewardsModule::add_share(&ALICE::get(), &PoolId::Loans(BTC), 100);
+		assert_ok!(RewardsModule::accumulate_reward(&PoolId::Loans(BTC), ACA, 2000));
+		RewardsModule::add_share(&BOB::get(), &PoolId::Loans(BTC), 100);
+		assert_eq!(
+			IncentivesModule::pending_rewards(&PoolId::Loans(BTC), &ALICE::get(), ACA),
+			2000
+		);
+		assert_eq!(
+			IncentivesModule::pending_rewards(&PoolId::Loans(BTC), &BOB::get(), ACA),
+			0
+		);
+		assert_eq!(
+			IncentivesModule::pending_rewards(&PoolId::Loans(BTC), &ALICE::get(), LDOT),
+			0
+		);
+
+		assert_ok!(RewardsModule::accumulate_reward(&PoolId::Loans(BTC), ACA, 1000));
+		assert_eq!(
+			<IncentivesModule as IncentivesManager<_, _, _, _>>::get_pending_rewards(
+				PoolId::Loans(BTC),
+				&ALICE::get(),
+				ACA
+			),
+			2500
+		);
+		assert_eq!(
+			IncentivesModule::pending_rewards(&PoolId::Loans(BTC), &BOB::get(), ACA),
+			500
+		);
+
+		assert_ok!(<IncentivesModule as IncentivesManager<_, _, _, _>>::claim_rewards(
+			&ALICE::get(),
+			PoolId::Loans(BTC)
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 2500);
+		assert_eq!(
+			IncentivesModule::pending_rewards(&PoolId::Loans(BTC), &ALICE::get(), ACA),
+			0
+		);
+	});
+}
+
 #[test]
 fn claim_rewards_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -584,7 +875,7 @@ fn claim_rewards_works() {
 			RewardsModule::pool_infos(PoolId::Loans(BTC)),
 			PoolInfo {
 				total_shares: 200,
-				rewards: vec![(ACA, (5800, 4000)), (LDOT, (725, 250))].into_iter().collect(),
+				rewards: vec![(ACA, (4000, 4000)), (LDOT, (500, 250))].into_iter().collect(),
 			}
 		);
 		assert_eq!(
@@ -595,6 +886,10 @@ fn claim_rewards_works() {
 		assert_eq!(TokensModule::free_balance(LDOT, &VAULT::get()), 9975);
 		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 200);
 		assert_eq!(TokensModule::free_balance(LDOT, &ALICE::get()), 25);
+		assert_eq!(
+			IncentivesModule::loyalty_bonus_buckets(PoolId::Loans(BTC)),
+			vec![(ACA, 1800), (LDOT, 225)].into_iter().collect()
+		);
 
 		// bob claim rewards for PoolId::Loans(BTC)
 		assert_eq!(
@@ -606,35 +901,32 @@ fn claim_rewards_works() {
 			Origin::signed(BOB::get()),
 			PoolId::Loans(BTC)
 		));
-		System::assert_has_event(Event::IncentivesModule(crate::Event::ClaimRewards(
-			BOB::get(),
-			PoolId::Loans(BTC),
-			ACA,
-			90,
-			810,
-		)));
 		System::assert_has_event(Event::IncentivesModule(crate::Event::ClaimRewards(
 			BOB::get(),
 			PoolId::Loans(BTC),
 			LDOT,
-			37,
-			325,
+			25,
+			225,
 		)));
 		assert_eq!(
 			RewardsModule::pool_infos(PoolId::Loans(BTC)),
 			PoolInfo {
 				total_shares: 200,
-				rewards: vec![(ACA, (6610, 4900)), (LDOT, (1050, 612))].into_iter().collect(),
+				rewards: vec![(ACA, (4000, 4000)), (LDOT, (500, 500))].into_iter().collect(),
 			}
 		);
 		assert_eq!(
 			RewardsModule::shares_and_withdrawn_rewards(PoolId::Loans(BTC), BOB::get()),
-			(100, vec![(ACA, 2900), (LDOT, 362)].into_iter().collect())
+			(100, vec![(ACA, 2000), (LDOT, 250)].into_iter().collect())
+		);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 9800);
+		assert_eq!(TokensModule::free_balance(LDOT, &VAULT::get()), 9950);
+		assert_eq!(TokensModule::free_balance(ACA, &BOB::get()), 0);
+		assert_eq!(TokensModule::free_balance(LDOT, &BOB::get()), 25);
+		assert_eq!(
+			IncentivesModule::loyalty_bonus_buckets(PoolId::Loans(BTC)),
+			vec![(ACA, 1800), (LDOT, 450)].into_iter().collect()
 		);
-		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 9710);
-		assert_eq!(TokensModule::free_balance(LDOT, &VAULT::get()), 9938);
-		assert_eq!(TokensModule::free_balance(ACA, &BOB::get()), 90);
-		assert_eq!(TokensModule::free_balance(LDOT, &BOB::get()), 37);
 
 		// alice remove share for PoolId::Dex(BTC_AUSD_LP) before claim rewards,
 		// rewards will be settled and as pending rewards, will not be deducted.
@@ -649,7 +941,7 @@ fn claim_rewards_works() {
 			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
 			(100, Default::default())
 		);
-		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 9710);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 9800);
 		assert_eq!(TokensModule::free_balance(AUSD, &VAULT::get()), 10000);
 		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 200);
 		assert_eq!(TokensModule::free_balance(AUSD, &ALICE::get()), 0);
@@ -669,7 +961,7 @@ fn claim_rewards_works() {
 			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
 			(50, vec![(ACA, 250), (AUSD, 500)].into_iter().collect())
 		);
-		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 9710);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 9800);
 		assert_eq!(TokensModule::free_balance(AUSD, &VAULT::get()), 10000);
 		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 200);
 		assert_eq!(TokensModule::free_balance(AUSD, &ALICE::get()), 0);
@@ -701,14 +993,14 @@ fn claim_rewards_works() {
 			RewardsModule::pool_infos(PoolId::Dex(BTC_AUSD_LP)),
 			PoolInfo {
 				total_shares: 150,
-				rewards: vec![(ACA, (1000, 250)), (AUSD, (2000, 500))].into_iter().collect(),
+				rewards: vec![(ACA, (750, 250)), (AUSD, (1500, 500))].into_iter().collect(),
 			}
 		);
 		assert_eq!(
 			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
 			(50, vec![(ACA, 250), (AUSD, 500)].into_iter().collect())
 		);
-		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 9460);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 9550);
 		assert_eq!(TokensModule::free_balance(AUSD, &VAULT::get()), 9500);
 		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 450);
 		assert_eq!(TokensModule::free_balance(AUSD, &ALICE::get()), 500);
@@ -716,6 +1008,337 @@ fn claim_rewards_works() {
 			IncentivesModule::pending_multi_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
 			BTreeMap::default()
 		);
+		assert_eq!(
+			IncentivesModule::loyalty_bonus_buckets(PoolId::Dex(BTC_AUSD_LP)),
+			vec![(ACA, 250), (AUSD, 500)].into_iter().collect()
+		);
+	});
+}
+
+#[test]
+fn claim_rewards_vested_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 10000));
+		assert_ok!(TokensModule::deposit(LDOT, &VAULT::get(), 10000));
+		assert_ok!(IncentivesModule::update_claim_reward_deduction_rates(
+			Origin::signed(ROOT::get()),
+			vec![(PoolId::Loans(BTC), Rate::saturating_from_rational(50, 100))]
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::Loans(BTC), 100);
+		assert_ok!(RewardsModule::accumulate_reward(&PoolId::Loans(BTC), ACA, 1000));
+		assert_ok!(RewardsModule::accumulate_reward(&PoolId::Loans(BTC), LDOT, 100));
+
+		assert_noop!(
+			IncentivesModule::claim_rewards_vested(Origin::signed(ALICE::get()), PoolId::Loans(BTC)),
+			Error::<Runtime>::VestingPayoutNotEnabled
+		);
+
+		assert_ok!(IncentivesModule::update_vesting_payout_configs(
+			Origin::signed(ROOT::get()),
+			vec![(
+				PoolId::Loans(BTC),
+				Some(VestingPayoutConfig {
+					upfront_rate: Rate::saturating_from_rational(30, 100),
+					period: 10,
+					period_count: 8,
+				})
+			)]
+		));
+		assert_ok!(IncentivesModule::claim_rewards_vested(
+			Origin::signed(ALICE::get()),
+			PoolId::Loans(BTC)
+		));
+
+		// native rewards are not deducted, the remainder of periods is paid upfront
+		System::assert_has_event(Event::IncentivesModule(crate::Event::ClaimRewardsVested(
+			ALICE::get(),
+			PoolId::Loans(BTC),
+			ACA,
+			304,
+			696,
+		)));
+		assert_eq!(
+			VESTED_TRANSFERS.with(|v| v.borrow().clone()),
+			vec![(VAULT::get(), ALICE::get(), 10, 8, 87)]
+		);
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 1000);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 9000);
+
+		// other rewards are deducted as usual
+		System::assert_has_event(Event::IncentivesModule(crate::Event::ClaimRewards(
+			ALICE::get(),
+			PoolId::Loans(BTC),
+			LDOT,
+			50,
+			50,
+		)));
+		assert_eq!(TokensModule::free_balance(LDOT, &ALICE::get()), 50);
+		assert_eq!(
+			IncentivesModule::loyalty_bonus_buckets(PoolId::Loans(BTC)),
+			vec![(LDOT, 50)].into_iter().collect()
+		);
+	});
+}
+
+#[test]
+fn loyalty_bonus_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 10000));
+		assert_ok!(IncentivesModule::update_claim_reward_deduction_rates(
+			Origin::signed(ROOT::get()),
+			vec![(PoolId::Loans(BTC), Rate::saturating_from_rational(50, 100))]
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::Loans(BTC), 100);
+		RewardsModule::add_share(&BOB::get(), &PoolId::Loans(BTC), 100);
+		assert_ok!(RewardsModule::accumulate_reward(&PoolId::Loans(BTC), ACA, 1000));
+
+		// alice claims within the period and forfeits half of the rewards
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			PoolId::Loans(BTC)
+		));
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 250);
+		assert_eq!(
+			IncentivesModule::loyalty_bonus_buckets(PoolId::Loans(BTC)),
+			vec![(ACA, 250)].into_iter().collect()
+		);
+		assert_eq!(IncentivesModule::disloyal_shares(PoolId::Loans(BTC)), 100);
+		assert_eq!(
+			IncentivesModule::last_disloyal_periods(PoolId::Loans(BTC), ALICE::get()),
+			Some(0)
+		);
+
+		// the first period finished, only bob is eligible
+		IncentivesModule::on_initialize(100);
+		System::assert_has_event(Event::IncentivesModule(crate::Event::LoyaltyBonusSettled(
+			PoolId::Loans(BTC),
+			0,
+			100,
+		)));
+		assert_eq!(
+			IncentivesModule::loyalty_bonuses(PoolId::Loans(BTC)),
+			Some(LoyaltyBonus {
+				period: 0,
+				eligible_shares: 100,
+				rewards: vec![(ACA, 250)].into_iter().collect(),
+			})
+		);
+		assert_eq!(
+			IncentivesModule::loyalty_bonus_buckets(PoolId::Loans(BTC)),
+			BTreeMap::default()
+		);
+		assert_eq!(IncentivesModule::disloyal_shares(PoolId::Loans(BTC)), 0);
+		assert_eq!(
+			IncentivesModule::pending_loyalty_bonus(&PoolId::Loans(BTC), &ALICE::get()),
+			vec![]
+		);
+		assert_eq!(
+			IncentivesModule::pending_loyalty_bonus(&PoolId::Loans(BTC), &BOB::get()),
+			vec![(ACA, 250)]
+		);
+
+		// bob claims the loyalty bonus together with the rewards
+		System::set_block_number(101);
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(BOB::get()),
+			PoolId::Loans(BTC)
+		));
+		System::assert_has_event(Event::IncentivesModule(crate::Event::ClaimLoyaltyBonus(
+			BOB::get(),
+			PoolId::Loans(BTC),
+			ACA,
+			250,
+		)));
+		assert_eq!(TokensModule::free_balance(ACA, &BOB::get()), 500);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 9250);
+		assert_eq!(
+			IncentivesModule::pending_loyalty_bonus(&PoolId::Loans(BTC), &BOB::get()),
+			vec![]
+		);
+		assert_eq!(
+			IncentivesModule::loyalty_bonus_buckets(PoolId::Loans(BTC)),
+			vec![(ACA, 250)].into_iter().collect()
+		);
+
+		// the second period finished, only alice is eligible
+		IncentivesModule::on_initialize(200);
+		assert_eq!(
+			IncentivesModule::loyalty_bonuses(PoolId::Loans(BTC)),
+			Some(LoyaltyBonus {
+				period: 1,
+				eligible_shares: 100,
+				rewards: vec![(ACA, 250)].into_iter().collect(),
+			})
+		);
+		assert_eq!(
+			IncentivesModule::pending_loyalty_bonus(&PoolId::Loans(BTC), &ALICE::get()),
+			vec![(ACA, 250)]
+		);
+		assert_eq!(
+			IncentivesModule::pending_loyalty_bonus(&PoolId::Loans(BTC), &BOB::get()),
+			vec![]
+		);
+	});
+}
+
+#[test]
+fn increasing_shares_forfeits_loyalty_bonus() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 10000));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &BOB::get(), 10000));
+
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			100
+		));
+		assert_eq!(IncentivesModule::disloyal_shares(PoolId::Dex(BTC_AUSD_LP)), 100);
+
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			100
+		));
+		assert_eq!(IncentivesModule::disloyal_shares(PoolId::Dex(BTC_AUSD_LP)), 200);
+
+		assert_ok!(IncentivesModule::withdraw_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			50
+		));
+		assert_eq!(IncentivesModule::disloyal_shares(PoolId::Dex(BTC_AUSD_LP)), 150);
+
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(BOB::get()),
+			BTC_AUSD_LP,
+			100
+		));
+		assert_eq!(IncentivesModule::disloyal_shares(PoolId::Dex(BTC_AUSD_LP)), 250);
+		assert_eq!(
+			IncentivesModule::last_disloyal_periods(PoolId::Dex(BTC_AUSD_LP), BOB::get()),
+			Some(0)
+		);
+	});
+}
+
+#[test]
+fn loyalty_bonus_uses_shares_at_the_end_of_the_period() {
+	ExtBuilder::default().build().execute_with(|| {
+		let charlie = AccountId::from([4u8; 32]);
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 10000));
+		assert_ok!(IncentivesModule::update_claim_reward_deduction_rates(
+			Origin::signed(ROOT::get()),
+			vec![(PoolId::Loans(BTC), Rate::saturating_from_rational(50, 100))]
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::Loans(BTC), 100);
+		RewardsModule::add_share(&BOB::get(), &PoolId::Loans(BTC), 100);
+		RewardsModule::add_share(&charlie, &PoolId::Loans(BTC), 200);
+		assert_ok!(RewardsModule::accumulate_reward(&PoolId::Loans(BTC), ACA, 2000));
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			PoolId::Loans(BTC)
+		));
+
+		// bob and charlie stayed loyal within the first period
+		IncentivesModule::on_initialize(100);
+		assert_eq!(
+			IncentivesModule::loyalty_bonuses(PoolId::Loans(BTC)),
+			Some(LoyaltyBonus {
+				period: 0,
+				eligible_shares: 300,
+				rewards: vec![(ACA, 250)].into_iter().collect(),
+			})
+		);
+
+		// topping up before claiming doesn't increase the bonus
+		System::set_block_number(101);
+		OnUpdateLoan::<Runtime>::happened(&(BOB::get(), BTC, 300, 100));
+		assert_eq!(
+			IncentivesModule::pending_loyalty_bonus(&PoolId::Loans(BTC), &BOB::get()),
+			vec![(ACA, 83)]
+		);
+		OnUpdateLoan::<Runtime>::happened(&(BOB::get(), BTC, 100, 400));
+		assert_eq!(
+			IncentivesModule::pending_loyalty_bonus(&PoolId::Loans(BTC), &BOB::get()),
+			vec![(ACA, 83)]
+		);
+
+		// removing shares doesn't decrease the bonus
+		OnUpdateLoan::<Runtime>::happened(&(charlie.clone(), BTC, -100, 200));
+		assert_eq!(
+			IncentivesModule::pending_loyalty_bonus(&PoolId::Loans(BTC), &charlie),
+			vec![(ACA, 166)]
+		);
+		assert_eq!(
+			IncentivesModule::pending_loyalty_bonus(&PoolId::Loans(BTC), &ALICE::get()),
+			vec![]
+		);
+	});
+}
+
+#[test]
+fn disloyal_in_current_period_keeps_loyalty_bonus_of_finished_period() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(ACA, &VAULT::get(), 10000));
+		assert_ok!(IncentivesModule::update_claim_reward_deduction_rates(
+			Origin::signed(ROOT::get()),
+			vec![(PoolId::Loans(BTC), Rate::saturating_from_rational(50, 100))]
+		));
+		RewardsModule::add_share(&ALICE::get(), &PoolId::Loans(BTC), 100);
+		RewardsModule::add_share(&BOB::get(), &PoolId::Loans(BTC), 100);
+		assert_ok!(RewardsModule::accumulate_reward(&PoolId::Loans(BTC), ACA, 1000));
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(ALICE::get()),
+			PoolId::Loans(BTC)
+		));
+		IncentivesModule::on_initialize(100);
+
+		// bob increases shares in the second period, and only forfeits the bonus of it
+		System::set_block_number(101);
+		OnUpdateLoan::<Runtime>::happened(&(BOB::get(), BTC, 100, 100));
+		assert_eq!(
+			IncentivesModule::last_disloyal_periods(PoolId::Loans(BTC), BOB::get()),
+			Some(1)
+		);
+		assert_eq!(
+			IncentivesModule::loyalty_share_snapshots(PoolId::Loans(BTC), BOB::get()),
+			Some((1, 100))
+		);
+		assert_eq!(
+			IncentivesModule::pending_loyalty_bonus(&PoolId::Loans(BTC), &BOB::get()),
+			vec![(ACA, 250)]
+		);
+
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(BOB::get()),
+			PoolId::Loans(BTC)
+		));
+		System::assert_has_event(Event::IncentivesModule(crate::Event::ClaimLoyaltyBonus(
+			BOB::get(),
+			PoolId::Loans(BTC),
+			ACA,
+			250,
+		)));
+		assert_eq!(
+			IncentivesModule::pending_loyalty_bonus(&PoolId::Loans(BTC), &BOB::get()),
+			vec![]
+		);
+
+		// alice stayed loyal within the second period
+		IncentivesModule::on_initialize(200);
+		assert_eq!(
+			IncentivesModule::pending_loyalty_bonus(&PoolId::Loans(BTC), &ALICE::get()),
+			vec![(ACA, 250)]
+		);
+		assert_eq!(
+			IncentivesModule::pending_loyalty_bonus(&PoolId::Loans(BTC), &BOB::get()),
+			vec![]
+		);
 	});
 }
 
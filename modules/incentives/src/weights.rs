@@ -52,9 +52,12 @@ pub trait WeightInfo {
 	fn deposit_dex_share() -> Weight;
 	fn withdraw_dex_share() -> Weight;
 	fn claim_rewards() -> Weight;
+	fn claim_rewards_vested() -> Weight;
 	fn update_incentive_rewards(c: u32, ) -> Weight;
 	fn update_dex_saving_rewards(c: u32, ) -> Weight;
 	fn update_claim_reward_deduction_rates(c: u32, ) -> Weight;
+	fn update_reward_multipliers(c: u32, ) -> Weight;
+	fn update_vesting_payout_configs(c: u32, ) -> Weight;
 }
 
 /// Weights for module_incentives using the Acala node and recommended hardware.
@@ -81,6 +84,11 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	fn claim_rewards_vested() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
 	fn update_incentive_rewards(c: u32, ) -> Weight {
 		(479_000 as Weight)
 			// Standard Error: 29_000
@@ -99,6 +107,18 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn update_reward_multipliers(c: u32, ) -> Weight {
+		(1_102_000 as Weight)
+			// Standard Error: 22_000
+			.saturating_add((2_046_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_vesting_payout_configs(c: u32, ) -> Weight {
+		(914_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -124,6 +144,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	fn claim_rewards_vested() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
 	fn update_incentive_rewards(c: u32, ) -> Weight {
 		(479_000 as Weight)
 			// Standard Error: 29_000
@@ -142,4 +167,16 @@ impl WeightInfo for () {
 			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn update_reward_multipliers(c: u32, ) -> Weight {
+		(1_102_000 as Weight)
+			// Standard Error: 22_000
+			.saturating_add((2_046_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_vesting_payout_configs(c: u32, ) -> Weight {
+		(914_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 }
@@ -0,0 +1,220 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Liquid Crowdloan Module
+//!
+//! ## Overview
+//!
+//! Liquid crowdloan module tracks the DOT contributed to the relaychain
+//! crowdloan on behalf of users, and issues the liquid crowdloan token
+//! (lcDOT) to the contributors 1:1. When the parachain lease ends, the
+//! contributed DOT is refunded to the module account, and the governance
+//! settles the crowdloan. After the settlement, lcDOT can be redeemed for
+//! DOT pro rata, or converted to LDOT directly by minting through Homa.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional, PalletId};
+use frame_system::pallet_prelude::*;
+use orml_traits::MultiCurrency;
+use primitives::{Balance, CurrencyId, EraIndex};
+use sp_runtime::{
+	traits::{AccountIdConversion, Zero},
+	FixedPointNumber,
+};
+use sp_std::prelude::*;
+use support::{HomaProtocol, Ratio};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency for transfer, issue and burn DOT and lcDOT
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The relaychain currency contributed to the crowdloan (DOT)
+		#[pallet::constant]
+		type RelayChainCurrencyId: Get<CurrencyId>;
+
+		/// The liquid crowdloan currency (lcDOT)
+		#[pallet::constant]
+		type LiquidCrowdloanCurrencyId: Get<CurrencyId>;
+
+		/// Homa protocol to convert the redeemed DOT to LDOT
+		type Homa: HomaProtocol<Self::AccountId, Balance, EraIndex>;
+
+		/// The origin which may issue lcDOT and settle the crowdloan.
+		type GovernanceOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The module id, keep the refunded DOT.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The crowdloan has been settled
+		AlreadySettled,
+		/// The crowdloan has not been settled yet
+		NotSettled,
+		/// The redeemed DOT amount is zero
+		ZeroRedeemAmount,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	pub enum Event<T: Config> {
+		/// Issued lcDOT for the crowdloan contribution. \[contributor, amount\]
+		Issued(T::AccountId, Balance),
+		/// The crowdloan has been settled. \[refunded_dot, lcdot_issuance\]
+		Settled(Balance, Balance),
+		/// Redeemed lcDOT for DOT. \[who, lcdot_amount, dot_amount\]
+		Redeemed(T::AccountId, Balance, Balance),
+		/// Redeemed lcDOT and minted LDOT with the DOT. \[who, lcdot_amount,
+		/// ldot_amount\]
+		RedeemedToLiquid(T::AccountId, Balance, Balance),
+	}
+
+	/// The total DOT contributed to the crowdloan
+	///
+	/// TotalContributed: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_contributed)]
+	pub type TotalContributed<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// Whether the crowdloan has been settled after the lease ends
+	///
+	/// IsSettled: bool
+	#[pallet::storage]
+	#[pallet::getter(fn is_settled)]
+	pub type IsSettled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Issue lcDOT 1:1 for the DOT contributed to the crowdloan.
+		///
+		/// The dispatch origin of this call must be `GovernanceOrigin`.
+		///
+		/// - `contributions`: the contributors and their contributed DOT amount.
+		#[pallet::weight(T::WeightInfo::issue(contributions.len() as u32))]
+		#[transactional]
+		pub fn issue(origin: OriginFor<T>, contributions: Vec<(T::AccountId, Balance)>) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_settled(), Error::<T>::AlreadySettled);
+
+			for (who, amount) in contributions {
+				T::Currency::deposit(T::LiquidCrowdloanCurrencyId::get(), &who, amount)?;
+				TotalContributed::<T>::mutate(|total| *total = total.saturating_add(amount));
+				Self::deposit_event(Event::Issued(who, amount));
+			}
+			Ok(())
+		}
+
+		/// Settle the crowdloan when the lease ends, after the contributed DOT
+		/// is refunded to the module account. lcDOT can be redeemed
+		/// afterwards.
+		///
+		/// The dispatch origin of this call must be `GovernanceOrigin`.
+		#[pallet::weight(T::WeightInfo::settle())]
+		#[transactional]
+		pub fn settle(origin: OriginFor<T>) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_settled(), Error::<T>::AlreadySettled);
+
+			IsSettled::<T>::put(true);
+			Self::deposit_event(Event::Settled(
+				T::Currency::free_balance(T::RelayChainCurrencyId::get(), &Self::account_id()),
+				T::Currency::total_issuance(T::LiquidCrowdloanCurrencyId::get()),
+			));
+			Ok(())
+		}
+
+		/// Burn lcDOT and redeem DOT pro rata from the refunded DOT.
+		///
+		/// - `amount`: the lcDOT amount to redeem.
+		#[pallet::weight(T::WeightInfo::redeem())]
+		#[transactional]
+		pub fn redeem(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let dot_amount = Self::do_redeem(&who, amount)?;
+			Self::deposit_event(Event::Redeemed(who, amount, dot_amount));
+			Ok(())
+		}
+
+		/// Burn lcDOT, redeem DOT and mint LDOT with it through Homa.
+		///
+		/// - `amount`: the lcDOT amount to redeem.
+		#[pallet::weight(T::WeightInfo::redeem_to_liquid())]
+		#[transactional]
+		pub fn redeem_to_liquid(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let dot_amount = Self::do_redeem(&who, amount)?;
+			let liquid_amount = T::Homa::mint(&who, dot_amount)?;
+			Self::deposit_event(Event::RedeemedToLiquid(who, amount, liquid_amount));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account()
+	}
+
+	/// The DOT amount to be redeemed by `amount` lcDOT, shared pro rata of
+	/// the DOT in the module account.
+	pub fn redeem_amount(amount: Balance) -> Balance {
+		let dot_balance = T::Currency::free_balance(T::RelayChainCurrencyId::get(), &Self::account_id());
+		let lcdot_issuance = T::Currency::total_issuance(T::LiquidCrowdloanCurrencyId::get());
+		Ratio::checked_from_rational(dot_balance, lcdot_issuance)
+			.unwrap_or_default()
+			.saturating_mul_int(amount)
+	}
+
+	fn do_redeem(who: &T::AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+		ensure!(Self::is_settled(), Error::<T>::NotSettled);
+		let dot_amount = Self::redeem_amount(amount);
+		ensure!(!dot_amount.is_zero(), Error::<T>::ZeroRedeemAmount);
+
+		T::Currency::withdraw(T::LiquidCrowdloanCurrencyId::get(), who, amount)?;
+		T::Currency::transfer(T::RelayChainCurrencyId::get(), &Self::account_id(), who, dot_amount)?;
+		Ok(dot_amount)
+	}
+}
@@ -0,0 +1,198 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the liquid crowdloan module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{construct_runtime, ord_parameter_types, parameter_types};
+use frame_system::EnsureSignedBy;
+use orml_traits::parameter_type_with_key;
+use primitives::{Amount, TokenSymbol};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup, DispatchResult};
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+pub const LDOT: CurrencyId = CurrencyId::Token(TokenSymbol::LDOT);
+pub const LCDOT: CurrencyId = CurrencyId::Token(TokenSymbol::LCDOT);
+
+mod liquid_crowdloan {
+	pub use super::super::*;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = ();
+	type DustRemovalWhitelist = ();
+}
+
+/// Mints LDOT 1:10 with the DOT of `who`.
+pub struct MockHoma;
+impl HomaProtocol<AccountId, Balance, EraIndex> for MockHoma {
+	type Balance = Balance;
+	type ReceiptId = u32;
+
+	fn mint(who: &AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+		let liquid_amount = amount * 10;
+		Tokens::withdraw(DOT, who, amount)?;
+		Tokens::deposit(LDOT, who, liquid_amount)?;
+		Ok(liquid_amount)
+	}
+
+	fn redeem_by_unbond(_who: &AccountId, _amount: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn redeem_by_free_unbonded(_who: &AccountId, _amount: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn redeem_by_claim_unbonding(_who: &AccountId, _amount: Balance, _target_era: EraIndex) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn withdraw_redemption(_who: &AccountId) -> Result<Balance, DispatchError> {
+		unimplemented!()
+	}
+
+	fn issue_unbonding_receipt(_who: &AccountId, _era: EraIndex) -> Result<Self::ReceiptId, DispatchError> {
+		unimplemented!()
+	}
+
+	fn withdraw_unbonding_receipt(_who: &AccountId, _receipt_id: Self::ReceiptId) -> Result<Balance, DispatchError> {
+		unimplemented!()
+	}
+}
+
+ord_parameter_types! {
+	pub const One: AccountId = 1;
+}
+
+parameter_types! {
+	pub const RelayChainCurrencyId: CurrencyId = DOT;
+	pub const LiquidCrowdloanCurrencyId: CurrencyId = LCDOT;
+	pub const LiquidCrowdloanPalletId: PalletId = PalletId(*b"aca/lcrd");
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type Currency = Tokens;
+	type RelayChainCurrencyId = RelayChainCurrencyId;
+	type LiquidCrowdloanCurrencyId = LiquidCrowdloanCurrencyId;
+	type Homa = MockHoma;
+	type GovernanceOrigin = EnsureSignedBy<One, AccountId>;
+	type PalletId = LiquidCrowdloanPalletId;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+		LiquidCrowdloanModule: liquid_crowdloan::{Pallet, Storage, Call, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+	}
+);
+
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, CurrencyId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self { balances: vec![] }
+	}
+}
+
+impl ExtBuilder {
+	pub fn balances(mut self, balances: Vec<(AccountId, CurrencyId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,155 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the liquid crowdloan module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn issue_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LiquidCrowdloanModule::issue(Origin::signed(BOB), vec![(BOB, 100)]),
+			BadOrigin
+		);
+		assert_ok!(LiquidCrowdloanModule::issue(
+			Origin::signed(ALICE),
+			vec![(BOB, 100), (CHARLIE, 300)]
+		));
+		System::assert_has_event(Event::LiquidCrowdloanModule(crate::Event::Issued(BOB, 100)));
+		System::assert_last_event(Event::LiquidCrowdloanModule(crate::Event::Issued(CHARLIE, 300)));
+		assert_eq!(Tokens::free_balance(LCDOT, &BOB), 100);
+		assert_eq!(Tokens::free_balance(LCDOT, &CHARLIE), 300);
+		assert_eq!(LiquidCrowdloanModule::total_contributed(), 400);
+
+		assert_ok!(LiquidCrowdloanModule::settle(Origin::signed(ALICE)));
+		assert_noop!(
+			LiquidCrowdloanModule::issue(Origin::signed(ALICE), vec![(BOB, 100)]),
+			Error::<Runtime>::AlreadySettled
+		);
+	});
+}
+
+#[test]
+fn settle_works() {
+	ExtBuilder::default()
+		.balances(vec![(LiquidCrowdloanModule::account_id(), DOT, 400)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(LiquidCrowdloanModule::issue(
+				Origin::signed(ALICE),
+				vec![(BOB, 100), (CHARLIE, 300)]
+			));
+			assert_noop!(LiquidCrowdloanModule::settle(Origin::signed(BOB)), BadOrigin);
+			assert_ok!(LiquidCrowdloanModule::settle(Origin::signed(ALICE)));
+			System::assert_last_event(Event::LiquidCrowdloanModule(crate::Event::Settled(400, 400)));
+			assert!(LiquidCrowdloanModule::is_settled());
+			assert_noop!(
+				LiquidCrowdloanModule::settle(Origin::signed(ALICE)),
+				Error::<Runtime>::AlreadySettled
+			);
+		});
+}
+
+#[test]
+fn redeem_works() {
+	ExtBuilder::default()
+		.balances(vec![(LiquidCrowdloanModule::account_id(), DOT, 400)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(LiquidCrowdloanModule::issue(
+				Origin::signed(ALICE),
+				vec![(BOB, 100), (CHARLIE, 300)]
+			));
+			assert_noop!(
+				LiquidCrowdloanModule::redeem(Origin::signed(BOB), 100),
+				Error::<Runtime>::NotSettled
+			);
+
+			assert_ok!(LiquidCrowdloanModule::settle(Origin::signed(ALICE)));
+			assert_noop!(
+				LiquidCrowdloanModule::redeem(Origin::signed(BOB), 0),
+				Error::<Runtime>::ZeroRedeemAmount
+			);
+			assert_noop!(
+				LiquidCrowdloanModule::redeem(Origin::signed(BOB), 101),
+				orml_tokens::Error::<Runtime>::BalanceTooLow
+			);
+
+			assert_ok!(LiquidCrowdloanModule::redeem(Origin::signed(BOB), 100));
+			System::assert_last_event(Event::LiquidCrowdloanModule(crate::Event::Redeemed(BOB, 100, 100)));
+			assert_eq!(Tokens::free_balance(LCDOT, &BOB), 0);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 100);
+			assert_eq!(Tokens::free_balance(DOT, &LiquidCrowdloanModule::account_id()), 300);
+			assert_eq!(Tokens::total_issuance(LCDOT), 300);
+		});
+}
+
+#[test]
+fn redeem_is_pro_rata_of_refunded_dot() {
+	ExtBuilder::default()
+		.balances(vec![(LiquidCrowdloanModule::account_id(), DOT, 200)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(LiquidCrowdloanModule::issue(
+				Origin::signed(ALICE),
+				vec![(BOB, 100), (CHARLIE, 300)]
+			));
+			assert_ok!(LiquidCrowdloanModule::settle(Origin::signed(ALICE)));
+
+			// only half of the contributed DOT is refunded
+			assert_eq!(LiquidCrowdloanModule::redeem_amount(100), 50);
+			assert_ok!(LiquidCrowdloanModule::redeem(Origin::signed(BOB), 100));
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 50);
+			assert_ok!(LiquidCrowdloanModule::redeem(Origin::signed(CHARLIE), 300));
+			assert_eq!(Tokens::free_balance(DOT, &CHARLIE), 150);
+			assert_eq!(Tokens::free_balance(DOT, &LiquidCrowdloanModule::account_id()), 0);
+		});
+}
+
+#[test]
+fn redeem_to_liquid_works() {
+	ExtBuilder::default()
+		.balances(vec![(LiquidCrowdloanModule::account_id(), DOT, 400)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(LiquidCrowdloanModule::issue(
+				Origin::signed(ALICE),
+				vec![(BOB, 100), (CHARLIE, 300)]
+			));
+			assert_noop!(
+				LiquidCrowdloanModule::redeem_to_liquid(Origin::signed(BOB), 100),
+				Error::<Runtime>::NotSettled
+			);
+			assert_ok!(LiquidCrowdloanModule::settle(Origin::signed(ALICE)));
+
+			assert_ok!(LiquidCrowdloanModule::redeem_to_liquid(Origin::signed(BOB), 100));
+			System::assert_last_event(Event::LiquidCrowdloanModule(crate::Event::RedeemedToLiquid(
+				BOB, 100, 1000,
+			)));
+			assert_eq!(Tokens::free_balance(LCDOT, &BOB), 0);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 0);
+			assert_eq!(Tokens::free_balance(LDOT, &BOB), 1000);
+			assert_eq!(Tokens::free_balance(DOT, &LiquidCrowdloanModule::account_id()), 300);
+		});
+}
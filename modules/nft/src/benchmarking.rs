@@ -25,14 +25,16 @@ use sp_std::vec;
 use frame_benchmarking::{account, benchmarks};
 use frame_support::{dispatch::DispatchErrorWithPostInfo, traits::Get, weights::DispatchClass};
 use frame_system::RawOrigin;
+use orml_traits::MultiCurrency;
 use sp_runtime::traits::{AccountIdConversion, StaticLookup, UniqueSaturatedInto};
 
 pub use crate::*;
-use primitives::Balance;
+use primitives::{Balance, CurrencyId, TokenSymbol};
 
 pub struct Module<T: Config>(crate::Pallet<T>);
 
 const SEED: u32 = 0;
+const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 
 fn dollar(d: u32) -> Balance {
 	let d: Balance = d.into();
@@ -128,6 +130,22 @@ benchmarks! {
 		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
 	}: _(RawOrigin::Signed(to), (0u32.into(), 0u32.into()), remark_message)
 
+	// revoke NFT token
+	revoke {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to);
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::update_class_properties(
+			RawOrigin::Signed(module_account.clone()).into(),
+			0u32.into(),
+			Properties(ClassProperty::Mintable | ClassProperty::Revocable),
+		)?;
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account.clone()).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+	}: _(RawOrigin::Signed(module_account), (0u32.into(), 0u32.into()))
+
 	// destroy NFT class
 	destroy_class {
 		let caller: T::AccountId = account("caller", 0, SEED);
@@ -146,6 +164,77 @@ benchmarks! {
 
 		let module_account = create_token_class::<T>(caller)?;
 	}: _(RawOrigin::Signed(module_account), 0u32.into(), Properties(ClassProperty::Transferable.into()))
+
+	list_for_sale {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+	}: _(RawOrigin::Signed(to), (0u32.into(), 0u32.into()), AUSD, dollar(10).unique_saturated_into())
+
+	cancel_listing {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+		crate::Pallet::<T>::list_for_sale(RawOrigin::Signed(to.clone()).into(), (0u32.into(), 0u32.into()), AUSD, dollar(10).unique_saturated_into())?;
+	}: _(RawOrigin::Signed(to), (0u32.into(), 0u32.into()))
+
+	buy {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account.clone()).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+		crate::Pallet::<T>::set_royalty(RawOrigin::Signed(module_account).into(), 0u32.into(), Permill::from_percent(5))?;
+		crate::Pallet::<T>::list_for_sale(RawOrigin::Signed(to).into(), (0u32.into(), 0u32.into()), AUSD, dollar(10).unique_saturated_into())?;
+		T::MultiCurrency::deposit(AUSD, &buyer, dollar(100).unique_saturated_into())?;
+	}: _(RawOrigin::Signed(buyer), (0u32.into(), 0u32.into()))
+
+	set_royalty {
+		let caller: T::AccountId = account("caller", 0, SEED);
+
+		let module_account = create_token_class::<T>(caller)?;
+	}: _(RawOrigin::Signed(module_account), 0u32.into(), Permill::from_percent(5))
+
+	// mint NFT tokens to multiple accounts
+	mint_batch {
+		let i in 1 .. T::MaxBatchItems::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let items = (0..i).map(|n| {
+			let to: T::AccountId = account("to", n, SEED);
+			(T::Lookup::unlookup(to), vec![1], test_attr())
+		}).collect::<Vec<_>>();
+
+		let module_account = create_token_class::<T>(caller)?;
+	}: _(RawOrigin::Signed(module_account), 0u32.into(), items)
+
+	// transfer NFT tokens to multiple accounts
+	transfer_batch {
+		let i in 1 .. T::MaxBatchItems::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let from: T::AccountId = account("from", 0, SEED);
+		let from_lookup = T::Lookup::unlookup(from.clone());
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), from_lookup, 0u32.into(), vec![1], test_attr(), i)?;
+		let items = (0..i).map(|n| {
+			let to: T::AccountId = account("to", n, SEED);
+			(T::Lookup::unlookup(to), (0u32.into(), n.into()))
+		}).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(from), items)
 }
 
 #[cfg(test)]
@@ -160,6 +249,8 @@ mod mock {
 		weights::Weight,
 		PalletId, RuntimeDebug,
 	};
+	use orml_traits::parameter_type_with_key;
+	use primitives::Amount;
 	use sp_core::{crypto::AccountId32, H256};
 	use sp_runtime::{
 		testing::Header,
@@ -278,12 +369,31 @@ mod mock {
 		type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	}
 
+	parameter_type_with_key! {
+		pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+			Default::default()
+		};
+	}
+
+	impl orml_tokens::Config for Runtime {
+		type Event = ();
+		type Balance = Balance;
+		type Amount = Amount;
+		type CurrencyId = CurrencyId;
+		type WeightInfo = ();
+		type ExistentialDeposits = ExistentialDeposits;
+		type OnDust = ();
+		type MaxLocks = ();
+		type DustRemovalWhitelist = ();
+	}
+
 	parameter_types! {
 		pub const CreateClassDeposit: Balance = 200;
 		pub const CreateTokenDeposit: Balance = 100;
 		pub const DataDepositPerByte: Balance = 10;
 		pub const NftPalletId: PalletId = PalletId(*b"aca/aNFT");
 		pub MaxAttributesBytes: u32 = 2048;
+		pub const MaxBatchItems: u32 = 100;
 	}
 
 	impl crate::Config for Runtime {
@@ -294,6 +404,8 @@ mod mock {
 		type DataDepositPerByte = DataDepositPerByte;
 		type PalletId = NftPalletId;
 		type MaxAttributesBytes = MaxAttributesBytes;
+		type MaxBatchItems = MaxBatchItems;
+		type MultiCurrency = Tokens;
 		type WeightInfo = ();
 	}
 
@@ -325,7 +437,8 @@ mod mock {
 			Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 			Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
 			OrmlNFT: orml_nft::{Pallet, Storage, Config<T>},
-			NFT: nft::{Pallet, Call, Event<T>},
+			NFT: nft::{Pallet, Call, Storage, Event<T>},
+			Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
 		}
 	);
 
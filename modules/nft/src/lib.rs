@@ -33,15 +33,16 @@ use frame_support::{
 	transactional, PalletId,
 };
 use frame_system::pallet_prelude::*;
-use orml_traits::NFT;
-use primitives::{NFTBalance, ReserveIdentifier};
+use orml_traits::{MultiCurrency, NFT};
+use primitives::{CurrencyId, NFTBalance, ReserveIdentifier};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::{
 	traits::{AccountIdConversion, Hash, Saturating, StaticLookup, Zero},
-	DispatchResult, RuntimeDebug,
+	DispatchResult, Permill, RuntimeDebug,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+use support::NFTIssuer;
 
 pub mod benchmarking;
 mod mock;
@@ -65,6 +66,8 @@ pub enum ClassProperty {
 	Mintable = 0b00000100,
 	/// Is class properties mutable
 	ClassPropertiesMutable = 0b00001000,
+	/// Is token revocable by the class owner
+	Revocable = 0b00010000,
 }
 
 #[derive(Clone, Copy, PartialEq, Default, RuntimeDebug)]
@@ -106,6 +109,16 @@ pub struct TokenData<Balance> {
 	pub attributes: Attributes,
 }
 
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct Listing<AccountId, Balance> {
+	/// The owner of the token who listed it
+	pub seller: AccountId,
+	/// The currency the token is priced in
+	pub currency_id: CurrencyId,
+	/// The asking price
+	pub price: Balance,
+}
+
 pub type TokenIdOf<T> = <T as orml_nft::Config>::TokenId;
 pub type ClassIdOf<T> = <T as orml_nft::Config>::ClassId;
 pub type BalanceOf<T> =
@@ -152,6 +165,13 @@ pub mod module {
 		#[pallet::constant]
 		type MaxAttributesBytes: Get<u32>;
 
+		/// Maximum number of items in a batch mint or batch transfer
+		#[pallet::constant]
+		type MaxBatchItems: Get<u32>;
+
+		/// Currency used to pay for listed tokens and royalties.
+		type MultiCurrency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = BalanceOf<Self>>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -179,11 +199,25 @@ pub mod module {
 		Immutable,
 		/// Attributes too large
 		AttributesTooLarge,
+		/// The token is not listed for sale
+		NotForSale,
+		/// The buyer already owns the token
+		CannotBuyOwnToken,
+		/// Batch is empty or exceeds `MaxBatchItems`
+		InvalidBatchSize,
+		/// Property of class don't support revoke
+		NonRevocable,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
-	#[pallet::metadata(T::AccountId = "AccountId", ClassIdOf<T> = "ClassId", TokenIdOf<T> = "TokenId", T::Hash = "Hash")]
+	#[pallet::metadata(
+		T::AccountId = "AccountId",
+		ClassIdOf<T> = "ClassId",
+		TokenIdOf<T> = "TokenId",
+		T::Hash = "Hash",
+		BalanceOf<T> = "Balance"
+	)]
 	pub enum Event<T: Config> {
 		/// Created NFT class. \[owner, class_id\]
 		CreatedClass(T::AccountId, ClassIdOf<T>),
@@ -197,8 +231,49 @@ pub mod module {
 		BurnedTokenWithRemark(T::AccountId, ClassIdOf<T>, TokenIdOf<T>, T::Hash),
 		/// Destroyed NFT class. \[owner, class_id\]
 		DestroyedClass(T::AccountId, ClassIdOf<T>),
+		/// Listed NFT token for sale. \[seller, class_id, token_id, currency_id, price\]
+		ListedForSale(T::AccountId, ClassIdOf<T>, TokenIdOf<T>, CurrencyId, BalanceOf<T>),
+		/// Cancelled NFT token listing. \[seller, class_id, token_id\]
+		CancelledListing(T::AccountId, ClassIdOf<T>, TokenIdOf<T>),
+		/// Sold NFT token. \[seller, buyer, class_id, token_id, currency_id, price, royalty\]
+		SoldToken(
+			T::AccountId,
+			T::AccountId,
+			ClassIdOf<T>,
+			TokenIdOf<T>,
+			CurrencyId,
+			BalanceOf<T>,
+			BalanceOf<T>,
+		),
+		/// Revoked NFT token by the class owner. \[class_owner, token_owner, class_id, token_id\]
+		RevokedToken(T::AccountId, T::AccountId, ClassIdOf<T>, TokenIdOf<T>),
+		/// Updated class royalty rate. \[class_id, rate\]
+		RoyaltyUpdated(ClassIdOf<T>, Permill),
 	}
 
+	/// The listing of tokens for sale.
+	///
+	/// Listings: double_map ClassId, TokenId => Option<Listing>
+	#[pallet::storage]
+	#[pallet::getter(fn listings)]
+	pub type Listings<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ClassIdOf<T>,
+		Twox64Concat,
+		TokenIdOf<T>,
+		Listing<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The royalty rate of the sale price paid to the class account on every
+	/// sale.
+	///
+	/// ClassRoyalties: map ClassId => Permill
+	#[pallet::storage]
+	#[pallet::getter(fn class_royalties)]
+	pub type ClassRoyalties<T: Config> = StorageMap<_, Twox64Concat, ClassIdOf<T>, Permill, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -266,7 +341,8 @@ pub mod module {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let to = T::Lookup::lookup(to)?;
-			Self::do_mint(who, to, class_id, metadata, attributes, quantity)
+			Self::do_mint(who, to, class_id, metadata, attributes, quantity)?;
+			Ok(())
 		}
 
 		/// Transfer NFT token to another account
@@ -285,6 +361,46 @@ pub mod module {
 			Self::do_transfer(&who, &to, token)
 		}
 
+		/// Mint one NFT token to each of the accounts
+		///
+		/// - `class_id`: token belong to the class id
+		/// - `items`: (to, metadata, attributes) of each token
+		#[pallet::weight(<T as Config>::WeightInfo::mint_batch(items.len() as u32))]
+		#[transactional]
+		pub fn mint_batch(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			items: Vec<(<T::Lookup as StaticLookup>::Source, CID, Attributes)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_batch_size(items.len())?;
+
+			for (to, metadata, attributes) in items {
+				let to = T::Lookup::lookup(to)?;
+				Self::do_mint(who.clone(), to, class_id, metadata, attributes, 1)?;
+			}
+			Ok(())
+		}
+
+		/// Transfer NFT tokens to other accounts
+		///
+		/// - `items`: (to, (class_id, token_id)) of each token
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_batch(items.len() as u32))]
+		#[transactional]
+		pub fn transfer_batch(
+			origin: OriginFor<T>,
+			items: Vec<(<T::Lookup as StaticLookup>::Source, (ClassIdOf<T>, TokenIdOf<T>))>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_batch_size(items.len())?;
+
+			for (to, token) in items {
+				let to = T::Lookup::lookup(to)?;
+				Self::do_transfer(&who, &to, token)?;
+			}
+			Ok(())
+		}
+
 		/// Burn NFT token
 		///
 		/// - `token`: (class_id, token_id)
@@ -310,6 +426,32 @@ pub mod module {
 			Self::do_burn(who, token, Some(remark))
 		}
 
+		/// Revoke NFT token from its owner. The class properties must contains
+		/// Revocable.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::revoke())]
+		#[transactional]
+		pub fn revoke(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(who == class_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				class_info.data.properties.0.contains(ClassProperty::Revocable),
+				Error::<T>::NonRevocable
+			);
+
+			let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
+
+			orml_nft::Pallet::<T>::burn(&token_info.owner, token)?;
+			Listings::<T>::remove(token.0, token.1);
+
+			<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &token_info.owner, token_info.data.deposit);
+
+			Self::deposit_event(Event::RevokedToken(who, token_info.owner, token.0, token.1));
+			Ok(())
+		}
+
 		/// Destroy NFT class, remove dest from proxy, and send all the free
 		/// balance to dest
 		///
@@ -336,6 +478,7 @@ pub mod module {
 			<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &who, data.deposit);
 
 			orml_nft::Pallet::<T>::destroy_class(&who, class_id)?;
+			ClassRoyalties::<T>::remove(class_id);
 
 			// this should unresere proxy deposit
 			pallet_proxy::Pallet::<T>::remove_proxy_delegate(&who, dest.clone(), Default::default(), Zero::zero())?;
@@ -379,6 +522,118 @@ pub mod module {
 				Ok(())
 			})
 		}
+
+		/// List NFT token for sale. Any existing listing of the token is
+		/// replaced.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `currency_id`: the currency to be paid in
+		/// - `price`: the asking price
+		#[pallet::weight(<T as Config>::WeightInfo::list_for_sale())]
+		#[transactional]
+		pub fn list_for_sale(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			currency_id: CurrencyId,
+			#[pallet::compact] price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(
+				class_info.data.properties.0.contains(ClassProperty::Transferable),
+				Error::<T>::NonTransferable
+			);
+			let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
+			ensure!(who == token_info.owner, Error::<T>::NoPermission);
+
+			Listings::<T>::insert(
+				token.0,
+				token.1,
+				Listing {
+					seller: who.clone(),
+					currency_id,
+					price,
+				},
+			);
+
+			Self::deposit_event(Event::ListedForSale(who, token.0, token.1, currency_id, price));
+			Ok(())
+		}
+
+		/// Cancel the sale listing of NFT token.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_listing())]
+		#[transactional]
+		pub fn cancel_listing(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let listing = Self::listings(token.0, token.1).ok_or(Error::<T>::NotForSale)?;
+			ensure!(who == listing.seller, Error::<T>::NoPermission);
+
+			Listings::<T>::remove(token.0, token.1);
+
+			Self::deposit_event(Event::CancelledListing(who, token.0, token.1));
+			Ok(())
+		}
+
+		/// Buy listed NFT token at its asking price. The class royalty is
+		/// paid to the class account, and the rest to the seller.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		#[transactional]
+		pub fn buy(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let listing = Listings::<T>::take(token.0, token.1).ok_or(Error::<T>::NotForSale)?;
+			ensure!(who != listing.seller, Error::<T>::CannotBuyOwnToken);
+			let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
+
+			let royalty = Self::class_royalties(token.0).mul_floor(listing.price);
+			if !royalty.is_zero() {
+				T::MultiCurrency::transfer(listing.currency_id, &who, &class_info.owner, royalty)?;
+			}
+			T::MultiCurrency::transfer(
+				listing.currency_id,
+				&who,
+				&listing.seller,
+				listing.price.saturating_sub(royalty),
+			)?;
+
+			Self::do_transfer(&listing.seller, &who, token)?;
+
+			Self::deposit_event(Event::SoldToken(
+				listing.seller,
+				who,
+				token.0,
+				token.1,
+				listing.currency_id,
+				listing.price,
+				royalty,
+			));
+			Ok(())
+		}
+
+		/// Set the royalty rate of NFT class, paid to the class account on
+		/// every sale of its tokens.
+		///
+		/// - `class_id`: The class ID to update
+		/// - `rate`: The royalty rate of the sale price
+		#[pallet::weight(<T as Config>::WeightInfo::set_royalty())]
+		#[transactional]
+		pub fn set_royalty(origin: OriginFor<T>, class_id: ClassIdOf<T>, rate: Permill) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(who == class_info.owner, Error::<T>::NoPermission);
+
+			if rate.is_zero() {
+				ClassRoyalties::<T>::remove(class_id);
+			} else {
+				ClassRoyalties::<T>::insert(class_id, rate);
+			}
+
+			Self::deposit_event(Event::RoyaltyUpdated(class_id, rate));
+			Ok(())
+		}
 	}
 }
 
@@ -395,6 +650,7 @@ impl<T: Config> Pallet<T> {
 		let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
 
 		orml_nft::Pallet::<T>::transfer(from, to, token)?;
+		Listings::<T>::remove(token.0, token.1);
 
 		<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, from, token_info.data.deposit);
 		<T as module::Config>::Currency::transfer(from, to, token_info.data.deposit, AllowDeath)?;
@@ -412,7 +668,7 @@ impl<T: Config> Pallet<T> {
 		metadata: CID,
 		attributes: Attributes,
 		quantity: u32,
-	) -> DispatchResult {
+	) -> Result<Vec<TokenIdOf<T>>, DispatchError> {
 		ensure!(quantity >= 1, Error::<T>::InvalidQuantity);
 		let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
 		ensure!(who == class_info.owner, Error::<T>::NoPermission);
@@ -432,12 +688,14 @@ impl<T: Config> Pallet<T> {
 		<T as module::Config>::Currency::reserve_named(&RESERVE_ID, &to, total_deposit)?;
 
 		let data = TokenData { deposit, attributes };
+		let mut token_ids = Vec::with_capacity(quantity as usize);
 		for _ in 0..quantity {
-			orml_nft::Pallet::<T>::mint(&to, class_id, metadata.clone(), data.clone())?;
+			let token_id = orml_nft::Pallet::<T>::mint(&to, class_id, metadata.clone(), data.clone())?;
+			token_ids.push(token_id);
 		}
 
 		Self::deposit_event(Event::MintedToken(who, to, class_id, quantity));
-		Ok(())
+		Ok(token_ids)
 	}
 
 	fn do_burn(who: T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>), remark: Option<Vec<u8>>) -> DispatchResult {
@@ -452,6 +710,7 @@ impl<T: Config> Pallet<T> {
 		ensure!(who == token_info.owner, Error::<T>::NoPermission);
 
 		orml_nft::Pallet::<T>::burn(&who, token)?;
+		Listings::<T>::remove(token.0, token.1);
 
 		<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &who, token_info.data.deposit);
 
@@ -465,6 +724,19 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Get the properties of the class.
+	pub fn class_properties(class_id: ClassIdOf<T>) -> Option<Properties> {
+		orml_nft::Pallet::<T>::classes(class_id).map(|class_info| class_info.data.properties)
+	}
+
+	fn ensure_batch_size(len: usize) -> DispatchResult {
+		ensure!(
+			len > 0 && len <= T::MaxBatchItems::get() as usize,
+			Error::<T>::InvalidBatchSize
+		);
+		Ok(())
+	}
+
 	fn data_deposit(metadata: &[u8], attributes: &Attributes) -> Result<BalanceOf<T>, DispatchError> {
 		// Addition can't overflow because we will be out of memory before that
 		let attributes_len = attributes.iter().fold(0, |acc, (k, v)| {
@@ -498,3 +770,24 @@ impl<T: Config> NFT<T::AccountId> for Pallet<T> {
 		Self::do_transfer(from, to, token)
 	}
 }
+
+impl<T: Config> NFTIssuer<T::AccountId> for Pallet<T> {
+	type ClassId = ClassIdOf<T>;
+	type TokenId = TokenIdOf<T>;
+
+	#[transactional]
+	fn issue(to: &T::AccountId, class_id: Self::ClassId, metadata: Vec<u8>) -> Result<Self::TokenId, DispatchError> {
+		let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+		let token_ids = Self::do_mint(class_info.owner, to.clone(), class_id, metadata, Default::default(), 1)?;
+		Ok(token_ids[0])
+	}
+
+	#[transactional]
+	fn burn(who: &T::AccountId, token: (Self::ClassId, Self::TokenId)) -> DispatchResult {
+		Self::do_burn(who.clone(), token, None)
+	}
+
+	fn owner(token: (Self::ClassId, Self::TokenId)) -> Option<T::AccountId> {
+		orml_nft::Pallet::<T>::tokens(token.0, token.1).map(|t| t.owner)
+	}
+}
@@ -170,6 +170,7 @@ impl orml_tokens::Config for Runtime {
 }
 
 pub const NATIVE_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
+pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 
 parameter_types! {
 	pub const GetNativeCurrencyId: CurrencyId = NATIVE_CURRENCY_ID;
@@ -193,6 +194,7 @@ parameter_types! {
 	pub const DataDepositPerByte: Balance = 10;
 	pub const NftPalletId: PalletId = PalletId(*b"aca/aNFT");
 	pub MaxAttributesBytes: u32 = 10;
+	pub const MaxBatchItems: u32 = 3;
 }
 impl Config for Runtime {
 	type Event = Event;
@@ -202,6 +204,8 @@ impl Config for Runtime {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = MaxAttributesBytes;
+	type MaxBatchItems = MaxBatchItems;
+	type MultiCurrency = Currency;
 	type WeightInfo = ();
 }
 
@@ -231,7 +235,7 @@ construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		NFTModule: nft::{Pallet, Call, Event<T>},
+		NFTModule: nft::{Pallet, Call, Storage, Event<T>},
 		OrmlNFT: orml_nft::{Pallet, Storage, Config<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
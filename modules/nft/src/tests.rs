@@ -37,6 +37,10 @@ fn reserved_balance(who: &AccountId) -> Balance {
 	<Runtime as pallet_proxy::Config>::Currency::reserved_balance(who)
 }
 
+fn ausd_balance(who: &AccountId) -> Balance {
+	<Tokens as MultiCurrency<AccountId>>::free_balance(AUSD, who)
+}
+
 fn class_id_account() -> AccountId {
 	<Runtime as Config>::PalletId::get().into_sub_account(CLASS_ID)
 }
@@ -396,6 +400,114 @@ fn transfer_should_fail() {
 	});
 }
 
+#[test]
+fn mint_batch_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			vec![1],
+			Properties(ClassProperty::Transferable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			3 * (CreateTokenDeposit::get() + DataDepositPerByte::get())
+		));
+
+		assert_noop!(
+			NFTModule::mint_batch(Origin::signed(class_id_account()), CLASS_ID, vec![]),
+			Error::<Runtime>::InvalidBatchSize
+		);
+		assert_noop!(
+			NFTModule::mint_batch(
+				Origin::signed(class_id_account()),
+				CLASS_ID,
+				vec![(BOB, vec![1], Default::default()); 4]
+			),
+			Error::<Runtime>::InvalidBatchSize
+		);
+		assert_noop!(
+			NFTModule::mint_batch(Origin::signed(BOB), CLASS_ID, vec![(BOB, vec![1], Default::default())]),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(NFTModule::mint_batch(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			vec![
+				(ALICE, vec![1], Default::default()),
+				(BOB, vec![2], Default::default()),
+				(BOB, vec![3], Default::default()),
+			]
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::MintedToken(
+			class_id_account(),
+			BOB,
+			CLASS_ID,
+			1,
+		)));
+		assert_eq!(<NFTModule as NFT<AccountId>>::owner((CLASS_ID, 0)), Some(ALICE));
+		assert_eq!(<NFTModule as NFT<AccountId>>::owner((CLASS_ID, 1)), Some(BOB));
+		assert_eq!(<NFTModule as NFT<AccountId>>::owner((CLASS_ID, 2)), Some(BOB));
+		assert_eq!(
+			reserved_balance(&BOB),
+			2 * (CreateTokenDeposit::get() + DataDepositPerByte::get())
+		);
+	});
+}
+
+#[test]
+fn transfer_batch_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			vec![1],
+			Properties(ClassProperty::Transferable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			3 * (CreateTokenDeposit::get() + DataDepositPerByte::get())
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			3
+		));
+
+		assert_noop!(
+			NFTModule::transfer_batch(Origin::signed(BOB), vec![]),
+			Error::<Runtime>::InvalidBatchSize
+		);
+		assert_noop!(
+			NFTModule::transfer_batch(Origin::signed(BOB), vec![(ALICE, (CLASS_ID, TOKEN_ID)); 4]),
+			Error::<Runtime>::InvalidBatchSize
+		);
+		assert_noop!(
+			NFTModule::transfer_batch(
+				Origin::signed(BOB),
+				vec![(ALICE, (CLASS_ID, 0)), (ALICE, (CLASS_ID, 3))]
+			),
+			Error::<Runtime>::TokenIdNotFound
+		);
+
+		assert_ok!(NFTModule::transfer_batch(
+			Origin::signed(BOB),
+			vec![(ALICE, (CLASS_ID, 0)), (ALICE, (CLASS_ID, 2))]
+		));
+		assert_eq!(<NFTModule as NFT<AccountId>>::owner((CLASS_ID, 0)), Some(ALICE));
+		assert_eq!(<NFTModule as NFT<AccountId>>::owner((CLASS_ID, 1)), Some(BOB));
+		assert_eq!(<NFTModule as NFT<AccountId>>::owner((CLASS_ID, 2)), Some(ALICE));
+		assert_eq!(
+			reserved_balance(&ALICE),
+			2 * (CreateTokenDeposit::get() + DataDepositPerByte::get())
+		);
+	});
+}
+
 #[test]
 fn burn_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -539,6 +651,99 @@ fn burn_with_remark_should_work() {
 	});
 }
 
+#[test]
+fn revoke_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			vec![1],
+			Properties(ClassProperty::Mintable | ClassProperty::Revocable),
+			Default::default(),
+		));
+		assert_eq!(
+			NFTModule::class_properties(CLASS_ID),
+			Some(Properties(ClassProperty::Mintable | ClassProperty::Revocable))
+		);
+		assert_eq!(NFTModule::class_properties(CLASS_ID_NOT_EXIST), None);
+
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			1
+		));
+
+		assert_noop!(
+			NFTModule::transfer(Origin::signed(BOB), ALICE, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NonTransferable
+		);
+		assert_noop!(
+			NFTModule::burn(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NonBurnable
+		);
+		assert_noop!(
+			NFTModule::revoke(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::revoke(Origin::signed(class_id_account()), (CLASS_ID, TOKEN_ID_NOT_EXIST)),
+			Error::<Runtime>::TokenIdNotFound
+		);
+
+		assert_ok!(NFTModule::revoke(
+			Origin::signed(class_id_account()),
+			(CLASS_ID, TOKEN_ID)
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::RevokedToken(
+			class_id_account(),
+			BOB,
+			CLASS_ID,
+			TOKEN_ID,
+		)));
+		assert_eq!(<NFTModule as NFT<AccountId>>::owner((CLASS_ID, TOKEN_ID)), None);
+		assert_eq!(reserved_balance(&BOB), 0);
+		assert_eq!(
+			free_balance(&BOB),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		);
+	});
+}
+
+#[test]
+fn revoke_should_fail_without_revocable() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			vec![1],
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			vec![1],
+			Default::default(),
+			1
+		));
+
+		assert_noop!(
+			NFTModule::revoke(Origin::signed(class_id_account()), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NonRevocable
+		);
+	});
+}
+
 #[test]
 fn destroy_class_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -709,3 +914,210 @@ fn update_class_properties_should_work() {
 		);
 	});
 }
+
+fn setup_listing_class(properties: Properties) {
+	assert_ok!(NFTModule::create_class(
+		Origin::signed(ALICE),
+		vec![1],
+		properties,
+		Default::default(),
+	));
+	assert_ok!(Balances::deposit_into_existing(
+		&class_id_account(),
+		CreateTokenDeposit::get() + DataDepositPerByte::get()
+	));
+	assert_ok!(NFTModule::mint(
+		Origin::signed(class_id_account()),
+		BOB,
+		CLASS_ID,
+		vec![1],
+		Default::default(),
+		1
+	));
+}
+
+#[test]
+fn list_for_sale_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_listing_class(Properties(ClassProperty::Transferable | ClassProperty::Mintable));
+
+		assert_noop!(
+			NFTModule::list_for_sale(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID), AUSD, 1000),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::list_for_sale(Origin::signed(BOB), (CLASS_ID, TOKEN_ID_NOT_EXIST), AUSD, 1000),
+			Error::<Runtime>::TokenIdNotFound
+		);
+
+		assert_ok!(NFTModule::list_for_sale(
+			Origin::signed(BOB),
+			(CLASS_ID, TOKEN_ID),
+			AUSD,
+			1000
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::ListedForSale(
+			BOB, CLASS_ID, TOKEN_ID, AUSD, 1000,
+		)));
+		assert_eq!(
+			NFTModule::listings(CLASS_ID, TOKEN_ID),
+			Some(Listing {
+				seller: BOB,
+				currency_id: AUSD,
+				price: 1000,
+			})
+		);
+
+		assert_noop!(
+			NFTModule::cancel_listing(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::cancel_listing(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+		System::assert_last_event(Event::NFTModule(crate::Event::CancelledListing(
+			BOB, CLASS_ID, TOKEN_ID,
+		)));
+		assert_eq!(NFTModule::listings(CLASS_ID, TOKEN_ID), None);
+		assert_noop!(
+			NFTModule::cancel_listing(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NotForSale
+		);
+
+		// transferring the token drops the listing
+		assert_ok!(NFTModule::list_for_sale(
+			Origin::signed(BOB),
+			(CLASS_ID, TOKEN_ID),
+			AUSD,
+			1000
+		));
+		assert_ok!(NFTModule::transfer(Origin::signed(BOB), ALICE, (CLASS_ID, TOKEN_ID)));
+		assert_eq!(NFTModule::listings(CLASS_ID, TOKEN_ID), None);
+	});
+
+	ExtBuilder::default().build().execute_with(|| {
+		setup_listing_class(Properties(ClassProperty::Mintable.into()));
+
+		assert_noop!(
+			NFTModule::list_for_sale(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), AUSD, 1000),
+			Error::<Runtime>::NonTransferable
+		);
+	});
+}
+
+#[test]
+fn buy_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_listing_class(Properties(ClassProperty::Transferable | ClassProperty::Mintable));
+		assert_ok!(Tokens::deposit(AUSD, &ALICE, 2000));
+
+		assert_noop!(
+			NFTModule::buy(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NotForSale
+		);
+
+		assert_ok!(NFTModule::set_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Permill::from_percent(10)
+		));
+		assert_ok!(NFTModule::list_for_sale(
+			Origin::signed(BOB),
+			(CLASS_ID, TOKEN_ID),
+			AUSD,
+			1000
+		));
+		assert_noop!(
+			NFTModule::buy(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::CannotBuyOwnToken
+		);
+
+		assert_ok!(NFTModule::buy(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)));
+		System::assert_last_event(Event::NFTModule(crate::Event::SoldToken(
+			BOB, ALICE, CLASS_ID, TOKEN_ID, AUSD, 1000, 100,
+		)));
+		assert_eq!(ausd_balance(&ALICE), 1000);
+		assert_eq!(ausd_balance(&BOB), 900);
+		assert_eq!(ausd_balance(&class_id_account()), 100);
+		assert_eq!(<NFTModule as NFT<AccountId>>::owner((CLASS_ID, TOKEN_ID)), Some(ALICE));
+		assert_eq!(
+			reserved_balance(&ALICE),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		);
+		assert_eq!(reserved_balance(&BOB), 0);
+		assert_eq!(NFTModule::listings(CLASS_ID, TOKEN_ID), None);
+	});
+}
+
+#[test]
+fn set_royalty_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_listing_class(Properties(ClassProperty::Transferable | ClassProperty::Mintable));
+
+		assert_noop!(
+			NFTModule::set_royalty(Origin::signed(ALICE), CLASS_ID, Permill::from_percent(10)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::set_royalty(
+				Origin::signed(class_id_account()),
+				CLASS_ID_NOT_EXIST,
+				Permill::from_percent(10)
+			),
+			Error::<Runtime>::ClassIdNotFound
+		);
+
+		assert_ok!(NFTModule::set_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Permill::from_percent(10)
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::RoyaltyUpdated(
+			CLASS_ID,
+			Permill::from_percent(10),
+		)));
+		assert_eq!(NFTModule::class_royalties(CLASS_ID), Permill::from_percent(10));
+
+		assert_ok!(NFTModule::set_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Permill::zero()
+		));
+		assert!(!ClassRoyalties::<Runtime>::contains_key(CLASS_ID));
+	});
+}
+
+#[test]
+fn nft_issuer_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			vec![1],
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		));
+
+		assert_eq!(
+			<NFTModule as NFTIssuer<AccountId>>::issue(&BOB, CLASS_ID, vec![2]),
+			Ok(TOKEN_ID)
+		);
+		assert_eq!(
+			<NFTModule as NFTIssuer<AccountId>>::owner((CLASS_ID, TOKEN_ID)),
+			Some(BOB)
+		);
+		assert_eq!(
+			reserved_balance(&BOB),
+			CreateTokenDeposit::get() + DataDepositPerByte::get()
+		);
+
+		assert_noop!(
+			<NFTModule as NFTIssuer<AccountId>>::burn(&ALICE, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(<NFTModule as NFTIssuer<AccountId>>::burn(&BOB, (CLASS_ID, TOKEN_ID)));
+		assert_eq!(<NFTModule as NFTIssuer<AccountId>>::owner((CLASS_ID, TOKEN_ID)), None);
+		assert_eq!(reserved_balance(&BOB), 0);
+	});
+}
@@ -54,6 +54,13 @@ pub trait WeightInfo {
 	fn burn_with_remark(b: u32, ) -> Weight;
 	fn destroy_class() -> Weight;
 	fn update_class_properties() -> Weight;
+	fn list_for_sale() -> Weight;
+	fn cancel_listing() -> Weight;
+	fn buy() -> Weight;
+	fn set_royalty() -> Weight;
+	fn mint_batch(i: u32, ) -> Weight;
+	fn transfer_batch(i: u32, ) -> Weight;
+	fn revoke() -> Weight;
 }
 
 /// Weights for module_nft using the Acala node and recommended hardware.
@@ -99,6 +106,47 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn list_for_sale() -> Weight {
+		(38_512_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_listing() -> Weight {
+		(27_903_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn buy() -> Weight {
+		(341_207_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	fn set_royalty() -> Weight {
+		(29_116_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn mint_batch(i: u32, ) -> Weight {
+		(38_175_000 as Weight)
+			// Standard Error: 51_000
+			.saturating_add((78_301_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(i as Weight)))
+	}
+	fn transfer_batch(i: u32, ) -> Weight {
+		(12_462_000 as Weight)
+			// Standard Error: 63_000
+			.saturating_add((255_813_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads((7 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes((8 as Weight).saturating_mul(i as Weight)))
+	}
+	fn revoke() -> Weight {
+		(178_540_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -143,4 +191,45 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn list_for_sale() -> Weight {
+		(38_512_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_listing() -> Weight {
+		(27_903_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn buy() -> Weight {
+		(341_207_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	fn set_royalty() -> Weight {
+		(29_116_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn mint_batch(i: u32, ) -> Weight {
+		(38_175_000 as Weight)
+			// Standard Error: 51_000
+			.saturating_add((78_301_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(i as Weight)))
+	}
+	fn transfer_batch(i: u32, ) -> Weight {
+		(12_462_000 as Weight)
+			// Standard Error: 63_000
+			.saturating_add((255_813_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(RocksDbWeight::get().reads((7 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((8 as Weight).saturating_mul(i as Weight)))
+	}
+	fn revoke() -> Weight {
+		(178_540_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
 }
@@ -31,7 +31,7 @@ use orml_traits::{BasicCurrency, BasicLockableCurrency};
 use primitives::{Balance, EraIndex};
 use sp_runtime::{
 	traits::{MaybeDisplay, MaybeSerializeDeserialize, Member, Zero},
-	RuntimeDebug, SaturatedConversion,
+	Perbill, RuntimeDebug, SaturatedConversion,
 };
 use sp_std::{convert::TryInto, fmt::Debug, prelude::*};
 use support::{NomineesProvider, OnNewEra};
@@ -118,6 +118,15 @@ where
 	}
 }
 
+/// The performance of a nominee on the relaychain, relayed by `PerformanceOrigin`.
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, MaxEncodedLen)]
+pub struct NomineePerformance {
+	/// The era points the nominee earned in the latest reported era.
+	pub era_points: u32,
+	/// The latest era in which the nominee was slashed.
+	pub last_slashed_era: Option<EraIndex>,
+}
+
 impl<T> Default for BondingLedger<T>
 where
 	T: Get<u32>,
@@ -151,6 +160,12 @@ pub mod module {
 		#[pallet::constant]
 		type MaxUnlockingChunks: Get<u32>;
 		type NomineeFilter: Contains<Self::NomineeId>;
+		/// The origin which may report the performance of nominees, e.g. an
+		/// oracle or the relaychain via XCM.
+		type PerformanceOrigin: EnsureOrigin<Self::Origin>;
+		/// The number of eras a slashed nominee is excluded from election.
+		#[pallet::constant]
+		type SlashedNomineeCooldown: Get<EraIndex>;
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -172,6 +187,8 @@ pub mod module {
 	pub enum Event<T: Config<I>, I: 'static = ()> {
 		/// rebond. \[who, amount\]
 		Rebond(T::AccountId, Balance),
+		/// Performance of nominees reported. \[era, reports_count\]
+		PerformanceReported(EraIndex, u32),
 	}
 
 	/// The nominations for nominators.
@@ -211,6 +228,14 @@ pub mod module {
 	pub type Nominees<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, BoundedVec<<T as Config<I>>::NomineeId, T::NominateesCount>, ValueQuery>;
 
+	/// The performance of nominees, used to score them when electing.
+	///
+	/// NomineePerformances: map NomineeId => NomineePerformance
+	#[pallet::storage]
+	#[pallet::getter(fn nominee_performances)]
+	pub type NomineePerformances<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, <T as Config<I>>::NomineeId, NomineePerformance, OptionQuery>;
+
 	/// Current era index.
 	///
 	/// CurrentEra: EraIndex
@@ -366,6 +391,37 @@ pub mod module {
 			Nominations::<T, I>::remove(&who);
 			Ok(Some(T::WeightInfo::chill(old_nominations.len() as u32)).into())
 		}
+
+		/// Report the era points and slashes of nominees in `era`, which will
+		/// be used to score nominees at the next rebalance.
+		///
+		/// The dispatch origin of this call must be `PerformanceOrigin`.
+		///
+		/// - `era`: the era the performance belongs to.
+		/// - `reports`: list of (nominee, era_points, is_slashed).
+		#[pallet::weight(T::WeightInfo::report_performance(reports.len() as u32))]
+		#[transactional]
+		pub fn report_performance(
+			origin: OriginFor<T>,
+			era: EraIndex,
+			reports: Vec<(T::NomineeId, u32, bool)>,
+		) -> DispatchResult {
+			T::PerformanceOrigin::ensure_origin(origin)?;
+
+			let reports_count = reports.len() as u32;
+			for (nominee, era_points, is_slashed) in reports {
+				NomineePerformances::<T, I>::mutate(&nominee, |maybe_performance| {
+					let performance = maybe_performance.get_or_insert_with(Default::default);
+					performance.era_points = era_points;
+					if is_slashed {
+						performance.last_slashed_era = Some(era);
+					}
+				});
+			}
+
+			Self::deposit_event(Event::PerformanceReported(era, reports_count));
+			Ok(())
+		}
 	}
 }
 
@@ -420,8 +476,41 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
+	/// The score of a nominee is its votes weighted by its era points relative
+	/// to the best performing nominee. Nominees without reported performance
+	/// are scored by votes only, nominees slashed within
+	/// `SlashedNomineeCooldown` eras are not eligible.
+	fn score(nominee: &T::NomineeId, votes: Balance, max_era_points: u32, current_era: EraIndex) -> Option<Balance> {
+		match Self::nominee_performances(nominee) {
+			Some(performance) => {
+				if let Some(slashed_era) = performance.last_slashed_era {
+					if current_era < slashed_era.saturating_add(T::SlashedNomineeCooldown::get()) {
+						return None;
+					}
+				}
+
+				if max_era_points.is_zero() {
+					Some(votes)
+				} else {
+					Some(Perbill::from_rational(performance.era_points, max_era_points) * votes)
+				}
+			}
+			None => Some(votes),
+		}
+	}
+
 	fn rebalance() {
-		let mut voters = Votes::<T, I>::iter().collect::<Vec<(T::NomineeId, Balance)>>();
+		let current_era = Self::current_era();
+		let max_era_points = NomineePerformances::<T, I>::iter_values()
+			.map(|performance| performance.era_points)
+			.max()
+			.unwrap_or_default();
+
+		let mut voters = Votes::<T, I>::iter()
+			.filter_map(|(nominee, votes)| {
+				Self::score(&nominee, votes, max_era_points, current_era).map(|score| (nominee, score))
+			})
+			.collect::<Vec<(T::NomineeId, Balance)>>();
 
 		voters.sort_by(|a, b| b.1.cmp(&a.1));
 
@@ -24,6 +24,7 @@ use super::*;
 
 use crate as nominees_election;
 use frame_support::{construct_runtime, ord_parameter_types, parameter_types};
+use frame_system::EnsureSignedBy;
 use orml_traits::parameter_type_with_key;
 use primitives::{Amount, CurrencyId, TokenSymbol};
 use sp_core::H256;
@@ -131,6 +132,7 @@ parameter_types! {
 	pub const NominateesCount: u32 = 5;
 	pub const MaxUnlockingChunks: u32 = 3;
 	pub const PalletId: LockIdentifier = *b"1       ";
+	pub const SlashedNomineeCooldown: EraIndex = 2;
 }
 
 pub struct MockNomineeFilter;
@@ -150,6 +152,8 @@ impl Config for Runtime {
 	type NominateesCount = NominateesCount;
 	type MaxUnlockingChunks = MaxUnlockingChunks;
 	type NomineeFilter = MockNomineeFilter;
+	type PerformanceOrigin = EnsureSignedBy<One, AccountId>;
+	type SlashedNomineeCooldown = SlashedNomineeCooldown;
 	type WeightInfo = ();
 }
 
@@ -23,6 +23,7 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::*;
+use sp_runtime::traits::BadOrigin;
 
 #[test]
 fn bond_below_min_bond_threshold() {
@@ -257,3 +258,82 @@ fn update_votes_work() {
 		assert_eq!(NomineesElectionModule::votes(4), 10);
 	});
 }
+
+#[test]
+fn report_performance_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			NomineesElectionModule::report_performance(Origin::signed(BOB), 1, vec![(1, 100, false)]),
+			BadOrigin
+		);
+		assert_ok!(NomineesElectionModule::report_performance(
+			Origin::signed(ALICE),
+			1,
+			vec![(1, 100, false), (2, 80, true)]
+		));
+		System::assert_last_event(mock::Event::NomineesElectionModule(crate::Event::PerformanceReported(
+			1, 2,
+		)));
+		assert_eq!(
+			NomineesElectionModule::nominee_performances(1),
+			Some(NomineePerformance {
+				era_points: 100,
+				last_slashed_era: None,
+			})
+		);
+		assert_eq!(
+			NomineesElectionModule::nominee_performances(2),
+			Some(NomineePerformance {
+				era_points: 80,
+				last_slashed_era: Some(1),
+			})
+		);
+
+		assert_ok!(NomineesElectionModule::report_performance(
+			Origin::signed(ALICE),
+			2,
+			vec![(2, 90, false)]
+		));
+		assert_eq!(
+			NomineesElectionModule::nominee_performances(2),
+			Some(NomineePerformance {
+				era_points: 90,
+				last_slashed_era: Some(1),
+			})
+		);
+	});
+}
+
+#[test]
+fn rebalance_by_performance_score() {
+	ExtBuilder::default().build().execute_with(|| {
+		<Votes<Runtime>>::insert(1, 100);
+		<Votes<Runtime>>::insert(2, 90);
+		<Votes<Runtime>>::insert(3, 80);
+		<Votes<Runtime>>::insert(4, 70);
+		<Votes<Runtime>>::insert(5, 60);
+		<Votes<Runtime>>::insert(6, 50);
+
+		// nominee 1 earns far less era points, nominee 2 is slashed,
+		// nominee 6 has no reported performance.
+		assert_ok!(NomineesElectionModule::report_performance(
+			Origin::signed(ALICE),
+			1,
+			vec![
+				(1, 10, false),
+				(2, 100, true),
+				(3, 100, false),
+				(4, 100, false),
+				(5, 100, false)
+			]
+		));
+
+		NomineesElectionModule::on_new_era(1);
+		assert_eq!(NomineesElectionModule::nominees(), vec![3, 4, 5, 6, 1]);
+
+		// slashed nominee is eligible again after the cooldown.
+		NomineesElectionModule::on_new_era(3);
+		assert_eq!(NomineesElectionModule::nominees(), vec![2, 3, 4, 5, 6]);
+	});
+}
@@ -53,6 +53,7 @@ pub trait WeightInfo {
 	fn withdraw_unbonded(c: u32, ) -> Weight;
 	fn nominate(c: u32, ) -> Weight;
 	fn chill(c: u32, ) -> Weight;
+	fn report_performance(c: u32, ) -> Weight;
 }
 
 /// Weights for module_nominees_election using the Acala node and recommended hardware.
@@ -100,6 +101,13 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn report_performance(c: u32, ) -> Weight {
+		(2_135_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((3_312_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -146,4 +154,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn report_performance(c: u32, ) -> Weight {
+		(2_135_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((3_312_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 }
@@ -0,0 +1,187 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Parameters Module
+//!
+//! ## Overview
+//!
+//! Stores the risk parameters of the protocol, e.g. liquidation ratios,
+//! stability fees and auction durations, keyed by typed parameter
+//! identifiers. Changes are scheduled by governance and enacted after the
+//! mandatory `EnactmentDelay`, so that users can react before they apply. The
+//! enacted parameters override the parameters of the modules using them, which
+//! makes this module the single place to audit the risk parameter changes.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::Saturating;
+use sp_std::prelude::*;
+use support::{RiskParameterKey, RiskParameterProvider, RiskParameterValue};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin which may schedule and cancel parameter changes.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The blocks between the scheduling and the enactment of a parameter
+		/// change.
+		#[pallet::constant]
+		type EnactmentDelay: Get<Self::BlockNumber>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The value doesn't have the type of the parameter
+		InvalidParameterValue,
+		/// The parameter has no scheduled change
+		NoScheduledChange,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	#[pallet::metadata(T::BlockNumber = "BlockNumber")]
+	pub enum Event<T: Config> {
+		/// Parameter change scheduled, `None` value resets the parameter.
+		/// \[key, value, enact_at\]
+		ParameterChangeScheduled(RiskParameterKey, Option<RiskParameterValue>, T::BlockNumber),
+		/// Scheduled parameter change cancelled. \[key\]
+		ParameterChangeCancelled(RiskParameterKey),
+		/// Parameter change enacted. \[key, value\]
+		ParameterChangeEnacted(RiskParameterKey, Option<RiskParameterValue>),
+	}
+
+	/// The enacted risk parameters.
+	///
+	/// Parameters: map RiskParameterKey => Option<RiskParameterValue>
+	#[pallet::storage]
+	#[pallet::getter(fn parameters)]
+	pub type Parameters<T: Config> = StorageMap<_, Twox64Concat, RiskParameterKey, RiskParameterValue, OptionQuery>;
+
+	/// The scheduled changes of risk parameters and the block numbers to enact
+	/// them.
+	///
+	/// ScheduledChanges: map RiskParameterKey => Option<(Option<RiskParameterValue>,
+	/// BlockNumber)>
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_changes)]
+	pub type ScheduledChanges<T: Config> =
+		StorageMap<_, Twox64Concat, RiskParameterKey, (Option<RiskParameterValue>, T::BlockNumber), OptionQuery>;
+
+	/// The parameters whose changes are to be enacted at the block number.
+	///
+	/// EnactmentQueue: map BlockNumber => Vec<RiskParameterKey>
+	#[pallet::storage]
+	#[pallet::getter(fn enactment_queue)]
+	pub type EnactmentQueue<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, Vec<RiskParameterKey>, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Enact the parameter changes scheduled at `now`.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let keys = EnactmentQueue::<T>::take(now);
+			let count = keys.len() as u32;
+			for key in keys {
+				// skip the changes which were cancelled or rescheduled
+				if let Some((value, enact_at)) = Self::scheduled_changes(key) {
+					if enact_at == now {
+						ScheduledChanges::<T>::remove(key);
+						Parameters::<T>::set(key, value);
+						Self::deposit_event(Event::ParameterChangeEnacted(key, value));
+					}
+				}
+			}
+			T::WeightInfo::on_initialize(count)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Schedule a change of a risk parameter, which is enacted after
+		/// `EnactmentDelay`. Replaces the scheduled change of the parameter if
+		/// any.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `key`: the parameter to change.
+		/// - `value`: the new value, `None` resets the parameter.
+		#[pallet::weight((T::WeightInfo::schedule_parameter_change(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn schedule_parameter_change(
+			origin: OriginFor<T>,
+			key: RiskParameterKey,
+			value: Option<RiskParameterValue>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				value.as_ref().map_or(true, |value| key.accepts(value)),
+				Error::<T>::InvalidParameterValue
+			);
+
+			let enact_at = frame_system::Pallet::<T>::block_number().saturating_add(T::EnactmentDelay::get());
+			ScheduledChanges::<T>::insert(key, (value, enact_at));
+			EnactmentQueue::<T>::append(enact_at, key);
+			Self::deposit_event(Event::ParameterChangeScheduled(key, value, enact_at));
+			Ok(())
+		}
+
+		/// Cancel the scheduled change of a risk parameter.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `key`: the parameter whose change is cancelled.
+		#[pallet::weight((T::WeightInfo::cancel_parameter_change(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn cancel_parameter_change(origin: OriginFor<T>, key: RiskParameterKey) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				ScheduledChanges::<T>::take(key).is_some(),
+				Error::<T>::NoScheduledChange
+			);
+			Self::deposit_event(Event::ParameterChangeCancelled(key));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> RiskParameterProvider for Pallet<T> {
+	fn get(key: RiskParameterKey) -> Option<RiskParameterValue> {
+		Self::parameters(key)
+	}
+}
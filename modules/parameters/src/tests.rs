@@ -0,0 +1,145 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the parameters module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use primitives::{CurrencyId, TokenSymbol};
+use sp_runtime::{traits::BadOrigin, FixedPointNumber};
+use support::Ratio;
+
+const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+
+#[test]
+fn schedule_parameter_change_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let key = RiskParameterKey::LiquidationRatio(DOT);
+		let value = RiskParameterValue::Ratio(Ratio::saturating_from_rational(3, 2));
+
+		assert_noop!(
+			ParametersModule::schedule_parameter_change(Origin::signed(2), key, Some(value)),
+			BadOrigin
+		);
+		assert_noop!(
+			ParametersModule::schedule_parameter_change(
+				Origin::signed(ALICE),
+				key,
+				Some(RiskParameterValue::Balance(100))
+			),
+			Error::<Runtime>::InvalidParameterValue
+		);
+
+		assert_ok!(ParametersModule::schedule_parameter_change(
+			Origin::signed(ALICE),
+			key,
+			Some(value)
+		));
+		System::assert_last_event(Event::ParametersModule(crate::Event::ParameterChangeScheduled(
+			key,
+			Some(value),
+			11,
+		)));
+		assert_eq!(ParametersModule::scheduled_changes(key), Some((Some(value), 11)));
+		assert_eq!(ParametersModule::enactment_queue(11), vec![key]);
+
+		ParametersModule::on_initialize(10);
+		assert_eq!(ParametersModule::get(key), None);
+
+		ParametersModule::on_initialize(11);
+		System::assert_last_event(Event::ParametersModule(crate::Event::ParameterChangeEnacted(
+			key,
+			Some(value),
+		)));
+		assert_eq!(ParametersModule::get(key), Some(value));
+		assert_eq!(
+			ParametersModule::get_ratio(key),
+			Some(Ratio::saturating_from_rational(3, 2))
+		);
+		assert_eq!(ParametersModule::get_rate(key), None);
+		assert_eq!(ParametersModule::scheduled_changes(key), None);
+		assert_eq!(ParametersModule::enactment_queue(11), vec![]);
+
+		// reset the parameter
+		System::set_block_number(20);
+		assert_ok!(ParametersModule::schedule_parameter_change(
+			Origin::signed(ALICE),
+			key,
+			None
+		));
+		ParametersModule::on_initialize(30);
+		System::assert_last_event(Event::ParametersModule(crate::Event::ParameterChangeEnacted(key, None)));
+		assert_eq!(ParametersModule::get(key), None);
+	});
+}
+
+#[test]
+fn rescheduled_parameter_change_replaces_the_previous_one() {
+	ExtBuilder::default().build().execute_with(|| {
+		let key = RiskParameterKey::AuctionTimeToClose;
+
+		assert_ok!(ParametersModule::schedule_parameter_change(
+			Origin::signed(ALICE),
+			key,
+			Some(RiskParameterValue::BlockNumber(100))
+		));
+		System::set_block_number(5);
+		assert_ok!(ParametersModule::schedule_parameter_change(
+			Origin::signed(ALICE),
+			key,
+			Some(RiskParameterValue::BlockNumber(200))
+		));
+
+		ParametersModule::on_initialize(11);
+		assert_eq!(ParametersModule::get(key), None);
+
+		ParametersModule::on_initialize(15);
+		assert_eq!(ParametersModule::get_block_number(key), Some(200));
+	});
+}
+
+#[test]
+fn cancel_parameter_change_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let key = RiskParameterKey::MaximumTotalDebitValue(DOT);
+
+		assert_noop!(
+			ParametersModule::cancel_parameter_change(Origin::signed(ALICE), key),
+			Error::<Runtime>::NoScheduledChange
+		);
+
+		assert_ok!(ParametersModule::schedule_parameter_change(
+			Origin::signed(ALICE),
+			key,
+			Some(RiskParameterValue::Balance(1000))
+		));
+		assert_noop!(
+			ParametersModule::cancel_parameter_change(Origin::signed(2), key),
+			BadOrigin
+		);
+		assert_ok!(ParametersModule::cancel_parameter_change(Origin::signed(ALICE), key));
+		System::assert_last_event(Event::ParametersModule(crate::Event::ParameterChangeCancelled(key)));
+		assert_eq!(ParametersModule::scheduled_changes(key), None);
+
+		ParametersModule::on_initialize(11);
+		assert_eq!(ParametersModule::get(key), None);
+	});
+}
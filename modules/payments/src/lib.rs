@@ -0,0 +1,531 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Payments Module
+//!
+//! ## Overview
+//!
+//! Recurring payments, subscription style. A payer approves a merchant to
+//! pull a fixed amount of a currency every interval, until the total cap is
+//! paid or either party cancels the payment. Due payments are executed by the
+//! `on_idle` worker. If the payer can't afford a payment it's retried every
+//! block, and the payment expires once it's overdue for the grace period.
+//!
+//! Streaming payments, for payroll and grants. A sender reserves an amount
+//! which vests to the recipient block by block over the stream duration, the
+//! recipient can withdraw the vested amount at any time.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use orml_traits::{BalanceStatus, MultiCurrency, MultiReservableCurrency};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
+	traits::{One, Saturating, UniqueSaturatedInto, Zero},
+	ArithmeticError, DispatchError, DispatchResult, RuntimeDebug,
+};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub type PaymentId = u64;
+pub type StreamId = u64;
+
+/// A recurring payment of `amount` from `payer` to `merchant` every
+/// `interval` blocks, up to `total_cap` in total.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct Payment<AccountId, BlockNumber> {
+	/// The account paying.
+	pub payer: AccountId,
+	/// The account paid.
+	pub merchant: AccountId,
+	/// The currency of the payment.
+	pub currency_id: CurrencyId,
+	/// The amount paid every interval.
+	pub amount: Balance,
+	/// The blocks between two payments.
+	pub interval: BlockNumber,
+	/// The maximum amount paid in total.
+	pub total_cap: Balance,
+	/// The amount paid so far.
+	pub paid: Balance,
+	/// The block the next payment is due at.
+	pub next_payment_at: BlockNumber,
+}
+
+/// A stream of `amount` from `sender` to `recipient`, vesting linearly from
+/// `start` to `end`.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct Stream<AccountId, BlockNumber> {
+	/// The account streaming, who reserved the amount.
+	pub sender: AccountId,
+	/// The account receiving the stream.
+	pub recipient: AccountId,
+	/// The currency of the stream.
+	pub currency_id: CurrencyId,
+	/// The total amount streamed.
+	pub amount: Balance,
+	/// The amount withdrawn by the recipient so far.
+	pub withdrawn: Balance,
+	/// The block the stream starts at.
+	pub start: BlockNumber,
+	/// The block the stream is fully vested at.
+	pub end: BlockNumber,
+}
+
+impl<AccountId, BlockNumber: Copy + Saturating + UniqueSaturatedInto<u128> + PartialOrd>
+	Stream<AccountId, BlockNumber>
+{
+	/// The amount vested at `now`.
+	pub fn vested(&self, now: BlockNumber) -> Result<Balance, ArithmeticError> {
+		if now >= self.end {
+			return Ok(self.amount);
+		}
+		let elapsed: u128 = now.saturating_sub(self.start).unique_saturated_into();
+		let duration: u128 = self.end.saturating_sub(self.start).unique_saturated_into();
+		multiply_by_rational(self.amount, elapsed, duration).map_err(|_| ArithmeticError::Overflow)
+	}
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency to pay with, and to reserve the amount of streams.
+		type Currency: MultiReservableCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The blocks a payment can be overdue before it expires.
+		#[pallet::constant]
+		type GracePeriod: Get<Self::BlockNumber>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The payment does not exist
+		PaymentNotFound,
+		/// Only the payer or the merchant can cancel the payment
+		NoPermission,
+		/// The amount is zero or above the total cap
+		InvalidAmount,
+		/// The interval is zero
+		ZeroInterval,
+		/// No payment id available
+		NoAvailablePaymentId,
+		/// The stream does not exist
+		StreamNotFound,
+		/// The stream duration is zero
+		ZeroDuration,
+		/// No stream id available
+		NoAvailableStreamId,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", T::BlockNumber = "BlockNumber")]
+	pub enum Event<T: Config> {
+		/// Approve a payment. \[payment_id, payer, merchant, currency_id, amount,
+		/// interval, total_cap\]
+		PaymentApproved(
+			PaymentId,
+			T::AccountId,
+			T::AccountId,
+			CurrencyId,
+			Balance,
+			T::BlockNumber,
+			Balance,
+		),
+		/// Cancel a payment. \[payment_id, who\]
+		PaymentCancelled(PaymentId, T::AccountId),
+		/// Execute a payment. \[payment_id, amount\]
+		PaymentExecuted(PaymentId, Balance),
+		/// The payer could not afford a payment, it will be retried.
+		/// \[payment_id\]
+		PaymentFailed(PaymentId),
+		/// A payment has paid its total cap. \[payment_id\]
+		PaymentCompleted(PaymentId),
+		/// A payment was overdue for the grace period. \[payment_id\]
+		PaymentExpired(PaymentId),
+		/// Create a stream. \[stream_id, sender, recipient, currency_id, amount,
+		/// start, end\]
+		StreamCreated(
+			StreamId,
+			T::AccountId,
+			T::AccountId,
+			CurrencyId,
+			Balance,
+			T::BlockNumber,
+			T::BlockNumber,
+		),
+		/// Withdraw from a stream. \[stream_id, amount\]
+		StreamWithdrawn(StreamId, Balance),
+		/// Cancel a stream. \[stream_id, paid_to_recipient, refunded_to_sender\]
+		StreamCancelled(StreamId, Balance, Balance),
+	}
+
+	/// The next payment id.
+	///
+	/// NextPaymentId: PaymentId
+	#[pallet::storage]
+	#[pallet::getter(fn next_payment_id)]
+	pub type NextPaymentId<T: Config> = StorageValue<_, PaymentId, ValueQuery>;
+
+	/// The active payments.
+	///
+	/// Payments: map PaymentId => Option<Payment>
+	#[pallet::storage]
+	#[pallet::getter(fn payments)]
+	pub type Payments<T: Config> =
+		StorageMap<_, Twox64Concat, PaymentId, Payment<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	/// The payments to execute at a block.
+	///
+	/// PaymentQueue: double_map BlockNumber, PaymentId => ()
+	#[pallet::storage]
+	#[pallet::getter(fn payment_queue)]
+	pub type PaymentQueue<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, PaymentId, (), OptionQuery>;
+
+	/// The first block whose queued payments have not all been executed.
+	///
+	/// NextQueueBlock: BlockNumber
+	#[pallet::storage]
+	#[pallet::getter(fn next_queue_block)]
+	pub type NextQueueBlock<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// The next stream id.
+	///
+	/// NextStreamId: StreamId
+	#[pallet::storage]
+	#[pallet::getter(fn next_stream_id)]
+	pub type NextStreamId<T: Config> = StorageValue<_, StreamId, ValueQuery>;
+
+	/// The active streams.
+	///
+	/// Streams: map StreamId => Option<Stream>
+	#[pallet::storage]
+	#[pallet::getter(fn streams)]
+	pub type Streams<T: Config> =
+		StorageMap<_, Twox64Concat, StreamId, Stream<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let read_weight = T::DbWeight::get().reads(1);
+			let write_weight = T::DbWeight::get().writes(1);
+			let execute_weight = <T as Config>::WeightInfo::execute_payment();
+
+			let mut used_weight = read_weight.saturating_add(write_weight);
+			if remaining_weight < used_weight {
+				return 0;
+			}
+
+			let mut block = Self::next_queue_block();
+			if block.is_zero() {
+				// no payment has been scheduled yet
+				return read_weight;
+			}
+
+			while block <= now {
+				// execute the queued payments of `block` until out of weight
+				loop {
+					if used_weight.saturating_add(read_weight) > remaining_weight {
+						NextQueueBlock::<T>::put(block);
+						return used_weight;
+					}
+					used_weight = used_weight.saturating_add(read_weight);
+
+					let payment_id = match PaymentQueue::<T>::iter_prefix(block).next() {
+						Some((payment_id, _)) => payment_id,
+						None => break,
+					};
+					if used_weight.saturating_add(execute_weight) > remaining_weight {
+						NextQueueBlock::<T>::put(block);
+						return used_weight;
+					}
+					used_weight = used_weight.saturating_add(execute_weight);
+
+					PaymentQueue::<T>::remove(block, payment_id);
+					Self::execute_payment(now, payment_id);
+				}
+				block = block.saturating_add(One::one());
+			}
+
+			NextQueueBlock::<T>::put(block);
+			used_weight
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Approve `merchant` to be paid `amount` every `interval` blocks, up to
+		/// `total_cap` in total. The first payment is due at the current block.
+		///
+		/// - `merchant`: the account to pay.
+		/// - `currency_id`: the currency to pay with.
+		/// - `amount`: the amount to pay every interval.
+		/// - `interval`: the blocks between two payments.
+		/// - `total_cap`: the maximum amount to pay in total.
+		#[pallet::weight(<T as Config>::WeightInfo::approve_payment())]
+		#[transactional]
+		pub fn approve_payment(
+			origin: OriginFor<T>,
+			merchant: T::AccountId,
+			currency_id: CurrencyId,
+			#[pallet::compact] amount: Balance,
+			interval: T::BlockNumber,
+			#[pallet::compact] total_cap: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero() && amount <= total_cap, Error::<T>::InvalidAmount);
+			ensure!(!interval.is_zero(), Error::<T>::ZeroInterval);
+
+			let payment_id =
+				NextPaymentId::<T>::try_mutate(|id| -> sp_std::result::Result<PaymentId, DispatchError> {
+					let current_id = *id;
+					*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailablePaymentId)?;
+					Ok(current_id)
+				})?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			Payments::<T>::insert(
+				payment_id,
+				Payment {
+					payer: who.clone(),
+					merchant: merchant.clone(),
+					currency_id,
+					amount,
+					interval,
+					total_cap,
+					paid: Zero::zero(),
+					next_payment_at: now,
+				},
+			);
+			Self::schedule(now, payment_id);
+
+			Self::deposit_event(Event::PaymentApproved(
+				payment_id,
+				who,
+				merchant,
+				currency_id,
+				amount,
+				interval,
+				total_cap,
+			));
+			Ok(())
+		}
+
+		/// Cancel a payment, either by the payer or the merchant.
+		///
+		/// - `payment_id`: the payment to cancel.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_payment())]
+		#[transactional]
+		pub fn cancel_payment(origin: OriginFor<T>, payment_id: PaymentId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let payment = Self::payments(payment_id).ok_or(Error::<T>::PaymentNotFound)?;
+			ensure!(
+				payment.payer == who || payment.merchant == who,
+				Error::<T>::NoPermission
+			);
+
+			// the queued execution is skipped once the payment is removed
+			Payments::<T>::remove(payment_id);
+
+			Self::deposit_event(Event::PaymentCancelled(payment_id, who));
+			Ok(())
+		}
+
+		/// Stream `amount` to `recipient` over `duration` blocks from the
+		/// current block. The amount is reserved until withdrawn by the recipient.
+		///
+		/// - `recipient`: the account to stream to.
+		/// - `currency_id`: the currency to stream.
+		/// - `amount`: the total amount to stream.
+		/// - `duration`: the blocks until the amount is fully vested.
+		#[pallet::weight(<T as Config>::WeightInfo::create_stream())]
+		#[transactional]
+		pub fn create_stream(
+			origin: OriginFor<T>,
+			recipient: T::AccountId,
+			currency_id: CurrencyId,
+			#[pallet::compact] amount: Balance,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+			ensure!(!duration.is_zero(), Error::<T>::ZeroDuration);
+
+			let stream_id = NextStreamId::<T>::try_mutate(|id| -> sp_std::result::Result<StreamId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableStreamId)?;
+				Ok(current_id)
+			})?;
+
+			T::Currency::reserve(currency_id, &who, amount)?;
+
+			let start = frame_system::Pallet::<T>::block_number();
+			let end = start.saturating_add(duration);
+			Streams::<T>::insert(
+				stream_id,
+				Stream {
+					sender: who.clone(),
+					recipient: recipient.clone(),
+					currency_id,
+					amount,
+					withdrawn: Zero::zero(),
+					start,
+					end,
+				},
+			);
+
+			Self::deposit_event(Event::StreamCreated(
+				stream_id,
+				who,
+				recipient,
+				currency_id,
+				amount,
+				start,
+				end,
+			));
+			Ok(())
+		}
+
+		/// Withdraw the vested amount of a stream, by the recipient.
+		///
+		/// - `stream_id`: the stream to withdraw from.
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_from_stream())]
+		#[transactional]
+		pub fn withdraw_from_stream(origin: OriginFor<T>, stream_id: StreamId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut stream = Self::streams(stream_id).ok_or(Error::<T>::StreamNotFound)?;
+			ensure!(stream.recipient == who, Error::<T>::NoPermission);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let amount = stream.vested(now)?.saturating_sub(stream.withdrawn);
+			Self::pay_stream(&stream, amount)?;
+
+			stream.withdrawn = stream.withdrawn.saturating_add(amount);
+			if stream.withdrawn >= stream.amount {
+				Streams::<T>::remove(stream_id);
+			} else {
+				Streams::<T>::insert(stream_id, stream);
+			}
+
+			Self::deposit_event(Event::StreamWithdrawn(stream_id, amount));
+			Ok(())
+		}
+
+		/// Cancel a stream, either by the sender or the recipient. The vested
+		/// amount is paid to the recipient and the rest is unreserved.
+		///
+		/// - `stream_id`: the stream to cancel.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_stream())]
+		#[transactional]
+		pub fn cancel_stream(origin: OriginFor<T>, stream_id: StreamId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let stream = Self::streams(stream_id).ok_or(Error::<T>::StreamNotFound)?;
+			ensure!(
+				stream.sender == who || stream.recipient == who,
+				Error::<T>::NoPermission
+			);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let vested = stream.vested(now)?;
+			let paid = vested.saturating_sub(stream.withdrawn);
+			let refunded = stream.amount.saturating_sub(vested);
+			Self::pay_stream(&stream, paid)?;
+			T::Currency::unreserve(stream.currency_id, &stream.sender, refunded);
+			Streams::<T>::remove(stream_id);
+
+			Self::deposit_event(Event::StreamCancelled(stream_id, paid, refunded));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Queue the payment for execution at `at`, or at the first block not
+	/// executed yet if `at` has already been executed.
+	fn schedule(at: T::BlockNumber, payment_id: PaymentId) {
+		let next_queue_block = Self::next_queue_block();
+		if next_queue_block.is_zero() {
+			// start the queue from the first payment instead of the genesis block
+			NextQueueBlock::<T>::put(at);
+		}
+		PaymentQueue::<T>::insert(at.max(next_queue_block), payment_id, ());
+	}
+
+	/// Pay `amount` from the reserved balance of the sender to the recipient.
+	fn pay_stream(stream: &Stream<T::AccountId, T::BlockNumber>, amount: Balance) -> DispatchResult {
+		let remaining = T::Currency::repatriate_reserved(
+			stream.currency_id,
+			&stream.sender,
+			&stream.recipient,
+			amount,
+			BalanceStatus::Free,
+		)?;
+		ensure!(remaining.is_zero(), ArithmeticError::Underflow);
+		Ok(())
+	}
+
+	fn execute_payment(now: T::BlockNumber, payment_id: PaymentId) {
+		let mut payment = match Self::payments(payment_id) {
+			Some(payment) => payment,
+			None => return,
+		};
+
+		let amount = payment.amount.min(payment.total_cap.saturating_sub(payment.paid));
+		let next_block = now.saturating_add(One::one());
+		if T::Currency::transfer(payment.currency_id, &payment.payer, &payment.merchant, amount).is_ok() {
+			payment.paid = payment.paid.saturating_add(amount);
+			Self::deposit_event(Event::PaymentExecuted(payment_id, amount));
+
+			if payment.paid >= payment.total_cap {
+				Payments::<T>::remove(payment_id);
+				Self::deposit_event(Event::PaymentCompleted(payment_id));
+			} else {
+				// missed payments are caught up one per block
+				payment.next_payment_at = payment.next_payment_at.saturating_add(payment.interval);
+				Self::schedule(payment.next_payment_at.max(next_block), payment_id);
+				Payments::<T>::insert(payment_id, payment);
+			}
+		} else if now >= payment.next_payment_at.saturating_add(T::GracePeriod::get()) {
+			Payments::<T>::remove(payment_id);
+			Self::deposit_event(Event::PaymentExpired(payment_id));
+		} else {
+			Self::schedule(next_block, payment_id);
+			Self::deposit_event(Event::PaymentFailed(payment_id));
+		}
+	}
+}
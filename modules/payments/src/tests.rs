@@ -0,0 +1,309 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the payments module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok, traits::OnIdle};
+use mock::{Event, *};
+
+fn run_to_block(n: BlockNumber) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		PaymentsModule::on_idle(System::block_number(), Weight::max_value());
+	}
+}
+
+#[test]
+fn approve_payment_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PaymentsModule::approve_payment(Origin::signed(ALICE), BOB, AUSD, 0, 10, 100),
+			Error::<Runtime>::InvalidAmount
+		);
+		assert_noop!(
+			PaymentsModule::approve_payment(Origin::signed(ALICE), BOB, AUSD, 200, 10, 100),
+			Error::<Runtime>::InvalidAmount
+		);
+		assert_noop!(
+			PaymentsModule::approve_payment(Origin::signed(ALICE), BOB, AUSD, 50, 0, 100),
+			Error::<Runtime>::ZeroInterval
+		);
+
+		assert_ok!(PaymentsModule::approve_payment(
+			Origin::signed(ALICE),
+			BOB,
+			AUSD,
+			50,
+			10,
+			100
+		));
+		System::assert_last_event(Event::PaymentsModule(crate::Event::PaymentApproved(
+			0, ALICE, BOB, AUSD, 50, 10, 100,
+		)));
+		assert_eq!(
+			PaymentsModule::payments(0),
+			Some(Payment {
+				payer: ALICE,
+				merchant: BOB,
+				currency_id: AUSD,
+				amount: 50,
+				interval: 10,
+				total_cap: 100,
+				paid: 0,
+				next_payment_at: 1,
+			})
+		);
+		assert_eq!(PaymentsModule::payment_queue(1, 0), Some(()));
+		assert_eq!(PaymentsModule::next_payment_id(), 1);
+	});
+}
+
+#[test]
+fn execute_payment_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(PaymentsModule::approve_payment(
+			Origin::signed(ALICE),
+			BOB,
+			AUSD,
+			40,
+			10,
+			100
+		));
+
+		// the first payment is executed in the current block
+		PaymentsModule::on_idle(1, Weight::max_value());
+		System::assert_last_event(Event::PaymentsModule(crate::Event::PaymentExecuted(0, 40)));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 960);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1040);
+		assert_eq!(PaymentsModule::payments(0).unwrap().next_payment_at, 11);
+		assert_eq!(PaymentsModule::next_queue_block(), 2);
+
+		run_to_block(10);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 960);
+
+		run_to_block(11);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 920);
+
+		// the last payment is capped by the total cap
+		run_to_block(21);
+		System::assert_has_event(Event::PaymentsModule(crate::Event::PaymentExecuted(0, 20)));
+		System::assert_last_event(Event::PaymentsModule(crate::Event::PaymentCompleted(0)));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 900);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1100);
+		assert_eq!(PaymentsModule::payments(0), None);
+	});
+}
+
+#[test]
+fn cancel_payment_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PaymentsModule::cancel_payment(Origin::signed(ALICE), 0),
+			Error::<Runtime>::PaymentNotFound
+		);
+		assert_ok!(PaymentsModule::approve_payment(
+			Origin::signed(ALICE),
+			BOB,
+			AUSD,
+			40,
+			10,
+			100
+		));
+		assert_noop!(
+			PaymentsModule::cancel_payment(Origin::signed(CHARLIE), 0),
+			Error::<Runtime>::NoPermission
+		);
+
+		// the merchant can cancel too
+		assert_ok!(PaymentsModule::cancel_payment(Origin::signed(BOB), 0));
+		System::assert_last_event(Event::PaymentsModule(crate::Event::PaymentCancelled(0, BOB)));
+		assert_eq!(PaymentsModule::payments(0), None);
+
+		// the queued execution is skipped
+		PaymentsModule::on_idle(1, Weight::max_value());
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1000);
+		assert_eq!(PaymentsModule::payment_queue(1, 0), None);
+	});
+}
+
+#[test]
+fn insufficient_funds_is_retried_until_grace_period() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(PaymentsModule::approve_payment(
+			Origin::signed(CHARLIE),
+			BOB,
+			AUSD,
+			40,
+			10,
+			100
+		));
+
+		PaymentsModule::on_idle(1, Weight::max_value());
+		System::assert_last_event(Event::PaymentsModule(crate::Event::PaymentFailed(0)));
+		assert_eq!(PaymentsModule::payment_queue(2, 0), Some(()));
+
+		// funded within the grace period
+		assert_ok!(Tokens::transfer(Origin::signed(ALICE), CHARLIE, AUSD, 40));
+		run_to_block(2);
+		System::assert_last_event(Event::PaymentsModule(crate::Event::PaymentExecuted(0, 40)));
+		assert_eq!(Tokens::free_balance(AUSD, &CHARLIE), 0);
+		assert_eq!(PaymentsModule::payments(0).unwrap().next_payment_at, 11);
+
+		// expires after the grace period
+		run_to_block(13);
+		System::assert_last_event(Event::PaymentsModule(crate::Event::PaymentFailed(0)));
+		assert!(PaymentsModule::payments(0).is_some());
+		run_to_block(14);
+		System::assert_last_event(Event::PaymentsModule(crate::Event::PaymentExpired(0)));
+		assert_eq!(PaymentsModule::payments(0), None);
+	});
+}
+
+#[test]
+fn on_idle_respects_remaining_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(PaymentsModule::approve_payment(
+			Origin::signed(ALICE),
+			BOB,
+			AUSD,
+			40,
+			10,
+			100
+		));
+		assert_ok!(PaymentsModule::approve_payment(
+			Origin::signed(BOB),
+			ALICE,
+			AUSD,
+			40,
+			10,
+			100
+		));
+
+		assert_eq!(PaymentsModule::on_idle(1, 0), 0);
+
+		// only enough weight for one payment
+		let weight = <() as WeightInfo>::execute_payment();
+		PaymentsModule::on_idle(1, weight);
+		assert_eq!(PaymentsModule::next_queue_block(), 1);
+		assert_eq!(
+			Tokens::free_balance(AUSD, &ALICE) + Tokens::free_balance(AUSD, &BOB),
+			2000
+		);
+		assert_eq!(
+			PaymentsModule::payment_queue(1, 0).is_some() as u32 + PaymentsModule::payment_queue(1, 1).is_some() as u32,
+			1
+		);
+
+		PaymentsModule::on_idle(1, Weight::max_value());
+		assert_eq!(PaymentsModule::next_queue_block(), 2);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1000);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
+		assert_eq!(PaymentsModule::payments(0).unwrap().paid, 40);
+		assert_eq!(PaymentsModule::payments(1).unwrap().paid, 40);
+	});
+}
+
+#[test]
+fn create_stream_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PaymentsModule::create_stream(Origin::signed(ALICE), BOB, AUSD, 0, 10),
+			Error::<Runtime>::InvalidAmount
+		);
+		assert_noop!(
+			PaymentsModule::create_stream(Origin::signed(ALICE), BOB, AUSD, 100, 0),
+			Error::<Runtime>::ZeroDuration
+		);
+
+		assert_ok!(PaymentsModule::create_stream(Origin::signed(ALICE), BOB, AUSD, 100, 10));
+		System::assert_last_event(Event::PaymentsModule(crate::Event::StreamCreated(
+			0, ALICE, BOB, AUSD, 100, 1, 11,
+		)));
+		assert_eq!(
+			PaymentsModule::streams(0),
+			Some(Stream {
+				sender: ALICE,
+				recipient: BOB,
+				currency_id: AUSD,
+				amount: 100,
+				withdrawn: 0,
+				start: 1,
+				end: 11,
+			})
+		);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 900);
+		assert_eq!(Tokens::reserved_balance(AUSD, &ALICE), 100);
+	});
+}
+
+#[test]
+fn withdraw_from_stream_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(PaymentsModule::create_stream(Origin::signed(ALICE), BOB, AUSD, 100, 10));
+		assert_noop!(
+			PaymentsModule::withdraw_from_stream(Origin::signed(ALICE), 0),
+			Error::<Runtime>::NoPermission
+		);
+
+		System::set_block_number(4);
+		assert_ok!(PaymentsModule::withdraw_from_stream(Origin::signed(BOB), 0));
+		System::assert_last_event(Event::PaymentsModule(crate::Event::StreamWithdrawn(0, 30)));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1030);
+		assert_eq!(Tokens::reserved_balance(AUSD, &ALICE), 70);
+		assert_eq!(PaymentsModule::streams(0).unwrap().withdrawn, 30);
+
+		// fully vested after the end
+		System::set_block_number(20);
+		assert_ok!(PaymentsModule::withdraw_from_stream(Origin::signed(BOB), 0));
+		System::assert_last_event(Event::PaymentsModule(crate::Event::StreamWithdrawn(0, 70)));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1100);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 900);
+		assert_eq!(Tokens::reserved_balance(AUSD, &ALICE), 0);
+		assert_eq!(PaymentsModule::streams(0), None);
+	});
+}
+
+#[test]
+fn cancel_stream_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PaymentsModule::cancel_stream(Origin::signed(ALICE), 0),
+			Error::<Runtime>::StreamNotFound
+		);
+		assert_ok!(PaymentsModule::create_stream(Origin::signed(ALICE), BOB, AUSD, 100, 10));
+		assert_noop!(
+			PaymentsModule::cancel_stream(Origin::signed(CHARLIE), 0),
+			Error::<Runtime>::NoPermission
+		);
+
+		System::set_block_number(4);
+		assert_ok!(PaymentsModule::withdraw_from_stream(Origin::signed(BOB), 0));
+
+		// the vested amount is paid and the rest refunded
+		System::set_block_number(6);
+		assert_ok!(PaymentsModule::cancel_stream(Origin::signed(ALICE), 0));
+		System::assert_last_event(Event::PaymentsModule(crate::Event::StreamCancelled(0, 20, 50)));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1050);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 950);
+		assert_eq!(Tokens::reserved_balance(AUSD, &ALICE), 0);
+		assert_eq!(PaymentsModule::streams(0), None);
+	});
+}
@@ -25,18 +25,31 @@
 //!   - specify a fixed price for stable currency
 //!   - feed price in USD or related price bewteen two currencies
 //!   - lock/unlock the price data get from oracle
+//!   - configure the strategy to aggregate oracle prices of currency
+//!   - track the heartbeat of oracle operators, and ignore the feeds of
+//!     operators who missed too many feed windows
+//!   - freeze the price of currency if it changes too much in a short time,
+//!     until the cooldown has passed or it is unfrozen by governance
+//!   - cache the prices from remote oracle, which are used for the currencies
+//!     without local feeders until they are stale
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::{pallet_prelude::*, transactional};
+use frame_support::{pallet_prelude::*, traits::SortedMembers, transactional};
 use frame_system::pallet_prelude::*;
-use orml_traits::{DataFeeder, DataProvider, MultiCurrency};
+use orml_traits::{DataFeeder, DataProvider, GetByKey, MultiCurrency, OnNewData};
 use primitives::{Balance, CurrencyId};
 use sp_core::U256;
-use sp_runtime::{traits::CheckedMul, FixedPointNumber};
-use sp_std::{convert::TryInto, marker::PhantomData};
-use support::{CurrencyIdMapping, DEXManager, ExchangeRateProvider, LockablePrice, Price, PriceProvider};
+use sp_runtime::{
+	traits::{CheckedDiv, CheckedMul, Saturating, Zero},
+	FixedPointNumber, RuntimeDebug,
+};
+use sp_std::{convert::TryInto, marker::PhantomData, prelude::*};
+use support::{
+	CurrencyIdMapping, DEXManager, ExchangeRateProvider, LockablePrice, Price, PriceAggregationStrategy,
+	PriceCircuitBreaker, PriceProvider, Ratio,
+};
 
 mod mock;
 mod tests;
@@ -45,6 +58,15 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The heartbeat of an oracle operator.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, Default)]
+pub struct FeederHeartbeat<BlockNumber> {
+	/// The block number of the last feed.
+	pub last_feed_at: BlockNumber,
+	/// The number of consecutive feed windows without any feed.
+	pub missed_windows: u32,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -88,6 +110,42 @@ pub mod module {
 		/// Mapping between CurrencyId and ERC20 address so user can use Erc20.
 		type CurrencyIdMapping: CurrencyIdMapping;
 
+		/// The oracle operators whose heartbeat is tracked.
+		type OracleOperators: SortedMembers<Self::AccountId>;
+
+		/// The length in blocks of a feed window, oracle operators should feed
+		/// at least once in every window.
+		#[pallet::constant]
+		type FeedWindow: Get<Self::BlockNumber>;
+
+		/// The number of consecutive missed feed windows after which the feeds
+		/// of an oracle operator are ignored.
+		#[pallet::constant]
+		type MaxMissedFeedWindows: Get<u32>;
+
+		/// The maximum ratio the price of currency can change by within
+		/// `CircuitBreakerWindow`, otherwise the price is frozen.
+		#[pallet::constant]
+		type MaxPriceChangeRatio: Get<Ratio>;
+
+		/// The length in blocks of the window in which the price change is
+		/// checked against the reference price.
+		#[pallet::constant]
+		type CircuitBreakerWindow: Get<Self::BlockNumber>;
+
+		/// The number of blocks after which the frozen price is released.
+		#[pallet::constant]
+		type CircuitBreakerCooldown: Get<Self::BlockNumber>;
+
+		/// The origin which may update the prices from remote oracle, such as
+		/// the XCM origin of the oracle parachain.
+		type RemoteOracleOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The number of blocks after which the cached price from remote
+		/// oracle is stale.
+		#[pallet::constant]
+		type RemotePriceStaleness: Get<Self::BlockNumber>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -98,15 +156,35 @@ pub mod module {
 		AccessPriceFailed,
 		/// There's no locked price
 		NoLockedPrice,
+		/// The feeds of the oracle operator are not ignored
+		FeederNotIgnored,
+		/// The price of currency is not frozen
+		PriceNotFrozen,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId")]
 	pub enum Event<T: Config> {
 		/// Lock price. \[currency_id, locked_price\]
 		LockPrice(CurrencyId, Price),
 		/// Unlock price. \[currency_id\]
 		UnlockPrice(CurrencyId),
+		/// The oracle operator missed too many feed windows, its feeds are
+		/// ignored. \[feeder, missed_windows\]
+		FeederIgnored(T::AccountId, u32),
+		/// The feeds of the oracle operator are accepted again. \[feeder\]
+		FeederReinstated(T::AccountId),
+		/// The price aggregation strategy of currency updated.
+		/// \[currency_id, strategy\]
+		PriceAggregationStrategyUpdated(CurrencyId, PriceAggregationStrategy),
+		/// The price of currency changed too much and is frozen.
+		/// \[currency_id, frozen_price, new_price\]
+		PriceFrozen(CurrencyId, Price, Price),
+		/// The frozen price of currency is released. \[currency_id\]
+		PriceUnfrozen(CurrencyId),
+		/// The price from remote oracle updated. \[currency_id, price\]
+		RemotePriceUpdated(CurrencyId, Price),
 	}
 
 	/// Mapping from currency id to it's locked price
@@ -116,11 +194,79 @@ pub mod module {
 	#[pallet::getter(fn locked_price)]
 	pub type LockedPrice<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Price, OptionQuery>;
 
+	/// The heartbeat of oracle operators.
+	///
+	/// FeederHeartbeats: map AccountId => FeederHeartbeat
+	#[pallet::storage]
+	#[pallet::getter(fn feeder_heartbeats)]
+	pub type FeederHeartbeats<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, FeederHeartbeat<T::BlockNumber>, ValueQuery>;
+
+	/// The oracle operators whose feeds are ignored.
+	///
+	/// IgnoredFeeders: map AccountId => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn ignored_feeders)]
+	pub type IgnoredFeeders<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	/// The block number of the last feed of currency.
+	///
+	/// LastFeedAt: map CurrencyId => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn last_feed_at)]
+	pub type LastFeedAt<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, T::BlockNumber, OptionQuery>;
+
+	/// The strategy to aggregate the oracle prices of currency.
+	///
+	/// PriceAggregationStrategies: map CurrencyId => PriceAggregationStrategy
+	#[pallet::storage]
+	#[pallet::getter(fn price_aggregation_strategies)]
+	pub type PriceAggregationStrategies<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, PriceAggregationStrategy, ValueQuery>;
+
+	/// The reference price of currency for the circuit breaker, and the
+	/// block number it was recorded at.
+	///
+	/// ReferencePrices: map CurrencyId => Option<(Price, BlockNumber)>
+	#[pallet::storage]
+	#[pallet::getter(fn reference_prices)]
+	pub type ReferencePrices<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, (Price, T::BlockNumber), OptionQuery>;
+
+	/// The frozen price of currency, and the block number it was frozen at.
+	///
+	/// FrozenPrices: map CurrencyId => Option<(Price, BlockNumber)>
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_prices)]
+	pub type FrozenPrices<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, (Price, T::BlockNumber), OptionQuery>;
+
+	/// The cached price of currency from remote oracle, and the block number
+	/// it was updated at.
+	///
+	/// RemotePrices: map CurrencyId => Option<(Price, BlockNumber)>
+	#[pallet::storage]
+	#[pallet::getter(fn remote_prices)]
+	pub type RemotePrices<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, (Price, T::BlockNumber), OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Check the heartbeat of oracle operators at the end of every feed
+		/// window.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let feed_window = T::FeedWindow::get();
+			if now.is_zero() || feed_window.is_zero() || !(now % feed_window).is_zero() {
+				return 0;
+			}
+
+			let operators = T::OracleOperators::sorted_members();
+			for operator in operators.iter() {
+				Self::check_heartbeat(operator, now, feed_window);
+			}
+			T::WeightInfo::on_initialize(operators.len() as u32)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -149,10 +295,167 @@ pub mod module {
 			<Pallet<T> as LockablePrice<CurrencyId>>::unlock_price(currency_id)?;
 			Ok(())
 		}
+
+		/// Accept the feeds of the ignored oracle operator again.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `feeder`: the ignored oracle operator.
+		#[pallet::weight((T::WeightInfo::reinstate_feeder(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn reinstate_feeder(origin: OriginFor<T>, feeder: T::AccountId) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			IgnoredFeeders::<T>::take(&feeder).ok_or(Error::<T>::FeederNotIgnored)?;
+			FeederHeartbeats::<T>::insert(
+				&feeder,
+				FeederHeartbeat {
+					last_feed_at: frame_system::Pallet::<T>::block_number(),
+					missed_windows: Zero::zero(),
+				},
+			);
+			Self::deposit_event(Event::FeederReinstated(feeder));
+			Ok(())
+		}
+
+		/// Set the strategy to aggregate the oracle prices of currency.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `strategy`: the price aggregation strategy.
+		#[pallet::weight((T::WeightInfo::set_price_aggregation_strategy(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_price_aggregation_strategy(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			strategy: PriceAggregationStrategy,
+		) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			PriceAggregationStrategies::<T>::insert(currency_id, strategy);
+			Self::deposit_event(Event::PriceAggregationStrategyUpdated(currency_id, strategy));
+			Ok(())
+		}
+
+		/// Release the frozen price before the cooldown has passed.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		#[pallet::weight((T::WeightInfo::unfreeze_price(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn unfreeze_price(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			FrozenPrices::<T>::take(currency_id).ok_or(Error::<T>::PriceNotFrozen)?;
+			ReferencePrices::<T>::remove(currency_id);
+			Self::deposit_event(Event::PriceUnfrozen(currency_id));
+			Ok(())
+		}
+
+		/// Update the cached prices from remote oracle.
+		///
+		/// The dispatch origin of this call must be `RemoteOracleOrigin`.
+		///
+		/// - `prices`: the list of currency and its price.
+		#[pallet::weight((T::WeightInfo::update_remote_prices(prices.len() as u32), DispatchClass::Operational))]
+		#[transactional]
+		pub fn update_remote_prices(origin: OriginFor<T>, prices: Vec<(CurrencyId, Price)>) -> DispatchResult {
+			T::RemoteOracleOrigin::ensure_origin(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			for (currency_id, price) in prices {
+				RemotePrices::<T>::insert(currency_id, (price, now));
+				Self::deposit_event(Event::RemotePriceUpdated(currency_id, price));
+			}
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Count the feed window ending at `now` as missed if `operator` didn't
+	/// feed in it, and ignore its feeds after too many consecutive misses.
+	fn check_heartbeat(operator: &T::AccountId, now: T::BlockNumber, feed_window: T::BlockNumber) {
+		let missed_windows = FeederHeartbeats::<T>::mutate(operator, |heartbeat| {
+			if heartbeat.last_feed_at.saturating_add(feed_window) <= now {
+				heartbeat.missed_windows = heartbeat.missed_windows.saturating_add(1);
+			} else {
+				heartbeat.missed_windows = Zero::zero();
+			}
+			heartbeat.missed_windows
+		});
+
+		if missed_windows >= T::MaxMissedFeedWindows::get() && !IgnoredFeeders::<T>::contains_key(operator) {
+			IgnoredFeeders::<T>::insert(operator, ());
+			Self::deposit_event(Event::FeederIgnored(operator.clone(), missed_windows));
+		}
+	}
+
+	/// Freeze the price of `currency_id` if the price from oracle changed by
+	/// more than `MaxPriceChangeRatio` from the reference price within
+	/// `CircuitBreakerWindow`.
+	fn check_price_change(currency_id: CurrencyId, now: T::BlockNumber) {
+		if let Some((_, frozen_at)) = Self::frozen_prices(currency_id) {
+			if frozen_at.saturating_add(T::CircuitBreakerCooldown::get()) > now {
+				return;
+			}
+
+			// the cooldown has passed, release the frozen price
+			FrozenPrices::<T>::remove(currency_id);
+			ReferencePrices::<T>::remove(currency_id);
+			Self::deposit_event(Event::PriceUnfrozen(currency_id));
+		}
+
+		let price = match T::Source::get(&currency_id) {
+			Some(price) => price,
+			None => return,
+		};
+
+		match Self::reference_prices(currency_id) {
+			Some((reference_price, recorded_at))
+				if recorded_at.saturating_add(T::CircuitBreakerWindow::get()) > now =>
+			{
+				let change = if price > reference_price {
+					price.saturating_sub(reference_price)
+				} else {
+					reference_price.saturating_sub(price)
+				};
+				let exceeded = change
+					.checked_div(&reference_price)
+					.map_or(true, |change_ratio| change_ratio > T::MaxPriceChangeRatio::get());
+
+				if exceeded {
+					FrozenPrices::<T>::insert(currency_id, (reference_price, now));
+					Self::deposit_event(Event::PriceFrozen(currency_id, reference_price, price));
+				}
+			}
+			_ => ReferencePrices::<T>::insert(currency_id, (price, now)),
+		}
+	}
+
+	/// The frozen price of `currency_id`, `None` if it is not frozen or the
+	/// cooldown has passed.
+	fn frozen_price(currency_id: CurrencyId) -> Option<Price> {
+		let now = frame_system::Pallet::<T>::block_number();
+		Self::frozen_prices(currency_id)
+			.filter(|(_, frozen_at)| frozen_at.saturating_add(T::CircuitBreakerCooldown::get()) > now)
+			.map(|(price, _)| price)
+	}
+
+	/// The cached price of `currency_id` from remote oracle, `None` if it is
+	/// stale.
+	fn remote_price(currency_id: CurrencyId) -> Option<Price> {
+		let now = frame_system::Pallet::<T>::block_number();
+		Self::remote_prices(currency_id)
+			.filter(|(_, updated_at)| updated_at.saturating_add(T::RemotePriceStaleness::get()) > now)
+			.map(|(price, _)| price)
+	}
+
+	/// The number of blocks since the last feed of `currency_id`, `None` if
+	/// it has never been fed.
+	pub fn feed_freshness(currency_id: CurrencyId) -> Option<T::BlockNumber> {
+		Self::last_feed_at(currency_id)
+			.map(|last_feed_at| frame_system::Pallet::<T>::block_number().saturating_sub(last_feed_at))
+	}
+
 	/// access the exchange rate of specific currency to USD,
 	/// it always access the real-time price directly.
 	///
@@ -180,8 +483,11 @@ impl<T: Config> Pallet<T> {
 				}
 			};
 		} else {
-			// get real-time price from oracle
-			T::Source::get(&currency_id)
+			// get real-time price from oracle, unless it is frozen, and fallback to
+			// the price from remote oracle
+			Self::frozen_price(currency_id)
+				.or_else(|| T::Source::get(&currency_id))
+				.or_else(|| Self::remote_price(currency_id))
 		};
 
 		let maybe_adjustment_multiplier = 10u128.checked_pow(T::CurrencyIdMapping::decimals(currency_id)?.into());
@@ -212,6 +518,59 @@ impl<T: Config> LockablePrice<CurrencyId> for Pallet<T> {
 	}
 }
 
+impl<T: Config> OnNewData<T::AccountId, CurrencyId, Price> for Pallet<T> {
+	/// Record the heartbeat of the oracle operator and the feed of currency,
+	/// and check the price change of currency.
+	fn on_new_data(who: &T::AccountId, key: &CurrencyId, _value: &Price) {
+		let now = frame_system::Pallet::<T>::block_number();
+		FeederHeartbeats::<T>::insert(
+			who,
+			FeederHeartbeat {
+				last_feed_at: now,
+				missed_windows: Zero::zero(),
+			},
+		);
+		LastFeedAt::<T>::insert(key, now);
+		Self::check_price_change(*key, now);
+	}
+}
+
+impl<T: Config> PriceCircuitBreaker<CurrencyId> for Pallet<T> {
+	/// The price of liquid currency and dex share follows the prices of the
+	/// currencies it is derived from.
+	fn is_frozen(currency_id: CurrencyId) -> bool {
+		if currency_id == T::GetLiquidCurrencyId::get() {
+			Self::is_frozen(T::GetStakingCurrencyId::get())
+		} else if let CurrencyId::DexShare(symbol_0, symbol_1) = currency_id {
+			Self::is_frozen(symbol_0.into()) || Self::is_frozen(symbol_1.into())
+		} else {
+			Self::frozen_price(currency_id).is_some()
+		}
+	}
+}
+
+impl<T: Config> GetByKey<CurrencyId, PriceAggregationStrategy> for Pallet<T> {
+	fn get(currency_id: &CurrencyId) -> PriceAggregationStrategy {
+		Self::price_aggregation_strategies(currency_id)
+	}
+}
+
+/// The oracle operators whose feeds are not ignored, should be used as the
+/// members of oracle.
+pub struct ActiveOracleOperators<T>(PhantomData<T>);
+impl<T: Config> SortedMembers<T::AccountId> for ActiveOracleOperators<T> {
+	fn sorted_members() -> Vec<T::AccountId> {
+		T::OracleOperators::sorted_members()
+			.into_iter()
+			.filter(|who| !IgnoredFeeders::<T>::contains_key(who))
+			.collect()
+	}
+
+	fn contains(who: &T::AccountId) -> bool {
+		T::OracleOperators::contains(who) && !IgnoredFeeders::<T>::contains_key(who)
+	}
+}
+
 /// PriceProvider that always provider real-time prices from oracle
 pub struct RealTimePriceProvider<T>(PhantomData<T>);
 impl<T: Config> PriceProvider<CurrencyId> for RealTimePriceProvider<T> {
@@ -239,7 +598,10 @@ impl<T: Config> PriceProvider<CurrencyId> for LockedPriceProvider<T> {
 
 /// The fair price is determined by the external feed price and the size of the liquidity pool:
 /// https://blog.alphafinance.io/fair-lp-token-pricing/
-/// fair_price = (pool_0 * pool_1)^0.5 * (price_0 * price_1)^0.5 / total_shares * 2
+/// fair_price = 2 * (pool_0 * pool_1)^0.5 * (price_0 * price_1)^0.5 / total_shares
+///
+/// The product of the pools is kept by swaps, so the fair price can't be
+/// manipulated by imbalancing the liquidity pool.
 fn lp_token_fair_price(
 	total_shares: Balance,
 	pool_a: Balance,
@@ -255,8 +617,8 @@ fn lp_token_fair_price(
 				.saturating_mul(U256::from(price_b.into_inner()))
 				.integer_sqrt(),
 		)
+		.saturating_mul(U256::from(2))
 		.checked_div(U256::from(total_shares))
-		.and_then(|n| n.checked_mul(U256::from(2)))
 		.and_then(|r| TryInto::<u128>::try_into(r).ok())
 		.map(Price::from_inner)
 }
@@ -37,6 +37,9 @@ use support::{mocks::MockCurrencyIdMapping, ExchangeRate};
 pub type AccountId = u128;
 pub type BlockNumber = u64;
 
+pub const ALICE: AccountId = 2;
+pub const BOB: AccountId = 3;
+
 pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 pub const BTC: CurrencyId = CurrencyId::Token(TokenSymbol::RENBTC);
@@ -216,11 +219,24 @@ ord_parameter_types! {
 	pub const One: AccountId = 1;
 }
 
+pub struct MockOracleOperators;
+impl SortedMembers<AccountId> for MockOracleOperators {
+	fn sorted_members() -> Vec<AccountId> {
+		vec![ALICE, BOB]
+	}
+}
+
 parameter_types! {
 	pub const GetStableCurrencyId: CurrencyId = AUSD;
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
 	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
 	pub StableCurrencyFixedPrice: Price = Price::one();
+	pub const FeedWindow: BlockNumber = 10;
+	pub const MaxMissedFeedWindows: u32 = 2;
+	pub MaxPriceChangeRatio: Ratio = Ratio::saturating_from_rational(50, 100);
+	pub const CircuitBreakerWindow: BlockNumber = 5;
+	pub const CircuitBreakerCooldown: BlockNumber = 20;
+	pub const RemotePriceStaleness: BlockNumber = 10;
 }
 
 impl Config for Runtime {
@@ -235,6 +251,14 @@ impl Config for Runtime {
 	type DEX = MockDEX;
 	type Currency = Tokens;
 	type CurrencyIdMapping = MockCurrencyIdMapping;
+	type OracleOperators = MockOracleOperators;
+	type FeedWindow = FeedWindow;
+	type MaxMissedFeedWindows = MaxMissedFeedWindows;
+	type MaxPriceChangeRatio = MaxPriceChangeRatio;
+	type CircuitBreakerWindow = CircuitBreakerWindow;
+	type CircuitBreakerCooldown = CircuitBreakerCooldown;
+	type RemoteOracleOrigin = EnsureSignedBy<One, AccountId>;
+	type RemotePriceStaleness = RemotePriceStaleness;
 	type WeightInfo = ();
 }
 
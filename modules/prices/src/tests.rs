@@ -27,6 +27,7 @@ use sp_runtime::{
 	traits::{BadOrigin, Bounded},
 	FixedPointNumber,
 };
+use support::Ratio;
 
 #[test]
 fn lp_token_fair_price_works() {
@@ -156,6 +157,24 @@ fn access_price_of_liquid_currency() {
 	});
 }
 
+#[test]
+fn lp_token_fair_price_not_affected_by_pool_imbalance() {
+	let price_0 = Price::saturating_from_integer(1);
+	let price_1 = Price::saturating_from_integer(100);
+	let fair_price = lp_token_fair_price(1000, 100000, 1000, price_0, price_1);
+	assert_eq!(fair_price, Some(Price::saturating_from_integer(200)));
+
+	// swap on the pool keeps the product of the pools, but moves the spot price
+	assert_eq!(lp_token_fair_price(1000, 400000, 250, price_0, price_1), fair_price);
+	assert_eq!(lp_token_fair_price(1000, 25000, 4000, price_0, price_1), fair_price);
+
+	// the fair price is only truncated once
+	assert_eq!(
+		lp_token_fair_price(2, 1, 1, Price::from_inner(9), Price::from_inner(1)),
+		Some(Price::from_inner(3))
+	);
+}
+
 #[test]
 fn access_price_of_dex_share_currency() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -307,6 +326,298 @@ fn unlock_price_work() {
 	});
 }
 
+#[test]
+fn on_new_data_record_heartbeat() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(5);
+		assert_eq!(PricesModule::feed_freshness(BTC), None);
+
+		PricesModule::on_new_data(&ALICE, &BTC, &Price::saturating_from_integer(1));
+		assert_eq!(
+			PricesModule::feeder_heartbeats(ALICE),
+			FeederHeartbeat {
+				last_feed_at: 5,
+				missed_windows: 0,
+			}
+		);
+		assert_eq!(PricesModule::last_feed_at(BTC), Some(5));
+		assert_eq!(PricesModule::feed_freshness(BTC), Some(0));
+
+		System::set_block_number(8);
+		assert_eq!(PricesModule::feed_freshness(BTC), Some(3));
+		assert_eq!(PricesModule::feed_freshness(DOT), None);
+	});
+}
+
+#[test]
+fn ignore_feeder_after_missed_feed_windows() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(5);
+		PricesModule::on_new_data(&ALICE, &BTC, &Price::saturating_from_integer(1));
+		PricesModule::on_new_data(&BOB, &BTC, &Price::saturating_from_integer(1));
+
+		// not the end of a feed window
+		PricesModule::on_initialize(9);
+		assert_eq!(PricesModule::feeder_heartbeats(BOB).missed_windows, 0);
+
+		System::set_block_number(10);
+		PricesModule::on_initialize(10);
+		assert_eq!(PricesModule::feeder_heartbeats(ALICE).missed_windows, 0);
+		assert_eq!(PricesModule::feeder_heartbeats(BOB).missed_windows, 0);
+
+		System::set_block_number(15);
+		PricesModule::on_new_data(&ALICE, &DOT, &Price::saturating_from_integer(1));
+
+		System::set_block_number(20);
+		PricesModule::on_initialize(20);
+		assert_eq!(PricesModule::feeder_heartbeats(ALICE).missed_windows, 0);
+		assert_eq!(PricesModule::feeder_heartbeats(BOB).missed_windows, 1);
+		assert_eq!(PricesModule::ignored_feeders(BOB), None);
+		assert!(ActiveOracleOperators::<Runtime>::contains(&BOB));
+
+		System::set_block_number(30);
+		PricesModule::on_initialize(30);
+		assert_eq!(PricesModule::feeder_heartbeats(ALICE).missed_windows, 1);
+		assert_eq!(PricesModule::feeder_heartbeats(BOB).missed_windows, 2);
+		assert_eq!(PricesModule::ignored_feeders(ALICE), None);
+		assert_eq!(PricesModule::ignored_feeders(BOB), Some(()));
+		System::assert_last_event(Event::PricesModule(crate::Event::FeederIgnored(BOB, 2)));
+		assert!(ActiveOracleOperators::<Runtime>::contains(&ALICE));
+		assert!(!ActiveOracleOperators::<Runtime>::contains(&BOB));
+		assert_eq!(ActiveOracleOperators::<Runtime>::sorted_members(), vec![ALICE]);
+
+		// the event is only emitted once
+		System::reset_events();
+		System::set_block_number(40);
+		PricesModule::on_initialize(40);
+		assert_eq!(PricesModule::feeder_heartbeats(BOB).missed_windows, 3);
+		assert_eq!(PricesModule::ignored_feeders(ALICE), Some(()));
+		System::assert_last_event(Event::PricesModule(crate::Event::FeederIgnored(ALICE, 2)));
+		assert_eq!(System::events().len(), 1);
+	});
+}
+
+#[test]
+fn reinstate_feeder_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(10);
+		PricesModule::on_initialize(10);
+		System::set_block_number(20);
+		PricesModule::on_initialize(20);
+		assert_eq!(PricesModule::ignored_feeders(BOB), Some(()));
+
+		assert_noop!(PricesModule::reinstate_feeder(Origin::signed(5), BOB), BadOrigin);
+		assert_noop!(
+			PricesModule::reinstate_feeder(Origin::signed(1), 5),
+			Error::<Runtime>::FeederNotIgnored
+		);
+
+		System::set_block_number(25);
+		assert_ok!(PricesModule::reinstate_feeder(Origin::signed(1), BOB));
+		System::assert_last_event(Event::PricesModule(crate::Event::FeederReinstated(BOB)));
+		assert_eq!(PricesModule::ignored_feeders(BOB), None);
+		assert_eq!(
+			PricesModule::feeder_heartbeats(BOB),
+			FeederHeartbeat {
+				last_feed_at: 25,
+				missed_windows: 0,
+			}
+		);
+		assert!(ActiveOracleOperators::<Runtime>::contains(&BOB));
+
+		// the feed window which the feeder is reinstated in is not missed
+		System::set_block_number(30);
+		PricesModule::on_initialize(30);
+		assert_eq!(PricesModule::feeder_heartbeats(BOB).missed_windows, 0);
+	});
+}
+
+#[test]
+fn set_price_aggregation_strategy_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let strategy = PriceAggregationStrategy::MedianWithOutlierRejection {
+			max_deviation: Ratio::saturating_from_rational(5, 100),
+		};
+
+		assert_noop!(
+			PricesModule::set_price_aggregation_strategy(Origin::signed(5), BTC, strategy),
+			BadOrigin
+		);
+
+		assert_eq!(
+			<PricesModule as GetByKey<_, _>>::get(&BTC),
+			PriceAggregationStrategy::Median
+		);
+		assert_ok!(PricesModule::set_price_aggregation_strategy(
+			Origin::signed(1),
+			BTC,
+			strategy
+		));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceAggregationStrategyUpdated(
+			BTC, strategy,
+		)));
+		assert_eq!(PricesModule::price_aggregation_strategies(BTC), strategy);
+		assert_eq!(<PricesModule as GetByKey<_, _>>::get(&BTC), strategy);
+		assert_eq!(
+			<PricesModule as GetByKey<_, _>>::get(&DOT),
+			PriceAggregationStrategy::Median
+		);
+	});
+}
+
+#[test]
+fn freeze_price_when_price_changes_too_much() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		PricesModule::on_new_data(&ALICE, &BTC, &Price::saturating_from_integer(1));
+		PricesModule::on_new_data(&ALICE, &DOT, &Price::saturating_from_integer(1));
+		assert_eq!(
+			PricesModule::reference_prices(BTC),
+			Some((Price::saturating_from_integer(50000), 1))
+		);
+		assert_eq!(
+			PricesModule::reference_prices(DOT),
+			Some((Price::saturating_from_integer(100), 1))
+		);
+
+		// BTC price changes by 20%, DOT price changes by 90%
+		mock_oracle_update();
+		System::set_block_number(3);
+		PricesModule::on_new_data(&ALICE, &BTC, &Price::saturating_from_integer(1));
+		assert_eq!(PricesModule::frozen_prices(BTC), None);
+		PricesModule::on_new_data(&ALICE, &DOT, &Price::saturating_from_integer(1));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceFrozen(
+			DOT,
+			Price::saturating_from_integer(100),
+			Price::saturating_from_integer(10),
+		)));
+		assert_eq!(
+			PricesModule::frozen_prices(DOT),
+			Some((Price::saturating_from_integer(100), 3))
+		);
+		assert_eq!(
+			PricesModule::access_price(DOT),
+			Some(Price::saturating_from_integer(10000000000u128))
+		);
+		assert!(!PricesModule::is_frozen(BTC));
+		assert!(PricesModule::is_frozen(DOT));
+		assert!(PricesModule::is_frozen(LDOT));
+		assert!(PricesModule::is_frozen(LP_AUSD_DOT));
+
+		// the frozen price is released after the cooldown
+		System::set_block_number(23);
+		assert!(!PricesModule::is_frozen(DOT));
+		assert_eq!(
+			PricesModule::access_price(DOT),
+			Some(Price::saturating_from_integer(1000000000u128))
+		);
+		PricesModule::on_new_data(&ALICE, &DOT, &Price::saturating_from_integer(1));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceUnfrozen(DOT)));
+		assert_eq!(PricesModule::frozen_prices(DOT), None);
+		assert_eq!(
+			PricesModule::reference_prices(DOT),
+			Some((Price::saturating_from_integer(10), 23))
+		);
+	});
+}
+
+#[test]
+fn renew_reference_price_after_circuit_breaker_window() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		PricesModule::on_new_data(&ALICE, &DOT, &Price::saturating_from_integer(1));
+
+		mock_oracle_update();
+		System::set_block_number(6);
+		PricesModule::on_new_data(&ALICE, &DOT, &Price::saturating_from_integer(1));
+		assert_eq!(PricesModule::frozen_prices(DOT), None);
+		assert_eq!(
+			PricesModule::reference_prices(DOT),
+			Some((Price::saturating_from_integer(10), 6))
+		);
+	});
+}
+
+#[test]
+fn unfreeze_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(PricesModule::unfreeze_price(Origin::signed(5), DOT), BadOrigin);
+		assert_noop!(
+			PricesModule::unfreeze_price(Origin::signed(1), DOT),
+			Error::<Runtime>::PriceNotFrozen
+		);
+
+		PricesModule::on_new_data(&ALICE, &DOT, &Price::saturating_from_integer(1));
+		mock_oracle_update();
+		PricesModule::on_new_data(&ALICE, &DOT, &Price::saturating_from_integer(1));
+		assert!(PricesModule::is_frozen(DOT));
+
+		assert_ok!(PricesModule::unfreeze_price(Origin::signed(1), DOT));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceUnfrozen(DOT)));
+		assert_eq!(PricesModule::frozen_prices(DOT), None);
+		assert_eq!(PricesModule::reference_prices(DOT), None);
+		assert!(!PricesModule::is_frozen(DOT));
+	});
+}
+
+#[test]
+fn update_remote_prices_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			PricesModule::update_remote_prices(Origin::signed(5), vec![(KSM, Price::saturating_from_integer(300))]),
+			BadOrigin
+		);
+
+		assert_eq!(PricesModule::access_price(KSM), None);
+		assert_ok!(PricesModule::update_remote_prices(
+			Origin::signed(1),
+			vec![(KSM, Price::saturating_from_integer(300))]
+		));
+		System::assert_last_event(Event::PricesModule(crate::Event::RemotePriceUpdated(
+			KSM,
+			Price::saturating_from_integer(300),
+		)));
+		assert_eq!(
+			PricesModule::remote_prices(KSM),
+			Some((Price::saturating_from_integer(300), 1))
+		);
+		assert_eq!(
+			PricesModule::access_price(KSM),
+			Some(Price::saturating_from_integer(300000000u128))
+		); // 300 USD, right shift the decimal point (18-12) places
+
+		// the price from local oracle takes precedence
+		mock_oracle_update();
+		assert_eq!(
+			PricesModule::access_price(KSM),
+			Some(Price::saturating_from_integer(200000000u128))
+		);
+	});
+}
+
+#[test]
+fn remote_price_is_stale_after_staleness() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PricesModule::update_remote_prices(
+			Origin::signed(1),
+			vec![(KSM, Price::saturating_from_integer(300))]
+		));
+
+		System::set_block_number(10);
+		assert_eq!(
+			PricesModule::access_price(KSM),
+			Some(Price::saturating_from_integer(300000000u128))
+		);
+
+		System::set_block_number(11);
+		assert_eq!(PricesModule::access_price(KSM), None);
+	});
+}
+
 #[test]
 fn price_providers_work() {
 	ExtBuilder::default().build().execute_with(|| {
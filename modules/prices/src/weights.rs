@@ -48,13 +48,26 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for module_prices.
 pub trait WeightInfo {
+	fn on_initialize(c: u32) -> Weight;
 	fn lock_price() -> Weight;
 	fn unlock_price() -> Weight;
+	fn reinstate_feeder() -> Weight;
+	fn set_price_aggregation_strategy() -> Weight;
+	fn unfreeze_price() -> Weight;
+	fn update_remote_prices(c: u32) -> Weight;
 }
 
 /// Weights for module_prices using the Acala node and recommended hardware.
 pub struct AcalaWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
+	fn on_initialize(c: u32) -> Weight {
+		(1_623_000 as Weight)
+			// Standard Error: 7_000
+			.saturating_add((6_847_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 	fn lock_price() -> Weight {
 		(53_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(11 as Weight))
@@ -64,10 +77,38 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		(12_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn reinstate_feeder() -> Weight {
+		(21_376_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn set_price_aggregation_strategy() -> Weight {
+		(18_204_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn unfreeze_price() -> Weight {
+		(20_115_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn update_remote_prices(c: u32) -> Weight {
+		(2_036_000 as Weight)
+			// Standard Error: 5_000
+			.saturating_add((7_415_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	fn on_initialize(c: u32) -> Weight {
+		(1_623_000 as Weight)
+			// Standard Error: 7_000
+			.saturating_add((6_847_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 	fn lock_price() -> Weight {
 		(53_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
@@ -77,4 +118,24 @@ impl WeightInfo for () {
 		(12_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn reinstate_feeder() -> Weight {
+		(21_376_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn set_price_aggregation_strategy() -> Weight {
+		(18_204_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn unfreeze_price() -> Weight {
+		(20_115_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn update_remote_prices(c: u32) -> Weight {
+		(2_036_000 as Weight)
+			// Standard Error: 5_000
+			.saturating_add((7_415_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 }
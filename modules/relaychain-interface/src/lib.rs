@@ -0,0 +1,202 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Relay Chain Interface Module
+//!
+//! ## Overview
+//!
+//! Builds the relay chain calls that Acala sends via XCM `Transact`, such as
+//! staking bond/unbond/withdraw, utility batches and proxy calls. The pallet
+//! and call indices differ between relay chains, so the encoding is selected
+//! by the `RelayChainNetwork` type the runtime configures the builder with.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Compact, Encode, Output};
+use cumulus_primitives_core::ParaId;
+use frame_support::{traits::Get, weights::Weight, RuntimeDebug};
+use sp_runtime::MultiAddress;
+use sp_std::{marker::PhantomData, prelude::*};
+use xcm::v0::{Junction, MultiAsset, MultiLocation, Order, OriginKind, Xcm};
+
+mod tests;
+
+/// A call of the relay chain, already SCALE encoded.
+///
+/// It encodes to the raw call bytes without a length prefix, so it can be
+/// nested in other relay chain calls like the relay chain `Call` type itself.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct RelayChainCall(Vec<u8>);
+
+impl RelayChainCall {
+	/// The encoded relay chain call.
+	pub fn as_bytes(&self) -> &[u8] {
+		&self.0
+	}
+}
+
+impl Encode for RelayChainCall {
+	fn size_hint(&self) -> usize {
+		self.0.len()
+	}
+
+	fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+		dest.write(&self.0)
+	}
+}
+
+/// The pallet indices of a relay chain runtime.
+pub trait RelayChainNetwork {
+	const BALANCES: u8;
+	const STAKING: u8;
+	const UTILITY: u8;
+	const PROXY: u8;
+}
+
+/// The Kusama relay chain.
+pub struct Kusama;
+impl RelayChainNetwork for Kusama {
+	const BALANCES: u8 = 4;
+	const STAKING: u8 = 6;
+	const UTILITY: u8 = 24;
+	const PROXY: u8 = 30;
+}
+
+/// The Polkadot relay chain.
+pub struct Polkadot;
+impl RelayChainNetwork for Polkadot {
+	const BALANCES: u8 = 5;
+	const STAKING: u8 = 7;
+	const UTILITY: u8 = 26;
+	const PROXY: u8 = 29;
+}
+
+/// The Westend relay chain.
+pub struct Westend;
+impl RelayChainNetwork for Westend {
+	const BALANCES: u8 = 4;
+	const STAKING: u8 = 6;
+	const UTILITY: u8 = 16;
+	const PROXY: u8 = 22;
+}
+
+/// Builds relay chain calls and the XCM messages dispatching them.
+pub trait RelayChainCallBuilder<AccountId, Balance> {
+	/// `balances::transfer_keep_alive(to, amount)`
+	fn balances_transfer_keep_alive(to: AccountId, amount: Balance) -> RelayChainCall;
+
+	/// `staking::bond_extra(amount)`
+	fn staking_bond_extra(amount: Balance) -> RelayChainCall;
+
+	/// `staking::unbond(amount)`
+	fn staking_unbond(amount: Balance) -> RelayChainCall;
+
+	/// `staking::withdraw_unbonded(num_slashing_spans)`
+	fn staking_withdraw_unbonded(num_slashing_spans: u32) -> RelayChainCall;
+
+	/// `utility::batch_all(calls)`
+	fn utility_batch_all(calls: Vec<RelayChainCall>) -> RelayChainCall;
+
+	/// `utility::as_derivative(index, call)`
+	fn utility_as_derivative(index: u16, call: RelayChainCall) -> RelayChainCall;
+
+	/// `proxy::proxy(real, None, call)`
+	fn proxy(real: AccountId, call: RelayChainCall) -> RelayChainCall;
+
+	/// Wrap `call` into an XCM message which withdraws `fee` relay chain token
+	/// from the parachain sovereign account to buy `weight`, transacts `call`
+	/// as the sovereign account and refunds the unused fee.
+	fn finalize_call_into_xcm_message(call: RelayChainCall, fee: Balance, weight: Weight) -> Xcm<()>;
+}
+
+/// The `RelayChainCallBuilder` for the relay chain `Network`, sending from
+/// the parachain `ParachainId`.
+pub struct CallBuilder<Network, ParachainId>(PhantomData<(Network, ParachainId)>);
+
+impl<AccountId, Balance, Network, ParachainId> RelayChainCallBuilder<AccountId, Balance>
+	for CallBuilder<Network, ParachainId>
+where
+	AccountId: Encode,
+	Balance: Into<u128>,
+	Network: RelayChainNetwork,
+	ParachainId: Get<ParaId>,
+{
+	fn balances_transfer_keep_alive(to: AccountId, amount: Balance) -> RelayChainCall {
+		RelayChainCall(
+			(
+				Network::BALANCES,
+				3u8,
+				MultiAddress::<AccountId, u32>::Id(to),
+				Compact(amount.into()),
+			)
+				.encode(),
+		)
+	}
+
+	fn staking_bond_extra(amount: Balance) -> RelayChainCall {
+		RelayChainCall((Network::STAKING, 1u8, Compact(amount.into())).encode())
+	}
+
+	fn staking_unbond(amount: Balance) -> RelayChainCall {
+		RelayChainCall((Network::STAKING, 2u8, Compact(amount.into())).encode())
+	}
+
+	fn staking_withdraw_unbonded(num_slashing_spans: u32) -> RelayChainCall {
+		RelayChainCall((Network::STAKING, 3u8, num_slashing_spans).encode())
+	}
+
+	fn utility_batch_all(calls: Vec<RelayChainCall>) -> RelayChainCall {
+		RelayChainCall((Network::UTILITY, 2u8, calls).encode())
+	}
+
+	fn utility_as_derivative(index: u16, call: RelayChainCall) -> RelayChainCall {
+		RelayChainCall((Network::UTILITY, 1u8, index, call).encode())
+	}
+
+	fn proxy(real: AccountId, call: RelayChainCall) -> RelayChainCall {
+		// `force_proxy_type` is `None`, any proxy type of the sender is allowed
+		RelayChainCall((Network::PROXY, 0u8, real, None::<u8>, call).encode())
+	}
+
+	fn finalize_call_into_xcm_message(call: RelayChainCall, fee: Balance, weight: Weight) -> Xcm<()> {
+		let fee: u128 = fee.into();
+		Xcm::WithdrawAsset {
+			assets: vec![MultiAsset::ConcreteFungible {
+				id: MultiLocation::Null,
+				amount: fee,
+			}],
+			effects: vec![
+				Order::BuyExecution {
+					fees: MultiAsset::All,
+					weight,
+					debt: 0,
+					halt_on_error: true,
+					xcm: vec![Xcm::Transact {
+						origin_type: OriginKind::SovereignAccount,
+						require_weight_at_most: weight,
+						call: call.encode().into(),
+					}],
+				},
+				Order::DepositAsset {
+					assets: vec![MultiAsset::All],
+					dest: MultiLocation::X1(Junction::Parachain(ParachainId::get().into())),
+				},
+			],
+		}
+	}
+}
@@ -0,0 +1,118 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the relay chain interface module.
+
+#![cfg(test)]
+
+use super::*;
+use sp_runtime::AccountId32;
+
+pub struct ParachainId;
+impl Get<ParaId> for ParachainId {
+	fn get() -> ParaId {
+		2000.into()
+	}
+}
+
+type KusamaBuilder = CallBuilder<Kusama, ParachainId>;
+type PolkadotBuilder = CallBuilder<Polkadot, ParachainId>;
+type WestendBuilder = CallBuilder<Westend, ParachainId>;
+
+const ALICE: AccountId32 = AccountId32::new([1u8; 32]);
+
+#[test]
+fn staking_calls_encoding_works() {
+	assert_eq!(
+		<KusamaBuilder as RelayChainCallBuilder<AccountId32, u128>>::staking_bond_extra(1).as_bytes(),
+		&[6, 1, 4]
+	);
+	assert_eq!(
+		<PolkadotBuilder as RelayChainCallBuilder<AccountId32, u128>>::staking_unbond(1).as_bytes(),
+		&[7, 2, 4]
+	);
+	assert_eq!(
+		<WestendBuilder as RelayChainCallBuilder<AccountId32, u128>>::staking_withdraw_unbonded(5).as_bytes(),
+		&[6, 3, 5, 0, 0, 0]
+	);
+}
+
+#[test]
+fn balances_transfer_keep_alive_encoding_works() {
+	let mut expected = vec![5, 3, 0];
+	expected.extend_from_slice(&[1u8; 32]);
+	expected.push(4);
+	assert_eq!(
+		<PolkadotBuilder as RelayChainCallBuilder<AccountId32, u128>>::balances_transfer_keep_alive(ALICE, 1)
+			.as_bytes(),
+		&expected[..]
+	);
+}
+
+#[test]
+fn nested_calls_encoding_works() {
+	let bond = <KusamaBuilder as RelayChainCallBuilder<AccountId32, u128>>::staking_bond_extra(1);
+	let unbond = <KusamaBuilder as RelayChainCallBuilder<AccountId32, u128>>::staking_unbond(1);
+
+	let batch =
+		<KusamaBuilder as RelayChainCallBuilder<AccountId32, u128>>::utility_batch_all(vec![bond.clone(), unbond]);
+	assert_eq!(batch.as_bytes(), &[24, 2, 8, 6, 1, 4, 6, 2, 4]);
+
+	let derivative =
+		<KusamaBuilder as RelayChainCallBuilder<AccountId32, u128>>::utility_as_derivative(1, bond.clone());
+	assert_eq!(derivative.as_bytes(), &[24, 1, 1, 0, 6, 1, 4]);
+
+	let mut expected = vec![30, 0];
+	expected.extend_from_slice(&[1u8; 32]);
+	expected.extend_from_slice(&[0, 6, 1, 4]);
+	assert_eq!(
+		<KusamaBuilder as RelayChainCallBuilder<AccountId32, u128>>::proxy(ALICE, bond).as_bytes(),
+		&expected[..]
+	);
+}
+
+#[test]
+fn finalize_call_into_xcm_message_works() {
+	let call = <KusamaBuilder as RelayChainCallBuilder<AccountId32, u128>>::staking_bond_extra(1);
+	assert_eq!(
+		<KusamaBuilder as RelayChainCallBuilder<AccountId32, u128>>::finalize_call_into_xcm_message(call, 100, 1_000),
+		Xcm::WithdrawAsset {
+			assets: vec![MultiAsset::ConcreteFungible {
+				id: MultiLocation::Null,
+				amount: 100,
+			}],
+			effects: vec![
+				Order::BuyExecution {
+					fees: MultiAsset::All,
+					weight: 1_000,
+					debt: 0,
+					halt_on_error: true,
+					xcm: vec![Xcm::Transact {
+						origin_type: OriginKind::SovereignAccount,
+						require_weight_at_most: 1_000,
+						call: vec![6, 1, 4].into(),
+					}],
+				},
+				Order::DepositAsset {
+					assets: vec![MultiAsset::All],
+					dest: MultiLocation::X1(Junction::Parachain(2000)),
+				},
+			],
+		}
+	);
+}
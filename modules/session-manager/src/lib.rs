@@ -20,6 +20,9 @@
 //!
 //! The module implement the `ShouldEndSession` and `EstimateNextSessionRotation`
 //! trait to handle the change of session time.
+//!
+//! Multiple session duration changes can be queued, each taking effect at a later
+//! session than the previously queued ones.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -65,6 +68,8 @@ pub mod module {
 		InvalidDuration,
 		/// Failed to estimate next session.
 		EstimateNextSessionFailed,
+		/// The session is not after the sessions of the queued changes.
+		OverlappedSchedule,
 	}
 
 	#[pallet::event]
@@ -144,7 +149,8 @@ pub mod module {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Schedule a new session duration in the specified session index.
+		/// Schedule a new session duration in the specified session index, which
+		/// must be after the sessions of the queued changes.
 		///
 		/// - `start_session`: the session index that the new change become effective.
 		/// - `duration`:  new session duration.
@@ -179,6 +185,24 @@ impl<T: Config> Pallet<T> {
 		ensure!(start_session > current_session, Error::<T>::InvalidSession);
 		ensure!(!duration.is_zero(), Error::<T>::InvalidDuration);
 
+		let last_change = SessionDurationChanges::<T>::iter().max_by_key(|(_, (session, _))| *session);
+		if let Some((last_block_number, (last_session, last_duration))) = last_change {
+			ensure!(start_session > last_session, Error::<T>::OverlappedSchedule);
+
+			if duration == last_duration {
+				return Ok(block_number);
+			}
+
+			// the queued change starts the session `last_session` at `last_block_number`.
+			let target_block_number = Into::<T::BlockNumber>::into(start_session.saturating_sub(last_session))
+				.saturating_mul(last_duration)
+				.saturating_add(last_block_number);
+
+			SessionDurationChanges::<T>::insert(target_block_number, (start_session, duration));
+
+			return Ok(target_block_number);
+		}
+
 		if duration == Self::session_duration() {
 			return Ok(block_number);
 		}
@@ -195,6 +219,15 @@ impl<T: Config> Pallet<T> {
 
 		Ok(target_block_number)
 	}
+
+	/// The session duration in effect at `block_number`, taking the queued
+	/// changes into account.
+	pub fn session_duration_at(block_number: T::BlockNumber) -> T::BlockNumber {
+		SessionDurationChanges::<T>::iter()
+			.filter(|(target_block_number, _)| *target_block_number <= block_number)
+			.max_by_key(|(target_block_number, _)| *target_block_number)
+			.map_or_else(Self::session_duration, |(_, (_, duration))| duration)
+	}
 }
 
 impl<T: Config> ShouldEndSession<T::BlockNumber> for Pallet<T> {
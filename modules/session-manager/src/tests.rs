@@ -53,6 +53,50 @@ fn schedule_session_duration_work() {
 	});
 }
 
+#[test]
+fn schedule_multiple_session_durations_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Session::session_index(), 0);
+		assert_eq!(SessionManager::session_duration(), 10);
+
+		assert_ok!(SessionManager::schedule_session_duration(Origin::root(), 2, 20));
+		System::assert_last_event(Event::SessionManager(crate::Event::ScheduledSessionDuration(20, 2, 20)));
+
+		assert_noop!(
+			SessionManager::schedule_session_duration(Origin::root(), 1, 5),
+			Error::<Runtime>::OverlappedSchedule
+		);
+		assert_noop!(
+			SessionManager::schedule_session_duration(Origin::root(), 2, 30),
+			Error::<Runtime>::OverlappedSchedule
+		);
+
+		assert_ok!(SessionManager::schedule_session_duration(Origin::root(), 4, 5));
+		System::assert_last_event(Event::SessionManager(crate::Event::ScheduledSessionDuration(60, 4, 5)));
+
+		// same duration as the last queued change
+		assert_ok!(SessionManager::schedule_session_duration(Origin::root(), 5, 5));
+		assert_eq!(SessionDurationChanges::<Runtime>::iter().count(), 2);
+
+		assert_eq!(SessionManager::session_duration_at(19), 10);
+		assert_eq!(SessionManager::session_duration_at(20), 20);
+		assert_eq!(SessionManager::session_duration_at(59), 20);
+		assert_eq!(SessionManager::session_duration_at(60), 5);
+		assert_eq!(SessionManager::session_duration_at(100), 5);
+
+		SessionManager::on_initialize(20);
+		assert_eq!(SessionManager::session_duration(), 20);
+		assert_eq!(SessionManager::duration_offset(), 20);
+		assert!(SessionManager::should_end_session(40));
+		assert!(SessionManager::should_end_session(60));
+
+		SessionManager::on_initialize(60);
+		assert_eq!(SessionManager::session_duration(), 5);
+		assert_eq!(SessionManager::duration_offset(), 60);
+		assert_eq!(SessionDurationChanges::<Runtime>::iter().count(), 0);
+	});
+}
+
 #[test]
 fn on_initialize_work() {
 	new_test_ext().execute_with(|| {
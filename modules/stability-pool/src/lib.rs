@@ -0,0 +1,317 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Stability Pool Module
+//!
+//! ## Overview
+//!
+//! Stable currency holders deposit into the stability pool, which absorbs the
+//! collateral of liquidated CDPs before collateral auctions are created. The
+//! pool pays the target stable amount of the liquidation to the CDP treasury,
+//! and receives the collateral at a discount from the oracle price.
+//!
+//! The deposits are accounted in shares, so the stable currency paid and the
+//! collateral received are shared pro-rata by all depositors. The collateral
+//! gains are paid out on deposit, withdraw or claim. The pool only absorbs a
+//! liquidation if it can pay the target without being depleted, otherwise the
+//! liquidation falls back to collateral auctions.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional, PalletId};
+use frame_system::pallet_prelude::*;
+use orml_traits::MultiCurrency;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
+	traits::{AccountIdConversion, Bounded, One, Saturating, Zero},
+	ArithmeticError, FixedPointNumber,
+};
+use support::{CDPTreasury, PriceProvider, Ratio, StabilityPool};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency for the deposits and the collateral.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The CDP treasury to receive the stable currency and to pay the
+		/// collateral of liquidations.
+		type CDPTreasury: CDPTreasury<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
+
+		/// The price source of the collateral.
+		type PriceSource: PriceProvider<CurrencyId>;
+
+		/// Stablecoin currency id
+		#[pallet::constant]
+		type GetStableCurrencyId: Get<CurrencyId>;
+
+		/// The discount from the oracle price of the collateral absorbed.
+		#[pallet::constant]
+		type CollateralDiscount: Get<Ratio>;
+
+		/// The stability pool's module id, keeps the deposits and the
+		/// collateral gains.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The amount is zero
+		ZeroAmount,
+		/// The deposit of the account is not enough
+		DepositNotEnough,
+		/// The stable currency in the pool, or the liquidated collateral at the
+		/// discount, is not enough to absorb
+		PoolNotEnough,
+		/// Feed price is invalid
+		InvalidFeedPrice,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Deposited stable currency to the pool. \[who, amount, shares\]
+		Deposited(T::AccountId, Balance, Balance),
+		/// Withdrawn stable currency from the pool. \[who, amount, shares\]
+		Withdrawn(T::AccountId, Balance, Balance),
+		/// Collateral gains paid out. \[who, currency_id, amount\]
+		CollateralGainPaid(T::AccountId, CurrencyId, Balance),
+		/// Absorbed the collateral of a liquidation. \[currency_id,
+		/// collateral_amount, stable_amount\]
+		LiquidationAbsorbed(CurrencyId, Balance, Balance),
+	}
+
+	/// The stable currency in the pool, only changed by deposits, withdrawals
+	/// and absorbed liquidations, so transfers to the pool account don't
+	/// change the price of the shares.
+	///
+	/// PoolBalance: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn pool_balance)]
+	pub type PoolBalance<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The total shares of the deposits.
+	///
+	/// TotalShares: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_shares)]
+	pub type TotalShares<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The shares of the deposit of accounts.
+	///
+	/// Shares: map AccountId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn shares)]
+	pub type Shares<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Balance, ValueQuery>;
+
+	/// The accumulated collateral gains per share.
+	///
+	/// CollateralGainPerShare: map CurrencyId => Ratio
+	#[pallet::storage]
+	#[pallet::getter(fn collateral_gain_per_share)]
+	pub type CollateralGainPerShare<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Ratio, ValueQuery>;
+
+	/// The collateral gains per share when the gains of the account were last
+	/// paid out.
+	///
+	/// GainSnapshots: double_map AccountId, CurrencyId => Ratio
+	#[pallet::storage]
+	#[pallet::getter(fn gain_snapshots)]
+	pub type GainSnapshots<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, CurrencyId, Ratio, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Deposit stable currency to the pool, the collateral gains are paid
+		/// out.
+		///
+		/// - `amount`: the stable currency amount to deposit
+		#[pallet::weight(T::WeightInfo::deposit())]
+		#[transactional]
+		pub fn deposit(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			Self::pay_collateral_gains(&who)?;
+
+			let total_shares = Self::total_shares();
+			let shares = if total_shares.is_zero() {
+				amount
+			} else {
+				multiply_by_rational(amount, total_shares, Self::pool_balance())
+					.map_err(|_| ArithmeticError::Overflow)?
+			};
+			ensure!(!shares.is_zero(), Error::<T>::ZeroAmount);
+
+			T::Currency::transfer(T::GetStableCurrencyId::get(), &who, &Self::account_id(), amount)?;
+			PoolBalance::<T>::try_mutate(|balance| -> DispatchResult {
+				*balance = balance.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
+			TotalShares::<T>::try_mutate(|total| -> DispatchResult {
+				*total = total.checked_add(shares).ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
+			Shares::<T>::mutate(&who, |s| *s = s.saturating_add(shares));
+
+			Self::deposit_event(Event::Deposited(who, amount, shares));
+			Ok(())
+		}
+
+		/// Withdraw stable currency from the pool, the collateral gains are
+		/// paid out.
+		///
+		/// - `amount`: the stable currency amount to withdraw, withdraw all the
+		///   deposit if it exceeds the deposit.
+		#[pallet::weight(T::WeightInfo::withdraw())]
+		#[transactional]
+		pub fn withdraw(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			Self::pay_collateral_gains(&who)?;
+
+			let account_shares = Self::shares(&who);
+			ensure!(!account_shares.is_zero(), Error::<T>::DepositNotEnough);
+			let total_shares = Self::total_shares();
+			let pool_balance = Self::pool_balance();
+			let deposit = multiply_by_rational(account_shares, pool_balance, total_shares)
+				.map_err(|_| ArithmeticError::Overflow)?;
+
+			let (amount, shares) = if amount >= deposit {
+				(deposit, account_shares)
+			} else {
+				// round the burned shares up in favor of the pool
+				let mut shares =
+					multiply_by_rational(amount, total_shares, pool_balance).map_err(|_| ArithmeticError::Overflow)?;
+				if multiply_by_rational(shares, pool_balance, total_shares).map_err(|_| ArithmeticError::Overflow)?
+					< amount
+				{
+					shares = shares.saturating_add(One::one());
+				}
+				(amount, shares.min(account_shares))
+			};
+
+			PoolBalance::<T>::mutate(|balance| *balance = balance.saturating_sub(amount));
+			TotalShares::<T>::mutate(|total| *total = total.saturating_sub(shares));
+			Shares::<T>::mutate_exists(&who, |s| {
+				let remaining = s.unwrap_or_default().saturating_sub(shares);
+				*s = if remaining.is_zero() { None } else { Some(remaining) };
+			});
+			T::Currency::transfer(T::GetStableCurrencyId::get(), &Self::account_id(), &who, amount)?;
+
+			Self::deposit_event(Event::Withdrawn(who, amount, shares));
+			Ok(())
+		}
+
+		/// Claim the collateral gains.
+		#[pallet::weight(T::WeightInfo::claim_collateral_gains())]
+		#[transactional]
+		pub fn claim_collateral_gains(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::pay_collateral_gains(&who)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Get account of stability pool module.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account()
+	}
+
+	/// Get the stable currency deposit of `who`.
+	pub fn deposit_of(who: &T::AccountId) -> Balance {
+		multiply_by_rational(Self::shares(who), Self::pool_balance(), Self::total_shares()).unwrap_or_default()
+	}
+
+	/// Get the unpaid collateral gain of `who` in `currency_id`.
+	pub fn pending_collateral_gain(who: &T::AccountId, currency_id: CurrencyId) -> Balance {
+		Self::collateral_gain_per_share(currency_id)
+			.saturating_sub(Self::gain_snapshots(who, currency_id))
+			.saturating_mul_int(Self::shares(who))
+	}
+
+	fn pay_collateral_gains(who: &T::AccountId) -> DispatchResult {
+		for (currency_id, gain_per_share) in CollateralGainPerShare::<T>::iter() {
+			let gain = Self::pending_collateral_gain(who, currency_id);
+			if !gain.is_zero() {
+				T::Currency::transfer(currency_id, &Self::account_id(), who, gain)?;
+				Self::deposit_event(Event::CollateralGainPaid(who.clone(), currency_id, gain));
+			}
+			GainSnapshots::<T>::insert(who, currency_id, gain_per_share);
+		}
+		Ok(())
+	}
+}
+
+impl<T: Config> StabilityPool<CurrencyId, Balance> for Pallet<T> {
+	#[transactional]
+	fn absorb(currency_id: CurrencyId, collateral: Balance, target: Balance) -> Result<Balance, DispatchError> {
+		// the shares are worthless once the pool is depleted
+		let total_shares = Self::total_shares();
+		ensure!(
+			!total_shares.is_zero() && Self::pool_balance() > target,
+			Error::<T>::PoolNotEnough
+		);
+
+		// calculate the collateral absorbed at the discount from the oracle price, the pool
+		// doesn't pay `target` for less collateral than it's worth.
+		let price = T::PriceSource::get_relative_price(T::GetStableCurrencyId::get(), currency_id)
+			.ok_or(Error::<T>::InvalidFeedPrice)?;
+		let absorbed_collateral = Ratio::one()
+			.saturating_sub(T::CollateralDiscount::get())
+			.reciprocal()
+			.unwrap_or_else(Ratio::max_value)
+			.saturating_mul_int(price.saturating_mul_int(target));
+		ensure!(absorbed_collateral <= collateral, Error::<T>::PoolNotEnough);
+
+		T::CDPTreasury::deposit_surplus(&Self::account_id(), target)?;
+		PoolBalance::<T>::mutate(|balance| *balance = balance.saturating_sub(target));
+		T::CDPTreasury::withdraw_collateral(&Self::account_id(), currency_id, absorbed_collateral)?;
+		CollateralGainPerShare::<T>::mutate(currency_id, |gain_per_share| {
+			*gain_per_share = gain_per_share
+				.saturating_add(Ratio::checked_from_rational(absorbed_collateral, total_shares).unwrap_or_default());
+		});
+
+		Self::deposit_event(Event::LiquidationAbsorbed(currency_id, absorbed_collateral, target));
+		Ok(absorbed_collateral)
+	}
+}
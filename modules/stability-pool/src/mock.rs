@@ -0,0 +1,222 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the stability pool module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{construct_runtime, parameter_types};
+use orml_traits::parameter_type_with_key;
+use primitives::{Amount, TokenSymbol};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+use support::Price;
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CDP_TREASURY: AccountId = 10;
+pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
+pub const BTC: CurrencyId = CurrencyId::Token(TokenSymbol::RENBTC);
+
+mod stability_pool {
+	pub use super::super::*;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = ();
+	type DustRemovalWhitelist = ();
+}
+
+/// Keeps the surplus and the collateral in the `CDP_TREASURY` account.
+pub struct MockCDPTreasury;
+impl CDPTreasury<AccountId> for MockCDPTreasury {
+	type Balance = Balance;
+	type CurrencyId = CurrencyId;
+
+	fn get_surplus_pool() -> Balance {
+		Tokens::free_balance(AUSD, &CDP_TREASURY)
+	}
+
+	fn get_debit_pool() -> Balance {
+		unimplemented!()
+	}
+
+	fn get_total_collaterals(currency_id: CurrencyId) -> Balance {
+		Tokens::free_balance(currency_id, &CDP_TREASURY)
+	}
+
+	fn get_debit_proportion(_: Balance) -> Ratio {
+		unimplemented!()
+	}
+
+	fn on_system_debit(_: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn on_system_surplus(_: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn issue_debit(_: &AccountId, _: Balance, _: bool) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn burn_debit(_: &AccountId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn deposit_surplus(from: &AccountId, surplus: Balance) -> DispatchResult {
+		Tokens::transfer(AUSD, from, &CDP_TREASURY, surplus)
+	}
+
+	fn withdraw_surplus(_: &AccountId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn deposit_collateral(_: &AccountId, _: CurrencyId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn withdraw_collateral(to: &AccountId, currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+		Tokens::transfer(currency_id, &CDP_TREASURY, to, amount)
+	}
+}
+
+parameter_types! {
+	pub static BtcPrice: Option<Price> = Some(Price::saturating_from_integer(10));
+}
+
+pub struct MockPriceSource;
+impl PriceProvider<CurrencyId> for MockPriceSource {
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		match currency_id {
+			AUSD => Some(Price::one()),
+			BTC => BtcPrice::get(),
+			_ => None,
+		}
+	}
+}
+
+parameter_types! {
+	pub const GetStableCurrencyId: CurrencyId = AUSD;
+	pub CollateralDiscount: Ratio = Ratio::saturating_from_rational(20, 100);
+	pub const StabilityPoolPalletId: PalletId = PalletId(*b"aca/stbp");
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type Currency = Tokens;
+	type CDPTreasury = MockCDPTreasury;
+	type PriceSource = MockPriceSource;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type CollateralDiscount = CollateralDiscount;
+	type PalletId = StabilityPoolPalletId;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		StabilityPoolModule: stability_pool::{Pallet, Call, Storage, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+	}
+);
+
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, CurrencyId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			balances: vec![(ALICE, AUSD, 1_000), (BOB, AUSD, 1_000), (CDP_TREASURY, BTC, 1_000)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
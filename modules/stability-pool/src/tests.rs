@@ -0,0 +1,158 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the stability pool module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+
+#[test]
+fn deposit_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			StabilityPoolModule::deposit(Origin::signed(ALICE), 0),
+			Error::<Runtime>::ZeroAmount
+		);
+		assert!(StabilityPoolModule::deposit(Origin::signed(ALICE), 1_001).is_err());
+
+		assert_ok!(StabilityPoolModule::deposit(Origin::signed(ALICE), 500));
+		System::assert_last_event(Event::StabilityPoolModule(crate::Event::Deposited(ALICE, 500, 500)));
+		assert_ok!(StabilityPoolModule::deposit(Origin::signed(BOB), 300));
+		assert_eq!(StabilityPoolModule::shares(ALICE), 500);
+		assert_eq!(StabilityPoolModule::shares(BOB), 300);
+		assert_eq!(StabilityPoolModule::total_shares(), 800);
+		assert_eq!(StabilityPoolModule::pool_balance(), 800);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 500);
+		assert_eq!(StabilityPoolModule::deposit_of(&BOB), 300);
+	});
+}
+
+#[test]
+fn donation_does_not_change_share_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(StabilityPoolModule::deposit(Origin::signed(ALICE), 1));
+		assert_ok!(Tokens::transfer(
+			Origin::signed(ALICE),
+			StabilityPoolModule::account_id(),
+			AUSD,
+			500
+		));
+		assert_eq!(StabilityPoolModule::pool_balance(), 1);
+
+		// the donation isn't counted, so the deposit isn't rounded down to 0 shares
+		assert_ok!(StabilityPoolModule::deposit(Origin::signed(BOB), 400));
+		System::assert_last_event(Event::StabilityPoolModule(crate::Event::Deposited(BOB, 400, 400)));
+		assert_eq!(StabilityPoolModule::pool_balance(), 401);
+		assert_eq!(StabilityPoolModule::deposit_of(&ALICE), 1);
+		assert_eq!(StabilityPoolModule::deposit_of(&BOB), 400);
+
+		assert_ok!(StabilityPoolModule::withdraw(Origin::signed(BOB), 1_000));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000);
+	});
+}
+
+#[test]
+fn absorb_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			StabilityPoolModule::absorb(BTC, 100, 200),
+			Error::<Runtime>::PoolNotEnough
+		);
+		assert_ok!(StabilityPoolModule::deposit(Origin::signed(ALICE), 600));
+		assert_ok!(StabilityPoolModule::deposit(Origin::signed(BOB), 200));
+
+		// 200 AUSD buys 20 BTC at the oracle price, 25 BTC at the discount
+		assert_eq!(StabilityPoolModule::absorb(BTC, 100, 200), Ok(25));
+		System::assert_last_event(Event::StabilityPoolModule(crate::Event::LiquidationAbsorbed(
+			BTC, 25, 200,
+		)));
+		assert_eq!(StabilityPoolModule::pool_balance(), 600);
+		assert_eq!(Tokens::free_balance(AUSD, &CDP_TREASURY), 200);
+		assert_eq!(Tokens::free_balance(BTC, &CDP_TREASURY), 975);
+		assert_eq!(Tokens::free_balance(BTC, &StabilityPoolModule::account_id()), 25);
+		assert_eq!(
+			StabilityPoolModule::collateral_gain_per_share(BTC),
+			Ratio::saturating_from_rational(25, 800)
+		);
+		assert_eq!(StabilityPoolModule::deposit_of(&ALICE), 450);
+		assert_eq!(StabilityPoolModule::deposit_of(&BOB), 150);
+		assert_eq!(StabilityPoolModule::pending_collateral_gain(&ALICE, BTC), 18);
+		assert_eq!(StabilityPoolModule::pending_collateral_gain(&BOB, BTC), 6);
+
+		// the liquidated collateral is worth less than the target at the discount
+		assert_noop!(
+			StabilityPoolModule::absorb(BTC, 24, 200),
+			Error::<Runtime>::PoolNotEnough
+		);
+
+		// the pool can't be depleted
+		assert_noop!(
+			StabilityPoolModule::absorb(BTC, 100, 600),
+			Error::<Runtime>::PoolNotEnough
+		);
+
+		BtcPrice::set(None);
+		assert_noop!(
+			StabilityPoolModule::absorb(BTC, 100, 200),
+			Error::<Runtime>::InvalidFeedPrice
+		);
+	});
+}
+
+#[test]
+fn withdraw_and_claim_collateral_gains_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(StabilityPoolModule::deposit(Origin::signed(ALICE), 600));
+		assert_ok!(StabilityPoolModule::deposit(Origin::signed(BOB), 200));
+		assert_ok!(StabilityPoolModule::absorb(BTC, 100, 200));
+
+		assert_ok!(StabilityPoolModule::claim_collateral_gains(Origin::signed(ALICE)));
+		System::assert_last_event(Event::StabilityPoolModule(crate::Event::CollateralGainPaid(
+			ALICE, BTC, 18,
+		)));
+		assert_eq!(Tokens::free_balance(BTC, &ALICE), 18);
+		assert_eq!(StabilityPoolModule::pending_collateral_gain(&ALICE, BTC), 0);
+
+		// the burned shares are rounded up
+		assert_noop!(
+			StabilityPoolModule::withdraw(Origin::signed(ALICE), 0),
+			Error::<Runtime>::ZeroAmount
+		);
+		assert_ok!(StabilityPoolModule::withdraw(Origin::signed(ALICE), 100));
+		System::assert_last_event(Event::StabilityPoolModule(crate::Event::Withdrawn(ALICE, 100, 134)));
+		assert_eq!(StabilityPoolModule::shares(ALICE), 466);
+		assert_eq!(StabilityPoolModule::total_shares(), 666);
+		assert_eq!(StabilityPoolModule::pool_balance(), 500);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 500);
+
+		// withdraw all the deposit and the collateral gains
+		assert_ok!(StabilityPoolModule::withdraw(Origin::signed(BOB), 1_000));
+		System::assert_last_event(Event::StabilityPoolModule(crate::Event::Withdrawn(BOB, 150, 200)));
+		assert_eq!(Tokens::free_balance(BTC, &BOB), 6);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 950);
+		assert_eq!(StabilityPoolModule::shares(BOB), 0);
+		assert_eq!(StabilityPoolModule::total_shares(), 466);
+		assert_noop!(
+			StabilityPoolModule::withdraw(Origin::signed(BOB), 100),
+			Error::<Runtime>::DepositNotEnough
+		);
+	});
+}
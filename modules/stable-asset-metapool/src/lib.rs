@@ -0,0 +1,278 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Stable Asset Metapool Module
+//!
+//! ## Overview
+//!
+//! A metapool is a stable asset pool of a new stablecoin and the pool token
+//! of an existing base pool, e.g. a 3pool, so that the new stablecoin doesn't
+//! need liquidity against every base asset. Governance registers which pool
+//! is a metapool of which base pool, then users can mint metapool token with
+//! the base assets, or redeem it to one of the base assets, and the base pool
+//! token is minted or redeemed on the way.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use nutsfinance_stable_asset::PoolId;
+use orml_traits::MultiCurrency;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{traits::Zero, ArithmeticError, DispatchResult, RuntimeDebug};
+use sp_std::prelude::*;
+use support::StableAssetManager;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// The base pool of a metapool.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub struct MetapoolInfo {
+	/// The pool id of the base pool.
+	pub base_pool_id: PoolId,
+	/// The pool token currency of the base pool.
+	pub base_pool_token: CurrencyId,
+	/// The index of the base pool token in the assets of the metapool.
+	pub base_pool_token_index: u32,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency to query the minted and redeemed base pool token.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// Stable asset pools to mint and redeem.
+		type StableAsset: StableAssetManager<Self::AccountId, CurrencyId, Balance, PoolId>;
+
+		/// The origin which may register and deregister metapools.
+		type ListingOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The metapool is not registered
+		MetapoolNotFound,
+		/// The metapool is already registered
+		MetapoolAlreadyRegistered,
+		/// The base pool can't be the metapool itself
+		InvalidBasePool,
+		/// The amounts don't contain the base pool token
+		InvalidAmounts,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	pub enum Event<T: Config> {
+		/// Metapool registered. \[pool_id, base_pool_id, base_pool_token,
+		/// base_pool_token_index\]
+		MetapoolRegistered(PoolId, PoolId, CurrencyId, u32),
+		/// Metapool deregistered. \[pool_id\]
+		MetapoolDeregistered(PoolId),
+		/// Minted metapool token with base assets. \[who, pool_id,
+		/// base_pool_token_amount\]
+		MintedThroughBasePool(T::AccountId, PoolId, Balance),
+		/// Redeemed metapool token to a base asset. \[who, pool_id,
+		/// base_pool_token_amount\]
+		RedeemedThroughBasePool(T::AccountId, PoolId, Balance),
+	}
+
+	/// The registered metapools.
+	///
+	/// Metapools: map PoolId => Option<MetapoolInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn metapools)]
+	pub type Metapools<T: Config> = StorageMap<_, Twox64Concat, PoolId, MetapoolInfo, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register `pool_id` as a metapool of `base_pool_id`.
+		///
+		/// - `base_pool_token`: the pool token currency of the base pool.
+		/// - `base_pool_token_index`: the index of the base pool token in the
+		///   assets of the metapool.
+		#[pallet::weight((T::WeightInfo::register_metapool(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn register_metapool(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			base_pool_id: PoolId,
+			base_pool_token: CurrencyId,
+			base_pool_token_index: u32,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			ensure!(pool_id != base_pool_id, Error::<T>::InvalidBasePool);
+			ensure!(
+				!Metapools::<T>::contains_key(pool_id),
+				Error::<T>::MetapoolAlreadyRegistered
+			);
+
+			Metapools::<T>::insert(
+				pool_id,
+				MetapoolInfo {
+					base_pool_id,
+					base_pool_token,
+					base_pool_token_index,
+				},
+			);
+			Self::deposit_event(Event::MetapoolRegistered(
+				pool_id,
+				base_pool_id,
+				base_pool_token,
+				base_pool_token_index,
+			));
+			Ok(())
+		}
+
+		/// Deregister the metapool `pool_id`.
+		#[pallet::weight((T::WeightInfo::deregister_metapool(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn deregister_metapool(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			ensure!(Metapools::<T>::contains_key(pool_id), Error::<T>::MetapoolNotFound);
+
+			Metapools::<T>::remove(pool_id);
+			Self::deposit_event(Event::MetapoolDeregistered(pool_id));
+			Ok(())
+		}
+
+		/// Mint metapool token, the base pool token is minted with
+		/// `base_amounts` first and added to the amount of the base pool
+		/// token in `amounts`.
+		///
+		/// - `amounts`: amounts of the metapool assets.
+		/// - `base_amounts`: amounts of the base pool assets.
+		/// - `min_mint_amount`: acceptable minimum metapool token amount.
+		#[pallet::weight(T::WeightInfo::mint())]
+		#[transactional]
+		pub fn mint(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			amounts: Vec<Balance>,
+			base_amounts: Vec<Balance>,
+			min_mint_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_mint(&who, pool_id, amounts, base_amounts, min_mint_amount)
+		}
+
+		/// Redeem metapool token to the base pool token, then redeem the base
+		/// pool token to a single base asset.
+		///
+		/// - `amount`: metapool token amount to redeem.
+		/// - `base_asset_index`: the index of the base asset to receive.
+		/// - `min_redeem_amount`: acceptable minimum base asset amount.
+		#[pallet::weight(T::WeightInfo::redeem_single())]
+		#[transactional]
+		pub fn redeem_single(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			amount: Balance,
+			base_asset_index: u32,
+			min_redeem_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_redeem_single(&who, pool_id, amount, base_asset_index, min_redeem_amount)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn do_mint(
+		who: &T::AccountId,
+		pool_id: PoolId,
+		mut amounts: Vec<Balance>,
+		base_amounts: Vec<Balance>,
+		min_mint_amount: Balance,
+	) -> DispatchResult {
+		let metapool_info = Self::metapools(pool_id).ok_or(Error::<T>::MetapoolNotFound)?;
+		let index = metapool_info.base_pool_token_index as usize;
+		ensure!(index < amounts.len(), Error::<T>::InvalidAmounts);
+
+		let mut base_pool_token_amount: Balance = Zero::zero();
+		if base_amounts.iter().any(|amount| !amount.is_zero()) {
+			let balance_before = T::Currency::free_balance(metapool_info.base_pool_token, who);
+			// the slippage is limited by `min_mint_amount` of the metapool
+			T::StableAsset::mint(who, metapool_info.base_pool_id, base_amounts, Zero::zero())?;
+			base_pool_token_amount =
+				T::Currency::free_balance(metapool_info.base_pool_token, who).saturating_sub(balance_before);
+			amounts[index] = amounts[index]
+				.checked_add(base_pool_token_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+		}
+
+		T::StableAsset::mint(who, pool_id, amounts, min_mint_amount)?;
+		Self::deposit_event(Event::MintedThroughBasePool(
+			who.clone(),
+			pool_id,
+			base_pool_token_amount,
+		));
+		Ok(())
+	}
+
+	fn do_redeem_single(
+		who: &T::AccountId,
+		pool_id: PoolId,
+		amount: Balance,
+		base_asset_index: u32,
+		min_redeem_amount: Balance,
+	) -> DispatchResult {
+		let metapool_info = Self::metapools(pool_id).ok_or(Error::<T>::MetapoolNotFound)?;
+
+		let balance_before = T::Currency::free_balance(metapool_info.base_pool_token, who);
+		// the slippage is limited by `min_redeem_amount` of the base pool
+		T::StableAsset::redeem_single(who, pool_id, amount, metapool_info.base_pool_token_index, Zero::zero())?;
+		let base_pool_token_amount =
+			T::Currency::free_balance(metapool_info.base_pool_token, who).saturating_sub(balance_before);
+
+		T::StableAsset::redeem_single(
+			who,
+			metapool_info.base_pool_id,
+			base_pool_token_amount,
+			base_asset_index,
+			min_redeem_amount,
+		)?;
+		Self::deposit_event(Event::RedeemedThroughBasePool(
+			who.clone(),
+			pool_id,
+			base_pool_token_amount,
+		));
+		Ok(())
+	}
+}
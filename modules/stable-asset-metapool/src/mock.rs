@@ -0,0 +1,217 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the stable asset metapool module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{construct_runtime, ord_parameter_types, parameter_types};
+use frame_system::EnsureSignedBy;
+use orml_traits::parameter_type_with_key;
+use primitives::{Amount, TokenSymbol};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup, DispatchError};
+
+pub type BlockNumber = u64;
+pub type AccountId = u128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
+pub const BTC: CurrencyId = CurrencyId::Token(TokenSymbol::RENBTC);
+pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+pub const KUSD: CurrencyId = CurrencyId::Token(TokenSymbol::KUSD);
+pub const BASE_POOL_ID: PoolId = 0;
+pub const BASE_POOL_TOKEN: CurrencyId = CurrencyId::StableAssetPoolToken(BASE_POOL_ID);
+pub const METAPOOL_ID: PoolId = 1;
+pub const METAPOOL_TOKEN: CurrencyId = CurrencyId::StableAssetPoolToken(METAPOOL_ID);
+
+mod stable_asset_metapool {
+	pub use super::super::*;
+}
+
+parameter_types! {
+	pub const BlockHashCount: BlockNumber = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = ();
+	type DustRemovalWhitelist = ();
+}
+
+/// Stable asset pools which mint and redeem pool token 1:1 with any asset.
+pub struct MockStableAsset;
+impl MockStableAsset {
+	fn pool_assets(pool_id: PoolId) -> Vec<CurrencyId> {
+		match pool_id {
+			BASE_POOL_ID => vec![AUSD, DOT, BTC],
+			METAPOOL_ID => vec![KUSD, BASE_POOL_TOKEN],
+			_ => vec![],
+		}
+	}
+}
+impl StableAssetManager<AccountId, CurrencyId, Balance, PoolId> for MockStableAsset {
+	fn pool_asset(pool_id: PoolId, i: u32) -> Option<CurrencyId> {
+		Self::pool_assets(pool_id).get(i as usize).copied()
+	}
+
+	fn get_swap_output_amount(pool_id: PoolId, i: u32, j: u32, dx: Balance) -> Option<Balance> {
+		Self::pool_asset(pool_id, i)?;
+		Self::pool_asset(pool_id, j)?;
+		Some(dx)
+	}
+
+	fn swap(who: &AccountId, pool_id: PoolId, i: u32, j: u32, dx: Balance, min_dy: Balance) -> DispatchResult {
+		let dy = Self::get_swap_output_amount(pool_id, i, j, dx).ok_or(DispatchError::Other("invalid asset index"))?;
+		ensure!(dy >= min_dy, DispatchError::Other("swap under min"));
+
+		Tokens::withdraw(Self::pool_assets(pool_id)[i as usize], who, dx)?;
+		Tokens::deposit(Self::pool_assets(pool_id)[j as usize], who, dy)
+	}
+
+	fn mint(who: &AccountId, pool_id: PoolId, amounts: Vec<Balance>, min_mint_amount: Balance) -> DispatchResult {
+		let assets = Self::pool_assets(pool_id);
+		ensure!(
+			!assets.is_empty() && assets.len() == amounts.len(),
+			DispatchError::Other("invalid amounts")
+		);
+		let mint_amount: Balance = amounts.iter().sum();
+		ensure!(mint_amount >= min_mint_amount, DispatchError::Other("mint under min"));
+
+		for (currency_id, amount) in assets.into_iter().zip(amounts) {
+			Tokens::withdraw(currency_id, who, amount)?;
+		}
+		Tokens::deposit(CurrencyId::StableAssetPoolToken(pool_id), who, mint_amount)
+	}
+
+	fn redeem_single(
+		who: &AccountId,
+		pool_id: PoolId,
+		amount: Balance,
+		i: u32,
+		min_redeem_amount: Balance,
+	) -> DispatchResult {
+		let currency_id = Self::pool_asset(pool_id, i).ok_or(DispatchError::Other("invalid asset index"))?;
+		ensure!(amount >= min_redeem_amount, DispatchError::Other("redeem under min"));
+
+		Tokens::withdraw(CurrencyId::StableAssetPoolToken(pool_id), who, amount)?;
+		Tokens::deposit(currency_id, who, amount)
+	}
+}
+
+ord_parameter_types! {
+	pub const ListingOrigin: AccountId = 3;
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type Currency = Tokens;
+	type StableAsset = MockStableAsset;
+	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+		StableAssetMetapoolModule: stable_asset_metapool::{Pallet, Storage, Call, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+	}
+);
+
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, CurrencyId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			balances: vec![
+				(ALICE, AUSD, 1_000),
+				(ALICE, DOT, 1_000),
+				(ALICE, BTC, 1_000),
+				(ALICE, KUSD, 1_000),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,224 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the stable asset metapool module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use sp_runtime::{traits::BadOrigin, DispatchError};
+
+#[test]
+fn register_metapool_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			StableAssetMetapoolModule::register_metapool(
+				Origin::signed(ALICE),
+				METAPOOL_ID,
+				BASE_POOL_ID,
+				BASE_POOL_TOKEN,
+				1
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			StableAssetMetapoolModule::register_metapool(
+				Origin::signed(ListingOrigin::get()),
+				METAPOOL_ID,
+				METAPOOL_ID,
+				BASE_POOL_TOKEN,
+				1
+			),
+			Error::<Runtime>::InvalidBasePool
+		);
+
+		assert_ok!(StableAssetMetapoolModule::register_metapool(
+			Origin::signed(ListingOrigin::get()),
+			METAPOOL_ID,
+			BASE_POOL_ID,
+			BASE_POOL_TOKEN,
+			1
+		));
+		System::assert_last_event(Event::StableAssetMetapoolModule(crate::Event::MetapoolRegistered(
+			METAPOOL_ID,
+			BASE_POOL_ID,
+			BASE_POOL_TOKEN,
+			1,
+		)));
+		assert_eq!(
+			StableAssetMetapoolModule::metapools(METAPOOL_ID),
+			Some(MetapoolInfo {
+				base_pool_id: BASE_POOL_ID,
+				base_pool_token: BASE_POOL_TOKEN,
+				base_pool_token_index: 1,
+			})
+		);
+
+		assert_noop!(
+			StableAssetMetapoolModule::register_metapool(
+				Origin::signed(ListingOrigin::get()),
+				METAPOOL_ID,
+				BASE_POOL_ID,
+				BASE_POOL_TOKEN,
+				1
+			),
+			Error::<Runtime>::MetapoolAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn deregister_metapool_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			StableAssetMetapoolModule::deregister_metapool(Origin::signed(ListingOrigin::get()), METAPOOL_ID),
+			Error::<Runtime>::MetapoolNotFound
+		);
+		assert_ok!(StableAssetMetapoolModule::register_metapool(
+			Origin::signed(ListingOrigin::get()),
+			METAPOOL_ID,
+			BASE_POOL_ID,
+			BASE_POOL_TOKEN,
+			1
+		));
+
+		assert_noop!(
+			StableAssetMetapoolModule::deregister_metapool(Origin::signed(ALICE), METAPOOL_ID),
+			BadOrigin
+		);
+		assert_ok!(StableAssetMetapoolModule::deregister_metapool(
+			Origin::signed(ListingOrigin::get()),
+			METAPOOL_ID
+		));
+		System::assert_last_event(Event::StableAssetMetapoolModule(crate::Event::MetapoolDeregistered(
+			METAPOOL_ID,
+		)));
+		assert_eq!(StableAssetMetapoolModule::metapools(METAPOOL_ID), None);
+	});
+}
+
+#[test]
+fn mint_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			StableAssetMetapoolModule::mint(Origin::signed(ALICE), METAPOOL_ID, vec![100, 0], vec![10, 20, 30], 0),
+			Error::<Runtime>::MetapoolNotFound
+		);
+		assert_ok!(StableAssetMetapoolModule::register_metapool(
+			Origin::signed(ListingOrigin::get()),
+			METAPOOL_ID,
+			BASE_POOL_ID,
+			BASE_POOL_TOKEN,
+			1
+		));
+		assert_noop!(
+			StableAssetMetapoolModule::mint(Origin::signed(ALICE), METAPOOL_ID, vec![100], vec![10, 20, 30], 0),
+			Error::<Runtime>::InvalidAmounts
+		);
+		assert_noop!(
+			StableAssetMetapoolModule::mint(Origin::signed(ALICE), METAPOOL_ID, vec![100, 0], vec![10, 20, 30], 161),
+			DispatchError::Other("mint under min")
+		);
+
+		assert_ok!(StableAssetMetapoolModule::mint(
+			Origin::signed(ALICE),
+			METAPOOL_ID,
+			vec![100, 0],
+			vec![10, 20, 30],
+			160
+		));
+		System::assert_last_event(Event::StableAssetMetapoolModule(crate::Event::MintedThroughBasePool(
+			ALICE,
+			METAPOOL_ID,
+			60,
+		)));
+		assert_eq!(Tokens::free_balance(KUSD, &ALICE), 900);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 990);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 980);
+		assert_eq!(Tokens::free_balance(BTC, &ALICE), 970);
+		assert_eq!(Tokens::free_balance(BASE_POOL_TOKEN, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(METAPOOL_TOKEN, &ALICE), 160);
+
+		// the base pool isn't touched without base amounts
+		assert_ok!(StableAssetMetapoolModule::mint(
+			Origin::signed(ALICE),
+			METAPOOL_ID,
+			vec![40, 0],
+			vec![0, 0, 0],
+			40
+		));
+		System::assert_last_event(Event::StableAssetMetapoolModule(crate::Event::MintedThroughBasePool(
+			ALICE,
+			METAPOOL_ID,
+			0,
+		)));
+		assert_eq!(Tokens::free_balance(KUSD, &ALICE), 860);
+		assert_eq!(Tokens::free_balance(METAPOOL_TOKEN, &ALICE), 200);
+	});
+}
+
+#[test]
+fn redeem_single_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			StableAssetMetapoolModule::redeem_single(Origin::signed(ALICE), METAPOOL_ID, 100, 2, 0),
+			Error::<Runtime>::MetapoolNotFound
+		);
+		assert_ok!(StableAssetMetapoolModule::register_metapool(
+			Origin::signed(ListingOrigin::get()),
+			METAPOOL_ID,
+			BASE_POOL_ID,
+			BASE_POOL_TOKEN,
+			1
+		));
+		assert_ok!(StableAssetMetapoolModule::mint(
+			Origin::signed(ALICE),
+			METAPOOL_ID,
+			vec![100, 0],
+			vec![10, 20, 30],
+			0
+		));
+
+		assert_noop!(
+			StableAssetMetapoolModule::redeem_single(Origin::signed(ALICE), METAPOOL_ID, 100, 2, 101),
+			DispatchError::Other("redeem under min")
+		);
+		assert_noop!(
+			StableAssetMetapoolModule::redeem_single(Origin::signed(ALICE), METAPOOL_ID, 100, 3, 0),
+			DispatchError::Other("invalid asset index")
+		);
+
+		assert_ok!(StableAssetMetapoolModule::redeem_single(
+			Origin::signed(ALICE),
+			METAPOOL_ID,
+			100,
+			2,
+			100
+		));
+		System::assert_last_event(Event::StableAssetMetapoolModule(crate::Event::RedeemedThroughBasePool(
+			ALICE,
+			METAPOOL_ID,
+			100,
+		)));
+		assert_eq!(Tokens::free_balance(METAPOOL_TOKEN, &ALICE), 60);
+		assert_eq!(Tokens::free_balance(BASE_POOL_TOKEN, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(BTC, &ALICE), 1_070);
+	});
+}
@@ -31,8 +31,8 @@ use sp_runtime::{
 };
 use sp_std::prelude::*;
 use support::{
-	ExchangeRate, HomaProtocol, NomineesProvider, OnNewEra, PolkadotBridge, PolkadotBridgeCall, PolkadotBridgeState,
-	PolkadotBridgeType, PolkadotStakingLedger, PolkadotUnlockChunk, Rate, Ratio,
+	ExchangeRate, HomaManager, HomaProtocol, NFTIssuer, NomineesProvider, OnNewEra, PolkadotBridge, PolkadotBridgeCall,
+	PolkadotBridgeState, PolkadotBridgeType, PolkadotStakingLedger, PolkadotUnlockChunk, Rate, Ratio,
 };
 
 mod mock;
@@ -40,6 +40,11 @@ mod tests;
 
 pub use module::*;
 
+pub type ReceiptClassIdOf<T> =
+	<<T as Config>::UnbondingReceipt as NFTIssuer<<T as frame_system::Config>::AccountId>>::ClassId;
+pub type ReceiptTokenIdOf<T> =
+	<<T as Config>::UnbondingReceipt as NFTIssuer<<T as frame_system::Config>::AccountId>>::TokenId;
+
 /// The configurable params of staking pool.
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -186,6 +191,14 @@ pub mod module {
 
 		/// The currency for managing assets related to Homa protocol.
 		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The NFT issuer of unbonding receipts, which represent transferable
+		/// claims of unbonding staking currency.
+		type UnbondingReceipt: NFTIssuer<Self::AccountId>;
+
+		/// The NFT class of unbonding receipts.
+		#[pallet::constant]
+		type UnbondingReceiptClassId: Get<ReceiptClassIdOf<Self>>;
 	}
 
 	#[pallet::error]
@@ -198,11 +211,19 @@ pub mod module {
 		InvalidConfig,
 		/// Rebalance process is unfinished.
 		RebalanceUnfinished,
+		/// There's no unbonding to issue receipt for.
+		NoUnbonding,
+		/// The unbonding receipt is not found.
+		UnbondingReceiptNotFound,
+		/// The operator is not the owner of the unbonding receipt.
+		NotReceiptOwner,
+		/// The unbonding of the receipt has not expired yet.
+		UnbondingNotExpired,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
-	#[pallet::metadata(T::AccountId = "AccountId")]
+	#[pallet::metadata(T::AccountId = "AccountId", ReceiptTokenIdOf<T> = "TokenId")]
 	pub enum Event<T: Config> {
 		/// Deposit staking currency(DOT) to staking pool and issue liquid
 		/// currency(LDOT). \[who, staking_amount_deposited,
@@ -220,6 +241,12 @@ pub mod module {
 		/// the unbonding_to_free of specific era. \[who, target_era,
 		/// fee_in_staking, liquid_amount_burned, staking_amount_redeemed\]
 		RedeemByClaimUnbonding(T::AccountId, EraIndex, Balance, Balance, Balance),
+		/// Issue unbonding receipt for the unbonding of specific era.
+		/// \[who, receipt_id, era_index, staking_amount\]
+		UnbondingReceiptIssued(T::AccountId, ReceiptTokenIdOf<T>, EraIndex, Balance),
+		/// Burn unbonding receipt and withdraw the unbonded staking currency.
+		/// \[who, receipt_id, staking_amount\]
+		UnbondingReceiptWithdrawn(T::AccountId, ReceiptTokenIdOf<T>, Balance),
 	}
 
 	/// Current era index on Relaychain.
@@ -255,6 +282,15 @@ pub mod module {
 	pub type Unbondings<T: Config> =
 		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, EraIndex, Balance, ValueQuery>;
 
+	/// The unbonding claims represented by receipts.
+	/// ReceiptId => (ExpiredEraIndex, Unbonding)
+	///
+	/// UnbondingReceipts: map ReceiptId => (EraIndex, Balance)
+	#[pallet::storage]
+	#[pallet::getter(fn unbonding_receipts)]
+	pub type UnbondingReceipts<T: Config> =
+		StorageMap<_, Twox64Concat, ReceiptTokenIdOf<T>, (EraIndex, Balance), OptionQuery>;
+
 	/// The ledger of staking pool.
 	///
 	/// StakingPoolLedger: Ledger
@@ -718,6 +754,7 @@ impl<T: Config> OnNewEra<EraIndex> for Pallet<T> {
 
 impl<T: Config> HomaProtocol<T::AccountId, Balance, EraIndex> for Pallet<T> {
 	type Balance = Balance;
+	type ReceiptId = ReceiptTokenIdOf<T>;
 
 	#[transactional]
 	fn mint(who: &T::AccountId, amount: Self::Balance) -> sp_std::result::Result<Self::Balance, DispatchError> {
@@ -975,6 +1012,63 @@ impl<T: Config> HomaProtocol<T::AccountId, Balance, EraIndex> for Pallet<T> {
 		T::Currency::transfer(T::StakingCurrencyId::get(), &Self::account_id(), who, withdrawn_amount)?;
 		Ok(withdrawn_amount)
 	}
+
+	#[transactional]
+	fn issue_unbonding_receipt(
+		who: &T::AccountId,
+		era: EraIndex,
+	) -> sp_std::result::Result<Self::ReceiptId, DispatchError> {
+		ensure!(era > Self::current_era(), Error::<T>::InvalidEra);
+
+		let unbonding = Unbondings::<T>::take(who, era);
+		ensure!(!unbonding.is_zero(), Error::<T>::NoUnbonding);
+
+		let receipt_id = T::UnbondingReceipt::issue(who, T::UnbondingReceiptClassId::get(), era.encode())?;
+		UnbondingReceipts::<T>::insert(receipt_id, (era, unbonding));
+
+		Self::deposit_event(Event::UnbondingReceiptIssued(who.clone(), receipt_id, era, unbonding));
+		Ok(receipt_id)
+	}
+
+	#[transactional]
+	fn withdraw_unbonding_receipt(
+		who: &T::AccountId,
+		receipt_id: Self::ReceiptId,
+	) -> sp_std::result::Result<Self::Balance, DispatchError> {
+		let (era, unbonding) = Self::unbonding_receipts(receipt_id).ok_or(Error::<T>::UnbondingReceiptNotFound)?;
+		let receipt = (T::UnbondingReceiptClassId::get(), receipt_id);
+		ensure!(
+			T::UnbondingReceipt::owner(receipt).as_ref() == Some(who),
+			Error::<T>::NotReceiptOwner
+		);
+		ensure!(era <= Self::current_era(), Error::<T>::UnbondingNotExpired);
+
+		T::UnbondingReceipt::burn(who, receipt)?;
+		UnbondingReceipts::<T>::remove(receipt_id);
+		T::Currency::transfer(T::StakingCurrencyId::get(), &Self::account_id(), who, unbonding)?;
+
+		Self::deposit_event(Event::UnbondingReceiptWithdrawn(who.clone(), receipt_id, unbonding));
+		Ok(unbonding)
+	}
+}
+
+impl<T: Config> HomaManager<T::AccountId, Balance> for Pallet<T> {
+	fn mint(who: &T::AccountId, amount: Balance) -> DispatchResult {
+		<Self as HomaProtocol<T::AccountId, Balance, EraIndex>>::mint(who, amount)?;
+		Ok(())
+	}
+
+	fn request_redeem(who: &T::AccountId, amount: Balance, fast_match: bool) -> DispatchResult {
+		if fast_match {
+			<Self as HomaProtocol<T::AccountId, Balance, EraIndex>>::redeem_by_free_unbonded(who, amount)
+		} else {
+			<Self as HomaProtocol<T::AccountId, Balance, EraIndex>>::redeem_by_unbond(who, amount)
+		}
+	}
+
+	fn get_exchange_rate() -> ExchangeRate {
+		Self::liquid_exchange_rate()
+	}
 }
 
 pub struct OnSlash<T>(sp_std::marker::PhantomData<T>);
@@ -33,7 +33,7 @@ use sp_runtime::{
 };
 use sp_std::cell::RefCell;
 use std::collections::HashMap;
-use support::PolkadotStakingLedger;
+use support::{NFTIssuer, PolkadotStakingLedger};
 
 pub type AccountId = u128;
 pub type BlockNumber = u64;
@@ -318,6 +318,44 @@ impl PolkadotBridgeState<Balance, EraIndex> for MockBridge {
 
 impl PolkadotBridge<AccountId, BlockNumber, Balance, EraIndex> for MockBridge {}
 
+thread_local! {
+	pub static RECEIPTS: RefCell<HashMap<u32, AccountId>> = RefCell::new(HashMap::new());
+	pub static NEXT_RECEIPT_ID: RefCell<u32> = RefCell::new(0);
+}
+
+pub struct MockUnbondingReceipt;
+impl NFTIssuer<AccountId> for MockUnbondingReceipt {
+	type ClassId = u32;
+	type TokenId = u32;
+
+	fn issue(to: &AccountId, _class_id: u32, _metadata: Vec<u8>) -> Result<u32, DispatchError> {
+		let token_id = NEXT_RECEIPT_ID.with(|v| {
+			let token_id = *v.borrow();
+			*v.borrow_mut() = token_id + 1;
+			token_id
+		});
+		RECEIPTS.with(|v| v.borrow_mut().insert(token_id, *to));
+		Ok(token_id)
+	}
+
+	fn burn(who: &AccountId, token: (u32, u32)) -> DispatchResult {
+		ensure!(Self::owner(token) == Some(*who), DispatchError::Other("NoPermission"));
+		RECEIPTS.with(|v| v.borrow_mut().remove(&token.1));
+		Ok(())
+	}
+
+	fn owner(token: (u32, u32)) -> Option<AccountId> {
+		RECEIPTS.with(|v| v.borrow().get(&token.1).cloned())
+	}
+}
+
+pub fn transfer_receipt(from: &AccountId, to: &AccountId, token_id: u32) {
+	RECEIPTS.with(|v| {
+		assert_eq!(v.borrow().get(&token_id), Some(from));
+		v.borrow_mut().insert(token_id, *to);
+	});
+}
+
 pub struct MockFeeModel;
 impl<Balance: FixedPointOperand> FeeModel<Balance> for MockFeeModel {
 	/// Linear model:
@@ -346,6 +384,7 @@ parameter_types! {
 	pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(10, 100);	// 1 : 10
 	pub const StakingPoolPalletId: PalletId = PalletId(*b"aca/stkp");
 	pub PoolAccountIndexes: Vec<u32> = vec![1, 2, 3, 4];
+	pub const UnbondingReceiptClassId: u32 = 0;
 }
 
 ord_parameter_types! {
@@ -364,6 +403,8 @@ impl Config for Runtime {
 	type Nominees = MockNomineesProvider;
 	type Bridge = MockBridge;
 	type Currency = CurrenciesModule;
+	type UnbondingReceipt = MockUnbondingReceipt;
+	type UnbondingReceiptClassId = UnbondingReceiptClassId;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
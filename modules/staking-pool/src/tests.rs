@@ -22,6 +22,7 @@
 
 use super::*;
 use frame_support::{assert_noop, assert_ok};
+use mock::{transfer_receipt, MockUnbondingReceipt};
 use mock::{
 	BondingDuration, CurrenciesModule, Event, ExtBuilder, One, Origin, Runtime, StakingPoolModule, Status, System,
 	ALICE, BOB, BRIDGE_STATUS, DOT, LDOT,
@@ -815,6 +816,69 @@ fn withdraw_redemption_work() {
 	});
 }
 
+#[test]
+fn issue_unbonding_receipt_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		Unbondings::<Runtime>::insert(ALICE, 2, 200);
+
+		assert_noop!(
+			StakingPoolModule::issue_unbonding_receipt(&ALICE, 0),
+			Error::<Runtime>::InvalidEra
+		);
+		assert_noop!(
+			StakingPoolModule::issue_unbonding_receipt(&BOB, 2),
+			Error::<Runtime>::NoUnbonding
+		);
+
+		assert_eq!(StakingPoolModule::issue_unbonding_receipt(&ALICE, 2), Ok(0));
+		System::assert_last_event(Event::StakingPoolModule(crate::Event::UnbondingReceiptIssued(
+			ALICE, 0, 2, 200,
+		)));
+		assert_eq!(StakingPoolModule::unbondings(&ALICE, 2), 0);
+		assert_eq!(StakingPoolModule::unbonding_receipts(0), Some((2, 200)));
+		assert_eq!(MockUnbondingReceipt::owner((0, 0)), Some(ALICE));
+	});
+}
+
+#[test]
+fn withdraw_unbonding_receipt_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		Unbondings::<Runtime>::insert(ALICE, 2, 200);
+		assert_ok!(CurrenciesModule::deposit(DOT, &StakingPoolModule::account_id(), 500));
+		assert_eq!(StakingPoolModule::issue_unbonding_receipt(&ALICE, 2), Ok(0));
+		transfer_receipt(&ALICE, &BOB, 0);
+
+		assert_noop!(
+			StakingPoolModule::withdraw_unbonding_receipt(&BOB, 1),
+			Error::<Runtime>::UnbondingReceiptNotFound
+		);
+		assert_noop!(
+			StakingPoolModule::withdraw_unbonding_receipt(&ALICE, 0),
+			Error::<Runtime>::NotReceiptOwner
+		);
+		assert_noop!(
+			StakingPoolModule::withdraw_unbonding_receipt(&BOB, 0),
+			Error::<Runtime>::UnbondingNotExpired
+		);
+
+		CurrentEra::<Runtime>::put(2);
+		assert_eq!(CurrenciesModule::free_balance(DOT, &BOB), 1000);
+		assert_eq!(StakingPoolModule::withdraw_unbonding_receipt(&BOB, 0), Ok(200));
+		System::assert_last_event(Event::StakingPoolModule(crate::Event::UnbondingReceiptWithdrawn(
+			BOB, 0, 200,
+		)));
+		assert_eq!(CurrenciesModule::free_balance(DOT, &BOB), 1200);
+		assert_eq!(
+			CurrenciesModule::free_balance(DOT, &StakingPoolModule::account_id()),
+			300
+		);
+		assert_eq!(StakingPoolModule::unbonding_receipts(0), None);
+		assert_eq!(MockUnbondingReceipt::owner((0, 0)), None);
+	});
+}
+
 #[test]
 fn redeem_by_unbond_work() {
 	ExtBuilder::default().build().execute_with(|| {
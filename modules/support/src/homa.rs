@@ -85,12 +85,35 @@ impl<Balance, CurrencyId> OnCommission<Balance, CurrencyId> for () {
 	fn on_commission(_currency_id: CurrencyId, _amount: Balance) {}
 }
 
+/// Claim the staking rewards of Homa on the relaychain.
+pub trait RelaychainRewardsClaimer<EraIndex> {
+	/// Send the XCM to claim the staking rewards of `era`.
+	fn claim_rewards(era: EraIndex) -> DispatchResult;
+}
+
+impl<EraIndex> RelaychainRewardsClaimer<EraIndex> for () {
+	fn claim_rewards(_era: EraIndex) -> DispatchResult {
+		Ok(())
+	}
+}
+
 pub trait HomaProtocol<AccountId, Balance, EraIndex> {
 	type Balance: Decode + Encode + Debug + Eq + PartialEq + Clone + HasCompact;
+	type ReceiptId: Parameter;
 
 	fn mint(who: &AccountId, amount: Balance) -> sp_std::result::Result<Balance, DispatchError>;
 	fn redeem_by_unbond(who: &AccountId, amount: Balance) -> DispatchResult;
 	fn redeem_by_free_unbonded(who: &AccountId, amount: Balance) -> DispatchResult;
 	fn redeem_by_claim_unbonding(who: &AccountId, amount: Balance, target_era: EraIndex) -> DispatchResult;
 	fn withdraw_redemption(who: &AccountId) -> sp_std::result::Result<Balance, DispatchError>;
+	/// Move the unbonding of `era` into a transferable receipt.
+	fn issue_unbonding_receipt(
+		who: &AccountId,
+		era: EraIndex,
+	) -> sp_std::result::Result<Self::ReceiptId, DispatchError>;
+	/// Burn the receipt and withdraw its unbonded amount.
+	fn withdraw_unbonding_receipt(
+		who: &AccountId,
+		receipt_id: Self::ReceiptId,
+	) -> sp_std::result::Result<Balance, DispatchError>;
 }
@@ -23,11 +23,13 @@ use codec::{Decode, Encode, FullCodec, HasCompact};
 use frame_support::pallet_prelude::{DispatchClass, Pays, Weight};
 use primitives::{
 	evm::{CallInfo, EvmAddress},
-	CurrencyId,
+	Balance, BlockNumber, CurrencyId,
 };
+#[cfg(feature = "std")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use sp_core::H160;
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, CheckedDiv, MaybeSerializeDeserialize},
+	traits::{AtLeast32BitUnsigned, CheckedDiv, MaybeSerializeDeserialize, One, Saturating},
 	transaction_validity::TransactionValidityError,
 	DispatchError, DispatchResult, FixedU128, RuntimeDebug,
 };
@@ -41,7 +43,7 @@ pub mod homa;
 pub mod mocks;
 pub use homa::{
 	HomaProtocol, NomineesProvider, OnCommission, OnNewEra, PolkadotBridge, PolkadotBridgeCall, PolkadotBridgeState,
-	PolkadotBridgeType, PolkadotStakingLedger, PolkadotUnlockChunk,
+	PolkadotBridgeType, PolkadotStakingLedger, PolkadotUnlockChunk, RelaychainRewardsClaimer,
 };
 
 pub type Price = FixedU128;
@@ -49,6 +51,22 @@ pub type ExchangeRate = FixedU128;
 pub type Ratio = FixedU128;
 pub type Rate = FixedU128;
 
+/// The auction type to sell the collateral of unsafe CDPs.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum CollateralAuctionType {
+	/// Ascending price auction, the highest bid wins when the auction ends.
+	English,
+	/// Descending price auction, the price decays every block and the first
+	/// bid wins.
+	Dutch,
+}
+
+impl Default for CollateralAuctionType {
+	fn default() -> Self {
+		CollateralAuctionType::English
+	}
+}
+
 pub trait RiskManager<AccountId, CurrencyId, Balance, DebitBalance> {
 	fn get_bad_debt_value(currency_id: CurrencyId, debit_balance: DebitBalance) -> Balance;
 
@@ -99,6 +117,124 @@ pub trait AuctionManager<AccountId> {
 	fn get_total_target_in_auction() -> Self::Balance;
 }
 
+/// The side of a swap which amount is fixed.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum SwapMode {
+	/// The supply amount is fixed.
+	ExactSupply,
+	/// The target amount is fixed.
+	ExactTarget,
+}
+
+/// The venue of a swap hop.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum SwapVenue {
+	/// An AMM pool of module-dex.
+	Dex,
+	/// A stable swap pool. \[pool_id\]
+	StableAsset(u32),
+}
+
+/// A hop of a swap quote.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct SwapHop<CurrencyId, Balance> {
+	pub venue: SwapVenue,
+	pub supply_currency_id: CurrencyId,
+	pub target_currency_id: CurrencyId,
+	/// Amount paid into the hop.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub supply_amount: Balance,
+	/// Amount got from the hop.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub target_amount: Balance,
+	/// Exchange fee in the supply currency, zero for the stable swap hops
+	/// whose fee is deducted from the target amount.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub fee: Balance,
+	/// Shortfall of the target amount to the spot price after the fee.
+	pub price_impact: Ratio,
+}
+
+/// Quote of a swap along the best path.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct SwapQuote<CurrencyId, Balance> {
+	/// Amount of supply currency paid.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub supply_amount: Balance,
+	/// Amount of target currency got.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub target_amount: Balance,
+	/// The hops of the swap path, in order.
+	pub hops: Vec<SwapHop<CurrencyId, Balance>>,
+	/// Shortfall of the target amount to the spot prices of the path after
+	/// the fees.
+	pub price_impact: Ratio,
+}
+
+impl<CurrencyId, Balance: Copy> SwapQuote<CurrencyId, Balance> {
+	/// Build the quote of swapping through `hops`, `None` if `hops` is empty.
+	pub fn from_hops(hops: Vec<SwapHop<CurrencyId, Balance>>) -> Option<Self> {
+		let supply_amount = hops.first()?.supply_amount;
+		let target_amount = hops.last()?.target_amount;
+		// the target amount of a hop is the supply amount of the next one, so the
+		// shortfalls of the hops compound
+		let price_impact = Ratio::one().saturating_sub(hops.iter().fold(Ratio::one(), |acc, hop| {
+			acc.saturating_mul(Ratio::one().saturating_sub(hop.price_impact))
+		}));
+
+		Some(Self {
+			supply_amount,
+			target_amount,
+			hops,
+			price_impact,
+		})
+	}
+
+	/// Whether the quote has a better price than `other` in `mode`.
+	pub fn is_better_than(&self, other: &Self, mode: SwapMode) -> bool
+	where
+		Balance: PartialOrd,
+	{
+		match mode {
+			SwapMode::ExactSupply => self.target_amount > other.target_amount,
+			SwapMode::ExactTarget => self.supply_amount < other.supply_amount,
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+fn serialize_as_string<S: Serializer, T: std::fmt::Display>(t: &T, serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.serialize_str(&t.to_string())
+}
+
+#[cfg(feature = "std")]
+fn deserialize_from_string<'de, D: Deserializer<'de>, T: std::str::FromStr>(deserializer: D) -> Result<T, D::Error> {
+	let s = String::deserialize(deserializer)?;
+	s.parse::<T>()
+		.map_err(|_| serde::de::Error::custom("Parse from string failed"))
+}
+
 pub trait DEXManager<AccountId, CurrencyId, Balance> {
 	fn get_liquidity_pool(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance);
 
@@ -141,6 +277,28 @@ pub trait DEXManager<AccountId, CurrencyId, Balance> {
 		min_withdrawn_b: Balance,
 		by_unstake: bool,
 	) -> DispatchResult;
+
+	/// Quote of swapping `amount` along `path`, `None` if the swap is not
+	/// available.
+	fn get_swap_quote(
+		_path: &[CurrencyId],
+		_amount: Balance,
+		_mode: SwapMode,
+	) -> Option<SwapQuote<CurrencyId, Balance>> {
+		None
+	}
+
+	/// Quote of swapping `amount` from `supply_currency_id` to
+	/// `target_currency_id` along the path with the best price, `None` if
+	/// there's no available path.
+	fn get_best_swap_quote(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_amount: Balance,
+		_mode: SwapMode,
+	) -> Option<SwapQuote<CurrencyId, Balance>> {
+		None
+	}
 }
 
 impl<AccountId, CurrencyId, Balance> DEXManager<AccountId, CurrencyId, Balance> for ()
@@ -206,6 +364,33 @@ where
 	}
 }
 
+/// An abstraction of stable asset pools, which swap assets of similar value.
+pub trait StableAssetManager<AccountId, CurrencyId, Balance, PoolId> {
+	/// The pool asset of `pool_id` at index `i`.
+	fn pool_asset(pool_id: PoolId, i: u32) -> Option<CurrencyId>;
+
+	/// The output amount of swapping `dx` of the pool asset at index `i` to
+	/// the pool asset at index `j`.
+	fn get_swap_output_amount(pool_id: PoolId, i: u32, j: u32, dx: Balance) -> Option<Balance>;
+
+	/// Swap `dx` of the pool asset at index `i` to the pool asset at index
+	/// `j`.
+	fn swap(who: &AccountId, pool_id: PoolId, i: u32, j: u32, dx: Balance, min_dy: Balance) -> DispatchResult;
+
+	/// Mint pool token of `pool_id` with `amounts` of every pool asset.
+	fn mint(who: &AccountId, pool_id: PoolId, amounts: Vec<Balance>, min_mint_amount: Balance) -> DispatchResult;
+
+	/// Redeem `amount` of pool token of `pool_id` to the pool asset at index
+	/// `i`.
+	fn redeem_single(
+		who: &AccountId,
+		pool_id: PoolId,
+		amount: Balance,
+		i: u32,
+		min_redeem_amount: Balance,
+	) -> DispatchResult;
+}
+
 /// An abstraction of cdp treasury for Honzon Protocol.
 pub trait CDPTreasury<AccountId> {
 	type Balance;
@@ -240,6 +425,9 @@ pub trait CDPTreasury<AccountId> {
 	/// deposit surplus(stable currency) to cdp treasury by `from`
 	fn deposit_surplus(from: &AccountId, surplus: Self::Balance) -> DispatchResult;
 
+	/// withdraw surplus(stable currency) of cdp treasury to `to`
+	fn withdraw_surplus(to: &AccountId, surplus: Self::Balance) -> DispatchResult;
+
 	/// deposit collateral assets to cdp treasury by `who`
 	fn deposit_collateral(from: &AccountId, currency_id: Self::CurrencyId, amount: Self::Balance) -> DispatchResult;
 
@@ -247,6 +435,32 @@ pub trait CDPTreasury<AccountId> {
 	fn withdraw_collateral(to: &AccountId, currency_id: Self::CurrencyId, amount: Self::Balance) -> DispatchResult;
 }
 
+/// An abstraction of flash minting stable currency.
+pub trait FlashMint<AccountId> {
+	type Balance;
+
+	/// mint `amount` stable currency to `who` and execute `f`, then burn the
+	/// same amount and charge the fee from `who`. All changes are reverted if
+	/// any of them failed.
+	fn flash_mint<F: FnOnce() -> DispatchResult>(who: &AccountId, amount: Self::Balance, f: F) -> DispatchResult;
+
+	/// the fee to flash mint `amount` stable currency
+	fn flash_mint_fee(amount: Self::Balance) -> Self::Balance;
+}
+
+/// Transfer native currency as a vesting schedule.
+pub trait VestedTransfer<AccountId, BlockNumber, Balance> {
+	/// transfer `per_period * period_count` native currency from `from` to
+	/// `to`, which is unlocked by `per_period` every `period` blocks from now.
+	fn vested_transfer(
+		from: &AccountId,
+		to: &AccountId,
+		period: BlockNumber,
+		period_count: u32,
+		per_period: Balance,
+	) -> DispatchResult;
+}
+
 pub trait CDPTreasuryExtended<AccountId>: CDPTreasury<AccountId> {
 	fn swap_exact_collateral_to_stable(
 		currency_id: Self::CurrencyId,
@@ -273,6 +487,129 @@ pub trait CDPTreasuryExtended<AccountId>: CDPTreasury<AccountId> {
 	) -> DispatchResult;
 }
 
+/// Absorb the collateral of liquidated CDPs with the stable currency deposited
+/// in the stability pool.
+pub trait StabilityPool<CurrencyId, Balance> {
+	/// Pay `target` stable currency to the CDP treasury in exchange for at
+	/// most `collateral` of `currency_id` held by the CDP treasury, returns
+	/// the collateral amount absorbed. Fails if `collateral` is not enough to
+	/// pay for `target`.
+	fn absorb(currency_id: CurrencyId, collateral: Balance, target: Balance) -> Result<Balance, DispatchError>;
+}
+
+impl<CurrencyId, Balance> StabilityPool<CurrencyId, Balance> for () {
+	fn absorb(_currency_id: CurrencyId, _collateral: Balance, _target: Balance) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("StabilityPool is not available"))
+	}
+}
+
+/// Identifier of a risk parameter stored by the parameters module, the
+/// variant determines the type of its value.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum RiskParameterKey {
+	/// The liquidation ratio of a collateral, a `Ratio`.
+	LiquidationRatio(CurrencyId),
+	/// The extra interest rate per sec of a collateral, a `Rate`.
+	InterestRatePerSec(CurrencyId),
+	/// The liquidation penalty of a collateral, a `Rate`.
+	LiquidationPenalty(CurrencyId),
+	/// The required collateral ratio of a collateral, a `Ratio`.
+	RequiredCollateralRatio(CurrencyId),
+	/// The hard cap of total debit value of a collateral, a `Balance`.
+	MaximumTotalDebitValue(CurrencyId),
+	/// The extended blocks of an auction when a new bid is made, a
+	/// `BlockNumber`.
+	AuctionTimeToClose,
+	/// The duration after which the auctions are extended by half of
+	/// `AuctionTimeToClose`, a `BlockNumber`.
+	AuctionDurationSoftCap,
+}
+
+/// The value of a risk parameter.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum RiskParameterValue {
+	Ratio(Ratio),
+	Rate(Rate),
+	Balance(Balance),
+	BlockNumber(BlockNumber),
+}
+
+impl RiskParameterKey {
+	/// Whether `value` has the type of the parameter.
+	pub fn accepts(&self, value: &RiskParameterValue) -> bool {
+		match self {
+			RiskParameterKey::LiquidationRatio(_) | RiskParameterKey::RequiredCollateralRatio(_) => {
+				matches!(value, RiskParameterValue::Ratio(_))
+			}
+			RiskParameterKey::InterestRatePerSec(_) | RiskParameterKey::LiquidationPenalty(_) => {
+				matches!(value, RiskParameterValue::Rate(_))
+			}
+			RiskParameterKey::MaximumTotalDebitValue(_) => matches!(value, RiskParameterValue::Balance(_)),
+			RiskParameterKey::AuctionTimeToClose | RiskParameterKey::AuctionDurationSoftCap => {
+				matches!(value, RiskParameterValue::BlockNumber(_))
+			}
+		}
+	}
+}
+
+/// Risk parameters set by governance, which override the parameters of the
+/// modules using them.
+pub trait RiskParameterProvider {
+	/// The value of `key`, `None` if it's not set.
+	fn get(key: RiskParameterKey) -> Option<RiskParameterValue>;
+
+	fn get_ratio(key: RiskParameterKey) -> Option<Ratio> {
+		match Self::get(key) {
+			Some(RiskParameterValue::Ratio(value)) => Some(value),
+			_ => None,
+		}
+	}
+
+	fn get_rate(key: RiskParameterKey) -> Option<Rate> {
+		match Self::get(key) {
+			Some(RiskParameterValue::Rate(value)) => Some(value),
+			_ => None,
+		}
+	}
+
+	fn get_balance(key: RiskParameterKey) -> Option<Balance> {
+		match Self::get(key) {
+			Some(RiskParameterValue::Balance(value)) => Some(value),
+			_ => None,
+		}
+	}
+
+	fn get_block_number(key: RiskParameterKey) -> Option<BlockNumber> {
+		match Self::get(key) {
+			Some(RiskParameterValue::BlockNumber(value)) => Some(value),
+			_ => None,
+		}
+	}
+}
+
+impl RiskParameterProvider for () {
+	fn get(_key: RiskParameterKey) -> Option<RiskParameterValue> {
+		None
+	}
+}
+
+/// The strategy to aggregate the prices fed by oracle operators.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum PriceAggregationStrategy {
+	/// The median of the prices.
+	Median,
+	/// The average of the prices which deviate from the median by no more
+	/// than `max_deviation`.
+	MedianWithOutlierRejection { max_deviation: Ratio },
+}
+
+impl Default for PriceAggregationStrategy {
+	fn default() -> Self {
+		PriceAggregationStrategy::Median
+	}
+}
+
 pub trait PriceProvider<CurrencyId> {
 	fn get_price(currency_id: CurrencyId) -> Option<Price>;
 	fn get_relative_price(base: CurrencyId, quote: CurrencyId) -> Option<Price> {
@@ -284,6 +621,16 @@ pub trait PriceProvider<CurrencyId> {
 	}
 }
 
+pub trait PriceCircuitBreaker<CurrencyId> {
+	fn is_frozen(currency_id: CurrencyId) -> bool;
+}
+
+impl<CurrencyId> PriceCircuitBreaker<CurrencyId> for () {
+	fn is_frozen(_: CurrencyId) -> bool {
+		false
+	}
+}
+
 pub trait LockablePrice<CurrencyId> {
 	fn lock_price(currency_id: CurrencyId) -> DispatchResult;
 	fn unlock_price(currency_id: CurrencyId) -> DispatchResult;
@@ -293,10 +640,67 @@ pub trait ExchangeRateProvider {
 	fn get_exchange_rate() -> ExchangeRate;
 }
 
+/// User entrance of the Honzon protocol.
+pub trait HonzonManager<AccountId, CurrencyId, Amount, Balance> {
+	/// Adjust the collateral and debit of the CDP of `who` under `currency_id`.
+	fn adjust_loan(
+		who: &AccountId,
+		currency_id: CurrencyId,
+		collateral_adjustment: Amount,
+		debit_adjustment: Amount,
+	) -> DispatchResult;
+	/// The `(collateral, debit)` of the CDP of `who` under `currency_id`.
+	fn get_position(who: &AccountId, currency_id: CurrencyId) -> (Balance, Balance);
+	/// The liquidation ratio of `currency_id`.
+	fn get_liquidation_ratio(currency_id: CurrencyId) -> Ratio;
+	/// The collateral ratio of the CDP of `who` under `currency_id`, `None` if
+	/// there is no collateral price.
+	fn get_current_collateral_ratio(who: &AccountId, currency_id: CurrencyId) -> Option<Ratio>;
+	/// The debit exchange rate of `currency_id`.
+	fn get_debit_exchange_rate(currency_id: CurrencyId) -> ExchangeRate;
+}
+
+/// Liquid staking entrance of the Homa protocol.
+pub trait HomaManager<AccountId, Balance> {
+	/// Mint liquid currency with `amount` of staking currency.
+	fn mint(who: &AccountId, amount: Balance) -> DispatchResult;
+	/// Redeem `amount` of liquid currency. If `fast_match` is true the
+	/// redemption is matched immediately with the available staking currency,
+	/// otherwise it waits for unbonding.
+	fn request_redeem(who: &AccountId, amount: Balance, fast_match: bool) -> DispatchResult;
+	/// The exchange rate of liquid currency to staking currency.
+	fn get_exchange_rate() -> ExchangeRate;
+}
+
 pub trait EmergencyShutdown {
 	fn is_shutdown() -> bool;
 }
 
+/// PoolId for various rewards pools
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum PoolId {
+	/// Rewards and shares pool for users who open CDP(CollateralCurrencyId)
+	Loans(CurrencyId),
+
+	/// Rewards and shares pool for DEX makers who stake LP token(LPCurrencyId)
+	Dex(CurrencyId),
+}
+
+/// User entrance of the Incentives protocol.
+pub trait IncentivesManager<AccountId, Balance, CurrencyId, PoolId> {
+	/// Stake `amount` of LP token `lp_currency_id` to add shares of `PoolId::Dex`.
+	fn deposit_dex_share(who: &AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult;
+	/// Unstake `amount` of LP token `lp_currency_id` to remove shares of
+	/// `PoolId::Dex`.
+	fn withdraw_dex_share(who: &AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult;
+	/// Claim all available rewards of `who` in `pool_id`.
+	fn claim_rewards(who: &AccountId, pool_id: PoolId) -> DispatchResult;
+	/// The rewards amount of `reward_currency` which `who` can claim in
+	/// `pool_id`, before the claim deduction.
+	fn get_pending_rewards(pool_id: PoolId, who: &AccountId, reward_currency: CurrencyId) -> Balance;
+}
+
 pub trait DEXIncentives<AccountId, CurrencyId, Balance> {
 	fn do_deposit_dex_share(who: &AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult;
 	fn do_withdraw_dex_share(who: &AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult;
@@ -317,6 +721,43 @@ pub trait PrecompileCallerFilter {
 	fn is_allowed(caller: H160) -> bool;
 }
 
+/// The result of dispatching a background task.
+#[derive(RuntimeDebug, PartialEq, Eq)]
+pub struct TaskResult {
+	pub result: DispatchResult,
+	pub used_weight: Weight,
+	pub finished: bool,
+}
+
+/// A background task which can be dispatched in parts across multiple blocks.
+pub trait DispatchableTask {
+	/// Dispatch the task using at most `weight`. The task is dispatched again
+	/// later if it is not finished.
+	fn dispatch(self, weight: Weight) -> TaskResult;
+}
+
+/// Schedule background tasks to be dispatched in the idle time of blocks.
+pub trait IdleScheduler<Task> {
+	fn schedule(task: Task) -> Result<u32, DispatchError>;
+}
+
+impl<Task> IdleScheduler<Task> for () {
+	fn schedule(_task: Task) -> Result<u32, DispatchError> {
+		Err(DispatchError::Other("IdleScheduler is not available"))
+	}
+}
+
+/// Return true if the EVM precompile contract at `address` is paused.
+pub trait PrecompilePauseFilter {
+	fn is_paused(address: H160) -> bool;
+}
+
+impl PrecompilePauseFilter for () {
+	fn is_paused(_: H160) -> bool {
+		false
+	}
+}
+
 /// An abstraction of EVM for EVMBridge
 pub trait EVM<AccountId> {
 	type Balance: AtLeast32BitUnsigned + Copy + MaybeSerializeDeserialize + Default;
@@ -433,6 +874,8 @@ pub trait TransactionPayment<AccountId, Balance, NegativeImbalance> {
 		pays_fee: Pays,
 		class: DispatchClass,
 	) -> Result<(), TransactionValidityError>;
+	/// The fee of `weight` adjusted by the current fee multiplier.
+	fn weight_to_fee(weight: Weight) -> Balance;
 }
 
 #[cfg(feature = "std")]
@@ -472,6 +915,22 @@ impl<AccountId, Balance: Default + Copy, NegativeImbalance: Imbalance<Balance>>
 	) -> Result<(), TransactionValidityError> {
 		Ok(())
 	}
+
+	fn weight_to_fee(_weight: Weight) -> Balance {
+		Default::default()
+	}
+}
+
+/// Fee swap paths of calls which pay the transaction fee in a currency they
+/// supply, tried before the fee swap paths of the account.
+pub trait CallFeeSwapPath<Call, CurrencyId> {
+	fn fee_swap_path_list(call: &Call) -> Vec<Vec<CurrencyId>>;
+}
+
+impl<Call, CurrencyId> CallFeeSwapPath<Call, CurrencyId> for () {
+	fn fee_swap_path_list(_call: &Call) -> Vec<Vec<CurrencyId>> {
+		vec![]
+	}
 }
 
 pub trait Contains<T> {
@@ -562,3 +1021,17 @@ impl CurrencyIdMapping for () {
 pub trait CompoundCashTrait<Balance, Moment> {
 	fn set_future_yield(next_cash_yield: Balance, yield_index: u128, timestamp_effective: Moment) -> DispatchResult;
 }
+
+/// Issue and burn NFT tokens on behalf of the owner of their class.
+pub trait NFTIssuer<AccountId> {
+	type ClassId: FullCodec + Eq + PartialEq + Copy + Debug;
+	type TokenId: FullCodec + Eq + PartialEq + Copy + Debug;
+
+	/// Mint a token of `class_id` to `to`, the deposit is paid by the class
+	/// owner.
+	fn issue(to: &AccountId, class_id: Self::ClassId, metadata: Vec<u8>) -> Result<Self::TokenId, DispatchError>;
+	/// Burn `token` owned by `who`.
+	fn burn(who: &AccountId, token: (Self::ClassId, Self::TokenId)) -> DispatchResult;
+	/// The owner of `token`.
+	fn owner(token: (Self::ClassId, Self::TokenId)) -> Option<AccountId>;
+}
@@ -22,13 +22,16 @@
 use frame_support::{
 	dispatch::{CallMetadata, GetCallMetadata},
 	pallet_prelude::*,
-	traits::{Contains, PalletInfoAccess},
+	traits::{Contains, PalletInfoAccess, StorageVersion},
 	transactional,
 };
 use frame_system::pallet_prelude::*;
+use primitives::evm::EvmAddress;
 use sp_runtime::DispatchResult;
 use sp_std::{prelude::*, vec::Vec};
+use support::PrecompilePauseFilter;
 
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -57,25 +60,45 @@ pub mod module {
 		CannotPause,
 		/// invalid character encoding
 		InvalidCharacter,
+		/// the expiry is not after the current block
+		InvalidExpiry,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// Paused transaction . \[pallet_name_bytes, function_name_bytes\]
-		TransactionPaused(Vec<u8>, Vec<u8>),
+		/// Paused transaction . \[pallet_name_bytes, function_name_bytes,
+		/// expiry\]
+		TransactionPaused(Vec<u8>, Vec<u8>, Option<T::BlockNumber>),
 		/// Unpaused transaction . \[pallet_name_bytes, function_name_bytes\]
 		TransactionUnpaused(Vec<u8>, Vec<u8>),
+		/// Paused EVM precompile. \[address, expiry\]
+		EvmPrecompilePaused(EvmAddress, Option<T::BlockNumber>),
+		/// Unpaused EVM precompile. \[address\]
+		EvmPrecompileUnpaused(EvmAddress),
 	}
 
-	/// The paused transaction map
+	/// The paused transaction map, paused until the expiry if any
 	///
-	/// map (PalletNameBytes, FunctionNameBytes) => Option<()>
+	/// map (PalletNameBytes, FunctionNameBytes) => Option<Expiry>
 	#[pallet::storage]
 	#[pallet::getter(fn paused_transactions)]
-	pub type PausedTransactions<T: Config> = StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), (), OptionQuery>;
+	pub type PausedTransactions<T: Config> =
+		StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), Option<T::BlockNumber>, OptionQuery>;
+
+	/// The paused EVM precompile map, paused until the expiry if any
+	///
+	/// map EvmAddress => Option<Expiry>
+	#[pallet::storage]
+	#[pallet::getter(fn paused_evm_precompiles)]
+	pub type PausedEvmPrecompiles<T: Config> =
+		StorageMap<_, Twox64Concat, EvmAddress, Option<T::BlockNumber>, OptionQuery>;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -83,10 +106,18 @@ pub mod module {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
+		/// Pause the call `function_name` of `pallet_name`, until the block
+		/// `expiry` if any.
 		#[pallet::weight(T::WeightInfo::pause_transaction())]
 		#[transactional]
-		pub fn pause_transaction(origin: OriginFor<T>, pallet_name: Vec<u8>, function_name: Vec<u8>) -> DispatchResult {
+		pub fn pause_transaction(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+			expiry: Option<T::BlockNumber>,
+		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::ensure_valid_expiry(expiry)?;
 
 			// not allowed to pause calls of this pallet to ensure safe
 			let pallet_name_string = sp_std::str::from_utf8(&pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
@@ -96,9 +127,9 @@ pub mod module {
 			);
 
 			PausedTransactions::<T>::mutate_exists((pallet_name.clone(), function_name.clone()), |maybe_paused| {
-				if maybe_paused.is_none() {
-					*maybe_paused = Some(());
-					Self::deposit_event(Event::TransactionPaused(pallet_name, function_name));
+				if *maybe_paused != Some(expiry) {
+					*maybe_paused = Some(expiry);
+					Self::deposit_event(Event::TransactionPaused(pallet_name, function_name, expiry));
 				}
 			});
 			Ok(())
@@ -117,6 +148,54 @@ pub mod module {
 			};
 			Ok(())
 		}
+
+		/// Pause the EVM precompile at `address`, until the block `expiry` if
+		/// any.
+		#[pallet::weight(T::WeightInfo::pause_evm_precompile())]
+		#[transactional]
+		pub fn pause_evm_precompile(
+			origin: OriginFor<T>,
+			address: EvmAddress,
+			expiry: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::ensure_valid_expiry(expiry)?;
+
+			PausedEvmPrecompiles::<T>::mutate_exists(address, |maybe_paused| {
+				if *maybe_paused != Some(expiry) {
+					*maybe_paused = Some(expiry);
+					Self::deposit_event(Event::EvmPrecompilePaused(address, expiry));
+				}
+			});
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::unpause_evm_precompile())]
+		#[transactional]
+		pub fn unpause_evm_precompile(origin: OriginFor<T>, address: EvmAddress) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if PausedEvmPrecompiles::<T>::take(address).is_some() {
+				Self::deposit_event(Event::EvmPrecompileUnpaused(address));
+			};
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn ensure_valid_expiry(expiry: Option<T::BlockNumber>) -> DispatchResult {
+		if let Some(expiry) = expiry {
+			ensure!(
+				expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidExpiry
+			);
+		}
+		Ok(())
+	}
+
+	/// Whether a pause until `expiry` is still in effect.
+	fn is_in_effect(expiry: Option<T::BlockNumber>) -> bool {
+		expiry.map_or(true, |expiry| frame_system::Pallet::<T>::block_number() < expiry)
 	}
 }
 
@@ -130,6 +209,14 @@ where
 			function_name,
 			pallet_name,
 		} = call.get_call_metadata();
-		PausedTransactions::<T>::contains_key((pallet_name.as_bytes(), function_name.as_bytes()))
+		PausedTransactions::<T>::get((pallet_name.as_bytes(), function_name.as_bytes()))
+			.map_or(false, Pallet::<T>::is_in_effect)
+	}
+}
+
+pub struct PausedPrecompileFilter<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> PrecompilePauseFilter for PausedPrecompileFilter<T> {
+	fn is_paused(address: EvmAddress) -> bool {
+		PausedEvmPrecompiles::<T>::get(address).map_or(false, Pallet::<T>::is_in_effect)
 	}
 }
@@ -0,0 +1,76 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Config, Pallet, PausedTransactions};
+use frame_support::{
+	log,
+	traits::{Get, StorageVersion},
+	weights::Weight,
+};
+use sp_std::vec::Vec;
+
+pub mod v1 {
+	use super::*;
+
+	/// Paused transactions had no expiry before, migrate them to be paused
+	/// until unpaused. Applied once, if the on-chain storage version is still
+	/// 0.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() != 0 {
+			log::info!(target: "transaction-pause", "transaction-pause v1 migration is already applied, skip");
+			return T::DbWeight::get().reads(1);
+		}
+		log::info!(target: "transaction-pause", "Migrating transaction-pause v1");
+
+		let mut count: Weight = 0;
+		PausedTransactions::<T>::translate::<(), _>(|_: (Vec<u8>, Vec<u8>), _| {
+			count += 1;
+			Some(None)
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+		log::info!(target: "transaction-pause", "Completed transaction-pause migration to v1");
+
+		T::DbWeight::get().reads_writes(count + 1, count + 1)
+	}
+
+	/// The number of paused transactions to migrate.
+	#[cfg(feature = "try-runtime")]
+	pub fn pre_migrate<T: Config>() -> Result<u32, &'static str> {
+		Ok(PausedTransactions::<T>::iter_keys().count() as u32)
+	}
+
+	/// Ensure all the `count` paused transactions decode with an expiry and
+	/// stay paused until unpaused, and the storage version is bumped.
+	#[cfg(feature = "try-runtime")]
+	pub fn post_migrate<T: Config>(count: u32) -> Result<(), &'static str> {
+		frame_support::ensure!(
+			StorageVersion::get::<Pallet<T>>() == 1,
+			"transaction-pause v1: storage version is not bumped"
+		);
+		let expiries = PausedTransactions::<T>::iter_values().collect::<Vec<_>>();
+		frame_support::ensure!(
+			expiries.len() as u32 == count,
+			"transaction-pause v1: not all paused transactions are migrated"
+		);
+		frame_support::ensure!(
+			expiries.iter().all(Option::is_none),
+			"transaction-pause v1: migrated paused transactions must not expire"
+		);
+		Ok(())
+	}
+}
@@ -36,7 +36,7 @@ fn pause_transaction_work() {
 		System::set_block_number(1);
 
 		assert_noop!(
-			TransactionPause::pause_transaction(Origin::signed(5), b"Balances".to_vec(), b"transfer".to_vec()),
+			TransactionPause::pause_transaction(Origin::signed(5), b"Balances".to_vec(), b"transfer".to_vec(), None),
 			BadOrigin
 		);
 
@@ -47,22 +47,25 @@ fn pause_transaction_work() {
 		assert_ok!(TransactionPause::pause_transaction(
 			Origin::signed(1),
 			b"Balances".to_vec(),
-			b"transfer".to_vec()
+			b"transfer".to_vec(),
+			None
 		));
 		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionPaused(
 			b"Balances".to_vec(),
 			b"transfer".to_vec(),
+			None,
 		)));
 		assert_eq!(
 			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
-			Some(())
+			Some(None)
 		);
 
 		assert_noop!(
 			TransactionPause::pause_transaction(
 				Origin::signed(1),
 				b"TransactionPause".to_vec(),
-				b"pause_transaction".to_vec()
+				b"pause_transaction".to_vec(),
+				None
 			),
 			Error::<Runtime>::CannotPause
 		);
@@ -70,14 +73,16 @@ fn pause_transaction_work() {
 			TransactionPause::pause_transaction(
 				Origin::signed(1),
 				b"TransactionPause".to_vec(),
-				b"some_other_call".to_vec()
+				b"some_other_call".to_vec(),
+				None
 			),
 			Error::<Runtime>::CannotPause
 		);
 		assert_ok!(TransactionPause::pause_transaction(
 			Origin::signed(1),
 			b"OtherPallet".to_vec(),
-			b"pause_transaction".to_vec()
+			b"pause_transaction".to_vec(),
+			None
 		));
 	});
 }
@@ -90,11 +95,12 @@ fn unpause_transaction_work() {
 		assert_ok!(TransactionPause::pause_transaction(
 			Origin::signed(1),
 			b"Balances".to_vec(),
-			b"transfer".to_vec()
+			b"transfer".to_vec(),
+			None
 		));
 		assert_eq!(
 			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
-			Some(())
+			Some(None)
 		);
 
 		assert_noop!(
@@ -126,12 +132,14 @@ fn paused_transaction_filter_work() {
 		assert_ok!(TransactionPause::pause_transaction(
 			Origin::signed(1),
 			b"Balances".to_vec(),
-			b"transfer".to_vec()
+			b"transfer".to_vec(),
+			None
 		));
 		assert_ok!(TransactionPause::pause_transaction(
 			Origin::signed(1),
 			b"Tokens".to_vec(),
-			b"transfer".to_vec()
+			b"transfer".to_vec(),
+			None
 		));
 		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
 		assert!(PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
@@ -149,3 +157,113 @@ fn paused_transaction_filter_work() {
 		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
 	});
 }
+
+#[test]
+fn pause_transaction_with_expiry_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(10);
+
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				Origin::signed(1),
+				b"Balances".to_vec(),
+				b"transfer".to_vec(),
+				Some(10)
+			),
+			Error::<Runtime>::InvalidExpiry
+		);
+
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			Some(20)
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionPaused(
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			Some(20),
+		)));
+		assert_eq!(
+			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+			Some(Some(20))
+		);
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+
+		System::set_block_number(19);
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+
+		System::set_block_number(20);
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+	});
+}
+
+#[test]
+fn pause_evm_precompile_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let precompile = EvmAddress::from_low_u64_be(1024);
+
+		assert_noop!(
+			TransactionPause::pause_evm_precompile(Origin::signed(5), precompile, None),
+			BadOrigin
+		);
+		assert_noop!(
+			TransactionPause::pause_evm_precompile(Origin::signed(1), precompile, Some(1)),
+			Error::<Runtime>::InvalidExpiry
+		);
+
+		assert!(!PausedPrecompileFilter::<Runtime>::is_paused(precompile));
+		assert_ok!(TransactionPause::pause_evm_precompile(
+			Origin::signed(1),
+			precompile,
+			Some(10)
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::EvmPrecompilePaused(
+			precompile,
+			Some(10),
+		)));
+		assert_eq!(TransactionPause::paused_evm_precompiles(precompile), Some(Some(10)));
+		assert!(PausedPrecompileFilter::<Runtime>::is_paused(precompile));
+
+		System::set_block_number(10);
+		assert!(!PausedPrecompileFilter::<Runtime>::is_paused(precompile));
+
+		assert_noop!(
+			TransactionPause::unpause_evm_precompile(Origin::signed(5), precompile),
+			BadOrigin
+		);
+		assert_ok!(TransactionPause::unpause_evm_precompile(Origin::signed(1), precompile));
+		System::assert_last_event(Event::TransactionPause(crate::Event::EvmPrecompileUnpaused(precompile)));
+		assert_eq!(TransactionPause::paused_evm_precompiles(precompile), None);
+	});
+}
+
+#[test]
+fn migrate_paused_transactions_to_v1_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let key = PausedTransactions::<Runtime>::hashed_key_for((b"Balances".to_vec(), b"transfer".to_vec()));
+		frame_support::storage::unhashed::put_raw(&key, &[]);
+		StorageVersion::new(0).put::<TransactionPause>();
+
+		migrations::v1::migrate::<Runtime>();
+		assert_eq!(StorageVersion::get::<TransactionPause>(), 1);
+		assert_eq!(
+			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+			Some(None)
+		);
+
+		// the migration is applied only once, an expiry set after it is kept
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			Some(10)
+		));
+		migrations::v1::migrate::<Runtime>();
+		assert_eq!(
+			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+			Some(Some(10))
+		);
+	});
+}
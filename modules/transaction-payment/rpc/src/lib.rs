@@ -0,0 +1,124 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the transaction payment module.
+
+use codec::{Codec, Decode};
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::{convert::TryInto, sync::Arc};
+
+pub use self::gen_client::Client as TransactionFeeClient;
+pub use module_transaction_payment_rpc_runtime_api::TransactionFeeApi as TransactionFeeRuntimeApi;
+
+#[rpc]
+pub trait TransactionFeeApi<BlockHash, CurrencyId> {
+	#[rpc(name = "payment_queryFeeDetailsInCurrency")]
+	fn query_fee_in_currency(
+		&self,
+		encoded_xt: Bytes,
+		currency_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> Result<NumberOrHex>;
+}
+
+/// A struct that implements the [`TransactionFeeApi`].
+pub struct TransactionFee<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> TransactionFee<C, B> {
+	/// Create new `TransactionFee` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		TransactionFee {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+	DecodeError,
+	CannotPayInCurrency,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::DecodeError => 2,
+			Error::CannotPayInCurrency => 3,
+		}
+	}
+}
+
+impl<C, Block, Balance, CurrencyId> TransactionFeeApi<<Block as BlockT>::Hash, CurrencyId>
+	for TransactionFee<C, (Block, Balance)>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: TransactionFeeRuntimeApi<Block, Balance, CurrencyId>,
+	Balance: Codec + TryInto<NumberOrHex> + Send + Sync + 'static,
+	CurrencyId: Codec,
+{
+	fn query_fee_in_currency(
+		&self,
+		encoded_xt: Bytes,
+		currency_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<NumberOrHex> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		let encoded_len = encoded_xt.len() as u32;
+		let uxt: Block::Extrinsic = Decode::decode(&mut &*encoded_xt).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::DecodeError.into()),
+			message: "Unable to query fee in currency.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+
+		let amount = api
+			.query_fee_in_currency(&at, uxt, encoded_len, currency_id)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to query fee in currency.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})?
+			.ok_or(RpcError {
+				code: ErrorCode::ServerError(Error::CannotPayInCurrency.into()),
+				message: "The fee cannot be paid in the currency.".into(),
+				data: None,
+			})?;
+
+		amount.try_into().map_err(|_| RpcError {
+			code: ErrorCode::InvalidParams,
+			message: "Doesn't fit in NumberOrHex representation".into(),
+			data: None,
+		})
+	}
+}
@@ -22,6 +22,18 @@
 //!
 //! Transaction payment module is responsible for charge fee and tip in
 //! different currencies
+//!
+//! If the native currency is not enough, the fee is swapped from other
+//! currencies by the fee swap paths. A path which starts with a DEX share
+//! removes a sliver of the liquidity first, and swaps the withdrawn token
+//! which follows in the path.
+//!
+//! The fee of a call can be paid by a sponsor, by wrapping it in
+//! `with_fee_paid_by` with an approval signed by the sponsor, which limits
+//! the max fee, the max total fee paid by the sponsor, the expiry and the
+//! calls allowed. The approval is bound to the chain by the genesis hash, and
+//! can be used until it expires or its max total fee is paid. The fee paid is
+//! tracked per approval, so the approvals of a sponsor are independent.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -30,8 +42,10 @@ use frame_support::{
 	dispatch::{DispatchResult, Dispatchable},
 	pallet_prelude::*,
 	traits::{
-		Currency, ExistenceRequirement, Imbalance, NamedReservableCurrency, OnUnbalanced, SameOrOther, WithdrawReasons,
+		Currency, ExistenceRequirement, GetCallMetadata, Imbalance, IsSubType, NamedReservableCurrency, OnUnbalanced,
+		SameOrOther, WithdrawReasons,
 	},
+	transactional,
 	weights::{DispatchInfo, GetDispatchInfo, Pays, PostDispatchInfo, WeightToFeeCoefficient, WeightToFeePolynomial},
 	BoundedVec,
 };
@@ -41,17 +55,18 @@ use pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo;
 use pallet_transaction_payment_rpc_runtime_api::{FeeDetails, InclusionFee};
 use primitives::{Balance, CurrencyId, ReserveIdentifier};
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
 	traits::{
-		Bounded, CheckedSub, Convert, DispatchInfoOf, One, PostDispatchInfoOf, SaturatedConversion, Saturating,
-		SignedExtension, UniqueSaturatedInto, Zero,
+		Bounded, CheckedSub, Convert, DispatchInfoOf, Hash, IdentifyAccount, One, PostDispatchInfoOf,
+		SaturatedConversion, Saturating, SignedExtension, UniqueSaturatedInto, Verify, Zero,
 	},
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionValidity, TransactionValidityError, ValidTransaction,
 	},
-	FixedPointNumber, FixedPointOperand, FixedU128, Perquintill,
+	ArithmeticError, FixedPointNumber, FixedPointOperand, FixedU128, Perquintill, RuntimeDebug,
 };
 use sp_std::{convert::TryInto, prelude::*, vec};
-use support::{DEXManager, PriceProvider, Ratio, TransactionPayment};
+use support::{CallFeeSwapPath, DEXManager, PriceProvider, Ratio, TransactionPayment};
 
 mod mock;
 mod tests;
@@ -63,6 +78,41 @@ pub use weights::WeightInfo;
 /// Fee multiplier.
 pub type Multiplier = FixedU128;
 
+/// The calls a sponsor approves to pay the fee for.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub enum SponsorScope<Hash> {
+	/// The call of the hash.
+	CallHash(Hash),
+	/// Any call of the pallet.
+	Pallet(Vec<u8>),
+}
+
+/// The approval of a sponsor to pay the fee of the calls of an account.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct SponsorApproval<AccountId, BlockNumber, Hash> {
+	/// The sponsor who pays the fee.
+	pub sponsor: AccountId,
+	/// The account whose calls are sponsored.
+	pub who: AccountId,
+	/// The max fee for a call.
+	pub max_fee: Balance,
+	/// The max total fee paid by the sponsor for the approval.
+	pub max_total_fee: Balance,
+	/// The nonce chosen by the sponsor, which tells apart the approvals of
+	/// the same terms.
+	pub nonce: u32,
+	/// The last block the approval is valid at.
+	pub expiry: BlockNumber,
+	/// The calls approved.
+	pub scope: SponsorScope<Hash>,
+}
+
+pub type SponsorApprovalOf<T> = SponsorApproval<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+	<T as frame_system::Config>::Hash,
+>;
+
 type PalletBalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 type NegativeImbalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
@@ -269,6 +319,22 @@ pub mod module {
 		/// The price source to provider external market price.
 		type PriceSource: PriceProvider<CurrencyId>;
 
+		/// The fee swap paths of calls which pay the fee in their supply
+		/// currency.
+		type CallFeeSwapPath: CallFeeSwapPath<<Self as frame_system::Config>::Call, CurrencyId>;
+
+		/// The overarching call type, for calls whose fee is paid by sponsors.
+		type Call: Parameter
+			+ Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ GetCallMetadata;
+
+		/// The signature of sponsor approvals.
+		type SponsorSignature: Parameter + Verify<Signer = Self::SponsorSigner>;
+
+		/// The signer of sponsor approvals.
+		type SponsorSigner: IdentifyAccount<AccountId = Self::AccountId>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -292,6 +358,13 @@ pub mod module {
 	pub enum Error<T> {
 		/// The swap path is invalid
 		InvalidSwapPath,
+		/// The sponsor approval is not signed by the sponsor, or not for the
+		/// caller
+		InvalidSponsorApproval,
+		/// The sponsor approval has expired
+		SponsorApprovalExpired,
+		/// The call is not in the scope of the sponsor approval
+		CallNotSponsored,
 	}
 
 	/// The next fee multiplier.
@@ -307,6 +380,14 @@ pub mod module {
 	pub type AlternativeFeeSwapPath<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<CurrencyId, T::TradingPathLimit>, OptionQuery>;
 
+	/// The total fee paid by sponsors for the approvals, keyed by the hash of
+	/// the approval.
+	///
+	/// SponsorFeePaid: map Hash => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn sponsor_fee_paid)]
+	pub type SponsorFeePaid<T: Config> = StorageMap<_, Identity, T::Hash, Balance, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -385,12 +466,99 @@ pub mod module {
 						&& path[path.len() - 1] == T::NativeCurrencyId::get(),
 					Error::<T>::InvalidSwapPath
 				);
+				// a DEX share must be followed by one of its tokens
+				if let Some((currency_id_0, currency_id_1)) = path[0].split_dex_share_currency_id() {
+					ensure!(
+						path[1] == currency_id_0 || path[1] == currency_id_1,
+						Error::<T>::InvalidSwapPath
+					);
+				}
 				AlternativeFeeSwapPath::<T>::insert(&who, &path);
 			} else {
 				AlternativeFeeSwapPath::<T>::remove(&who);
 			}
 			Ok(())
 		}
+
+		/// Dispatch `call` by the caller, with the fee paid by the sponsor of
+		/// `approval`.
+		///
+		/// - `call`: the call to dispatch.
+		/// - `approval`: the approval of the sponsor.
+		/// - `sponsor_signature`: the signature of the sponsor on the genesis
+		///   hash and the encoded `approval`.
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				<T as Config>::WeightInfo::with_fee_paid_by().saturating_add(dispatch_info.weight),
+				dispatch_info.class,
+			)
+		})]
+		pub fn with_fee_paid_by(
+			origin: OriginFor<T>,
+			call: Box<<T as Config>::Call>,
+			approval: SponsorApprovalOf<T>,
+			sponsor_signature: T::SponsorSignature,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_sponsor_approval(&who, &call, &approval, &sponsor_signature)?;
+
+			let base_weight = <T as Config>::WeightInfo::with_fee_paid_by();
+			call.dispatch(frame_system::RawOrigin::Signed(who).into())
+				.map(|post_info| {
+					post_info
+						.actual_weight
+						.map(|weight| weight.saturating_add(base_weight))
+						.into()
+				})
+				.map_err(|mut err| {
+					err.post_info = err
+						.post_info
+						.actual_weight
+						.map(|weight| weight.saturating_add(base_weight))
+						.into();
+					err
+				})
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The payload signed by the sponsor for `approval`, which binds it to
+	/// the chain by the genesis hash.
+	pub fn sponsor_approval_payload(approval: &SponsorApprovalOf<T>) -> Vec<u8> {
+		(frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero()), approval).encode()
+	}
+
+	/// The hash of `approval`, which the fee paid for it is keyed by.
+	pub fn sponsor_approval_hash(approval: &SponsorApprovalOf<T>) -> T::Hash {
+		T::Hashing::hash_of(approval)
+	}
+
+	/// Ensure `approval` is signed by the sponsor for `who`, not expired and
+	/// allows `call`.
+	pub fn ensure_sponsor_approval(
+		who: &T::AccountId,
+		call: &<T as Config>::Call,
+		approval: &SponsorApprovalOf<T>,
+		sponsor_signature: &T::SponsorSignature,
+	) -> Result<(), Error<T>> {
+		ensure!(
+			approval.who == *who
+				&& sponsor_signature.verify(&Self::sponsor_approval_payload(approval)[..], &approval.sponsor),
+			Error::<T>::InvalidSponsorApproval
+		);
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <= approval.expiry,
+			Error::<T>::SponsorApprovalExpired
+		);
+
+		let sponsored = match &approval.scope {
+			SponsorScope::CallHash(hash) => *hash == T::Hashing::hash_of(call),
+			SponsorScope::Pallet(pallet_name) => pallet_name[..] == *call.get_call_metadata().pallet_name.as_bytes(),
+		};
+		ensure!(sponsored, Error::<T>::CallNotSponsored);
+		Ok(())
 	}
 }
 
@@ -440,20 +608,71 @@ where
 		len: u32,
 	) -> FeeDetails<PalletBalanceOf<T>>
 	where
-		T::Call: Dispatchable<Info = DispatchInfo>,
+		<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo>,
 	{
 		let dispatch_info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
 		Self::compute_fee_details(len, &dispatch_info, 0u32.into())
 	}
 
+	/// Query the amount of `currency_id` deducted to pay the fee of a given
+	/// `call`, including the DEX swap to native currency by the default fee
+	/// swap paths. Returns `None` if the fee cannot be paid in
+	/// `currency_id`.
+	///
+	/// The account is assumed to keep the existential deposit of native
+	/// currency, otherwise the gap is swapped too.
+	pub fn query_fee_in_currency<Extrinsic: GetDispatchInfo>(
+		unchecked_extrinsic: Extrinsic,
+		len: u32,
+		currency_id: CurrencyId,
+	) -> Option<Balance>
+	where
+		<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo>,
+	{
+		let dispatch_info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
+		let fee = Self::compute_fee(len, &dispatch_info, 0u32.into());
+		Self::fee_in_currency(fee.unique_saturated_into(), currency_id)
+	}
+
+	/// The amount of `currency_id` to swap `fee` native currency, following
+	/// the swap for fee in `ensure_can_charge_fee`.
+	fn fee_in_currency(fee: Balance, currency_id: CurrencyId) -> Option<Balance> {
+		let native_currency_id = T::NativeCurrencyId::get();
+		if currency_id == native_currency_id {
+			return Some(fee);
+		}
+
+		if let Some((currency_id_0, currency_id_1)) = currency_id.split_dex_share_currency_id() {
+			// the share to remove for the cheaper token of the pair
+			let total_shares = <T as Config>::MultiCurrency::total_issuance(currency_id);
+			return [(currency_id_0, currency_id_1), (currency_id_1, currency_id_0)]
+				.iter()
+				.filter_map(|(supply_currency_id, other_currency_id)| {
+					let supply_amount = Self::fee_in_currency(fee, *supply_currency_id)?;
+					let (supply_pool, _) = T::DEX::get_liquidity_pool(*supply_currency_id, *other_currency_id);
+					multiply_by_rational(supply_amount, total_shares, supply_pool)
+						.ok()
+						.map(|share| share.saturating_add(One::one()))
+				})
+				.min();
+		}
+
+		T::DefaultFeeSwapPathList::get()
+			.into_iter()
+			.filter(|path| path.first() == Some(&currency_id) && path.last() == Some(&native_currency_id))
+			.chain(sp_std::iter::once(vec![currency_id, native_currency_id]))
+			.find_map(|path| T::DEX::get_swap_supply_amount(&path, fee))
+			.filter(|supply_amount| *supply_amount <= Self::max_supply_limit(currency_id, fee))
+	}
+
 	/// Compute the fee details for a particular transaction.
 	pub fn compute_fee_details(
 		len: u32,
-		info: &DispatchInfoOf<T::Call>,
+		info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
 		tip: PalletBalanceOf<T>,
 	) -> FeeDetails<PalletBalanceOf<T>>
 	where
-		T::Call: Dispatchable<Info = DispatchInfo>,
+		<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo>,
 	{
 		Self::compute_fee_raw(len, info.weight, tip, info.pays_fee, info.class)
 	}
@@ -495,12 +714,12 @@ where
 	/// transaction.
 	pub fn compute_actual_fee_details(
 		len: u32,
-		info: &DispatchInfoOf<T::Call>,
-		post_info: &PostDispatchInfoOf<T::Call>,
+		info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
+		post_info: &PostDispatchInfoOf<<T as frame_system::Config>::Call>,
 		tip: PalletBalanceOf<T>,
 	) -> FeeDetails<PalletBalanceOf<T>>
 	where
-		T::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+		<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
 	{
 		Self::compute_fee_raw(
 			len,
@@ -571,7 +790,15 @@ where
 		T::WeightToFee::calc(&capped_weight)
 	}
 
-	pub fn ensure_can_charge_fee(who: &T::AccountId, fee: PalletBalanceOf<T>, reason: WithdrawReasons) {
+	/// Swap to native currency if it's not enough to charge `fee`, by the
+	/// fee swap paths of the call first, then of the account and the
+	/// default ones.
+	pub fn ensure_can_charge_fee(
+		who: &T::AccountId,
+		fee: PalletBalanceOf<T>,
+		reason: WithdrawReasons,
+		call_fee_swap_path_list: Vec<Vec<CurrencyId>>,
+	) {
 		let native_existential_deposit = <T as Config>::Currency::minimum_balance();
 		let total_native = <T as Config>::Currency::total_balance(who);
 
@@ -591,38 +818,35 @@ where
 			let default_fee_swap_path_list = T::DefaultFeeSwapPathList::get();
 			let fee_swap_path_list: Vec<Vec<CurrencyId>> =
 				if let Some(trading_path) = AlternativeFeeSwapPath::<T>::get(who) {
-					vec![vec![trading_path.into_inner()], default_fee_swap_path_list].concat()
+					vec![
+						call_fee_swap_path_list,
+						vec![trading_path.into_inner()],
+						default_fee_swap_path_list,
+					]
+					.concat()
 				} else {
-					default_fee_swap_path_list
+					vec![call_fee_swap_path_list, default_fee_swap_path_list].concat()
 				};
 
 			for trading_path in fee_swap_path_list {
 				match trading_path.last() {
 					Some(target_currency_id) if *target_currency_id == native_currency_id => {
 						let supply_currency_id = *trading_path.first().expect("these's first guaranteed by match");
-						// calculate the supply limit according to oracle price and the slippage limit,
-						// if oracle price is not avalible, do not limit
-						let max_supply_limit = if let Some(target_price) =
-							T::PriceSource::get_relative_price(*target_currency_id, supply_currency_id)
-						{
-							Ratio::one()
-								.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
-								.reciprocal()
-								.unwrap_or_else(Ratio::max_value)
-								.saturating_mul_int(target_price.saturating_mul_int(amount))
+						let result = if supply_currency_id.is_dex_share_currency_id() {
+							Self::swap_from_dex_share(who, &trading_path, amount.unique_saturated_into())
 						} else {
-							PalletBalanceOf::<T>::max_value()
+							T::DEX::swap_with_exact_target(
+								who,
+								&trading_path,
+								amount.unique_saturated_into(),
+								<T as Config>::MultiCurrency::free_balance(supply_currency_id, who).min(
+									Self::max_supply_limit(supply_currency_id, amount.unique_saturated_into()),
+								),
+							)
+							.map(|_| ())
 						};
 
-						if T::DEX::swap_with_exact_target(
-							who,
-							&trading_path,
-							amount.unique_saturated_into(),
-							<T as Config>::MultiCurrency::free_balance(supply_currency_id, who)
-								.min(max_supply_limit.unique_saturated_into()),
-						)
-						.is_ok()
-						{
+						if result.is_ok() {
 							// successfully swap, break iteration
 							break;
 						}
@@ -632,6 +856,75 @@ where
 			}
 		}
 	}
+
+	/// The max amount of `supply_currency_id` to swap `amount` native
+	/// currency, according to oracle price and the slippage limit. If oracle
+	/// price is not avalible, do not limit.
+	fn max_supply_limit(supply_currency_id: CurrencyId, amount: Balance) -> Balance {
+		if let Some(target_price) = T::PriceSource::get_relative_price(T::NativeCurrencyId::get(), supply_currency_id) {
+			Ratio::one()
+				.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
+				.reciprocal()
+				.unwrap_or_else(Ratio::max_value)
+				.saturating_mul_int(target_price.saturating_mul_int(amount))
+		} else {
+			Balance::max_value()
+		}
+	}
+
+	/// Remove a sliver of the liquidity of the DEX share at the head of
+	/// `trading_path`, enough to withdraw the token which follows, and swap
+	/// it to `amount` native currency by the rest of the path. The other
+	/// withdrawn token is kept by `who`.
+	#[transactional]
+	fn swap_from_dex_share(who: &T::AccountId, trading_path: &[CurrencyId], amount: Balance) -> DispatchResult {
+		let dex_share_currency_id = trading_path[0];
+		let swap_path = &trading_path[1..];
+		let supply_currency_id = *swap_path.first().ok_or(Error::<T>::InvalidSwapPath)?;
+		let (currency_id_0, currency_id_1) = dex_share_currency_id
+			.split_dex_share_currency_id()
+			.ok_or(Error::<T>::InvalidSwapPath)?;
+		let other_currency_id = if supply_currency_id == currency_id_0 {
+			currency_id_1
+		} else if supply_currency_id == currency_id_1 {
+			currency_id_0
+		} else {
+			return Err(Error::<T>::InvalidSwapPath.into());
+		};
+
+		let supply_amount = if swap_path.len() > 1 {
+			T::DEX::get_swap_supply_amount(swap_path, amount).ok_or(Error::<T>::InvalidSwapPath)?
+		} else {
+			amount
+		};
+
+		// round up to withdraw at least `supply_amount`
+		let (supply_pool, _) = T::DEX::get_liquidity_pool(supply_currency_id, other_currency_id);
+		let total_shares = <T as Config>::MultiCurrency::total_issuance(dex_share_currency_id);
+		let remove_share = multiply_by_rational(supply_amount, total_shares, supply_pool)
+			.map_err(|_| ArithmeticError::Overflow)?
+			.saturating_add(One::one());
+		T::DEX::remove_liquidity(
+			who,
+			supply_currency_id,
+			other_currency_id,
+			remove_share,
+			supply_amount,
+			Zero::zero(),
+			false,
+		)?;
+
+		if swap_path.len() > 1 {
+			T::DEX::swap_with_exact_target(
+				who,
+				swap_path,
+				amount,
+				<T as Config>::MultiCurrency::free_balance(supply_currency_id, who)
+					.min(Self::max_supply_limit(supply_currency_id, amount)),
+			)?;
+		}
+		Ok(())
+	}
 }
 
 impl<T> Convert<Weight, PalletBalanceOf<T>> for Pallet<T>
@@ -668,7 +961,8 @@ impl<T: Config + Send + Sync> sp_std::fmt::Debug for ChargeTransactionPayment<T>
 
 impl<T: Config + Send + Sync> ChargeTransactionPayment<T>
 where
-	<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	<T as frame_system::Config>::Call:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + IsSubType<Call<T>>,
 	PalletBalanceOf<T>: Send + Sync + FixedPointOperand,
 {
 	/// utility constructor. Used only in client/factory code.
@@ -679,16 +973,38 @@ where
 	fn withdraw_fee(
 		&self,
 		who: &T::AccountId,
-		_call: &<T as frame_system::Config>::Call,
+		call: &<T as frame_system::Config>::Call,
 		info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
 		len: usize,
-	) -> Result<(PalletBalanceOf<T>, Option<NegativeImbalanceOf<T>>), TransactionValidityError> {
+	) -> Result<(T::AccountId, PalletBalanceOf<T>, Option<NegativeImbalanceOf<T>>), TransactionValidityError> {
 		let tip = self.0;
 		let fee = Pallet::<T>::compute_fee(len as u32, info, tip);
 
+		// the fee of sponsored calls is paid by the sponsor
+		let payer =
+			if let Some(Call::with_fee_paid_by(sponsored_call, approval, sponsor_signature)) = call.is_sub_type() {
+				Pallet::<T>::ensure_sponsor_approval(who, sponsored_call, approval, sponsor_signature).map_err(
+					|e| match e {
+						Error::<T>::SponsorApprovalExpired => InvalidTransaction::Stale,
+						_ => InvalidTransaction::BadProof,
+					},
+				)?;
+				let fee = fee.saturated_into::<Balance>();
+				let approval_hash = Pallet::<T>::sponsor_approval_hash(approval);
+				let fee_paid = Pallet::<T>::sponsor_fee_paid(approval_hash).saturating_add(fee);
+				ensure!(
+					fee <= approval.max_fee && fee_paid <= approval.max_total_fee,
+					InvalidTransaction::Payment
+				);
+				SponsorFeePaid::<T>::insert(approval_hash, fee_paid);
+				approval.sponsor.clone()
+			} else {
+				who.clone()
+			};
+
 		// Only mess with balances if fee is not zero.
 		if fee.is_zero() {
-			return Ok((fee, None));
+			return Ok((payer, fee, None));
 		}
 
 		let reason = if tip.is_zero() {
@@ -697,11 +1013,11 @@ where
 			WithdrawReasons::TRANSACTION_PAYMENT | WithdrawReasons::TIP
 		};
 
-		Pallet::<T>::ensure_can_charge_fee(who, fee, reason);
+		Pallet::<T>::ensure_can_charge_fee(&payer, fee, reason, T::CallFeeSwapPath::fee_swap_path_list(call));
 
 		// withdraw native currency as fee
-		match <T as Config>::Currency::withdraw(who, fee, reason, ExistenceRequirement::KeepAlive) {
-			Ok(imbalance) => Ok((fee, Some(imbalance))),
+		match <T as Config>::Currency::withdraw(&payer, fee, reason, ExistenceRequirement::KeepAlive) {
+			Ok(imbalance) => Ok((payer, fee, Some(imbalance))),
 			Err(_) => Err(InvalidTransaction::Payment.into()),
 		}
 	}
@@ -738,7 +1054,8 @@ where
 impl<T: Config + Send + Sync> SignedExtension for ChargeTransactionPayment<T>
 where
 	PalletBalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
-	<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	<T as frame_system::Config>::Call:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + IsSubType<Call<T>>,
 {
 	const IDENTIFIER: &'static str = "ChargeTransactionPayment";
 	type AccountId = T::AccountId;
@@ -762,7 +1079,7 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> TransactionValidity {
-		let (fee, _) = self.withdraw_fee(who, call, info, len)?;
+		let (_, fee, _) = self.withdraw_fee(who, call, info, len)?;
 		Ok(ValidTransaction {
 			priority: Self::get_priority(len, info, fee),
 			..Default::default()
@@ -776,8 +1093,8 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
-		let (fee, imbalance) = self.withdraw_fee(who, call, info, len)?;
-		Ok((self.0, who.clone(), imbalance, fee))
+		let (payer, fee, imbalance) = self.withdraw_fee(who, call, info, len)?;
+		Ok((self.0, payer, imbalance, fee))
 	}
 
 	fn post_dispatch(
@@ -821,7 +1138,7 @@ where
 {
 	fn reserve_fee(who: &T::AccountId, weight: Weight) -> Result<PalletBalanceOf<T>, DispatchError> {
 		let fee = Pallet::<T>::weight_to_fee(weight);
-		Pallet::<T>::ensure_can_charge_fee(who, fee, WithdrawReasons::TRANSACTION_PAYMENT);
+		Pallet::<T>::ensure_can_charge_fee(who, fee, WithdrawReasons::TRANSACTION_PAYMENT, vec![]);
 		<T as Config>::Currency::reserve_named(&RESERVE_ID, who, fee)?;
 		Ok(fee)
 	}
@@ -884,7 +1201,7 @@ where
 	) -> Result<(), TransactionValidityError> {
 		let fee = Pallet::<T>::compute_fee_raw(len, weight, tip, pays_fee, class).final_fee();
 
-		Pallet::<T>::ensure_can_charge_fee(who, fee, WithdrawReasons::TRANSACTION_PAYMENT);
+		Pallet::<T>::ensure_can_charge_fee(who, fee, WithdrawReasons::TRANSACTION_PAYMENT, vec![]);
 
 		// withdraw native currency as fee
 		let actual_payment = <T as Config>::Currency::withdraw(
@@ -899,4 +1216,8 @@ where
 		<T as Config>::OnTransactionPayment::on_unbalanced(actual_payment);
 		Ok(())
 	}
+
+	fn weight_to_fee(weight: Weight) -> PalletBalanceOf<T> {
+		<Pallet<T> as Convert<Weight, PalletBalanceOf<T>>>::convert(weight)
+	}
 }
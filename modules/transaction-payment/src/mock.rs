@@ -32,7 +32,7 @@ use smallvec::smallvec;
 use sp_core::{crypto::AccountId32, H256};
 use sp_runtime::{testing::Header, traits::IdentityLookup, Perbill};
 use sp_std::cell::RefCell;
-use support::{mocks::MockAddressMapping, Price};
+use support::{mocks::MockAddressMapping, CallFeeSwapPath, Price};
 
 pub type AccountId = AccountId32;
 pub type BlockNumber = u64;
@@ -43,6 +43,7 @@ pub const CHARLIE: AccountId = AccountId::new([3u8; 32]);
 pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+pub const BTC: CurrencyId = CurrencyId::Token(TokenSymbol::RENBTC);
 
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
@@ -158,6 +159,7 @@ parameter_types! {
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, ACA).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
+		TradingPair::from_currency_ids(AUSD, BTC).unwrap(),
 	];
 }
 
@@ -166,6 +168,9 @@ impl module_dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type RoutingCurrencies = ();
+	type TWAPObservationPeriod = ();
+	type MaxTWAPObservations = ();
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = ();
 	type DEXIncentives = ();
@@ -216,6 +221,19 @@ impl PriceProvider<CurrencyId> for MockPriceSource {
 	}
 }
 
+pub struct MockCallFeeSwapPath;
+impl CallFeeSwapPath<Call, CurrencyId> for MockCallFeeSwapPath {
+	fn fee_swap_path_list(call: &Call) -> Vec<Vec<CurrencyId>> {
+		match call {
+			Call::DEXModule(module_dex::Call::swap_with_exact_target_and_fee_in_supply(path, ..)) => path
+				.first()
+				.map(|supply_currency_id| vec![vec![*supply_currency_id, AUSD, ACA]])
+				.unwrap_or_default(),
+			_ => vec![],
+		}
+	}
+}
+
 impl Config for Runtime {
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type DefaultFeeSwapPathList = DefaultFeeSwapPathList;
@@ -229,6 +247,10 @@ impl Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = MockPriceSource;
+	type CallFeeSwapPath = MockCallFeeSwapPath;
+	type Call = Call;
+	type SponsorSignature = sp_runtime::MultiSignature;
+	type SponsorSigner = sp_runtime::MultiSigner;
 	type WeightInfo = ();
 }
 
@@ -279,7 +301,7 @@ pub struct ExtBuilder {
 impl Default for ExtBuilder {
 	fn default() -> Self {
 		Self {
-			balances: vec![(ALICE, AUSD, 10000), (ALICE, DOT, 1000)],
+			balances: vec![(ALICE, AUSD, 10000), (ALICE, DOT, 1000), (ALICE, BTC, 1000)],
 			base_weight: 0,
 			byte_fee: 2,
 			weight_to_fee: 1,
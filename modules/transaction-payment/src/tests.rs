@@ -26,11 +26,16 @@ use frame_support::{
 	weights::{DispatchClass, DispatchInfo, Pays},
 };
 use mock::{
-	AccountId, BlockWeights, Call, Currencies, DEXModule, ExtBuilder, MockPriceSource, Origin, Runtime,
-	TransactionPayment, ACA, ALICE, AUSD, BOB, CHARLIE, DOT, FEE_UNBALANCED_AMOUNT, TIP_UNBALANCED_AMOUNT,
+	AccountId, BlockWeights, Call, Currencies, DEXModule, ExtBuilder, MockPriceSource, Origin, Runtime, System,
+	TransactionPayment, ACA, ALICE, AUSD, BOB, BTC, CHARLIE, DOT, FEE_UNBALANCED_AMOUNT, TIP_UNBALANCED_AMOUNT,
 };
 use orml_traits::MultiCurrency;
-use sp_runtime::{testing::TestXt, traits::One};
+use sp_core::{sr25519, Pair, H256};
+use sp_runtime::{
+	testing::TestXt,
+	traits::{BlakeTwo256, One},
+	MultiSignature, MultiSigner,
+};
 use support::Price;
 
 const CALL: &<Runtime as frame_system::Config>::Call =
@@ -45,6 +50,38 @@ const INFO: DispatchInfo = DispatchInfo {
 	pays_fee: Pays::Yes,
 };
 
+fn sponsor_pair() -> sr25519::Pair {
+	sr25519::Pair::from_seed(&[9u8; 32])
+}
+
+fn sponsor() -> AccountId {
+	MultiSigner::from(sponsor_pair().public()).into_account()
+}
+
+fn sponsor_approval(
+	who: AccountId,
+	max_fee: Balance,
+	expiry: u64,
+	scope: SponsorScope<H256>,
+) -> (SponsorApprovalOf<Runtime>, MultiSignature) {
+	sign_sponsor_approval(SponsorApproval {
+		sponsor: sponsor(),
+		who,
+		max_fee,
+		max_total_fee: max_fee,
+		nonce: 0,
+		expiry,
+		scope,
+	})
+}
+
+fn sign_sponsor_approval(approval: SponsorApprovalOf<Runtime>) -> (SponsorApprovalOf<Runtime>, MultiSignature) {
+	let signature = sponsor_pair()
+		.sign(&TransactionPayment::sponsor_approval_payload(&approval))
+		.into();
+	(approval, signature)
+}
+
 const POST_INFO: PostDispatchInfo = PostDispatchInfo {
 	actual_weight: Some(800),
 	pays_fee: Pays::Yes,
@@ -378,6 +415,180 @@ fn charge_fee_by_default_swap_path() {
 		});
 }
 
+#[test]
+fn charge_fee_by_dex_share_swap_path() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			// add liquidity to DEX
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				ACA,
+				AUSD,
+				10000,
+				1000,
+				0,
+				false
+			));
+			let aca_ausd_lp = CurrencyId::join_dex_share_currency_id(ACA, AUSD).unwrap();
+			assert_eq!(Currencies::total_issuance(aca_ausd_lp), 20000);
+
+			// the token following the DEX share must be one of its tokens
+			assert_noop!(
+				TransactionPayment::set_alternative_fee_swap_path(
+					Origin::signed(BOB),
+					Some(vec![aca_ausd_lp, DOT, ACA])
+				),
+				Error::<Runtime>::InvalidSwapPath
+			);
+			assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+				Origin::signed(BOB),
+				Some(vec![aca_ausd_lp, ACA])
+			));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(
+				aca_ausd_lp,
+				&ALICE,
+				&BOB,
+				10000
+			));
+			assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(ACA, &BOB), 0);
+			assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(AUSD, &BOB), 0);
+
+			// remove liquidity to withdraw fee and ED directly
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.validate(&BOB, CALL2, &INFO, 500)
+					.unwrap()
+					.priority,
+				fee
+			);
+
+			assert_eq!(Currencies::free_balance(ACA, &BOB), Currencies::minimum_balance(ACA));
+			assert_eq!(Currencies::free_balance(AUSD, &BOB), 201);
+			assert_eq!(Currencies::free_balance(aca_ausd_lp, &BOB), 10000 - 4021);
+			assert_eq!(DEXModule::get_liquidity_pool(ACA, AUSD), (10000 - 2010, 1000 - 201));
+		});
+}
+
+#[test]
+fn charge_fee_by_dex_share_swap_path_with_swap() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			// add liquidity to DEX
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				ACA,
+				AUSD,
+				10000,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				AUSD,
+				100,
+				1000,
+				0,
+				false
+			));
+			let ausd_dot_lp = CurrencyId::join_dex_share_currency_id(AUSD, DOT).unwrap();
+			assert_eq!(Currencies::total_issuance(ausd_dot_lp), 2000);
+
+			assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+				Origin::signed(BOB),
+				Some(vec![ausd_dot_lp, AUSD, ACA])
+			));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(
+				ausd_dot_lp,
+				&ALICE,
+				&BOB,
+				1000
+			));
+
+			// remove liquidity to withdraw AUSD, then swap AUSD to ACA
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.validate(&BOB, CALL2, &INFO, 500)
+					.unwrap()
+					.priority,
+				fee
+			);
+
+			assert_eq!(Currencies::free_balance(ACA, &BOB), Currencies::minimum_balance(ACA));
+			assert_eq!(Currencies::free_balance(AUSD, &BOB), 0);
+			assert_eq!(Currencies::free_balance(DOT, &BOB), 25);
+			assert_eq!(Currencies::free_balance(ausd_dot_lp, &BOB), 1000 - 505);
+			assert_eq!(DEXModule::get_liquidity_pool(ACA, AUSD), (10000 - 2000 - 10, 1252));
+			assert_eq!(DEXModule::get_liquidity_pool(DOT, AUSD), (100 - 25, 1000 - 252));
+		});
+}
+
+#[test]
+fn charge_fee_by_call_fee_swap_path() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			// add liquidity to DEX
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				ACA,
+				AUSD,
+				10000,
+				1000,
+				0,
+				false
+			));
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				BTC,
+				AUSD,
+				100,
+				1000,
+				0,
+				false
+			));
+			assert_eq!(DEXModule::get_liquidity_pool(ACA, AUSD), (10000, 1000));
+			assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 1000));
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(BTC, &ALICE, &BOB, 100));
+			assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(ACA, &BOB), 0);
+			assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(BTC, &BOB), 100);
+
+			// BTC is not in the default fee swap paths
+			assert_noop!(
+				ChargeTransactionPayment::<Runtime>::from(0).validate(&BOB, CALL2, &INFO, 500),
+				TransactionValidityError::Invalid(InvalidTransaction::Payment)
+			);
+
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			let call = Call::DEXModule(module_dex::Call::swap_with_exact_target_and_fee_in_supply(
+				vec![BTC, AUSD],
+				10,
+				10,
+			));
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.validate(&BOB, &call, &INFO, 500)
+					.unwrap()
+					.priority,
+				fee
+			);
+
+			assert_eq!(Currencies::free_balance(ACA, &BOB), Currencies::minimum_balance(ACA));
+			assert_eq!(Currencies::free_balance(AUSD, &BOB), 0);
+			assert_eq!(Currencies::free_balance(BTC, &BOB), 100 - 34);
+			assert_eq!(DEXModule::get_liquidity_pool(ACA, AUSD), (10000 - 2000 - 10, 1252));
+			assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100 + 34, 1000 - 252));
+		});
+}
+
 #[test]
 fn query_info_works() {
 	ExtBuilder::default()
@@ -410,6 +621,52 @@ fn query_info_works() {
 		});
 }
 
+#[test]
+fn query_fee_in_currency_works() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			assert_ok!(DEXModule::add_liquidity(
+				Origin::signed(ALICE),
+				ACA,
+				AUSD,
+				10000,
+				1000,
+				0,
+				false
+			));
+			let xt = TestXt::new(CALL2.clone(), Some((111111, ())));
+			let len = xt.encode().len() as u32;
+			let fee = TransactionPayment::compute_fee(len, &xt.get_dispatch_info(), 0);
+
+			assert_eq!(
+				TransactionPayment::query_fee_in_currency(xt.clone(), len, ACA),
+				Some(fee)
+			);
+
+			let ausd_amount = DEXModule::get_swap_supply_amount(&[AUSD, ACA], fee).unwrap();
+			assert_eq!(
+				TransactionPayment::query_fee_in_currency(xt.clone(), len, AUSD),
+				Some(ausd_amount)
+			);
+
+			// no liquidity for DOT
+			assert_eq!(TransactionPayment::query_fee_in_currency(xt.clone(), len, DOT), None);
+
+			// the share to remove for the cheaper token
+			let aca_ausd_lp = CurrencyId::join_dex_share_currency_id(ACA, AUSD).unwrap();
+			assert_eq!(
+				TransactionPayment::query_fee_in_currency(xt.clone(), len, aca_ausd_lp),
+				Some((fee * 20000 / 10000 + 1).min(ausd_amount * 20000 / 1000 + 1))
+			);
+
+			// exceeds the slippage limit compared to oracle price
+			MockPriceSource::set_relative_price(Some(Price::saturating_from_rational(1, 100)));
+			assert_eq!(TransactionPayment::query_fee_in_currency(xt, len, AUSD), None);
+		});
+}
+
 #[test]
 fn compute_fee_works_without_multiplier() {
 	ExtBuilder::default()
@@ -530,3 +787,263 @@ fn compute_fee_does_not_overflow() {
 			);
 		});
 }
+
+#[test]
+fn weight_to_fee_works_with_multiplier() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			<ChargeTransactionPayment<Runtime> as TransactionPayment<AccountId, Balance, _>>::weight_to_fee(1000),
+			1000
+		);
+
+		// Add a next fee multiplier. Fees will be x3/2.
+		NextFeeMultiplier::<Runtime>::put(Multiplier::saturating_from_rational(3, 2));
+		assert_eq!(
+			<ChargeTransactionPayment<Runtime> as TransactionPayment<AccountId, Balance, _>>::weight_to_fee(1000),
+			1500
+		);
+	});
+}
+
+#[test]
+fn charges_fee_from_sponsor() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(
+				ACA,
+				&ALICE,
+				&sponsor(),
+				10000
+			));
+			assert_eq!(Currencies::free_balance(ACA, &BOB), 0);
+
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			let sponsored_call =
+				Call::TransactionPayment(crate::Call::set_alternative_fee_swap_path(Some(vec![AUSD, ACA])));
+			let (approval, signature) = sponsor_approval(
+				BOB,
+				fee,
+				10,
+				SponsorScope::CallHash(BlakeTwo256::hash_of(&sponsored_call)),
+			);
+			let call = Call::TransactionPayment(crate::Call::with_fee_paid_by(
+				Box::new(sponsored_call.clone()),
+				approval.clone(),
+				signature.clone(),
+			));
+
+			assert_eq!(
+				ChargeTransactionPayment::<Runtime>::from(0)
+					.validate(&BOB, &call, &INFO, 500)
+					.unwrap()
+					.priority,
+				fee
+			);
+			assert_eq!(Currencies::free_balance(ACA, &sponsor()), 10000 - fee);
+			assert_eq!(Currencies::free_balance(ACA, &BOB), 0);
+			assert_eq!(
+				TransactionPayment::sponsor_fee_paid(TransactionPayment::sponsor_approval_hash(&approval)),
+				fee
+			);
+
+			assert_ok!(TransactionPayment::with_fee_paid_by(
+				Origin::signed(BOB),
+				Box::new(sponsored_call),
+				approval,
+				signature
+			));
+			assert_eq!(
+				TransactionPayment::alternative_fee_swap_path(&BOB).unwrap(),
+				vec![AUSD, ACA]
+			);
+
+			// the approval can't be replayed beyond the max total fee
+			assert_noop!(
+				ChargeTransactionPayment::<Runtime>::from(0).validate(&BOB, &call, &INFO, 500),
+				TransactionValidityError::Invalid(InvalidTransaction::Payment)
+			);
+		});
+}
+
+#[test]
+fn sponsor_approvals_are_independent() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(
+				ACA,
+				&ALICE,
+				&sponsor(),
+				10000
+			));
+
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			let sponsored_call = Call::TransactionPayment(crate::Call::set_alternative_fee_swap_path(None));
+			let validate = |who: &AccountId, approval: &SponsorApprovalOf<Runtime>, signature: &MultiSignature| {
+				let call = Call::TransactionPayment(crate::Call::with_fee_paid_by(
+					Box::new(sponsored_call.clone()),
+					approval.clone(),
+					signature.clone(),
+				));
+				ChargeTransactionPayment::<Runtime>::from(0).validate(who, &call, &INFO, 500)
+			};
+			let approval_of = |who: AccountId, max_total_fee: Balance, nonce: u32| {
+				sign_sponsor_approval(SponsorApproval {
+					sponsor: sponsor(),
+					who,
+					max_fee: fee,
+					max_total_fee,
+					nonce,
+					expiry: 10,
+					scope: SponsorScope::Pallet(b"TransactionPayment".to_vec()),
+				})
+			};
+
+			// overlapping approvals don't cancel each other
+			let (bob_approval, bob_signature) = approval_of(BOB, fee * 2, 0);
+			let (charlie_approval, charlie_signature) = approval_of(CHARLIE, fee, 0);
+			assert_ok!(validate(&BOB, &bob_approval, &bob_signature));
+			assert_ok!(validate(&CHARLIE, &charlie_approval, &charlie_signature));
+			assert_ok!(validate(&BOB, &bob_approval, &bob_signature));
+			assert_eq!(Currencies::free_balance(ACA, &sponsor()), 10000 - fee * 3);
+			assert_eq!(
+				TransactionPayment::sponsor_fee_paid(TransactionPayment::sponsor_approval_hash(&bob_approval)),
+				fee * 2
+			);
+			assert_eq!(
+				TransactionPayment::sponsor_fee_paid(TransactionPayment::sponsor_approval_hash(&charlie_approval)),
+				fee
+			);
+
+			// each approval is capped by its own max total fee
+			assert_noop!(
+				validate(&BOB, &bob_approval, &bob_signature),
+				TransactionValidityError::Invalid(InvalidTransaction::Payment)
+			);
+			assert_noop!(
+				validate(&CHARLIE, &charlie_approval, &charlie_signature),
+				TransactionValidityError::Invalid(InvalidTransaction::Payment)
+			);
+
+			// a new approval issued after the cap is hit has its own budget
+			let (new_approval, new_signature) = approval_of(BOB, fee * 2, 1);
+			assert_ok!(validate(&BOB, &new_approval, &new_signature));
+			assert_eq!(
+				TransactionPayment::sponsor_fee_paid(TransactionPayment::sponsor_approval_hash(&new_approval)),
+				fee
+			);
+			assert_eq!(Currencies::free_balance(ACA, &sponsor()), 10000 - fee * 4);
+		});
+}
+
+#[test]
+fn with_fee_paid_by_checks_approval() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			assert_ok!(<Currencies as MultiCurrency<_>>::transfer(
+				ACA,
+				&ALICE,
+				&sponsor(),
+				10000
+			));
+
+			let fee = 500 * 2 + 1000; // len * byte + weight
+			let sponsored_call = Call::TransactionPayment(crate::Call::set_alternative_fee_swap_path(None));
+			let validate = |who: &AccountId, approval: &SponsorApprovalOf<Runtime>, signature: &MultiSignature| {
+				let call = Call::TransactionPayment(crate::Call::with_fee_paid_by(
+					Box::new(sponsored_call.clone()),
+					approval.clone(),
+					signature.clone(),
+				));
+				ChargeTransactionPayment::<Runtime>::from(0).validate(who, &call, &INFO, 500)
+			};
+
+			// the fee exceeds the max fee
+			let (approval, signature) =
+				sponsor_approval(BOB, fee - 1, 10, SponsorScope::Pallet(b"TransactionPayment".to_vec()));
+			assert_noop!(
+				validate(&BOB, &approval, &signature),
+				TransactionValidityError::Invalid(InvalidTransaction::Payment)
+			);
+
+			// the approval is not for the caller
+			let (approval, signature) =
+				sponsor_approval(BOB, fee, 10, SponsorScope::Pallet(b"TransactionPayment".to_vec()));
+			assert_noop!(
+				validate(&CHARLIE, &approval, &signature),
+				TransactionValidityError::Invalid(InvalidTransaction::BadProof)
+			);
+			assert_noop!(
+				TransactionPayment::with_fee_paid_by(
+					Origin::signed(CHARLIE),
+					Box::new(sponsored_call.clone()),
+					approval.clone(),
+					signature.clone()
+				),
+				Error::<Runtime>::InvalidSponsorApproval
+			);
+
+			// the approval is not signed by the sponsor
+			let mut forged_approval = approval.clone();
+			forged_approval.max_fee = fee * 10;
+			assert_noop!(
+				validate(&BOB, &forged_approval, &signature),
+				TransactionValidityError::Invalid(InvalidTransaction::BadProof)
+			);
+
+			// the approval is not signed for the chain
+			let unbound_signature = sponsor_pair().sign(&approval.encode()).into();
+			assert_noop!(
+				validate(&BOB, &approval, &unbound_signature),
+				TransactionValidityError::Invalid(InvalidTransaction::BadProof)
+			);
+
+			// the call is not in the scope
+			let (other_approval, other_signature) =
+				sponsor_approval(BOB, fee, 10, SponsorScope::Pallet(b"Currencies".to_vec()));
+			assert_noop!(
+				validate(&BOB, &other_approval, &other_signature),
+				TransactionValidityError::Invalid(InvalidTransaction::BadProof)
+			);
+			assert_noop!(
+				TransactionPayment::with_fee_paid_by(
+					Origin::signed(BOB),
+					Box::new(sponsored_call.clone()),
+					other_approval,
+					other_signature
+				),
+				Error::<Runtime>::CallNotSponsored
+			);
+
+			// the approval has expired
+			System::set_block_number(11);
+			assert_noop!(
+				validate(&BOB, &approval, &signature),
+				TransactionValidityError::Invalid(InvalidTransaction::Stale)
+			);
+			assert_noop!(
+				TransactionPayment::with_fee_paid_by(
+					Origin::signed(BOB),
+					Box::new(sponsored_call.clone()),
+					approval.clone(),
+					signature.clone()
+				),
+				Error::<Runtime>::SponsorApprovalExpired
+			);
+
+			System::set_block_number(10);
+			assert_ok!(validate(&BOB, &approval, &signature));
+			assert_eq!(Currencies::free_balance(ACA, &sponsor()), 10000 - fee);
+
+			// the total fee paid by the sponsor exceeds the max total fee
+			assert_noop!(
+				validate(&BOB, &approval, &signature),
+				TransactionValidityError::Invalid(InvalidTransaction::Payment)
+			);
+		});
+}
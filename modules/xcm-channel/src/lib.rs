@@ -0,0 +1,185 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # XCM Channel Module
+//!
+//! ## Overview
+//!
+//! Controls and meters the XCM messages received from sibling parachains
+//! over HRMP channels. Governance can pause the channel of a sibling
+//! parachain, e.g. during an exploit, after which its messages are rejected
+//! by the XCM executor instead of depositing assets. The number of messages
+//! processed and failed and the weight used are counted per sibling
+//! parachain and exposed by the runtime API.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use cumulus_primitives_core::ParaId;
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use sp_std::marker::PhantomData;
+use xcm::v0::{Error as XcmError, ExecuteXcm, Junction, MultiLocation, Outcome, Xcm};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// The metrics of the XCM messages received from a sibling parachain.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, Default)]
+pub struct ChannelMetrics {
+	/// The number of messages executed completely.
+	pub processed: u64,
+	/// The number of messages failed or rejected.
+	pub failed: u64,
+	/// The total weight used by the executed messages.
+	pub weight_used: Weight,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin which may pause and unpause channels.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The channel of a sibling parachain has been paused. \[para_id\]
+		ChannelPaused(ParaId),
+		/// The channel of a sibling parachain has been unpaused. \[para_id\]
+		ChannelUnpaused(ParaId),
+	}
+
+	/// The sibling parachains whose messages are rejected.
+	///
+	/// PausedChannels: map ParaId => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn paused_channels)]
+	pub type PausedChannels<T: Config> = StorageMap<_, Twox64Concat, ParaId, (), OptionQuery>;
+
+	/// The metrics of the XCM messages received from sibling parachains.
+	///
+	/// Metrics: map ParaId => ChannelMetrics
+	#[pallet::storage]
+	#[pallet::getter(fn metrics)]
+	pub type Metrics<T: Config> = StorageMap<_, Twox64Concat, ParaId, ChannelMetrics, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Reject the XCM messages from the sibling parachain `para_id`.
+		#[pallet::weight(T::WeightInfo::pause_channel())]
+		#[transactional]
+		pub fn pause_channel(origin: OriginFor<T>, para_id: ParaId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			PausedChannels::<T>::insert(para_id, ());
+			Self::deposit_event(Event::ChannelPaused(para_id));
+			Ok(())
+		}
+
+		/// Accept the XCM messages from the sibling parachain `para_id` again.
+		#[pallet::weight(T::WeightInfo::unpause_channel())]
+		#[transactional]
+		pub fn unpause_channel(origin: OriginFor<T>, para_id: ParaId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if PausedChannels::<T>::take(para_id).is_some() {
+				Self::deposit_event(Event::ChannelUnpaused(para_id));
+			}
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether the channel of the sibling parachain `para_id` is paused.
+	pub fn is_paused(para_id: ParaId) -> bool {
+		PausedChannels::<T>::contains_key(para_id)
+	}
+
+	fn record(para_id: ParaId, outcome: &Outcome) {
+		Metrics::<T>::mutate(para_id, |metrics| match outcome {
+			Outcome::Complete(weight) => {
+				metrics.processed = metrics.processed.saturating_add(1);
+				metrics.weight_used = metrics.weight_used.saturating_add(*weight);
+			}
+			Outcome::Incomplete(weight, _) => {
+				metrics.failed = metrics.failed.saturating_add(1);
+				metrics.weight_used = metrics.weight_used.saturating_add(*weight);
+			}
+			Outcome::Error(_) => {
+				metrics.failed = metrics.failed.saturating_add(1);
+			}
+		});
+	}
+}
+
+/// The sibling parachain `origin` is from, if any.
+fn sibling_para_id(origin: &MultiLocation) -> Option<ParaId> {
+	match origin {
+		MultiLocation::X2(Junction::Parent, Junction::Parachain(id)) => Some((*id).into()),
+		_ => None,
+	}
+}
+
+/// Executes the XCM messages with `Executor`, rejecting the messages from
+/// sibling parachains with paused channels and recording the outcomes of
+/// the messages from sibling parachains.
+///
+/// Use it as the `XcmExecutor` of the XCMP queue.
+pub struct MeteredXcmExecutor<T, Executor>(PhantomData<(T, Executor)>);
+impl<T: Config, Call, Executor: ExecuteXcm<Call>> ExecuteXcm<Call> for MeteredXcmExecutor<T, Executor> {
+	fn execute_xcm_in_credit(
+		origin: MultiLocation,
+		message: Xcm<Call>,
+		weight_limit: Weight,
+		weight_credit: Weight,
+	) -> Outcome {
+		let para_id = match sibling_para_id(&origin) {
+			Some(para_id) => para_id,
+			None => return Executor::execute_xcm_in_credit(origin, message, weight_limit, weight_credit),
+		};
+
+		let outcome = if Pallet::<T>::is_paused(para_id) {
+			Outcome::Error(XcmError::Barrier)
+		} else {
+			Executor::execute_xcm_in_credit(origin, message, weight_limit, weight_credit)
+		};
+		Pallet::<T>::record(para_id, &outcome);
+		outcome
+	}
+}
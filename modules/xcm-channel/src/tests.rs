@@ -0,0 +1,123 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the XCM channel module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use sp_runtime::traits::BadOrigin;
+use xcm::v0::{Junction::Parent, MultiLocation::X1};
+
+fn sibling(id: u32) -> MultiLocation {
+	MultiLocation::X2(Parent, Junction::Parachain(id))
+}
+
+fn withdraw_asset() -> Xcm<Call> {
+	Xcm::WithdrawAsset {
+		assets: vec![],
+		effects: vec![],
+	}
+}
+
+#[test]
+fn pause_and_unpause_channel_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(XcmChannel::pause_channel(Origin::signed(BOB), 2000.into()), BadOrigin);
+
+		assert_ok!(XcmChannel::pause_channel(Origin::signed(ALICE), 2000.into()));
+		System::assert_last_event(Event::XcmChannel(crate::Event::ChannelPaused(2000.into())));
+		assert!(XcmChannel::is_paused(2000.into()));
+		assert!(!XcmChannel::is_paused(2001.into()));
+
+		assert_noop!(XcmChannel::unpause_channel(Origin::signed(BOB), 2000.into()), BadOrigin);
+
+		assert_ok!(XcmChannel::unpause_channel(Origin::signed(ALICE), 2000.into()));
+		System::assert_last_event(Event::XcmChannel(crate::Event::ChannelUnpaused(2000.into())));
+		assert!(!XcmChannel::is_paused(2000.into()));
+	});
+}
+
+#[test]
+fn metered_executor_records_metrics() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			MeteredExecutor::execute_xcm(sibling(2000), withdraw_asset(), 1_000),
+			Outcome::Complete(100)
+		);
+		assert_eq!(
+			MeteredExecutor::execute_xcm(
+				sibling(2000),
+				Xcm::RelayedFrom {
+					who: MultiLocation::Null,
+					message: Box::new(withdraw_asset()),
+				},
+				1_000
+			),
+			Outcome::Incomplete(50, XcmError::Unimplemented)
+		);
+		assert_eq!(
+			XcmChannel::metrics(ParaId::from(2000)),
+			ChannelMetrics {
+				processed: 1,
+				failed: 1,
+				weight_used: 150,
+			}
+		);
+
+		// messages not from sibling parachains are not recorded
+		assert_eq!(
+			MeteredExecutor::execute_xcm(X1(Parent), withdraw_asset(), 1_000),
+			Outcome::Complete(100)
+		);
+		assert_eq!(XcmChannel::metrics(ParaId::from(2000)).processed, 1);
+		assert_eq!(XcmChannel::metrics(ParaId::from(2001)), ChannelMetrics::default());
+	});
+}
+
+#[test]
+fn metered_executor_rejects_paused_channel() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmChannel::pause_channel(Origin::signed(ALICE), 2000.into()));
+
+		assert_eq!(
+			MeteredExecutor::execute_xcm(sibling(2000), withdraw_asset(), 1_000),
+			Outcome::Error(XcmError::Barrier)
+		);
+		assert_eq!(
+			MeteredExecutor::execute_xcm(sibling(2001), withdraw_asset(), 1_000),
+			Outcome::Complete(100)
+		);
+		assert_eq!(
+			XcmChannel::metrics(ParaId::from(2000)),
+			ChannelMetrics {
+				processed: 0,
+				failed: 1,
+				weight_used: 0,
+			}
+		);
+
+		assert_ok!(XcmChannel::unpause_channel(Origin::signed(ALICE), 2000.into()));
+		assert_eq!(
+			MeteredExecutor::execute_xcm(sibling(2000), withdraw_asset(), 1_000),
+			Outcome::Complete(100)
+		);
+	});
+}
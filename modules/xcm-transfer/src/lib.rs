@@ -0,0 +1,283 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # XCM Transfer Module
+//!
+//! ## Overview
+//!
+//! Cross-chain transfers which bundle a second asset to pay for the execution
+//! on the destination chain, so an asset which can't pay for execution on the
+//! destination chain isn't trapped there. Both assets must have the same
+//! reserve chain. The fee asset left after buying execution is deposited to
+//! the recipient with the transferred asset.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::traits::Convert;
+use sp_std::{boxed::Box, prelude::*};
+use xcm::v0::{
+	ExecuteXcm, Junction, MultiAsset, MultiLocation, Order,
+	Xcm::{self, WithdrawAsset},
+};
+use xcm_executor::traits::WeightBounds;
+
+mod mock;
+mod tests;
+
+pub use module::*;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Convert `CurrencyId` to its `MultiLocation`.
+		type CurrencyIdConvert: Convert<CurrencyId, Option<MultiLocation>>;
+
+		/// Convert `AccountId` to `MultiLocation`.
+		type AccountIdToMultiLocation: Convert<Self::AccountId, MultiLocation>;
+
+		/// The location of this parachain.
+		#[pallet::constant]
+		type SelfLocation: Get<MultiLocation>;
+
+		/// XCM executor.
+		type XcmExecutor: ExecuteXcm<Self::Call>;
+
+		/// The weigher of XCM messages.
+		type Weigher: WeightBounds<Self::Call>;
+
+		/// Base XCM weight of the transfer extrinsic.
+		#[pallet::constant]
+		type BaseXcmWeight: Get<Weight>;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The currency can't be transferred cross-chain
+		NotCrossChainTransferableCurrency,
+		/// The amount is zero
+		ZeroAmount,
+		/// The fee asset is the transferred asset
+		FeeAssetIsTransferredAsset,
+		/// The asset and fee asset have different reserve chains
+		DistinctReserveForAssetAndFee,
+		/// The destination isn't a chain and an account on it
+		InvalidDest,
+		/// The XCM message can't be weighed
+		UnweighableMessage,
+		/// The XCM execution failed
+		XcmExecutionFailed,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Transferred with a fee asset. \[sender, currency_id, amount,
+		/// fee_currency_id, fee_amount, dest\]
+		TransferredWithFee(T::AccountId, CurrencyId, Balance, CurrencyId, Balance, MultiLocation),
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Transfer `amount` of `currency_id` to the account `dest` on another
+		/// chain, with `fee_amount` of `fee_currency_id` to pay for the
+		/// execution on the destination chain.
+		#[pallet::weight(Pallet::<T>::weight_of_transfer(*currency_id, *amount, *fee_currency_id, *fee_amount, dest, *dest_weight))]
+		#[transactional]
+		pub fn transfer_with_fee_asset(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			amount: Balance,
+			fee_currency_id: CurrencyId,
+			fee_amount: Balance,
+			dest: Box<MultiLocation>,
+			dest_weight: Weight,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(amount != 0 && fee_amount != 0, Error::<T>::ZeroAmount);
+
+			let mut msg = Self::transfer_message(currency_id, amount, fee_currency_id, fee_amount, &dest, dest_weight)?;
+			let weight = T::Weigher::shallow(&mut msg)
+				.and_then(|shallow| T::Weigher::deep(&mut msg).map(|deep| shallow.saturating_add(deep)))
+				.map_err(|()| Error::<T>::UnweighableMessage)?;
+			let origin_location = T::AccountIdToMultiLocation::convert(who.clone());
+			T::XcmExecutor::execute_xcm_in_credit(origin_location, msg, weight, weight)
+				.ensure_complete()
+				.map_err(|_| Error::<T>::XcmExecutionFailed)?;
+
+			Self::deposit_event(Event::TransferredWithFee(
+				who,
+				currency_id,
+				amount,
+				fee_currency_id,
+				fee_amount,
+				*dest,
+			));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The weight of the transfer, only the base weight if the transfer is
+	/// invalid.
+	pub fn weight_of_transfer(
+		currency_id: CurrencyId,
+		amount: Balance,
+		fee_currency_id: CurrencyId,
+		fee_amount: Balance,
+		dest: &MultiLocation,
+		dest_weight: Weight,
+	) -> Weight {
+		Self::transfer_message(currency_id, amount, fee_currency_id, fee_amount, dest, dest_weight)
+			.ok()
+			.and_then(|mut msg| {
+				let shallow = T::Weigher::shallow(&mut msg).ok()?;
+				let deep = T::Weigher::deep(&mut msg).ok()?;
+				Some(T::BaseXcmWeight::get().saturating_add(shallow).saturating_add(deep))
+			})
+			.unwrap_or_else(T::BaseXcmWeight::get)
+	}
+
+	/// Build the XCM message to withdraw both assets and send them to the
+	/// recipient via their reserve chain.
+	fn transfer_message(
+		currency_id: CurrencyId,
+		amount: Balance,
+		fee_currency_id: CurrencyId,
+		fee_amount: Balance,
+		dest: &MultiLocation,
+		dest_weight: Weight,
+	) -> Result<Xcm<T::Call>, DispatchError> {
+		ensure!(currency_id != fee_currency_id, Error::<T>::FeeAssetIsTransferredAsset);
+		let location =
+			T::CurrencyIdConvert::convert(currency_id).ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
+		let fee_location =
+			T::CurrencyIdConvert::convert(fee_currency_id).ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
+
+		let reserve = Self::reserve_of(&location).ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
+		ensure!(
+			Self::reserve_of(&fee_location) == Some(reserve.clone()),
+			Error::<T>::DistinctReserveForAssetAndFee
+		);
+
+		let (dest_chain, recipient) = match dest.clone().split_last() {
+			(dest_chain, Some(recipient))
+				if dest_chain != MultiLocation::Null
+					&& matches!(
+						recipient,
+						Junction::AccountId32 { .. } | Junction::AccountKey20 { .. } | Junction::AccountIndex64 { .. }
+					) =>
+			{
+				(dest_chain, MultiLocation::X1(recipient))
+			}
+			_ => return Err(Error::<T>::InvalidDest.into()),
+		};
+
+		let assets = vec![
+			MultiAsset::ConcreteFungible { id: location, amount },
+			MultiAsset::ConcreteFungible {
+				id: fee_location,
+				amount: fee_amount,
+			},
+		];
+		let deposit_to_recipient = vec![
+			Self::buy_execution(dest_weight),
+			Order::DepositAsset {
+				assets: vec![MultiAsset::All],
+				dest: recipient,
+			},
+		];
+
+		let effect = if reserve == T::SelfLocation::get() {
+			Order::DepositReserveAsset {
+				assets: vec![MultiAsset::All],
+				dest: dest_chain,
+				effects: deposit_to_recipient,
+			}
+		} else if reserve == dest_chain {
+			Order::InitiateReserveWithdraw {
+				assets: vec![MultiAsset::All],
+				reserve,
+				effects: deposit_to_recipient,
+			}
+		} else {
+			// the destination seen from the relay chain
+			let reanchored_dest = match (&reserve, &dest_chain) {
+				(MultiLocation::X1(Junction::Parent), MultiLocation::X2(Junction::Parent, Junction::Parachain(id))) => {
+					MultiLocation::X1(Junction::Parachain(*id))
+				}
+				_ => dest_chain,
+			};
+			Order::InitiateReserveWithdraw {
+				assets: vec![MultiAsset::All],
+				reserve,
+				effects: vec![
+					Self::buy_execution(dest_weight),
+					Order::DepositReserveAsset {
+						assets: vec![MultiAsset::All],
+						dest: reanchored_dest,
+						effects: deposit_to_recipient,
+					},
+				],
+			}
+		};
+
+		Ok(WithdrawAsset {
+			assets,
+			effects: vec![effect],
+		})
+	}
+
+	/// Buy execution with any of the holding assets, the destination trader
+	/// takes the asset it accepts and the rest is kept in holding.
+	fn buy_execution(dest_weight: Weight) -> Order<()> {
+		Order::BuyExecution {
+			fees: MultiAsset::All,
+			weight: 0,
+			debt: dest_weight,
+			halt_on_error: false,
+			xcm: vec![],
+		}
+	}
+
+	/// The reserve chain of the asset at `location`.
+	fn reserve_of(location: &MultiLocation) -> Option<MultiLocation> {
+		match (location.first(), location.at(1)) {
+			(Some(Junction::Parent), Some(Junction::Parachain(id))) => {
+				Some(MultiLocation::X2(Junction::Parent, Junction::Parachain(*id)))
+			}
+			(Some(Junction::Parent), _) => Some(MultiLocation::X1(Junction::Parent)),
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,180 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the xcm transfer module.
+
+#![cfg(test)]
+
+use super::*;
+use codec::Encode;
+use frame_support::{construct_runtime, parameter_types};
+use primitives::TokenSymbol;
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+use sp_std::cell::RefCell;
+use xcm::v0::{
+	Junction::{AccountIndex64, GeneralKey, Parachain, Parent},
+	MultiLocation::{X1, X2, X3},
+	NetworkId, Outcome,
+};
+use xcm_builder::FixedWeightBounds;
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+pub const KAR: CurrencyId = CurrencyId::Token(TokenSymbol::KAR);
+pub const KUSD: CurrencyId = CurrencyId::Token(TokenSymbol::KUSD);
+pub const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+pub const BNC: CurrencyId = CurrencyId::Token(TokenSymbol::BNC);
+pub const VSKSM: CurrencyId = CurrencyId::Token(TokenSymbol::VSKSM);
+
+pub const SELF_PARA_ID: u32 = 2000;
+pub const SIBLING_PARA_ID: u32 = 2001;
+
+mod xcm_transfer {
+	pub use super::super::*;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+pub struct CurrencyIdConvert;
+impl Convert<CurrencyId, Option<MultiLocation>> for CurrencyIdConvert {
+	fn convert(id: CurrencyId) -> Option<MultiLocation> {
+		match id {
+			KSM => Some(X1(Parent)),
+			KAR | KUSD => Some(X3(Parent, Parachain(SELF_PARA_ID), GeneralKey(id.encode()))),
+			BNC | VSKSM => Some(X3(Parent, Parachain(SIBLING_PARA_ID), GeneralKey(id.encode()))),
+			_ => None,
+		}
+	}
+}
+
+pub struct AccountIdToMultiLocation;
+impl Convert<AccountId, MultiLocation> for AccountIdToMultiLocation {
+	fn convert(account: AccountId) -> MultiLocation {
+		X1(AccountIndex64 {
+			network: NetworkId::Any,
+			index: account as u64,
+		})
+	}
+}
+
+thread_local! {
+	pub static EXECUTED_XCM: RefCell<Vec<(MultiLocation, Xcm<Call>)>> = RefCell::new(vec![]);
+}
+
+/// Records the executed messages.
+pub struct MockXcmExecutor;
+impl ExecuteXcm<Call> for MockXcmExecutor {
+	fn execute_xcm_in_credit(
+		origin: MultiLocation,
+		message: Xcm<Call>,
+		weight_limit: Weight,
+		_weight_credit: Weight,
+	) -> Outcome {
+		EXECUTED_XCM.with(|v| v.borrow_mut().push((origin, message)));
+		Outcome::Complete(weight_limit)
+	}
+}
+
+pub fn executed_xcm() -> Vec<(MultiLocation, Xcm<Call>)> {
+	EXECUTED_XCM.with(|v| v.borrow().clone())
+}
+
+parameter_types! {
+	pub SelfLocation: MultiLocation = X2(Parent, Parachain(SELF_PARA_ID));
+	pub const UnitWeightCost: Weight = 10;
+	pub const BaseXcmWeight: Weight = 100;
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type CurrencyIdConvert = CurrencyIdConvert;
+	type AccountIdToMultiLocation = AccountIdToMultiLocation;
+	type SelfLocation = SelfLocation;
+	type XcmExecutor = MockXcmExecutor;
+	type Weigher = FixedWeightBounds<UnitWeightCost, Call>;
+	type BaseXcmWeight = BaseXcmWeight;
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		XcmTransfer: xcm_transfer::{Pallet, Call, Event<T>},
+	}
+);
+
+pub struct ExtBuilder;
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		EXECUTED_XCM.with(|v| v.borrow_mut().clear());
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
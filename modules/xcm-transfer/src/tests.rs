@@ -0,0 +1,211 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the xcm transfer module.
+
+#![cfg(test)]
+
+use super::*;
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use xcm::v0::{
+	Junction::{AccountId32, AccountIndex64, GeneralKey, Parachain, Parent},
+	MultiLocation::{X1, X2, X3},
+	NetworkId,
+};
+
+const BOB_ID: [u8; 32] = [2u8; 32];
+
+fn sibling_bob() -> MultiLocation {
+	X3(
+		Parent,
+		Parachain(SIBLING_PARA_ID),
+		AccountId32 {
+			network: NetworkId::Any,
+			id: BOB_ID,
+		},
+	)
+}
+
+fn asset(location: MultiLocation, amount: Balance) -> MultiAsset {
+	MultiAsset::ConcreteFungible { id: location, amount }
+}
+
+fn deposit_to_bob(dest_weight: Weight) -> Vec<Order<()>> {
+	vec![
+		Order::BuyExecution {
+			fees: MultiAsset::All,
+			weight: 0,
+			debt: dest_weight,
+			halt_on_error: false,
+			xcm: vec![],
+		},
+		Order::DepositAsset {
+			assets: vec![MultiAsset::All],
+			dest: X1(AccountId32 {
+				network: NetworkId::Any,
+				id: BOB_ID,
+			}),
+		},
+	]
+}
+
+#[test]
+fn transfer_self_reserve_asset_with_fee_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmTransfer::transfer_with_fee_asset(
+			Origin::signed(ALICE),
+			KUSD,
+			1_000,
+			KAR,
+			100,
+			Box::new(sibling_bob()),
+			500
+		));
+		System::assert_last_event(Event::XcmTransfer(crate::Event::TransferredWithFee(
+			ALICE,
+			KUSD,
+			1_000,
+			KAR,
+			100,
+			sibling_bob(),
+		)));
+
+		assert_eq!(
+			executed_xcm(),
+			vec![(
+				X1(AccountIndex64 {
+					network: NetworkId::Any,
+					index: ALICE as u64,
+				}),
+				WithdrawAsset {
+					assets: vec![
+						asset(X3(Parent, Parachain(SELF_PARA_ID), GeneralKey(KUSD.encode())), 1_000),
+						asset(X3(Parent, Parachain(SELF_PARA_ID), GeneralKey(KAR.encode())), 100),
+					],
+					effects: vec![Order::DepositReserveAsset {
+						assets: vec![MultiAsset::All],
+						dest: X2(Parent, Parachain(SIBLING_PARA_ID)),
+						effects: deposit_to_bob(500),
+					}],
+				}
+			)]
+		);
+	});
+}
+
+#[test]
+fn transfer_to_reserve_with_fee_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmTransfer::transfer_with_fee_asset(
+			Origin::signed(ALICE),
+			VSKSM,
+			1_000,
+			BNC,
+			100,
+			Box::new(sibling_bob()),
+			500
+		));
+
+		assert_eq!(
+			executed_xcm()[0].1,
+			WithdrawAsset {
+				assets: vec![
+					asset(
+						X3(Parent, Parachain(SIBLING_PARA_ID), GeneralKey(VSKSM.encode())),
+						1_000
+					),
+					asset(X3(Parent, Parachain(SIBLING_PARA_ID), GeneralKey(BNC.encode())), 100),
+				],
+				effects: vec![Order::InitiateReserveWithdraw {
+					assets: vec![MultiAsset::All],
+					reserve: X2(Parent, Parachain(SIBLING_PARA_ID)),
+					effects: deposit_to_bob(500),
+				}],
+			}
+		);
+	});
+}
+
+#[test]
+fn transfer_with_fee_asset_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmTransfer::transfer_with_fee_asset(
+				Origin::signed(ALICE),
+				KUSD,
+				0,
+				KAR,
+				100,
+				Box::new(sibling_bob()),
+				500
+			),
+			Error::<Runtime>::ZeroAmount
+		);
+		assert_noop!(
+			XcmTransfer::transfer_with_fee_asset(
+				Origin::signed(ALICE),
+				KUSD,
+				1_000,
+				KUSD,
+				100,
+				Box::new(sibling_bob()),
+				500
+			),
+			Error::<Runtime>::FeeAssetIsTransferredAsset
+		);
+		assert_noop!(
+			XcmTransfer::transfer_with_fee_asset(
+				Origin::signed(ALICE),
+				CurrencyId::Token(primitives::TokenSymbol::LKSM),
+				1_000,
+				KAR,
+				100,
+				Box::new(sibling_bob()),
+				500
+			),
+			Error::<Runtime>::NotCrossChainTransferableCurrency
+		);
+		assert_noop!(
+			XcmTransfer::transfer_with_fee_asset(
+				Origin::signed(ALICE),
+				KSM,
+				1_000,
+				KAR,
+				100,
+				Box::new(sibling_bob()),
+				500
+			),
+			Error::<Runtime>::DistinctReserveForAssetAndFee
+		);
+		assert_noop!(
+			XcmTransfer::transfer_with_fee_asset(
+				Origin::signed(ALICE),
+				KUSD,
+				1_000,
+				KAR,
+				100,
+				Box::new(X2(Parent, Parachain(SIBLING_PARA_ID))),
+				500
+			),
+			Error::<Runtime>::InvalidDest
+		);
+		assert!(executed_xcm().is_empty());
+	});
+}
@@ -0,0 +1,230 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! The `benchmark-block` command, profiling the weight of historical blocks.
+
+use codec::{Codec, Decode, Encode};
+use frame_support::{
+	traits::GetCallMetadata,
+	weights::{constants::WEIGHT_PER_NANOS, DispatchInfo, Weight},
+};
+use frame_system::Phase;
+use pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi;
+use runtime_common::events::{EncodedEvent, EventsApi};
+use sc_cli::{CliConfiguration, ImportParams, Result, SharedParams};
+use sc_client_api::BlockBackend;
+use sp_api::{Core, ProvideRuntimeApi};
+use sp_block_builder::BlockBuilder;
+use sp_core::ExecutionContext;
+use sp_runtime::{
+	generic::{BlockId, UncheckedExtrinsic},
+	traits::{Block as BlockT, Header as HeaderT, MaybeDisplay, NumberFor, SignedExtension},
+	DispatchError,
+};
+use std::{fs::File, io::Write, path::PathBuf, sync::Arc, time::Instant};
+use structopt::StructOpt;
+
+/// The index of `frame_system` in all the runtimes.
+const SYSTEM_PALLET_INDEX: u8 = 0;
+
+/// The `benchmark-block` command used to re-execute historical blocks and
+/// compare the execution time of the extrinsics with their declared weight.
+#[derive(Debug, StructOpt)]
+pub struct BenchmarkBlockCmd {
+	/// Number of the first block to re-execute.
+	#[structopt(long)]
+	pub from: u32,
+
+	/// Number of the last block to re-execute, inclusive.
+	#[structopt(long)]
+	pub to: u32,
+
+	/// Write the CSV report into the file instead of stdout.
+	#[structopt(long, parse(from_os_str))]
+	pub output: Option<PathBuf>,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub import_params: ImportParams,
+}
+
+impl BenchmarkBlockCmd {
+	/// Re-execute the blocks on top of their parent state and write a CSV
+	/// report with a row for each extrinsic. `call_name` names the call of a
+	/// SCALE encoded extrinsic.
+	pub fn run<B, C, Balance>(&self, client: Arc<C>, call_name: impl Fn(&[u8]) -> String) -> Result<()>
+	where
+		B: BlockT,
+		C: ProvideRuntimeApi<B> + BlockBackend<B>,
+		C::Api: Core<B> + BlockBuilder<B> + TransactionPaymentApi<B, Balance> + EventsApi<B>,
+		Balance: Codec + MaybeDisplay,
+	{
+		if self.from > self.to {
+			return Err("`--from` must not be greater than `--to`".into());
+		}
+
+		let mut output: Box<dyn Write> = match &self.output {
+			Some(path) => Box::new(File::create(path)?),
+			None => Box::new(std::io::stdout()),
+		};
+		writeln!(
+			output,
+			"block,extrinsic,call,success,declared_weight,actual_weight,time_ns,time_weight,underweighted"
+		)?;
+
+		for number in self.from..=self.to {
+			let block = client
+				.block(&BlockId::Number(NumberFor::<B>::from(number)))?
+				.ok_or_else(|| format!("Block {} not found", number))?
+				.block;
+			let (mut header, extrinsics) = block.deconstruct();
+			let parent = BlockId::Hash(*header.parent_hash());
+
+			// the seal is added after the execution, remove it like the block import
+			if header
+				.digest()
+				.logs()
+				.last()
+				.map_or(false, |log| log.as_seal().is_some())
+			{
+				header.digest_mut().pop();
+			}
+
+			// the weights declared before the dispatch, as the transaction pool sees them
+			let api = client.runtime_api();
+			let declared_weights = extrinsics
+				.iter()
+				.map(|xt| {
+					api.query_info(&parent, xt.clone(), xt.encoded_size() as u32)
+						.map(|info| info.weight)
+				})
+				.collect::<std::result::Result<Vec<_>, _>>()
+				.map_err(|e| format!("Failed to query the dispatch info: {:?}", e))?;
+
+			// the changes of the calls are kept in the same runtime api instance
+			let api = client.runtime_api();
+			let block_start = Instant::now();
+			api.initialize_block_with_context(&parent, ExecutionContext::Importing, &header)
+				.map_err(|e| format!("Failed to initialize block {}: {:?}", number, e))?;
+
+			let mut results = Vec::with_capacity(extrinsics.len());
+			for xt in &extrinsics {
+				let start = Instant::now();
+				let result = api
+					.apply_extrinsic_with_context(&parent, ExecutionContext::Importing, xt.clone())
+					.map_err(|e| format!("Failed to apply extrinsic in block {}: {:?}", number, e))?;
+				results.push((matches!(result, Ok(Ok(()))), start.elapsed()));
+			}
+
+			api.finalize_block_with_context(&parent, ExecutionContext::Importing)
+				.map_err(|e| format!("Failed to finalize block {}: {:?}", number, e))?;
+			let block_time = block_start.elapsed();
+
+			let events = api
+				.query_events(&parent, Some(SYSTEM_PALLET_INDEX), None)
+				.map_err(|e| format!("Failed to query the events: {:?}", e))?;
+			let actual_weights = actual_weights(&events, extrinsics.len());
+
+			for (index, xt) in extrinsics.iter().enumerate() {
+				let (success, time) = results[index];
+				let time_weight = time.as_nanos() as Weight * WEIGHT_PER_NANOS;
+				writeln!(
+					output,
+					"{},{},{},{},{},{},{},{},{}",
+					number,
+					index,
+					call_name(&xt.encode()),
+					success,
+					declared_weights[index],
+					actual_weights[index].map(|w| w.to_string()).unwrap_or_default(),
+					time.as_nanos(),
+					time_weight,
+					time_weight > declared_weights[index],
+				)?;
+			}
+
+			log::info!(
+				"Block {}: {} extrinsics executed in {:?}, {} weight declared",
+				number,
+				extrinsics.len(),
+				block_time,
+				declared_weights.iter().sum::<Weight>(),
+			);
+		}
+
+		Ok(())
+	}
+}
+
+/// The `pallet::call` name of a SCALE encoded extrinsic.
+pub fn call_name<Address, Call, Signature, Extra>(extrinsic: &[u8]) -> String
+where
+	Address: Decode,
+	Call: Decode + GetCallMetadata,
+	Signature: Decode,
+	Extra: SignedExtension,
+{
+	UncheckedExtrinsic::<Address, Call, Signature, Extra>::decode(&mut &*extrinsic)
+		.map(|xt| {
+			let metadata = xt.function.get_call_metadata();
+			format!("{}::{}", metadata.pallet_name, metadata.function_name)
+		})
+		.unwrap_or_else(|_| "unknown".into())
+}
+
+/// The actual weights of the extrinsics, in the `ExtrinsicSuccess` and
+/// `ExtrinsicFailed` events of `frame_system`.
+fn actual_weights(events: &[EncodedEvent], count: usize) -> Vec<Option<Weight>> {
+	let mut weights = vec![None; count];
+
+	for event in events {
+		let mut input = &event.record[..];
+		let index = match Phase::decode(&mut input) {
+			Ok(Phase::ApplyExtrinsic(index)) if (index as usize) < count => index as usize,
+			_ => continue,
+		};
+		// skip the pallet index and the event index
+		let mut input = input.get(2..).unwrap_or_default();
+
+		let info = match event.event_index {
+			0 => DispatchInfo::decode(&mut input).ok(),
+			1 => DispatchError::decode(&mut input)
+				.and_then(|_| DispatchInfo::decode(&mut input))
+				.ok(),
+			_ => None,
+		};
+		if let Some(info) = info {
+			weights[index] = Some(info.weight);
+		}
+	}
+
+	weights
+}
+
+impl CliConfiguration for BenchmarkBlockCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+}
@@ -19,7 +19,7 @@
 //! Acala CLI library.
 
 use sc_cli::{KeySubcommand, SignCmd, VanityCmd, VerifyCmd};
-use std::path::PathBuf;
+use std::{path::PathBuf, str::FromStr};
 use structopt::StructOpt;
 
 use service::chain_spec;
@@ -49,10 +49,18 @@ pub enum Subcommand {
 	#[structopt(name = "benchmark", about = "Benchmark runtime modules.")]
 	Benchmark(frame_benchmarking_cli::BenchmarkCmd),
 
+	/// Re-execute historical blocks and report the execution time and the
+	/// weight of their extrinsics.
+	#[structopt(
+		name = "benchmark-block",
+		about = "Profile the execution time of historical blocks against their weight."
+	)]
+	BenchmarkBlock(crate::BenchmarkBlockCmd),
+
 	/// Try some experimental command on the runtime. This includes migration and runtime-upgrade
 	/// testing.
 	#[cfg(feature = "try-runtime")]
-	TryRuntime(try_runtime_cli::TryRuntimeCmd),
+	TryRuntime(TryRuntimeCmd),
 
 	/// Verify a signature for a message, provided on STDIN, with a given
 	/// (public or secret) key.
@@ -86,6 +94,29 @@ pub enum Subcommand {
 	Revert(sc_cli::RevertCmd),
 }
 
+/// The `try-runtime` command, with a cache of the live state snapshots.
+#[cfg(feature = "try-runtime")]
+#[derive(Debug, StructOpt)]
+pub struct TryRuntimeCmd {
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub cmd: try_runtime_cli::TryRuntimeCmd,
+
+	/// Directory of the cached state snapshots.
+	///
+	/// The live state of the chain is written into the directory by the first
+	/// `on-runtime-upgrade live` run, and read from the snapshot by the
+	/// following runs, to rehearse the migrations without scraping the state
+	/// again.
+	#[structopt(long, parse(from_os_str))]
+	pub snapshot_cache: Option<PathBuf>,
+
+	/// Scrape the live state again and replace the cached snapshot, e.g. to
+	/// rehearse at a new block.
+	#[structopt(long, requires = "snapshot-cache")]
+	pub refresh_snapshot: bool,
+}
+
 /// Command for exporting the genesis state of the parachain
 #[derive(Debug, StructOpt)]
 pub struct ExportGenesisStateCommand {
@@ -147,6 +178,50 @@ pub struct Cli {
 	/// Can only be used with `--dev`
 	#[structopt(long = "instant-sealing", requires = "dev")]
 	pub instant_sealing: bool,
+
+	/// Custom genesis of the Mandala dev chain
+	///
+	/// A JSON file, or the JSON itself, overriding the endowed accounts, the
+	/// oracle members, the enabled trading pairs and the collateral params.
+	/// Can only be used with `--dev`
+	#[structopt(long = "genesis-config", value_name = "JSON", requires = "dev")]
+	pub genesis_config: Option<String>,
+
+	/// Fast sync the parachain
+	///
+	/// Download the state of the latest finalized block instead of importing
+	/// all the blocks. Can't be used with an archive node
+	#[structopt(long = "fast-sync", conflicts_with = "dev")]
+	pub fast_sync: bool,
+
+	/// Warp sync the embedded relay chain node
+	///
+	/// Warp to the latest finalized relay chain block with GRANDPA finality
+	/// proofs, then download its state
+	#[structopt(long = "relay-chain-warp-sync", conflicts_with = "dev")]
+	pub relay_chain_warp_sync: bool,
+
+	/// Enable the optional EVM RPC APIs, e.g. `debug`
+	#[structopt(long = "ethapi", use_delimiter = true)]
+	pub ethapi: Vec<EthApi>,
+}
+
+/// Optional EVM RPC APIs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EthApi {
+	/// The `debug_*` tracing RPCs.
+	Debug,
+}
+
+impl FromStr for EthApi {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"debug" => Ok(Self::Debug),
+			_ => Err(format!("`{}` is not recognized as a supported EVM RPC API", s)),
+		}
+	}
 }
 
 /// Relay chain CLI.
@@ -19,7 +19,12 @@
 // Disable the following lints
 #![allow(clippy::borrowed_box)]
 
-use crate::cli::{Cli, RelayChainCli, Subcommand};
+#[cfg(feature = "try-runtime")]
+use crate::cli::TryRuntimeCmd;
+use crate::{
+	benchmark_block::call_name,
+	cli::{Cli, EthApi, RelayChainCli, Subcommand},
+};
 use codec::Encode;
 use cumulus_client_service::genesis::generate_genesis_block;
 use cumulus_primitives_core::ParaId;
@@ -30,7 +35,10 @@ use sc_cli::{
 	ChainSpec, CliConfiguration, DefaultConfigurationValues, ImportParams, KeystoreParams, NetworkParams, Result,
 	RuntimeVersion, SharedParams, SubstrateCli,
 };
-use sc_service::config::{BasePath, PrometheusConfig};
+use sc_service::{
+	config::{BasePath, DatabaseConfig, PrometheusConfig},
+	Configuration, PartialComponents,
+};
 use sp_core::hexdisplay::HexDisplay;
 use sp_runtime::traits::Block as BlockT;
 use std::{io::Write, net::SocketAddr};
@@ -71,7 +79,16 @@ impl SubstrateCli for Cli {
 
 		Ok(match id {
 			#[cfg(feature = "with-mandala-runtime")]
-			"dev" => Box::new(chain_spec::mandala::dev_testnet_config()?),
+			"dev" => match &self.genesis_config {
+				Some(genesis_config) => {
+					// either a JSON file or the JSON itself
+					let json = std::fs::read(genesis_config).unwrap_or_else(|_| genesis_config.as_bytes().to_vec());
+					Box::new(chain_spec::mandala::mandala_custom(
+						chain_spec::mandala::GenesisOverrides::from_json_bytes(&json)?,
+					)?)
+				}
+				None => Box::new(chain_spec::mandala::dev_testnet_config()?),
+			},
 			#[cfg(feature = "with-mandala-runtime")]
 			"pc-dev" => Box::new(chain_spec::mandala::parachain_dev_testnet_config()?),
 			#[cfg(feature = "with-mandala-runtime")]
@@ -213,7 +230,7 @@ macro_rules! with_runtime_or_err {
 		if $chain_spec.is_acala() {
 			#[cfg(feature = "with-acala-runtime")]
 			#[allow(unused_imports)]
-			use service::{acala_runtime::{Block, RuntimeApi}, AcalaExecutor as Executor};
+			use service::{acala_runtime::{AccountId, Address, Balance, Block, Call, CurrencyId, Runtime, RuntimeApi, Signature, SignedExtra}, AcalaExecutor as Executor};
 			#[cfg(feature = "with-acala-runtime")]
 			$( $code )*
 
@@ -222,7 +239,7 @@ macro_rules! with_runtime_or_err {
 		} else if $chain_spec.is_karura() {
 			#[cfg(feature = "with-karura-runtime")]
 			#[allow(unused_imports)]
-			use service::{karura_runtime::{Block, RuntimeApi}, KaruraExecutor as Executor};
+			use service::{karura_runtime::{AccountId, Address, Balance, Block, Call, CurrencyId, Runtime, RuntimeApi, Signature, SignedExtra}, KaruraExecutor as Executor};
 			#[cfg(feature = "with-karura-runtime")]
 			$( $code )*
 
@@ -231,7 +248,7 @@ macro_rules! with_runtime_or_err {
 		} else {
 			#[cfg(feature = "with-mandala-runtime")]
 			#[allow(unused_imports)]
-			use service::{mandala_runtime::{Block, RuntimeApi}, MandalaExecutor as Executor};
+			use service::{mandala_runtime::{AccountId, Address, Balance, Block, Call, CurrencyId, Runtime, RuntimeApi, Signature, SignedExtra}, MandalaExecutor as Executor};
 			#[cfg(feature = "with-mandala-runtime")]
 			$( $code )*
 
@@ -252,6 +269,14 @@ pub fn run() -> sc_cli::Result<()> {
 
 			set_default_ss58_version(chain_spec);
 
+			if cmd.is_decode() {
+				with_runtime_or_err!(chain_spec, {
+					return cmd.run_decode::<AccountId, CurrencyId, Address, Call, Signature, SignedExtra>(
+						&Runtime::metadata(),
+					);
+				})
+			}
+
 			runner.sync_run(|mut config| {
 				let (client, _, _, _) = service::new_chain_ops(&mut config)?;
 				cmd.run(client)
@@ -269,6 +294,24 @@ pub fn run() -> sc_cli::Result<()> {
 			})
 		}
 
+		Some(Subcommand::BenchmarkBlock(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			let chain_spec = &runner.config().chain_spec;
+
+			set_default_ss58_version(chain_spec);
+
+			with_runtime_or_err!(chain_spec, {
+				return runner.sync_run(|config| {
+					let PartialComponents { client, .. } = service::new_partial::<RuntimeApi, Executor>(
+						&config,
+						config.chain_spec.is_mandala_dev(),
+						false,
+					)?;
+					cmd.run::<Block, _, Balance>(client, call_name::<Address, Call, Signature, SignedExtra>)
+				});
+			})
+		}
+
 		Some(Subcommand::Key(cmd)) => cmd.run(&cli),
 		Some(Subcommand::Sign(cmd)) => cmd.run(),
 		Some(Subcommand::Verify(cmd)) => cmd.run(),
@@ -345,10 +388,12 @@ pub fn run() -> sc_cli::Result<()> {
 						.chain(cli.relaychain_args.iter()),
 				);
 
-				let polkadot_config =
+				let mut polkadot_config =
 					SubstrateCli::create_configuration(&polkadot_cli, &polkadot_cli, config.task_executor.clone())
 						.map_err(|err| format!("Relay chain argument error: {}", err))?;
 
+				inherit_parity_db(&polkadot_cli, &config, &mut polkadot_config);
+
 				cmd.run(config, polkadot_config)
 			})
 		}
@@ -421,6 +466,8 @@ pub fn run() -> sc_cli::Result<()> {
 
 			set_default_ss58_version(chain_spec);
 
+			let cmd = cmd.with_snapshot_cache(chain_spec.id())?;
+
 			with_runtime_or_err!(chain_spec, {
 				return runner.async_run(|config| {
 					let registry = config.prometheus_config.as_ref().map(|cfg| &cfg.registry);
@@ -432,18 +479,40 @@ pub fn run() -> sc_cli::Result<()> {
 		}
 
 		None => {
-			let runner = cli.create_runner(&cli.run.normalize())?;
+			let mut run_cmd = cli.run.normalize();
+			// `archive-canonical` is not known by `sc_cli`, the profile is applied to the
+			// configuration of the node instead.
+			let pruning = run_cmd
+				.base
+				.import_params
+				.pruning_params
+				.pruning
+				.take()
+				.map(|pruning| pruning.parse::<service::PruningProfile>())
+				.transpose()?;
+
+			let runner = cli.create_runner(&run_cmd)?;
 			let chain_spec = &runner.config().chain_spec;
 			let is_mandala_dev = chain_spec.is_mandala_dev();
+			let enable_evm_debug = cli.ethapi.contains(&EthApi::Debug);
+			let sync_strategy = service::SyncStrategy {
+				parachain_fast_sync: cli.fast_sync,
+				relay_chain_warp_sync: cli.relay_chain_warp_sync,
+			};
 
 			set_default_ss58_version(chain_spec);
 
-			runner.run_node_until_exit(|config| async move {
+			runner.run_node_until_exit(|mut config| async move {
 				let para_id = chain_spec::Extensions::try_get(&*config.chain_spec).map(|e| e.para_id);
 
+				if let Some(pruning) = pruning {
+					pruning.validate(&config.role, enable_evm_debug)?;
+					config.state_pruning = pruning.into();
+				}
+
 				if is_mandala_dev {
 					#[cfg(feature = "with-mandala-runtime")]
-					return service::mandala_dev(config, cli.instant_sealing).map_err(Into::into);
+					return service::mandala_dev(config, cli.instant_sealing, enable_evm_debug).map_err(Into::into);
 					#[cfg(not(feature = "with-mandala-runtime"))]
 					return Err(service::MANDALA_RUNTIME_NOT_AVAILABLE.into());
 				} else if cli.instant_sealing {
@@ -459,9 +528,10 @@ pub fn run() -> sc_cli::Result<()> {
 
 				let id = ParaId::from(cli.run.parachain_id.or(para_id).unwrap_or(2000));
 
-				let polkadot_config =
+				let mut polkadot_config =
 					SubstrateCli::create_configuration(&polkadot_cli, &polkadot_cli, config.task_executor.clone())
 						.map_err(|err| format!("Relay chain argument error: {}", err))?;
+				inherit_parity_db(&polkadot_cli, &config, &mut polkadot_config);
 
 				info!("Parachain id: {:?}", id);
 				info!(
@@ -471,10 +541,16 @@ pub fn run() -> sc_cli::Result<()> {
 
 				with_runtime_or_err!(config.chain_spec, {
 					{
-						service::start_node::<RuntimeApi, Executor>(config, polkadot_config, id)
-							.await
-							.map(|r| r.0)
-							.map_err(Into::into)
+						service::start_node::<RuntimeApi, Executor>(
+							config,
+							polkadot_config,
+							id,
+							enable_evm_debug,
+							sync_strategy,
+						)
+						.await
+						.map(|r| r.0)
+						.map_err(Into::into)
 					}
 				})
 			})
@@ -482,6 +558,63 @@ pub fn run() -> sc_cli::Result<()> {
 	}
 }
 
+/// The embedded relay chain node uses ParityDB too if the parachain does,
+/// unless `--database` is in the relay chain arguments.
+fn inherit_parity_db(polkadot_cli: &RelayChainCli, config: &Configuration, polkadot_config: &mut Configuration) {
+	if polkadot_cli.base.base.import_params.database_params.database.is_some() {
+		return;
+	}
+
+	if let (DatabaseConfig::ParityDb { .. }, DatabaseConfig::RocksDb { path, .. }) =
+		(&config.database, &polkadot_config.database)
+	{
+		polkadot_config.database = DatabaseConfig::ParityDb {
+			path: path.with_file_name("paritydb"),
+		};
+	}
+}
+
+#[cfg(feature = "try-runtime")]
+impl TryRuntimeCmd {
+	/// The `try-runtime on-runtime-upgrade` command reading the live state from
+	/// the snapshot in the cache directory if it exists, or writing the
+	/// snapshot there.
+	fn with_snapshot_cache(&self, chain_id: &str) -> Result<try_runtime_cli::TryRuntimeCmd> {
+		use try_runtime_cli::{Command, State};
+
+		let mut cmd = self.cmd.clone();
+		let (dir, state) = match (&self.snapshot_cache, &mut cmd.command) {
+			(Some(dir), Command::OnRuntimeUpgrade(inner)) => (dir, &mut inner.state),
+			_ => return Ok(cmd),
+		};
+
+		std::fs::create_dir_all(dir)?;
+		let path = dir.join(format!("{}.snap", chain_id));
+
+		if let State::Live { snapshot_path, .. } = &mut *state {
+			if path.exists() && !self.refresh_snapshot {
+				info!("Using the cached snapshot {:?}", path);
+				*state = State::Snap { snapshot_path: path };
+			} else if snapshot_path.is_none() {
+				*snapshot_path = Some(path);
+			}
+		}
+
+		Ok(cmd)
+	}
+}
+
+#[cfg(feature = "try-runtime")]
+impl CliConfiguration for TryRuntimeCmd {
+	fn shared_params(&self) -> &SharedParams {
+		self.cmd.shared_params()
+	}
+
+	fn chain_id(&self, is_dev: bool) -> Result<String> {
+		self.cmd.chain_id(is_dev)
+	}
+}
+
 impl DefaultConfigurationValues for RelayChainCli {
 	fn p2p_listen_port() -> u16 {
 		30334
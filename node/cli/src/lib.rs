@@ -21,11 +21,16 @@
 #![warn(missing_docs)]
 #![warn(unused_extern_crates)]
 
+#[cfg(feature = "cli")]
+mod benchmark_block;
 #[cfg(feature = "cli")]
 mod cli;
 #[cfg(feature = "cli")]
 mod command;
 
+#[cfg(feature = "cli")]
+pub use benchmark_block::*;
+
 #[cfg(feature = "cli")]
 pub use cli::*;
 
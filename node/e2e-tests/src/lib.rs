@@ -20,18 +20,20 @@
 
 #![allow(clippy::type_complexity)]
 
+use acala_rpc::DenyUnsafe;
 use node_primitives::Block;
 use node_runtime::{api, native_version, Runtime, RuntimeApi, SignedExtra};
 use node_service::chain_spec::mandala::dev_testnet_config;
 use node_service::default_mock_parachain_inherent_data_provider;
 use sc_consensus_manual_seal::ConsensusDataProvider;
 use sc_service::{new_full_parts, Configuration, TFullBackend, TFullClient, TaskExecutor, TaskManager};
+use sc_transaction_pool::FullPool;
 use sp_inherents::CreateInherentDataProviders;
 use sp_keyring::sr25519::Keyring::Alice;
 use sp_keystore::SyncCryptoStorePtr;
 use sp_runtime::{generic::Era, traits::IdentifyAccount, MultiSigner};
 use std::sync::Arc;
-use test_runner::{default_config, ChainInfo, Node, SignatureVerificationOverride};
+use test_runner::{default_config, ChainInfo, ConfigMode, Node, SignatureVerificationOverride, SignedExtrasOptions};
 
 sc_executor::native_executor_instance!(
 	pub Executor,
@@ -72,8 +74,28 @@ impl ChainInfo for NodeTemplateChainInfo {
 		)
 	}
 
+	fn signed_extras_with(
+		_from: <Self::Runtime as frame_system::Config>::AccountId,
+		options: SignedExtrasOptions<<Self::Runtime as frame_system::Config>::Index>,
+	) -> Self::SignedExtras {
+		(
+			frame_system::CheckSpecVersion::<Self::Runtime>::new(),
+			frame_system::CheckTxVersion::<Self::Runtime>::new(),
+			frame_system::CheckGenesis::<Self::Runtime>::new(),
+			frame_system::CheckMortality::<Self::Runtime>::from(options.era),
+			frame_system::CheckNonce::<Self::Runtime>::from(options.nonce),
+			frame_system::CheckWeight::<Self::Runtime>::new(),
+			module_transaction_payment::ChargeTransactionPayment::<Self::Runtime>::from(options.tip),
+			module_evm::SetEvmOrigin::<Self::Runtime>::new(),
+		)
+	}
+
 	fn config(task_executor: TaskExecutor) -> Configuration {
-		default_config(task_executor, Box::new(dev_testnet_config().unwrap()))
+		default_config::<Self::Block>(
+			task_executor,
+			Box::new(dev_testnet_config().unwrap()),
+			ConfigMode::Local,
+		)
 	}
 
 	fn create_client_parts(
@@ -124,6 +146,20 @@ impl ChainInfo for NodeTemplateChainInfo {
 		))
 	}
 
+	fn rpc_extensions(
+		client: Arc<TFullClient<Self::Block, Self::RuntimeApi, Self::Executor>>,
+		pool: Arc<FullPool<Self::Block, TFullClient<Self::Block, Self::RuntimeApi, Self::Executor>>>,
+		deny_unsafe: DenyUnsafe,
+	) -> jsonrpc_core::IoHandler<sc_rpc::Metadata> {
+		acala_rpc::create_full(acala_rpc::FullDeps {
+			client,
+			pool,
+			deny_unsafe,
+			enable_evm_debug: false,
+			subscription_executor: acala_rpc::SubscriptionTaskExecutor::new(sp_core::testing::TaskExecutor::new()),
+		})
+	}
+
 	fn dispatch_with_root(call: <Self::Runtime as frame_system::Config>::Call, node: &mut Node<Self>) {
 		let alice = MultiSigner::from(Alice.public()).into_account();
 		let call = pallet_sudo::Call::sudo(Box::new(call));
@@ -138,9 +174,14 @@ mod tests {
 	use ecosystem_renvm_bridge::EcdsaSignature;
 	use hex_literal::hex;
 	use log::LevelFilter;
+	use node_service::chain_spec::mandala::PARA_ID;
+	use sp_blockchain::HeaderBackend;
 	use sp_keyring::sr25519::Keyring::Bob;
 	use sp_runtime::{AccountId32, MultiAddress};
-	use test_runner::NodeConfig;
+	use std::time::Duration;
+	use test_runner::{
+		AbiCall, Mortality, NetworkBuilder, NodeConfig, SignedExtrinsicBuilder, SimulatedRelay, TestConfigBuilder,
+	};
 
 	#[test]
 	fn test_runner() {
@@ -166,6 +207,7 @@ mod tests {
 				("runtime", LevelFilter::Trace),
 				("aura", LevelFilter::Debug),
 			],
+			relay: None,
 		};
 		let mut node = Node::<NodeTemplateChainInfo>::new(config).unwrap();
 		// seals blocks
@@ -182,7 +224,10 @@ mod tests {
 
 	#[test]
 	fn simple_balances_test() {
-		let config = NodeConfig { log_targets: vec![] };
+		let config = NodeConfig {
+			log_targets: vec![],
+			relay: None,
+		};
 		let mut node = Node::<NodeTemplateChainInfo>::new(config).unwrap();
 
 		type Balances = pallet_balances::Pallet<Runtime>;
@@ -211,7 +256,10 @@ mod tests {
 
 	#[test]
 	fn transaction_pool_priority_order_test() {
-		let config = NodeConfig { log_targets: vec![] };
+		let config = NodeConfig {
+			log_targets: vec![],
+			relay: None,
+		};
 		let mut node = Node::<NodeTemplateChainInfo>::new(config).unwrap();
 
 		let (alice, bob) = (MultiSigner::from(Alice.public()), MultiSigner::from(Bob.public()));
@@ -260,4 +308,321 @@ mod tests {
 		assert_eq!(tx3.hash, normal_tx_hash);
 		assert_eq!(tx3.priority, 42785501349000);
 	}
+
+	#[test]
+	fn network_block_propagation_test() {
+		let mut network = NetworkBuilder::<NodeTemplateChainInfo>::new()
+			.with_authorities(2)
+			.with_full_nodes(1)
+			.build()
+			.unwrap();
+
+		assert_eq!(network.authorities().len(), 2);
+		assert_eq!(network.full_nodes().len(), 1);
+
+		// blocks sealed by one authority are imported by every other node.
+		network.node(0).seal_blocks(2);
+		assert!(network.wait_for_best_block(2, Duration::from_secs(30)));
+	}
+
+	#[test]
+	fn network_partition_test() {
+		let mut network = NetworkBuilder::<NodeTemplateChainInfo>::new()
+			.with_authorities(2)
+			.build()
+			.unwrap();
+
+		// blocks sealed while partitioned stay on the sealing node.
+		network.partition_for_blocks(&[&[0], &[1]], 2);
+		network.seal_blocks(0, 1);
+		assert!(!network.wait_for_best_block(1, Duration::from_secs(5)));
+
+		// the partition heals after the second block.
+		network.seal_blocks(0, 1);
+		assert!(network.wait_for_best_block(2, Duration::from_secs(30)));
+	}
+
+	#[test]
+	fn simulated_relay_test() {
+		let relay = SimulatedRelay::new(PARA_ID.into());
+		let config = NodeConfig {
+			log_targets: vec![],
+			relay: Some(relay.clone()),
+		};
+		let mut node = Node::<NodeTemplateChainInfo>::new(config).unwrap();
+
+		node.seal_blocks(1);
+		let validation_data = node.with_state(cumulus_pallet_parachain_system::Pallet::<Runtime>::validation_data);
+		assert_eq!(validation_data.map(|data| data.relay_parent_number), Some(1000));
+
+		// queued messages are delivered with the next block.
+		relay.send_downward_message(b"not a valid xcm".to_vec());
+		relay.send_horizontal_message(2000.into(), b"not a valid xcmp message".to_vec());
+		assert_eq!(relay.pending_messages(), 2);
+
+		node.seal_blocks(1);
+		assert_eq!(relay.pending_messages(), 0);
+		assert_eq!(relay.relay_block_number(), 1004);
+		assert_eq!(node.client().info().best_number, 2);
+
+		let validation_data = node.with_state(cumulus_pallet_parachain_system::Pallet::<Runtime>::validation_data);
+		assert_eq!(validation_data.map(|data| data.relay_parent_number), Some(1002));
+	}
+
+	#[test]
+	fn time_travel_test() {
+		let config = NodeConfig {
+			log_targets: vec![],
+			relay: None,
+		};
+		let mut node = Node::<NodeTemplateChainInfo>::new(config).unwrap();
+
+		node.seal_blocks(1);
+		let now = node.with_state(node_runtime::Timestamp::now);
+
+		// one day later
+		let later = now + 24 * 60 * 60 * 1000;
+		node.jump_to_timestamp(later);
+		node.seal_blocks(1);
+		assert_eq!(node.with_state(node_runtime::Timestamp::now), later);
+
+		// following blocks advance by the block time
+		node.set_block_time(6_000);
+		node.seal_blocks(2);
+		assert_eq!(node.with_state(node_runtime::Timestamp::now), later + 12_000 + 6_000);
+
+		node.advance_time(60_000);
+		node.seal_blocks(1);
+		assert_eq!(
+			node.with_state(node_runtime::Timestamp::now),
+			later + 18_000 + 6_000 + 60_000
+		);
+	}
+
+	#[test]
+	fn snapshot_restore_test() {
+		let config = NodeConfig {
+			log_targets: vec![],
+			relay: None,
+		};
+		let mut node = Node::<NodeTemplateChainInfo>::new(config).unwrap();
+
+		type Balances = pallet_balances::Pallet<Runtime>;
+
+		let (alice, bob) = (MultiSigner::from(Alice.public()), MultiSigner::from(Bob.public()));
+		let (alice_account_id, bob_account_id) = (alice.into_account(), bob.into_account());
+
+		node.seal_blocks(1);
+		let snapshot = node.snapshot();
+		let old_balance = node.with_state(|| Balances::free_balance(bob_account_id.clone()));
+
+		for _ in 0..2 {
+			let tx = pallet_balances::Call::transfer(MultiAddress::from(bob_account_id.clone()), 1_000_000_000_000);
+			node.submit_extrinsic(tx, Some(alice_account_id.clone()));
+			node.seal_blocks(1);
+			assert_eq!(
+				node.with_state(|| Balances::free_balance(bob_account_id.clone())),
+				old_balance + 1_000_000_000_000
+			);
+
+			// every case starts from the same state
+			node.restore(snapshot);
+			assert_eq!(node.client().info().best_number, 1);
+			assert_eq!(
+				node.with_state(|| Balances::free_balance(bob_account_id.clone())),
+				old_balance
+			);
+		}
+	}
+
+	#[test]
+	fn signed_extrinsic_builder_test() {
+		let config = NodeConfig {
+			log_targets: vec![],
+			relay: None,
+		};
+		let mut node = Node::<NodeTemplateChainInfo>::new(config).unwrap();
+		node.seal_blocks(1);
+
+		type Balances = pallet_balances::Pallet<Runtime>;
+
+		let mut builder = SignedExtrinsicBuilder::<NodeTemplateChainInfo>::new()
+			.tip(1_000)
+			.mortality(Mortality::Mortal { period: 64 });
+		let alice_account_id = SignedExtrinsicBuilder::<NodeTemplateChainInfo>::account_id(&Alice.into());
+		let bob_account_id = MultiSigner::from(Bob.public()).into_account();
+		let old_balance = node.with_state(|| Balances::free_balance(bob_account_id.clone()));
+
+		// several extrinsics of the same account in one block.
+		for _ in 0..3 {
+			let tx = pallet_balances::Call::transfer(MultiAddress::from(bob_account_id.clone()), 1_000_000_000_000);
+			builder.submit(&mut node, tx, Alice).unwrap();
+		}
+		assert_eq!(builder.next_nonce(&node, &alice_account_id), 3);
+		assert_eq!(node.pool().ready().count(), 3);
+
+		node.seal_blocks(1);
+		assert_eq!(
+			node.with_state(|| Balances::free_balance(bob_account_id.clone())),
+			old_balance + 3_000_000_000_000
+		);
+		assert_eq!(
+			node.with_state(|| frame_system::Pallet::<Runtime>::account_nonce(alice_account_id.clone())),
+			3
+		);
+	}
+
+	#[test]
+	fn rpc_server_test() {
+		let config = NodeConfig {
+			log_targets: vec![],
+			relay: None,
+		};
+		let node = Node::<NodeTemplateChainInfo>::with_config(config, |task_executor| {
+			TestConfigBuilder::new()
+				.with_ws_rpc()
+				.with_http_rpc()
+				.build(task_executor, Box::new(dev_testnet_config().unwrap()))
+		})
+		.unwrap();
+
+		let ws_address = node.rpc_ws_address().expect("ws rpc is enabled");
+		let http_address = node.rpc_http_address().expect("http rpc is enabled");
+		assert!(std::net::TcpStream::connect(ws_address).is_ok());
+		assert!(std::net::TcpStream::connect(http_address).is_ok());
+
+		// acala rpc extensions are served.
+		let request = r#"{"jsonrpc":"2.0","method":"oracle_getAllValues","params":["Aggregated"],"id":1}"#;
+		let response = node
+			.rpc_handler()
+			.handle_request_sync(request, Default::default())
+			.expect("request is answered");
+		assert!(response.contains(r#""result""#));
+	}
+
+	#[test]
+	fn execution_config_test() {
+		let config = NodeConfig {
+			log_targets: vec![],
+			relay: None,
+		};
+		let mut node = Node::<NodeTemplateChainInfo>::with_config(config, |task_executor| {
+			TestConfigBuilder::new()
+				.with_native_else_wasm()
+				.with_heap_pages(4096)
+				.with_max_runtime_instances(2)
+				.build(task_executor, Box::new(dev_testnet_config().unwrap()))
+		})
+		.unwrap();
+
+		node.seal_blocks(1);
+		assert_eq!(node.client().info().best_number, 1);
+	}
+
+	#[test]
+	fn parity_db_test() {
+		let config = NodeConfig {
+			log_targets: vec![],
+			relay: None,
+		};
+		let mut node = Node::<NodeTemplateChainInfo>::with_config(config, |task_executor| {
+			TestConfigBuilder::new()
+				.with_parity_db()
+				.with_state_pruning(sc_service::config::PruningMode::ArchiveCanonical)
+				.build(task_executor, Box::new(dev_testnet_config().unwrap()))
+		})
+		.unwrap();
+
+		node.seal_blocks(2);
+		assert_eq!(node.client().info().best_number, 2);
+	}
+
+	#[test]
+	fn deploy_contract_test() {
+		let config = NodeConfig {
+			log_targets: vec![],
+			relay: None,
+		};
+		let mut node = Node::<NodeTemplateChainInfo>::new(config).unwrap();
+		node.seal_blocks(1);
+
+		let alice = MultiSigner::from(Alice.public()).into_account();
+		let bob = MultiSigner::from(Bob.public()).into_account();
+
+		let bytecode =
+			sp_core::bytes::from_hex(include!("../../../modules/evm-bridge/src/erc20_demo_contract")).unwrap();
+		let contract = node
+			.deploy_contract(alice.clone(), bytecode, AbiCall::constructor())
+			.unwrap();
+
+		// the deployed contract is callable by anyone.
+		use module_evm::AddressMapping;
+		let bob_evm_address =
+			node.with_state(|| <Runtime as module_evm::Config>::AddressMapping::get_or_create_evm_address(&bob));
+		assert_eq!(
+			contract.call(
+				&mut node,
+				alice,
+				AbiCall::new("transfer(address,uint256)")
+					.address(bob_evm_address)
+					.uint(1_000)
+			),
+			Ok(())
+		);
+	}
+
+	#[test]
+	fn runtime_upgrade_test() {
+		let config = NodeConfig {
+			log_targets: vec![],
+			relay: None,
+		};
+		let mut node = Node::<NodeTemplateChainInfo>::new(config).unwrap();
+		node.seal_blocks(1);
+
+		let version = node.runtime_version();
+		assert_eq!(version.spec_version, native_version().runtime_version.spec_version);
+
+		// upgrading to the same runtime is rejected, the spec version must increase.
+		let wasm = node_runtime::WASM_BINARY.expect("wasm binary is built").to_vec();
+		assert!(node.perform_runtime_upgrade(wasm).is_err());
+		assert_eq!(node.runtime_version(), version);
+	}
+
+	#[test]
+	fn dry_run_extrinsic_test() {
+		let config = NodeConfig {
+			log_targets: vec![],
+			relay: None,
+		};
+		let mut node = Node::<NodeTemplateChainInfo>::new(config).unwrap();
+		node.seal_blocks(1);
+
+		type Balances = pallet_balances::Pallet<Runtime>;
+
+		let alice = MultiSigner::from(Alice.public()).into_account();
+		let bob = MultiSigner::from(Bob.public()).into_account();
+		let old_balance = node.with_state(|| Balances::free_balance(bob.clone()));
+
+		let tx = pallet_balances::Call::transfer(MultiAddress::from(bob.clone()), 1_000_000_000_000);
+		let dry_run = node.dry_run_extrinsic(tx, Some(alice.clone()));
+
+		assert!(dry_run.result.is_ok());
+		assert!(dry_run.weight > 0);
+		assert!(!dry_run.storage_changes.is_empty());
+		assert!(dry_run.events.iter().any(|record| matches!(
+			record.event,
+			node_runtime::Event::Balances(pallet_balances::Event::Transfer(ref from, ref to, 1_000_000_000_000))
+				if *from == alice && *to == bob
+		)));
+
+		// nothing changed on chain.
+		assert_eq!(node.with_state(|| Balances::free_balance(bob.clone())), old_balance);
+		assert_eq!(node.client().info().best_number, 1);
+
+		// root only calls are rejected for signed origins.
+		let call = frame_system::Call::set_heap_pages(64);
+		assert!(node.dry_run_extrinsic(call.clone(), Some(alice)).result.is_err());
+		assert!(node.dry_run_extrinsic(call, None).result.is_ok());
+	}
 }
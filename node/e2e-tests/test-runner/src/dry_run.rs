@@ -0,0 +1,83 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![allow(clippy::all)]
+
+use frame_support::{
+	dispatch::{DispatchResultWithPostInfo, Dispatchable},
+	weights::{GetDispatchInfo, PostDispatchInfo, Weight},
+};
+use sc_client_api::CallExecutor;
+use sc_service::TFullCallExecutor;
+
+use crate::{node::EventRecord, ChainInfo, Node, StorageChange};
+
+type AccountIdFor<T> = <<T as ChainInfo>::Runtime as frame_system::Config>::AccountId;
+type CallFor<T> = <<T as ChainInfo>::Runtime as frame_system::Config>::Call;
+type OriginFor<T> = <<T as ChainInfo>::Runtime as frame_system::Config>::Origin;
+
+/// Outcome of a call executed by `Node::dry_run_extrinsic`.
+pub struct DryRunResult<T: ChainInfo> {
+	/// result of the dispatch.
+	pub result: DispatchResultWithPostInfo,
+	/// events deposited by the call.
+	pub events: Vec<EventRecord<T::Runtime>>,
+	/// storage changes made by the call, ordered by key.
+	pub storage_changes: Vec<StorageChange>,
+	/// actual weight consumed by the call.
+	pub weight: Weight,
+}
+
+impl<T: ChainInfo> Node<T>
+where
+	CallFor<T>: Dispatchable<Origin = OriginFor<T>, PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<TFullCallExecutor<T::Block, T::Executor> as CallExecutor<T::Block>>::Error: std::fmt::Debug,
+{
+	/// Dispatches `call` on top of the best block without importing a block,
+	/// signed by `from`, or with root privileges if `from` is `None`.
+	///
+	/// Only the call is dispatched, signed extensions like fee payment are
+	/// skipped. The state of the node is left untouched.
+	pub fn dry_run_extrinsic(&self, call: impl Into<CallFor<T>>, from: Option<AccountIdFor<T>>) -> DryRunResult<T> {
+		let call = call.into();
+		let info = call.get_dispatch_info();
+		let origin: OriginFor<T> = match from {
+			Some(who) => frame_system::RawOrigin::Signed(who).into(),
+			None => frame_system::RawOrigin::Root.into(),
+		};
+
+		let ((result, events), storage_changes) = self.with_state_changes(|| {
+			// only report the events of the call.
+			frame_system::Pallet::<T::Runtime>::reset_events();
+			let result = call.dispatch(origin);
+			(result, frame_system::Pallet::<T::Runtime>::events())
+		});
+
+		let post_info = match &result {
+			Ok(post_info) => *post_info,
+			Err(err) => err.post_info,
+		};
+
+		DryRunResult {
+			result,
+			events,
+			storage_changes,
+			weight: post_info.actual_weight.unwrap_or(info.weight),
+		}
+	}
+}
@@ -0,0 +1,195 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![allow(clippy::all)]
+
+//! Helpers to deploy and call EVM+ contracts through module-evm.
+
+use std::convert::TryInto;
+
+use module_evm::ExitReason;
+use sc_client_api::CallExecutor;
+use sc_service::TFullCallExecutor;
+use sp_core::{H160, U256};
+use sp_runtime::{
+	generic::UncheckedExtrinsic,
+	traits::{Block as BlockT, Zero},
+	MultiAddress, MultiSignature,
+};
+
+use crate::{ChainInfo, Node};
+
+/// Gas limit of the extrinsics submitted by the helpers.
+pub const DEFAULT_GAS_LIMIT: u64 = 10_000_000;
+
+/// Storage limit of the extrinsics submitted by the helpers.
+pub const DEFAULT_STORAGE_LIMIT: u32 = 100_000;
+
+type AccountIdFor<T> = <<T as ChainInfo>::Runtime as frame_system::Config>::AccountId;
+type IndexFor<T> = <<T as ChainInfo>::Runtime as frame_system::Config>::Index;
+type CallFor<T> = <<T as ChainInfo>::Runtime as frame_system::Config>::Call;
+type EventFor<T> = <<T as ChainInfo>::Runtime as frame_system::Config>::Event;
+
+/// Failure of a contract creation or call.
+#[derive(Debug, PartialEq)]
+pub enum EvmError {
+	/// The extrinsic was not included in the sealed block, or failed to
+	/// dispatch before reaching the EVM.
+	NotExecuted,
+	/// The EVM execution failed. \[exit_reason, output\]
+	Failed(ExitReason, Vec<u8>),
+}
+
+/// ABI encoded input of a contract call, with static arguments only.
+pub struct AbiCall {
+	input: Vec<u8>,
+}
+
+impl AbiCall {
+	/// A call of the function with `signature`, e.g. `transfer(address,uint256)`.
+	pub fn new(signature: &str) -> Self {
+		Self {
+			input: sp_io::hashing::keccak_256(signature.as_bytes())[..4].to_vec(),
+		}
+	}
+
+	/// Constructor arguments, appended to the contract bytecode.
+	pub fn constructor() -> Self {
+		Self { input: vec![] }
+	}
+
+	/// Append an `address` argument.
+	pub fn address(mut self, address: H160) -> Self {
+		self.input.extend_from_slice(&[0u8; 12]);
+		self.input.extend_from_slice(address.as_bytes());
+		self
+	}
+
+	/// Append an `uint256` argument.
+	pub fn uint(mut self, value: impl Into<U256>) -> Self {
+		let mut word = [0u8; 32];
+		value.into().to_big_endian(&mut word);
+		self.input.extend_from_slice(&word);
+		self
+	}
+
+	/// Append a `bool` argument.
+	pub fn bool(self, value: bool) -> Self {
+		self.uint(value as u8)
+	}
+
+	/// Append a `bytes32` argument.
+	pub fn bytes32(mut self, value: [u8; 32]) -> Self {
+		self.input.extend_from_slice(&value);
+		self
+	}
+
+	/// The encoded input.
+	pub fn encode(self) -> Vec<u8> {
+		self.input
+	}
+}
+
+/// A contract deployed by `Node::deploy_contract`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Contract {
+	address: H160,
+}
+
+impl Contract {
+	/// The EVM address of the contract.
+	pub fn address(&self) -> H160 {
+		self.address
+	}
+
+	/// Calls the contract from `from` and seals the block including the call.
+	pub fn call<T: ChainInfo>(&self, node: &mut Node<T>, from: AccountIdFor<T>, input: AbiCall) -> Result<(), EvmError>
+	where
+		T::Runtime: module_evm::Config,
+		CallFor<T>: From<module_evm::Call<T::Runtime>>,
+		EventFor<T>: TryInto<module_evm::Event<T::Runtime>>,
+		<TFullCallExecutor<T::Block, T::Executor> as CallExecutor<T::Block>>::Error: std::fmt::Debug,
+		<T::Block as BlockT>::Extrinsic: From<
+			UncheckedExtrinsic<MultiAddress<AccountIdFor<T>, IndexFor<T>>, CallFor<T>, MultiSignature, T::SignedExtras>,
+		>,
+	{
+		let call = module_evm::Call::<T::Runtime>::call(
+			self.address,
+			input.encode(),
+			Zero::zero(),
+			DEFAULT_GAS_LIMIT,
+			DEFAULT_STORAGE_LIMIT,
+		);
+		node.submit_extrinsic(call, Some(from));
+		node.seal_blocks(1);
+
+		node.events()
+			.into_iter()
+			.find_map(|record| match record.event.try_into() {
+				Ok(module_evm::Event::Executed(address)) if address == self.address => Some(Ok(())),
+				Ok(module_evm::Event::ExecutedFailed(address, exit_reason, output)) if address == self.address => {
+					Some(Err(EvmError::Failed(exit_reason, output)))
+				}
+				_ => None,
+			})
+			.unwrap_or(Err(EvmError::NotExecuted))
+	}
+}
+
+impl<T: ChainInfo> Node<T>
+where
+	T::Runtime: module_evm::Config,
+	CallFor<T>: From<module_evm::Call<T::Runtime>>,
+	EventFor<T>: TryInto<module_evm::Event<T::Runtime>>,
+	<TFullCallExecutor<T::Block, T::Executor> as CallExecutor<T::Block>>::Error: std::fmt::Debug,
+	<T::Block as BlockT>::Extrinsic: From<
+		UncheckedExtrinsic<MultiAddress<AccountIdFor<T>, IndexFor<T>>, CallFor<T>, MultiSignature, T::SignedExtras>,
+	>,
+{
+	/// Creates a contract from `deployer`, waits for its `Created` event and
+	/// marks it as deployed with root privileges, so that anyone can call it.
+	pub fn deploy_contract(
+		&mut self,
+		deployer: AccountIdFor<T>,
+		bytecode: Vec<u8>,
+		constructor_args: AbiCall,
+	) -> Result<Contract, EvmError> {
+		let mut init = bytecode;
+		init.extend(constructor_args.encode());
+
+		let call = module_evm::Call::<T::Runtime>::create(init, Zero::zero(), DEFAULT_GAS_LIMIT, DEFAULT_STORAGE_LIMIT);
+		self.submit_extrinsic(call, Some(deployer));
+		self.seal_blocks(1);
+
+		let address = self
+			.events()
+			.into_iter()
+			.find_map(|record| match record.event.try_into() {
+				Ok(module_evm::Event::Created(address)) => Some(Ok(address)),
+				Ok(module_evm::Event::CreatedFailed(_, exit_reason)) => {
+					Some(Err(EvmError::Failed(exit_reason, vec![])))
+				}
+				_ => None,
+			})
+			.unwrap_or(Err(EvmError::NotExecuted))?;
+
+		T::dispatch_with_root(module_evm::Call::<T::Runtime>::deploy_free(address).into(), self);
+
+		Ok(Contract { address })
+	}
+}
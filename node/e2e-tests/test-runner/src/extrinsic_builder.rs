@@ -0,0 +1,201 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![allow(clippy::all)]
+
+use std::collections::BTreeMap;
+
+use codec::Encode;
+use sc_client_api::CallExecutor;
+use sc_service::TFullCallExecutor;
+use sp_blockchain::HeaderBackend;
+use sp_core::{sr25519, Pair};
+use sp_keyring::sr25519::Keyring;
+use sp_runtime::{
+	generic::{Era, SignedPayload, UncheckedExtrinsic},
+	traits::{Block as BlockT, IdentifyAccount, One, UniqueSaturatedInto},
+	AccountId32, MultiAddress, MultiSignature, MultiSigner,
+};
+
+use crate::{ChainInfo, Node};
+
+type AccountIdFor<T> = <<T as ChainInfo>::Runtime as frame_system::Config>::AccountId;
+type IndexFor<T> = <<T as ChainInfo>::Runtime as frame_system::Config>::Index;
+type CallFor<T> = <<T as ChainInfo>::Runtime as frame_system::Config>::Call;
+
+/// Options of the signed extras, passed to `ChainInfo::signed_extras_with`.
+pub struct SignedExtrasOptions<Index> {
+	/// nonce of the signer.
+	pub nonce: Index,
+	/// tip paid to the block author.
+	pub tip: u128,
+	/// mortality of the extrinsic.
+	pub era: Era,
+}
+
+/// Key signing an extrinsic.
+#[derive(Clone)]
+pub enum Signer {
+	/// One of the dev keyrings.
+	Keyring(Keyring),
+	/// A secret uri, e.g. a raw seed or `//Alice`.
+	Seed(String),
+}
+
+impl Signer {
+	fn pair(&self) -> sr25519::Pair {
+		match self {
+			Signer::Keyring(keyring) => keyring.pair(),
+			Signer::Seed(seed) => sr25519::Pair::from_string(seed, None).expect("invalid secret uri"),
+		}
+	}
+}
+
+impl From<Keyring> for Signer {
+	fn from(keyring: Keyring) -> Self {
+		Signer::Keyring(keyring)
+	}
+}
+
+/// Mortality of a built extrinsic.
+#[derive(Clone, Copy)]
+pub enum Mortality {
+	Immortal,
+	/// Valid for `period` blocks from the best block at signing time.
+	Mortal {
+		period: u64,
+	},
+}
+
+/// Builds, signs and submits extrinsics, tracking the nonce of every signer.
+///
+/// The nonce of an account is read from the chain on first use and increased
+/// locally for every extrinsic signed afterwards, so several extrinsics of the
+/// same account can be sealed in one block. Call `reset_nonce` after an
+/// extrinsic was dropped from the pool.
+pub struct SignedExtrinsicBuilder<T: ChainInfo> {
+	nonces: BTreeMap<AccountIdFor<T>, IndexFor<T>>,
+	tip: u128,
+	mortality: Mortality,
+}
+
+impl<T: ChainInfo> SignedExtrinsicBuilder<T>
+where
+	AccountIdFor<T>: From<AccountId32>,
+	<TFullCallExecutor<T::Block, T::Executor> as CallExecutor<T::Block>>::Error: std::fmt::Debug,
+	<T::Block as BlockT>::Extrinsic: From<
+		UncheckedExtrinsic<MultiAddress<AccountIdFor<T>, IndexFor<T>>, CallFor<T>, MultiSignature, T::SignedExtras>,
+	>,
+{
+	/// A builder signing immortal extrinsics without tip.
+	pub fn new() -> Self {
+		Self {
+			nonces: BTreeMap::new(),
+			tip: 0,
+			mortality: Mortality::Immortal,
+		}
+	}
+
+	/// Set the tip of the extrinsics built afterwards.
+	pub fn tip(mut self, tip: u128) -> Self {
+		self.tip = tip;
+		self
+	}
+
+	/// Set the mortality of the extrinsics built afterwards.
+	pub fn mortality(mut self, mortality: Mortality) -> Self {
+		self.mortality = mortality;
+		self
+	}
+
+	/// The account of `signer`.
+	pub fn account_id(signer: &Signer) -> AccountIdFor<T> {
+		MultiSigner::from(signer.pair().public()).into_account().into()
+	}
+
+	/// The nonce that the next extrinsic of `who` will be signed with.
+	pub fn next_nonce(&mut self, node: &Node<T>, who: &AccountIdFor<T>) -> IndexFor<T> {
+		*self
+			.nonces
+			.entry(who.clone())
+			.or_insert_with(|| node.with_state(|| frame_system::Pallet::<T::Runtime>::account_nonce(who)))
+	}
+
+	/// Forget the nonce of `who`, it is read from the chain again on next use.
+	pub fn reset_nonce(&mut self, who: &AccountIdFor<T>) {
+		self.nonces.remove(who);
+	}
+
+	/// Signs `call` with the next nonce of `signer`.
+	pub fn sign(
+		&mut self,
+		node: &Node<T>,
+		call: impl Into<CallFor<T>>,
+		signer: impl Into<Signer>,
+	) -> UncheckedExtrinsic<MultiAddress<AccountIdFor<T>, IndexFor<T>>, CallFor<T>, MultiSignature, T::SignedExtras> {
+		let signer = signer.into();
+		let pair = signer.pair();
+		let who = Self::account_id(&signer);
+		let nonce = self.next_nonce(node, &who);
+
+		let era = match self.mortality {
+			Mortality::Immortal => Era::Immortal,
+			Mortality::Mortal { period } => {
+				Era::mortal(period, node.client().info().best_number.unique_saturated_into())
+			}
+		};
+		let options = SignedExtrasOptions {
+			nonce,
+			tip: self.tip,
+			era,
+		};
+
+		// additional signed data, like the genesis hash, is read from state.
+		let (call, extra, signature) = node.with_state(|| {
+			let extra = T::signed_extras_with(who.clone(), options);
+			let raw_payload = SignedPayload::new(call.into(), extra).expect("signed extras are valid");
+			let signature = raw_payload.using_encoded(|payload| pair.sign(payload));
+			let (call, extra, _) = raw_payload.deconstruct();
+			(call, extra, signature)
+		});
+
+		self.nonces.insert(who.clone(), nonce + One::one());
+
+		UncheckedExtrinsic::new_signed(call, who.into(), MultiSignature::Sr25519(signature), extra)
+	}
+
+	/// Signs `call` with the next nonce of `signer` and submits it to the
+	/// transaction pool of `node`.
+	pub fn submit(
+		&mut self,
+		node: &mut Node<T>,
+		call: impl Into<CallFor<T>>,
+		signer: impl Into<Signer>,
+	) -> Result<<T::Block as BlockT>::Hash, sc_transaction_pool::error::Error> {
+		let signer = signer.into();
+		let who = Self::account_id(&signer);
+		let extrinsic = self.sign(node, call, signer);
+
+		let result = node.submit_raw_extrinsic(extrinsic.into());
+		if result.is_err() {
+			// the nonce was not used.
+			self.reset_nonce(&who);
+		}
+		result
+	}
+}
@@ -21,7 +21,9 @@
 use manual_seal::consensus::ConsensusDataProvider;
 use sc_consensus::BlockImport;
 use sc_executor::NativeExecutionDispatch;
+use sc_rpc::DenyUnsafe;
 use sc_service::{Configuration, TFullBackend, TFullClient, TaskExecutor, TaskManager};
+use sc_transaction_pool::FullPool;
 use sp_api::{ConstructRuntimeApi, TransactionFor};
 use sp_consensus::SelectChain;
 use sp_inherents::{CreateInherentDataProviders, InherentDataProvider};
@@ -29,12 +31,26 @@ use sp_keystore::SyncCryptoStorePtr;
 use sp_runtime::traits::{Block as BlockT, SignedExtension};
 use std::sync::Arc;
 
+mod dry_run;
+mod evm;
+mod extrinsic_builder;
 mod host_functions;
+mod log_capture;
+mod network;
 mod node;
+mod simulated_relay;
+mod time_travel;
 mod utils;
 
+pub use dry_run::*;
+pub use evm::*;
+pub use extrinsic_builder::*;
 pub use host_functions::*;
+pub use log_capture::*;
+pub use network::*;
 pub use node::*;
+pub use simulated_relay::*;
+pub use time_travel::*;
 pub use utils::*;
 
 /// Wrapper trait for concrete type required by this testing framework.
@@ -76,6 +92,13 @@ pub trait ChainInfo: Sized {
 	/// Signed extras, this function is caled in an externalities provided environment.
 	fn signed_extras(from: <Self::Runtime as frame_system::Config>::AccountId) -> Self::SignedExtras;
 
+	/// Signed extras with an explicit nonce, tip and mortality, used by `SignedExtrinsicBuilder`.
+	/// This function is called in an externalities provided environment.
+	fn signed_extras_with(
+		from: <Self::Runtime as frame_system::Config>::AccountId,
+		options: SignedExtrasOptions<<Self::Runtime as frame_system::Config>::Index>,
+	) -> Self::SignedExtras;
+
 	/// config factory
 	fn config(task_executor: TaskExecutor) -> Configuration;
 
@@ -107,6 +130,15 @@ pub trait ChainInfo: Sized {
 		sc_service::Error,
 	>;
 
+	/// RPC extensions served by the node in addition to the substrate rpc, none by default.
+	fn rpc_extensions(
+		_client: Arc<TFullClient<Self::Block, Self::RuntimeApi, Self::Executor>>,
+		_pool: Arc<FullPool<Self::Block, TFullClient<Self::Block, Self::RuntimeApi, Self::Executor>>>,
+		_deny_unsafe: DenyUnsafe,
+	) -> jsonrpc_core::IoHandler<sc_rpc::Metadata> {
+		Default::default()
+	}
+
 	/// Given a call and a handle to the node, execute the call with root privileges.
 	fn dispatch_with_root(call: <Self::Runtime as frame_system::Config>::Call, node: &mut Node<Self>);
 }
@@ -0,0 +1,116 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![allow(clippy::all)]
+
+use std::{
+	fmt,
+	sync::{Arc, Condvar, Mutex},
+	time::{Duration, Instant},
+};
+
+use log::Level;
+
+/// A log line emitted by the node or the runtime.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogRecord {
+	pub level: Level,
+	pub target: String,
+	pub message: String,
+}
+
+impl fmt::Display for LogRecord {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} {} {}", self.level, self.target, self.message)
+	}
+}
+
+/// Queryable sink of the log lines captured by `logger`.
+#[derive(Clone, Default)]
+pub struct LogCapture {
+	inner: Arc<(Mutex<Vec<LogRecord>>, Condvar)>,
+}
+
+impl LogCapture {
+	pub(crate) fn push(&self, record: LogRecord) {
+		let (records, appended) = &*self.inner;
+		records.lock().unwrap().push(record);
+		appended.notify_all();
+	}
+
+	/// All captured records, oldest first.
+	pub fn records(&self) -> Vec<LogRecord> {
+		self.inner.0.lock().unwrap().clone()
+	}
+
+	/// Records whose target starts with `target` and whose level is at
+	/// least as severe as `level`.
+	pub fn filter(&self, target: Option<&str>, level: Option<Level>) -> Vec<LogRecord> {
+		self.inner
+			.0
+			.lock()
+			.unwrap()
+			.iter()
+			.filter(|record| target.map_or(true, |target| record.target.starts_with(target)))
+			.filter(|record| level.map_or(true, |level| record.level <= level))
+			.cloned()
+			.collect()
+	}
+
+	/// Whether any record, formatted as `<level> <target> <message>`, contains `pattern`.
+	pub fn contains(&self, pattern: &str) -> bool {
+		self.inner
+			.0
+			.lock()
+			.unwrap()
+			.iter()
+			.any(|record| record.to_string().contains(pattern))
+	}
+
+	/// Waits for a record containing `pattern`, see `contains`. Records
+	/// captured before the call are searched too.
+	///
+	/// Returns `None` if `timeout` elapsed first.
+	pub fn wait_for(&self, pattern: &str, timeout: Duration) -> Option<LogRecord> {
+		let deadline = Instant::now() + timeout;
+		let (records, appended) = &*self.inner;
+		let mut records = records.lock().unwrap();
+		let mut searched = 0;
+
+		loop {
+			if let Some(record) = records[searched..]
+				.iter()
+				.find(|record| record.to_string().contains(pattern))
+			{
+				return Some(record.clone());
+			}
+			searched = records.len();
+
+			let now = Instant::now();
+			if now >= deadline {
+				return None;
+			}
+			records = appended.wait_timeout(records, deadline - now).unwrap().0;
+		}
+	}
+
+	/// Discards all captured records.
+	pub fn clear(&self) {
+		self.inner.0.lock().unwrap().clear();
+	}
+}
@@ -0,0 +1,353 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![allow(clippy::all)]
+
+use std::{
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+	time::{Duration, Instant},
+};
+
+use log::LevelFilter;
+use sc_client_api::backend::Backend;
+use sc_network::{
+	config::{MultiaddrWithPeerId, Role},
+	multiaddr, Multiaddr,
+};
+use sc_service::{Configuration, DatabaseConfig, KeystoreConfig, TFullBackend, TFullClient};
+use sp_api::{ApiExt, ConstructRuntimeApi, Core, Metadata};
+use sp_block_builder::BlockBuilder;
+use sp_blockchain::HeaderBackend;
+use sp_keyring::sr25519::Keyring;
+use sp_offchain::OffchainWorkerApi;
+use sp_runtime::traits::NumberFor;
+use sp_session::SessionKeys;
+use sp_transaction_pool::runtime_api::TaggedTransactionQueue;
+
+use crate::{ChainInfo, Node, NodeConfig};
+
+/// Memory transport ports are global to the process, hand out a fresh one
+/// for every node so that networks built by concurrently running tests
+/// never collide.
+static NEXT_MEMORY_PORT: AtomicU64 = AtomicU64::new(1);
+
+/// Dev keyrings used as authority keys, in order.
+const AUTHORITY_KEYRINGS: [Keyring; 6] = [
+	Keyring::Alice,
+	Keyring::Bob,
+	Keyring::Charlie,
+	Keyring::Dave,
+	Keyring::Eve,
+	Keyring::Ferdie,
+];
+
+/// Builder for a set of nodes connected to each other through memory
+/// transports.
+pub struct NetworkBuilder<T: ChainInfo> {
+	/// number of authority nodes.
+	authorities: usize,
+	/// number of full (non-authoring) nodes.
+	full_nodes: usize,
+	/// log targets passed to every node.
+	log_targets: Vec<(&'static str, LevelFilter)>,
+	_phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: ChainInfo> NetworkBuilder<T> {
+	/// A network with a single authority and no full nodes.
+	pub fn new() -> Self {
+		Self {
+			authorities: 1,
+			full_nodes: 0,
+			log_targets: vec![],
+			_phantom: Default::default(),
+		}
+	}
+
+	/// Set the number of authority nodes, at most one per dev keyring.
+	pub fn with_authorities(mut self, authorities: usize) -> Self {
+		assert!(
+			authorities <= AUTHORITY_KEYRINGS.len(),
+			"at most {} authorities are supported",
+			AUTHORITY_KEYRINGS.len()
+		);
+		self.authorities = authorities;
+		self
+	}
+
+	/// Set the number of full nodes.
+	pub fn with_full_nodes(mut self, full_nodes: usize) -> Self {
+		self.full_nodes = full_nodes;
+		self
+	}
+
+	/// Set the log targets of the nodes.
+	pub fn with_log_targets(mut self, log_targets: Vec<(&'static str, LevelFilter)>) -> Self {
+		self.log_targets = log_targets;
+		self
+	}
+
+	/// Start all nodes. The first node is used as the boot node of all
+	/// others, authorities are started before full nodes.
+	pub fn build(self) -> Result<Network<T>, sc_service::Error>
+	where
+		<T::RuntimeApi as ConstructRuntimeApi<T::Block, TFullClient<T::Block, T::RuntimeApi, T::Executor>>>::RuntimeApi:
+			Core<T::Block>
+				+ Metadata<T::Block>
+				+ OffchainWorkerApi<T::Block>
+				+ SessionKeys<T::Block>
+				+ TaggedTransactionQueue<T::Block>
+				+ BlockBuilder<T::Block>
+				+ ApiExt<T::Block, StateBackend = <TFullBackend<T::Block> as Backend<T::Block>>::State>,
+	{
+		assert!(
+			self.authorities + self.full_nodes > 0,
+			"network needs at least one node"
+		);
+
+		let mut nodes = Vec::with_capacity(self.authorities + self.full_nodes);
+		let mut boot_node: Option<MultiaddrWithPeerId> = None;
+		let mut addresses = Vec::with_capacity(self.authorities + self.full_nodes);
+		let mut block_announces_protocol = String::new();
+
+		for index in 0..self.authorities + self.full_nodes {
+			let role = if index < self.authorities {
+				Role::Authority
+			} else {
+				Role::Full
+			};
+			let listen_address: Multiaddr =
+				multiaddr::Protocol::Memory(NEXT_MEMORY_PORT.fetch_add(1, Ordering::Relaxed)).into();
+			let boot_nodes = boot_node.clone().into_iter().collect::<Vec<_>>();
+
+			let node_config = NodeConfig {
+				log_targets: self.log_targets.clone(),
+				relay: None,
+			};
+			let address = listen_address.clone();
+			let protocol = &mut block_announces_protocol;
+			let node = Node::<T>::with_config(node_config, move |task_executor| {
+				let mut config = T::config(task_executor);
+				*protocol = format!("/{}/block-announces/1", config.protocol_id().as_ref());
+				configure_network_node(&mut config, index, role, address, boot_nodes);
+				config
+			})?;
+
+			let address = MultiaddrWithPeerId {
+				multiaddr: listen_address,
+				peer_id: node.network().local_peer_id().clone(),
+			};
+			if boot_node.is_none() {
+				boot_node = Some(address.clone());
+			}
+
+			addresses.push(address);
+			nodes.push(node);
+		}
+
+		Ok(Network {
+			authorities: self.authorities,
+			nodes,
+			addresses,
+			block_announces_protocol,
+			partition_expiry: None,
+		})
+	}
+}
+
+/// Overrides the parts of a single-node configuration which must be unique
+/// per node of a network.
+fn configure_network_node(
+	config: &mut Configuration,
+	index: usize,
+	role: Role,
+	listen_address: Multiaddr,
+	boot_nodes: Vec<MultiaddrWithPeerId>,
+) {
+	let node_dir = format!("node-{}", index);
+
+	config.dev_key_seed = match role {
+		Role::Authority => Some(AUTHORITY_KEYRINGS[index].to_seed()),
+		_ => None,
+	};
+	config.role = role;
+	config.network.node_name = format!("Test Node {}", index);
+	config.network.listen_addresses = vec![listen_address];
+	config.network.boot_nodes = boot_nodes;
+
+	if let KeystoreConfig::Path { path, .. } = &mut config.keystore {
+		*path = path.join(&node_dir);
+	}
+	match &mut config.database {
+		DatabaseConfig::RocksDb { path, .. } | DatabaseConfig::ParityDb { path } => *path = path.join(&node_dir),
+		_ => {}
+	}
+}
+
+/// A set of running nodes connected to each other.
+pub struct Network<T: ChainInfo> {
+	/// number of authorities, these are the first nodes.
+	authorities: usize,
+	/// all nodes, authorities first.
+	nodes: Vec<Node<T>>,
+	/// listen addresses of the nodes.
+	addresses: Vec<MultiaddrWithPeerId>,
+	/// name of the block announce notifications protocol.
+	block_announces_protocol: String,
+	/// blocks left to seal with `seal_blocks` before the partition heals.
+	partition_expiry: Option<usize>,
+}
+
+impl<T: ChainInfo> Network<T> {
+	/// All nodes, authorities first.
+	pub fn nodes(&mut self) -> &mut [Node<T>] {
+		&mut self.nodes
+	}
+
+	/// The node at `index`.
+	pub fn node(&mut self, index: usize) -> &mut Node<T> {
+		&mut self.nodes[index]
+	}
+
+	/// The authority nodes.
+	pub fn authorities(&mut self) -> &mut [Node<T>] {
+		&mut self.nodes[..self.authorities]
+	}
+
+	/// The full nodes.
+	pub fn full_nodes(&mut self) -> &mut [Node<T>] {
+		&mut self.nodes[self.authorities..]
+	}
+
+	/// Clients of all nodes, authorities first.
+	pub fn clients(&self) -> Vec<Arc<TFullClient<T::Block, T::RuntimeApi, T::Executor>>> {
+		self.nodes.iter().map(|node| node.client()).collect()
+	}
+
+	/// Waits until every node has imported at least block `number`,
+	/// returns false if `timeout` elapsed first.
+	pub fn wait_for_best_block(&self, number: NumberFor<T::Block>, timeout: Duration) -> bool {
+		let deadline = Instant::now() + timeout;
+
+		loop {
+			if self.nodes.iter().all(|node| node.client().info().best_number >= number) {
+				return true;
+			}
+			if Instant::now() >= deadline {
+				return false;
+			}
+			std::thread::sleep(Duration::from_millis(100));
+		}
+	}
+
+	/// Seals `num` blocks on the node at `index`, one at a time, healing a
+	/// partition created by `partition_for_blocks` once it expires.
+	pub fn seal_blocks(&mut self, index: usize, num: usize) {
+		for _ in 0..num {
+			self.nodes[index].seal_blocks(1);
+
+			if let Some(blocks) = self.partition_expiry.as_mut() {
+				*blocks = blocks.saturating_sub(1);
+				if *blocks == 0 {
+					self.heal();
+				}
+			}
+		}
+	}
+
+	/// Disconnects the node at `index` from the node at `peer`, on the block
+	/// announce protocol. The nodes may connect again later.
+	pub fn drop_peer(&self, index: usize, peer: usize) {
+		self.nodes[index].network().disconnect_peer(
+			self.addresses[peer].peer_id.clone(),
+			self.block_announces_protocol.clone(),
+		);
+	}
+
+	/// Splits the network, every node only stays connected to the nodes of
+	/// its group. Nodes not in any group are isolated.
+	pub fn partition(&mut self, groups: &[&[usize]]) {
+		for index in 0..self.nodes.len() {
+			let network = self.nodes[index].network();
+			let group = groups.iter().find(|group| group.contains(&index));
+
+			for (peer, address) in self.addresses.iter().enumerate() {
+				if peer == index {
+					continue;
+				}
+				if group.map_or(false, |group| group.contains(&peer)) {
+					let _ = network.add_reserved_peer(address.to_string());
+				} else {
+					network.remove_reserved_peer(address.peer_id.clone());
+				}
+			}
+			network.deny_unreserved_peers();
+		}
+		self.partition_expiry = None;
+	}
+
+	/// Splits the network like `partition` for the next `blocks` blocks
+	/// sealed through `Network::seal_blocks`.
+	pub fn partition_for_blocks(&mut self, groups: &[&[usize]], blocks: usize) {
+		self.partition(groups);
+		self.partition_expiry = Some(blocks);
+	}
+
+	/// Isolates the node at `index` for `delay`, messages from and to it are
+	/// only exchanged after reconnecting.
+	pub fn delay_messages(&self, index: usize, delay: Duration) {
+		let network = self.nodes[index].network();
+		let peers = self
+			.addresses
+			.iter()
+			.enumerate()
+			.filter(|(peer, _)| *peer != index)
+			.map(|(_, address)| address.clone())
+			.collect::<Vec<_>>();
+
+		for address in peers.iter() {
+			network.remove_reserved_peer(address.peer_id.clone());
+		}
+		network.deny_unreserved_peers();
+
+		std::thread::spawn(move || {
+			std::thread::sleep(delay);
+			network.accept_unreserved_peers();
+			for address in peers {
+				let _ = network.add_reserved_peer(address.to_string());
+			}
+		});
+	}
+
+	/// Reconnects all nodes after a partition.
+	pub fn heal(&mut self) {
+		for index in 0..self.nodes.len() {
+			let network = self.nodes[index].network();
+			network.accept_unreserved_peers();
+			for (peer, address) in self.addresses.iter().enumerate() {
+				if peer != index {
+					let _ = network.add_reserved_peer(address.to_string());
+				}
+			}
+		}
+		self.partition_expiry = None;
+	}
+}
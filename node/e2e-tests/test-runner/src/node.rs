@@ -18,7 +18,7 @@
 
 #![allow(clippy::all)]
 
-use std::sync::Arc;
+use std::{convert::TryInto, net::SocketAddr, sync::Arc, time::Duration};
 
 use futures::{
 	channel::{mpsc, oneshot},
@@ -31,16 +31,18 @@ use sc_client_api::{
 	backend::{self, Backend},
 	CallExecutor, ExecutorProvider,
 };
+use sc_network::NetworkService;
 use sc_service::{
-	build_network, spawn_tasks, BuildNetworkParams, SpawnTasksParams, TFullBackend, TFullCallExecutor, TFullClient,
-	TaskManager, TaskType,
+	build_network, spawn_tasks, BuildNetworkParams, Configuration, SpawnTasksParams, TFullBackend, TFullCallExecutor,
+	TFullClient, TaskExecutor, TaskManager, TaskType,
 };
 use sc_transaction_pool::BasicPool;
 use sc_transaction_pool_api::TransactionPool;
-use sp_api::{ApiExt, ConstructRuntimeApi, Core, Metadata, OverlayedChanges, StorageTransactionCache};
+use sp_api::{ApiExt, ConstructRuntimeApi, Core, Metadata, OverlayedChanges, RuntimeVersion, StorageTransactionCache};
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::HeaderBackend;
-use sp_core::ExecutionContext;
+use sp_core::{storage::well_known_keys, ExecutionContext};
+use sp_inherents::CreateInherentDataProviders;
 use sp_offchain::OffchainWorkerApi;
 use sp_runtime::traits::{Block as BlockT, Extrinsic};
 use sp_runtime::{generic::BlockId, transaction_validity::TransactionSource, MultiAddress, MultiSignature};
@@ -49,7 +51,7 @@ use sp_session::SessionKeys;
 use sp_state_machine::Ext;
 use sp_transaction_pool::runtime_api::TaggedTransactionQueue;
 
-use crate::{utils::logger, ChainInfo};
+use crate::{utils::logger, ChainInfo, LogCapture, OptionalInherentDataProvider, SimulatedRelay, TimeTravel};
 use log::LevelFilter;
 
 /// This holds a reference to a running node on another thread,
@@ -58,8 +60,8 @@ use log::LevelFilter;
 pub struct Node<T: ChainInfo> {
 	/// rpc handler for communicating with the node over rpc.
 	rpc_handler: Arc<MetaIoHandler<sc_rpc::Metadata, sc_rpc_server::RpcMiddleware>>,
-	/// Stream of log lines
-	log_stream: mpsc::UnboundedReceiver<String>,
+	/// Captured log lines
+	log_capture: LogCapture,
 	/// node tokio runtime
 	_runtime: tokio::runtime::Runtime,
 	/// handle to the running node.
@@ -82,17 +84,40 @@ pub struct Node<T: ChainInfo> {
 	manual_seal_command_sink: mpsc::Sender<EngineCommand<<T::Block as BlockT>::Hash>>,
 	/// backend type.
 	backend: Arc<TFullBackend<T::Block>>,
+	/// network service.
+	network: Arc<NetworkService<T::Block, <T::Block as BlockT>::Hash>>,
 	/// Block number at initialization of this Node.
 	initial_block_number: NumberFor<T::Block>,
+	/// clock used for the timestamps of sealed blocks.
+	time_travel: TimeTravel,
+	/// address of the websocket rpc server, if enabled.
+	rpc_ws_address: Option<SocketAddr>,
+	/// address of the http rpc server, if enabled.
+	rpc_http_address: Option<SocketAddr>,
+	/// best blocks recorded by `snapshot`, indexed by `SnapshotId`.
+	snapshots: Vec<(NumberFor<T::Block>, <T::Block as BlockT>::Hash)>,
 }
 
+/// How long `assert_log_line` waits for a matching log line.
+const ASSERT_LOG_LINE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A storage key and its new value, `None` if removed.
+pub type StorageChange = (Vec<u8>, Option<Vec<u8>>);
+
+/// Identifier of a chain state recorded by `Node::snapshot`.
+pub type SnapshotId = usize;
+
 /// Configuration options for the node.
 pub struct NodeConfig {
 	/// A set of log targets you'd like to enable/disbale
 	pub log_targets: Vec<(&'static str, LevelFilter)>,
+	/// Simulated relay chain feeding the parachain inherent of every sealed block,
+	/// the chain's own inherent data providers are used if `None`.
+	pub relay: Option<SimulatedRelay>,
 }
 
-type EventRecord<T> = frame_system::EventRecord<<T as frame_system::Config>::Event, <T as frame_system::Config>::Hash>;
+pub(crate) type EventRecord<T> =
+	frame_system::EventRecord<<T as frame_system::Config>::Event, <T as frame_system::Config>::Hash>;
 
 impl<T: ChainInfo> Node<T> {
 	/// Starts a node with the manual-seal authorship.
@@ -107,7 +132,26 @@ impl<T: ChainInfo> Node<T> {
 				+ BlockBuilder<T::Block>
 				+ ApiExt<T::Block, StateBackend = <TFullBackend<T::Block> as Backend<T::Block>>::State>,
 	{
-		let NodeConfig { log_targets } = node_config;
+		Self::with_config(node_config, T::config)
+	}
+
+	/// Starts a node with the manual-seal authorship, using the configuration
+	/// produced by `config_builder` instead of `ChainInfo::config`.
+	pub fn with_config(
+		node_config: NodeConfig,
+		config_builder: impl FnOnce(TaskExecutor) -> Configuration,
+	) -> Result<Self, sc_service::Error>
+	where
+		<T::RuntimeApi as ConstructRuntimeApi<T::Block, TFullClient<T::Block, T::RuntimeApi, T::Executor>>>::RuntimeApi:
+			Core<T::Block>
+				+ Metadata<T::Block>
+				+ OffchainWorkerApi<T::Block>
+				+ SessionKeys<T::Block>
+				+ TaggedTransactionQueue<T::Block>
+				+ BlockBuilder<T::Block>
+				+ ApiExt<T::Block, StateBackend = <TFullBackend<T::Block> as Backend<T::Block>>::State>,
+	{
+		let NodeConfig { log_targets, relay } = node_config;
 		let tokio_runtime = build_runtime().unwrap();
 		let runtime_handle = tokio_runtime.handle().clone();
 		let task_executor = move |fut, task_type| match task_type {
@@ -116,11 +160,8 @@ impl<T: ChainInfo> Node<T> {
 				.spawn_blocking(move || futures::executor::block_on(fut))
 				.map(drop),
 		};
-		// unbounded logs, should be fine, test is shortlived.
-		let (log_sink, log_stream) = mpsc::unbounded();
-
-		logger(log_targets, tokio_runtime.handle().clone(), log_sink);
-		let config = T::config(task_executor.into());
+		let log_capture = logger(log_targets);
+		let config = config_builder(task_executor.into());
 
 		let (
 			client,
@@ -133,6 +174,25 @@ impl<T: ChainInfo> Node<T> {
 			block_import,
 		) = T::create_client_parts(&config)?;
 
+		// the simulated relay, if any, overrides the parachain inherent of the chain,
+		// and time travel overrides the timestamp inherent.
+		let time_travel = TimeTravel::new();
+		let clock = time_travel.clone();
+		let create_inherent_data_providers: Arc<
+			dyn CreateInherentDataProviders<T::Block, (), InherentDataProviders = T::InherentDataProviders>,
+		> = create_inherent_data_providers.into();
+		let create_inherent_data_providers = move |parent: <T::Block as BlockT>::Hash, ()| {
+			let create_inherent_data_providers = create_inherent_data_providers.clone();
+			let relay = OptionalInherentDataProvider(relay.as_ref().map(|relay| relay.next_inherent_data_provider()));
+			let timestamp = clock.next_inherent_data_provider();
+			async move {
+				let providers = create_inherent_data_providers
+					.create_inherent_data_providers(parent, ())
+					.await?;
+				Ok::<_, Box<dyn std::error::Error + Send + Sync>>((providers, relay, timestamp))
+			}
+		};
+
 		let import_queue = manual_seal::import_queue(
 			Box::new(block_import.clone()),
 			&task_manager.spawn_essential_handle(),
@@ -175,6 +235,14 @@ impl<T: ChainInfo> Node<T> {
 		// Channel for the rpc handler to communicate with the authorship task.
 		let (command_sink, commands_stream) = mpsc::channel(10);
 
+		let (rpc_ws_address, rpc_http_address) = (config.rpc_ws, config.rpc_http);
+		let rpc_extensions_builder = {
+			let client = client.clone();
+			let pool = transaction_pool.clone();
+
+			Box::new(move |deny_unsafe, _| Ok(T::rpc_extensions(client.clone(), pool.clone(), deny_unsafe)))
+		};
+
 		let rpc_handlers = {
 			let params = SpawnTasksParams {
 				config,
@@ -184,9 +252,9 @@ impl<T: ChainInfo> Node<T> {
 				keystore,
 				on_demand: None,
 				transaction_pool: transaction_pool.clone(),
-				rpc_extensions_builder: Box::new(move |_, _| Ok(jsonrpc_core::IoHandler::default())),
+				rpc_extensions_builder,
 				remote_blockchain: None,
-				network,
+				network: network.clone(),
 				system_rpc_tx,
 				telemetry: None,
 			};
@@ -221,9 +289,14 @@ impl<T: ChainInfo> Node<T> {
 			client,
 			pool: transaction_pool,
 			backend,
-			log_stream,
+			network,
+			log_capture,
 			manual_seal_command_sink: command_sink,
 			initial_block_number: initial_number,
+			time_travel,
+			rpc_ws_address,
+			rpc_http_address,
+			snapshots: vec![],
 		})
 	}
 
@@ -237,6 +310,21 @@ impl<T: ChainInfo> Node<T> {
 		self.client.clone()
 	}
 
+	/// Address of the websocket rpc server, if enabled in the configuration.
+	pub fn rpc_ws_address(&self) -> Option<SocketAddr> {
+		self.rpc_ws_address
+	}
+
+	/// Address of the http rpc server, if enabled in the configuration.
+	pub fn rpc_http_address(&self) -> Option<SocketAddr> {
+		self.rpc_http_address
+	}
+
+	/// Return a reference to the network service
+	pub fn network(&self) -> Arc<NetworkService<T::Block, <T::Block as BlockT>::Hash>> {
+		self.network.clone()
+	}
+
 	pub fn pool(
 		&self,
 	) -> Arc<
@@ -255,6 +343,15 @@ impl<T: ChainInfo> Node<T> {
 
 	/// Executes closure in an externalities provided environment.
 	pub fn with_state<R>(&self, closure: impl FnOnce() -> R) -> R
+	where
+		<TFullCallExecutor<T::Block, T::Executor> as CallExecutor<T::Block>>::Error: std::fmt::Debug,
+	{
+		self.with_state_changes(closure).0
+	}
+
+	/// Executes closure in an externalities provided environment, returns the
+	/// storage changes made by the closure, which are discarded afterwards.
+	pub fn with_state_changes<R>(&self, closure: impl FnOnce() -> R) -> (R, Vec<StorageChange>)
 	where
 		<TFullCallExecutor<T::Block, T::Executor> as CallExecutor<T::Block>>::Error: std::fmt::Debug,
 	{
@@ -272,14 +369,23 @@ impl<T: ChainInfo> Node<T> {
 			.state_at(id.clone())
 			.expect(&format!("State at block {} not found", id));
 
-		let mut ext = Ext::new(
-			&mut overlay,
-			&mut cache,
-			&state_backend,
-			changes_trie.clone(),
-			Some(&mut extensions),
-		);
-		sp_externalities::set_and_run_with_externalities(&mut ext, closure)
+		let result = {
+			let mut ext = Ext::new(
+				&mut overlay,
+				&mut cache,
+				&state_backend,
+				changes_trie.clone(),
+				Some(&mut extensions),
+			);
+			sp_externalities::set_and_run_with_externalities(&mut ext, closure)
+		};
+
+		let changes = overlay
+			.changes()
+			.map(|(key, value)| (key.clone(), value.value().cloned()))
+			.collect();
+
+		(result, changes)
 	}
 
 	/// submit some extrinsic to the node, providing the sending account.
@@ -314,14 +420,18 @@ impl<T: ChainInfo> Node<T> {
 			T::SignedExtras,
 		>::new(call.into(), signed_data)
 		.expect("UncheckedExtrinsic::new() always returns Some");
+		self.submit_raw_extrinsic(ext.into()).unwrap()
+	}
+
+	/// submit an already built extrinsic to the transaction pool of the node.
+	pub fn submit_raw_extrinsic(
+		&mut self,
+		ext: <T::Block as BlockT>::Extrinsic,
+	) -> Result<<T::Block as BlockT>::Hash, sc_transaction_pool::error::Error> {
 		let at = self.client.info().best_hash;
 
 		self._runtime
-			.block_on(
-				self.pool
-					.submit_one(&BlockId::Hash(at), TransactionSource::Local, ext.into()),
-			)
-			.unwrap()
+			.block_on(self.pool.submit_one(&BlockId::Hash(at), TransactionSource::Local, ext))
 	}
 
 	/// Get the events of the most recently produced block
@@ -329,19 +439,16 @@ impl<T: ChainInfo> Node<T> {
 		self.with_state(|| frame_system::Pallet::<T::Runtime>::events())
 	}
 
-	/// Checks the node logs for a specific entry.
-	pub fn assert_log_line(&mut self, content: &str) {
-		futures::executor::block_on(async {
-			use futures::StreamExt;
-
-			while let Some(log_line) = self.log_stream.next().await {
-				if log_line.contains(content) {
-					return;
-				}
-			}
+	/// Returns the captured log lines.
+	pub fn log_capture(&self) -> LogCapture {
+		self.log_capture.clone()
+	}
 
+	/// Checks the node logs for a specific entry.
+	pub fn assert_log_line(&self, content: &str) {
+		if self.log_capture.wait_for(content, ASSERT_LOG_LINE_TIMEOUT).is_none() {
 			panic!("Could not find {} in logs content", content);
-		});
+		}
 	}
 
 	/// Instructs manual seal to seal new, possibly empty blocks.
@@ -369,11 +476,75 @@ impl<T: ChainInfo> Node<T> {
 		}
 	}
 
+	/// Instructs manual seal to seal the next block at `timestamp`, in milliseconds.
+	///
+	/// Blocks sealed afterwards keep advancing from `timestamp` by the block time,
+	/// `timestamp` must be later than the timestamp of the best block.
+	pub fn jump_to_timestamp(&mut self, timestamp: u64) {
+		self.time_travel.jump_to(timestamp);
+	}
+
+	/// Moves the clock of the next sealed block forward by `duration` milliseconds.
+	pub fn advance_time(&mut self, duration: u64) {
+		let next = self.time_travel.next_timestamp();
+		self.time_travel.jump_to(next + duration);
+	}
+
+	/// Set the time between two blocks sealed after a time jump, in milliseconds.
+	pub fn set_block_time(&mut self, block_time: u64) {
+		self.time_travel.set_block_time(block_time);
+	}
+
+	/// Seals `num` blocks, starting at `timestamp`.
+	pub fn seal_blocks_at(&mut self, timestamp: u64, num: usize) {
+		self.jump_to_timestamp(timestamp);
+		self.seal_blocks(num);
+	}
+
 	/// Revert count number of blocks from the chain.
 	pub fn revert_blocks(&self, count: NumberFor<T::Block>) {
 		self.backend.revert(count, true).expect("Failed to revert blocks: ");
 	}
 
+	/// Records the current best block, so the state can be restored after
+	/// running a test case on top of an expensive setup.
+	///
+	/// Snapshots are restored by reverting blocks rather than from RocksDB
+	/// checkpoints, as the client backend doesn't expose its database to
+	/// checkpoint. So a snapshot can only be restored while its block is an
+	/// ancestor of the best block with its state kept, it can't bring back a
+	/// fork point the chain has moved away from or state pruned after
+	/// finality.
+	pub fn snapshot(&mut self) -> SnapshotId {
+		let info = self.client.info();
+		self.snapshots.push((info.best_number, info.best_hash));
+		self.snapshots.len() - 1
+	}
+
+	/// Restores the chain state recorded by `snapshot`, reverting all blocks
+	/// sealed since. Snapshots taken after `snapshot_id` are discarded.
+	pub fn restore(&mut self, snapshot_id: SnapshotId) {
+		let (number, hash) = *self
+			.snapshots
+			.get(snapshot_id)
+			.unwrap_or_else(|| panic!("Snapshot {} not found", snapshot_id));
+		let best_number = self.client.info().best_number;
+		assert!(
+			best_number >= number,
+			"Snapshot {} is ahead of the best block, it has already been reverted",
+			snapshot_id
+		);
+
+		self.revert_blocks(best_number - number);
+		assert_eq!(
+			self.client.info().best_hash,
+			hash,
+			"Snapshot {} is not on the best chain",
+			snapshot_id
+		);
+		self.snapshots.truncate(snapshot_id + 1);
+	}
+
 	/// Revert all blocks added since creation of the node.
 	pub fn clean(&self) {
 		// if a db path was specified, revert all blocks we've added
@@ -391,6 +562,56 @@ impl<T: ChainInfo> Node<T> {
 		let call = frame_system::Call::set_code(wasm);
 		T::dispatch_with_root(call.into(), self);
 	}
+
+	/// Version of the runtime at the best block.
+	pub fn runtime_version(&self) -> RuntimeVersion {
+		let id = BlockId::Hash(self.client.info().best_hash);
+		self.client
+			.runtime_version_at(&id)
+			.unwrap_or_else(|e| panic!("Runtime version at block {} not found: {:?}", id, e))
+	}
+
+	/// Performs a runtime upgrade given a wasm blob, checks that it was
+	/// applied and seals a block with the new runtime.
+	///
+	/// Returns the version of the new runtime.
+	pub fn perform_runtime_upgrade(&mut self, wasm: Vec<u8>) -> Result<RuntimeVersion, String>
+	where
+		<T::Runtime as frame_system::Config>::Call: From<frame_system::Call<T::Runtime>>,
+		<T::Runtime as frame_system::Config>::Event: TryInto<frame_system::Event<T::Runtime>>,
+		<TFullCallExecutor<T::Block, T::Executor> as CallExecutor<T::Block>>::Error: std::fmt::Debug,
+	{
+		let old_version = self.runtime_version();
+		self.upgrade_runtime(wasm.clone());
+
+		let code_updated = self
+			.events()
+			.into_iter()
+			.any(|record| matches!(record.event.try_into(), Ok(frame_system::Event::CodeUpdated)));
+		if !code_updated {
+			return Err(format!(
+				"Runtime upgrade from spec version {} was not applied",
+				old_version.spec_version
+			));
+		}
+
+		let code = self.with_state(|| sp_io::storage::get(well_known_keys::CODE));
+		if code.as_ref() != Some(&wasm) {
+			return Err("Runtime code in storage differs from the upgrade".into());
+		}
+
+		// the new runtime builds the next block.
+		self.seal_blocks(1);
+		let new_version = self.runtime_version();
+		if new_version.spec_version <= old_version.spec_version {
+			return Err(format!(
+				"Spec version did not increase: {} -> {}",
+				old_version.spec_version, new_version.spec_version
+			));
+		}
+
+		Ok(new_version)
+	}
 }
 
 impl<T: ChainInfo> Drop for Node<T> {
@@ -0,0 +1,251 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![allow(clippy::all)]
+
+//! A mock relay chain which feeds validation data and inbound XCM messages
+//! to a parachain node sealed by manual-seal.
+
+use std::{
+	collections::BTreeMap,
+	sync::{Arc, Mutex},
+};
+
+use cumulus_primitives_core::{
+	relay_chain::{v1::HrmpChannelId, BlockNumber as RelayBlockNumber, Hash as RelayHash},
+	AbridgedHrmpChannel, InboundDownwardMessage, InboundHrmpMessage, ParaId, PersistedValidationData,
+};
+use cumulus_primitives_parachain_inherent::{ParachainInherentData, INHERENT_IDENTIFIER};
+use cumulus_test_relay_sproof_builder::RelayStateSproofBuilder;
+use sp_inherents::{InherentData, InherentDataProvider, InherentIdentifier};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+/// Relay block number of the first sealed parachain block, matches
+/// `default_mock_parachain_inherent_data_provider`.
+const DEFAULT_RELAY_OFFSET: RelayBlockNumber = 1000;
+
+/// Relay blocks passing between two parachain blocks.
+const DEFAULT_RELAY_BLOCKS_PER_PARA_BLOCK: RelayBlockNumber = 2;
+
+/// Extends a message queue chain head, the same way the relay chain does.
+fn extend_mqc_head(head: RelayHash, sent_at: RelayBlockNumber, msg: &[u8]) -> RelayHash {
+	BlakeTwo256::hash_of(&(head, sent_at, BlakeTwo256::hash(msg)))
+}
+
+#[derive(Default)]
+struct RelayState {
+	/// relay parent number of the next sealed parachain block.
+	relay_block_number: RelayBlockNumber,
+	/// queued downward messages, delivered with the next block.
+	downward_messages: Vec<Vec<u8>>,
+	/// queued horizontal messages by sender, delivered with the next block.
+	horizontal_messages: BTreeMap<ParaId, Vec<Vec<u8>>>,
+	/// head of the downward message queue chain.
+	dmq_mqc_head: RelayHash,
+	/// heads of the inbound hrmp channels by sender.
+	hrmp_mqc_heads: BTreeMap<ParaId, RelayHash>,
+}
+
+/// A simulated relay chain, shared between a test and the node it feeds.
+///
+/// Every parachain block sealed while the relay is attached advances the
+/// relay chain and delivers all messages queued since the previous block.
+#[derive(Clone)]
+pub struct SimulatedRelay {
+	para_id: ParaId,
+	relay_blocks_per_para_block: RelayBlockNumber,
+	state: Arc<Mutex<RelayState>>,
+}
+
+impl SimulatedRelay {
+	/// A relay chain hosting `para_id`.
+	pub fn new(para_id: ParaId) -> Self {
+		Self {
+			para_id,
+			relay_blocks_per_para_block: DEFAULT_RELAY_BLOCKS_PER_PARA_BLOCK,
+			state: Arc::new(Mutex::new(RelayState {
+				relay_block_number: DEFAULT_RELAY_OFFSET,
+				..Default::default()
+			})),
+		}
+	}
+
+	/// Set the number of relay blocks passing between two parachain blocks.
+	pub fn with_relay_blocks_per_para_block(mut self, relay_blocks_per_para_block: RelayBlockNumber) -> Self {
+		self.relay_blocks_per_para_block = relay_blocks_per_para_block;
+		self
+	}
+
+	/// The parachain hosted by this relay chain.
+	pub fn para_id(&self) -> ParaId {
+		self.para_id
+	}
+
+	/// Relay parent number of the next sealed parachain block.
+	pub fn relay_block_number(&self) -> RelayBlockNumber {
+		self.state.lock().unwrap().relay_block_number
+	}
+
+	/// Queue an encoded `VersionedXcm` sent by the relay chain.
+	pub fn send_downward_message(&self, msg: Vec<u8>) {
+		self.state.lock().unwrap().downward_messages.push(msg);
+	}
+
+	/// Queue an encoded XCMP message sent by the sibling `sender`.
+	///
+	/// An inbound channel from `sender` is opened on first use.
+	pub fn send_horizontal_message(&self, sender: ParaId, msg: Vec<u8>) {
+		self.state
+			.lock()
+			.unwrap()
+			.horizontal_messages
+			.entry(sender)
+			.or_default()
+			.push(msg);
+	}
+
+	/// Number of messages waiting for the next parachain block.
+	pub fn pending_messages(&self) -> usize {
+		let state = self.state.lock().unwrap();
+		state.downward_messages.len() + state.horizontal_messages.values().map(Vec::len).sum::<usize>()
+	}
+
+	/// Builds the validation data of the next parachain block and advances
+	/// the relay chain.
+	pub fn next_inherent_data_provider(&self) -> SimulatedRelayInherentDataProvider {
+		let mut state = self.state.lock().unwrap();
+		let relay_parent_number = state.relay_block_number;
+		state.relay_block_number += self.relay_blocks_per_para_block;
+
+		let mut sproof = RelayStateSproofBuilder {
+			para_id: self.para_id,
+			..Default::default()
+		};
+
+		let downward_messages = std::mem::take(&mut state.downward_messages)
+			.into_iter()
+			.map(|msg| {
+				state.dmq_mqc_head = extend_mqc_head(state.dmq_mqc_head, relay_parent_number, &msg);
+				InboundDownwardMessage {
+					sent_at: relay_parent_number,
+					msg,
+				}
+			})
+			.collect::<Vec<_>>();
+		sproof.dmq_mqc_head = Some(state.dmq_mqc_head);
+
+		let mut horizontal_messages = BTreeMap::new();
+		for (sender, messages) in std::mem::take(&mut state.horizontal_messages) {
+			let head = state.hrmp_mqc_heads.entry(sender).or_default();
+			let inbound = messages
+				.into_iter()
+				.map(|data| {
+					*head = extend_mqc_head(*head, relay_parent_number, &data);
+					InboundHrmpMessage {
+						sent_at: relay_parent_number,
+						data,
+					}
+				})
+				.collect::<Vec<_>>();
+			horizontal_messages.insert(sender, inbound);
+		}
+
+		// every channel ever used stays open, with its current head.
+		sproof.hrmp_ingress_channel_index = Some(state.hrmp_mqc_heads.keys().cloned().collect());
+		for (sender, head) in state.hrmp_mqc_heads.iter() {
+			sproof.hrmp_channels.insert(
+				HrmpChannelId {
+					sender: *sender,
+					recipient: self.para_id,
+				},
+				AbridgedHrmpChannel {
+					max_capacity: u32::MAX,
+					max_total_size: u32::MAX,
+					max_message_size: u32::MAX,
+					msg_count: 0,
+					total_size: 0,
+					mqc_head: Some(*head),
+				},
+			);
+		}
+
+		let (relay_parent_storage_root, relay_chain_state) = sproof.into_state_root_and_proof();
+
+		SimulatedRelayInherentDataProvider {
+			data: ParachainInherentData {
+				validation_data: PersistedValidationData {
+					parent_head: Default::default(),
+					relay_parent_number,
+					relay_parent_storage_root,
+					max_pov_size: Default::default(),
+				},
+				relay_chain_state,
+				downward_messages,
+				horizontal_messages,
+			},
+		}
+	}
+}
+
+/// Provides the parachain inherent built by a `SimulatedRelay`.
+///
+/// Replaces any parachain inherent provided before it, so it can be put
+/// after a chain's default mock provider.
+pub struct SimulatedRelayInherentDataProvider {
+	data: ParachainInherentData,
+}
+
+#[async_trait::async_trait]
+impl InherentDataProvider for SimulatedRelayInherentDataProvider {
+	fn provide_inherent_data(&self, inherent_data: &mut InherentData) -> Result<(), sp_inherents::Error> {
+		inherent_data.replace_data(INHERENT_IDENTIFIER, &self.data);
+		Ok(())
+	}
+
+	async fn try_handle_error(
+		&self,
+		_identifier: &InherentIdentifier,
+		_error: &[u8],
+	) -> Option<Result<(), sp_inherents::Error>> {
+		None
+	}
+}
+
+/// An inherent data provider which may be absent.
+pub struct OptionalInherentDataProvider<P>(pub Option<P>);
+
+#[async_trait::async_trait]
+impl<P: InherentDataProvider> InherentDataProvider for OptionalInherentDataProvider<P> {
+	fn provide_inherent_data(&self, inherent_data: &mut InherentData) -> Result<(), sp_inherents::Error> {
+		match &self.0 {
+			Some(provider) => provider.provide_inherent_data(inherent_data),
+			None => Ok(()),
+		}
+	}
+
+	async fn try_handle_error(
+		&self,
+		identifier: &InherentIdentifier,
+		error: &[u8],
+	) -> Option<Result<(), sp_inherents::Error>> {
+		match &self.0 {
+			Some(provider) => provider.try_handle_error(identifier, error).await,
+			None => None,
+		}
+	}
+}
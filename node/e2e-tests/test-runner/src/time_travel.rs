@@ -0,0 +1,114 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![allow(clippy::all)]
+
+//! Overrides the timestamp inherent of sealed blocks, so tests can move
+//! the chain clock forward without sleeping.
+
+use std::{
+	sync::{Arc, Mutex},
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+use sp_inherents::{InherentData, InherentDataProvider, InherentIdentifier};
+
+/// Time between two sealed blocks once the clock has been overridden, in
+/// milliseconds.
+pub const DEFAULT_BLOCK_TIME: u64 = 12_000;
+
+struct ClockState {
+	/// timestamp of the next sealed block, `None` until time travel is used.
+	next_timestamp: Option<u64>,
+	/// increment of the timestamp between two sealed blocks.
+	block_time: u64,
+}
+
+/// The clock of a node, shared with its inherent data providers.
+#[derive(Clone)]
+pub struct TimeTravel {
+	state: Arc<Mutex<ClockState>>,
+}
+
+impl TimeTravel {
+	pub(crate) fn new() -> Self {
+		Self {
+			state: Arc::new(Mutex::new(ClockState {
+				next_timestamp: None,
+				block_time: DEFAULT_BLOCK_TIME,
+			})),
+		}
+	}
+
+	/// Timestamp of the next sealed block, defaulting to system time.
+	pub fn next_timestamp(&self) -> u64 {
+		self.state.lock().unwrap().next_timestamp.unwrap_or_else(now)
+	}
+
+	/// Seal the next block at `timestamp`, following blocks advance by the
+	/// block time from there.
+	pub fn jump_to(&self, timestamp: u64) {
+		self.state.lock().unwrap().next_timestamp = Some(timestamp);
+	}
+
+	/// Set the increment of the timestamp between two sealed blocks.
+	pub fn set_block_time(&self, block_time: u64) {
+		self.state.lock().unwrap().block_time = block_time;
+	}
+
+	/// Provider of the timestamp of the next sealed block, if overridden,
+	/// and advances the clock by one block.
+	pub(crate) fn next_inherent_data_provider(&self) -> TimestampOverrideInherentDataProvider {
+		let mut state = self.state.lock().unwrap();
+		let timestamp = state.next_timestamp;
+		state.next_timestamp = timestamp.map(|timestamp| timestamp + state.block_time);
+
+		TimestampOverrideInherentDataProvider { timestamp }
+	}
+}
+
+fn now() -> u64 {
+	SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.expect("Current time is always after unix epoch; qed")
+		.as_millis() as u64
+}
+
+/// Replaces the timestamp inherent provided by the chain when the clock is
+/// overridden.
+pub struct TimestampOverrideInherentDataProvider {
+	timestamp: Option<u64>,
+}
+
+#[async_trait::async_trait]
+impl InherentDataProvider for TimestampOverrideInherentDataProvider {
+	fn provide_inherent_data(&self, inherent_data: &mut InherentData) -> Result<(), sp_inherents::Error> {
+		if let Some(timestamp) = self.timestamp {
+			inherent_data.replace_data(sp_timestamp::INHERENT_IDENTIFIER, &timestamp);
+		}
+		Ok(())
+	}
+
+	async fn try_handle_error(
+		&self,
+		_identifier: &InherentIdentifier,
+		_error: &[u8],
+	) -> Option<Result<(), sp_inherents::Error>> {
+		None
+	}
+}
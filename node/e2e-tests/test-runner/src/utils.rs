@@ -18,22 +18,26 @@
 
 #![allow(clippy::all)]
 
-use futures::{Sink, SinkExt};
 use log::LevelFilter;
-use sc_client_api::execution_extensions::ExecutionStrategies;
+use once_cell::sync::Lazy;
+use sc_client_api::{execution_extensions::ExecutionStrategies, ExecutionStrategy};
 use sc_executor::WasmExecutionMethod;
 use sc_informant::OutputFormat;
 use sc_network::{
 	config::{NetworkConfiguration, Role, TransportConfig},
 	multiaddr,
 };
-use sc_service::config::KeystoreConfig;
+use sc_service::config::{KeystoreConfig, PruningMode};
 use sc_service::{
 	BasePath, ChainSpec, Configuration, DatabaseConfig, KeepBlocks, TaskExecutor, TransactionStorageMode,
 };
+use sp_core::storage::Storage;
 use sp_keyring::sr25519::Keyring::Alice;
-use std::fmt;
+use sp_runtime::traits::Block as BlockT;
 use std::io::Write;
+use std::net::{SocketAddr, TcpListener};
+
+use crate::{LogCapture, LogRecord};
 
 /// Base db path gotten from env
 pub fn base_path() -> BasePath {
@@ -44,21 +48,24 @@ pub fn base_path() -> BasePath {
 	}
 }
 
-/// Builds the global logger.
-pub fn logger<S>(log_targets: Vec<(&'static str, LevelFilter)>, executor: tokio::runtime::Handle, log_sink: S)
-where
-	S: Sink<String> + Clone + Unpin + Send + Sync + 'static,
-	S::Error: Send + Sync + fmt::Debug,
-{
+/// Sink of the global logger, shared by all nodes of the process.
+static LOG_CAPTURE: Lazy<LogCapture> = Lazy::new(LogCapture::default);
+
+/// Builds the global logger, returns the sink capturing its log lines.
+///
+/// The logger is only installed once per process, with the log targets of
+/// the first call.
+pub fn logger(log_targets: Vec<(&'static str, LevelFilter)>) -> LogCapture {
 	let mut builder = env_logger::builder();
 	builder.format(move |buf: &mut env_logger::fmt::Formatter, record: &log::Record| {
-		let entry = format!("{} {} {}", record.level(), record.target(), record.args());
-		let res = writeln!(buf, "{}", entry);
+		let record = LogRecord {
+			level: record.level(),
+			target: record.target().to_string(),
+			message: record.args().to_string(),
+		};
+		let res = writeln!(buf, "{}", record);
 
-		let mut log_sink_clone = log_sink.clone();
-		let _ = executor.spawn(async move {
-			log_sink_clone.send(entry).await.expect("log_stream is dropped");
-		});
+		LOG_CAPTURE.push(record);
 		res
 	});
 	builder.write_style(env_logger::WriteStyle::Always);
@@ -67,89 +74,256 @@ where
 		builder.filter_module(module, level);
 	}
 	let _ = builder.is_test(true).try_init();
+
+	LOG_CAPTURE.clone()
+}
+
+/// The genesis state of the node configured by `default_config`.
+pub enum ConfigMode<B: BlockT> {
+	/// The genesis storage of the chain spec.
+	Local,
+	/// The state of a live chain, so that bugs seen on Karura/Acala can be
+	/// reproduced locally. The runtime code is forked too, so the node
+	/// executes the live runtime.
+	ForkRemote(ForkRemote<B>),
 }
 
 /// Produces a default configuration object, suitable for use with most set ups.
-pub fn default_config(task_executor: TaskExecutor, mut chain_spec: Box<dyn ChainSpec>) -> Configuration {
-	let base_path = base_path();
-	let root_path = base_path.path().to_path_buf().join("chains").join(chain_spec.id());
-
-	let storage = chain_spec
-		.as_storage_builder()
-		.build_storage()
-		.expect("could not build storage");
-
-	chain_spec.set_storage(storage);
-	let key_seed = Alice.to_seed();
-
-	let mut network_config = NetworkConfiguration::new(
-		format!("Test Node for: {}", key_seed),
-		"network/test/0.1",
-		Default::default(),
-		None,
-	);
-	let informant_output_format = OutputFormat { enable_color: false };
-	network_config.allow_non_globals_in_dht = true;
-
-	network_config
-		.listen_addresses
-		.push(multiaddr::Protocol::Memory(0).into());
-
-	network_config.transport = TransportConfig::MemoryOnly;
-
-	Configuration {
-		impl_name: "test-node".to_string(),
-		impl_version: "0.1".to_string(),
-		role: Role::Authority,
-		task_executor: task_executor.into(),
-		transaction_pool: Default::default(),
-		network: network_config,
-		keystore: KeystoreConfig::Path {
-			path: root_path.join("key"),
-			password: None,
-		},
-		database: DatabaseConfig::RocksDb {
-			path: root_path.join("db"),
-			cache_size: 128,
-		},
-		state_cache_size: 16777216,
-		state_cache_child_ratio: None,
-		chain_spec,
-		wasm_method: WasmExecutionMethod::Interpreted,
-		execution_strategies: ExecutionStrategies {
-			syncing: sc_client_api::ExecutionStrategy::AlwaysWasm,
-			importing: sc_client_api::ExecutionStrategy::AlwaysWasm,
-			block_construction: sc_client_api::ExecutionStrategy::AlwaysWasm,
-			offchain_worker: sc_client_api::ExecutionStrategy::AlwaysWasm,
-			other: sc_client_api::ExecutionStrategy::AlwaysWasm,
-		},
-		rpc_http: None,
-		rpc_ws: None,
-		rpc_ipc: None,
-		rpc_ws_max_connections: None,
-		rpc_http_threads: None,
-		rpc_cors: None,
-		rpc_methods: Default::default(),
-		rpc_max_payload: None,
-		prometheus_config: None,
-		telemetry_endpoints: None,
-		telemetry_external_transport: None,
-		default_heap_pages: None,
-		offchain_worker: Default::default(),
-		force_authoring: false,
-		disable_grandpa: false,
-		dev_key_seed: Some(key_seed),
-		tracing_targets: None,
-		tracing_receiver: Default::default(),
-		max_runtime_instances: 8,
-		announce_block: true,
-		base_path: Some(base_path),
-		wasm_runtime_overrides: None,
-		informant_output_format,
-		disable_log_reloading: false,
-		keystore_remote: None,
-		keep_blocks: KeepBlocks::All,
-		state_pruning: Default::default(),
-		transaction_storage: TransactionStorageMode::BlockBody,
+pub fn default_config<B: BlockT>(
+	task_executor: TaskExecutor,
+	mut chain_spec: Box<dyn ChainSpec>,
+	mode: ConfigMode<B>,
+) -> Configuration {
+	if let ConfigMode::ForkRemote(fork) = mode {
+		chain_spec.set_storage(remote_storage(fork));
+	}
+	TestConfigBuilder::new().build(task_executor, chain_spec)
+}
+
+/// A free localhost address for a rpc server.
+fn ephemeral_address() -> SocketAddr {
+	let listener = TcpListener::bind("127.0.0.1:0").expect("couldn't bind an ephemeral port");
+	listener.local_addr().expect("listener is bound; qed")
+}
+
+/// Builder of test node configurations, `default_config` uses its defaults.
+pub struct TestConfigBuilder {
+	/// serve websocket rpc.
+	rpc_ws: bool,
+	/// serve http rpc.
+	rpc_http: bool,
+	/// strategy used for every execution context.
+	execution_strategy: ExecutionStrategy,
+	/// method of executing the wasm runtime.
+	wasm_method: WasmExecutionMethod,
+	/// heap pages of the wasm runtime, `None` uses the on-chain value.
+	default_heap_pages: Option<u64>,
+	/// maximum number of runtime instances kept in the cache.
+	max_runtime_instances: usize,
+	/// store the chain in ParityDB instead of RocksDB.
+	parity_db: bool,
+	/// state pruning of the database.
+	state_pruning: PruningMode,
+}
+
+impl Default for TestConfigBuilder {
+	fn default() -> Self {
+		Self {
+			rpc_ws: false,
+			rpc_http: false,
+			execution_strategy: ExecutionStrategy::AlwaysWasm,
+			wasm_method: WasmExecutionMethod::Interpreted,
+			default_heap_pages: None,
+			max_runtime_instances: 8,
+			parity_db: false,
+			state_pruning: Default::default(),
+		}
+	}
+}
+
+impl TestConfigBuilder {
+	/// A builder with rpc servers disabled, executing the interpreted wasm
+	/// runtime only.
+	pub fn new() -> Self {
+		Default::default()
+	}
+
+	/// Serve websocket rpc on an ephemeral localhost port, see `Node::rpc_ws_address`.
+	pub fn with_ws_rpc(mut self) -> Self {
+		self.rpc_ws = true;
+		self
+	}
+
+	/// Serve http rpc on an ephemeral localhost port, see `Node::rpc_http_address`.
+	pub fn with_http_rpc(mut self) -> Self {
+		self.rpc_http = true;
+		self
+	}
+
+	/// Set the execution strategy of every execution context.
+	pub fn with_execution_strategy(mut self, execution_strategy: ExecutionStrategy) -> Self {
+		self.execution_strategy = execution_strategy;
+		self
+	}
+
+	/// Execute the native runtime, falling back to wasm if the versions differ.
+	pub fn with_native_else_wasm(self) -> Self {
+		self.with_execution_strategy(ExecutionStrategy::NativeElseWasm)
+	}
+
+	/// Execute the wasm runtime compiled with wasmtime instead of interpreting it.
+	#[cfg(feature = "wasmtime")]
+	pub fn with_compiled_wasm(mut self) -> Self {
+		self.wasm_method = WasmExecutionMethod::Compiled;
+		self
+	}
+
+	/// Set the heap pages of the wasm runtime, overriding the on-chain value.
+	pub fn with_heap_pages(mut self, heap_pages: u64) -> Self {
+		self.default_heap_pages = Some(heap_pages);
+		self
+	}
+
+	/// Set the maximum number of runtime instances kept in the cache.
+	pub fn with_max_runtime_instances(mut self, max_runtime_instances: usize) -> Self {
+		self.max_runtime_instances = max_runtime_instances;
+		self
+	}
+
+	/// Store the chain in ParityDB instead of RocksDB.
+	pub fn with_parity_db(mut self) -> Self {
+		self.parity_db = true;
+		self
+	}
+
+	/// Set the state pruning of the database, e.g. `PruningMode::ArchiveCanonical`.
+	pub fn with_state_pruning(mut self, state_pruning: PruningMode) -> Self {
+		self.state_pruning = state_pruning;
+		self
+	}
+
+	/// Produces the configuration.
+	pub fn build(self, task_executor: TaskExecutor, mut chain_spec: Box<dyn ChainSpec>) -> Configuration {
+		let base_path = base_path();
+		let root_path = base_path.path().to_path_buf().join("chains").join(chain_spec.id());
+
+		let storage = chain_spec
+			.as_storage_builder()
+			.build_storage()
+			.expect("could not build storage");
+
+		chain_spec.set_storage(storage);
+		let key_seed = Alice.to_seed();
+
+		let mut network_config = NetworkConfiguration::new(
+			format!("Test Node for: {}", key_seed),
+			"network/test/0.1",
+			Default::default(),
+			None,
+		);
+		let informant_output_format = OutputFormat { enable_color: false };
+		network_config.allow_non_globals_in_dht = true;
+
+		network_config
+			.listen_addresses
+			.push(multiaddr::Protocol::Memory(0).into());
+
+		network_config.transport = TransportConfig::MemoryOnly;
+
+		Configuration {
+			impl_name: "test-node".to_string(),
+			impl_version: "0.1".to_string(),
+			role: Role::Authority,
+			task_executor: task_executor.into(),
+			transaction_pool: Default::default(),
+			network: network_config,
+			keystore: KeystoreConfig::Path {
+				path: root_path.join("key"),
+				password: None,
+			},
+			database: if self.parity_db {
+				DatabaseConfig::ParityDb {
+					path: root_path.join("paritydb"),
+				}
+			} else {
+				DatabaseConfig::RocksDb {
+					path: root_path.join("db"),
+					cache_size: 128,
+				}
+			},
+			state_cache_size: 16777216,
+			state_cache_child_ratio: None,
+			chain_spec,
+			wasm_method: self.wasm_method,
+			execution_strategies: ExecutionStrategies {
+				syncing: self.execution_strategy,
+				importing: self.execution_strategy,
+				block_construction: self.execution_strategy,
+				offchain_worker: self.execution_strategy,
+				other: self.execution_strategy,
+			},
+			rpc_http: self.rpc_http.then(ephemeral_address),
+			rpc_ws: self.rpc_ws.then(ephemeral_address),
+			rpc_ipc: None,
+			rpc_ws_max_connections: None,
+			rpc_http_threads: None,
+			rpc_cors: None,
+			rpc_methods: Default::default(),
+			rpc_max_payload: None,
+			prometheus_config: None,
+			telemetry_endpoints: None,
+			telemetry_external_transport: None,
+			default_heap_pages: self.default_heap_pages,
+			offchain_worker: Default::default(),
+			force_authoring: false,
+			disable_grandpa: false,
+			dev_key_seed: Some(key_seed),
+			tracing_targets: None,
+			tracing_receiver: Default::default(),
+			max_runtime_instances: self.max_runtime_instances,
+			announce_block: true,
+			base_path: Some(base_path),
+			wasm_runtime_overrides: None,
+			informant_output_format,
+			disable_log_reloading: false,
+			keystore_remote: None,
+			keep_blocks: KeepBlocks::All,
+			state_pruning: self.state_pruning,
+			transaction_storage: TransactionStorageMode::BlockBody,
+		}
+	}
+}
+
+/// A live chain to fork the state of.
+pub struct ForkRemote<B: BlockT> {
+	/// websocket rpc endpoint of a node of the live chain, e.g. `wss://karura.api.onfinality.io/public-ws`.
+	pub uri: String,
+	/// block to fork at, the best block of the remote node if `None`.
+	pub at: Option<B::Hash>,
+	/// pallets to fetch the storage of, everything if empty.
+	pub modules: Vec<String>,
+}
+
+/// Fetches the storage of a live chain, child tries are not included.
+pub fn remote_storage<B: BlockT>(fork: ForkRemote<B>) -> Storage {
+	use remote_externalities::{Builder, Mode, OnlineConfig};
+	use sp_state_machine::Backend;
+
+	let ForkRemote { uri, at, modules } = fork;
+	let builder = Builder::<B>::new().mode(Mode::Online(OnlineConfig {
+		transport: uri.clone().into(),
+		at,
+		modules,
+		..Default::default()
+	}));
+
+	let mut runtime = tokio::runtime::Runtime::new().expect("couldn't create a tokio runtime");
+	let ext = runtime
+		.block_on(builder.build())
+		.unwrap_or_else(|e| panic!("couldn't fetch remote state from {}: {}", uri, e));
+
+	Storage {
+		top: ext.as_backend().pairs().into_iter().collect(),
+		children_default: Default::default(),
 	}
 }
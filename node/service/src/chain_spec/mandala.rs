@@ -16,10 +16,11 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use acala_primitives::{AccountId, TokenSymbol};
+use acala_primitives::{AccountId, Balance, CurrencyId, TokenSymbol, TradingPair};
 use hex_literal::hex;
 use sc_chain_spec::ChainType;
 use sc_telemetry::TelemetryEndpoints;
+use serde::Deserialize;
 use serde_json::map::Map;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::UncheckedInto, sr25519};
@@ -35,17 +36,85 @@ pub type ChainSpec = sc_service::GenericChainSpec<mandala_runtime::GenesisConfig
 
 pub const PARA_ID: u32 = 1000;
 
+/// Overrides of the development genesis, to boot testnets and e2e tests with
+/// a custom genesis.
+///
+/// The fixed point ratios are the inner values with 18 decimals, e.g.
+/// `"1500000000000000000"` for 150%.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct GenesisOverrides {
+	/// Accounts endowed with ACA, DOT and aUSD, instead of the dev accounts.
+	pub endowed_accounts: Option<Vec<AccountId>>,
+	/// Members of the Acala and Band oracle operators, instead of the root key.
+	pub oracle_members: Option<Vec<AccountId>>,
+	/// Trading pairs enabled on DEX, instead of `EnabledTradingPairs`.
+	pub enabled_trading_pairs: Option<Vec<(CurrencyId, CurrencyId)>>,
+	/// Risk management params of the collaterals, instead of DOT, LDOT and
+	/// RENBTC.
+	pub collaterals_params: Option<Vec<CollateralParams>>,
+}
+
+/// Initial risk management params of a collateral.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CollateralParams {
+	/// The collateral currency.
+	pub currency_id: CurrencyId,
+	/// Extra interest rate per sec.
+	pub interest_rate_per_sec: Option<FixedU128>,
+	/// Liquidation ratio.
+	pub liquidation_ratio: Option<FixedU128>,
+	/// Liquidation penalty rate.
+	pub liquidation_penalty: Option<FixedU128>,
+	/// Required collateral ratio.
+	pub required_collateral_ratio: Option<FixedU128>,
+	/// Maximum total debit value in aUSD.
+	pub maximum_total_debit_value: Balance,
+}
+
+impl GenesisOverrides {
+	/// Parse the overrides from JSON.
+	pub fn from_json_bytes(json: &[u8]) -> Result<Self, String> {
+		serde_json::from_slice(json).map_err(|e| format!("Error parsing genesis overrides: {}", e))
+	}
+
+	/// The enabled trading pairs, if overridden.
+	fn trading_pairs(&self) -> Result<Option<Vec<TradingPair>>, String> {
+		self.enabled_trading_pairs
+			.as_ref()
+			.map(|pairs| {
+				pairs
+					.iter()
+					.map(|&(a, b)| {
+						TradingPair::from_currency_ids(a, b)
+							.ok_or_else(|| format!("Invalid trading pair: {:?}, {:?}", a, b))
+					})
+					.collect()
+			})
+			.transpose()
+	}
+}
+
 /// Development testnet config (single validator Alice), non-parachain
 pub fn dev_testnet_config() -> Result<ChainSpec, String> {
-	dev_testnet_config_from_chain_id("mandala-dev")
+	dev_testnet_config_from_chain_id("mandala-dev", GenesisOverrides::default())
 }
 
 /// Parachain development testnet config (single collator Alice)
 pub fn parachain_dev_testnet_config() -> Result<ChainSpec, String> {
-	dev_testnet_config_from_chain_id("mandala-pc-dev")
+	dev_testnet_config_from_chain_id("mandala-pc-dev", GenesisOverrides::default())
 }
 
-fn dev_testnet_config_from_chain_id(chain_id: &str) -> Result<ChainSpec, String> {
+/// Development testnet config (single validator Alice), non-parachain, with
+/// the custom genesis of `overrides`
+pub fn mandala_custom(overrides: GenesisOverrides) -> Result<ChainSpec, String> {
+	// check the trading pairs before building the genesis
+	overrides.trading_pairs()?;
+	dev_testnet_config_from_chain_id("mandala-dev", overrides)
+}
+
+fn dev_testnet_config_from_chain_id(chain_id: &str, overrides: GenesisOverrides) -> Result<ChainSpec, String> {
 	let mut properties = Map::new();
 	let mut token_symbol: Vec<String> = vec![];
 	let mut token_decimals: Vec<u32> = vec![];
@@ -76,6 +145,7 @@ fn dev_testnet_config_from_chain_id(chain_id: &str) -> Result<ChainSpec, String>
 					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
 				],
+				overrides.clone(),
 			)
 		},
 		vec![],
@@ -129,6 +199,7 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
 				],
+				GenesisOverrides::default(),
 			)
 		},
 		vec![],
@@ -231,15 +302,16 @@ fn testnet_genesis(
 	initial_authorities: Vec<(AccountId, AccountId, GrandpaId, AuraId)>,
 	root_key: AccountId,
 	endowed_accounts: Vec<AccountId>,
+	overrides: GenesisOverrides,
 ) -> mandala_runtime::GenesisConfig {
 	use mandala_runtime::{
-		dollar, get_all_module_accounts, AirDropConfig, Balance, BalancesConfig, CdpEngineConfig, CdpTreasuryConfig,
+		dollar, get_all_module_accounts, AirDropConfig, BalancesConfig, CdpEngineConfig, CdpTreasuryConfig,
 		CollatorSelectionConfig, DexConfig, EVMConfig, EnabledTradingPairs, FinancialCouncilMembershipConfig,
-		GeneralCouncilMembershipConfig, HomaCouncilMembershipConfig, IndicesConfig, NativeTokenExistentialDeposit,
-		OperatorMembershipAcalaConfig, OperatorMembershipBandConfig, OrmlNFTConfig, ParachainInfoConfig, Period,
-		RenVmBridgeConfig, SessionConfig, SessionKeys, SessionManagerConfig, StakingPoolConfig, StarportConfig,
-		SudoConfig, SystemConfig, TechnicalCommitteeMembershipConfig, TokensConfig, VestingConfig, ACA, AUSD, DOT,
-		LDOT, RENBTC,
+		FlashMintConfig, GeneralCouncilMembershipConfig, HomaCouncilMembershipConfig, IndicesConfig,
+		NativeTokenExistentialDeposit, OperatorMembershipAcalaConfig, OperatorMembershipBandConfig, OrmlNFTConfig,
+		ParachainInfoConfig, Period, RenVmBridgeConfig, SessionConfig, SessionKeys, SessionManagerConfig,
+		StakingPoolConfig, StarportConfig, SudoConfig, SystemConfig, TechnicalCommitteeMembershipConfig, TokensConfig,
+		VestingConfig, ACA, AUSD, DOT, LDOT, RENBTC,
 	};
 
 	let existential_deposit = NativeTokenExistentialDeposit::get();
@@ -247,6 +319,16 @@ fn testnet_genesis(
 	let initial_balance: u128 = 10_000_000 * dollar(ACA);
 	let initial_staking: u128 = 100_000 * dollar(ACA);
 
+	let endowed_accounts = overrides.endowed_accounts.clone().unwrap_or(endowed_accounts);
+	let oracle_members = overrides
+		.oracle_members
+		.clone()
+		.unwrap_or_else(|| vec![root_key.clone()]);
+	let enabled_trading_pairs = overrides
+		.trading_pairs()
+		.expect("trading pairs are checked by `mandala_custom`")
+		.unwrap_or_else(EnabledTradingPairs::get);
+
 	let evm_genesis_accounts = evm_genesis();
 	let balances = initial_authorities
 		.iter()
@@ -306,11 +388,11 @@ fn testnet_genesis(
 			phantom: Default::default(),
 		},
 		operator_membership_acala: OperatorMembershipAcalaConfig {
-			members: vec![root_key.clone()],
+			members: oracle_members.clone(),
 			phantom: Default::default(),
 		},
 		operator_membership_band: OperatorMembershipBandConfig {
-			members: vec![root_key.clone()],
+			members: oracle_members,
 			phantom: Default::default(),
 		},
 		democracy: Default::default(),
@@ -329,37 +411,56 @@ fn testnet_genesis(
 			],
 		},
 		cdp_engine: CdpEngineConfig {
-			collaterals_params: vec![
-				(
-					DOT,
-					Some(FixedU128::zero()),                             // interest rate per sec for this collateral
-					Some(FixedU128::saturating_from_rational(150, 100)), // liquidation ratio
-					Some(FixedU128::saturating_from_rational(10, 100)),  // liquidation penalty rate
-					Some(FixedU128::saturating_from_rational(150, 100)), // required liquidation ratio
-					10_000_000 * dollar(AUSD),                           // maximum debit value in aUSD (cap)
-				),
-				(
-					LDOT,
-					Some(FixedU128::zero()),
-					Some(FixedU128::saturating_from_rational(150, 100)),
-					Some(FixedU128::saturating_from_rational(10, 100)),
-					Some(FixedU128::saturating_from_rational(180, 100)),
-					10_000_000 * dollar(AUSD),
-				),
-				(
-					RENBTC,
-					Some(FixedU128::zero()),
-					Some(FixedU128::saturating_from_rational(150, 100)),
-					Some(FixedU128::saturating_from_rational(10, 100)),
-					Some(FixedU128::saturating_from_rational(150, 100)),
-					10_000_000 * dollar(AUSD),
-				),
-			],
+			collaterals_params: match overrides.collaterals_params {
+				Some(params) => params
+					.into_iter()
+					.map(|p| {
+						(
+							p.currency_id,
+							p.interest_rate_per_sec,
+							p.liquidation_ratio,
+							p.liquidation_penalty,
+							p.required_collateral_ratio,
+							p.maximum_total_debit_value,
+						)
+					})
+					.collect(),
+				None => vec![
+					(
+						DOT,
+						Some(FixedU128::zero()), // interest rate per sec for this collateral
+						Some(FixedU128::saturating_from_rational(150, 100)), // liquidation ratio
+						Some(FixedU128::saturating_from_rational(10, 100)), // liquidation penalty rate
+						Some(FixedU128::saturating_from_rational(150, 100)), // required liquidation ratio
+						10_000_000 * dollar(AUSD), // maximum debit value in aUSD (cap)
+					),
+					(
+						LDOT,
+						Some(FixedU128::zero()),
+						Some(FixedU128::saturating_from_rational(150, 100)),
+						Some(FixedU128::saturating_from_rational(10, 100)),
+						Some(FixedU128::saturating_from_rational(180, 100)),
+						10_000_000 * dollar(AUSD),
+					),
+					(
+						RENBTC,
+						Some(FixedU128::zero()),
+						Some(FixedU128::saturating_from_rational(150, 100)),
+						Some(FixedU128::saturating_from_rational(10, 100)),
+						Some(FixedU128::saturating_from_rational(150, 100)),
+						10_000_000 * dollar(AUSD),
+					),
+				],
+			},
 			global_interest_rate_per_sec: FixedU128::saturating_from_rational(
 				1_547_126_000u128,
 				1_000_000_000_000_000_000u128,
 			), /* 5% APR */
 		},
+		flash_mint: FlashMintConfig {
+			maximum_mint_amount: 1_000_000 * dollar(AUSD),
+			fee_rate: FixedU128::saturating_from_rational(9, 10_000), // 0.09%
+		},
 		air_drop: AirDropConfig {
 			airdrop_accounts: vec![],
 		},
@@ -378,7 +479,7 @@ fn testnet_genesis(
 		},
 		dex: DexConfig {
 			initial_listing_trading_pairs: vec![],
-			initial_enabled_trading_pairs: EnabledTradingPairs::get(),
+			initial_enabled_trading_pairs: enabled_trading_pairs,
 			#[cfg(feature = "runtime-benchmarks")]
 			initial_added_liquidity_pools: vec![],
 			#[cfg(not(feature = "runtime-benchmarks"))]
@@ -439,13 +540,13 @@ fn mandala_genesis(
 	endowed_accounts: Vec<AccountId>,
 ) -> mandala_runtime::GenesisConfig {
 	use mandala_runtime::{
-		cent, dollar, get_all_module_accounts, AirDropConfig, AirDropCurrencyId, Balance, BalancesConfig,
-		CdpEngineConfig, CdpTreasuryConfig, CollatorSelectionConfig, DexConfig, EVMConfig, EnabledTradingPairs,
-		FinancialCouncilMembershipConfig, GeneralCouncilMembershipConfig, HomaCouncilMembershipConfig, IndicesConfig,
-		NativeTokenExistentialDeposit, OperatorMembershipAcalaConfig, OperatorMembershipBandConfig, OrmlNFTConfig,
-		ParachainInfoConfig, Period, RenVmBridgeConfig, SessionConfig, SessionKeys, SessionManagerConfig,
-		StakingPoolConfig, StarportConfig, SudoConfig, SystemConfig, TechnicalCommitteeMembershipConfig, TokensConfig,
-		VestingConfig, ACA, AUSD, DOT, LDOT, RENBTC,
+		cent, dollar, get_all_module_accounts, AirDropConfig, AirDropCurrencyId, BalancesConfig, CdpEngineConfig,
+		CdpTreasuryConfig, CollatorSelectionConfig, DexConfig, EVMConfig, EnabledTradingPairs,
+		FinancialCouncilMembershipConfig, FlashMintConfig, GeneralCouncilMembershipConfig, HomaCouncilMembershipConfig,
+		IndicesConfig, NativeTokenExistentialDeposit, OperatorMembershipAcalaConfig, OperatorMembershipBandConfig,
+		OrmlNFTConfig, ParachainInfoConfig, Period, RenVmBridgeConfig, SessionConfig, SessionKeys,
+		SessionManagerConfig, StakingPoolConfig, StarportConfig, SudoConfig, SystemConfig,
+		TechnicalCommitteeMembershipConfig, TokensConfig, VestingConfig, ACA, AUSD, DOT, LDOT, RENBTC,
 	};
 
 	let existential_deposit = NativeTokenExistentialDeposit::get();
@@ -535,11 +636,11 @@ fn mandala_genesis(
 			collaterals_params: vec![
 				(
 					DOT,
-					Some(FixedU128::zero()),                             // interest rate per sec for this collateral
+					Some(FixedU128::zero()), // interest rate per sec for this collateral
 					Some(FixedU128::saturating_from_rational(105, 100)), // liquidation ratio
-					Some(FixedU128::saturating_from_rational(3, 100)),   // liquidation penalty rate
+					Some(FixedU128::saturating_from_rational(3, 100)), // liquidation penalty rate
 					Some(FixedU128::saturating_from_rational(110, 100)), // required liquidation ratio
-					10_000_000 * dollar(AUSD),                           // maximum debit value in aUSD (cap)
+					10_000_000 * dollar(AUSD), // maximum debit value in aUSD (cap)
 				),
 				(
 					LDOT,
@@ -563,6 +664,10 @@ fn mandala_genesis(
 				1_000_000_000_000_000_000u128,
 			), /* 5% APR */
 		},
+		flash_mint: FlashMintConfig {
+			maximum_mint_amount: 1_000_000 * dollar(AUSD),
+			fee_rate: FixedU128::saturating_from_rational(9, 10_000), // 0.09%
+		},
 		air_drop: AirDropConfig {
 			airdrop_accounts: {
 				let aca_airdrop_accounts_json = &include_bytes!("../../../../resources/mandala-airdrop-ACA.json")[..];
@@ -71,3 +71,53 @@ fn check_nfts() {
 		Vec<(Vec<u8>, module_nft::TokenData<Balance>, Vec<AccountId>)>,
 	)> = serde_json::from_slice(nfts_json).unwrap();
 }
+
+#[test]
+#[cfg(feature = "with-mandala-runtime")]
+fn mandala_genesis_overrides_from_json() {
+	use super::mandala::{mandala_custom, GenesisOverrides};
+	use acala_primitives::{CurrencyId, TokenSymbol};
+	use sp_core::crypto::Ss58Codec;
+
+	let overrides = GenesisOverrides::from_json_bytes(
+		br#"{
+			"endowedAccounts": ["5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"],
+			"enabledTradingPairs": [[{"token": "AUSD"}, {"token": "DOT"}]],
+			"collateralsParams": [{
+				"currencyId": {"token": "DOT"},
+				"interestRatePerSec": null,
+				"liquidationRatio": "1500000000000000000",
+				"liquidationPenalty": null,
+				"requiredCollateralRatio": null,
+				"maximumTotalDebitValue": 1000000
+			}]
+		}"#,
+	)
+	.unwrap();
+	assert_eq!(
+		overrides.endowed_accounts,
+		Some(vec![AccountId::from_string(
+			"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+		)
+		.unwrap()])
+	);
+	assert_eq!(overrides.oracle_members, None);
+	assert_eq!(
+		overrides.enabled_trading_pairs,
+		Some(vec![(
+			CurrencyId::Token(TokenSymbol::AUSD),
+			CurrencyId::Token(TokenSymbol::DOT)
+		)])
+	);
+	assert_eq!(
+		overrides.collaterals_params.unwrap()[0].maximum_total_debit_value,
+		1_000_000
+	);
+
+	assert!(GenesisOverrides::from_json_bytes(br#"{"unknown": []}"#).is_err());
+	assert!(mandala_custom(
+		GenesisOverrides::from_json_bytes(br#"{"enabledTradingPairs": [[{"token": "DOT"}, {"token": "DOT"}]]}"#)
+			.unwrap()
+	)
+	.is_err());
+}
@@ -18,7 +18,10 @@
 
 //! Acala Client abstractions.
 
-use acala_primitives::{AccountId, Balance, Block, BlockNumber, CurrencyId, DataProviderId, Hash, Header, Nonce};
+use acala_primitives::{
+	AccountId, Balance, Block, BlockNumber, CurrencyId, DataProviderId, Hash, Header, Moment, Nonce,
+};
+use module_support::PoolId;
 use runtime_common::TimeStampedPrice;
 use sc_client_api::{Backend as BackendT, BlockchainEvents, KeyIterator};
 use sp_api::{CallApiAt, NumberFor, ProvideRuntimeApi};
@@ -40,8 +43,15 @@ pub trait RuntimeApiCollection:
 	+ frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce>
 	+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
 	+ orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>
+	+ runtime_common::OracleFeedsApi<Block, DataProviderId, CurrencyId, AccountId, Moment>
 	+ module_staking_pool_rpc::StakingPoolRuntimeApi<Block, AccountId, Balance>
+	+ module_cdp_engine_rpc::CDPEngineRuntimeApi<Block, AccountId, CurrencyId, Balance>
+	+ module_dex_rpc::DexRuntimeApi<Block, CurrencyId, BlockNumber, Balance>
+	+ module_asset_registry_rpc::PortfolioRuntimeApi<Block, AccountId, CurrencyId, PoolId, Balance>
+	+ module_transaction_payment_rpc::TransactionFeeRuntimeApi<Block, Balance, CurrencyId>
 	+ module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance>
+	+ runtime_common::events::EventsApi<Block>
+	+ runtime_common::metrics::ProtocolMetricsApi<Block>
 	+ sp_api::Metadata<Block>
 	+ sp_offchain::OffchainWorkerApi<Block>
 	+ sp_session::SessionKeys<Block>
@@ -59,8 +69,15 @@ where
 		+ frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce>
 		+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
 		+ orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, TimeStampedPrice>
+		+ runtime_common::OracleFeedsApi<Block, DataProviderId, CurrencyId, AccountId, Moment>
 		+ module_staking_pool_rpc::StakingPoolRuntimeApi<Block, AccountId, Balance>
+		+ module_cdp_engine_rpc::CDPEngineRuntimeApi<Block, AccountId, CurrencyId, Balance>
+		+ module_dex_rpc::DexRuntimeApi<Block, CurrencyId, BlockNumber, Balance>
+		+ module_asset_registry_rpc::PortfolioRuntimeApi<Block, AccountId, CurrencyId, PoolId, Balance>
+		+ module_transaction_payment_rpc::TransactionFeeRuntimeApi<Block, Balance, CurrencyId>
 		+ module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance>
+		+ runtime_common::events::EventsApi<Block>
+		+ runtime_common::metrics::ProtocolMetricsApi<Block>
 		+ sp_api::Metadata<Block>
 		+ sp_offchain::OffchainWorkerApi<Block>
 		+ sp_session::SessionKeys<Block>
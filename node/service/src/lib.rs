@@ -48,9 +48,10 @@ use sc_client_api::ExecutorProvider;
 use sc_consensus::LongestChain;
 use sc_consensus_aura::ImportQueueParams;
 use sc_executor::native_executor_instance;
-use sc_network::NetworkService;
+use sc_network::{config::SyncMode, NetworkService};
 use sc_service::{
-	error::Error as ServiceError, Configuration, PartialComponents, Role, TFullBackend, TFullClient, TaskManager,
+	config::PruningMode, error::Error as ServiceError, Configuration, PartialComponents, Role, TFullBackend,
+	TFullClient, TaskManager,
 };
 use sc_telemetry::{Telemetry, TelemetryHandle, TelemetryWorker, TelemetryWorkerHandle};
 use sc_transaction_pool::BasicPool;
@@ -74,6 +75,10 @@ pub use sp_api::ConstructRuntimeApi;
 
 pub mod chain_spec;
 mod client;
+mod metrics;
+mod pruning;
+
+pub use pruning::{PruningProfile, MIN_COLLATOR_PRUNING};
 
 pub fn default_mock_parachain_inherent_data_provider() -> MockValidationDataInherentDataProvider {
 	MockValidationDataInherentDataProvider {
@@ -141,6 +146,46 @@ impl IdentifyVariant for Box<dyn ChainSpec> {
 	}
 }
 
+/// How the parachain node and its embedded relay chain node sync.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SyncStrategy {
+	/// Download the parachain state of the latest finalized block instead of
+	/// importing all the blocks. Requires a pruned state database.
+	pub parachain_fast_sync: bool,
+	/// Warp to the latest finalized relay chain block with GRANDPA finality
+	/// proofs, then download its state.
+	pub relay_chain_warp_sync: bool,
+}
+
+impl SyncStrategy {
+	/// Set the sync mode of the parachain and relay chain configurations.
+	fn apply(
+		self,
+		parachain_config: &mut Configuration,
+		polkadot_config: &mut Configuration,
+	) -> Result<(), ServiceError> {
+		if self.parachain_fast_sync {
+			if matches!(
+				parachain_config.state_pruning,
+				PruningMode::ArchiveAll | PruningMode::ArchiveCanonical
+			) {
+				return Err("Fast sync of the parachain can't be used with an archive node, set `--pruning`".into());
+			}
+
+			parachain_config.network.sync_mode = SyncMode::Fast {
+				skip_proofs: false,
+				storage_chain_mode: false,
+			};
+		}
+
+		if self.relay_chain_warp_sync {
+			polkadot_config.network.sync_mode = SyncMode::Warp;
+		}
+
+		Ok(())
+	}
+}
+
 /// Acala's full backend.
 type FullBackend = TFullBackend<Block>;
 
@@ -288,9 +333,11 @@ where
 /// runtime api.
 #[sc_tracing::logging::prefix_logs_with("Parachain")]
 async fn start_node_impl<RB, RuntimeApi, Executor, BIC>(
-	parachain_config: Configuration,
-	polkadot_config: Configuration,
+	mut parachain_config: Configuration,
+	mut polkadot_config: Configuration,
 	id: ParaId,
+	enable_evm_debug: bool,
+	sync_strategy: SyncStrategy,
 	_rpc_ext_builder: RB,
 	build_consensus: BIC,
 ) -> sc_service::error::Result<(TaskManager, Arc<FullClient<RuntimeApi, Executor>>)>
@@ -316,6 +363,8 @@ where
 		return Err("Light client not supported!".into());
 	}
 
+	sync_strategy.apply(&mut parachain_config, &mut polkadot_config)?;
+
 	let parachain_config = prepare_node_config(parachain_config);
 
 	let params = new_partial(&parachain_config, false, false)?;
@@ -359,11 +408,13 @@ where
 		let client = client.clone();
 		let transaction_pool = transaction_pool.clone();
 
-		Box::new(move |deny_unsafe, _| {
+		Box::new(move |deny_unsafe, subscription_executor| {
 			let deps = acala_rpc::FullDeps {
 				client: client.clone(),
 				pool: transaction_pool.clone(),
 				deny_unsafe,
+				enable_evm_debug,
+				subscription_executor,
 			};
 
 			Ok(acala_rpc::create_full(deps))
@@ -379,6 +430,13 @@ where
 		);
 	};
 
+	if let Some(registry) = prometheus_registry.clone() {
+		task_manager.spawn_handle().spawn(
+			"protocol-metrics",
+			metrics::run_protocol_metrics(client.clone(), registry),
+		);
+	}
+
 	sc_service::spawn_tasks(sc_service::SpawnTasksParams {
 		on_demand: None,
 		remote_blockchain: None,
@@ -449,6 +507,8 @@ pub async fn start_node<RuntimeApi, Executor>(
 	parachain_config: Configuration,
 	polkadot_config: Configuration,
 	id: ParaId,
+	enable_evm_debug: bool,
+	sync_strategy: SyncStrategy,
 ) -> sc_service::error::Result<(TaskManager, Arc<FullClient<RuntimeApi, Executor>>)>
 where
 	RuntimeApi: ConstructRuntimeApi<Block, FullClient<RuntimeApi, Executor>> + Send + Sync + 'static,
@@ -460,6 +520,8 @@ where
 		parachain_config,
 		polkadot_config,
 		id,
+		enable_evm_debug,
+		sync_strategy,
 		|_| Default::default(),
 		|client,
 		 prometheus_registry,
@@ -595,7 +657,11 @@ pub fn new_chain_ops(
 }
 
 #[cfg(feature = "with-mandala-runtime")]
-fn inner_mandala_dev(config: Configuration, instant_sealing: bool) -> Result<TaskManager, ServiceError> {
+fn inner_mandala_dev(
+	config: Configuration,
+	instant_sealing: bool,
+	enable_evm_debug: bool,
+) -> Result<TaskManager, ServiceError> {
 	let sc_service::PartialComponents {
 		client,
 		backend,
@@ -624,6 +690,13 @@ fn inner_mandala_dev(config: Configuration, instant_sealing: bool) -> Result<Tas
 
 	let prometheus_registry = config.prometheus_registry().cloned();
 
+	if let Some(registry) = prometheus_registry.clone() {
+		task_manager.spawn_handle().spawn(
+			"protocol-metrics",
+			metrics::run_protocol_metrics(client.clone(), registry),
+		);
+	}
+
 	let role = config.role.clone();
 	let force_authoring = config.force_authoring;
 	let backoff_authoring_blocks: Option<()> = None;
@@ -714,11 +787,13 @@ fn inner_mandala_dev(config: Configuration, instant_sealing: bool) -> Result<Tas
 		let client = client.clone();
 		let transaction_pool = transaction_pool.clone();
 
-		Box::new(move |deny_unsafe, _| {
+		Box::new(move |deny_unsafe, subscription_executor| {
 			let deps = acala_rpc::FullDeps {
 				client: client.clone(),
 				pool: transaction_pool.clone(),
 				deny_unsafe,
+				enable_evm_debug,
+				subscription_executor,
 			};
 
 			Ok(acala_rpc::create_full(deps))
@@ -746,6 +821,10 @@ fn inner_mandala_dev(config: Configuration, instant_sealing: bool) -> Result<Tas
 }
 
 #[cfg(feature = "with-mandala-runtime")]
-pub fn mandala_dev(config: Configuration, instant_sealing: bool) -> Result<TaskManager, ServiceError> {
-	inner_mandala_dev(config, instant_sealing)
+pub fn mandala_dev(
+	config: Configuration,
+	instant_sealing: bool,
+	enable_evm_debug: bool,
+) -> Result<TaskManager, ServiceError> {
+	inner_mandala_dev(config, instant_sealing, enable_evm_debug)
 }
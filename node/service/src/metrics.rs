@@ -0,0 +1,161 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Prometheus metrics of the protocol health, updated at every new best block.
+
+use acala_primitives::Block;
+use futures::StreamExt;
+use runtime_common::metrics::{ProtocolMetrics, ProtocolMetricsApi};
+use sc_client_api::BlockchainEvents;
+use sp_api::ProvideRuntimeApi;
+use sp_runtime::generic::BlockId;
+use std::sync::Arc;
+use substrate_prometheus_endpoint::{register, Gauge, GaugeVec, Opts, PrometheusError, Registry, F64, U64};
+
+const LOG_TARGET: &str = "protocol-metrics";
+
+/// The Prometheus gauges of the protocol metrics.
+struct Metrics {
+	open_cdps: GaugeVec<U64>,
+	total_debit_value: GaugeVec<F64>,
+	active_auctions: Gauge<U64>,
+	oracle_feed_age: GaugeVec<F64>,
+	homa_pending_redemptions: Gauge<F64>,
+	xcm_queue_depth: GaugeVec<U64>,
+}
+
+impl Metrics {
+	fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			open_cdps: register(
+				GaugeVec::new(
+					Opts::new("acala_cdp_open_positions", "Number of loan positions with debit"),
+					&["collateral"],
+				)?,
+				registry,
+			)?,
+			total_debit_value: register(
+				GaugeVec::new(
+					Opts::new(
+						"acala_cdp_total_debit_value",
+						"Value of the total debit, in stable currency",
+					),
+					&["collateral"],
+				)?,
+				registry,
+			)?,
+			active_auctions: register(
+				Gauge::new(
+					"acala_collateral_auctions_active",
+					"Number of collateral auctions in progress",
+				)?,
+				registry,
+			)?,
+			oracle_feed_age: register(
+				GaugeVec::new(
+					Opts::new(
+						"acala_oracle_feed_age_seconds",
+						"Seconds elapsed since the aggregated price was fed",
+					),
+					&["currency"],
+				)?,
+				registry,
+			)?,
+			homa_pending_redemptions: register(
+				Gauge::new(
+					"acala_homa_pending_redemptions",
+					"Liquid currency waiting in the Homa redeem requests",
+				)?,
+				registry,
+			)?,
+			xcm_queue_depth: register(
+				GaugeVec::new(
+					Opts::new(
+						"acala_xcm_queue_depth",
+						"Number of XCM messages, or DMP pages, waiting in the queue",
+					),
+					&["queue"],
+				)?,
+				registry,
+			)?,
+		})
+	}
+
+	fn update(&self, metrics: ProtocolMetrics) {
+		for collateral in metrics.collaterals {
+			let label = format!("{:?}", collateral.currency_id);
+			self.open_cdps
+				.with_label_values(&[&label])
+				.set(collateral.open_cdps.into());
+			self.total_debit_value
+				.with_label_values(&[&label])
+				.set(collateral.total_debit_value as f64);
+		}
+
+		self.active_auctions.set(metrics.active_auctions.into());
+
+		for (currency_id, age) in metrics.oracle_feed_ages {
+			self.oracle_feed_age
+				.with_label_values(&[&format!("{:?}", currency_id)])
+				.set(age as f64 / 1000.0);
+		}
+
+		self.homa_pending_redemptions
+			.set(metrics.homa_pending_redemptions as f64);
+
+		let depth = metrics.xcm_queue_depth;
+		for (queue, len) in [
+			("inbound_xcmp", depth.inbound_xcmp),
+			("outbound_xcmp", depth.outbound_xcmp),
+			("dmp_pages", depth.dmp_pages),
+		]
+		.iter()
+		{
+			self.xcm_queue_depth.with_label_values(&[queue]).set((*len).into());
+		}
+	}
+}
+
+/// Register the protocol metrics in `registry`, and update them with the
+/// state of every new best block of `client`.
+pub async fn run_protocol_metrics<C>(client: Arc<C>, registry: Registry)
+where
+	C: BlockchainEvents<Block> + ProvideRuntimeApi<Block>,
+	C::Api: ProtocolMetricsApi<Block>,
+{
+	let metrics = match Metrics::register(&registry) {
+		Ok(metrics) => metrics,
+		Err(err) => {
+			log::warn!(target: LOG_TARGET, "Failed to register the protocol metrics: {:?}", err);
+			return;
+		}
+	};
+
+	let mut imports = client.import_notification_stream();
+	while let Some(notification) = imports.next().await {
+		if !notification.is_new_best {
+			continue;
+		}
+
+		// the runtimes before the metrics runtime api can't be queried
+		match client.runtime_api().protocol_metrics(&BlockId::Hash(notification.hash)) {
+			Ok(protocol_metrics) => metrics.update(protocol_metrics),
+			Err(err) => log::debug!(target: LOG_TARGET, "Failed to query the protocol metrics: {:?}", err),
+		}
+	}
+}
@@ -0,0 +1,130 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Named state pruning profiles of the `--pruning` option.
+
+use sc_service::{config::PruningMode, Role};
+use std::{fmt, str::FromStr};
+
+/// The smallest number of finalized blocks whose state a collator keeps.
+pub const MIN_COLLATOR_PRUNING: u32 = 256;
+
+/// State pruning profile of the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruningProfile {
+	/// Keep the state of all blocks.
+	Archive,
+	/// Keep the state of all finalized blocks, the forks are pruned.
+	ArchiveCanonical,
+	/// Keep the state of the given number of last finalized blocks.
+	Constrained(u32),
+}
+
+impl FromStr for PruningProfile {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"archive" => Ok(Self::Archive),
+			"archive-canonical" => Ok(Self::ArchiveCanonical),
+			n => n.parse().map(Self::Constrained).map_err(|_| {
+				format!(
+					"Invalid pruning profile `{}`, expected `archive`, `archive-canonical` or a number of blocks",
+					s
+				)
+			}),
+		}
+	}
+}
+
+impl fmt::Display for PruningProfile {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Archive => write!(f, "archive"),
+			Self::ArchiveCanonical => write!(f, "archive-canonical"),
+			Self::Constrained(n) => write!(f, "{}", n),
+		}
+	}
+}
+
+impl From<PruningProfile> for PruningMode {
+	fn from(profile: PruningProfile) -> Self {
+		match profile {
+			PruningProfile::Archive => PruningMode::ArchiveAll,
+			PruningProfile::ArchiveCanonical => PruningMode::ArchiveCanonical,
+			PruningProfile::Constrained(n) => PruningMode::keep_blocks(n),
+		}
+	}
+}
+
+impl PruningProfile {
+	/// Returns `true` if the state of all finalized blocks is kept.
+	pub fn is_archive(&self) -> bool {
+		matches!(self, Self::Archive | Self::ArchiveCanonical)
+	}
+
+	/// Check the profile can be used by a node of `role`.
+	///
+	/// Collators must keep at least `MIN_COLLATOR_PRUNING` blocks, and the EVM
+	/// debug RPCs re-execute historical blocks so they need an archive node.
+	pub fn validate(&self, role: &Role, enable_evm_debug: bool) -> Result<(), String> {
+		match self {
+			Self::Constrained(n) if role.is_authority() && *n < MIN_COLLATOR_PRUNING => Err(format!(
+				"Collators must keep the state of at least {} blocks, got `--pruning {}`",
+				MIN_COLLATOR_PRUNING, n
+			)),
+			Self::Constrained(_) if enable_evm_debug => Err(format!(
+				"`--ethapi debug` needs the historical state, got `--pruning {}`, use `archive` or `archive-canonical`",
+				self
+			)),
+			_ => Ok(()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_pruning_profiles() {
+		assert_eq!("archive".parse(), Ok(PruningProfile::Archive));
+		assert_eq!("archive-canonical".parse(), Ok(PruningProfile::ArchiveCanonical));
+		assert_eq!("256".parse(), Ok(PruningProfile::Constrained(256)));
+		assert!("archive-all".parse::<PruningProfile>().is_err());
+		assert!("-1".parse::<PruningProfile>().is_err());
+
+		for profile in &["archive", "archive-canonical", "1000"] {
+			assert_eq!(profile.parse::<PruningProfile>().unwrap().to_string(), *profile);
+		}
+	}
+
+	#[test]
+	fn validate_pruning_profiles_per_role() {
+		let collator = Role::Authority;
+		assert_eq!(PruningProfile::Constrained(256).validate(&collator, false), Ok(()));
+		assert_eq!(PruningProfile::ArchiveCanonical.validate(&collator, false), Ok(()));
+		assert!(PruningProfile::Constrained(100).validate(&collator, false).is_err());
+
+		let full = Role::Full;
+		assert_eq!(PruningProfile::Constrained(100).validate(&full, false), Ok(()));
+		assert_eq!(PruningProfile::ArchiveCanonical.validate(&full, true), Ok(()));
+		assert_eq!(PruningProfile::Archive.validate(&full, true), Ok(()));
+		assert!(PruningProfile::Constrained(256).validate(&full, true).is_err());
+	}
+}
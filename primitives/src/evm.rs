@@ -81,6 +81,95 @@ pub struct EstimateResourcesRequest {
 	pub data: Option<Vec<u8>>,
 }
 
+/// The storage usage of a contract and the deposits backing it.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ContractStorageStatement<Balance> {
+	/// Bytes of storage used by the contract, including its code
+	pub used_storage: u32,
+	/// Storage deposit reserved by the contract
+	pub storage_deposit: Balance,
+	/// Storage deposit required by the used storage
+	pub required_deposit: Balance,
+	/// Developer deposit reserved by the maintainer
+	pub developer_deposit: Balance,
+}
+
+/// The kind of a traced call frame.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "UPPERCASE"))]
+pub enum CallType {
+	Call,
+	StaticCall,
+	DelegateCall,
+	Create,
+	SelfDestruct,
+}
+
+/// A call frame of a traced transaction, with its nested calls.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct CallTrace {
+	#[cfg_attr(feature = "std", serde(rename = "type"))]
+	pub call_type: CallType,
+	pub from: H160,
+	pub to: H160,
+	pub input: Vec<u8>,
+	pub value: U256,
+	/// The gas limit of the call frame
+	pub gas: u64,
+	pub output: Vec<u8>,
+	/// None if the call frame did not exit, e.g. selfdestruct
+	pub exit_reason: Option<ExitReason>,
+	pub calls: Vec<CallTrace>,
+}
+
+/// An executed opcode of a traced transaction.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Step {
+	pub pc: u64,
+	pub op: u8,
+	/// The depth of the call frame, starting from 1
+	pub depth: u32,
+	pub stack: Vec<H256>,
+	pub memory: Vec<u8>,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum TracerConfig {
+	/// Trace the call frames
+	CallTracer,
+	/// Trace every executed opcode
+	OpcodeTracer,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum TraceOutcome {
+	Calls(Vec<CallTrace>),
+	Steps(Vec<Step>),
+}
+
+/// The state of an account overridden before simulating a call.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct StateOverride {
+	/// Balance
+	pub balance: Option<Balance>,
+	/// Code
+	pub code: Option<Vec<u8>>,
+	/// Storage slots
+	pub state_diff: Option<Vec<(H256, H256)>>,
+}
+
 pub struct EthereumTransactionMessage {
 	pub nonce: Nonce,
 	pub tip: Balance,
@@ -92,4 +181,6 @@ pub struct EthereumTransactionMessage {
 	pub chain_id: u64,
 	pub genesis: H256,
 	pub valid_until: BlockNumber,
+	/// The current base fee per gas, not signed.
+	pub base_fee_per_gas: Balance,
 }
@@ -16,6 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::Balance;
 use codec::{Decode, Encode};
 use sp_runtime::{
 	traits::{Lazy, Verify},
@@ -38,6 +39,9 @@ pub enum AcalaMultiSignature {
 	Ethereum([u8; 65]),
 	// An Ethereum SECP256k1 signature using Eip712 for message encoding.
 	AcalaEip712([u8; 65]),
+	// An Ethereum SECP256k1 signature using Eip1559 for message encoding, and
+	// the max fee per gas of the transaction.
+	Eip1559([u8; 65], Balance),
 }
 
 impl From<ed25519::Signature> for AcalaMultiSignature {
@@ -19,10 +19,10 @@
 use crate::{
 	evm::{EthereumTransactionMessage, TransactionAction},
 	signature::AcalaMultiSignature,
-	Address,
+	Address, Balance, BlockNumber,
 };
 use codec::{Decode, Encode};
-use ethereum::LegacyTransactionMessage;
+use ethereum::{EIP1559TransactionMessage, LegacyTransactionMessage};
 use frame_support::{
 	traits::ExtrinsicCall,
 	weights::{DispatchInfo, GetDispatchInfo},
@@ -139,6 +139,42 @@ where
 					function,
 				})
 			}
+			Some((addr, AcalaMultiSignature::Eip1559(sig, max_fee_per_gas), extra)) => {
+				let function = self.0.function;
+				let eth_msg = ConvertTx::convert((function.clone(), extra.clone()))?;
+
+				let max_priority_fee_per_gas = eip1559_priority_fee_per_gas(&eth_msg, max_fee_per_gas)?;
+
+				let msg = EIP1559TransactionMessage {
+					chain_id: eth_msg.chain_id,
+					nonce: eth_msg.nonce.into(),
+					max_priority_fee_per_gas: max_priority_fee_per_gas.into(),
+					max_fee_per_gas: eip1559_max_fee_per_gas(
+						max_fee_per_gas,
+						eth_msg.storage_limit,
+						eth_msg.valid_until,
+					),
+					gas_limit: eth_msg.gas_limit.into(),
+					action: eth_msg.action,
+					value: eth_msg.value.into(),
+					input: eth_msg.input,
+					access_list: vec![],
+				};
+
+				let signer = recover_signer(&sig, msg.hash().as_fixed_bytes()).ok_or(InvalidTransaction::BadProof)?;
+
+				let acc = lookup.lookup(Address::Address20(signer.into()))?;
+				let expected = lookup.lookup(addr)?;
+
+				if acc != expected {
+					return Err(InvalidTransaction::BadProof.into());
+				}
+
+				Ok(CheckedExtrinsic {
+					signed: Some((acc, extra)),
+					function,
+				})
+			}
 			Some((addr, AcalaMultiSignature::AcalaEip712(sig), extra)) => {
 				let function = self.0.function;
 				let eth_msg = ConvertTx::convert((function.clone(), extra.clone()))?;
@@ -197,6 +233,40 @@ impl<'a, Call: Decode, Extra: SignedExtension, ConvertTx> serde::Deserialize<'a>
 	}
 }
 
+/// The max priority fee per gas of an EIP-1559 transaction, which is the
+/// substrate tip paid for the whole gas limit. The base fee and the priority
+/// fee must be covered by `max_fee_per_gas`.
+fn eip1559_priority_fee_per_gas(
+	eth_msg: &EthereumTransactionMessage,
+	max_fee_per_gas: Balance,
+) -> Result<Balance, InvalidTransaction> {
+	let max_priority_fee_per_gas = if eth_msg.gas_limit == 0 {
+		if eth_msg.tip != 0 {
+			return Err(InvalidTransaction::BadProof);
+		}
+		0
+	} else {
+		let gas_limit = eth_msg.gas_limit as u128;
+		if eth_msg.tip % gas_limit != 0 {
+			return Err(InvalidTransaction::BadProof);
+		}
+		eth_msg.tip / gas_limit
+	};
+
+	if eth_msg.base_fee_per_gas.saturating_add(max_priority_fee_per_gas) > max_fee_per_gas {
+		return Err(InvalidTransaction::Payment);
+	}
+	Ok(max_priority_fee_per_gas)
+}
+
+/// The `max_fee_per_gas` signed in EIP-1559 transactions. The storage limit
+/// and the valid until block are not in the transaction otherwise, so they
+/// are signed in it too: `valid_until` in the lowest 32 bits, `storage_limit`
+/// in the next 32 bits and the max fee per gas above them.
+pub fn eip1559_max_fee_per_gas(max_fee_per_gas: Balance, storage_limit: u32, valid_until: BlockNumber) -> U256 {
+	U256::from(max_fee_per_gas) << 64 | U256::from(storage_limit) << 32 | U256::from(valid_until)
+}
+
 fn recover_signer(sig: &[u8; 65], msg_hash: &[u8; 32]) -> Option<H160> {
 	secp256k1_ecdsa_recover(sig, msg_hash)
 		.map(|pubkey| H160::from(H256::from_slice(&keccak_256(&pubkey))))
@@ -264,6 +334,7 @@ mod tests {
 			chain_id: 595,
 			genesis: H256::from_str("0xc3751fc073ec83e6aa13e2be395d21b05dce0692618a129324261c80ede07d4c").unwrap(),
 			valid_until: 444,
+			base_fee_per_gas: 0,
 		}, hex_literal::hex!("acb56f12b407bd0bc8f7abefe2e2585affe28009abcb6980aa33aecb815c56b324ab60a41eff339a88631c4b0e5183427be1fcfde3c05fb9b6c71a691e977c4a1b"));
 
 		assert_eq!(
@@ -271,4 +342,43 @@ mod tests {
 			Some(H160::from_str("0x14791697260E4c9A71f18484C9f997B308e59325").unwrap())
 		);
 	}
+
+	#[test]
+	fn eip1559_fees_work() {
+		let eth_msg = |tip: Balance, base_fee_per_gas: Balance| EthereumTransactionMessage {
+			nonce: 1,
+			tip,
+			gas_limit: 100,
+			storage_limit: 333,
+			action: TransactionAction::Create,
+			value: 0,
+			input: vec![],
+			chain_id: 595,
+			genesis: H256::default(),
+			valid_until: 444,
+			base_fee_per_gas,
+		};
+
+		assert_eq!(eip1559_priority_fee_per_gas(&eth_msg(200, 8), 10), Ok(2));
+		// the tip is not a priority fee for the whole gas limit
+		assert_eq!(
+			eip1559_priority_fee_per_gas(&eth_msg(201, 8), 10),
+			Err(InvalidTransaction::BadProof)
+		);
+		// the base fee and the priority fee exceed the max fee
+		assert_eq!(
+			eip1559_priority_fee_per_gas(&eth_msg(300, 8), 10),
+			Err(InvalidTransaction::Payment)
+		);
+		// the base fee exceeds the max fee
+		assert_eq!(
+			eip1559_priority_fee_per_gas(&eth_msg(0, 11), 10),
+			Err(InvalidTransaction::Payment)
+		);
+
+		assert_eq!(
+			eip1559_max_fee_per_gas(10, 333, 444),
+			U256::from(10) * U256::from(u64::MAX as u128 + 1) + U256::from(333) * U256::from(1u64 << 32) + 444
+		);
+	}
 }
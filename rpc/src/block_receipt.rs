@@ -0,0 +1,173 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! RPC interface for the compact commitments of blocks, for bridges and
+//! light clients verifying the state.
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sc_client_api::BlockBackend;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_finality_grandpa::GRANDPA_ENGINE_ID;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, Hash as HashT, Header as HeaderT, NumberFor},
+};
+use std::sync::Arc;
+
+use crate::EventsRuntimeApi;
+
+/// The compact commitment of a block.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockReceipt<Hash, Number> {
+	/// The hash of the block.
+	pub hash: Hash,
+	/// The number of the block.
+	pub number: Number,
+	/// The hash of the parent block.
+	pub parent_hash: Hash,
+	/// The state root of the block.
+	pub state_root: Hash,
+	/// The extrinsics root of the block.
+	pub extrinsics_root: Hash,
+	/// The ordered trie root of the SCALE encoded `EventRecord`s selected by
+	/// the pallet index and the event index.
+	pub event_root: Hash,
+	/// The count of the selected events.
+	pub event_count: u32,
+	/// The SCALE encoded GRANDPA justification of the block, if any.
+	pub justification: Option<Bytes>,
+}
+
+/// Block receipt RPC methods.
+#[rpc]
+pub trait BlockReceiptApi<BlockHash, ResponseType> {
+	/// The compact commitment of block `at`, the events are selected by the
+	/// pallet index and the event index of the metadata.
+	#[rpc(name = "acala_getBlockReceipt")]
+	fn get_block_receipt(
+		&self,
+		pallet_index: Option<u8>,
+		event_index: Option<u8>,
+		at: Option<BlockHash>,
+	) -> Result<ResponseType>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to client or runtime failed.
+	RuntimeError,
+	/// The block is not found.
+	BlockNotFound,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::BlockNotFound => 2,
+		}
+	}
+}
+
+/// A struct that implements the [`BlockReceiptApi`].
+pub struct BlockReceipts<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> BlockReceipts<C, B> {
+	/// Create new `BlockReceipts` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		BlockReceipts {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block> BlockReceiptApi<<Block as BlockT>::Hash, BlockReceipt<<Block as BlockT>::Hash, NumberFor<Block>>>
+	for BlockReceipts<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockBackend<Block>,
+	C::Api: EventsRuntimeApi<Block>,
+{
+	fn get_block_receipt(
+		&self,
+		pallet_index: Option<u8>,
+		event_index: Option<u8>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<BlockReceipt<<Block as BlockT>::Hash, NumberFor<Block>>> {
+		let hash = at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		);
+		let at = BlockId::hash(hash);
+
+		let header = self
+			.client
+			.header(at)
+			.map_err(runtime_error_into_rpc_error)?
+			.ok_or(RpcError {
+				code: ErrorCode::ServerError(Error::BlockNotFound.into()),
+				message: "Block not found.".into(),
+				data: None,
+			})?;
+
+		let events = self
+			.client
+			.runtime_api()
+			.query_events(&at, pallet_index, event_index)
+			.map_err(runtime_error_into_rpc_error)?;
+		let event_count = events.len() as u32;
+		let event_root = <<Block as BlockT>::Header as HeaderT>::Hashing::ordered_trie_root(
+			events.into_iter().map(|event| event.record).collect(),
+		);
+
+		let justification = self
+			.client
+			.justifications(&at)
+			.map_err(runtime_error_into_rpc_error)?
+			.and_then(|justifications| justifications.into_justification(GRANDPA_ENGINE_ID))
+			.map(Into::into);
+
+		Ok(BlockReceipt {
+			hash,
+			number: *header.number(),
+			parent_hash: *header.parent_hash(),
+			state_root: *header.state_root(),
+			extrinsics_root: *header.extrinsics_root(),
+			event_root,
+			event_count,
+			justification,
+		})
+	}
+}
+
+/// Converts a client or runtime error into an RPC error.
+fn runtime_error_into_rpc_error(err: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: "Unable to get block receipt.".into(),
+		data: Some(format!("{:?}", err).into()),
+	}
+}
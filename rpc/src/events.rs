@@ -0,0 +1,111 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for querying the events of a block.
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use runtime_common::events::EncodedEvent;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::sync::Arc;
+
+pub use runtime_common::events::EventsApi as EventsRuntimeApi;
+
+/// An event of a block, with the indexes to look up its pallet and variant
+/// in the metadata.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventInfo {
+	/// Index of the event in the events of the block.
+	pub index: u32,
+	/// Index of the pallet which deposited the event.
+	pub pallet_index: u8,
+	/// Index of the event in the event enum of the pallet.
+	pub event_index: u8,
+	/// The SCALE encoded `EventRecord`.
+	pub record: Bytes,
+}
+
+impl From<EncodedEvent> for EventInfo {
+	fn from(event: EncodedEvent) -> Self {
+		EventInfo {
+			index: event.index,
+			pallet_index: event.pallet_index,
+			event_index: event.event_index,
+			record: event.record.into(),
+		}
+	}
+}
+
+/// Events RPC methods.
+#[rpc]
+pub trait EventsApi<BlockHash> {
+	/// The events of block `at`, filtered by the pallet index and the event
+	/// index of the metadata.
+	#[rpc(name = "events_query")]
+	fn query(&self, pallet_index: Option<u8>, event_index: Option<u8>, at: Option<BlockHash>)
+		-> Result<Vec<EventInfo>>;
+}
+
+/// A struct that implements the [`EventsApi`].
+pub struct Events<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Events<C, B> {
+	/// Create new `Events` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Events {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block> EventsApi<<Block as BlockT>::Hash> for Events<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: EventsRuntimeApi<Block>,
+{
+	fn query(
+		&self,
+		pallet_index: Option<u8>,
+		event_index: Option<u8>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<EventInfo>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.query_events(&at, pallet_index, event_index)
+			.map(|events| events.into_iter().map(Into::into).collect())
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(1),
+				message: "Unable to query events.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+}
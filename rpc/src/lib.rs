@@ -20,8 +20,12 @@
 
 #![warn(missing_docs)]
 
-use primitives::{AccountId, Balance, Block, CurrencyId, DataProviderId, Hash, Nonce};
-use sc_client_api::light::{Fetcher, RemoteBlockchain};
+use module_support::PoolId;
+use primitives::{AccountId, Balance, Block, BlockNumber, CurrencyId, DataProviderId, Hash, Moment, Nonce};
+use sc_client_api::{
+	light::{Fetcher, RemoteBlockchain},
+	BlockBackend, BlockchainEvents,
+};
 pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
@@ -31,7 +35,19 @@ use std::sync::Arc;
 
 pub use sc_rpc::SubscriptionTaskExecutor;
 
-pub use evm_rpc::{EVMApi, EVMApiServer, EVMRuntimeRPCApi};
+pub use evm_rpc::{EVMApi, EVMApiServer, EVMDebugApi, EVMDebugApiServer, EVMRuntimeRPCApi};
+
+mod block_receipt;
+pub use block_receipt::{BlockReceipt, BlockReceiptApi, BlockReceipts};
+
+mod events;
+pub use events::{EventInfo, Events, EventsApi, EventsRuntimeApi};
+
+mod oracle;
+pub use oracle::{OracleFeeds, OracleFeedsApi, OracleFeedsRuntimeApi};
+
+mod storage_diff;
+pub use storage_diff::{StorageDiff, StorageDiffApi};
 
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpc_core::IoHandler<sc_rpc::Metadata>;
@@ -56,6 +72,10 @@ pub struct FullDeps<C, P> {
 	pub pool: Arc<P>,
 	/// Whether to deny unsafe calls
 	pub deny_unsafe: DenyUnsafe,
+	/// Whether to enable the EVM debug RPCs
+	pub enable_evm_debug: bool,
+	/// Executor to drive the subscriptions
+	pub subscription_executor: SubscriptionTaskExecutor,
 }
 
 /// Instantiate all Full RPC extensions.
@@ -63,16 +83,27 @@ pub fn create_full<C, P>(deps: FullDeps<C, P>) -> RpcExtension
 where
 	C: ProvideRuntimeApi<Block>,
 	C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError>,
+	C: BlockBackend<Block> + BlockchainEvents<Block>,
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, runtime_common::TimeStampedPrice>,
+	C::Api: OracleFeedsRuntimeApi<Block, DataProviderId, CurrencyId, AccountId, Moment>,
 	C::Api: module_staking_pool_rpc::StakingPoolRuntimeApi<Block, AccountId, Balance>,
+	C::Api: module_cdp_engine_rpc::CDPEngineRuntimeApi<Block, AccountId, CurrencyId, Balance>,
+	C::Api: module_dex_rpc::DexRuntimeApi<Block, CurrencyId, BlockNumber, Balance>,
+	C::Api: module_asset_registry_rpc::PortfolioRuntimeApi<Block, AccountId, CurrencyId, PoolId, Balance>,
+	C::Api: module_transaction_payment_rpc::TransactionFeeRuntimeApi<Block, Balance, CurrencyId>,
 	C::Api: EVMRuntimeRPCApi<Block, Balance>,
+	C::Api: EventsRuntimeApi<Block>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
+	use module_asset_registry_rpc::{Assets, AssetsApi};
+	use module_cdp_engine_rpc::{CDPEngine, CDPEngineApi};
+	use module_dex_rpc::{Dex, DexApi};
 	use module_staking_pool_rpc::{StakingPool, StakingPoolApi};
+	use module_transaction_payment_rpc::{TransactionFee, TransactionFeeApi};
 	use orml_oracle_rpc::{Oracle, OracleApi};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
 	use substrate_frame_rpc_system::{FullSystem, SystemApi};
@@ -82,6 +113,8 @@ where
 		client,
 		pool,
 		deny_unsafe,
+		enable_evm_debug,
+		subscription_executor,
 	} = deps;
 
 	io.extend_with(SystemApi::to_delegate(FullSystem::new(
@@ -96,8 +129,23 @@ where
 	// more context: https://github.com/paritytech/substrate/pull/3480
 	// These RPCs should use an asynchronous caller instead.
 	io.extend_with(OracleApi::to_delegate(Oracle::new(client.clone())));
+	io.extend_with(OracleFeedsApi::to_delegate(OracleFeeds::new(client.clone())));
 	io.extend_with(StakingPoolApi::to_delegate(StakingPool::new(client.clone())));
-	io.extend_with(EVMApiServer::to_delegate(EVMApi::new(client, deny_unsafe)));
+	io.extend_with(CDPEngineApi::to_delegate(CDPEngine::new(client.clone())));
+	io.extend_with(DexApi::to_delegate(Dex::new(client.clone())));
+	io.extend_with(AssetsApi::to_delegate(Assets::new(client.clone())));
+	io.extend_with(TransactionFeeApi::to_delegate(TransactionFee::new(client.clone())));
+	io.extend_with(EVMApiServer::to_delegate(EVMApi::new(client.clone(), deny_unsafe)));
+	io.extend_with(EventsApi::to_delegate(Events::new(client.clone())));
+	io.extend_with(BlockReceiptApi::to_delegate(BlockReceipts::new(client.clone())));
+	io.extend_with(StorageDiffApi::to_delegate(StorageDiff::new(
+		client.clone(),
+		subscription_executor,
+	)));
+
+	if enable_evm_debug {
+		io.extend_with(EVMDebugApiServer::to_delegate(EVMDebugApi::new(client, deny_unsafe)));
+	}
 
 	io
 }
@@ -0,0 +1,92 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! RPC interface for the raw prices fed by the oracle operators.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use runtime_common::FeedDetails;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::sync::Arc;
+
+pub use runtime_common::OracleFeedsApi as OracleFeedsRuntimeApi;
+
+/// Oracle feeds RPC methods, complementing the combined values served by the
+/// `oracle_getValue` and `oracle_getAllValues` methods.
+#[rpc]
+pub trait OracleFeedsApi<BlockHash, ProviderId, Key, ResponseType> {
+	/// The raw prices fed by the oracle operators of `provider_id` for `key`,
+	/// with their timestamps and whether they are counted in the combined
+	/// price.
+	#[rpc(name = "oracle_getFeedDetails")]
+	fn get_feed_details(
+		&self,
+		provider_id: ProviderId,
+		key: Key,
+		at: Option<BlockHash>,
+	) -> Result<Option<ResponseType>>;
+}
+
+/// A struct that implements the [`OracleFeedsApi`].
+pub struct OracleFeeds<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> OracleFeeds<C, B> {
+	/// Create new `OracleFeeds` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		OracleFeeds {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block, ProviderId, Key, AccountId, Moment>
+	OracleFeedsApi<<Block as BlockT>::Hash, ProviderId, Key, FeedDetails<AccountId, Moment>> for OracleFeeds<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: OracleFeedsRuntimeApi<Block, ProviderId, Key, AccountId, Moment>,
+	ProviderId: Codec,
+	Key: Codec,
+	AccountId: Codec,
+	Moment: Codec,
+{
+	fn get_feed_details(
+		&self,
+		provider_id: ProviderId,
+		key: Key,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<FeedDetails<AccountId, Moment>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_feed_details(&at, provider_id, key).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(1),
+			message: "Unable to get oracle feed details.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+}
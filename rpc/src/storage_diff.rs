@@ -0,0 +1,137 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! RPC interface for subscribing the storage changes under key prefixes.
+
+use futures::{future, FutureExt, SinkExt, StreamExt};
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use jsonrpc_pubsub::{manager::SubscriptionManager, typed::Subscriber, SubscriptionId};
+use log::warn;
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
+use sp_core::storage::{StorageChangeSet, StorageKey};
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+/// Storage diff RPC methods.
+#[rpc]
+pub trait StorageDiffApi<BlockHash> {
+	/// RPC metadata
+	type Metadata;
+
+	/// Subscribe to the keys changed under any of `prefixes` in every imported
+	/// block, along with their new values. Blocks without such changes are
+	/// not notified.
+	#[pubsub(subscription = "acala_storageDiff", subscribe, name = "acala_subscribeStorageDiff")]
+	fn subscribe_storage_diff(
+		&self,
+		metadata: Self::Metadata,
+		subscriber: Subscriber<StorageChangeSet<BlockHash>>,
+		prefixes: Vec<StorageKey>,
+	);
+
+	/// Unsubscribe from the storage diff subscription.
+	#[pubsub(
+		subscription = "acala_storageDiff",
+		unsubscribe,
+		name = "acala_unsubscribeStorageDiff"
+	)]
+	fn unsubscribe_storage_diff(&self, metadata: Option<Self::Metadata>, id: SubscriptionId) -> Result<bool>;
+}
+
+/// A struct that implements the [`StorageDiffApi`].
+pub struct StorageDiff<C, B> {
+	client: Arc<C>,
+	subscriptions: SubscriptionManager,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> StorageDiff<C, B> {
+	/// Create new `StorageDiff` with the given reference to the client, the
+	/// subscriptions are driven by `executor`.
+	pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
+		StorageDiff {
+			client,
+			subscriptions: SubscriptionManager::new(Arc::new(executor)),
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block> StorageDiffApi<<Block as BlockT>::Hash> for StorageDiff<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + BlockchainEvents<Block>,
+{
+	type Metadata = sc_rpc::Metadata;
+
+	fn subscribe_storage_diff(
+		&self,
+		_metadata: Self::Metadata,
+		subscriber: Subscriber<StorageChangeSet<<Block as BlockT>::Hash>>,
+		prefixes: Vec<StorageKey>,
+	) {
+		if prefixes.is_empty() {
+			let _ = subscriber.reject(RpcError {
+				code: ErrorCode::InvalidParams,
+				message: "No storage key prefix to subscribe.".into(),
+				data: None,
+			});
+			return;
+		}
+
+		// the notifications can't be filtered by prefixes, subscribe all the changes
+		// and filter them here.
+		let stream = match self.client.storage_changes_notification_stream(None, None) {
+			Ok(stream) => stream,
+			Err(e) => {
+				let _ = subscriber.reject(RpcError {
+					code: ErrorCode::ServerError(1),
+					message: "Unable to subscribe storage changes.".into(),
+					data: Some(format!("{:?}", e).into()),
+				});
+				return;
+			}
+		};
+
+		self.subscriptions.add(subscriber, |sink| {
+			stream
+				.map(move |(block, changes)| StorageChangeSet {
+					block,
+					changes: changes
+						.iter()
+						.filter_map(|(child_key, key, value)| match child_key {
+							None if prefixes.iter().any(|prefix| key.0.starts_with(&prefix.0)) => {
+								Some((key.clone(), value.cloned()))
+							}
+							_ => None,
+						})
+						.collect(),
+				})
+				.filter(|diff| future::ready(!diff.changes.is_empty()))
+				.map(|diff| Ok(Ok::<_, RpcError>(diff)))
+				.forward(sink.sink_map_err(|e| warn!("Error sending notifications: {:?}", e)))
+				// we ignore the resulting Stream (if the first stream is over we are unsubscribed)
+				.map(|_| ())
+		});
+	}
+
+	fn unsubscribe_storage_diff(&self, _metadata: Option<Self::Metadata>, id: SubscriptionId) -> Result<bool> {
+		Ok(self.subscriptions.cancel(id))
+	}
+}
@@ -39,7 +39,10 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H160};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdConversion, BadOrigin, BlakeTwo256, Block as BlockT, SaturatedConversion, StaticLookup, Zero},
+	traits::{
+		AccountIdConversion, BadOrigin, BlakeTwo256, Block as BlockT, BlockNumberProvider, SaturatedConversion,
+		StaticLookup, Zero,
+	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, DispatchResult, FixedPointNumber,
 };
@@ -51,7 +54,7 @@ use sp_version::RuntimeVersion;
 use frame_system::{EnsureRoot, RawOrigin};
 use module_currencies::{BasicCurrencyAdapter, Currency};
 use module_evm::Runner;
-use module_evm::{CallInfo, CreateInfo};
+use module_evm::{CallInfo, ContractStorageStatement, CreateInfo, StateOverride, TraceOutcome, TracerConfig};
 use module_evm_accounts::EvmAddressMapping;
 use module_evm_manager::EvmCurrencyIdMapping;
 use module_transaction_payment::{Multiplier, TargetedFeeAdjustment};
@@ -100,7 +103,7 @@ pub use primitives::{
 	TokenSymbol, TradingPair,
 };
 pub use runtime_common::{
-	cent, dollar, microcent, millicent, CurveFeeModel, EnsureRootOrAllGeneralCouncil,
+	cent, dollar, microcent, millicent, AggregatedCombineData, CurveFeeModel, EnsureRootOrAllGeneralCouncil,
 	EnsureRootOrAllTechnicalCommittee, EnsureRootOrHalfFinancialCouncil, EnsureRootOrHalfGeneralCouncil,
 	EnsureRootOrHalfHomaCouncil, EnsureRootOrOneGeneralCouncil, EnsureRootOrOneThirdsTechnicalCommittee,
 	EnsureRootOrThreeFourthsGeneralCouncil, EnsureRootOrTwoThirdsGeneralCouncil,
@@ -256,6 +259,8 @@ parameter_types! {
 	pub const MaxInvulnerables: u32 = 50;
 	pub const KickPenaltySessionLength: u32 = 8;
 	pub const CollatorKickThreshold: Permill = Permill::from_percent(50);
+	pub const CollatorKickMissedSessions: u32 = 2;
+	pub const CollatorKickSlash: Permill = Permill::from_percent(10);
 }
 
 impl module_collator_selection::Config for Runtime {
@@ -269,6 +274,8 @@ impl module_collator_selection::Config for Runtime {
 	type MaxInvulnerables = MaxInvulnerables;
 	type KickPenaltySessionLength = KickPenaltySessionLength;
 	type CollatorKickThreshold = CollatorKickThreshold;
+	type CollatorKickMissedSessions = CollatorKickMissedSessions;
+	type CollatorKickSlash = CollatorKickSlash;
 	type WeightInfo = weights::module_collator_selection::WeightInfo<Runtime>;
 }
 
@@ -715,13 +722,13 @@ parameter_types! {
 type AcalaDataProvider = orml_oracle::Instance1;
 impl orml_oracle::Config<AcalaDataProvider> for Runtime {
 	type Event = Event;
-	type OnNewData = ();
-	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider>;
+	type OnNewData = Prices;
+	type CombineData = AggregatedCombineData<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider, Prices>;
 	type Time = Timestamp;
 	type OracleKey = CurrencyId;
 	type OracleValue = Price;
 	type RootOperatorAccountId = ZeroAccountId;
-	type Members = OperatorMembershipAcala;
+	type Members = module_prices::ActiveOracleOperators<Runtime>;
 	type MaxHasDispatchedSize = MaxHasDispatchedSize;
 	type WeightInfo = weights::orml_oracle::WeightInfo<Runtime>;
 }
@@ -730,7 +737,7 @@ type BandDataProvider = orml_oracle::Instance2;
 impl orml_oracle::Config<BandDataProvider> for Runtime {
 	type Event = Event;
 	type OnNewData = ();
-	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, BandDataProvider>;
+	type CombineData = AggregatedCombineData<Runtime, MinimumCount, ExpiresIn, BandDataProvider, Prices>;
 	type Time = Timestamp;
 	type OracleKey = CurrencyId;
 	type OracleValue = Price;
@@ -785,6 +792,12 @@ impl orml_tokens::Config for Runtime {
 
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
+	pub const FeedWindow: BlockNumber = 10 * MINUTES;
+	pub const MaxMissedFeedWindows: u32 = 6;
+	pub MaxPriceChangeRatio: Ratio = Ratio::saturating_from_rational(30, 100);
+	pub const CircuitBreakerWindow: BlockNumber = HOURS;
+	pub const CircuitBreakerCooldown: BlockNumber = 6 * HOURS;
+	pub const RemotePriceStaleness: BlockNumber = HOURS;
 }
 
 impl module_prices::Config for Runtime {
@@ -799,6 +812,14 @@ impl module_prices::Config for Runtime {
 	type DEX = Dex;
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
+	type OracleOperators = OperatorMembershipAcala;
+	type FeedWindow = FeedWindow;
+	type MaxMissedFeedWindows = MaxMissedFeedWindows;
+	type MaxPriceChangeRatio = MaxPriceChangeRatio;
+	type CircuitBreakerWindow = CircuitBreakerWindow;
+	type CircuitBreakerCooldown = CircuitBreakerCooldown;
+	type RemoteOracleOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type RemotePriceStaleness = RemotePriceStaleness;
 	type WeightInfo = weights::module_prices::WeightInfo<Runtime>;
 }
 
@@ -834,7 +855,10 @@ impl EnsureOrigin<Origin> for EnsureRootOrTreasury {
 		Into::<Result<RawOrigin<AccountId>, Origin>>::into(o).and_then(|o| match o {
 			RawOrigin::Root => Ok(TreasuryPalletId::get().into_account()),
 			RawOrigin::Signed(caller) => {
-				if caller == TreasuryPalletId::get().into_account() {
+				// incentives pays the vested rewards
+				if caller == TreasuryPalletId::get().into_account()
+					|| caller == IncentivesPalletId::get().into_account()
+				{
 					Ok(caller)
 				} else {
 					Err(Origin::from(Some(caller)))
@@ -906,6 +930,8 @@ parameter_types! {
 	pub DefaultSwapParitalPathList: Vec<Vec<CurrencyId>> = vec![
 		vec![GetStableCurrencyId::get()],
 	];
+	pub DutchAuctionStartingPremium: Rate = Rate::saturating_from_rational(10, 100);
+	pub DutchAuctionPriceDecay: Rate = Rate::saturating_from_rational(1, 1000);
 }
 
 impl module_auction_manager::Config for Runtime {
@@ -919,9 +945,14 @@ impl module_auction_manager::Config for Runtime {
 	type CDPTreasury = CdpTreasury;
 	type DEX = Dex;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type PriceCircuitBreaker = Prices;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type CollateralAuctionTypes = module_cdp_engine::CollateralAuctionTypeOf<Runtime>;
+	type DutchAuctionStartingPremium = DutchAuctionStartingPremium;
+	type DutchAuctionPriceDecay = DutchAuctionPriceDecay;
+	type RiskParameters = ();
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -1001,11 +1032,14 @@ parameter_types! {
 	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(5, 100);
 	pub MinimumDebitValue: Balance = dollar(AUSD);
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(5, 100);
+	pub PartialLiquidationTarget: Option<Ratio> = None;
+	pub KeeperIncentiveRate: Rate = Rate::saturating_from_rational(1, 10);
 }
 
 impl module_cdp_engine::Config for Runtime {
 	type Event = Event;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type PriceCircuitBreaker = Prices;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -1013,8 +1047,12 @@ impl module_cdp_engine::Config for Runtime {
 	type MinimumDebitValue = MinimumDebitValue;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CdpTreasury;
+	type StabilityPool = ();
+	type RiskParameters = ();
 	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
+	type PartialLiquidationTarget = PartialLiquidationTarget;
+	type KeeperIncentiveRate = KeeperIncentiveRate;
 	type UnsignedPriority = runtime_common::CdpEngineUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
 	type UnixTime = Timestamp;
@@ -1024,12 +1062,14 @@ impl module_cdp_engine::Config for Runtime {
 
 parameter_types! {
 	pub DepositPerAuthorization: Balance = dollar(ACA);
+	pub TopUpRewardRate: Rate = Rate::saturating_from_rational(1, 100);
 }
 
 impl module_honzon::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
 	type DepositPerAuthorization = DepositPerAuthorization;
+	type TopUpRewardRate = TopUpRewardRate;
 	type WeightInfo = weights::module_honzon::WeightInfo<Runtime>;
 }
 
@@ -1037,6 +1077,7 @@ impl module_emergency_shutdown::Config for Runtime {
 	type Event = Event;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type PriceSource = Prices;
+	type LockedPriceSource = module_prices::LockedPriceProvider<Runtime>;
 	type CDPTreasury = CdpTreasury;
 	type AuctionManagerHandler = AuctionManager;
 	type ShutdownOrigin = EnsureRootOrHalfGeneralCouncil;
@@ -1046,6 +1087,9 @@ impl module_emergency_shutdown::Config for Runtime {
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 1000);	// 0.1%
 	pub const TradingPathLimit: u32 = 3;
+	pub RoutingCurrencies: Vec<CurrencyId> = vec![AUSD, DOT];
+	pub const TWAPObservationPeriod: BlockNumber = 10 * MINUTES;
+	pub const MaxTWAPObservations: u32 = 144;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, ACA).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
@@ -1059,6 +1103,9 @@ impl module_dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type RoutingCurrencies = RoutingCurrencies;
+	type TWAPObservationPeriod = TWAPObservationPeriod;
+	type MaxTWAPObservations = MaxTWAPObservations;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
 	type DEXIncentives = Incentives;
@@ -1075,12 +1122,14 @@ impl module_cdp_treasury::Config for Runtime {
 	type Event = Event;
 	type Currency = Currencies;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type AuctionManagerHandler = AuctionManager;
 	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type DEX = Dex;
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = HonzonTreasuryAccount;
+	type IdleScheduler = ();
 	type WeightInfo = weights::module_cdp_treasury::WeightInfo<Runtime>;
 }
 
@@ -1103,6 +1152,29 @@ impl OnUnbalanced<NegativeImbalance> for DealWithFees {
 	}
 }
 
+/// Pay the fee of `swap_with_exact_target_and_fee_in_supply` with the supply
+/// currency of the trading path.
+pub struct DexCallFeeSwapPath;
+impl module_support::CallFeeSwapPath<Call, CurrencyId> for DexCallFeeSwapPath {
+	fn fee_swap_path_list(call: &Call) -> Vec<Vec<CurrencyId>> {
+		let native_currency_id = GetNativeCurrencyId::get();
+		let stable_currency_id = GetStableCurrencyId::get();
+		match call {
+			Call::Dex(module_dex::Call::swap_with_exact_target_and_fee_in_supply(path, ..)) => match path.first() {
+				Some(&supply_currency_id) if supply_currency_id == stable_currency_id => {
+					vec![vec![supply_currency_id, native_currency_id]]
+				}
+				Some(&supply_currency_id) if supply_currency_id != native_currency_id => vec![
+					vec![supply_currency_id, native_currency_id],
+					vec![supply_currency_id, stable_currency_id, native_currency_id],
+				],
+				_ => vec![],
+			},
+			_ => vec![],
+		}
+	}
+}
+
 impl module_transaction_payment::Config for Runtime {
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type DefaultFeeSwapPathList = DefaultFeeSwapPathList;
@@ -1116,6 +1188,10 @@ impl module_transaction_payment::Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type CallFeeSwapPath = DexCallFeeSwapPath;
+	type Call = Call;
+	type SponsorSignature = Signature;
+	type SponsorSigner = <Signature as sp_runtime::traits::Verify>::Signer;
 	type WeightInfo = weights::module_transaction_payment::WeightInfo<Runtime>;
 }
 
@@ -1127,9 +1203,17 @@ impl module_evm_accounts::Config for Runtime {
 	type WeightInfo = weights::module_evm_accounts::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub Erc20RegistrationDeposit: Balance = 100 * dollar(ACA);
+}
+
 impl module_evm_manager::Config for Runtime {
+	type Event = Event;
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type Erc20RegistrationDeposit = Erc20RegistrationDeposit;
+	type VetoOrigin = EnsureRootOrTwoThirdsTechnicalCommittee;
+	type WeightInfo = weights::module_evm_manager::WeightInfo<Runtime>;
 }
 
 impl orml_rewards::Config for Runtime {
@@ -1142,6 +1226,29 @@ impl orml_rewards::Config for Runtime {
 
 parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = MINUTES;
+	pub const LoyaltyPeriod: BlockNumber = 7 * DAYS;
+}
+
+pub struct IncentivesVestedTransfer;
+impl module_support::VestedTransfer<AccountId, BlockNumber, Balance> for IncentivesVestedTransfer {
+	fn vested_transfer(
+		from: &AccountId,
+		to: &AccountId,
+		period: BlockNumber,
+		period_count: u32,
+		per_period: Balance,
+	) -> DispatchResult {
+		Vesting::vested_transfer(
+			RawOrigin::Signed(from.clone()).into(),
+			<Runtime as frame_system::Config>::Lookup::unlookup(to.clone()),
+			orml_vesting::VestingSchedule {
+				start: RelaychainBlockNumberProvider::<Runtime>::current_block_number(),
+				period,
+				period_count,
+				per_period,
+			},
+		)
+	}
 }
 
 impl module_incentives::Config for Runtime {
@@ -1149,11 +1256,14 @@ impl module_incentives::Config for Runtime {
 	type RewardsSource = UnreleasedNativeVaultAccountId;
 	type StableCurrencyId = GetStableCurrencyId;
 	type AccumulatePeriod = AccumulatePeriod;
+	type LoyaltyPeriod = LoyaltyPeriod;
+	type NativeCurrencyId = GetNativeCurrencyId;
 	type UpdateOrigin = EnsureRootOrThreeFourthsGeneralCouncil;
 	type CDPTreasury = CdpTreasury;
 	type Currency = Currencies;
 	type DEX = Dex;
 	type EmergencyShutdown = EmergencyShutdown;
+	type VestedTransfer = IncentivesVestedTransfer;
 	type PalletId = IncentivesPalletId;
 	type WeightInfo = weights::module_incentives::WeightInfo<Runtime>;
 }
@@ -1162,6 +1272,7 @@ parameter_types! {
 	pub const PolkadotBondingDuration: EraIndex = 7;
 	pub const EraLength: BlockNumber = DAYS;
 	pub const MaxUnbonding: u32 = 1000;
+	pub EstimatedStakingRewardRatePerEra: Permill = Permill::from_rational(383u32, 1_000_000u32); // 1.15 ^ (1/365) = 1.000383
 }
 
 impl module_polkadot_bridge::Config for Runtime {
@@ -1178,6 +1289,7 @@ parameter_types! {
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
 	pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(10, 100);	// 1 : 10
 	pub PoolAccountIndexes: Vec<u32> = vec![1, 2, 3, 4];
+	pub const UnbondingReceiptClassId: u32 = 0;
 }
 
 impl module_staking_pool::Config for Runtime {
@@ -1192,6 +1304,8 @@ impl module_staking_pool::Config for Runtime {
 	type Nominees = NomineesElection;
 	type Bridge = PolkadotBridge;
 	type Currency = Currencies;
+	type UnbondingReceipt = NFT;
+	type UnbondingReceiptClassId = UnbondingReceiptClassId;
 }
 
 impl module_homa::Config for Runtime {
@@ -1204,6 +1318,7 @@ parameter_types! {
 	pub const NominateesCount: u32 = 7;
 	pub const MaxUnlockingChunks: u32 = 7;
 	pub const NomineesElectionBondingDuration: EraIndex = 7;
+	pub const SlashedNomineeCooldown: EraIndex = 28;
 }
 
 impl module_nominees_election::Config for Runtime {
@@ -1216,6 +1331,8 @@ impl module_nominees_election::Config for Runtime {
 	type NominateesCount = NominateesCount;
 	type MaxUnlockingChunks = MaxUnlockingChunks;
 	type NomineeFilter = runtime_common::DummyNomineeFilter;
+	type PerformanceOrigin = EnsureRootOrHalfHomaCouncil;
+	type SlashedNomineeCooldown = SlashedNomineeCooldown;
 	type WeightInfo = weights::module_nominees_election::WeightInfo<Runtime>;
 }
 
@@ -1245,6 +1362,7 @@ parameter_types! {
 	pub CreateClassDeposit: Balance = 500 * millicent(ACA);
 	pub CreateTokenDeposit: Balance = 100 * millicent(ACA);
 	pub MaxAttributesBytes: u32 = 2048;
+	pub const MaxNftBatchItems: u32 = 100;
 }
 
 impl module_nft::Config for Runtime {
@@ -1254,7 +1372,9 @@ impl module_nft::Config for Runtime {
 	type CreateTokenDeposit = CreateTokenDeposit;
 	type DataDepositPerByte = DataDepositPerByte;
 	type MaxAttributesBytes = MaxAttributesBytes;
+	type MaxBatchItems = MaxNftBatchItems;
 	type PalletId = NftPalletId;
+	type MultiCurrency = Currencies;
 	type WeightInfo = weights::module_nft::WeightInfo<Runtime>;
 }
 
@@ -1334,6 +1454,8 @@ parameter_types! {
 	pub NetworkContractSource: H160 = H160::from_low_u64_be(0);
 	pub DeveloperDeposit: Balance = dollar(ACA);
 	pub DeploymentFee: Balance = dollar(ACA);
+	pub PublicationFee: Balance = dollar(ACA);
+	pub const BaseFeeHistoryLength: u32 = 1024;
 }
 
 pub type MultiCurrencyPrecompile = runtime_common::MultiCurrencyPrecompile<
@@ -1367,6 +1489,23 @@ pub type ScheduleCallPrecompile = runtime_common::ScheduleCallPrecompile<
 
 pub type DexPrecompile =
 	runtime_common::DexPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Dex>;
+pub type HomaPrecompile =
+	runtime_common::HomaPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, StakingPool>;
+pub type HonzonPrecompile =
+	runtime_common::HonzonPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Honzon>;
+pub type IncentivesPrecompile = runtime_common::IncentivesPrecompile<
+	AccountId,
+	EvmAddressMapping<Runtime>,
+	EvmCurrencyIdMapping<Runtime>,
+	Incentives,
+>;
+// XTokens is not enabled yet, the XCM transfers from EVM are rejected.
+pub type XtokensPrecompile = runtime_common::XtokensPrecompile<
+	AccountId,
+	EvmAddressMapping<Runtime>,
+	EvmCurrencyIdMapping<Runtime>,
+	runtime_common::DisabledXcmTransfer,
+>;
 
 impl module_evm::Config for Runtime {
 	type AddressMapping = EvmAddressMapping<Runtime>;
@@ -1377,12 +1516,17 @@ impl module_evm::Config for Runtime {
 	type Event = Event;
 	type Precompiles = runtime_common::AllPrecompiles<
 		SystemContractsFilter,
+		(),
 		MultiCurrencyPrecompile,
 		NFTPrecompile,
 		StateRentPrecompile,
 		OraclePrecompile,
 		ScheduleCallPrecompile,
 		DexPrecompile,
+		HomaPrecompile,
+		HonzonPrecompile,
+		IncentivesPrecompile,
+		XtokensPrecompile,
 	>;
 	type ChainId = ChainId;
 	type GasToWeight = GasToWeight;
@@ -1391,10 +1535,12 @@ impl module_evm::Config for Runtime {
 	type NetworkContractSource = NetworkContractSource;
 	type DeveloperDeposit = DeveloperDeposit;
 	type DeploymentFee = DeploymentFee;
+	type PublicationFee = PublicationFee;
 	type TreasuryAccount = TreasuryAccount;
 	type FreeDeploymentOrigin = EnsureRootOrHalfGeneralCouncil;
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
+	type BaseFeeHistoryLength = BaseFeeHistoryLength;
 	type WeightInfo = weights::module_evm::WeightInfo<Runtime>;
 }
 
@@ -1651,7 +1797,7 @@ construct_runtime!(
 
 		// Acala Other
 		Incentives: module_incentives::{Pallet, Storage, Call, Event<T>} = 140,
-		NFT: module_nft::{Pallet, Call, Event<T>} = 141,
+		NFT: module_nft::{Pallet, Call, Storage, Event<T>} = 141,
 
 		// Ecosystem modules
 		RenVmBridge: ecosystem_renvm_bridge::{Pallet, Call, Config, Storage, Event<T>, ValidateUnsigned} = 150,
@@ -1669,7 +1815,7 @@ construct_runtime!(
 		EVM: module_evm::{Pallet, Config<T>, Call, Storage, Event<T>} = 180,
 		EVMBridge: module_evm_bridge::{Pallet} = 181,
 		EvmAccounts: module_evm_accounts::{Pallet, Call, Storage, Event<T>} = 182,
-		EvmManager: module_evm_manager::{Pallet, Storage} = 183,
+		EvmManager: module_evm_manager::{Pallet, Call, Storage, Event<T>} = 183,
 
 		// Collator support. the order of these 4 are important and shall not change.
 		Authorship: pallet_authorship::{Pallet, Call, Storage} = 190,
@@ -1710,8 +1856,48 @@ pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, Call, SignedExtra>;
 /// Executive: handles dispatch to the various modules.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPallets, ()>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPallets,
+	OnRuntimeUpgrade,
+>;
+
+pub struct OnRuntimeUpgrade;
+impl frame_support::traits::OnRuntimeUpgrade for OnRuntimeUpgrade {
+	fn on_runtime_upgrade() -> u64 {
+		module_cdp_engine::migrations::v1::migrate::<Runtime>()
+			.saturating_add(module_evm_accounts::migrations::v1::migrate::<Runtime>())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+		Self::set_temp_storage(
+			module_cdp_engine::migrations::v1::pre_migrate::<Runtime>()?,
+			"cdp_engine_v1",
+		);
+		Self::set_temp_storage(
+			module_evm_accounts::migrations::v1::pre_migrate::<Runtime>()?,
+			"evm_accounts_v1",
+		);
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+		module_cdp_engine::migrations::v1::post_migrate::<Runtime>(
+			Self::get_temp_storage("cdp_engine_v1").ok_or("cdp_engine_v1 count is missing")?,
+		)?;
+		module_evm_accounts::migrations::v1::post_migrate::<Runtime>(
+			Self::get_temp_storage("evm_accounts_v1").ok_or("evm_accounts_v1 count is missing")?,
+		)?;
+		Ok(())
+	}
+}
 
 #[cfg(not(feature = "disable-runtime-api"))]
 impl_runtime_apis! {
@@ -1813,6 +1999,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_transaction_payment_rpc_runtime_api::TransactionFeeApi<
+		Block,
+		Balance,
+		CurrencyId,
+	> for Runtime {
+		fn query_fee_in_currency(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			currency_id: CurrencyId,
+		) -> Option<Balance> {
+			TransactionPayment::query_fee_in_currency(uxt, len, currency_id)
+		}
+	}
+
 	impl orml_oracle_rpc_runtime_api::OracleApi<
 		Block,
 		DataProviderId,
@@ -1834,6 +2034,23 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl runtime_common::OracleFeedsApi<
+		Block,
+		DataProviderId,
+		CurrencyId,
+		AccountId,
+		Moment,
+	> for Runtime {
+		fn get_feed_details(provider_id: DataProviderId, key: CurrencyId) -> Option<runtime_common::FeedDetails<AccountId, Moment>> {
+			match provider_id {
+				DataProviderId::Acala => Some(
+					AggregatedCombineData::<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider, Prices>::feed_details(&key)
+				),
+				DataProviderId::Aggregated => None,
+			}
+		}
+	}
+
 	impl module_staking_pool_rpc_runtime_api::StakingPoolApi<
 		Block,
 		AccountId,
@@ -1850,6 +2067,260 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_cdp_engine_rpc_runtime_api::CDPEngineApi<
+		Block,
+		AccountId,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn get_position_risk(
+			account: AccountId,
+			currency_id: CurrencyId,
+		) -> Option<module_cdp_engine_rpc_runtime_api::PositionRisk<Balance>> {
+			let module_loans::Position { collateral, debit } = Loans::positions(currency_id, &account);
+			let price = CdpEngine::get_collateral_price(currency_id)?;
+
+			Some(module_cdp_engine_rpc_runtime_api::PositionRisk {
+				collateral_ratio: CdpEngine::calculate_collateral_ratio(currency_id, collateral, debit, price),
+				liquidation_price: CdpEngine::calculate_liquidation_price(currency_id, collateral, debit),
+				required_collateral: CdpEngine::calculate_required_collateral(currency_id, collateral, debit, price),
+				accumulated_interest: CdpEngine::get_accumulated_interest(currency_id, debit),
+			})
+		}
+
+		fn get_interest_accrued(
+			account: AccountId,
+			currency_id: CurrencyId,
+		) -> module_cdp_engine_rpc_runtime_api::InterestInfo<Balance> {
+			module_cdp_engine_rpc_runtime_api::InterestInfo {
+				accrued: CdpEngine::get_interest_accrued(&account, currency_id)
+			}
+		}
+	}
+
+	impl module_session_manager_runtime_api::SessionManagerApi<
+		Block,
+		BlockNumber,
+	> for Runtime {
+		fn session_duration_at(block_number: BlockNumber) -> BlockNumber {
+			SessionManager::session_duration_at(block_number)
+		}
+	}
+
+	impl module_dex_rpc_runtime_api::DexApi<
+		Block,
+		CurrencyId,
+		BlockNumber,
+		Balance,
+	> for Runtime {
+		fn get_twap(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			window: BlockNumber,
+		) -> Option<ExchangeRate> {
+			Dex::get_twap(currency_id_a, currency_id_b, window)
+		}
+
+		fn get_swap_quote(
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			amount: Balance,
+			mode: module_support::SwapMode,
+		) -> Option<module_support::SwapQuote<CurrencyId, Balance>> {
+			Dex::get_best_swap_quote(supply_currency_id, target_currency_id, amount, mode)
+		}
+	}
+
+	impl module_asset_registry_runtime_api::PortfolioApi<
+		Block,
+		AccountId,
+		CurrencyId,
+		module_incentives::PoolId,
+		Balance,
+	> for Runtime {
+		fn get_portfolio(
+			account: AccountId,
+		) -> module_asset_registry_runtime_api::Portfolio<CurrencyId, module_incentives::PoolId, Balance> {
+			use module_asset_registry_runtime_api::*;
+			use module_support::{DEXManager, ExchangeRateProvider};
+
+			let mut portfolio = Portfolio::default();
+
+			let native = System::account(&account).data;
+			if !native.free.is_zero() || !native.reserved.is_zero() {
+				portfolio.balances.push(TokenBalance {
+					currency_id: GetNativeCurrencyId::get(),
+					free: native.free,
+					reserved: native.reserved,
+					frozen: native.misc_frozen.max(native.fee_frozen),
+				});
+			}
+
+			for (currency_id, data) in orml_tokens::Accounts::<Runtime>::iter_prefix(&account) {
+				let total = data.free.saturating_add(data.reserved);
+				if total.is_zero() {
+					continue;
+				}
+
+				if let Some((currency_id_0, currency_id_1)) = currency_id.split_dex_share_currency_id() {
+					let (pool_0, pool_1) = Dex::get_liquidity_pool(currency_id_0, currency_id_1);
+					let proportion = Ratio::checked_from_rational(total, Tokens::total_issuance(currency_id))
+						.unwrap_or_default();
+					portfolio.dex_shares.push(DexShareBalance {
+						currency_id,
+						balance: total,
+						underlying: vec![
+							CurrencyAmount {
+								currency_id: currency_id_0,
+								amount: proportion.saturating_mul_int(pool_0),
+							},
+							CurrencyAmount {
+								currency_id: currency_id_1,
+								amount: proportion.saturating_mul_int(pool_1),
+							},
+						],
+					});
+					continue;
+				}
+
+				if currency_id == GetLiquidCurrencyId::get() {
+					portfolio.liquid_staking = Some(LiquidStakingBalance {
+						liquid: CurrencyAmount {
+							currency_id,
+							amount: total,
+						},
+						staking: CurrencyAmount {
+							currency_id: GetStakingCurrencyId::get(),
+							amount: LiquidStakingExchangeRateProvider::get_exchange_rate().saturating_mul_int(total),
+						},
+					});
+				}
+				portfolio.balances.push(TokenBalance {
+					currency_id,
+					free: data.free,
+					reserved: data.reserved,
+					frozen: data.frozen,
+				});
+			}
+
+			for currency_id in CollateralCurrencyIds::get() {
+				let module_loans::Position { collateral, debit } = Loans::positions(currency_id, &account);
+				if !collateral.is_zero() || !debit.is_zero() {
+					portfolio.loans.push(LoanPosition {
+						collateral_currency_id: currency_id,
+						collateral,
+						debit,
+						debit_value: CdpEngine::get_debit_value(currency_id, debit),
+					});
+				}
+			}
+
+			for pool_id in orml_rewards::PoolInfos::<Runtime>::iter_keys() {
+				let (share, _) = Rewards::shares_and_withdrawn_rewards(&pool_id, &account);
+				if !share.is_zero() {
+					portfolio.incentive_stakes.push(IncentiveStake { pool_id, share });
+				}
+			}
+
+			portfolio
+		}
+	}
+
+	impl module_prices_rpc_runtime_api::PricesApi<
+		Block,
+		CurrencyId,
+		BlockNumber,
+	> for Runtime {
+		fn get_feed_freshness(currency_id: CurrencyId) -> Option<BlockNumber> {
+			Prices::feed_freshness(currency_id)
+		}
+	}
+
+	impl module_incentives_rpc_runtime_api::IncentivesApi<
+		Block,
+		AccountId,
+		module_incentives::PoolId,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn get_pending_loyalty_bonus(pool_id: module_incentives::PoolId, who: AccountId) -> Vec<(CurrencyId, Balance)> {
+			Incentives::pending_loyalty_bonus(&pool_id, &who)
+		}
+	}
+
+	impl module_nft_rpc_runtime_api::NftApi<
+		Block,
+		u32,
+		module_nft::Properties,
+	> for Runtime {
+		fn query_class_properties(class_id: u32) -> Option<module_nft::Properties> {
+			NFT::class_properties(class_id)
+		}
+	}
+
+	impl runtime_common::events::EventsApi<Block> for Runtime {
+		fn query_events(pallet_index: Option<u8>, event_index: Option<u8>) -> Vec<runtime_common::events::EncodedEvent> {
+			runtime_common::events::query_events::<Runtime>(pallet_index, event_index)
+		}
+	}
+
+	impl runtime_common::yields::YieldsApi<Block> for Runtime {
+		fn get_yields() -> runtime_common::yields::Yields {
+			use module_support::ExchangeRateProvider;
+			use runtime_common::yields::{compounded_apy, incentive_pool_apr, Yields};
+
+			let now = System::block_number();
+			let incentive_pools = orml_rewards::PoolInfos::<Runtime>::iter()
+				.filter_map(|(pool_id, pool_info)| {
+					incentive_pool_apr::<module_prices::RealTimePriceProvider<Runtime>>(
+						pool_id,
+						pool_info.total_shares,
+						&Incentives::reward_amounts_per_period(pool_id, now),
+						365 * DAYS / AccumulatePeriod::get(),
+					)
+				})
+				.collect();
+
+			Yields {
+				liquid_exchange_rate: LiquidStakingExchangeRateProvider::get_exchange_rate(),
+				staking_apy: compounded_apy(EstimatedStakingRewardRatePerEra::get().into(), 365 * DAYS / EraLength::get()),
+				incentive_pools,
+			}
+		}
+	}
+
+	impl runtime_common::metrics::ProtocolMetricsApi<Block> for Runtime {
+		fn protocol_metrics() -> runtime_common::metrics::ProtocolMetrics {
+			use runtime_common::metrics::{CollateralMetrics, ProtocolMetrics, XcmQueueDepth};
+
+			let collaterals = CollateralCurrencyIds::get()
+				.into_iter()
+				.map(|currency_id| CollateralMetrics {
+					currency_id,
+					open_cdps: module_loans::Positions::<Runtime>::iter_prefix_values(currency_id)
+						.filter(|position| !position.debit.is_zero())
+						.count() as u32,
+					total_debit_value: CdpEngine::get_debit_value(currency_id, Loans::total_positions(currency_id).debit),
+				})
+				.collect();
+			let now = Timestamp::now();
+			let oracle_feed_ages = <AggregatedDataProvider as DataProviderExtended<_, _>>::get_all_values()
+				.into_iter()
+				.filter_map(|(currency_id, price)| Some((currency_id, now.saturating_sub(price?.timestamp))))
+				.collect();
+
+			ProtocolMetrics {
+				collaterals,
+				active_auctions: module_auction_manager::CollateralAuctions::<Runtime>::iter_keys().count() as u32,
+				oracle_feed_ages,
+				// Homa redeem requests are not enabled on Acala
+				homa_pending_redemptions: Zero::zero(),
+				// no XCMP and DMP queues on Acala yet
+				xcm_queue_depth: XcmQueueDepth::default(),
+			}
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn call(
 			from: H160,
@@ -1859,7 +2330,10 @@ impl_runtime_apis! {
 			gas_limit: u64,
 			storage_limit: u32,
 			estimate: bool,
+			state_overrides: Vec<(H160, StateOverride)>,
 		) -> Result<CallInfo, sp_runtime::DispatchError> {
+			EVM::apply_state_overrides(state_overrides)?;
+
 			let config = if estimate {
 				let mut config = <Runtime as module_evm::Config>::config().clone();
 				config.estimate = true;
@@ -1887,7 +2361,10 @@ impl_runtime_apis! {
 			gas_limit: u64,
 			storage_limit: u32,
 			estimate: bool,
+			state_overrides: Vec<(H160, StateOverride)>,
 		) -> Result<CreateInfo, sp_runtime::DispatchError> {
+			EVM::apply_state_overrides(state_overrides)?;
+
 			let config = if estimate {
 				let mut config = <Runtime as module_evm::Config>::config().clone();
 				config.estimate = true;
@@ -1936,6 +2413,40 @@ impl_runtime_apis! {
 
 			request.ok_or(sp_runtime::DispatchError::Other("Invalid parameter extrinsic, not evm Call"))
 		}
+
+		fn base_fee_per_gas() -> Balance {
+			EVM::base_fee_per_gas()
+		}
+
+		fn base_fee_history(block_count: u32) -> Vec<Balance> {
+			EVM::base_fee_history(block_count)
+		}
+
+		fn storage_statement(contract: H160) -> Option<ContractStorageStatement<Balance>> {
+			EVM::storage_statement(&contract)
+		}
+
+		fn trace_extrinsic(
+			extrinsics: Vec<<Block as BlockT>::Extrinsic>,
+			extrinsic: <Block as BlockT>::Extrinsic,
+			tracer: TracerConfig,
+		) -> Result<TraceOutcome, sp_runtime::DispatchError> {
+			#[cfg(feature = "tracing")]
+			{
+				for extrinsic in extrinsics {
+					let _ = Executive::apply_extrinsic(extrinsic);
+				}
+
+				let (_, outcome) = module_evm::runner::tracing::trace(tracer, || Executive::apply_extrinsic(extrinsic));
+				Ok(outcome)
+			}
+
+			#[cfg(not(feature = "tracing"))]
+			{
+				let _ = (extrinsics, extrinsic, tracer);
+				Err(sp_runtime::DispatchError::Other("EVM tracing is not enabled"))
+			}
+		}
 	}
 
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
@@ -59,6 +59,12 @@ impl<T: frame_system::Config> module_cdp_engine::WeightInfo for WeightInfo<T> {
 	fn set_global_params() -> Weight {
 		(12_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_maximum_debit_value_per_account() -> Weight {
+		(33_904_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_offchain_worker_params() -> Weight {
+		(12_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn liquidate_by_auction() -> Weight {
 		(210_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(28 as Weight))
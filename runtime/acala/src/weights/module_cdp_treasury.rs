@@ -58,4 +58,12 @@ impl<T: frame_system::Config> module_cdp_treasury::WeightInfo for WeightInfo<T>
 	fn set_expected_collateral_auction_size() -> Weight {
 		(12_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_dex_disposal_params() -> Weight {
+		(15_841_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn dispose_lot() -> Weight {
+		(213_476_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
 }
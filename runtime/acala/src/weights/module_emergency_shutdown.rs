@@ -67,4 +67,12 @@ impl<T: frame_system::Config> module_emergency_shutdown::WeightInfo for WeightIn
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
 	}
+	fn refund_collateral(c: u32) -> Weight {
+		(126_000_000 as Weight)
+			// Standard Error: 215_000
+			.saturating_add((9_900_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 }
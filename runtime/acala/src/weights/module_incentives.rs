@@ -67,6 +67,11 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	fn claim_rewards_vested() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
 	fn update_incentive_rewards(c: u32) -> Weight {
 		(746_000 as Weight)
 			// Standard Error: 31_000
@@ -85,4 +90,16 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 			.saturating_add((1_832_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn update_reward_multipliers(c: u32) -> Weight {
+		(1_102_000 as Weight)
+			// Standard Error: 22_000
+			.saturating_add((2_046_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_vesting_payout_configs(c: u32) -> Weight {
+		(914_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 }
@@ -53,4 +53,7 @@ impl<T: frame_system::Config> module_transaction_payment::WeightInfo for WeightI
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn with_fee_paid_by() -> Weight {
+		(51_630_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
 }
@@ -0,0 +1,109 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Query of the events deposited in a block, filtered in the runtime.
+
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+use sp_std::prelude::*;
+
+/// A SCALE encoded event record, along with the indexes to look up its
+/// pallet and variant in the metadata.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct EncodedEvent {
+	/// Index of the event in the events of the block.
+	pub index: u32,
+	/// Index of the pallet which deposited the event.
+	pub pallet_index: u8,
+	/// Index of the event in the event enum of the pallet.
+	pub event_index: u8,
+	/// The SCALE encoded `EventRecord`.
+	pub record: Vec<u8>,
+}
+
+/// The events of the current block, filtered by the pallet index and the
+/// event index.
+pub fn query_events<T: frame_system::Config>(pallet_index: Option<u8>, event_index: Option<u8>) -> Vec<EncodedEvent> {
+	frame_system::Pallet::<T>::events()
+		.into_iter()
+		.enumerate()
+		.filter_map(|(index, record)| {
+			// the outer event is encoded as the pallet index followed by the encoded pallet event
+			let event = record.event.encode();
+			let (pallet, variant) = (*event.get(0)?, *event.get(1)?);
+
+			if pallet_index.map_or(true, |i| i == pallet) && event_index.map_or(true, |i| i == variant) {
+				Some(EncodedEvent {
+					index: index as u32,
+					pallet_index: pallet,
+					event_index: variant,
+					record: record.encode(),
+				})
+			} else {
+				None
+			}
+		})
+		.collect()
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait EventsApi {
+		/// The events of the block, filtered by the pallet index and the event index.
+		fn query_events(pallet_index: Option<u8>, event_index: Option<u8>) -> Vec<EncodedEvent>;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{new_test_ext, Scheduler, System, Test};
+	use frame_support::traits::PalletInfo;
+
+	#[test]
+	fn query_events_works() {
+		new_test_ext().execute_with(|| {
+			System::reset_events();
+			System::deposit_event(pallet_scheduler::Event::<Test>::Scheduled(3, 0));
+			System::deposit_event(frame_system::Event::<Test>::CodeUpdated);
+			System::deposit_event(pallet_scheduler::Event::<Test>::Canceled(3, 0));
+
+			let scheduler_index = <Test as frame_system::Config>::PalletInfo::index::<Scheduler>().unwrap() as u8;
+
+			assert_eq!(query_events::<Test>(None, None).len(), 3);
+			assert_eq!(
+				query_events::<Test>(Some(scheduler_index), None)
+					.iter()
+					.map(|event| event.index)
+					.collect::<Vec<_>>(),
+				vec![0, 2]
+			);
+			assert_eq!(
+				query_events::<Test>(Some(scheduler_index), Some(1)),
+				vec![EncodedEvent {
+					index: 2,
+					pallet_index: scheduler_index,
+					event_index: 1,
+					record: System::events()[2].encode(),
+				}]
+			);
+			assert!(query_events::<Test>(Some(scheduler_index), Some(2)).is_empty());
+		});
+	}
+}
@@ -46,16 +46,26 @@ use sp_runtime::{
 };
 use static_assertions::const_assert;
 
+pub mod events;
+pub mod metrics;
+
 mod homa;
 pub use homa::*;
 
+mod oracle;
+pub use oracle::*;
+
 pub mod precompile;
 pub use precompile::{
-	AllPrecompiles, DexPrecompile, MultiCurrencyPrecompile, NFTPrecompile, OraclePrecompile, ScheduleCallPrecompile,
-	StateRentPrecompile,
+	AllPrecompiles, DexPrecompile, DisabledXcmTransfer, HomaPrecompile, HonzonPrecompile, IncentivesPrecompile,
+	MultiCurrencyPrecompile, NFTPrecompile, OraclePrecompile, ScheduleCallPrecompile, StateRentPrecompile,
+	XtokensPrecompile,
 };
+
+pub mod yields;
+
 pub use primitives::{
-	currency::{TokenInfo, ACA, AUSD, BNC, DOT, KAR, KSM, KUSD, LDOT, LKSM, RENBTC, VSKSM},
+	currency::{TokenInfo, ACA, AUSD, BNC, DOT, KAR, KSM, KUSD, LCDOT, LDOT, LKSM, RENBTC, VSKSM},
 	AccountId,
 };
 
@@ -344,6 +354,7 @@ pub enum RelaychainSubAccountId {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use sp_runtime::FixedPointNumber;
 
 	#[test]
 	fn system_contracts_filter_works() {
@@ -385,4 +396,119 @@ mod tests {
 		assert!(!is_acala_precompile(H160::from_low_u64_be(PREDEPLOY_ADDRESS_START)));
 		assert!(!is_acala_precompile([1u8; 20].into()));
 	}
+
+	#[test]
+	fn aggregate_prices_works() {
+		let values: Vec<TimeStampedPrice> = vec![
+			TimeStampedPrice {
+				value: Price::saturating_from_integer(150),
+				timestamp: 1,
+			},
+			TimeStampedPrice {
+				value: Price::saturating_from_integer(101),
+				timestamp: 2,
+			},
+			TimeStampedPrice {
+				value: Price::saturating_from_integer(102),
+				timestamp: 3,
+			},
+			TimeStampedPrice {
+				value: Price::saturating_from_integer(100),
+				timestamp: 4,
+			},
+		];
+
+		assert_eq!(aggregate_prices::<u64>(vec![], PriceAggregationStrategy::Median), None);
+		assert_eq!(
+			aggregate_prices(values.clone(), PriceAggregationStrategy::Median),
+			Some(TimeStampedPrice {
+				value: Price::saturating_from_integer(102),
+				timestamp: 3,
+			})
+		);
+
+		// 150 deviates from the median 102 by more than 5%
+		assert_eq!(
+			aggregate_prices(
+				values.clone(),
+				PriceAggregationStrategy::MedianWithOutlierRejection {
+					max_deviation: Ratio::saturating_from_rational(5, 100)
+				}
+			),
+			Some(TimeStampedPrice {
+				value: Price::saturating_from_integer(101),
+				timestamp: 3,
+			})
+		);
+		assert_eq!(
+			aggregate_prices(
+				values,
+				PriceAggregationStrategy::MedianWithOutlierRejection {
+					max_deviation: Ratio::saturating_from_rational(1, 2)
+				}
+			),
+			Some(TimeStampedPrice {
+				value: Price::saturating_from_rational(4530, 40),
+				timestamp: 3,
+			})
+		);
+	}
+
+	#[test]
+	fn feed_statuses_works() {
+		let values: Vec<TimeStampedPrice> = vec![
+			TimeStampedPrice {
+				value: Price::saturating_from_integer(150),
+				timestamp: 10,
+			},
+			TimeStampedPrice {
+				value: Price::saturating_from_integer(101),
+				timestamp: 10,
+			},
+			TimeStampedPrice {
+				value: Price::saturating_from_integer(102),
+				timestamp: 10,
+			},
+			TimeStampedPrice {
+				value: Price::saturating_from_integer(500),
+				timestamp: 1,
+			},
+		];
+		let strategy = PriceAggregationStrategy::MedianWithOutlierRejection {
+			max_deviation: Ratio::saturating_from_rational(5, 100),
+		};
+
+		assert_eq!(
+			feed_statuses(&values, 12, 10, 3, PriceAggregationStrategy::Median),
+			vec![
+				FeedStatus::Included,
+				FeedStatus::Included,
+				FeedStatus::Included,
+				FeedStatus::Expired
+			]
+		);
+		// 150 deviates from the median 102 by more than 5%
+		assert_eq!(
+			feed_statuses(&values, 12, 10, 3, strategy),
+			vec![
+				FeedStatus::Outlier,
+				FeedStatus::Included,
+				FeedStatus::Included,
+				FeedStatus::Expired
+			]
+		);
+		assert_eq!(
+			feed_statuses(&values, 12, 10, 4, strategy),
+			vec![
+				FeedStatus::NotEnoughFeeds,
+				FeedStatus::NotEnoughFeeds,
+				FeedStatus::NotEnoughFeeds,
+				FeedStatus::Expired
+			]
+		);
+		assert_eq!(
+			feed_statuses(&values, 20, 10, 0, strategy),
+			vec![FeedStatus::Expired; 4]
+		);
+	}
 }
@@ -0,0 +1,99 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Protocol health metrics of the runtime, exported to Prometheus by the node.
+
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+use primitives::{Balance, CurrencyId, Moment};
+use sp_std::prelude::*;
+
+/// The metrics of the loans of a collateral.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct CollateralMetrics {
+	pub currency_id: CurrencyId,
+	/// The number of positions with debit.
+	pub open_cdps: u32,
+	/// The value of the total debit, in stable currency.
+	pub total_debit_value: Balance,
+}
+
+/// The number of XCM messages, or pages of messages, waiting in the queues.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct XcmQueueDepth {
+	/// The messages received from the sibling parachains, not executed yet.
+	pub inbound_xcmp: u32,
+	/// The messages to the sibling parachains, not sent yet.
+	pub outbound_xcmp: u32,
+	/// The pages of the messages received from the relay chain, not executed
+	/// yet.
+	pub dmp_pages: u32,
+}
+
+/// The health of the protocol at a block.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ProtocolMetrics {
+	pub collaterals: Vec<CollateralMetrics>,
+	/// The number of collateral auctions in progress.
+	pub active_auctions: u32,
+	/// The milliseconds elapsed since the aggregated price of each currency
+	/// was fed.
+	pub oracle_feed_ages: Vec<(CurrencyId, Moment)>,
+	/// The liquid currency waiting in the Homa redeem requests.
+	pub homa_pending_redemptions: Balance,
+	pub xcm_queue_depth: XcmQueueDepth,
+}
+
+/// The number of entries of the storage map `storage` of `pallet`, for the
+/// maps which are not public in their pallet.
+pub fn storage_map_len(pallet: &str, storage: &[u8]) -> u32 {
+	frame_support::storage::migration::storage_iter::<()>(pallet.as_bytes(), storage).count() as u32
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait ProtocolMetricsApi {
+		/// The open CDPs, the total debits, the active auctions, the oracle feed ages,
+		/// the pending Homa redemptions and the XCM queue depths.
+		fn protocol_metrics() -> ProtocolMetrics;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::storage::migration::put_storage_value;
+
+	#[test]
+	fn storage_map_len_works() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert_eq!(storage_map_len("XcmpQueue", b"OutboundXcmpMessages"), 0);
+
+			put_storage_value(b"XcmpQueue", b"OutboundXcmpMessages", &[1], vec![1u8, 2, 3]);
+			put_storage_value(b"XcmpQueue", b"OutboundXcmpMessages", &[2], Vec::<u8>::new());
+			put_storage_value(b"XcmpQueue", b"InboundXcmpMessages", &[1], vec![1u8]);
+			put_storage_value(b"DmpQueue", b"Pages", &[1], vec![1u8]);
+
+			assert_eq!(storage_map_len("XcmpQueue", b"OutboundXcmpMessages"), 2);
+			assert_eq!(storage_map_len("XcmpQueue", b"InboundXcmpMessages"), 1);
+			assert_eq!(storage_map_len("DmpQueue", b"Pages"), 1);
+			assert_eq!(storage_map_len("DmpQueue", b"PageIndex"), 0);
+		});
+	}
+}
@@ -0,0 +1,246 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::{Codec, Decode, Encode};
+use frame_support::{
+	traits::{Get, SortedMembers, Time},
+	RuntimeDebug,
+};
+use module_support::Price;
+pub use module_support::PriceAggregationStrategy;
+use orml_oracle::{Config, MomentOf, TimestampedValue, TimestampedValueOf};
+use orml_traits::{CombineData, DataProviderExtended, GetByKey};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	FixedPointNumber,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// Combine the prices fed by oracle operators with the
+/// `PriceAggregationStrategy` of the oracle key provided by `Strategy`, the
+/// expired prices are not counted.
+pub struct AggregatedCombineData<T, MinimumCount, ExpiresIn, I, Strategy>(
+	PhantomData<(T, MinimumCount, ExpiresIn, I, Strategy)>,
+);
+
+impl<T, I, MinimumCount, ExpiresIn, Strategy> CombineData<<T as Config<I>>::OracleKey, TimestampedValueOf<T, I>>
+	for AggregatedCombineData<T, MinimumCount, ExpiresIn, I, Strategy>
+where
+	T: Config<I, OracleValue = Price>,
+	I: 'static,
+	MinimumCount: Get<u32>,
+	ExpiresIn: Get<MomentOf<T, I>>,
+	Strategy: GetByKey<<T as Config<I>>::OracleKey, PriceAggregationStrategy>,
+{
+	fn combine_data(
+		key: &<T as Config<I>>::OracleKey,
+		mut values: Vec<TimestampedValueOf<T, I>>,
+		prev_value: Option<TimestampedValueOf<T, I>>,
+	) -> Option<TimestampedValueOf<T, I>> {
+		let expires_in = ExpiresIn::get();
+		let now = T::Time::now();
+		values.retain(|x| x.timestamp.saturating_add(expires_in) > now);
+
+		let count = values.len() as u32;
+		if count < MinimumCount::get() || count == 0 {
+			return prev_value;
+		}
+
+		aggregate_prices(values, Strategy::get(key))
+	}
+}
+
+/// Whether the price fed by an oracle operator is counted in the combined
+/// price.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum FeedStatus {
+	/// Counted in the combined price.
+	Included,
+	/// Fed earlier than `ExpiresIn` ago.
+	Expired,
+	/// Rejected by `MedianWithOutlierRejection` for deviating too far from the
+	/// median.
+	Outlier,
+	/// Fewer unexpired prices than `MinimumCount`, the previous combined price
+	/// is kept.
+	NotEnoughFeeds,
+}
+
+/// The raw price fed by an oracle operator.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct OperatorFeed<AccountId, Moment> {
+	pub operator: AccountId,
+	pub value: Price,
+	pub timestamp: Moment,
+	pub status: FeedStatus,
+}
+
+/// The combined price of an oracle key, along with the raw prices it is
+/// aggregated from.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FeedDetails<AccountId, Moment> {
+	pub combined: Option<TimestampedValue<Price, Moment>>,
+	pub strategy: PriceAggregationStrategy,
+	pub feeds: Vec<OperatorFeed<AccountId, Moment>>,
+}
+
+impl<T, I, MinimumCount, ExpiresIn, Strategy> AggregatedCombineData<T, MinimumCount, ExpiresIn, I, Strategy>
+where
+	T: Config<I, OracleValue = Price>,
+	I: 'static,
+	MinimumCount: Get<u32>,
+	ExpiresIn: Get<MomentOf<T, I>>,
+	Strategy: GetByKey<<T as Config<I>>::OracleKey, PriceAggregationStrategy>,
+{
+	/// The raw prices fed by the oracle operators for `key`, and whether each
+	/// of them is counted in the combined price.
+	pub fn feed_details(key: &<T as Config<I>>::OracleKey) -> FeedDetails<T::AccountId, MomentOf<T, I>> {
+		let (operators, values): (Vec<T::AccountId>, Vec<TimestampedValueOf<T, I>>) = T::Members::sorted_members()
+			.into_iter()
+			.filter_map(|who| orml_oracle::Pallet::<T, I>::raw_values(&who, key).map(|value| (who, value)))
+			.unzip();
+
+		let strategy = Strategy::get(key);
+		let statuses = feed_statuses(&values, T::Time::now(), ExpiresIn::get(), MinimumCount::get(), strategy);
+		let feeds = operators
+			.into_iter()
+			.zip(values)
+			.zip(statuses)
+			.map(|((operator, value), status)| OperatorFeed {
+				operator,
+				value: value.value,
+				timestamp: value.timestamp,
+				status,
+			})
+			.collect();
+
+		FeedDetails {
+			combined: <orml_oracle::Pallet<T, I> as DataProviderExtended<_, _>>::get_no_op(key),
+			strategy,
+			feeds,
+		}
+	}
+}
+
+/// The `FeedStatus` of each of the timestamped prices, following the rules
+/// of `AggregatedCombineData`.
+pub fn feed_statuses<Moment: Copy + PartialOrd + Saturating>(
+	values: &[TimestampedValue<Price, Moment>],
+	now: Moment,
+	expires_in: Moment,
+	minimum_count: u32,
+	strategy: PriceAggregationStrategy,
+) -> Vec<FeedStatus> {
+	let is_expired = |x: &TimestampedValue<Price, Moment>| x.timestamp.saturating_add(expires_in) <= now;
+
+	let mut unexpired: Vec<Price> = values.iter().filter(|x| !is_expired(x)).map(|x| x.value).collect();
+	let count = unexpired.len() as u32;
+	unexpired.sort();
+	let median = if count < minimum_count || count == 0 {
+		None
+	} else {
+		Some(unexpired[unexpired.len() / 2])
+	};
+
+	values
+		.iter()
+		.map(|x| {
+			if is_expired(x) {
+				FeedStatus::Expired
+			} else {
+				match median {
+					None => FeedStatus::NotEnoughFeeds,
+					Some(median) if is_accepted(x.value, median, strategy) => FeedStatus::Included,
+					Some(_) => FeedStatus::Outlier,
+				}
+			}
+		})
+		.collect()
+}
+
+/// Whether `value` is counted in the price aggregated with `strategy`.
+fn is_accepted(value: Price, median: Price, strategy: PriceAggregationStrategy) -> bool {
+	match strategy {
+		PriceAggregationStrategy::Median => true,
+		PriceAggregationStrategy::MedianWithOutlierRejection { max_deviation } => {
+			let diff = if value > median {
+				value.saturating_sub(median)
+			} else {
+				median.saturating_sub(value)
+			};
+			diff <= max_deviation.saturating_mul(median)
+		}
+	}
+}
+
+/// Aggregate the timestamped prices with `strategy`. The result has the
+/// timestamp of the median.
+pub fn aggregate_prices<Moment: Clone>(
+	mut values: Vec<TimestampedValue<Price, Moment>>,
+	strategy: PriceAggregationStrategy,
+) -> Option<TimestampedValue<Price, Moment>> {
+	if values.is_empty() {
+		return None;
+	}
+
+	values.sort_by(|a, b| a.value.cmp(&b.value));
+	let median = values[values.len() / 2].clone();
+
+	match strategy {
+		PriceAggregationStrategy::Median => Some(median),
+		PriceAggregationStrategy::MedianWithOutlierRejection { .. } => {
+			let accepted: Vec<Price> = values
+				.into_iter()
+				.map(|x| x.value)
+				.filter(|value| is_accepted(*value, median.value, strategy))
+				.collect();
+
+			// the median is always accepted, `accepted` is not empty.
+			let sum = accepted
+				.iter()
+				.fold(Price::zero(), |sum, value| sum.saturating_add(*value));
+			Some(TimestampedValue {
+				value: Price::from_inner(sum.into_inner() / accepted.len() as u128),
+				timestamp: median.timestamp,
+			})
+		}
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait OracleFeedsApi<ProviderId, Key, AccountId, Moment> where
+		ProviderId: Codec,
+		Key: Codec,
+		AccountId: Codec,
+		Moment: Codec,
+	{
+		/// The raw prices fed by the oracle operators of `provider_id` for `key`,
+		/// along with the combined price. `None` if the provider has no operators.
+		fn get_feed_details(provider_id: ProviderId, key: Key) -> Option<FeedDetails<AccountId, Moment>>;
+	}
+}
@@ -0,0 +1,122 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::input::{Input, InputT, Output};
+use crate::precompile::PrecompileOutput;
+use frame_support::{log, sp_runtime::FixedPointNumber};
+use module_evm::{Context, ExitError, ExitSucceed, Precompile};
+use module_support::{AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, HomaManager};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use primitives::Balance;
+use sp_runtime::RuntimeDebug;
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
+
+/// The `Homa` impl precompile.
+///
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Mint liquid currency. Rest `input` bytes: `who`, `amount`.
+/// - Request redeem. Rest `input` bytes: `who`, `amount`, `fast_match`.
+/// - Get exchange rate.
+pub struct HomaPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Homa>(
+	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, Homa)>,
+);
+
+#[primitives_proc_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	MintLiquid = "mintLiquid(address,uint256)",
+	RequestRedeem = "requestRedeem(address,uint256,bool)",
+	GetExchangeRate = "getExchangeRate()",
+}
+
+impl<AccountId, AddressMapping, CurrencyIdMapping, Homa> Precompile
+	for HomaPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Homa>
+where
+	AccountId: Debug + Clone,
+	AddressMapping: AddressMappingT<AccountId>,
+	CurrencyIdMapping: CurrencyIdMappingT,
+	Homa: HomaManager<AccountId, Balance>,
+{
+	fn execute(
+		input: &[u8],
+		_target_gas: Option<u64>,
+		_context: &Context,
+	) -> result::Result<PrecompileOutput, ExitError> {
+		let input = Input::<Action, AccountId, AddressMapping, CurrencyIdMapping>::new(input);
+
+		let action = input.action()?;
+
+		match action {
+			Action::MintLiquid => {
+				let who = input.account_id_at(1)?;
+				let amount = input.balance_at(2)?;
+				log::debug!(target: "evm", "homa: mint_liquid who: {:?}, amount: {:?}", who, amount);
+
+				Homa::mint(&who, amount).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::RequestRedeem => {
+				let who = input.account_id_at(1)?;
+				let amount = input.balance_at(2)?;
+				let fast_match = input.bool_at(3)?;
+				log::debug!(
+					target: "evm",
+					"homa: request_redeem who: {:?}, amount: {:?}, fast_match: {:?}",
+					who, amount, fast_match
+				);
+
+				Homa::request_redeem(&who, amount, fast_match).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::GetExchangeRate => {
+				let exchange_rate = Homa::get_exchange_rate();
+				log::debug!(target: "evm", "homa: get_exchange_rate exchange_rate: {:?}", exchange_rate);
+
+				// `ExchangeRate` has 18 decimals, the same as solidity fixed point numbers.
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: Output::default().encode_u128(exchange_rate.into_inner()),
+					logs: Default::default(),
+				})
+			}
+		}
+	}
+}
@@ -0,0 +1,167 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::input::{Input, InputT, Output};
+use crate::precompile::PrecompileOutput;
+use frame_support::{log, sp_runtime::FixedPointNumber};
+use module_evm::{Context, ExitError, ExitSucceed, Precompile};
+use module_support::{AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, HonzonManager};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use primitives::{Amount, Balance, CurrencyId};
+use sp_runtime::RuntimeDebug;
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
+
+/// The `Honzon` impl precompile.
+///
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Adjust loan. Rest `input` bytes: `who`, `currency_id`, `collateral_adjustment`,
+///   `debit_adjustment`.
+/// - Get position. Rest `input` bytes: `who`, `currency_id`.
+/// - Get liquidation ratio. Rest `input` bytes: `currency_id`.
+/// - Get current collateral ratio. Rest `input` bytes: `who`, `currency_id`.
+/// - Get debit exchange rate. Rest `input` bytes: `currency_id`.
+pub struct HonzonPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Honzon>(
+	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, Honzon)>,
+);
+
+#[primitives_proc_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	AdjustLoan = "adjustLoan(address,address,int128,int128)",
+	GetPosition = "getPosition(address,address)",
+	GetLiquidationRatio = "getLiquidationRatio(address)",
+	GetCurrentCollateralRatio = "getCurrentCollateralRatio(address,address)",
+	GetDebitExchangeRate = "getDebitExchangeRate(address)",
+}
+
+impl<AccountId, AddressMapping, CurrencyIdMapping, Honzon> Precompile
+	for HonzonPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Honzon>
+where
+	AccountId: Debug + Clone,
+	AddressMapping: AddressMappingT<AccountId>,
+	CurrencyIdMapping: CurrencyIdMappingT,
+	Honzon: HonzonManager<AccountId, CurrencyId, Amount, Balance>,
+{
+	fn execute(
+		input: &[u8],
+		_target_gas: Option<u64>,
+		_context: &Context,
+	) -> result::Result<PrecompileOutput, ExitError> {
+		let input = Input::<Action, AccountId, AddressMapping, CurrencyIdMapping>::new(input);
+
+		let action = input.action()?;
+
+		match action {
+			Action::AdjustLoan => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let collateral_adjustment = input.amount_at(3)?;
+				let debit_adjustment = input.amount_at(4)?;
+				log::debug!(
+					target: "evm",
+					"honzon: adjust_loan who: {:?}, currency_id: {:?}, collateral_adjustment: {:?}, debit_adjustment: {:?}",
+					who, currency_id, collateral_adjustment, debit_adjustment
+				);
+
+				Honzon::adjust_loan(&who, currency_id, collateral_adjustment, debit_adjustment).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::GetPosition => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let (collateral, debit) = Honzon::get_position(&who, currency_id);
+				log::debug!(
+					target: "evm",
+					"honzon: get_position who: {:?}, currency_id: {:?}, collateral: {:?}, debit: {:?}",
+					who, currency_id, collateral, debit
+				);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: Output::default().encode_u128_tuple(collateral, debit),
+					logs: Default::default(),
+				})
+			}
+			Action::GetLiquidationRatio => {
+				let currency_id = input.currency_id_at(1)?;
+				let ratio = Honzon::get_liquidation_ratio(currency_id);
+				log::debug!(
+					target: "evm",
+					"honzon: get_liquidation_ratio currency_id: {:?}, ratio: {:?}",
+					currency_id, ratio
+				);
+
+				// `Ratio` has 18 decimals, the same as solidity fixed point numbers.
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: Output::default().encode_u128(ratio.into_inner()),
+					logs: Default::default(),
+				})
+			}
+			Action::GetCurrentCollateralRatio => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let ratio = Honzon::get_current_collateral_ratio(&who, currency_id);
+				log::debug!(
+					target: "evm",
+					"honzon: get_current_collateral_ratio who: {:?}, currency_id: {:?}, ratio: {:?}",
+					who, currency_id, ratio
+				);
+
+				// Positions without debit have no collateral ratio, return 0.
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: Output::default().encode_u128(ratio.map_or(0, |r| r.into_inner())),
+					logs: Default::default(),
+				})
+			}
+			Action::GetDebitExchangeRate => {
+				let currency_id = input.currency_id_at(1)?;
+				let exchange_rate = Honzon::get_debit_exchange_rate(currency_id);
+				log::debug!(
+					target: "evm",
+					"honzon: get_debit_exchange_rate currency_id: {:?}, exchange_rate: {:?}",
+					currency_id, exchange_rate
+				);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: Output::default().encode_u128(exchange_rate.into_inner()),
+					logs: Default::default(),
+				})
+			}
+		}
+	}
+}
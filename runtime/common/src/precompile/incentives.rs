@@ -0,0 +1,176 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::input::{Input, InputT, Output};
+use crate::precompile::PrecompileOutput;
+use frame_support::log;
+use module_evm::{Context, ExitError, ExitSucceed, Precompile};
+use module_support::{
+	AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, IncentivesManager, PoolId,
+};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::RuntimeDebug;
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
+
+/// The `Incentives` impl precompile.
+///
+///
+/// `input` data starts with `action`.
+///
+/// Pools are identified by `pool_type` (0: Loans, 1: Dex) and `currency_id`.
+///
+/// Actions:
+/// - Deposit dex share. Rest `input` bytes: `who`, `lp_currency_id`, `amount`.
+/// - Withdraw dex share. Rest `input` bytes: `who`, `lp_currency_id`, `amount`.
+/// - Claim rewards. Rest `input` bytes: `who`, `pool_type`, `currency_id`.
+/// - Get pending rewards. Rest `input` bytes: `pool_type`, `currency_id`, `who`,
+///   `reward_currency_id`.
+pub struct IncentivesPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Incentives>(
+	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, Incentives)>,
+);
+
+#[primitives_proc_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	DepositDexShare = "depositDexShare(address,address,uint256)",
+	WithdrawDexShare = "withdrawDexShare(address,address,uint256)",
+	ClaimRewards = "claimRewards(address,uint32,address)",
+	GetPendingRewards = "getPendingRewards(uint32,address,address,address)",
+}
+
+impl<AccountId, AddressMapping, CurrencyIdMapping, Incentives> Precompile
+	for IncentivesPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Incentives>
+where
+	AccountId: Debug + Clone,
+	AddressMapping: AddressMappingT<AccountId>,
+	CurrencyIdMapping: CurrencyIdMappingT,
+	Incentives: IncentivesManager<AccountId, Balance, CurrencyId, PoolId>,
+{
+	fn execute(
+		input: &[u8],
+		_target_gas: Option<u64>,
+		_context: &Context,
+	) -> result::Result<PrecompileOutput, ExitError> {
+		let input = Input::<Action, AccountId, AddressMapping, CurrencyIdMapping>::new(input);
+
+		let action = input.action()?;
+
+		match action {
+			Action::DepositDexShare => {
+				let who = input.account_id_at(1)?;
+				let lp_currency_id = input.currency_id_at(2)?;
+				let amount = input.balance_at(3)?;
+				log::debug!(
+					target: "evm",
+					"incentives: deposit_dex_share who: {:?}, lp_currency_id: {:?}, amount: {:?}",
+					who, lp_currency_id, amount
+				);
+
+				Incentives::deposit_dex_share(&who, lp_currency_id, amount).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::WithdrawDexShare => {
+				let who = input.account_id_at(1)?;
+				let lp_currency_id = input.currency_id_at(2)?;
+				let amount = input.balance_at(3)?;
+				log::debug!(
+					target: "evm",
+					"incentives: withdraw_dex_share who: {:?}, lp_currency_id: {:?}, amount: {:?}",
+					who, lp_currency_id, amount
+				);
+
+				Incentives::withdraw_dex_share(&who, lp_currency_id, amount).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::ClaimRewards => {
+				let who = input.account_id_at(1)?;
+				let pool_id = pool_id_at(&input, 2)?;
+				log::debug!(target: "evm", "incentives: claim_rewards who: {:?}, pool_id: {:?}", who, pool_id);
+
+				Incentives::claim_rewards(&who, pool_id).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::GetPendingRewards => {
+				let pool_id = pool_id_at(&input, 1)?;
+				let who = input.account_id_at(3)?;
+				let reward_currency_id = input.currency_id_at(4)?;
+				let value = Incentives::get_pending_rewards(pool_id, &who, reward_currency_id);
+				log::debug!(
+					target: "evm",
+					"incentives: get_pending_rewards pool_id: {:?}, who: {:?}, reward_currency_id: {:?}, value: {:?}",
+					pool_id, who, reward_currency_id, value
+				);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: Output::default().encode_u128(value),
+					logs: Default::default(),
+				})
+			}
+		}
+	}
+}
+
+// Decode the pool from `pool_type` at `index` and `currency_id` at `index + 1`.
+fn pool_id_at<AccountId, AddressMapping, CurrencyIdMapping>(
+	input: &Input<'_, Action, AccountId, AddressMapping, CurrencyIdMapping>,
+	index: usize,
+) -> result::Result<PoolId, ExitError>
+where
+	AddressMapping: AddressMappingT<AccountId>,
+	CurrencyIdMapping: CurrencyIdMappingT,
+{
+	let pool_type = input.u32_at(index)?;
+	let currency_id = input.currency_id_at(index + 1)?;
+
+	match pool_type {
+		0 => Ok(PoolId::Loans(currency_id)),
+		1 => Ok(PoolId::Dex(currency_id)),
+		_ => Err(ExitError::Other("invalid pool type".into())),
+	}
+}
@@ -58,6 +58,7 @@ pub trait InputT {
 
 	fn u64_at(&self, index: usize) -> Result<u64, Self::Error>;
 	fn u32_at(&self, index: usize) -> Result<u32, Self::Error>;
+	fn bool_at(&self, index: usize) -> Result<bool, Self::Error>;
 
 	fn bytes_at(&self, start: usize, len: usize) -> Result<Vec<u8>, Self::Error>;
 }
@@ -180,6 +181,21 @@ where
 		Ok(u32::from_be_bytes(num))
 	}
 
+	fn bool_at(&self, index: usize) -> Result<bool, Self::Error> {
+		let param = self.nth_param(index, None)?;
+
+		ensure!(
+			param[..PER_PARAM_BYTES - 1].iter().all(|b| *b == 0),
+			ExitError::Other("invalid bool".into())
+		);
+
+		match param[PER_PARAM_BYTES - 1] {
+			0 => Ok(false),
+			1 => Ok(true),
+			_ => Err(ExitError::Other("invalid bool".into())),
+		}
+	}
+
 	fn bytes_at(&self, index: usize, len: usize) -> Result<Vec<u8>, Self::Error> {
 		let bytes = self.nth_param(index, Some(len))?;
 
@@ -343,4 +359,19 @@ mod tests {
 		let input = TestInput::new(&raw_input[..]);
 		assert_ok!(input.u64_at(1), u64_num);
 	}
+
+	#[test]
+	fn bool_works() {
+		let mut raw_input = [0u8; 36];
+		let input = TestInput::new(&raw_input[..]);
+		assert_ok!(input.bool_at(1), false);
+
+		raw_input[35] = 1;
+		let input = TestInput::new(&raw_input[..]);
+		assert_ok!(input.bool_at(1), true);
+
+		raw_input[35] = 2;
+		let input = TestInput::new(&raw_input[..]);
+		assert_err!(input.bool_at(1), ExitError::Other("invalid bool".into()));
+	}
 }
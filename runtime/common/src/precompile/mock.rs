@@ -30,8 +30,9 @@ use frame_support::{
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use module_support::{
 	mocks::MockAddressMapping, AddressMapping as AddressMappingT, DEXIncentives, ExchangeRate, ExchangeRateProvider,
+	HomaManager, HonzonManager, IncentivesManager, PoolId,
 };
-use orml_traits::{parameter_type_with_key, MultiReservableCurrency};
+use orml_traits::{parameter_type_with_key, MultiCurrency, MultiReservableCurrency, XcmTransfer};
 pub use primitives::{
 	evm::EvmAddress, Amount, BlockNumber, CurrencyId, DexShare, Header, Nonce, ReserveIdentifier, TokenSymbol,
 	TradingPair,
@@ -39,9 +40,10 @@ pub use primitives::{
 use sp_core::{crypto::AccountId32, H160, H256};
 use sp_runtime::{
 	traits::{BlakeTwo256, Convert, IdentityLookup, One as OneT},
-	DispatchResult, FixedPointNumber, FixedU128, Perbill,
+	DispatchError, DispatchResult, FixedPointNumber, FixedU128, Perbill,
 };
-use sp_std::{collections::btree_map::BTreeMap, convert::TryFrom, str::FromStr};
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap, convert::TryFrom, str::FromStr};
+use xcm::v0::{MultiAsset, MultiLocation};
 
 pub type AccountId = AccountId32;
 type Key = CurrencyId;
@@ -187,6 +189,7 @@ parameter_types! {
 	pub const DataDepositPerByte: Balance = 10;
 	pub const NftPalletId: PalletId = PalletId(*b"aca/aNFT");
 	pub MaxAttributesBytes: u32 = 2048;
+	pub const MaxNftBatchItems: u32 = 100;
 }
 impl module_nft::Config for Test {
 	type Event = Event;
@@ -196,6 +199,8 @@ impl module_nft::Config for Test {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = MaxAttributesBytes;
+	type MaxBatchItems = MaxNftBatchItems;
+	type MultiCurrency = Currencies;
 	type WeightInfo = ();
 }
 
@@ -233,6 +238,10 @@ impl module_transaction_payment::Config for Test {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Test>;
+	type CallFeeSwapPath = ();
+	type Call = Call;
+	type SponsorSignature = sp_runtime::MultiSignature;
+	type SponsorSigner = sp_runtime::MultiSigner;
 	type WeightInfo = ();
 }
 pub type ChargeTransactionPayment = module_transaction_payment::ChargeTransactionPayment<Test>;
@@ -334,6 +343,9 @@ impl module_dex::Config for Test {
 	type Currency = Tokens;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type RoutingCurrencies = ();
+	type TWAPObservationPeriod = ();
+	type MaxTWAPObservations = ();
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = EvmCurrencyIdMapping;
 	type WeightInfo = ();
@@ -368,6 +380,12 @@ pub type ScheduleCallPrecompile = crate::ScheduleCallPrecompile<
 	Test,
 >;
 pub type DexPrecompile = crate::DexPrecompile<AccountId, MockAddressMapping, EvmCurrencyIdMapping, DexModule>;
+pub type HomaPrecompile = crate::HomaPrecompile<AccountId, MockAddressMapping, EvmCurrencyIdMapping, MockHoma>;
+pub type HonzonPrecompile = crate::HonzonPrecompile<AccountId, MockAddressMapping, EvmCurrencyIdMapping, MockHonzon>;
+pub type IncentivesPrecompile =
+	crate::IncentivesPrecompile<AccountId, MockAddressMapping, EvmCurrencyIdMapping, MockIncentives>;
+pub type XtokensPrecompile =
+	crate::XtokensPrecompile<AccountId, MockAddressMapping, EvmCurrencyIdMapping, MockXcmTransfer>;
 
 parameter_types! {
 	pub NetworkContractSource: H160 = alice_evm_addr();
@@ -381,6 +399,8 @@ ord_parameter_types! {
 	pub const StorageDepositPerByte: u64 = 10;
 	pub const DeveloperDeposit: u64 = 1000;
 	pub const DeploymentFee: u64 = 200;
+	pub const PublicationFee: u64 = 200;
+	pub const BaseFeeHistoryLength: u32 = 10;
 	pub const ChainId: u64 = 1;
 }
 
@@ -400,12 +420,17 @@ impl module_evm::Config for Test {
 	type Event = Event;
 	type Precompiles = AllPrecompiles<
 		SystemContractsFilter,
+		(),
 		MultiCurrencyPrecompile,
 		NFTPrecompile,
 		StateRentPrecompile,
 		OraclePrecompile,
 		ScheduleCallPrecompile,
 		DexPrecompile,
+		HomaPrecompile,
+		HonzonPrecompile,
+		IncentivesPrecompile,
+		XtokensPrecompile,
 	>;
 	type ChainId = ChainId;
 	type GasToWeight = GasToWeight;
@@ -414,10 +439,12 @@ impl module_evm::Config for Test {
 	type NetworkContractSource = NetworkContractSource;
 	type DeveloperDeposit = DeveloperDeposit;
 	type DeploymentFee = DeploymentFee;
+	type PublicationFee = PublicationFee;
 	type TreasuryAccount = TreasuryAccount;
 	type FreeDeploymentOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
+	type BaseFeeHistoryLength = BaseFeeHistoryLength;
 	type WeightInfo = ();
 }
 
@@ -428,10 +455,167 @@ impl ExchangeRateProvider for MockLiquidStakingExchangeProvider {
 	}
 }
 
+/// Mints LDOT 2:1 with DOT, the fast redeem is paid immediately and the normal redeem is paid
+/// after unbonding.
+pub struct MockHoma;
+impl HomaManager<AccountId, Balance> for MockHoma {
+	fn mint(who: &AccountId, amount: Balance) -> DispatchResult {
+		Tokens::withdraw(DOT, who, amount)?;
+		Tokens::deposit(LDOT, who, amount * 2)
+	}
+
+	fn request_redeem(who: &AccountId, amount: Balance, fast_match: bool) -> DispatchResult {
+		Tokens::withdraw(LDOT, who, amount)?;
+		if fast_match {
+			Tokens::deposit(DOT, who, amount / 2)?;
+		}
+		Ok(())
+	}
+
+	fn get_exchange_rate() -> ExchangeRate {
+		MockLiquidStakingExchangeProvider::get_exchange_rate()
+	}
+}
+
+thread_local! {
+	static POSITIONS: RefCell<BTreeMap<(AccountId, CurrencyId), (Balance, Balance)>> = RefCell::new(BTreeMap::new());
+}
+
+/// Locks collateral and issues AUSD 1:1 with debit, the liquidation ratio is fixed at 150%.
+pub struct MockHonzon;
+impl HonzonManager<AccountId, CurrencyId, Amount, Balance> for MockHonzon {
+	fn adjust_loan(
+		who: &AccountId,
+		currency_id: CurrencyId,
+		collateral_adjustment: Amount,
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		let (collateral, debit) = Self::get_position(who, currency_id);
+
+		let collateral = if collateral_adjustment.is_positive() {
+			Tokens::withdraw(currency_id, who, collateral_adjustment.unsigned_abs())?;
+			collateral + collateral_adjustment.unsigned_abs()
+		} else {
+			let collateral = collateral
+				.checked_sub(collateral_adjustment.unsigned_abs())
+				.ok_or(DispatchError::Other("CollateralTooLow"))?;
+			Tokens::deposit(currency_id, who, collateral_adjustment.unsigned_abs())?;
+			collateral
+		};
+
+		let debit = if debit_adjustment.is_positive() {
+			Tokens::deposit(AUSD, who, debit_adjustment.unsigned_abs())?;
+			debit + debit_adjustment.unsigned_abs()
+		} else {
+			let debit = debit
+				.checked_sub(debit_adjustment.unsigned_abs())
+				.ok_or(DispatchError::Other("DebitTooLow"))?;
+			Tokens::withdraw(AUSD, who, debit_adjustment.unsigned_abs())?;
+			debit
+		};
+
+		POSITIONS.with(|v| v.borrow_mut().insert((who.clone(), currency_id), (collateral, debit)));
+		Ok(())
+	}
+
+	fn get_position(who: &AccountId, currency_id: CurrencyId) -> (Balance, Balance) {
+		POSITIONS.with(|v| v.borrow().get(&(who.clone(), currency_id)).copied().unwrap_or_default())
+	}
+
+	fn get_liquidation_ratio(_currency_id: CurrencyId) -> Ratio {
+		Ratio::saturating_from_rational(3, 2)
+	}
+
+	fn get_current_collateral_ratio(who: &AccountId, currency_id: CurrencyId) -> Option<Ratio> {
+		let (collateral, debit) = Self::get_position(who, currency_id);
+		Ratio::checked_from_rational(collateral, debit)
+	}
+
+	fn get_debit_exchange_rate(_currency_id: CurrencyId) -> ExchangeRate {
+		ExchangeRate::one()
+	}
+}
+
+thread_local! {
+	static PENDING_REWARDS: RefCell<BTreeMap<(PoolId, AccountId), Balance>> = RefCell::new(BTreeMap::new());
+}
+
+/// Reserves the staked LP token and accrues AUSD rewards of 10% of the staked
+/// amount.
+pub struct MockIncentives;
+impl IncentivesManager<AccountId, Balance, CurrencyId, PoolId> for MockIncentives {
+	fn deposit_dex_share(who: &AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+		Tokens::reserve(lp_currency_id, who, amount)?;
+		PENDING_REWARDS.with(|v| {
+			*v.borrow_mut()
+				.entry((PoolId::Dex(lp_currency_id), who.clone()))
+				.or_default() += amount / 10
+		});
+		Ok(())
+	}
+
+	fn withdraw_dex_share(who: &AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+		let _ = Tokens::unreserve(lp_currency_id, who, amount);
+		Ok(())
+	}
+
+	fn claim_rewards(who: &AccountId, pool_id: PoolId) -> DispatchResult {
+		let reward = PENDING_REWARDS.with(|v| v.borrow_mut().remove(&(pool_id, who.clone())).unwrap_or_default());
+		Tokens::deposit(AUSD, who, reward)
+	}
+
+	fn get_pending_rewards(pool_id: PoolId, who: &AccountId, reward_currency: CurrencyId) -> Balance {
+		if reward_currency != AUSD {
+			return 0;
+		}
+		PENDING_REWARDS.with(|v| v.borrow().get(&(pool_id, who.clone())).copied().unwrap_or_default())
+	}
+}
+
+thread_local! {
+	static XCM_TRANSFERS: RefCell<Vec<(AccountId, CurrencyId, Balance, MultiLocation, Weight)>> = RefCell::new(vec![]);
+}
+
+/// Burns the transferred tokens and records the transfers.
+pub struct MockXcmTransfer;
+impl MockXcmTransfer {
+	pub fn transfers() -> Vec<(AccountId, CurrencyId, Balance, MultiLocation, Weight)> {
+		XCM_TRANSFERS.with(|v| v.borrow().clone())
+	}
+}
+impl XcmTransfer<AccountId, Balance, CurrencyId> for MockXcmTransfer {
+	fn transfer(
+		who: AccountId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		dest: MultiLocation,
+		dest_weight: Weight,
+	) -> DispatchResult {
+		Tokens::withdraw(currency_id, &who, amount)?;
+		XCM_TRANSFERS.with(|v| v.borrow_mut().push((who, currency_id, amount, dest, dest_weight)));
+		Ok(())
+	}
+
+	fn transfer_multi_asset(
+		_who: AccountId,
+		_asset: MultiAsset,
+		_dest: MultiLocation,
+		_dest_weight: Weight,
+	) -> DispatchResult {
+		Err(DispatchError::Other("NotSupported"))
+	}
+}
+
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
 	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
+	pub const FeedWindow: BlockNumber = 10;
+	pub const MaxMissedFeedWindows: u32 = 6;
+	pub MaxPriceChangeRatio: Ratio = Ratio::saturating_from_rational(30, 100);
+	pub const CircuitBreakerWindow: BlockNumber = 10;
+	pub const CircuitBreakerCooldown: BlockNumber = 100;
+	pub const RemotePriceStaleness: BlockNumber = 100;
 }
 
 ord_parameter_types! {
@@ -450,6 +634,14 @@ impl module_prices::Config for Test {
 	type DEX = DexModule;
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping;
+	type OracleOperators = Members;
+	type FeedWindow = FeedWindow;
+	type MaxMissedFeedWindows = MaxMissedFeedWindows;
+	type MaxPriceChangeRatio = MaxPriceChangeRatio;
+	type CircuitBreakerWindow = CircuitBreakerWindow;
+	type CircuitBreakerCooldown = CircuitBreakerCooldown;
+	type RemoteOracleOrigin = EnsureSignedBy<One, AccountId>;
+	type RemotePriceStaleness = RemotePriceStaleness;
 	type WeightInfo = ();
 }
 
@@ -512,7 +704,7 @@ frame_support::construct_runtime!(
 		Currencies: module_currencies::{Pallet, Call, Event<T>},
 		EVMBridge: module_evm_bridge::{Pallet},
 		EVMManager: module_evm_manager::{Pallet, Storage},
-		NFTModule: module_nft::{Pallet, Call, Event<T>},
+		NFTModule: module_nft::{Pallet, Call, Storage, Event<T>},
 		TransactionPayment: module_transaction_payment::{Pallet, Call, Storage},
 		Prices: module_prices::{Pallet, Storage, Call, Event<T>},
 		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
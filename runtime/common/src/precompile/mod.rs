@@ -21,7 +21,7 @@
 
 #![allow(clippy::upper_case_acronyms)]
 
-mod mock;
+pub(crate) mod mock;
 mod tests;
 
 use crate::is_acala_precompile;
@@ -34,71 +34,107 @@ use module_evm::{
 	runner::state::PrecompileOutput,
 	Context, ExitError,
 };
-use module_support::PrecompileCallerFilter as PrecompileCallerFilterT;
+use module_support::{
+	PrecompileCallerFilter as PrecompileCallerFilterT, PrecompilePauseFilter as PrecompilePauseFilterT,
+};
 use primitives::PRECOMPILE_ADDRESS_START;
 use sp_core::H160;
 use sp_std::marker::PhantomData;
 
 pub mod dex;
+pub mod homa;
+pub mod honzon;
+pub mod incentives;
 pub mod input;
 pub mod multicurrency;
 pub mod nft;
 pub mod oracle;
 pub mod schedule_call;
 pub mod state_rent;
+pub mod xtokens;
 
 pub use dex::DexPrecompile;
+pub use homa::HomaPrecompile;
+pub use honzon::HonzonPrecompile;
+pub use incentives::IncentivesPrecompile;
 pub use multicurrency::MultiCurrencyPrecompile;
 pub use nft::NFTPrecompile;
 pub use oracle::OraclePrecompile;
 pub use schedule_call::ScheduleCallPrecompile;
 pub use state_rent::StateRentPrecompile;
+pub use xtokens::{DisabledXcmTransfer, XtokensPrecompile};
 
 pub struct AllPrecompiles<
 	PrecompileCallerFilter,
+	PrecompilePauseFilter,
 	MultiCurrencyPrecompile,
 	NFTPrecompile,
 	StateRentPrecompile,
 	OraclePrecompile,
 	ScheduleCallPrecompile,
 	DexPrecompile,
+	HomaPrecompile,
+	HonzonPrecompile,
+	IncentivesPrecompile,
+	XtokensPrecompile,
 >(
 	PhantomData<(
 		PrecompileCallerFilter,
+		PrecompilePauseFilter,
 		MultiCurrencyPrecompile,
 		NFTPrecompile,
 		StateRentPrecompile,
 		OraclePrecompile,
 		ScheduleCallPrecompile,
 		DexPrecompile,
+		HomaPrecompile,
+		HonzonPrecompile,
+		IncentivesPrecompile,
+		XtokensPrecompile,
 	)>,
 );
 
 impl<
 		PrecompileCallerFilter,
+		PrecompilePauseFilter,
 		MultiCurrencyPrecompile,
 		NFTPrecompile,
 		StateRentPrecompile,
 		OraclePrecompile,
 		ScheduleCallPrecompile,
 		DexPrecompile,
+		HomaPrecompile,
+		HonzonPrecompile,
+		IncentivesPrecompile,
+		XtokensPrecompile,
 	> PrecompileSet
 	for AllPrecompiles<
 		PrecompileCallerFilter,
+		PrecompilePauseFilter,
 		MultiCurrencyPrecompile,
 		NFTPrecompile,
 		StateRentPrecompile,
 		OraclePrecompile,
 		ScheduleCallPrecompile,
 		DexPrecompile,
-	> where
+		HomaPrecompile,
+		HonzonPrecompile,
+		IncentivesPrecompile,
+		XtokensPrecompile,
+	>
+where
 	MultiCurrencyPrecompile: Precompile,
 	NFTPrecompile: Precompile,
 	StateRentPrecompile: Precompile,
 	OraclePrecompile: Precompile,
 	ScheduleCallPrecompile: Precompile,
 	PrecompileCallerFilter: PrecompileCallerFilterT,
+	PrecompilePauseFilter: PrecompilePauseFilterT,
 	DexPrecompile: Precompile,
+	HomaPrecompile: Precompile,
+	HonzonPrecompile: Precompile,
+	IncentivesPrecompile: Precompile,
+	XtokensPrecompile: Precompile,
 {
 	#[allow(clippy::type_complexity)]
 	fn execute(
@@ -120,6 +156,11 @@ impl<
 				return Some(Err(ExitError::Other("no permission".into())));
 			}
 
+			if PrecompilePauseFilter::is_paused(address) {
+				log::debug!(target: "evm", "Precompile paused, address: {:?}", address);
+				return Some(Err(ExitError::Other("precompile is paused".into())));
+			}
+
 			log::debug!(target: "evm", "Precompile begin, address: {:?}, input: {:?}, target_gas: {:?}, context: {:?}", address, input, target_gas, context);
 
 			let result = if address == H160::from_low_u64_be(PRECOMPILE_ADDRESS_START) {
@@ -134,6 +175,14 @@ impl<
 				Some(ScheduleCallPrecompile::execute(input, target_gas, context))
 			} else if address == H160::from_low_u64_be(PRECOMPILE_ADDRESS_START + 5) {
 				Some(DexPrecompile::execute(input, target_gas, context))
+			} else if address == H160::from_low_u64_be(PRECOMPILE_ADDRESS_START + 6) {
+				Some(HomaPrecompile::execute(input, target_gas, context))
+			} else if address == H160::from_low_u64_be(PRECOMPILE_ADDRESS_START + 7) {
+				Some(HonzonPrecompile::execute(input, target_gas, context))
+			} else if address == H160::from_low_u64_be(PRECOMPILE_ADDRESS_START + 8) {
+				Some(IncentivesPrecompile::execute(input, target_gas, context))
+			} else if address == H160::from_low_u64_be(PRECOMPILE_ADDRESS_START + 9) {
+				Some(XtokensPrecompile::execute(input, target_gas, context))
 			} else {
 				None
 			};
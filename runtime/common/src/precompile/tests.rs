@@ -22,9 +22,10 @@ use super::*;
 use crate::precompile::{
 	mock::{
 		aca_evm_address, alice, alice_evm_addr, ausd_evm_address, bob, bob_evm_addr, erc20_address_not_exists,
-		get_task_id, lp_aca_ausd_evm_address, new_test_ext, renbtc_evm_address, run_to_block, Balances, DexModule,
-		DexPrecompile, Event as TestEvent, MultiCurrencyPrecompile, Oracle, OraclePrecompile, Origin, Price,
-		ScheduleCallPrecompile, System, Test, ALICE, AUSD, INITIAL_BALANCE, RENBTC,
+		get_task_id, lp_aca_ausd_evm_address, new_test_ext, renbtc_evm_address, run_to_block, Balances, Currencies,
+		DexModule, DexPrecompile, Event as TestEvent, HomaPrecompile, HonzonPrecompile, IncentivesPrecompile,
+		MockXcmTransfer, MultiCurrencyPrecompile, Oracle, OraclePrecompile, Origin, Price, ScheduleCallPrecompile,
+		System, Test, XtokensPrecompile, ALICE, AUSD, DOT, INITIAL_BALANCE, LDOT, LP_ACA_AUSD, RENBTC,
 	},
 	schedule_call::TaskInfo,
 };
@@ -33,11 +34,12 @@ use frame_support::{assert_noop, assert_ok};
 use hex_literal::hex;
 use module_evm::{Context, ExitError, ExitSucceed, Precompile};
 use module_support::AddressMapping;
-use orml_traits::DataFeeder;
+use orml_traits::{DataFeeder, MultiCurrency};
 use primitives::{Balance, PREDEPLOY_ADDRESS_START};
 use sp_core::{H160, U256};
 use sp_runtime::FixedPointNumber;
 use std::str::FromStr;
+use xcm::v0::{Junction, MultiLocation, NetworkId};
 
 pub struct DummyPrecompile;
 impl Precompile for DummyPrecompile {
@@ -57,6 +59,11 @@ impl Precompile for DummyPrecompile {
 
 pub type WithSystemContractFilter = AllPrecompiles<
 	crate::SystemContractsFilter,
+	(),
+	DummyPrecompile,
+	DummyPrecompile,
+	DummyPrecompile,
+	DummyPrecompile,
 	DummyPrecompile,
 	DummyPrecompile,
 	DummyPrecompile,
@@ -884,6 +891,350 @@ fn dex_precompile_swap_with_exact_target_should_work() {
 	});
 }
 
+#[test]
+fn homa_precompile_mint_and_redeem_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Currencies::update_balance(Origin::root(), alice(), DOT, 1_000));
+
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// action + who + amount
+		let mut input = [0u8; 3 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(homa::Action::MintLiquid).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// amount
+		U256::from(100).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+
+		let resp = HomaPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, [0u8; 0]);
+		assert_eq!(Currencies::free_balance(DOT, &alice()), 900);
+		assert_eq!(Currencies::free_balance(LDOT, &alice()), 200);
+
+		// action + who + amount + fast_match
+		let mut input = [0u8; 4 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(homa::Action::RequestRedeem).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// amount
+		U256::from(100).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// fast_match
+		U256::from(1).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+
+		let resp = HomaPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(Currencies::free_balance(DOT, &alice()), 950);
+		assert_eq!(Currencies::free_balance(LDOT, &alice()), 100);
+
+		// fast_match = false
+		U256::from(0).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+
+		let resp = HomaPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(Currencies::free_balance(DOT, &alice()), 950);
+		assert_eq!(Currencies::free_balance(LDOT, &alice()), 0);
+
+		// insufficient LDOT
+		assert_noop!(
+			HomaPrecompile::execute(&input, None, &context),
+			ExitError::Other("BalanceTooLow".into())
+		);
+	});
+}
+
+#[test]
+fn homa_precompile_get_exchange_rate_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// action
+		let mut input = [0u8; 4];
+		input[0..4].copy_from_slice(&Into::<u32>::into(homa::Action::GetExchangeRate).to_be_bytes());
+
+		// 0.5 with 18 decimals
+		let mut expected_output = [0u8; 32];
+		U256::from(500_000_000_000_000_000u128).to_big_endian(&mut expected_output[..32]);
+
+		let resp = HomaPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(resp.cost, 0);
+	});
+}
+
+#[test]
+fn honzon_precompile_adjust_loan_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// action + who + currency_id + collateral_adjustment + debit_adjustment
+		let mut input = [0u8; 5 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(honzon::Action::AdjustLoan).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// currency_id
+		U256::from(renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// collateral_adjustment
+		U256::from(1_000).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// debit_adjustment
+		U256::from(500).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+
+		let resp = HonzonPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, [0u8; 0]);
+		assert_eq!(Currencies::free_balance(RENBTC, &alice()), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &alice()), 500);
+
+		// collateral_adjustment, -200 as int128
+		input[4 + 2 * 32..4 + 3 * 32].copy_from_slice(&[0xffu8; 32]);
+		input[4 + 3 * 32 - 16..4 + 3 * 32].copy_from_slice(&(-200i128).to_be_bytes());
+		// debit_adjustment, -100 as int128
+		input[4 + 3 * 32..4 + 4 * 32].copy_from_slice(&[0xffu8; 32]);
+		input[4 + 4 * 32 - 16..4 + 4 * 32].copy_from_slice(&(-100i128).to_be_bytes());
+
+		let resp = HonzonPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(Currencies::free_balance(RENBTC, &alice()), 200);
+		assert_eq!(Currencies::free_balance(AUSD, &alice()), 400);
+
+		// action + who + currency_id
+		let mut input = [0u8; 3 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(honzon::Action::GetPosition).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// currency_id
+		U256::from(renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+
+		let mut expected_output = [0u8; 64];
+		U256::from(800).to_big_endian(&mut expected_output[..32]);
+		U256::from(400).to_big_endian(&mut expected_output[32..64]);
+
+		let resp = HonzonPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+
+		input[0..4].copy_from_slice(&Into::<u32>::into(honzon::Action::GetCurrentCollateralRatio).to_be_bytes());
+
+		// 2 with 18 decimals
+		let mut expected_output = [0u8; 32];
+		U256::from(2_000_000_000_000_000_000u128).to_big_endian(&mut expected_output[..32]);
+
+		let resp = HonzonPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+	});
+}
+
+#[test]
+fn honzon_precompile_get_risk_params_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// action + currency_id
+		let mut input = [0u8; 2 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(honzon::Action::GetLiquidationRatio).to_be_bytes());
+		// currency_id
+		U256::from(renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+
+		// 1.5 with 18 decimals
+		let mut expected_output = [0u8; 32];
+		U256::from(1_500_000_000_000_000_000u128).to_big_endian(&mut expected_output[..32]);
+
+		let resp = HonzonPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+
+		input[0..4].copy_from_slice(&Into::<u32>::into(honzon::Action::GetDebitExchangeRate).to_be_bytes());
+
+		// 1 with 18 decimals
+		let mut expected_output = [0u8; 32];
+		U256::from(1_000_000_000_000_000_000u128).to_big_endian(&mut expected_output[..32]);
+
+		let resp = HonzonPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+	});
+}
+
+#[test]
+fn incentives_precompile_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Currencies::update_balance(Origin::root(), alice(), LP_ACA_AUSD, 1_000));
+
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// action + who + lp_currency_id + amount
+		let mut input = [0u8; 4 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(incentives::Action::DepositDexShare).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// lp_currency_id
+		U256::from(lp_aca_ausd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// amount
+		U256::from(1_000).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+
+		let resp = IncentivesPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, [0u8; 0]);
+		assert_eq!(Currencies::free_balance(LP_ACA_AUSD, &alice()), 0);
+
+		// action + pool_type + currency_id + who + reward_currency_id
+		let mut input = [0u8; 5 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(incentives::Action::GetPendingRewards).to_be_bytes());
+		// pool_type
+		U256::from(1).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// currency_id
+		U256::from(lp_aca_ausd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// reward_currency_id
+		U256::from(ausd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+
+		let mut expected_output = [0u8; 32];
+		U256::from(100).to_big_endian(&mut expected_output[..32]);
+
+		let resp = IncentivesPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+
+		// action + who + pool_type + currency_id
+		let mut input = [0u8; 4 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(incentives::Action::ClaimRewards).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// pool_type
+		U256::from(1).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// currency_id
+		U256::from(lp_aca_ausd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+
+		let resp = IncentivesPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(Currencies::free_balance(AUSD, &alice()), 100);
+
+		// invalid pool_type
+		U256::from(2).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		assert_noop!(
+			IncentivesPrecompile::execute(&input, None, &context),
+			ExitError::Other("invalid pool type".into())
+		);
+
+		// action + who + lp_currency_id + amount
+		let mut input = [0u8; 4 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(incentives::Action::WithdrawDexShare).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// lp_currency_id
+		U256::from(lp_aca_ausd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// amount
+		U256::from(1_000).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+
+		let resp = IncentivesPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(Currencies::free_balance(LP_ACA_AUSD, &alice()), 1_000);
+	});
+}
+
+#[test]
+fn xtokens_precompile_transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// action + who + currency_id + amount + para_id + dest_account + dest_weight
+		let mut input = [0u8; 7 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(xtokens::Action::Transfer).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// currency_id
+		U256::from(renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// amount
+		U256::from(100).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// para_id
+		U256::from(2000).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+		// dest_account
+		input[4 + 4 * 32..4 + 5 * 32].copy_from_slice(&[2u8; 32]);
+		// dest_weight
+		U256::from(1_000_000).to_big_endian(&mut input[4 + 5 * 32..4 + 6 * 32]);
+
+		let resp = XtokensPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, [0u8; 0]);
+		assert_eq!(Currencies::free_balance(RENBTC, &alice()), 900);
+
+		// transfer to the relay chain
+		U256::from(0).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+
+		let resp = XtokensPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(Currencies::free_balance(RENBTC, &alice()), 800);
+
+		let dest_account = Junction::AccountId32 {
+			network: NetworkId::Any,
+			id: [2u8; 32],
+		};
+		assert_eq!(
+			MockXcmTransfer::transfers(),
+			vec![
+				(
+					alice(),
+					RENBTC,
+					100,
+					MultiLocation::X3(Junction::Parent, Junction::Parachain(2000), dest_account.clone()),
+					1_000_000
+				),
+				(
+					alice(),
+					RENBTC,
+					100,
+					MultiLocation::X2(Junction::Parent, dest_account),
+					1_000_000
+				),
+			]
+		);
+
+		// insufficient balance
+		U256::from(1_000).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		assert_noop!(
+			XtokensPrecompile::execute(&input, None, &context),
+			ExitError::Other("BalanceTooLow".into())
+		);
+	});
+}
+
 #[test]
 fn task_id_max_and_min() {
 	let task_id = TaskInfo {
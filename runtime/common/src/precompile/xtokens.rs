@@ -0,0 +1,125 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::input::{Input, InputT, PER_PARAM_BYTES};
+use crate::precompile::PrecompileOutput;
+use frame_support::{log, weights::Weight};
+use module_evm::{Context, ExitError, ExitSucceed, Precompile};
+use module_support::{AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use orml_traits::XcmTransfer as XcmTransferT;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{DispatchError, DispatchResult, RuntimeDebug};
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
+use xcm::v0::{Junction, MultiAsset, MultiLocation, NetworkId};
+
+/// The `Xtokens` impl precompile.
+///
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Transfer. Rest `input` bytes: `who`, `currency_id`, `amount`, `para_id`, `dest_account`,
+///   `dest_weight`. `para_id` of 0 means the relay chain.
+pub struct XtokensPrecompile<AccountId, AddressMapping, CurrencyIdMapping, XcmTransfer>(
+	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, XcmTransfer)>,
+);
+
+#[primitives_proc_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	Transfer = "transfer(address,address,uint256,uint32,bytes32,uint64)",
+}
+
+impl<AccountId, AddressMapping, CurrencyIdMapping, XcmTransfer> Precompile
+	for XtokensPrecompile<AccountId, AddressMapping, CurrencyIdMapping, XcmTransfer>
+where
+	AccountId: Debug + Clone,
+	AddressMapping: AddressMappingT<AccountId>,
+	CurrencyIdMapping: CurrencyIdMappingT,
+	XcmTransfer: XcmTransferT<AccountId, Balance, CurrencyId>,
+{
+	fn execute(
+		input: &[u8],
+		_target_gas: Option<u64>,
+		_context: &Context,
+	) -> result::Result<PrecompileOutput, ExitError> {
+		let input = Input::<Action, AccountId, AddressMapping, CurrencyIdMapping>::new(input);
+
+		let action = input.action()?;
+
+		match action {
+			Action::Transfer => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let amount = input.balance_at(3)?;
+				let para_id = input.u32_at(4)?;
+				let mut dest_account = [0u8; 32];
+				dest_account.copy_from_slice(input.nth_param(5, Some(PER_PARAM_BYTES))?);
+				let dest_weight = input.u64_at(6)?;
+
+				let dest = dest_location(para_id, dest_account);
+				log::debug!(
+					target: "evm",
+					"xtokens: transfer who: {:?}, currency_id: {:?}, amount: {:?}, dest: {:?}, dest_weight: {:?}",
+					who, currency_id, amount, dest, dest_weight
+				);
+
+				XcmTransfer::transfer(who, currency_id, amount, dest, dest_weight).map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+		}
+	}
+}
+
+/// The location of `account` on the relay chain if `para_id` is 0, otherwise on
+/// the sibling parachain `para_id`.
+pub fn dest_location(para_id: u32, account: [u8; 32]) -> MultiLocation {
+	let account = Junction::AccountId32 {
+		network: NetworkId::Any,
+		id: account,
+	};
+	if para_id == 0 {
+		MultiLocation::X2(Junction::Parent, account)
+	} else {
+		MultiLocation::X3(Junction::Parent, Junction::Parachain(para_id), account)
+	}
+}
+
+/// The `XcmTransfer` of the runtimes which don't support XCM transfers yet, all
+/// transfers are rejected.
+pub struct DisabledXcmTransfer;
+impl<AccountId> XcmTransferT<AccountId, Balance, CurrencyId> for DisabledXcmTransfer {
+	fn transfer(_: AccountId, _: CurrencyId, _: Balance, _: MultiLocation, _: Weight) -> DispatchResult {
+		Err(DispatchError::Other("XcmTransferDisabled"))
+	}
+
+	fn transfer_multi_asset(_: AccountId, _: MultiAsset, _: MultiLocation, _: Weight) -> DispatchResult {
+		Err(DispatchError::Other("XcmTransferDisabled"))
+	}
+}
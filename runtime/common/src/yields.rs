@@ -0,0 +1,165 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Staking and incentive yields, estimated in the runtime.
+
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+use module_support::{ExchangeRate, PoolId, PriceProvider, Rate};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{One, Saturating, Zero},
+	FixedPointNumber,
+};
+use sp_std::prelude::*;
+
+/// The APR of an incentive pool.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct IncentivePoolApr {
+	pub pool_id: PoolId,
+	/// The value of the shares of the pool, in stable currency.
+	pub total_value_locked: Balance,
+	/// The value of the rewards accumulated in a year to `total_value_locked`.
+	pub apr: Rate,
+}
+
+/// The yields of liquid staking and the incentive pools.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Yields {
+	/// The amount of staking currency one liquid currency is worth.
+	pub liquid_exchange_rate: ExchangeRate,
+	/// The projected APY of the staking currency, compounded every era.
+	pub staking_apy: Rate,
+	/// The incentive pools whose shares and rewards can be priced.
+	pub incentive_pools: Vec<IncentivePoolApr>,
+}
+
+/// The APY of `rate_per_period` compounded `periods_per_year` times.
+pub fn compounded_apy(rate_per_period: Rate, periods_per_year: u32) -> Rate {
+	Rate::one()
+		.saturating_add(rate_per_period)
+		.saturating_pow(periods_per_year as usize)
+		.saturating_sub(Rate::one())
+}
+
+/// The APR of `pool_id` with `total_shares`, the `rewards` are accumulated
+/// `periods_per_year` times. `None` if the shares or any of the rewards can't
+/// be priced by `P`, or the pool is empty.
+pub fn incentive_pool_apr<P: PriceProvider<CurrencyId>>(
+	pool_id: PoolId,
+	total_shares: Balance,
+	rewards: &[(CurrencyId, Balance)],
+	periods_per_year: u32,
+) -> Option<IncentivePoolApr> {
+	let share_currency_id = match pool_id {
+		PoolId::Loans(currency_id) | PoolId::Dex(currency_id) => currency_id,
+	};
+	let total_value_locked = P::get_price(share_currency_id)?.checked_mul_int(total_shares)?;
+	let reward_value = rewards
+		.iter()
+		.try_fold(Balance::zero(), |value, (currency_id, amount)| {
+			Some(value.saturating_add(P::get_price(*currency_id)?.checked_mul_int(*amount)?))
+		})?;
+	let apr = Rate::checked_from_rational(
+		reward_value.saturating_mul(Balance::from(periods_per_year)),
+		total_value_locked,
+	)?;
+
+	Some(IncentivePoolApr {
+		pool_id,
+		total_value_locked,
+		apr,
+	})
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait YieldsApi {
+		/// The liquid staking exchange rate, the projected staking APY and the APRs of the incentive pools.
+		fn get_yields() -> Yields;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use module_support::Price;
+	use primitives::currency::{ACA, AUSD, DOT, RENBTC};
+
+	pub struct MockPriceSource;
+	impl PriceProvider<CurrencyId> for MockPriceSource {
+		fn get_price(currency_id: CurrencyId) -> Option<Price> {
+			match currency_id {
+				ACA => Some(Price::saturating_from_rational(1, 2)),
+				AUSD => Some(Price::one()),
+				DOT => Some(Price::saturating_from_integer(10)),
+				_ => None,
+			}
+		}
+	}
+
+	#[test]
+	fn compounded_apy_works() {
+		assert_eq!(compounded_apy(Rate::zero(), 365), Rate::zero());
+		assert_eq!(
+			compounded_apy(Rate::saturating_from_rational(1, 10), 1),
+			Rate::saturating_from_rational(1, 10)
+		);
+		assert_eq!(
+			compounded_apy(Rate::saturating_from_rational(1, 10), 2),
+			Rate::saturating_from_rational(21, 100)
+		);
+	}
+
+	#[test]
+	fn incentive_pool_apr_works() {
+		// 1000 DOT worth 10000 AUSD, 100 ACA and 10 AUSD worth 60 AUSD every period
+		assert_eq!(
+			incentive_pool_apr::<MockPriceSource>(PoolId::Loans(DOT), 1000, &[(ACA, 100), (AUSD, 10)], 10),
+			Some(IncentivePoolApr {
+				pool_id: PoolId::Loans(DOT),
+				total_value_locked: 10000,
+				apr: Rate::saturating_from_rational(6, 100),
+			})
+		);
+		assert_eq!(
+			incentive_pool_apr::<MockPriceSource>(PoolId::Loans(DOT), 1000, &[], 10),
+			Some(IncentivePoolApr {
+				pool_id: PoolId::Loans(DOT),
+				total_value_locked: 10000,
+				apr: Rate::zero(),
+			})
+		);
+		assert_eq!(
+			incentive_pool_apr::<MockPriceSource>(PoolId::Loans(DOT), 0, &[(ACA, 100)], 10),
+			None
+		);
+		assert_eq!(
+			incentive_pool_apr::<MockPriceSource>(PoolId::Loans(ACA), 1000, &[(DOT, 100), (ACA, 100)], 10),
+			Some(IncentivePoolApr {
+				pool_id: PoolId::Loans(ACA),
+				total_value_locked: 500,
+				apr: Rate::saturating_from_integer(21),
+			})
+		);
+		assert_eq!(
+			incentive_pool_apr::<MockPriceSource>(PoolId::Loans(RENBTC), 1000, &[(ACA, 100)], 10),
+			None
+		);
+	}
+}
@@ -54,6 +54,9 @@ pub mod honzon {
 pub mod incentives {
 	include!("../../../mandala/src/benchmarking/incentives.rs");
 }
+pub mod payments {
+	include!("../../../mandala/src/benchmarking/payments.rs");
+}
 pub mod prices {
 	include!("../../../mandala/src/benchmarking/prices.rs");
 }
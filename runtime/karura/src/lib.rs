@@ -39,8 +39,8 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H160};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdConversion, AccountIdLookup, BadOrigin, BlakeTwo256, Block as BlockT, Convert, SaturatedConversion,
-		StaticLookup, Zero,
+		AccountIdConversion, AccountIdLookup, BadOrigin, BlakeTwo256, Block as BlockT, BlockNumberProvider, Convert,
+		SaturatedConversion, StaticLookup, Zero,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, DispatchResult, FixedPointNumber,
@@ -51,9 +51,10 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
 use frame_system::{EnsureRoot, RawOrigin};
+use module_asset_registry::FeePerSecondOf;
 use module_currencies::BasicCurrencyAdapter;
 use module_evm::Runner;
-use module_evm::{CallInfo, CreateInfo};
+use module_evm::{CallInfo, ContractStorageStatement, CreateInfo, StateOverride, TraceOutcome, TracerConfig};
 use module_evm_accounts::EvmAddressMapping;
 use module_evm_manager::EvmCurrencyIdMapping;
 use module_transaction_payment::{Multiplier, TargetedFeeAdjustment};
@@ -71,7 +72,7 @@ pub use xcm::v0::{
 	Junction::{self, AccountId32, GeneralKey, Parachain, Parent},
 	MultiAsset,
 	MultiLocation::{self, X1, X2, X3},
-	NetworkId, Xcm,
+	NetworkId, Order, OriginKind, Xcm,
 };
 
 pub use xcm_builder::{
@@ -80,7 +81,10 @@ pub use xcm_builder::{
 	ParentIsDefault, RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
 	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeRevenue, TakeWeightCredit,
 };
-pub use xcm_executor::{traits::WeightTrader, Assets, Config, XcmExecutor};
+pub use xcm_executor::{
+	traits::{ConvertOrigin, WeightBounds, WeightTrader},
+	Assets, Config, XcmExecutor,
+};
 
 /// Weights for pallets used in the runtime.
 mod weights;
@@ -89,10 +93,10 @@ pub use frame_support::{
 	construct_runtime, log, parameter_types,
 	traits::{
 		Contains, ContainsLengthBound, Currency as PalletCurrency, EnsureOrigin, Everything, Get, Imbalance,
-		InstanceFilter, IsSubType, IsType, KeyOwnerProofSystem, LockIdentifier, OnUnbalanced, Randomness,
+		InstanceFilter, IsSubType, IsType, KeyOwnerProofSystem, LockIdentifier, OnUnbalanced, OriginTrait, Randomness,
 		SortedMembers, U128CurrencyToVote,
 	},
-	weights::{constants::RocksDbWeight, IdentityFee, Weight},
+	weights::{constants::RocksDbWeight, GetDispatchInfo, IdentityFee, Weight},
 	PalletId, RuntimeDebug, StorageValue,
 };
 
@@ -110,7 +114,7 @@ pub use primitives::{
 	TokenSymbol, TradingPair,
 };
 pub use runtime_common::{
-	cent, dollar, microcent, millicent, CurveFeeModel, EnsureRootOrAllGeneralCouncil,
+	cent, dollar, microcent, millicent, AggregatedCombineData, CurveFeeModel, EnsureRootOrAllGeneralCouncil,
 	EnsureRootOrAllTechnicalCommittee, EnsureRootOrHalfFinancialCouncil, EnsureRootOrHalfGeneralCouncil,
 	EnsureRootOrHalfHomaCouncil, EnsureRootOrOneGeneralCouncil, EnsureRootOrOneThirdsTechnicalCommittee,
 	EnsureRootOrThreeFourthsGeneralCouncil, EnsureRootOrTwoThirdsGeneralCouncil,
@@ -210,7 +214,7 @@ impl Contains<Call> for BaseCallFilter {
 
 		let is_evm = matches!(
 			call,
-			Call::EVM(_) | Call::EvmAccounts(_) // EvmBridge / EvmManager does not have call
+			Call::EVM(_) | Call::EvmAccounts(_) | Call::EvmManager(_) // EvmBridge does not have call
 		);
 		if is_evm {
 			// no evm call
@@ -305,6 +309,8 @@ parameter_types! {
 	pub const MaxInvulnerables: u32 = 10;
 	pub const KickPenaltySessionLength: u32 = 8;
 	pub const CollatorKickThreshold: Permill = Permill::from_percent(85);
+	pub const CollatorKickMissedSessions: u32 = 2;
+	pub const CollatorKickSlash: Permill = Permill::from_percent(10);
 }
 
 impl module_collator_selection::Config for Runtime {
@@ -318,6 +324,8 @@ impl module_collator_selection::Config for Runtime {
 	type MaxInvulnerables = MaxInvulnerables;
 	type KickPenaltySessionLength = KickPenaltySessionLength;
 	type CollatorKickThreshold = CollatorKickThreshold;
+	type CollatorKickMissedSessions = CollatorKickMissedSessions;
+	type CollatorKickSlash = CollatorKickSlash;
 	type WeightInfo = weights::module_collator_selection::WeightInfo<Runtime>;
 }
 
@@ -705,13 +713,13 @@ parameter_types! {
 type AcalaDataProvider = orml_oracle::Instance1;
 impl orml_oracle::Config<AcalaDataProvider> for Runtime {
 	type Event = Event;
-	type OnNewData = ();
-	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider>;
+	type OnNewData = Prices;
+	type CombineData = AggregatedCombineData<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider, Prices>;
 	type Time = Timestamp;
 	type OracleKey = CurrencyId;
 	type OracleValue = Price;
 	type RootOperatorAccountId = ZeroAccountId;
-	type Members = OperatorMembershipAcala;
+	type Members = module_prices::ActiveOracleOperators<Runtime>;
 	type MaxHasDispatchedSize = MaxHasDispatchedSize;
 	type WeightInfo = ();
 }
@@ -794,6 +802,12 @@ impl orml_tokens::Config for Runtime {
 
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
+	pub const FeedWindow: BlockNumber = 10 * MINUTES;
+	pub const MaxMissedFeedWindows: u32 = 6;
+	pub MaxPriceChangeRatio: Ratio = Ratio::saturating_from_rational(30, 100);
+	pub const CircuitBreakerWindow: BlockNumber = HOURS;
+	pub const CircuitBreakerCooldown: BlockNumber = 6 * HOURS;
+	pub const RemotePriceStaleness: BlockNumber = HOURS;
 }
 
 impl module_prices::Config for Runtime {
@@ -808,6 +822,14 @@ impl module_prices::Config for Runtime {
 	type DEX = Dex;
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
+	type OracleOperators = OperatorMembershipAcala;
+	type FeedWindow = FeedWindow;
+	type MaxMissedFeedWindows = MaxMissedFeedWindows;
+	type MaxPriceChangeRatio = MaxPriceChangeRatio;
+	type CircuitBreakerWindow = CircuitBreakerWindow;
+	type CircuitBreakerCooldown = CircuitBreakerCooldown;
+	type RemoteOracleOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type RemotePriceStaleness = RemotePriceStaleness;
 	type WeightInfo = weights::module_prices::WeightInfo<Runtime>;
 }
 
@@ -847,7 +869,10 @@ impl EnsureOrigin<Origin> for EnsureKaruraFoundation {
 	fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
 		Into::<Result<RawOrigin<AccountId>, Origin>>::into(o).and_then(|o| match o {
 			RawOrigin::Signed(caller) => {
-				if KaruraFoundationAccounts::get().contains(&caller) {
+				// incentives pays the vested rewards
+				if KaruraFoundationAccounts::get().contains(&caller)
+					|| caller == IncentivesPalletId::get().into_account()
+				{
 					Ok(caller)
 				} else {
 					Err(Origin::from(Some(caller)))
@@ -901,6 +926,8 @@ parameter_types! {
 	pub DefaultSwapParitalPathList: Vec<Vec<CurrencyId>> = vec![
 		vec![GetStableCurrencyId::get()],
 	];
+	pub DutchAuctionStartingPremium: Rate = Rate::saturating_from_rational(10, 100);
+	pub DutchAuctionPriceDecay: Rate = Rate::saturating_from_rational(1, 1000);
 }
 
 impl module_auction_manager::Config for Runtime {
@@ -914,9 +941,14 @@ impl module_auction_manager::Config for Runtime {
 	type CDPTreasury = CdpTreasury;
 	type DEX = Dex;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type PriceCircuitBreaker = Prices;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type CollateralAuctionTypes = module_cdp_engine::CollateralAuctionTypeOf<Runtime>;
+	type DutchAuctionStartingPremium = DutchAuctionStartingPremium;
+	type DutchAuctionPriceDecay = DutchAuctionPriceDecay;
+	type RiskParameters = ();
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -996,11 +1028,14 @@ parameter_types! {
 	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(8, 100);
 	pub MinimumDebitValue: Balance = 20 * dollar(KUSD);
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(15, 100);
+	pub PartialLiquidationTarget: Option<Ratio> = None;
+	pub KeeperIncentiveRate: Rate = Rate::saturating_from_rational(1, 10);
 }
 
 impl module_cdp_engine::Config for Runtime {
 	type Event = Event;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type PriceCircuitBreaker = Prices;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -1008,8 +1043,12 @@ impl module_cdp_engine::Config for Runtime {
 	type MinimumDebitValue = MinimumDebitValue;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CdpTreasury;
+	type StabilityPool = ();
+	type RiskParameters = ();
 	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
+	type PartialLiquidationTarget = PartialLiquidationTarget;
+	type KeeperIncentiveRate = KeeperIncentiveRate;
 	type UnsignedPriority = runtime_common::CdpEngineUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
 	type UnixTime = Timestamp;
@@ -1019,12 +1058,14 @@ impl module_cdp_engine::Config for Runtime {
 
 parameter_types! {
 	pub DepositPerAuthorization: Balance = deposit(1, 64);
+	pub TopUpRewardRate: Rate = Rate::saturating_from_rational(1, 100);
 }
 
 impl module_honzon::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
 	type DepositPerAuthorization = DepositPerAuthorization;
+	type TopUpRewardRate = TopUpRewardRate;
 	type WeightInfo = weights::module_honzon::WeightInfo<Runtime>;
 }
 
@@ -1032,6 +1073,7 @@ impl module_emergency_shutdown::Config for Runtime {
 	type Event = Event;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type PriceSource = Prices;
+	type LockedPriceSource = module_prices::LockedPriceProvider<Runtime>;
 	type CDPTreasury = CdpTreasury;
 	type AuctionManagerHandler = AuctionManager;
 	type ShutdownOrigin = EnsureRoot<AccountId>;
@@ -1041,6 +1083,9 @@ impl module_emergency_shutdown::Config for Runtime {
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (3, 1000);	// 0.3%
 	pub const TradingPathLimit: u32 = 3;
+	pub RoutingCurrencies: Vec<CurrencyId> = vec![KUSD, KSM];
+	pub const TWAPObservationPeriod: BlockNumber = 10 * MINUTES;
+	pub const MaxTWAPObservations: u32 = 144;
 }
 
 impl module_dex::Config for Runtime {
@@ -1048,6 +1093,9 @@ impl module_dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type RoutingCurrencies = RoutingCurrencies;
+	type TWAPObservationPeriod = TWAPObservationPeriod;
+	type MaxTWAPObservations = MaxTWAPObservations;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
 	type DEXIncentives = Incentives;
@@ -1064,12 +1112,14 @@ impl module_cdp_treasury::Config for Runtime {
 	type Event = Event;
 	type Currency = Currencies;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type AuctionManagerHandler = AuctionManager;
 	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type DEX = Dex;
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = HonzonTreasuryAccount;
+	type IdleScheduler = ();
 	type WeightInfo = weights::module_cdp_treasury::WeightInfo<Runtime>;
 }
 
@@ -1098,6 +1148,29 @@ impl OnUnbalanced<NegativeImbalance> for DealWithFees {
 	}
 }
 
+/// Pay the fee of `swap_with_exact_target_and_fee_in_supply` with the supply
+/// currency of the trading path.
+pub struct DexCallFeeSwapPath;
+impl module_support::CallFeeSwapPath<Call, CurrencyId> for DexCallFeeSwapPath {
+	fn fee_swap_path_list(call: &Call) -> Vec<Vec<CurrencyId>> {
+		let native_currency_id = GetNativeCurrencyId::get();
+		let stable_currency_id = GetStableCurrencyId::get();
+		match call {
+			Call::Dex(module_dex::Call::swap_with_exact_target_and_fee_in_supply(path, ..)) => match path.first() {
+				Some(&supply_currency_id) if supply_currency_id == stable_currency_id => {
+					vec![vec![supply_currency_id, native_currency_id]]
+				}
+				Some(&supply_currency_id) if supply_currency_id != native_currency_id => vec![
+					vec![supply_currency_id, native_currency_id],
+					vec![supply_currency_id, stable_currency_id, native_currency_id],
+				],
+				_ => vec![],
+			},
+			_ => vec![],
+		}
+	}
+}
+
 impl module_transaction_payment::Config for Runtime {
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type DefaultFeeSwapPathList = DefaultFeeSwapPathList;
@@ -1111,6 +1184,10 @@ impl module_transaction_payment::Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type CallFeeSwapPath = DexCallFeeSwapPath;
+	type Call = Call;
+	type SponsorSignature = Signature;
+	type SponsorSigner = <Signature as sp_runtime::traits::Verify>::Signer;
 	type WeightInfo = weights::module_transaction_payment::WeightInfo<Runtime>;
 }
 
@@ -1122,9 +1199,17 @@ impl module_evm_accounts::Config for Runtime {
 	type WeightInfo = weights::module_evm_accounts::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub Erc20RegistrationDeposit: Balance = 100 * dollar(KAR);
+}
+
 impl module_evm_manager::Config for Runtime {
+	type Event = Event;
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type Erc20RegistrationDeposit = Erc20RegistrationDeposit;
+	type VetoOrigin = EnsureRootOrTwoThirdsTechnicalCommittee;
+	type WeightInfo = weights::module_evm_manager::WeightInfo<Runtime>;
 }
 
 impl orml_rewards::Config for Runtime {
@@ -1137,6 +1222,29 @@ impl orml_rewards::Config for Runtime {
 
 parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = MINUTES;
+	pub const LoyaltyPeriod: BlockNumber = 7 * DAYS;
+}
+
+pub struct IncentivesVestedTransfer;
+impl module_support::VestedTransfer<AccountId, BlockNumber, Balance> for IncentivesVestedTransfer {
+	fn vested_transfer(
+		from: &AccountId,
+		to: &AccountId,
+		period: BlockNumber,
+		period_count: u32,
+		per_period: Balance,
+	) -> DispatchResult {
+		Vesting::vested_transfer(
+			RawOrigin::Signed(from.clone()).into(),
+			<Runtime as frame_system::Config>::Lookup::unlookup(to.clone()),
+			orml_vesting::VestingSchedule {
+				start: RelaychainBlockNumberProvider::<Runtime>::current_block_number(),
+				period,
+				period_count,
+				per_period,
+			},
+		)
+	}
 }
 
 impl module_incentives::Config for Runtime {
@@ -1144,11 +1252,14 @@ impl module_incentives::Config for Runtime {
 	type RewardsSource = UnreleasedNativeVaultAccountId;
 	type StableCurrencyId = GetStableCurrencyId;
 	type AccumulatePeriod = AccumulatePeriod;
+	type LoyaltyPeriod = LoyaltyPeriod;
+	type NativeCurrencyId = GetNativeCurrencyId;
 	type UpdateOrigin = EnsureRootOrThreeFourthsGeneralCouncil;
 	type CDPTreasury = CdpTreasury;
 	type Currency = Currencies;
 	type DEX = Dex;
 	type EmergencyShutdown = EmergencyShutdown;
+	type VestedTransfer = IncentivesVestedTransfer;
 	type PalletId = IncentivesPalletId;
 	type WeightInfo = weights::module_incentives::WeightInfo<Runtime>;
 }
@@ -1157,6 +1268,7 @@ parameter_types! {
 	pub CreateClassDeposit: Balance = 50 * dollar(KAR);
 	pub CreateTokenDeposit: Balance = 20 * cent(KAR);
 	pub MaxAttributesBytes: u32 = 2048;
+	pub const MaxNftBatchItems: u32 = 100;
 }
 
 impl module_nft::Config for Runtime {
@@ -1167,6 +1279,8 @@ impl module_nft::Config for Runtime {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = MaxAttributesBytes;
+	type MaxBatchItems = MaxNftBatchItems;
+	type MultiCurrency = Currencies;
 	type WeightInfo = weights::module_nft::WeightInfo<Runtime>;
 }
 
@@ -1184,6 +1298,17 @@ impl orml_nft::Config for Runtime {
 	type MaxTokenMetadata = MaxTokenMetadata;
 }
 
+parameter_types! {
+	pub const PaymentGracePeriod: BlockNumber = 3 * DAYS;
+}
+
+impl module_payments::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type GracePeriod = PaymentGracePeriod;
+	type WeightInfo = weights::module_payments::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	// One storage item; key size 32, value size 8; .
 	pub ProxyDepositBase: Balance = deposit(1, 8);
@@ -1229,6 +1354,7 @@ impl InstanceFilter<Call> for ProxyType {
 				matches!(
 					c,
 					Call::Honzon(module_honzon::Call::adjust_loan(..))
+						| Call::Honzon(module_honzon::Call::adjust_loans_batch(..))
 						| Call::Honzon(module_honzon::Call::close_loan_has_debit_by_dex(..))
 				)
 			}
@@ -1266,6 +1392,8 @@ parameter_types! {
 	pub NetworkContractSource: H160 = H160::from_low_u64_be(0);
 	pub DeveloperDeposit: Balance = 100 * dollar(KAR);
 	pub DeploymentFee: Balance = 10000 * dollar(KAR);
+	pub PublicationFee: Balance = 10000 * dollar(KAR);
+	pub const BaseFeeHistoryLength: u32 = 1024;
 }
 
 pub type MultiCurrencyPrecompile = runtime_common::MultiCurrencyPrecompile<
@@ -1298,6 +1426,64 @@ pub type ScheduleCallPrecompile = runtime_common::ScheduleCallPrecompile<
 >;
 pub type DexPrecompile =
 	runtime_common::DexPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Dex>;
+pub type HomaPrecompile =
+	runtime_common::HomaPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, HomaLite>;
+pub type HonzonPrecompile =
+	runtime_common::HonzonPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Honzon>;
+pub type IncentivesPrecompile = runtime_common::IncentivesPrecompile<
+	AccountId,
+	EvmAddressMapping<Runtime>,
+	EvmCurrencyIdMapping<Runtime>,
+	Incentives,
+>;
+
+/// XCM transfers from EVM, which are filtered by `BaseCallFilter` as the
+/// corresponding `XTokens` calls.
+pub struct EvmXcmTransfer;
+impl orml_traits::XcmTransfer<AccountId, Balance, CurrencyId> for EvmXcmTransfer {
+	fn transfer(
+		who: AccountId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		dest: MultiLocation,
+		dest_weight: Weight,
+	) -> DispatchResult {
+		let call = Call::XTokens(orml_xtokens::Call::transfer(
+			currency_id,
+			amount,
+			Box::new(dest.clone()),
+			dest_weight,
+		));
+		if !BaseCallFilter::contains(&call) {
+			return Err(sp_runtime::DispatchError::Other("CallFiltered"));
+		}
+		<XTokens as orml_traits::XcmTransfer<_, _, _>>::transfer(who, currency_id, amount, dest, dest_weight)
+	}
+
+	fn transfer_multi_asset(
+		who: AccountId,
+		asset: MultiAsset,
+		dest: MultiLocation,
+		dest_weight: Weight,
+	) -> DispatchResult {
+		let call = Call::XTokens(orml_xtokens::Call::transfer_multiasset(
+			Box::new(asset.clone()),
+			Box::new(dest.clone()),
+			dest_weight,
+		));
+		if !BaseCallFilter::contains(&call) {
+			return Err(sp_runtime::DispatchError::Other("CallFiltered"));
+		}
+		<XTokens as orml_traits::XcmTransfer<_, _, _>>::transfer_multi_asset(who, asset, dest, dest_weight)
+	}
+}
+
+pub type XtokensPrecompile = runtime_common::XtokensPrecompile<
+	AccountId,
+	EvmAddressMapping<Runtime>,
+	EvmCurrencyIdMapping<Runtime>,
+	EvmXcmTransfer,
+>;
 
 impl module_evm::Config for Runtime {
 	type AddressMapping = EvmAddressMapping<Runtime>;
@@ -1308,12 +1494,17 @@ impl module_evm::Config for Runtime {
 	type Event = Event;
 	type Precompiles = runtime_common::AllPrecompiles<
 		SystemContractsFilter,
+		module_transaction_pause::PausedPrecompileFilter<Runtime>,
 		MultiCurrencyPrecompile,
 		NFTPrecompile,
 		StateRentPrecompile,
 		OraclePrecompile,
 		ScheduleCallPrecompile,
 		DexPrecompile,
+		HomaPrecompile,
+		HonzonPrecompile,
+		IncentivesPrecompile,
+		XtokensPrecompile,
 	>;
 	type ChainId = ChainId;
 	type GasToWeight = GasToWeight;
@@ -1322,10 +1513,12 @@ impl module_evm::Config for Runtime {
 	type NetworkContractSource = NetworkContractSource;
 	type DeveloperDeposit = DeveloperDeposit;
 	type DeploymentFee = DeploymentFee;
+	type PublicationFee = PublicationFee;
 	type TreasuryAccount = KaruraTreasuryAccount;
 	type FreeDeploymentOrigin = EnsureRootOrHalfGeneralCouncil;
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
+	type BaseFeeHistoryLength = BaseFeeHistoryLength;
 	type WeightInfo = weights::module_evm::WeightInfo<Runtime>;
 }
 
@@ -1382,6 +1575,9 @@ pub type LocationToAccountId = (
 /// ready for dispatching a transaction with Xcm's `Transact`. There is an `OriginKind` which can
 /// biases the kind of local `Origin` it will become.
 pub type XcmOriginToCallOrigin = (
+	// Relay-chain sovereign account converter; only the Homa XCM calls are allowed to be dispatched
+	// with it. Must be placed before `SovereignSignedViaLocation`.
+	RelaychainSovereignSignedWithHomaXcmFilter,
 	// Sovereign account converter; this attempts to derive an `AccountId` from the origin location
 	// using `LocationToAccountId` and then turn that into the usual `Signed` origin. Useful for
 	// foreign chains who want to have a local sovereign account on this chain which they control.
@@ -1399,14 +1595,44 @@ pub type XcmOriginToCallOrigin = (
 	XcmPassthrough<Origin>,
 );
 
+/// The calls which can be dispatched by the relaychain sovereign account via XCM `Transact`.
+pub struct HomaXcmCallFilter;
+impl Contains<Call> for HomaXcmCallFilter {
+	fn contains(call: &Call) -> bool {
+		matches!(call, Call::HomaLite(module_homa_lite::Call::mint_for(..)))
+	}
+}
+
+/// Converts the `Parent` location with `SovereignAccount` kind into the `Signed` origin of the
+/// relaychain sovereign account, filtered by `HomaXcmCallFilter`.
+pub struct RelaychainSovereignSignedWithHomaXcmFilter;
+impl ConvertOrigin<Origin> for RelaychainSovereignSignedWithHomaXcmFilter {
+	fn convert_origin(origin: MultiLocation, kind: OriginKind) -> Result<Origin, MultiLocation> {
+		match (kind, origin) {
+			(OriginKind::SovereignAccount, X1(Parent)) => {
+				let mut origin = Origin::signed(AccountId::default());
+				origin.add_filter(HomaXcmCallFilter::contains);
+				Ok(origin)
+			}
+			(_, origin) => Err(origin),
+		}
+	}
+}
+
 parameter_types! {
 	// One XCM operation is 200_000_000 weight, cross-chain transfer ~= 2x of transfer.
 	pub const UnitWeightCost: Weight = 200_000_000;
 	pub KsmPerSecond: (MultiLocation, u128) = (X1(Parent), ksm_per_second());
 }
 
+// The remote mint XCM from relaychain (see `build_remote_mint_xcm`) pays for its execution with the
+// first `BuyExecution`, so `AllowTopLevelPaidExecutionFrom` is sufficient for it.
 pub type Barrier = (TakeWeightCredit, AllowTopLevelPaidExecutionFrom<Everything>);
 
+/// Builds the Kusama relay chain calls for Homa and liquid crowdloan.
+pub type RelayChainCallBuilder =
+	module_relaychain_interface::CallBuilder<module_relaychain_interface::Kusama, ParachainInfo>;
+
 pub struct ToTreasury;
 impl TakeRevenue for ToTreasury {
 	fn take_revenue(revenue: MultiAsset) {
@@ -1504,11 +1730,24 @@ impl<KsmTrader: WeightTrader, BncTrader: WeightTrader, VsksmTrader: WeightTrader
 }
 
 pub type Trader = MultiWeightTraders<
-	FixedRateOfConcreteFungible<KsmPerSecond, ToTreasury>,
-	FixedRateOfConcreteFungible<BncPerSecond, ToTreasury>,
-	FixedRateOfConcreteFungible<VsksmPerSecond, ToTreasury>,
+	FixedRateOfConcreteFungible<FeePerSecondOf<Runtime, KsmPerSecond>, ToTreasury>,
+	FixedRateOfConcreteFungible<FeePerSecondOf<Runtime, BncPerSecond>, ToTreasury>,
+	FixedRateOfConcreteFungible<FeePerSecondOf<Runtime, VsksmPerSecond>, ToTreasury>,
 >;
 
+/// The XCM execution fee rate of the asset at `location`, `None` if the asset can't pay for XCM
+/// execution.
+fn xcm_fee_per_second(location: &MultiLocation) -> Option<u128> {
+	[
+		FeePerSecondOf::<Runtime, KsmPerSecond>::get(),
+		FeePerSecondOf::<Runtime, BncPerSecond>::get(),
+		FeePerSecondOf::<Runtime, VsksmPerSecond>::get(),
+	]
+	.iter()
+	.find(|(id, _)| id == location)
+	.map(|(_, fee_per_second)| *fee_per_second)
+}
+
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
 	type Call = Call;
@@ -1562,7 +1801,7 @@ impl cumulus_pallet_xcm::Config for Runtime {
 
 impl cumulus_pallet_xcmp_queue::Config for Runtime {
 	type Event = Event;
-	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmExecutor = module_xcm_channel::MeteredXcmExecutor<Runtime, XcmExecutor<XcmConfig>>;
 	type ChannelInfo = ParachainSystem;
 }
 
@@ -1589,7 +1828,12 @@ parameter_types! {
 	pub RelaychainSovereignSubAccount: MultiLocation = create_x2_parachain_multilocation(RelaychainSubAccountId::HomaLite as u16);
 	pub MaxRewardPerEra: Permill = Permill::from_rational(500u32, 1_000_000u32); // 1.2 ^ (1/365) = 1.0004996359
 	pub MintFee: Balance = 20 * millicent(KSM); // 2x XCM fee on Kusama
+	pub const FastRedeemFeeShareToMinter: Permill = Permill::from_percent(80);
+	pub const MaximumRedeemRequestMatchesForMint: u32 = 20;
 	pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(1, 10);
+	pub const RelaychainEraLength: BlockNumber = 6 * HOURS;
+	pub EstimatedRewardRatePerEra: Permill = Permill::from_rational(96u32, 1_000_000u32); // 1.15 ^ (1/1460) = 1.0000957307
+	pub RemoteMintFee: Balance = 10 * millicent(KSM); // XCM execution fee on Karura, the unused part is refunded
 }
 impl module_homa_lite::Config for Runtime {
 	type Event = Event;
@@ -1604,6 +1848,82 @@ impl module_homa_lite::Config for Runtime {
 	type DefaultExchangeRate = DefaultExchangeRate;
 	type MaxRewardPerEra = MaxRewardPerEra;
 	type MintFee = MintFee;
+	type TreasuryAccount = KaruraTreasuryAccount;
+	type FastRedeemFeeShareToMinter = FastRedeemFeeShareToMinter;
+	type MaximumRedeemRequestMatchesForMint = MaximumRedeemRequestMatchesForMint;
+	type RewardsClaimer = ();
+	type EraLength = RelaychainEraLength;
+	type EstimatedRewardRatePerEra = EstimatedRewardRatePerEra;
+}
+
+/// Build the XCM to be executed on the relaychain, which reserve transfers `amount` of KSM to
+/// Karura and mints LKSM for `beneficiary` in one message:
+/// - buy execution for the message with `RemoteMintFee`;
+/// - deposit the KSM to be minted to the relaychain sovereign account;
+/// - `Transact` as the relaychain sovereign account to `mint_for` the `beneficiary`;
+/// - refund the unused fee to `beneficiary`.
+pub fn build_remote_mint_xcm(beneficiary: AccountId, amount: Balance) -> Xcm<()> {
+	let mint_amount = amount.saturating_sub(RemoteMintFee::get());
+	let mint_call = Call::HomaLite(module_homa_lite::Call::mint_for(beneficiary.clone(), mint_amount));
+	let mint_weight = mint_call.get_dispatch_info().weight;
+
+	let mut effects: Vec<Order<Call>> = vec![
+		Order::BuyExecution {
+			fees: MultiAsset::All,
+			weight: 0,
+			debt: 0,
+			halt_on_error: true,
+			xcm: vec![],
+		},
+		Order::DepositAsset {
+			assets: vec![MultiAsset::ConcreteFungible {
+				id: KsmLocation::get(),
+				amount: mint_amount,
+			}],
+			dest: X1(Parent),
+		},
+		Order::BuyExecution {
+			fees: MultiAsset::All,
+			weight: mint_weight,
+			debt: 0,
+			halt_on_error: true,
+			xcm: vec![Xcm::Transact {
+				origin_type: OriginKind::SovereignAccount,
+				require_weight_at_most: mint_weight,
+				call: mint_call.encode().into(),
+			}],
+		},
+		Order::DepositAsset {
+			assets: vec![MultiAsset::All],
+			dest: X1(AccountId32 {
+				network: NetworkId::Any,
+				id: beneficiary.into(),
+			}),
+		},
+	];
+
+	// The first `BuyExecution` must pay for the shallow weight of the message received by Karura,
+	// to pass the `Barrier`.
+	let mut message = Xcm::<Call>::ReserveAssetDeposit {
+		assets: vec![MultiAsset::ConcreteFungible {
+			id: KsmLocation::get(),
+			amount,
+		}],
+		effects: effects.clone(),
+	};
+	let shallow_weight = <XcmConfig as xcm_executor::Config>::Weigher::shallow(&mut message).unwrap_or_default();
+	if let Some(Order::BuyExecution { debt, .. }) = effects.first_mut() {
+		*debt = shallow_weight;
+	}
+
+	Xcm::TransferReserveAsset {
+		assets: vec![MultiAsset::ConcreteFungible {
+			id: MultiLocation::Null,
+			amount,
+		}],
+		dest: X1(Parachain(ParachainInfo::get().into())),
+		effects: effects.into_iter().map(|order| order.into()).collect(),
+	}
 }
 
 pub type LocalAssetTransactor = MultiCurrencyAdapter<
@@ -1715,6 +2035,16 @@ impl orml_xtokens::Config for Runtime {
 	type BaseXcmWeight = BaseXcmWeight;
 }
 
+impl module_xcm_transfer::Config for Runtime {
+	type Event = Event;
+	type CurrencyIdConvert = CurrencyIdConvert;
+	type AccountIdToMultiLocation = AccountIdToMultiLocation;
+	type SelfLocation = SelfLocation;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type Weigher = FixedWeightBounds<UnitWeightCost, Call>;
+	type BaseXcmWeight = BaseXcmWeight;
+}
+
 impl orml_unknown_tokens::Config for Runtime {
 	type Event = Event;
 }
@@ -1724,11 +2054,57 @@ impl orml_xcm::Config for Runtime {
 	type SovereignOrigin = EnsureRootOrHalfGeneralCouncil;
 }
 
+impl module_asset_registry::Config for Runtime {
+	type Event = Event;
+	type RegisterOrigin = EnsureRootOrHalfGeneralCouncil;
+	type WeightInfo = weights::module_asset_registry::WeightInfo<Runtime>;
+}
+
+impl module_xcm_channel::Config for Runtime {
+	type Event = Event;
+	type UpdateOrigin = EnsureRootOrTwoThirdsTechnicalCommittee;
+	type WeightInfo = weights::module_xcm_channel::WeightInfo<Runtime>;
+}
+
 pub struct OnRuntimeUpgrade;
 impl frame_support::traits::OnRuntimeUpgrade for OnRuntimeUpgrade {
 	fn on_runtime_upgrade() -> u64 {
-		// no migration
-		0
+		module_cdp_engine::migrations::v1::migrate::<Runtime>()
+			.saturating_add(module_evm_accounts::migrations::v1::migrate::<Runtime>())
+			.saturating_add(module_transaction_pause::migrations::v1::migrate::<Runtime>())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+		Self::set_temp_storage(
+			module_cdp_engine::migrations::v1::pre_migrate::<Runtime>()?,
+			"cdp_engine_v1",
+		);
+		Self::set_temp_storage(
+			module_evm_accounts::migrations::v1::pre_migrate::<Runtime>()?,
+			"evm_accounts_v1",
+		);
+		Self::set_temp_storage(
+			module_transaction_pause::migrations::v1::pre_migrate::<Runtime>()?,
+			"transaction_pause_v1",
+		);
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+		module_cdp_engine::migrations::v1::post_migrate::<Runtime>(
+			Self::get_temp_storage("cdp_engine_v1").ok_or("cdp_engine_v1 count is missing")?,
+		)?;
+		module_evm_accounts::migrations::v1::post_migrate::<Runtime>(
+			Self::get_temp_storage("evm_accounts_v1").ok_or("evm_accounts_v1 count is missing")?,
+		)?;
+		module_transaction_pause::migrations::v1::post_migrate::<Runtime>(
+			Self::get_temp_storage("transaction_pause_v1").ok_or("transaction_pause_v1 count is missing")?,
+		)?;
+		Ok(())
 	}
 }
 
@@ -1780,6 +2156,9 @@ construct_runtime!(
 		XTokens: orml_xtokens::{Pallet, Storage, Call, Event<T>} = 54,
 		UnknownTokens: orml_unknown_tokens::{Pallet, Storage, Event} = 55,
 		OrmlXcm: orml_xcm::{Pallet, Call, Event<T>} = 56,
+		AssetRegistry: module_asset_registry::{Pallet, Call, Storage, Event<T>} = 57,
+		XcmTransfer: module_xcm_transfer::{Pallet, Call, Event<T>} = 58,
+		XcmChannel: module_xcm_channel::{Pallet, Call, Storage, Event<T>} = 59,
 
 		// Governance
 		Authority: orml_authority::{Pallet, Call, Storage, Event<T>, Origin<T>} = 60,
@@ -1826,13 +2205,14 @@ construct_runtime!(
 
 		// Karura Other
 		Incentives: module_incentives::{Pallet, Storage, Call, Event<T>} = 120,
-		NFT: module_nft::{Pallet, Call, Event<T>} = 121,
+		NFT: module_nft::{Pallet, Call, Storage, Event<T>} = 121,
+		Payments: module_payments::{Pallet, Call, Storage, Event<T>} = 122,
 
 		// Smart contracts
 		EVM: module_evm::{Pallet, Config<T>, Call, Storage, Event<T>} = 130,
 		EVMBridge: module_evm_bridge::{Pallet} = 131,
 		EvmAccounts: module_evm_accounts::{Pallet, Call, Storage, Event<T>} = 132,
-		EvmManager: module_evm_manager::{Pallet, Storage} = 133,
+		EvmManager: module_evm_manager::{Pallet, Call, Storage, Event<T>} = 133,
 
 		// Temporary
 		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>} = 255,
@@ -1974,6 +2354,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_transaction_payment_rpc_runtime_api::TransactionFeeApi<
+		Block,
+		Balance,
+		CurrencyId,
+	> for Runtime {
+		fn query_fee_in_currency(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			currency_id: CurrencyId,
+		) -> Option<Balance> {
+			TransactionPayment::query_fee_in_currency(uxt, len, currency_id)
+		}
+	}
+
 	impl orml_oracle_rpc_runtime_api::OracleApi<
 		Block,
 		DataProviderId,
@@ -1995,6 +2389,23 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl runtime_common::OracleFeedsApi<
+		Block,
+		DataProviderId,
+		CurrencyId,
+		AccountId,
+		Moment,
+	> for Runtime {
+		fn get_feed_details(provider_id: DataProviderId, key: CurrencyId) -> Option<runtime_common::FeedDetails<AccountId, Moment>> {
+			match provider_id {
+				DataProviderId::Acala => Some(
+					AggregatedCombineData::<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider, Prices>::feed_details(&key)
+				),
+				DataProviderId::Aggregated => None,
+			}
+		}
+	}
+
 	impl module_staking_pool_rpc_runtime_api::StakingPoolApi<
 		Block,
 		AccountId,
@@ -2011,6 +2422,299 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_cdp_engine_rpc_runtime_api::CDPEngineApi<
+		Block,
+		AccountId,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn get_position_risk(
+			account: AccountId,
+			currency_id: CurrencyId,
+		) -> Option<module_cdp_engine_rpc_runtime_api::PositionRisk<Balance>> {
+			let module_loans::Position { collateral, debit } = Loans::positions(currency_id, &account);
+			let price = CdpEngine::get_collateral_price(currency_id)?;
+
+			Some(module_cdp_engine_rpc_runtime_api::PositionRisk {
+				collateral_ratio: CdpEngine::calculate_collateral_ratio(currency_id, collateral, debit, price),
+				liquidation_price: CdpEngine::calculate_liquidation_price(currency_id, collateral, debit),
+				required_collateral: CdpEngine::calculate_required_collateral(currency_id, collateral, debit, price),
+				accumulated_interest: CdpEngine::get_accumulated_interest(currency_id, debit),
+			})
+		}
+
+		fn get_interest_accrued(
+			account: AccountId,
+			currency_id: CurrencyId,
+		) -> module_cdp_engine_rpc_runtime_api::InterestInfo<Balance> {
+			module_cdp_engine_rpc_runtime_api::InterestInfo {
+				accrued: CdpEngine::get_interest_accrued(&account, currency_id)
+			}
+		}
+	}
+
+	impl module_asset_registry_runtime_api::AssetRegistryApi<
+		Block,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn xcm_execution_fee(currency_id: CurrencyId, weight: u64) -> Option<Balance> {
+			let location = CurrencyIdConvert::convert(currency_id)?;
+			xcm_fee_per_second(&location).map(|fee_per_second| AssetRegistry::execution_fee(fee_per_second, weight))
+		}
+	}
+
+	impl module_xcm_channel_runtime_api::XcmChannelApi<
+		Block,
+		module_xcm_channel::ChannelMetrics,
+	> for Runtime {
+		fn channel_metrics(para_id: u32) -> module_xcm_channel::ChannelMetrics {
+			XcmChannel::metrics(ParaId::from(para_id))
+		}
+
+		fn is_channel_paused(para_id: u32) -> bool {
+			XcmChannel::is_paused(ParaId::from(para_id))
+		}
+	}
+
+	impl module_session_manager_runtime_api::SessionManagerApi<
+		Block,
+		BlockNumber,
+	> for Runtime {
+		fn session_duration_at(block_number: BlockNumber) -> BlockNumber {
+			SessionManager::session_duration_at(block_number)
+		}
+	}
+
+	impl module_dex_rpc_runtime_api::DexApi<
+		Block,
+		CurrencyId,
+		BlockNumber,
+		Balance,
+	> for Runtime {
+		fn get_twap(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			window: BlockNumber,
+		) -> Option<ExchangeRate> {
+			Dex::get_twap(currency_id_a, currency_id_b, window)
+		}
+
+		fn get_swap_quote(
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			amount: Balance,
+			mode: module_support::SwapMode,
+		) -> Option<module_support::SwapQuote<CurrencyId, Balance>> {
+			Dex::get_best_swap_quote(supply_currency_id, target_currency_id, amount, mode)
+		}
+	}
+
+	impl module_asset_registry_runtime_api::PortfolioApi<
+		Block,
+		AccountId,
+		CurrencyId,
+		module_incentives::PoolId,
+		Balance,
+	> for Runtime {
+		fn get_portfolio(
+			account: AccountId,
+		) -> module_asset_registry_runtime_api::Portfolio<CurrencyId, module_incentives::PoolId, Balance> {
+			use module_asset_registry_runtime_api::*;
+			use module_support::{DEXManager, ExchangeRateProvider};
+
+			let mut portfolio = Portfolio::default();
+
+			let native = System::account(&account).data;
+			if !native.free.is_zero() || !native.reserved.is_zero() {
+				portfolio.balances.push(TokenBalance {
+					currency_id: GetNativeCurrencyId::get(),
+					free: native.free,
+					reserved: native.reserved,
+					frozen: native.misc_frozen.max(native.fee_frozen),
+				});
+			}
+
+			for (currency_id, data) in orml_tokens::Accounts::<Runtime>::iter_prefix(&account) {
+				let total = data.free.saturating_add(data.reserved);
+				if total.is_zero() {
+					continue;
+				}
+
+				if let Some((currency_id_0, currency_id_1)) = currency_id.split_dex_share_currency_id() {
+					let (pool_0, pool_1) = Dex::get_liquidity_pool(currency_id_0, currency_id_1);
+					let proportion = Ratio::checked_from_rational(total, Tokens::total_issuance(currency_id))
+						.unwrap_or_default();
+					portfolio.dex_shares.push(DexShareBalance {
+						currency_id,
+						balance: total,
+						underlying: vec![
+							CurrencyAmount {
+								currency_id: currency_id_0,
+								amount: proportion.saturating_mul_int(pool_0),
+							},
+							CurrencyAmount {
+								currency_id: currency_id_1,
+								amount: proportion.saturating_mul_int(pool_1),
+							},
+						],
+					});
+					continue;
+				}
+
+				if currency_id == GetLiquidCurrencyId::get() {
+					portfolio.liquid_staking = Some(LiquidStakingBalance {
+						liquid: CurrencyAmount {
+							currency_id,
+							amount: total,
+						},
+						staking: CurrencyAmount {
+							currency_id: GetStakingCurrencyId::get(),
+							amount: module_homa_lite::LiquidExchangeProvider::<Runtime>::get_exchange_rate()
+								.saturating_mul_int(total),
+						},
+					});
+				}
+				portfolio.balances.push(TokenBalance {
+					currency_id,
+					free: data.free,
+					reserved: data.reserved,
+					frozen: data.frozen,
+				});
+			}
+
+			for currency_id in CollateralCurrencyIds::get() {
+				let module_loans::Position { collateral, debit } = Loans::positions(currency_id, &account);
+				if !collateral.is_zero() || !debit.is_zero() {
+					portfolio.loans.push(LoanPosition {
+						collateral_currency_id: currency_id,
+						collateral,
+						debit,
+						debit_value: CdpEngine::get_debit_value(currency_id, debit),
+					});
+				}
+			}
+
+			for pool_id in orml_rewards::PoolInfos::<Runtime>::iter_keys() {
+				let (share, _) = Rewards::shares_and_withdrawn_rewards(&pool_id, &account);
+				if !share.is_zero() {
+					portfolio.incentive_stakes.push(IncentiveStake { pool_id, share });
+				}
+			}
+
+			portfolio
+		}
+	}
+
+	impl module_prices_rpc_runtime_api::PricesApi<
+		Block,
+		CurrencyId,
+		BlockNumber,
+	> for Runtime {
+		fn get_feed_freshness(currency_id: CurrencyId) -> Option<BlockNumber> {
+			Prices::feed_freshness(currency_id)
+		}
+	}
+
+	impl module_incentives_rpc_runtime_api::IncentivesApi<
+		Block,
+		AccountId,
+		module_incentives::PoolId,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn get_pending_loyalty_bonus(pool_id: module_incentives::PoolId, who: AccountId) -> Vec<(CurrencyId, Balance)> {
+			Incentives::pending_loyalty_bonus(&pool_id, &who)
+		}
+	}
+
+	impl module_nft_rpc_runtime_api::NftApi<
+		Block,
+		u32,
+		module_nft::Properties,
+	> for Runtime {
+		fn query_class_properties(class_id: u32) -> Option<module_nft::Properties> {
+			NFT::class_properties(class_id)
+		}
+	}
+
+	impl module_homa_lite_rpc_runtime_api::HomaLiteApi<
+		Block,
+		AccountId,
+		Balance,
+	> for Runtime {
+		fn build_remote_mint_xcm(beneficiary: AccountId, amount: Balance) -> xcm::VersionedXcm<()> {
+			build_remote_mint_xcm(beneficiary, amount).into()
+		}
+	}
+
+	impl runtime_common::events::EventsApi<Block> for Runtime {
+		fn query_events(pallet_index: Option<u8>, event_index: Option<u8>) -> Vec<runtime_common::events::EncodedEvent> {
+			runtime_common::events::query_events::<Runtime>(pallet_index, event_index)
+		}
+	}
+
+	impl runtime_common::yields::YieldsApi<Block> for Runtime {
+		fn get_yields() -> runtime_common::yields::Yields {
+			use module_support::ExchangeRateProvider;
+			use runtime_common::yields::{compounded_apy, incentive_pool_apr, Yields};
+
+			let now = System::block_number();
+			let incentive_pools = orml_rewards::PoolInfos::<Runtime>::iter()
+				.filter_map(|(pool_id, pool_info)| {
+					incentive_pool_apr::<module_prices::RealTimePriceProvider<Runtime>>(
+						pool_id,
+						pool_info.total_shares,
+						&Incentives::reward_amounts_per_period(pool_id, now),
+						365 * DAYS / AccumulatePeriod::get(),
+					)
+				})
+				.collect();
+
+			Yields {
+				liquid_exchange_rate: module_homa_lite::LiquidExchangeProvider::<Runtime>::get_exchange_rate(),
+				staking_apy: compounded_apy(EstimatedRewardRatePerEra::get().into(), 365 * DAYS / RelaychainEraLength::get()),
+				incentive_pools,
+			}
+		}
+	}
+
+	impl runtime_common::metrics::ProtocolMetricsApi<Block> for Runtime {
+		fn protocol_metrics() -> runtime_common::metrics::ProtocolMetrics {
+			use frame_support::traits::PalletInfoAccess;
+			use runtime_common::metrics::{storage_map_len, CollateralMetrics, ProtocolMetrics, XcmQueueDepth};
+
+			let collaterals = CollateralCurrencyIds::get()
+				.into_iter()
+				.map(|currency_id| CollateralMetrics {
+					currency_id,
+					open_cdps: module_loans::Positions::<Runtime>::iter_prefix_values(currency_id)
+						.filter(|position| !position.debit.is_zero())
+						.count() as u32,
+					total_debit_value: CdpEngine::get_debit_value(currency_id, Loans::total_positions(currency_id).debit),
+				})
+				.collect();
+			let now = Timestamp::now();
+			let oracle_feed_ages = <AggregatedDataProvider as DataProviderExtended<_, _>>::get_all_values()
+				.into_iter()
+				.filter_map(|(currency_id, price)| Some((currency_id, now.saturating_sub(price?.timestamp))))
+				.collect();
+
+			ProtocolMetrics {
+				collaterals,
+				active_auctions: module_auction_manager::CollateralAuctions::<Runtime>::iter_keys().count() as u32,
+				oracle_feed_ages,
+				homa_pending_redemptions: module_homa_lite::RedeemRequests::<Runtime>::iter_values()
+					.fold(Zero::zero(), |total: Balance, (amount, _)| total.saturating_add(amount)),
+				xcm_queue_depth: XcmQueueDepth {
+					inbound_xcmp: storage_map_len(XcmpQueue::name(), b"InboundXcmpMessages"),
+					outbound_xcmp: storage_map_len(XcmpQueue::name(), b"OutboundXcmpMessages"),
+					dmp_pages: storage_map_len(DmpQueue::name(), b"Pages"),
+				},
+			}
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn call(
 			from: H160,
@@ -2020,7 +2724,10 @@ impl_runtime_apis! {
 			gas_limit: u64,
 			storage_limit: u32,
 			estimate: bool,
+			state_overrides: Vec<(H160, StateOverride)>,
 		) -> Result<CallInfo, sp_runtime::DispatchError> {
+			EVM::apply_state_overrides(state_overrides)?;
+
 			let config = if estimate {
 				let mut config = <Runtime as module_evm::Config>::config().clone();
 				config.estimate = true;
@@ -2048,7 +2755,10 @@ impl_runtime_apis! {
 			gas_limit: u64,
 			storage_limit: u32,
 			estimate: bool,
+			state_overrides: Vec<(H160, StateOverride)>,
 		) -> Result<CreateInfo, sp_runtime::DispatchError> {
+			EVM::apply_state_overrides(state_overrides)?;
+
 			let config = if estimate {
 				let mut config = <Runtime as module_evm::Config>::config().clone();
 				config.estimate = true;
@@ -2097,6 +2807,40 @@ impl_runtime_apis! {
 
 			request.ok_or(sp_runtime::DispatchError::Other("Invalid parameter extrinsic, not evm Call"))
 		}
+
+		fn base_fee_per_gas() -> Balance {
+			EVM::base_fee_per_gas()
+		}
+
+		fn base_fee_history(block_count: u32) -> Vec<Balance> {
+			EVM::base_fee_history(block_count)
+		}
+
+		fn storage_statement(contract: H160) -> Option<ContractStorageStatement<Balance>> {
+			EVM::storage_statement(&contract)
+		}
+
+		fn trace_extrinsic(
+			extrinsics: Vec<<Block as BlockT>::Extrinsic>,
+			extrinsic: <Block as BlockT>::Extrinsic,
+			tracer: TracerConfig,
+		) -> Result<TraceOutcome, sp_runtime::DispatchError> {
+			#[cfg(feature = "tracing")]
+			{
+				for extrinsic in extrinsics {
+					let _ = Executive::apply_extrinsic(extrinsic);
+				}
+
+				let (_, outcome) = module_evm::runner::tracing::trace(tracer, || Executive::apply_extrinsic(extrinsic));
+				Ok(outcome)
+			}
+
+			#[cfg(not(feature = "tracing"))]
+			{
+				let _ = (extrinsics, extrinsic, tracer);
+				Err(sp_runtime::DispatchError::Other("EVM tracing is not enabled"))
+			}
+		}
 	}
 
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
@@ -2145,6 +2889,7 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, module_transaction_payment, benchmarking::transaction_payment);
 			orml_list_benchmark!(list, extra, module_incentives, benchmarking::incentives);
 			orml_list_benchmark!(list, extra, module_prices, benchmarking::prices);
+			orml_list_benchmark!(list, extra, module_payments, benchmarking::payments);
 			orml_list_benchmark!(list, extra, module_evm_accounts, benchmarking::evm_accounts);
 			// orml_list_benchmark!(list, extra, module_homa, benchmarking::homa);
 			orml_list_benchmark!(list, extra, module_currencies, benchmarking::currencies);
@@ -2205,6 +2950,7 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, module_transaction_payment, benchmarking::transaction_payment);
 			orml_add_benchmark!(params, batches, module_incentives, benchmarking::incentives);
 			orml_add_benchmark!(params, batches, module_prices, benchmarking::prices);
+			orml_add_benchmark!(params, batches, module_payments, benchmarking::payments);
 			orml_add_benchmark!(params, batches, module_evm_accounts, benchmarking::evm_accounts);
 			// orml_add_benchmark!(params, batches, module_homa, benchmarking::homa);
 			orml_add_benchmark!(params, batches, module_currencies, benchmarking::currencies);
@@ -19,6 +19,7 @@
 //! A list of the different weight modules for our runtime.
 #![allow(clippy::unnecessary_cast)]
 
+pub mod module_asset_registry;
 pub mod module_auction_manager;
 pub mod module_cdp_engine;
 pub mod module_cdp_treasury;
@@ -28,16 +29,19 @@ pub mod module_dex;
 pub mod module_emergency_shutdown;
 pub mod module_evm;
 pub mod module_evm_accounts;
+pub mod module_evm_manager;
 pub mod module_homa;
 pub mod module_homa_lite;
 pub mod module_honzon;
 pub mod module_incentives;
 pub mod module_nft;
 pub mod module_nominees_election;
+pub mod module_payments;
 pub mod module_prices;
 pub mod module_session_manager;
 pub mod module_transaction_pause;
 pub mod module_transaction_payment;
+pub mod module_xcm_channel;
 
 pub mod orml_auction;
 pub mod orml_authority;
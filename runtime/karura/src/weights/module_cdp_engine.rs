@@ -63,6 +63,14 @@ impl<T: frame_system::Config> module_cdp_engine::WeightInfo for WeightInfo<T> {
 		(20_645_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_maximum_debit_value_per_account() -> Weight {
+		(33_904_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_offchain_worker_params() -> Weight {
+		(20_645_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn liquidate_by_auction() -> Weight {
 		(315_153_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(24 as Weight))
@@ -57,6 +57,24 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_exchange_fee() -> Weight {
+		(19_873_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_lbp_creator() -> Weight {
+		(19_871_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn create_lbp() -> Weight {
+		(98_746_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	fn end_lbp() -> Weight {
+		(86_213_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
 	fn list_provisioning() -> Weight {
 		(37_299_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
@@ -72,6 +90,16 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
+	fn abort_provisioning() -> Weight {
+		(27_842_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn extend_provisioning_deadline() -> Weight {
+		(24_315_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn add_provision() -> Weight {
 		(164_657_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(5 as Weight))
@@ -82,6 +110,11 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(7 as Weight))
 			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
+	fn claim_provisioning_refund() -> Weight {
+		(86_370_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
 	fn add_liquidity() -> Weight {
 		(209_556_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(9 as Weight))
@@ -92,6 +125,16 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(12 as Weight))
 			.saturating_add(T::DbWeight::get().writes(10 as Weight))
 	}
+	fn add_liquidity_single() -> Weight {
+		(236_904_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	fn add_liquidity_single_and_stake() -> Weight {
+		(309_772_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+	}
 	fn remove_liquidity() -> Weight {
 		(179_304_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(6 as Weight))
@@ -102,6 +145,16 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(12 as Weight))
 			.saturating_add(T::DbWeight::get().writes(10 as Weight))
 	}
+	fn migrate_liquidity() -> Weight {
+		(561_237_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(21 as Weight))
+			.saturating_add(T::DbWeight::get().writes(18 as Weight))
+	}
+	fn migrate_liquidity_by_unstake() -> Weight {
+		(692_504_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(25 as Weight))
+			.saturating_add(T::DbWeight::get().writes(22 as Weight))
+	}
 	fn swap_with_exact_supply(_u: u32, ) -> Weight {
 		(141_366_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(9 as Weight))
@@ -114,4 +167,9 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(9 as Weight))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
+	fn swap_with_best_path() -> Weight {
+		(187_362_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
 }
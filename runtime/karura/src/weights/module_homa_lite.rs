@@ -52,6 +52,20 @@ impl<T: frame_system::Config> module_homa_lite::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(13 as Weight))
 			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
+	fn mint_for_redeem_requests(r: u32) -> Weight {
+		(243_172_000 as Weight)
+			// Standard Error: 62_000
+			.saturating_add((96_411_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(r as Weight)))
+	}
+	fn request_redeem_fast() -> Weight {
+		(52_938_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 	fn set_total_staking_currency() -> Weight {
 		(24_685_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
@@ -69,4 +83,9 @@ impl<T: frame_system::Config> module_homa_lite::WeightInfo for WeightInfo<T> {
 		(19_859_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn compound_rewards() -> Weight {
+		(31_482_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 }
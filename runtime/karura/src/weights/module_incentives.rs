@@ -69,6 +69,11 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(7 as Weight))
 			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
+	fn claim_rewards_vested() -> Weight {
+		(148_445_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
 	fn update_incentive_rewards(c: u32, ) -> Weight {
 		(2_507_000 as Weight)
 			// Standard Error: 45_000
@@ -90,4 +95,16 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn update_reward_multipliers(c: u32, ) -> Weight {
+		(1_102_000 as Weight)
+			// Standard Error: 22_000
+			.saturating_add((2_046_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_vesting_payout_configs(c: u32, ) -> Weight {
+		(914_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 }
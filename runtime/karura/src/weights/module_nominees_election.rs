@@ -89,4 +89,11 @@ impl<T: frame_system::Config> module_nominees_election::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn report_performance(c: u32, ) -> Weight {
+		(2_135_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((3_312_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 }
@@ -47,6 +47,14 @@ use sp_std::marker::PhantomData;
 /// Weight functions for module_prices.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> module_prices::WeightInfo for WeightInfo<T> {
+	fn on_initialize(c: u32) -> Weight {
+		(1_623_000 as Weight)
+			// Standard Error: 7_000
+			.saturating_add((6_847_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 	fn lock_price() -> Weight {
 		(24_059_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
@@ -56,4 +64,24 @@ impl<T: frame_system::Config> module_prices::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn reinstate_feeder() -> Weight {
+		(21_376_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn set_price_aggregation_strategy() -> Weight {
+		(18_204_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn unfreeze_price() -> Weight {
+		(20_115_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn update_remote_prices(c: u32) -> Weight {
+		(2_036_000 as Weight)
+			// Standard Error: 5_000
+			.saturating_add((7_415_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 }
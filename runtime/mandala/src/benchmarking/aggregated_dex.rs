@@ -0,0 +1,113 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::{
+	dollar, AccountId, AggregatedDex, Balance, Currencies, CurrencyId, Dex, GetNativeCurrencyId, GetStableCurrencyId,
+	Runtime,
+};
+
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_system::RawOrigin;
+use module_aggregated_dex::AggregatedSwapPath;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrencyExtended;
+use primitives::TokenSymbol;
+use sp_runtime::traits::UniqueSaturatedInto;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
+const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
+const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+
+fn inject_liquidity(
+	maker: AccountId,
+	currency_id_a: CurrencyId,
+	currency_id_b: CurrencyId,
+	amount_a: Balance,
+	amount_b: Balance,
+) -> Result<(), &'static str> {
+	<Currencies as MultiCurrencyExtended<_>>::update_balance(currency_id_a, &maker, amount_a.unique_saturated_into())?;
+	<Currencies as MultiCurrencyExtended<_>>::update_balance(currency_id_b, &maker, amount_b.unique_saturated_into())?;
+
+	let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id_a, currency_id_b);
+
+	Dex::add_liquidity(
+		RawOrigin::Signed(maker).into(),
+		currency_id_a,
+		currency_id_b,
+		amount_a,
+		amount_b,
+		Default::default(),
+		false,
+	)?;
+
+	Ok(())
+}
+
+fn alternative_swap_paths() -> Vec<Vec<AggregatedSwapPath>> {
+	vec![vec![
+		AggregatedSwapPath::Dex(vec![STABLECOIN, DOT]),
+		AggregatedSwapPath::Dex(vec![DOT, NATIVE]),
+	]]
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_aggregated_dex }
+
+	swap_with_exact_supply {
+		let u in 1 .. 3;
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+		inject_liquidity(maker, STABLECOIN, NATIVE, 10_000 * dollar(STABLECOIN), 10_000 * dollar(NATIVE))?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STABLECOIN, &taker, (100 * dollar(STABLECOIN)).unique_saturated_into())?;
+
+		// swap back and forth to use `u` hops
+		let mut swap_path = vec![];
+		for i in 0 .. u {
+			if i % 2 == 0 {
+				swap_path.push(AggregatedSwapPath::Dex(vec![STABLECOIN, NATIVE]));
+			} else {
+				swap_path.push(AggregatedSwapPath::Dex(vec![NATIVE, STABLECOIN]));
+			}
+		}
+	}: _(RawOrigin::Signed(taker), swap_path, 100 * dollar(STABLECOIN), 0)
+
+	swap_with_best_path {
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+		inject_liquidity(maker.clone(), STABLECOIN, NATIVE, 10_000 * dollar(STABLECOIN), 10_000 * dollar(NATIVE))?;
+		inject_liquidity(maker.clone(), STABLECOIN, DOT, 10_000 * dollar(STABLECOIN), 10_000 * dollar(DOT))?;
+		inject_liquidity(maker, DOT, NATIVE, 10_000 * dollar(DOT), 10_000 * dollar(NATIVE))?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STABLECOIN, &taker, (100 * dollar(STABLECOIN)).unique_saturated_into())?;
+		AggregatedDex::update_alternative_swap_paths(RawOrigin::Root.into(), STABLECOIN, NATIVE, Some(alternative_swap_paths()))?;
+	}: _(RawOrigin::Signed(taker), STABLECOIN, NATIVE, 100 * dollar(STABLECOIN), 0)
+
+	update_alternative_swap_paths {
+	}: _(RawOrigin::Root, STABLECOIN, NATIVE, Some(alternative_swap_paths()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
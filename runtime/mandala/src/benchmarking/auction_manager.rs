@@ -17,14 +17,14 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	dollar, AccountId, AuctionId, AuctionManager, CdpTreasury, Currencies, CurrencyId, EmergencyShutdown,
-	GetStableCurrencyId, GetStakingCurrencyId, Price, Runtime,
+	dollar, AccountId, AuctionId, AuctionManager, CdpEngine, CdpTreasury, Currencies, CurrencyId, Dex,
+	EmergencyShutdown, GetStableCurrencyId, GetStakingCurrencyId, Price, Runtime, RENBTC,
 };
 
 use super::utils::feed_price;
 use frame_benchmarking::account;
 use frame_system::RawOrigin;
-use module_support::{AuctionManager as AuctionManagerTrait, CDPTreasury};
+use module_support::{AuctionManager as AuctionManagerTrait, CDPTreasury, CollateralAuctionType};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
 use sp_runtime::FixedPointNumber;
@@ -62,6 +62,64 @@ runtime_benchmarks! {
 		// shutdown
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
 	}: cancel(RawOrigin::None, auction_id)
+
+	// take a Dutch collateral auction, worst case:
+	// auction is in reverse stage and collateral is refunded
+	bid_dutch_auction {
+		let bidder: AccountId = account("bidder", 0, SEED);
+		let funder: AccountId = account("funder", 0, SEED);
+
+		// set balance
+		Currencies::deposit(STABLECOIN, &bidder, 200 * dollar(STABLECOIN))?;
+		Currencies::deposit(STAKING, &funder, dollar(STAKING))?;
+		CdpTreasury::deposit_collateral(&funder, STAKING, dollar(STAKING))?;
+
+		// feed price
+		feed_price(vec![(STAKING, Price::saturating_from_integer(120))])?;
+
+		// create Dutch collateral auction
+		CdpEngine::set_collateral_auction_type(RawOrigin::Root.into(), STAKING, CollateralAuctionType::Dutch)?;
+		AuctionManager::new_collateral_auction(&funder, STAKING, dollar(STAKING), 100 * dollar(STABLECOIN))?;
+		let auction_id: AuctionId = Default::default();
+	}: _(RawOrigin::Signed(bidder), auction_id, 200 * dollar(STABLECOIN))
+
+	// bid a collateral auction with other currency, worst case:
+	// auction have been already bid
+	bid_with_swap {
+		let bidder: AccountId = account("bidder", 0, SEED);
+		let new_bidder: AccountId = account("new_bidder", 0, SEED);
+		let funder: AccountId = account("funder", 0, SEED);
+
+		// set balance
+		Currencies::deposit(STABLECOIN, &bidder, 80 * dollar(STABLECOIN))?;
+		Currencies::deposit(STAKING, &funder, dollar(STAKING))?;
+		Currencies::deposit(RENBTC, &new_bidder, dollar(RENBTC))?;
+		CdpTreasury::deposit_collateral(&funder, STAKING, dollar(STAKING))?;
+
+		// feed price
+		feed_price(vec![(STAKING, Price::saturating_from_integer(120))])?;
+
+		// add liquidity
+		Currencies::deposit(RENBTC, &funder, 100 * dollar(RENBTC))?;
+		Currencies::deposit(STABLECOIN, &funder, 10_000 * dollar(STABLECOIN))?;
+		let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), RENBTC, STABLECOIN);
+		Dex::add_liquidity(
+			RawOrigin::Signed(funder.clone()).into(),
+			RENBTC,
+			STABLECOIN,
+			100 * dollar(RENBTC),
+			10_000 * dollar(STABLECOIN),
+			Default::default(),
+			false,
+		)?;
+
+		// create collateral auction
+		AuctionManager::new_collateral_auction(&funder, STAKING, dollar(STAKING), 100 * dollar(STABLECOIN))?;
+		let auction_id: AuctionId = Default::default();
+
+		// bid collateral auction
+		let _ = AuctionManager::collateral_auction_bid_handler(1, auction_id, (bidder, 80 * dollar(STABLECOIN)), None);
+	}: _(RawOrigin::Signed(new_bidder), auction_id, RENBTC, dollar(RENBTC), 0)
 }
 
 #[cfg(test)]
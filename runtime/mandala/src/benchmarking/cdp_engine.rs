@@ -141,6 +141,16 @@ runtime_benchmarks! {
 	set_global_params {
 	}: _(RawOrigin::Root, Rate::saturating_from_rational(1, 1000000))
 
+	set_maximum_debit_value_per_account {
+	}: _(RawOrigin::Root, STAKING, Some(10_000 * dollar(STABLECOIN)))
+
+	set_offchain_worker_params {
+	}: _(RawOrigin::Root, module_cdp_engine::OffchainWorkerParams {
+		max_iterations: 500,
+		lock_expiration: 200,
+		shuffle_start: false,
+	})
+
 	// `liquidate` by_auction
 	liquidate_by_auction {
 		let owner: AccountId = account("owner", 0, SEED);
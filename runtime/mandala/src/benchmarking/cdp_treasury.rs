@@ -16,15 +16,34 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{dollar, CdpTreasury, Currencies, CurrencyId, GetStableCurrencyId, GetStakingCurrencyId, Runtime};
+use crate::{
+	dollar, AccountId, CdpTreasury, Currencies, CurrencyId, Dex, GetNativeCurrencyId, GetStableCurrencyId,
+	GetStakingCurrencyId, Ratio, Runtime,
+};
 
+use super::utils::set_balance;
+use frame_benchmarking::account;
+use frame_support::weights::Weight;
 use frame_system::RawOrigin;
+use module_cdp_treasury::{DexDisposalParams, DisposalTask};
 use module_support::CDPTreasury;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
+use sp_runtime::FixedPointNumber;
+
+const SEED: u32 = 0;
 
 const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
 const STAKING: CurrencyId = GetStakingCurrencyId::get();
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
+
+fn dex_disposal_params() -> DexDisposalParams {
+	DexDisposalParams {
+		surplus_buffer: 100 * dollar(STABLECOIN),
+		max_lot_size: 200 * dollar(STABLECOIN),
+		max_slippage: Ratio::saturating_from_rational(10, 100),
+	}
+}
 
 runtime_benchmarks! {
 	{ Runtime, module_cdp_treasury }
@@ -39,6 +58,29 @@ runtime_benchmarks! {
 	extract_surplus_to_treasury {
 		CdpTreasury::on_system_surplus(1_000 * dollar(STABLECOIN))?;
 	}: _(RawOrigin::Root, 200 * dollar(STABLECOIN))
+
+	set_dex_disposal_params {
+	}: _(RawOrigin::Root, Some(dex_disposal_params()))
+
+	dispose_lot {
+		let maker: AccountId = account("maker", 0, SEED);
+		set_balance(STABLECOIN, &maker, 10_000 * dollar(STABLECOIN));
+		set_balance(NATIVE, &maker, 10_000 * dollar(NATIVE));
+		let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), STABLECOIN, NATIVE);
+		Dex::add_liquidity(
+			RawOrigin::Signed(maker).into(),
+			STABLECOIN,
+			NATIVE,
+			10_000 * dollar(STABLECOIN),
+			10_000 * dollar(NATIVE),
+			Default::default(),
+			false,
+		)?;
+		CdpTreasury::set_dex_disposal_params(RawOrigin::Root.into(), Some(dex_disposal_params()))?;
+		CdpTreasury::on_system_surplus(1_000 * dollar(STABLECOIN))?;
+	}: {
+		CdpTreasury::dispatch_disposal(DisposalTask::Surplus, Weight::max_value());
+	}
 }
 
 #[cfg(test)]
@@ -17,8 +17,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	AccountId, Balance, Balances, CollatorKickThreshold, CollatorSelection, Event, MaxCandidates, MaxInvulnerables,
-	MinCandidates, Period, Runtime, Session, SessionKeys, System,
+	AccountId, Balance, Balances, CollatorKickMissedSessions, CollatorKickThreshold, CollatorSelection, Event,
+	MaxCandidates, MaxInvulnerables, MinCandidates, Period, Runtime, Session, SessionKeys, System,
 };
 
 use frame_benchmarking::{account, whitelisted_caller};
@@ -118,6 +118,26 @@ runtime_benchmarks! {
 		assert_last_event(module_collator_selection::Event::CandidateAdded(caller, bond.checked_div(2u32.into()).unwrap()).into());
 	}
 
+	// worse case is when all the max-candidate slots are filled, and the lowest bonded candidate is
+	// evicted.
+	register_as_candidate_with_bond {
+		// MinCandidates = 5, so begin with 5.
+		let c in 5 .. MaxCandidates::get();
+
+		module_collator_selection::CandidacyBond::<Runtime>::put(Balances::minimum_balance());
+		module_collator_selection::DesiredCandidates::<Runtime>::put(c);
+		register_candidates(c);
+
+		let caller: AccountId = whitelisted_caller();
+		let bond: Balance = Balances::minimum_balance().checked_mul(2u32.into()).unwrap();
+		Balances::make_free_balance_be(&caller, bond.checked_mul(2u32.into()).unwrap());
+
+		Session::set_keys(RawOrigin::Signed(caller.clone()).into(), SessionKeys::default(), vec![]).unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), bond)
+	verify {
+		assert_last_event(module_collator_selection::Event::CandidateAdded(caller, bond).into());
+	}
+
 	register_candidate {
 		let c in 1 .. MaxCandidates::get();
 
@@ -237,8 +257,9 @@ runtime_benchmarks! {
 		let mut count = 0;
 		candidates.iter().for_each(|candidate| {
 			if count < removals {
-				// point = 0, will be removed.
+				// point = 0 in the last allowed session, will be removed.
 				module_collator_selection::SessionPoints::<Runtime>::insert(&candidate, 0);
+				module_collator_selection::MissedSessions::<Runtime>::insert(&candidate, CollatorKickMissedSessions::get() - 1);
 			} else {
 				module_collator_selection::SessionPoints::<Runtime>::insert(&candidate, CollatorKickThreshold::get().mul_floor(Period::get() * POINT_PER_BLOCK));
 			}
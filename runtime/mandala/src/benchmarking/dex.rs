@@ -17,7 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	dollar, AccountId, Balance, Currencies, CurrencyId, Dex, GetNativeCurrencyId, GetStableCurrencyId, Runtime,
+	dollar, AccountId, Balance, Currencies, CurrencyId, Dex, GetNativeCurrencyId, GetStableCurrencyId, Runtime, System,
 	TradingPathLimit,
 };
 
@@ -26,8 +26,8 @@ use frame_system::RawOrigin;
 use module_dex::TradingPairStatus;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrencyExtended;
-use primitives::TradingPair;
-use sp_runtime::traits::UniqueSaturatedInto;
+use primitives::{TokenSymbol, TradingPair};
+use sp_runtime::{traits::UniqueSaturatedInto, Permill};
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -89,6 +89,42 @@ runtime_benchmarks! {
 		}
 	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second())
 
+	// set the exchange fee of a trading pair
+	set_exchange_fee {
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), Some((3, 1000)))
+
+	// whitelist a creator of liquidity bootstrapping pools
+	set_lbp_creator {
+		let creator: AccountId = account("creator", 0, SEED);
+	}: _(RawOrigin::Root, creator, true)
+
+	// create a liquidity bootstrapping pool
+	create_lbp {
+		let creator: AccountId = whitelisted_caller();
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		if let TradingPairStatus::Enabled = Dex::trading_pair_statuses(trading_pair) {
+			Dex::disable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
+		}
+		Dex::set_lbp_creator(RawOrigin::Root.into(), creator.clone(), true)?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(NATIVE, &creator, (1000 * dollar(NATIVE)).unique_saturated_into())?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STABLECOIN, &creator, (100 * dollar(STABLECOIN)).unique_saturated_into())?;
+	}: _(RawOrigin::Signed(creator), NATIVE, STABLECOIN, 1000 * dollar(NATIVE), 100 * dollar(STABLECOIN), Permill::from_percent(90), Permill::from_percent(50), 100u32.into())
+
+	// end a liquidity bootstrapping pool
+	end_lbp {
+		let creator: AccountId = whitelisted_caller();
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		if let TradingPairStatus::Enabled = Dex::trading_pair_statuses(trading_pair) {
+			Dex::disable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
+		}
+		Dex::set_lbp_creator(RawOrigin::Root.into(), creator.clone(), true)?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(NATIVE, &creator, (1000 * dollar(NATIVE)).unique_saturated_into())?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STABLECOIN, &creator, (100 * dollar(STABLECOIN)).unique_saturated_into())?;
+		Dex::create_lbp(RawOrigin::Signed(creator.clone()).into(), NATIVE, STABLECOIN, 1000 * dollar(NATIVE), 100 * dollar(STABLECOIN), Permill::from_percent(90), Permill::from_percent(50), 100u32.into())?;
+		System::set_block_number(100u32.into());
+	}: _(RawOrigin::Signed(creator), NATIVE, STABLECOIN)
+
 	// list a Provisioning trading pair
 	list_provisioning {
 		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
@@ -147,6 +183,44 @@ runtime_benchmarks! {
 		)?;
 	}: _(RawOrigin::Signed(founder), trading_pair.first(), trading_pair.second())
 
+	// abort a Provisioning trading pair
+	abort_provisioning {
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		if let TradingPairStatus::Enabled = Dex::trading_pair_statuses(trading_pair) {
+			Dex::disable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
+		}
+		Dex::list_provisioning(
+			RawOrigin::Root.into(),
+			trading_pair.first(),
+			trading_pair.second(),
+			dollar(trading_pair.first()),
+			dollar(trading_pair.second()),
+			100 * dollar(trading_pair.first()),
+			1000 * dollar(trading_pair.second()),
+			0
+		)?;
+		Dex::extend_provisioning_deadline(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second(), 100)?;
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second())
+
+	// extend the deadline of a Provisioning trading pair
+	extend_provisioning_deadline {
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		if let TradingPairStatus::Enabled = Dex::trading_pair_statuses(trading_pair) {
+			Dex::disable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
+		}
+		Dex::list_provisioning(
+			RawOrigin::Root.into(),
+			trading_pair.first(),
+			trading_pair.second(),
+			dollar(trading_pair.first()),
+			dollar(trading_pair.second()),
+			100 * dollar(trading_pair.first()),
+			1000 * dollar(trading_pair.second()),
+			0
+		)?;
+		Dex::extend_provisioning_deadline(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second(), 100)?;
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), 200)
+
 	add_provision {
 		let founder: AccountId = whitelisted_caller();
 		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
@@ -204,6 +278,39 @@ runtime_benchmarks! {
 		)?;
 	}: _(RawOrigin::Signed(whitelisted_caller()), founder, trading_pair.first(), trading_pair.second())
 
+	// worst: claim refund after the deadline, which aborts the provisioning
+	claim_provisioning_refund {
+		let founder: AccountId = whitelisted_caller();
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		if let TradingPairStatus::Enabled = Dex::trading_pair_statuses(trading_pair) {
+			Dex::disable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
+		}
+		Dex::list_provisioning(
+			RawOrigin::Root.into(),
+			trading_pair.first(),
+			trading_pair.second(),
+			dollar(trading_pair.first()),
+			dollar(trading_pair.second()),
+			100 * dollar(trading_pair.first()),
+			1000 * dollar(trading_pair.second()),
+			0
+		)?;
+
+		// set balance
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.first(), &founder, (10 * dollar(trading_pair.first())).unique_saturated_into())?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.second(), &founder, (10 * dollar(trading_pair.second())).unique_saturated_into())?;
+
+		Dex::add_provision(
+			RawOrigin::Signed(founder.clone()).into(),
+			trading_pair.first(),
+			trading_pair.second(),
+			dollar(trading_pair.first()),
+			dollar(trading_pair.second())
+		)?;
+		Dex::extend_provisioning_deadline(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second(), System::block_number() + 1)?;
+		System::set_block_number(System::block_number() + 2);
+	}: _(RawOrigin::Signed(whitelisted_caller()), founder, trading_pair.first(), trading_pair.second())
+
 	// add liquidity but don't staking lp
 	add_liquidity {
 		let first_maker: AccountId = account("first_maker", 0, SEED);
@@ -236,6 +343,36 @@ runtime_benchmarks! {
 		inject_liquidity(first_maker.clone(), trading_pair.first(), trading_pair.second(), amount_a, amount_b, true)?;
 	}: add_liquidity(RawOrigin::Signed(second_maker), trading_pair.first(), trading_pair.second(), amount_a, amount_b, Default::default(), true)
 
+	// add liquidity with only one currency
+	add_liquidity_single {
+		let first_maker: AccountId = account("first_maker", 0, SEED);
+		let second_maker: AccountId = whitelisted_caller();
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		let amount_a = 100 * dollar(trading_pair.first());
+		let amount_b = 10_000 * dollar(trading_pair.second());
+
+		// set balance
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.first(), &second_maker, amount_a.unique_saturated_into())?;
+
+		// first maker inject liquidity
+		inject_liquidity(first_maker.clone(), trading_pair.first(), trading_pair.second(), amount_a, amount_b, true)?;
+	}: add_liquidity_single(RawOrigin::Signed(second_maker), trading_pair.first(), trading_pair.second(), amount_a, Default::default(), false)
+
+	// add liquidity with only one currency
+	add_liquidity_single_and_stake {
+		let first_maker: AccountId = account("first_maker", 0, SEED);
+		let second_maker: AccountId = whitelisted_caller();
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		let amount_a = 100 * dollar(trading_pair.first());
+		let amount_b = 10_000 * dollar(trading_pair.second());
+
+		// set balance
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.first(), &second_maker, amount_a.unique_saturated_into())?;
+
+		// first maker inject liquidity
+		inject_liquidity(first_maker.clone(), trading_pair.first(), trading_pair.second(), amount_a, amount_b, true)?;
+	}: add_liquidity_single(RawOrigin::Signed(second_maker), trading_pair.first(), trading_pair.second(), amount_a, Default::default(), true)
+
 	// remove liquidity by liquid lp share
 	remove_liquidity {
 		let maker: AccountId = whitelisted_caller();
@@ -250,6 +387,24 @@ runtime_benchmarks! {
 		inject_liquidity(maker.clone(), trading_pair.first(), trading_pair.second(), 100 * dollar(trading_pair.first()), 10_000 * dollar(trading_pair.second()), true)?;
 	}: remove_liquidity(RawOrigin::Signed(maker), trading_pair.first(), trading_pair.second(), 50 * dollar(trading_pair.first()), Default::default(), Default::default(), true)
 
+	// migrate liquidity by liquid lp share
+	migrate_liquidity {
+		let maker: AccountId = whitelisted_caller();
+		let dot: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+		inject_liquidity(account("maker", 0, SEED), NATIVE, dot, 10_000 * dollar(NATIVE), 10_000 * dollar(dot), false)?;
+		inject_liquidity(account("maker", 0, SEED), STABLECOIN, dot, 10_000 * dollar(STABLECOIN), 10_000 * dollar(dot), false)?;
+		inject_liquidity(maker.clone(), STABLECOIN, NATIVE, 100 * dollar(STABLECOIN), 100 * dollar(NATIVE), false)?;
+	}: migrate_liquidity(RawOrigin::Signed(maker), STABLECOIN, NATIVE, dot, 50 * dollar(STABLECOIN), Default::default(), false)
+
+	// migrate liquidity by withdraw staking lp share
+	migrate_liquidity_by_unstake {
+		let maker: AccountId = whitelisted_caller();
+		let dot: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+		inject_liquidity(account("maker", 0, SEED), NATIVE, dot, 10_000 * dollar(NATIVE), 10_000 * dollar(dot), false)?;
+		inject_liquidity(account("maker", 0, SEED), STABLECOIN, dot, 10_000 * dollar(STABLECOIN), 10_000 * dollar(dot), false)?;
+		inject_liquidity(maker.clone(), STABLECOIN, NATIVE, 100 * dollar(STABLECOIN), 100 * dollar(NATIVE), true)?;
+	}: migrate_liquidity(RawOrigin::Signed(maker), STABLECOIN, NATIVE, dot, 50 * dollar(STABLECOIN), Default::default(), true)
+
 	swap_with_exact_supply {
 		let u in 2 .. TradingPathLimit::get() as u32;
 
@@ -299,6 +454,17 @@ runtime_benchmarks! {
 
 		<Currencies as MultiCurrencyExtended<_>>::update_balance(path[0], &taker, (10_000 * dollar(path[0])).unique_saturated_into())?;
 	}: swap_with_exact_target(RawOrigin::Signed(taker), path.clone(), 10 * dollar(path[path.len() - 1]), 100 * dollar(path[0]))
+
+	swap_with_best_path {
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+		let dot: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+		inject_liquidity(maker.clone(), NATIVE, STABLECOIN, 10_000 * dollar(NATIVE), 10_000 * dollar(STABLECOIN), false)?;
+		inject_liquidity(maker.clone(), STABLECOIN, dot, 10_000 * dollar(STABLECOIN), 10_000 * dollar(dot), false)?;
+		inject_liquidity(maker, NATIVE, dot, 10_000 * dollar(NATIVE), 10_000 * dollar(dot), false)?;
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(NATIVE, &taker, (10_000 * dollar(NATIVE)).unique_saturated_into())?;
+	}: swap_with_best_path(RawOrigin::Signed(taker), NATIVE, dot, 100 * dollar(NATIVE), 0)
 }
 
 #[cfg(test)]
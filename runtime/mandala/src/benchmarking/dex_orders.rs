@@ -0,0 +1,101 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	dollar, AccountId, Balance, Currencies, CurrencyId, Dex, DexOrders, GetNativeCurrencyId, GetStableCurrencyId,
+	Runtime, TradingPathLimit,
+};
+
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrencyExtended;
+use primitives::TokenSymbol;
+use sp_runtime::traits::UniqueSaturatedInto;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
+const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
+const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+
+fn inject_liquidity(
+	maker: AccountId,
+	currency_id_a: CurrencyId,
+	currency_id_b: CurrencyId,
+	amount_a: Balance,
+	amount_b: Balance,
+) -> Result<(), &'static str> {
+	<Currencies as MultiCurrencyExtended<_>>::update_balance(currency_id_a, &maker, amount_a.unique_saturated_into())?;
+	<Currencies as MultiCurrencyExtended<_>>::update_balance(currency_id_b, &maker, amount_b.unique_saturated_into())?;
+
+	let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id_a, currency_id_b);
+
+	Dex::add_liquidity(
+		RawOrigin::Signed(maker).into(),
+		currency_id_a,
+		currency_id_b,
+		amount_a,
+		amount_b,
+		Default::default(),
+		false,
+	)?;
+
+	Ok(())
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_dex_orders }
+
+	place_order {
+		let caller: AccountId = whitelisted_caller();
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(NATIVE, &caller, (10_000 * dollar(NATIVE)).unique_saturated_into())?;
+	}: _(RawOrigin::Signed(caller), NATIVE, STABLECOIN, 100 * dollar(NATIVE), dollar(STABLECOIN))
+
+	cancel_order {
+		let caller: AccountId = whitelisted_caller();
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(NATIVE, &caller, (10_000 * dollar(NATIVE)).unique_saturated_into())?;
+		DexOrders::place_order(RawOrigin::Signed(caller.clone()).into(), NATIVE, STABLECOIN, 100 * dollar(NATIVE), dollar(STABLECOIN))?;
+		let order_id = DexOrders::next_order_id() - 1;
+	}: _(RawOrigin::Signed(caller), order_id)
+
+	fill_order {
+		let u in 2 .. TradingPathLimit::get() as u32;
+
+		let path: Vec<CurrencyId> = vec![NATIVE, STABLECOIN, DOT].into_iter().take(u as usize).collect();
+		let maker: AccountId = account("maker", 0, SEED);
+		let owner: AccountId = account("owner", 0, SEED);
+		let filler: AccountId = whitelisted_caller();
+		inject_liquidity(maker.clone(), NATIVE, STABLECOIN, 10_000 * dollar(NATIVE), 10_000 * dollar(STABLECOIN))?;
+		inject_liquidity(maker, STABLECOIN, DOT, 10_000 * dollar(STABLECOIN), 10_000 * dollar(DOT))?;
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(NATIVE, &owner, (10_000 * dollar(NATIVE)).unique_saturated_into())?;
+		DexOrders::place_order(RawOrigin::Signed(owner).into(), path[0], path[path.len() - 1], 100 * dollar(NATIVE), 1)?;
+		let order_id = DexOrders::next_order_id() - 1;
+	}: _(RawOrigin::Signed(filler), order_id, path.clone())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -0,0 +1,99 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::set_balance;
+use crate::{
+	dollar, AccountId, CurrencyId, Earning, EarningConvictionPeriod, EarningUnbondingPeriod, GetNativeCurrencyId,
+	Runtime, System,
+};
+use frame_benchmarking::whitelisted_caller;
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use sp_std::prelude::*;
+
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
+
+runtime_benchmarks! {
+	{ Runtime, module_earning }
+
+	bond {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 1_000 * dollar(NATIVE));
+	}: _(RawOrigin::Signed(caller.clone()), 100 * dollar(NATIVE))
+	verify {
+		assert_eq!(Earning::bonded(&caller), 100 * dollar(NATIVE));
+	}
+
+	unbond {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 1_000 * dollar(NATIVE));
+		Earning::bond(RawOrigin::Signed(caller.clone()).into(), 100 * dollar(NATIVE))?;
+	}: _(RawOrigin::Signed(caller.clone()), 50 * dollar(NATIVE))
+	verify {
+		assert_eq!(Earning::bonded(&caller), 50 * dollar(NATIVE));
+	}
+
+	unbond_instant {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 1_000 * dollar(NATIVE));
+		Earning::bond(RawOrigin::Signed(caller.clone()).into(), 100 * dollar(NATIVE))?;
+	}: _(RawOrigin::Signed(caller.clone()), 50 * dollar(NATIVE))
+	verify {
+		assert_eq!(Earning::bonded(&caller), 50 * dollar(NATIVE));
+	}
+
+	rebond {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 1_000 * dollar(NATIVE));
+		Earning::bond(RawOrigin::Signed(caller.clone()).into(), 100 * dollar(NATIVE))?;
+		Earning::unbond(RawOrigin::Signed(caller.clone()).into(), 50 * dollar(NATIVE))?;
+	}: _(RawOrigin::Signed(caller.clone()), 50 * dollar(NATIVE))
+	verify {
+		assert_eq!(Earning::bonded(&caller), 100 * dollar(NATIVE));
+	}
+
+	withdraw_unbonded {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 1_000 * dollar(NATIVE));
+		Earning::bond(RawOrigin::Signed(caller.clone()).into(), 100 * dollar(NATIVE))?;
+		Earning::unbond(RawOrigin::Signed(caller.clone()).into(), 50 * dollar(NATIVE))?;
+		System::set_block_number(System::block_number() + EarningUnbondingPeriod::get());
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(Earning::ledger(&caller).unwrap().total, 50 * dollar(NATIVE));
+	}
+
+	commit {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 1_000 * dollar(NATIVE));
+		Earning::bond(RawOrigin::Signed(caller.clone()).into(), 100 * dollar(NATIVE))?;
+		let until = System::block_number() + EarningConvictionPeriod::get();
+	}: _(RawOrigin::Signed(caller.clone()), until)
+	verify {
+		assert_eq!(Earning::commitments(&caller), Some(until));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
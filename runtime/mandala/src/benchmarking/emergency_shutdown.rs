@@ -72,6 +72,28 @@ runtime_benchmarks! {
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
 		EmergencyShutdown::open_collateral_refund(RawOrigin::Root.into())?;
 	}: _(RawOrigin::Signed(caller),  1_000 * dollar(STABLECOIN))
+
+	refund_collateral {
+		let c in 1 .. CollateralCurrencyIds::get().len() as u32;
+		let currency_ids = CollateralCurrencyIds::get();
+		let funder: AccountId = account("funder", 0, SEED);
+		let caller: AccountId = whitelisted_caller();
+		let mut values = vec![];
+
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			values.push((currency_id, Price::one()));
+			set_balance(currency_id, &funder, 100 * dollar(currency_id));
+			CdpTreasury::deposit_collateral(&funder, currency_id, 100 * dollar(currency_id))?;
+		}
+		feed_price(values)?;
+
+		CdpTreasury::issue_debit(&caller, dollar(STABLECOIN), true)?;
+		CdpTreasury::issue_debit(&funder, 1_000 * dollar(STABLECOIN), true)?;
+
+		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
+		EmergencyShutdown::open_collateral_refund(RawOrigin::Root.into())?;
+	}: _(RawOrigin::Signed(caller), currency_ids[0], dollar(STABLECOIN))
 }
 
 #[cfg(test)]
@@ -93,6 +93,29 @@ runtime_benchmarks! {
 		whitelist_account!(alice_account);
 	}: _(RawOrigin::Signed(alice_account_id()), contract, bob_address)
 
+	propose_maintainer {
+		let alice_account = alice_account_id();
+
+		set_balance(NATIVE, &alice_account, 1_000_000 * dollar(NATIVE));
+		set_balance(NATIVE, &bob_account_id(), 1_000 * dollar(NATIVE));
+		let contract = deploy_contract(alice_account_id())?;
+		let bob_address = EvmAccounts::eth_address(&bob());
+
+		whitelist_account!(alice_account);
+	}: _(RawOrigin::Signed(alice_account_id()), contract, bob_address)
+
+	accept_maintainer {
+		let bob_account = bob_account_id();
+
+		set_balance(NATIVE, &alice_account_id(), 1_000_000 * dollar(NATIVE));
+		set_balance(NATIVE, &bob_account, 1_000 * dollar(NATIVE));
+		let contract = deploy_contract(alice_account_id())?;
+		let bob_address = EvmAccounts::eth_address(&bob());
+		EVM::propose_maintainer(Origin::signed(alice_account_id()), contract, bob_address)?;
+
+		whitelist_account!(bob_account);
+	}: _(RawOrigin::Signed(bob_account_id()), contract)
+
 	deploy {
 		let alice_account = alice_account_id();
 
@@ -103,6 +126,27 @@ runtime_benchmarks! {
 		whitelist_account!(alice_account);
 	}: _(RawOrigin::Signed(alice_account_id()), contract)
 
+	publish_contract {
+		let alice_account = alice_account_id();
+
+		set_balance(NATIVE, &alice_account, 1_000_000 * dollar(NATIVE));
+		set_balance(NATIVE, &bob_account_id(), 1_000 * dollar(NATIVE));
+		let contract = deploy_contract(alice_account_id())?;
+
+		whitelist_account!(alice_account);
+	}: _(RawOrigin::Signed(alice_account_id()), contract)
+
+	charge_storage_deposit {
+		let alice_account = alice_account_id();
+		let bob_account = bob_account_id();
+
+		set_balance(NATIVE, &alice_account, 1_000_000 * dollar(NATIVE));
+		set_balance(NATIVE, &bob_account, 1_000 * dollar(NATIVE));
+		let contract = deploy_contract(alice_account_id())?;
+
+		whitelist_account!(bob_account);
+	}: _(RawOrigin::Signed(bob_account), contract, 10 * dollar(NATIVE))
+
 	deploy_free {
 		let alice_account = alice_account_id();
 
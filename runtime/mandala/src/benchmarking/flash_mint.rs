@@ -0,0 +1,63 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{dollar, AccountId, Call, CurrencyId, FlashMint, GetStableCurrencyId, Rate, Runtime};
+
+use super::utils::set_balance;
+use frame_benchmarking::whitelisted_caller;
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::Change;
+use sp_runtime::FixedPointNumber;
+use sp_std::prelude::*;
+
+const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
+
+runtime_benchmarks! {
+	{ Runtime, module_flash_mint }
+
+	flash_mint {
+		let caller: AccountId = whitelisted_caller();
+		let amount = 10_000 * dollar(STABLECOIN);
+
+		// set balance to pay the fee
+		set_balance(STABLECOIN, &caller, dollar(STABLECOIN) * 100);
+
+		FlashMint::set_flash_mint_params(
+			RawOrigin::Root.into(),
+			Change::NewValue(amount),
+			Change::NewValue(Rate::saturating_from_rational(9, 10000)),
+		)?;
+	}: _(RawOrigin::Signed(caller), amount, Box::new(Call::System(frame_system::Call::remark(vec![]))))
+
+	set_flash_mint_params {
+	}: _(
+		RawOrigin::Root,
+		Change::NewValue(1_000_000 * dollar(STABLECOIN)),
+		Change::NewValue(Rate::saturating_from_rational(9, 10000))
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
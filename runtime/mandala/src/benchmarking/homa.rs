@@ -18,17 +18,20 @@
 
 use super::utils::set_balance;
 use crate::{
-	dollar, AccountId, Currencies, GetStakingCurrencyId, Homa, PolkadotBondingDuration, PolkadotBridge, Runtime,
-	StakingPool,
+	dollar, AccountId, Currencies, CurrencyId, GetNativeCurrencyId, GetStakingCurrencyId, Homa, NftPalletId,
+	PolkadotBondingDuration, PolkadotBridge, Runtime, StakingPool, NFT,
 };
 use frame_benchmarking::account;
 use frame_system::RawOrigin;
 use module_homa::RedeemStrategy;
+use module_nft::{ClassProperty, Properties};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
+use sp_runtime::traits::AccountIdConversion;
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
 
 fn new_era() {
 	PolkadotBridge::new_era(Default::default());
@@ -37,6 +40,20 @@ fn new_era() {
 	StakingPool::rebalance();
 }
 
+fn create_receipt_class() -> Result<(), sp_runtime::DispatchError> {
+	let owner: AccountId = account("owner", 0, SEED);
+	let class_account: AccountId = NftPalletId::get().into_sub_account(orml_nft::Pallet::<Runtime>::next_class_id());
+	set_balance(NATIVE, &owner, 1_000 * dollar(NATIVE));
+	NFT::create_class(
+		RawOrigin::Signed(owner).into(),
+		vec![1],
+		Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+		Default::default(),
+	)?;
+	set_balance(NATIVE, &class_account, 1_000 * dollar(NATIVE));
+	Ok(())
+}
+
 runtime_benchmarks! {
 	{ Runtime, module_homa }
 
@@ -94,6 +111,35 @@ runtime_benchmarks! {
 	verify {
 		assert!(<Currencies as MultiCurrency<_>>::total_balance(GetStakingCurrencyId::get(), &caller) > 0);
 	}
+
+	issue_unbonding_receipt {
+		let caller: AccountId = account("caller", 0, SEED);
+		let currency_id = GetStakingCurrencyId::get();
+		set_balance(currency_id, &caller, 1_000 * dollar(currency_id));
+		create_receipt_class()?;
+		Homa::mint(RawOrigin::Signed(caller.clone()).into(), 1_000 * dollar(currency_id))?;
+		new_era();
+		Homa::redeem(RawOrigin::Signed(caller.clone()).into(), dollar(currency_id), RedeemStrategy::WaitForUnbonding)?;
+		new_era();
+	}: _(RawOrigin::Signed(caller), StakingPool::current_era() + PolkadotBondingDuration::get())
+
+	withdraw_unbonding_receipt {
+		let caller: AccountId = account("caller", 0, SEED);
+		let currency_id = GetStakingCurrencyId::get();
+		set_balance(currency_id, &caller, 1_000 * dollar(currency_id));
+		create_receipt_class()?;
+		Homa::mint(RawOrigin::Signed(caller.clone()).into(), 1_000 * dollar(currency_id))?;
+		new_era();
+		Homa::redeem(RawOrigin::Signed(caller.clone()).into(), dollar(currency_id), RedeemStrategy::WaitForUnbonding)?;
+		new_era();
+		Homa::issue_unbonding_receipt(RawOrigin::Signed(caller.clone()).into(), StakingPool::current_era() + PolkadotBondingDuration::get())?;
+		for era_index in 0..=PolkadotBondingDuration::get() {
+			new_era();
+		}
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+	verify {
+		assert!(<Currencies as MultiCurrency<_>>::total_balance(GetStakingCurrencyId::get(), &caller) > 0);
+	}
 }
 
 #[cfg(test)]
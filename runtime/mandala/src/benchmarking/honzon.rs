@@ -166,6 +166,68 @@ runtime_benchmarks! {
 		)?;
 	}: _(RawOrigin::Signed(receiver), currency_id, sender_lookup)
 
+	transfer_loan_to {
+		let currency_id: CurrencyId = CollateralCurrencyIds::get()[0];
+		let sender: AccountId = whitelisted_caller();
+		let receiver: AccountId = account("receiver", 0, SEED);
+		let receiver_lookup = AccountIdLookup::unlookup(receiver);
+	}: _(RawOrigin::Signed(sender), receiver_lookup, currency_id)
+
+	cancel_loan_transfer {
+		let currency_id: CurrencyId = CollateralCurrencyIds::get()[0];
+		let sender: AccountId = whitelisted_caller();
+		let receiver: AccountId = account("receiver", 0, SEED);
+		let receiver_lookup = AccountIdLookup::unlookup(receiver);
+		Honzon::transfer_loan_to(RawOrigin::Signed(sender.clone()).into(), receiver_lookup, currency_id)?;
+	}: _(RawOrigin::Signed(sender), currency_id)
+
+	accept_loan_transfer {
+		let currency_id: CurrencyId = CollateralCurrencyIds::get()[0];
+		let sender: AccountId = account("sender", 0, SEED);
+		let sender_lookup = AccountIdLookup::unlookup(sender.clone());
+		let receiver: AccountId = whitelisted_caller();
+		let receiver_lookup = AccountIdLookup::unlookup(receiver.clone());
+
+		let debit_value = 100 * dollar(STABLECOIN);
+		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(currency_id);
+		let debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(debit_value);
+		let debit_amount: Amount = debit_amount.unique_saturated_into();
+		let collateral_value = 10 * debit_value;
+		let collateral_amount = Price::saturating_from_rational(dollar(currency_id), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+		// set balance
+		set_balance(currency_id, &sender, collateral_amount + ExistentialDeposits::get(&currency_id));
+
+		// feed price
+		feed_price(vec![(currency_id, Price::one())])?;
+
+		// set risk params
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			currency_id,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(debit_value * 100),
+		)?;
+
+		// initialize sender's loan
+		Honzon::adjust_loan(
+			RawOrigin::Signed(sender.clone()).into(),
+			currency_id,
+			collateral_amount.try_into().unwrap(),
+			debit_amount,
+		)?;
+
+		// offer the loan to receiver
+		Honzon::transfer_loan_to(
+			RawOrigin::Signed(sender.clone()).into(),
+			receiver_lookup,
+			currency_id,
+		)?;
+	}: _(RawOrigin::Signed(receiver), sender_lookup, currency_id)
+
 	close_loan_has_debit_by_dex {
 		let u in 2 .. TradingPathLimit::get() as u32;
 		let currency_id: CurrencyId = CollateralCurrencyIds::get()[0];
@@ -258,6 +320,77 @@ runtime_benchmarks! {
 			debit_amount,
 		)?;
 	}: _(RawOrigin::Signed(sender), currency_id, collateral_amount, Some(path))
+
+	authorize_auto_top_up {
+		let caller: AccountId = whitelisted_caller();
+		let currency_id: CurrencyId = CollateralCurrencyIds::get()[0];
+	}: _(RawOrigin::Signed(caller), currency_id, STABLECOIN, 100 * dollar(STABLECOIN))
+
+	cancel_auto_top_up {
+		let caller: AccountId = whitelisted_caller();
+		let currency_id: CurrencyId = CollateralCurrencyIds::get()[0];
+		Honzon::authorize_auto_top_up(
+			RawOrigin::Signed(caller.clone()).into(),
+			currency_id,
+			STABLECOIN,
+			100 * dollar(STABLECOIN),
+		)?;
+	}: _(RawOrigin::Signed(caller), currency_id)
+
+	top_up_unsafe_position {
+		let currency_id: CurrencyId = CollateralCurrencyIds::get()[0];
+		let owner: AccountId = account("owner", 0, SEED);
+		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		let keeper: AccountId = whitelisted_caller();
+		let debit_value = 100 * dollar(STABLECOIN);
+		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(currency_id);
+		let debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(debit_value);
+		let debit_amount: Amount = debit_amount.unique_saturated_into();
+		let collateral_value = 2 * debit_value;
+		let collateral_amount = Price::saturating_from_rational(dollar(currency_id), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+		// set balance
+		set_balance(currency_id, &owner, 3 * collateral_amount + ExistentialDeposits::get(&currency_id));
+
+		// feed price
+		feed_price(vec![(currency_id, Price::one())])?;
+
+		// set risk params
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			currency_id,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(debit_value * 100),
+		)?;
+
+		// initialize owner's loan and authorize to top up with collateral
+		Honzon::adjust_loan(
+			RawOrigin::Signed(owner.clone()).into(),
+			currency_id,
+			collateral_amount.try_into().unwrap(),
+			debit_amount,
+		)?;
+		Honzon::authorize_auto_top_up(
+			RawOrigin::Signed(owner.clone()).into(),
+			currency_id,
+			currency_id,
+			2 * collateral_amount,
+		)?;
+
+		// raise the required collateral ratio above the collateral ratio of the loan
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			currency_id,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(300, 100))),
+			Change::NoChange,
+		)?;
+	}: _(RawOrigin::Signed(keeper), owner_lookup, currency_id)
 }
 
 #[cfg(test)]
@@ -25,10 +25,11 @@ use super::utils::set_balance;
 use frame_benchmarking::{account, whitelisted_caller};
 use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
-use module_incentives::PoolId;
+use module_incentives::{PoolId, VestingPayoutConfig};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
 use primitives::DexShare;
+use sp_runtime::FixedPointNumber;
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -88,6 +89,24 @@ runtime_benchmarks! {
 		Rewards::accumulate_reward(&pool_id, native_currency_id, 80 * dollar(native_currency_id))?;
 	}: _(RawOrigin::Signed(caller), pool_id)
 
+	claim_rewards_vested {
+		let caller: AccountId = whitelisted_caller();
+		let pool_id = PoolId::Loans(STAKING);
+		let native_currency_id = GetNativeCurrencyId::get();
+
+		Incentives::update_vesting_payout_configs(
+			RawOrigin::Root.into(),
+			vec![(pool_id, Some(VestingPayoutConfig {
+				upfront_rate: Rate::saturating_from_rational(30, 100),
+				period: 10,
+				period_count: 8,
+			}))],
+		)?;
+		Rewards::add_share(&caller, &pool_id, 100);
+		Currencies::deposit(native_currency_id, &Incentives::account_id(), 80 * dollar(native_currency_id))?;
+		Rewards::accumulate_reward(&pool_id, native_currency_id, 80 * dollar(native_currency_id))?;
+	}: _(RawOrigin::Signed(caller), pool_id)
+
 	update_incentive_rewards {
 		let c in 0 .. CollateralCurrencyIds::get().len().saturating_sub(1) as u32;
 		let currency_ids = CollateralCurrencyIds::get();
@@ -128,6 +147,40 @@ runtime_benchmarks! {
 			updates.push((PoolId::Loans(currency_id), Rate::default()));
 		}
 	}: _(RawOrigin::Root, updates)
+
+	update_reward_multipliers {
+		let c in 0 .. CollateralCurrencyIds::get().len().saturating_sub(1) as u32;
+		let currency_ids = CollateralCurrencyIds::get();
+		let mut updates = vec![];
+
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			updates.push((
+				PoolId::Loans(currency_id),
+				Rate::saturating_from_rational(2, 1),
+				Rate::saturating_from_rational(1, 10),
+				30 * AccumulatePeriod::get(),
+			));
+		}
+	}: _(RawOrigin::Root, updates)
+
+	update_vesting_payout_configs {
+		let c in 0 .. CollateralCurrencyIds::get().len().saturating_sub(1) as u32;
+		let currency_ids = CollateralCurrencyIds::get();
+		let mut updates = vec![];
+
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			updates.push((
+				PoolId::Loans(currency_id),
+				Some(VestingPayoutConfig {
+					upfront_rate: Rate::saturating_from_rational(30, 100),
+					period: 10,
+					period_count: 8,
+				}),
+			));
+		}
+	}: _(RawOrigin::Root, updates)
 }
 
 #[cfg(test)]
@@ -0,0 +1,83 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::set_balance;
+use crate::{
+	dollar, AccountId, Balance, Currencies, CurrencyId, GetLiquidCrowdloanCurrencyId, GetLiquidCurrencyId,
+	GetStakingCurrencyId, LiquidCrowdloan, Runtime,
+};
+use frame_benchmarking::account;
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrency;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+const STAKING: CurrencyId = GetStakingCurrencyId::get();
+const LIQUID: CurrencyId = GetLiquidCurrencyId::get();
+const LIQUID_CROWDLOAN: CurrencyId = GetLiquidCrowdloanCurrencyId::get();
+
+fn issue_and_settle(who: &AccountId, amount: Balance) -> Result<(), sp_runtime::DispatchError> {
+	set_balance(STAKING, &LiquidCrowdloan::account_id(), amount);
+	LiquidCrowdloan::issue(RawOrigin::Root.into(), vec![(who.clone(), amount)])?;
+	LiquidCrowdloan::settle(RawOrigin::Root.into())?;
+	Ok(())
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_liquid_crowdloan }
+
+	issue {
+		let c in 1 .. 1000;
+
+		let contributions = (0..c)
+			.map(|i| (account("contributor", i, SEED), 100 * dollar(STAKING)))
+			.collect::<Vec<(AccountId, _)>>();
+	}: _(RawOrigin::Root, contributions)
+
+	settle {
+		let caller: AccountId = account("caller", 0, SEED);
+		set_balance(STAKING, &LiquidCrowdloan::account_id(), 1_000 * dollar(STAKING));
+		LiquidCrowdloan::issue(RawOrigin::Root.into(), vec![(caller, 1_000 * dollar(STAKING))])?;
+	}: _(RawOrigin::Root)
+
+	redeem {
+		let caller: AccountId = account("caller", 0, SEED);
+		issue_and_settle(&caller, 1_000 * dollar(STAKING))?;
+	}: _(RawOrigin::Signed(caller.clone()), 1_000 * dollar(LIQUID_CROWDLOAN))
+	verify {
+		assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(STAKING, &caller), 1_000 * dollar(STAKING));
+	}
+
+	redeem_to_liquid {
+		let caller: AccountId = account("caller", 0, SEED);
+		issue_and_settle(&caller, 1_000 * dollar(STAKING))?;
+	}: _(RawOrigin::Signed(caller.clone()), 1_000 * dollar(LIQUID_CROWDLOAN))
+	verify {
+		assert!(<Currencies as MultiCurrency<_>>::free_balance(LIQUID, &caller) > 0);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -20,25 +20,32 @@
 
 use sp_runtime::traits::AccountIdConversion;
 
+pub mod payments;
 pub mod utils;
 
 // module benchmarking
+pub mod aggregated_dex;
 pub mod auction_manager;
 pub mod cdp_engine;
 pub mod cdp_treasury;
 pub mod collator_selection;
 pub mod currencies;
 pub mod dex;
+pub mod dex_orders;
+pub mod earning;
 pub mod emergency_shutdown;
 pub mod evm;
 pub mod evm_accounts;
+pub mod flash_mint;
 pub mod homa;
 pub mod honzon;
 pub mod incentives;
+pub mod liquid_crowdloan;
 pub mod nominees_election;
 pub mod nutsfinance_stable_asset;
 pub mod prices;
 pub mod session_manager;
+pub mod stable_asset_metapool;
 pub mod transaction_pause;
 pub mod transaction_payment;
 
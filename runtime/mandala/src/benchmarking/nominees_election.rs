@@ -87,6 +87,11 @@ runtime_benchmarks! {
 		NomineesElection::bond(RawOrigin::Signed(caller.clone()).into(), MinCouncilBondThreshold::get())?;
 		NomineesElection::nominate(RawOrigin::Signed(caller.clone()).into(), targets)?;
 	}: _(RawOrigin::Signed(caller))
+
+	report_performance {
+		let c in 1 .. 1000;
+		let reports = (0..c).map(|c| (account("nominatees", c, SEED), 100u32, c % 10 == 0)).collect::<Vec<_>>();
+	}: _(RawOrigin::Root, 1, reports)
 }
 
 #[cfg(test)]
@@ -0,0 +1,97 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::set_balance;
+use crate::{dollar, AccountId, CurrencyId, GetStableCurrencyId, Payments, Runtime, System};
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::{traits::OnIdle, weights::Weight};
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
+
+runtime_benchmarks! {
+	{ Runtime, module_payments }
+
+	approve_payment {
+		let caller: AccountId = whitelisted_caller();
+		let merchant: AccountId = account("merchant", 0, SEED);
+	}: _(RawOrigin::Signed(caller), merchant, STABLECOIN, 10 * dollar(STABLECOIN), 100, 100 * dollar(STABLECOIN))
+
+	cancel_payment {
+		let caller: AccountId = whitelisted_caller();
+		let merchant: AccountId = account("merchant", 0, SEED);
+		Payments::approve_payment(RawOrigin::Signed(caller.clone()).into(), merchant, STABLECOIN, 10 * dollar(STABLECOIN), 100, 100 * dollar(STABLECOIN))?;
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert_eq!(Payments::payments(0), None);
+	}
+
+	execute_payment {
+		let payer: AccountId = account("payer", 0, SEED);
+		let merchant: AccountId = account("merchant", 0, SEED);
+		set_balance(STABLECOIN, &payer, 100 * dollar(STABLECOIN));
+		Payments::approve_payment(RawOrigin::Signed(payer).into(), merchant, STABLECOIN, 10 * dollar(STABLECOIN), 100, 100 * dollar(STABLECOIN))?;
+		let now = System::block_number();
+	}: {
+		Payments::on_idle(now, Weight::max_value());
+	}
+	verify {
+		assert_eq!(Payments::payments(0).unwrap().paid, 10 * dollar(STABLECOIN));
+	}
+
+	create_stream {
+		let caller: AccountId = whitelisted_caller();
+		let recipient: AccountId = account("recipient", 0, SEED);
+		set_balance(STABLECOIN, &caller, 100 * dollar(STABLECOIN));
+	}: _(RawOrigin::Signed(caller), recipient, STABLECOIN, 100 * dollar(STABLECOIN), 100)
+
+	withdraw_from_stream {
+		let sender: AccountId = account("sender", 0, SEED);
+		let caller: AccountId = whitelisted_caller();
+		set_balance(STABLECOIN, &sender, 100 * dollar(STABLECOIN));
+		Payments::create_stream(RawOrigin::Signed(sender).into(), caller.clone(), STABLECOIN, 100 * dollar(STABLECOIN), 100)?;
+		System::set_block_number(System::block_number() + 50);
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert_eq!(Payments::streams(0).unwrap().withdrawn, 50 * dollar(STABLECOIN));
+	}
+
+	cancel_stream {
+		let caller: AccountId = whitelisted_caller();
+		let recipient: AccountId = account("recipient", 0, SEED);
+		set_balance(STABLECOIN, &caller, 100 * dollar(STABLECOIN));
+		Payments::create_stream(RawOrigin::Signed(caller.clone()).into(), recipient, STABLECOIN, 100 * dollar(STABLECOIN), 100)?;
+		System::set_block_number(System::block_number() + 50);
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert_eq!(Payments::streams(0), None);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
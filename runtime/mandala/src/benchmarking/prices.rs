@@ -16,13 +16,17 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CurrencyId, GetStableCurrencyId, Origin, Price, Prices, Runtime};
+use crate::{AccountId, CollateralCurrencyIds, CurrencyId, GetStableCurrencyId, Origin, Price, Prices, Ratio, Runtime};
 
 use super::utils::feed_price;
+use frame_benchmarking::account;
 use frame_system::RawOrigin;
+use module_support::PriceAggregationStrategy;
 use orml_benchmarking::runtime_benchmarks;
-use sp_runtime::traits::One;
-use sp_std::vec;
+use sp_runtime::{traits::One, FixedPointNumber};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
 
 const STAKING: CurrencyId = GetStableCurrencyId::get();
 
@@ -39,6 +43,27 @@ runtime_benchmarks! {
 		feed_price(vec![(STAKING, Price::one())])?;
 		Prices::lock_price(Origin::root(), STAKING)?;
 	}: _(RawOrigin::Root, STAKING)
+
+	reinstate_feeder {
+		let feeder: AccountId = account("feeder", 0, SEED);
+		module_prices::IgnoredFeeders::<Runtime>::insert(&feeder, ());
+	}: _(RawOrigin::Root, feeder)
+
+	set_price_aggregation_strategy {
+		let strategy = PriceAggregationStrategy::MedianWithOutlierRejection { max_deviation: Ratio::saturating_from_rational(5, 100) };
+	}: _(RawOrigin::Root, STAKING, strategy)
+
+	unfreeze_price {
+		module_prices::FrozenPrices::<Runtime>::insert(STAKING, (Price::one(), 1));
+	}: _(RawOrigin::Root, STAKING)
+
+	update_remote_prices {
+		let c in 0 .. CollateralCurrencyIds::get().len() as u32;
+		let prices = CollateralCurrencyIds::get()[0..c as usize]
+			.iter()
+			.map(|currency_id| (*currency_id, Price::one()))
+			.collect::<Vec<_>>();
+	}: _(RawOrigin::Root, prices)
 }
 
 #[cfg(test)]
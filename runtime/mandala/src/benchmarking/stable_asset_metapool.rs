@@ -0,0 +1,99 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::{AccountId, Runtime, StableAsset, StableAssetMetapool};
+
+use super::utils::set_balance_fungibles;
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use primitives::currency::{CurrencyId, AUSD, DOT, LDOT};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+/// Creates a base pool of LDOT and DOT, and a metapool of AUSD and the base
+/// pool token, returns their ids.
+fn create_pools() -> (u32, u32) {
+	let fee_recipient: AccountId = account("fee", 0, SEED);
+	let base_pool_id = StableAsset::pool_count();
+	let _ = StableAsset::create_pool(
+		RawOrigin::Root.into(),
+		CurrencyId::StableAssetPoolToken(base_pool_id),
+		vec![LDOT, DOT],
+		vec![1u128, 1u128],
+		10000000u128,
+		20000000u128,
+		50000000u128,
+		100u128,
+		fee_recipient.clone(),
+	);
+	let pool_id = StableAsset::pool_count();
+	let _ = StableAsset::create_pool(
+		RawOrigin::Root.into(),
+		CurrencyId::StableAssetPoolToken(pool_id),
+		vec![AUSD, CurrencyId::StableAssetPoolToken(base_pool_id)],
+		vec![1u128, 1u128],
+		10000000u128,
+		20000000u128,
+		50000000u128,
+		100u128,
+		fee_recipient,
+	);
+	(base_pool_id, pool_id)
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_stable_asset_metapool }
+
+	register_metapool {
+		let (base_pool_id, pool_id) = create_pools();
+	}: _(RawOrigin::Root, pool_id, base_pool_id, CurrencyId::StableAssetPoolToken(base_pool_id), 1)
+
+	deregister_metapool {
+		let (base_pool_id, pool_id) = create_pools();
+		StableAssetMetapool::register_metapool(RawOrigin::Root.into(), pool_id, base_pool_id, CurrencyId::StableAssetPoolToken(base_pool_id), 1)?;
+	}: _(RawOrigin::Root, pool_id)
+
+	mint {
+		let tester: AccountId = whitelisted_caller();
+		let (base_pool_id, pool_id) = create_pools();
+		StableAssetMetapool::register_metapool(RawOrigin::Root.into(), pool_id, base_pool_id, CurrencyId::StableAssetPoolToken(base_pool_id), 1)?;
+		set_balance_fungibles(LDOT, &tester, 100000000000u128);
+		set_balance_fungibles(DOT, &tester, 100000000000u128);
+		set_balance_fungibles(AUSD, &tester, 100000000000u128);
+	}: _(RawOrigin::Signed(tester), pool_id, vec![20000000000u128, 0u128], vec![10000000000u128, 10000000000u128], 0u128)
+
+	redeem_single {
+		let tester: AccountId = whitelisted_caller();
+		let (base_pool_id, pool_id) = create_pools();
+		StableAssetMetapool::register_metapool(RawOrigin::Root.into(), pool_id, base_pool_id, CurrencyId::StableAssetPoolToken(base_pool_id), 1)?;
+		set_balance_fungibles(LDOT, &tester, 100000000000u128);
+		set_balance_fungibles(DOT, &tester, 100000000000u128);
+		set_balance_fungibles(AUSD, &tester, 100000000000u128);
+		StableAssetMetapool::mint(RawOrigin::Signed(tester.clone()).into(), pool_id, vec![20000000000u128, 0u128], vec![10000000000u128, 10000000000u128], 0u128)?;
+	}: _(RawOrigin::Signed(tester), pool_id, 1000000000u128, 0u32, 0u128)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -20,16 +20,25 @@ use crate::{Origin, Runtime, TransactionPause};
 
 use frame_system::RawOrigin;
 use orml_benchmarking::runtime_benchmarks;
+use primitives::PRECOMPILE_ADDRESS_START;
+use sp_core::H160;
 
 runtime_benchmarks! {
 	{ Runtime, module_transaction_pause }
 
 	pause_transaction {
-	}: _(RawOrigin::Root, b"Balances".to_vec(), b"transfer".to_vec())
+	}: _(RawOrigin::Root, b"Balances".to_vec(), b"transfer".to_vec(), Some(100))
 
 	unpause_transaction {
-		TransactionPause::pause_transaction(Origin::root(), b"Balances".to_vec(), b"transfer".to_vec())?;
+		TransactionPause::pause_transaction(Origin::root(), b"Balances".to_vec(), b"transfer".to_vec(), None)?;
 	}: _(RawOrigin::Root, b"Balances".to_vec(), b"transfer".to_vec())
+
+	pause_evm_precompile {
+	}: _(RawOrigin::Root, H160::from_low_u64_be(PRECOMPILE_ADDRESS_START), Some(100))
+
+	unpause_evm_precompile {
+		TransactionPause::pause_evm_precompile(Origin::root(), H160::from_low_u64_be(PRECOMPILE_ADDRESS_START), None)?;
+	}: _(RawOrigin::Root, H160::from_low_u64_be(PRECOMPILE_ADDRESS_START))
 }
 
 #[cfg(test)]
@@ -16,16 +16,42 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AccountId, CurrencyId, GetNativeCurrencyId, GetStableCurrencyId, Runtime, System, TransactionPayment};
+use crate::{
+	dollar, AccountId, Call, CurrencyId, GetNativeCurrencyId, GetStableCurrencyId, Runtime, System, TransactionPayment,
+};
 use frame_benchmarking::whitelisted_caller;
 use frame_support::traits::OnFinalize;
 use frame_system::RawOrigin;
+use module_transaction_payment::{SponsorApproval, SponsorScope};
 use orml_benchmarking::runtime_benchmarks;
+use sp_core::ecdsa;
+use sp_io::hashing::{blake2_256, keccak_256};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash, IdentifyAccount},
+	MultiSignature, MultiSigner,
+};
 use sp_std::prelude::*;
 
 const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
 const NATIVECOIN: CurrencyId = GetNativeCurrencyId::get();
 
+fn sponsor() -> secp256k1::SecretKey {
+	secp256k1::SecretKey::parse(&keccak_256(b"Sponsor")).unwrap()
+}
+
+fn sponsor_account_id() -> AccountId {
+	let public = secp256k1::PublicKey::from_secret_key(&sponsor()).serialize_compressed();
+	MultiSigner::from(ecdsa::Public::from_raw(public)).into_account()
+}
+
+fn sponsor_sign(msg: &[u8]) -> MultiSignature {
+	let (sig, recovery_id) = secp256k1::sign(&secp256k1::Message::parse(&blake2_256(msg)), &sponsor());
+	let mut r = [0u8; 65];
+	r[0..64].copy_from_slice(&sig.serialize()[..]);
+	r[64] = recovery_id.serialize();
+	ecdsa::Signature::from_raw(r).into()
+}
+
 runtime_benchmarks! {
 	{ Runtime, module_transaction_payment }
 
@@ -40,6 +66,21 @@ runtime_benchmarks! {
 	}: {
 		TransactionPayment::on_finalize(System::block_number());
 	}
+
+	with_fee_paid_by {
+		let caller: AccountId = whitelisted_caller();
+		let call: Call = frame_system::Call::remark(vec![]).into();
+		let approval = SponsorApproval {
+			sponsor: sponsor_account_id(),
+			who: caller.clone(),
+			max_fee: dollar(NATIVECOIN),
+			max_total_fee: dollar(NATIVECOIN),
+			nonce: 0,
+			expiry: System::block_number(),
+			scope: SponsorScope::CallHash(BlakeTwo256::hash_of(&call)),
+		};
+		let sponsor_signature = sponsor_sign(&TransactionPayment::sponsor_approval_payload(&approval));
+	}: _(RawOrigin::Signed(caller), Box::new(call), approval, sponsor_signature)
 }
 
 #[cfg(test)]
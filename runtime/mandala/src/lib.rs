@@ -49,9 +49,10 @@ pub use frame_support::{
 };
 use frame_system::{EnsureRoot, RawOrigin};
 use hex_literal::hex;
+use module_asset_registry::FeePerSecondOf;
 use module_currencies::{BasicCurrencyAdapter, Currency};
 use module_evm::Runner;
-use module_evm::{CallInfo, CreateInfo};
+use module_evm::{CallInfo, ContractStorageStatement, CreateInfo, StateOverride, TraceOutcome, TracerConfig};
 use module_evm_accounts::EvmAddressMapping;
 pub use module_evm_manager::EvmCurrencyIdMapping;
 use module_transaction_payment::{Multiplier, TargetedFeeAdjustment};
@@ -67,7 +68,8 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H160};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdConversion, BadOrigin, BlakeTwo256, Block as BlockT, Convert, SaturatedConversion, StaticLookup,
+		AccountIdConversion, BadOrigin, BlakeTwo256, Block as BlockT, BlockNumberProvider, Convert,
+		SaturatedConversion, StaticLookup, Zero,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, DispatchResult, FixedPointNumber,
@@ -113,7 +115,7 @@ pub use primitives::{
 	ReserveIdentifier, Share, Signature, TokenSymbol, TradingPair,
 };
 pub use runtime_common::{
-	cent, dollar, microcent, millicent, CurveFeeModel, EnsureRootOrAllGeneralCouncil,
+	cent, dollar, microcent, millicent, AggregatedCombineData, CurveFeeModel, EnsureRootOrAllGeneralCouncil,
 	EnsureRootOrAllTechnicalCommittee, EnsureRootOrHalfFinancialCouncil, EnsureRootOrHalfGeneralCouncil,
 	EnsureRootOrHalfHomaCouncil, EnsureRootOrOneGeneralCouncil, EnsureRootOrOneThirdsTechnicalCommittee,
 	EnsureRootOrThreeFourthsGeneralCouncil, EnsureRootOrTwoThirdsGeneralCouncil,
@@ -122,7 +124,7 @@ pub use runtime_common::{
 	HomaCouncilInstance, HomaCouncilMembershipInstance, OffchainSolutionWeightLimit, OperatorMembershipInstanceAcala,
 	OperatorMembershipInstanceBand, Price, ProxyType, Rate, Ratio, RelaychainBlockNumberProvider,
 	RelaychainSubAccountId, RuntimeBlockLength, RuntimeBlockWeights, SystemContractsFilter, TechnicalCommitteeInstance,
-	TechnicalCommitteeMembershipInstance, TimeStampedPrice, ACA, AUSD, DOT, LDOT, RENBTC,
+	TechnicalCommitteeMembershipInstance, TimeStampedPrice, ACA, AUSD, DOT, LCDOT, LDOT, RENBTC,
 };
 
 /// Import the stable_asset pallet.
@@ -169,6 +171,7 @@ parameter_types! {
 	pub const StakingPoolPalletId: PalletId = PalletId(*b"aca/stkp");
 	pub const HonzonTreasuryPalletId: PalletId = PalletId(*b"aca/hztr");
 	pub const HomaTreasuryPalletId: PalletId = PalletId(*b"aca/hmtr");
+	pub const LiquidCrowdloanPalletId: PalletId = PalletId(*b"aca/lcrd");
 	pub const IncentivesPalletId: PalletId = PalletId(*b"aca/inct");
 	pub const CollatorPotId: PalletId = PalletId(*b"aca/cpot");
 	// Treasury reserve
@@ -180,6 +183,8 @@ parameter_types! {
 	// Ecosystem modules
 	pub const StarportPalletId: PalletId = PalletId(*b"aca/stpt");
 	pub const StableAssetPalletId: PalletId = PalletId(*b"nuts/sta");
+	pub const BuybackPalletId: PalletId = PalletId(*b"aca/bybk");
+	pub const StabilityPoolPalletId: PalletId = PalletId(*b"aca/stbp");
 }
 
 pub fn get_all_module_accounts() -> Vec<AccountId> {
@@ -191,6 +196,7 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 		StakingPoolPalletId::get().into_account(),
 		HonzonTreasuryPalletId::get().into_account(),
 		HomaTreasuryPalletId::get().into_account(),
+		LiquidCrowdloanPalletId::get().into_account(),
 		IncentivesPalletId::get().into_account(),
 		TreasuryReservePalletId::get().into_account(),
 		CollatorPotId::get().into_account(),
@@ -198,6 +204,8 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 		ZeroAccountId::get(),
 		UnreleasedNativeVaultAccountId::get(),
 		StableAssetPalletId::get().into_account(),
+		BuybackPalletId::get().into_account(),
+		StabilityPoolPalletId::get().into_account(),
 	]
 }
 
@@ -286,6 +294,8 @@ parameter_types! {
 	pub const MaxInvulnerables: u32 = 50;
 	pub const KickPenaltySessionLength: u32 = 8;
 	pub const CollatorKickThreshold: Permill = Permill::from_percent(50);
+	pub const CollatorKickMissedSessions: u32 = 2;
+	pub const CollatorKickSlash: Permill = Permill::from_percent(10);
 }
 
 impl module_collator_selection::Config for Runtime {
@@ -299,6 +309,8 @@ impl module_collator_selection::Config for Runtime {
 	type MaxInvulnerables = MaxInvulnerables;
 	type KickPenaltySessionLength = KickPenaltySessionLength;
 	type CollatorKickThreshold = CollatorKickThreshold;
+	type CollatorKickMissedSessions = CollatorKickMissedSessions;
+	type CollatorKickSlash = CollatorKickSlash;
 	type WeightInfo = weights::module_collator_selection::WeightInfo<Runtime>;
 }
 
@@ -646,7 +658,8 @@ parameter_types! {
 impl pallet_democracy::Config for Runtime {
 	type Proposal = Call;
 	type Event = Event;
-	type Currency = Balances;
+	/// Votes are weighted by the bond committed in the earning module.
+	type Currency = module_earning::ConvictionVotingCurrency<Runtime, Balances>;
 	type EnactmentPeriod = EnactmentPeriod;
 	type LaunchPeriod = LaunchPeriod;
 	type VotingPeriod = VotingPeriod;
@@ -741,13 +754,13 @@ parameter_types! {
 type AcalaDataProvider = orml_oracle::Instance1;
 impl orml_oracle::Config<AcalaDataProvider> for Runtime {
 	type Event = Event;
-	type OnNewData = ();
-	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider>;
+	type OnNewData = Prices;
+	type CombineData = AggregatedCombineData<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider, Prices>;
 	type Time = Timestamp;
 	type OracleKey = CurrencyId;
 	type OracleValue = Price;
 	type RootOperatorAccountId = ZeroAccountId;
-	type Members = OperatorMembershipAcala;
+	type Members = module_prices::ActiveOracleOperators<Runtime>;
 	type MaxHasDispatchedSize = MaxHasDispatchedSize;
 	type WeightInfo = weights::orml_oracle::WeightInfo<Runtime>;
 }
@@ -756,7 +769,7 @@ type BandDataProvider = orml_oracle::Instance2;
 impl orml_oracle::Config<BandDataProvider> for Runtime {
 	type Event = Event;
 	type OnNewData = ();
-	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, BandDataProvider>;
+	type CombineData = AggregatedCombineData<Runtime, MinimumCount, ExpiresIn, BandDataProvider, Prices>;
 	type Time = Timestamp;
 	type OracleKey = CurrencyId;
 	type OracleValue = Price;
@@ -844,6 +857,12 @@ impl orml_tokens::Config for Runtime {
 
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
+	pub const FeedWindow: BlockNumber = 10 * MINUTES;
+	pub const MaxMissedFeedWindows: u32 = 6;
+	pub MaxPriceChangeRatio: Ratio = Ratio::saturating_from_rational(30, 100);
+	pub const CircuitBreakerWindow: BlockNumber = HOURS;
+	pub const CircuitBreakerCooldown: BlockNumber = 6 * HOURS;
+	pub const RemotePriceStaleness: BlockNumber = HOURS;
 }
 
 impl module_prices::Config for Runtime {
@@ -858,6 +877,14 @@ impl module_prices::Config for Runtime {
 	type DEX = Dex;
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
+	type OracleOperators = OperatorMembershipAcala;
+	type FeedWindow = FeedWindow;
+	type MaxMissedFeedWindows = MaxMissedFeedWindows;
+	type MaxPriceChangeRatio = MaxPriceChangeRatio;
+	type CircuitBreakerWindow = CircuitBreakerWindow;
+	type CircuitBreakerCooldown = CircuitBreakerCooldown;
+	type RemoteOracleOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type RemotePriceStaleness = RemotePriceStaleness;
 	type WeightInfo = weights::module_prices::WeightInfo<Runtime>;
 }
 
@@ -893,7 +920,10 @@ impl EnsureOrigin<Origin> for EnsureRootOrTreasury {
 		Into::<Result<RawOrigin<AccountId>, Origin>>::into(o).and_then(|o| match o {
 			RawOrigin::Root => Ok(TreasuryPalletId::get().into_account()),
 			RawOrigin::Signed(caller) => {
-				if caller == TreasuryPalletId::get().into_account() {
+				// incentives pays the vested rewards
+				if caller == TreasuryPalletId::get().into_account()
+					|| caller == IncentivesPalletId::get().into_account()
+				{
 					Ok(caller)
 				} else {
 					Err(Origin::from(Some(caller)))
@@ -964,6 +994,8 @@ parameter_types! {
 	pub DefaultSwapParitalPathList: Vec<Vec<CurrencyId>> = vec![
 		vec![GetStableCurrencyId::get()],
 	];
+	pub DutchAuctionStartingPremium: Rate = Rate::saturating_from_rational(10, 100);
+	pub DutchAuctionPriceDecay: Rate = Rate::saturating_from_rational(1, 1000);
 }
 
 impl module_auction_manager::Config for Runtime {
@@ -977,9 +1009,14 @@ impl module_auction_manager::Config for Runtime {
 	type CDPTreasury = CdpTreasury;
 	type DEX = Dex;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type PriceCircuitBreaker = Prices;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type CollateralAuctionTypes = module_cdp_engine::CollateralAuctionTypeOf<Runtime>;
+	type DutchAuctionStartingPremium = DutchAuctionStartingPremium;
+	type DutchAuctionPriceDecay = DutchAuctionPriceDecay;
+	type RiskParameters = Parameters;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -1059,11 +1096,14 @@ parameter_types! {
 	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(5, 100);
 	pub MinimumDebitValue: Balance = dollar(AUSD);
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(15, 100);
+	pub PartialLiquidationTarget: Option<Ratio> = None;
+	pub KeeperIncentiveRate: Rate = Rate::saturating_from_rational(1, 10);
 }
 
 impl module_cdp_engine::Config for Runtime {
 	type Event = Event;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type PriceCircuitBreaker = Prices;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -1071,8 +1111,12 @@ impl module_cdp_engine::Config for Runtime {
 	type MinimumDebitValue = MinimumDebitValue;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CdpTreasury;
+	type StabilityPool = StabilityPool;
+	type RiskParameters = Parameters;
 	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
+	type PartialLiquidationTarget = PartialLiquidationTarget;
+	type KeeperIncentiveRate = KeeperIncentiveRate;
 	type UnsignedPriority = runtime_common::CdpEngineUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
 	type UnixTime = Timestamp;
@@ -1082,12 +1126,14 @@ impl module_cdp_engine::Config for Runtime {
 
 parameter_types! {
 	pub DepositPerAuthorization: Balance = dollar(ACA);
+	pub TopUpRewardRate: Rate = Rate::saturating_from_rational(1, 100);
 }
 
 impl module_honzon::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
 	type DepositPerAuthorization = DepositPerAuthorization;
+	type TopUpRewardRate = TopUpRewardRate;
 	type WeightInfo = weights::module_honzon::WeightInfo<Runtime>;
 }
 
@@ -1095,15 +1141,27 @@ impl module_emergency_shutdown::Config for Runtime {
 	type Event = Event;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type PriceSource = Prices;
+	type LockedPriceSource = module_prices::LockedPriceProvider<Runtime>;
 	type CDPTreasury = CdpTreasury;
 	type AuctionManagerHandler = AuctionManager;
 	type ShutdownOrigin = EnsureRootOrHalfGeneralCouncil;
 	type WeightInfo = weights::module_emergency_shutdown::WeightInfo<Runtime>;
 }
 
+impl module_flash_mint::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type CDPTreasury = CdpTreasury;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type WeightInfo = weights::module_flash_mint::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 1000);	// 0.1%
 	pub const TradingPathLimit: u32 = 3;
+	pub RoutingCurrencies: Vec<CurrencyId> = vec![AUSD, DOT];
+	pub const TWAPObservationPeriod: BlockNumber = 10 * MINUTES;
+	pub const MaxTWAPObservations: u32 = 144;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, ACA).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
@@ -1117,6 +1175,9 @@ impl module_dex::Config for Runtime {
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
 	type TradingPathLimit = TradingPathLimit;
+	type RoutingCurrencies = RoutingCurrencies;
+	type TWAPObservationPeriod = TWAPObservationPeriod;
+	type MaxTWAPObservations = MaxTWAPObservations;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
 	type DEXIncentives = Incentives;
@@ -1124,6 +1185,18 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
 }
 
+parameter_types! {
+	pub DexOrdersMakerFee: Rate = Rate::saturating_from_rational(1, 1000);	// 0.1%
+}
+
+impl module_dex_orders::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type DEX = Dex;
+	type MakerFee = DexOrdersMakerFee;
+	type WeightInfo = weights::module_dex_orders::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const MaxAuctionsCount: u32 = 100;
 	pub HonzonTreasuryAccount: AccountId = HonzonTreasuryPalletId::get().into_account();
@@ -1133,15 +1206,93 @@ impl module_cdp_treasury::Config for Runtime {
 	type Event = Event;
 	type Currency = Currencies;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type AuctionManagerHandler = AuctionManager;
 	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
 	type DEX = Dex;
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = HonzonTreasuryAccount;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_cdp_treasury::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const BuybackPeriod: BlockNumber = HOURS;
+}
+
+parameter_types! {
+	pub StabilityPoolCollateralDiscount: Ratio = Ratio::saturating_from_rational(5, 100);
+}
+
+impl module_stability_pool::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type CDPTreasury = CdpTreasury;
+	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type CollateralDiscount = StabilityPoolCollateralDiscount;
+	type PalletId = StabilityPoolPalletId;
+	type WeightInfo = weights::module_stability_pool::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const RiskParameterEnactmentDelay: BlockNumber = 2 * DAYS;
+}
+
+impl module_parameters::Config for Runtime {
+	type Event = Event;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type EnactmentDelay = RiskParameterEnactmentDelay;
+	type WeightInfo = weights::module_parameters::WeightInfo<Runtime>;
+}
+
+impl module_buyback::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type CDPTreasury = CdpTreasury;
+	type DEX = Dex;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type BuybackPeriod = BuybackPeriod;
+	type IncentivesAccount = UnreleasedNativeVaultAccountId;
+	type PalletId = BuybackPalletId;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type WeightInfo = weights::module_buyback::WeightInfo<Runtime>;
+}
+
+/// The background tasks dispatched by `IdleScheduler`.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub enum ScheduledTasks {
+	CdpTreasury(module_cdp_treasury::DisposalTask),
+}
+
+impl From<module_cdp_treasury::DisposalTask> for ScheduledTasks {
+	fn from(task: module_cdp_treasury::DisposalTask) -> Self {
+		ScheduledTasks::CdpTreasury(task)
+	}
+}
+
+impl module_support::DispatchableTask for ScheduledTasks {
+	fn dispatch(self, weight: Weight) -> module_support::TaskResult {
+		match self {
+			ScheduledTasks::CdpTreasury(task) => CdpTreasury::dispatch_disposal(task, weight),
+		}
+	}
+}
+
+parameter_types! {
+	pub MinimumWeightRemainInBlock: Weight = RuntimeBlockWeights::get().max_block / 50;
+}
+
+impl module_idle_scheduler::Config for Runtime {
+	type Event = Event;
+	type Task = ScheduledTasks;
+	type MinimumWeightRemainInBlock = MinimumWeightRemainInBlock;
+	type UpdateOrigin = EnsureRootOrThreeFourthsGeneralCouncil;
+	type WeightInfo = weights::module_idle_scheduler::WeightInfo<Runtime>;
+}
+
 impl module_transaction_pause::Config for Runtime {
 	type Event = Event;
 	type UpdateOrigin = EnsureRootOrThreeFourthsGeneralCouncil;
@@ -1174,6 +1325,29 @@ impl OnUnbalanced<NegativeImbalance> for DealWithFees {
 	}
 }
 
+/// Pay the fee of `swap_with_exact_target_and_fee_in_supply` with the supply
+/// currency of the trading path.
+pub struct DexCallFeeSwapPath;
+impl module_support::CallFeeSwapPath<Call, CurrencyId> for DexCallFeeSwapPath {
+	fn fee_swap_path_list(call: &Call) -> Vec<Vec<CurrencyId>> {
+		let native_currency_id = GetNativeCurrencyId::get();
+		let stable_currency_id = GetStableCurrencyId::get();
+		match call {
+			Call::Dex(module_dex::Call::swap_with_exact_target_and_fee_in_supply(path, ..)) => match path.first() {
+				Some(&supply_currency_id) if supply_currency_id == stable_currency_id => {
+					vec![vec![supply_currency_id, native_currency_id]]
+				}
+				Some(&supply_currency_id) if supply_currency_id != native_currency_id => vec![
+					vec![supply_currency_id, native_currency_id],
+					vec![supply_currency_id, stable_currency_id, native_currency_id],
+				],
+				_ => vec![],
+			},
+			_ => vec![],
+		}
+	}
+}
+
 impl module_transaction_payment::Config for Runtime {
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type DefaultFeeSwapPathList = DefaultFeeSwapPathList;
@@ -1187,6 +1361,10 @@ impl module_transaction_payment::Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type CallFeeSwapPath = DexCallFeeSwapPath;
+	type Call = Call;
+	type SponsorSignature = Signature;
+	type SponsorSigner = <Signature as sp_runtime::traits::Verify>::Signer;
 	type WeightInfo = weights::module_transaction_payment::WeightInfo<Runtime>;
 }
 
@@ -1198,9 +1376,17 @@ impl module_evm_accounts::Config for Runtime {
 	type WeightInfo = weights::module_evm_accounts::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub Erc20RegistrationDeposit: Balance = 100 * dollar(ACA);
+}
+
 impl module_evm_manager::Config for Runtime {
+	type Event = Event;
 	type Currency = Balances;
 	type EVMBridge = EVMBridge;
+	type Erc20RegistrationDeposit = Erc20RegistrationDeposit;
+	type VetoOrigin = EnsureRootOrTwoThirdsTechnicalCommittee;
+	type WeightInfo = weights::module_evm_manager::WeightInfo<Runtime>;
 }
 
 impl orml_rewards::Config for Runtime {
@@ -1213,6 +1399,29 @@ impl orml_rewards::Config for Runtime {
 
 parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = MINUTES;
+	pub const LoyaltyPeriod: BlockNumber = 7 * DAYS;
+}
+
+pub struct IncentivesVestedTransfer;
+impl module_support::VestedTransfer<AccountId, BlockNumber, Balance> for IncentivesVestedTransfer {
+	fn vested_transfer(
+		from: &AccountId,
+		to: &AccountId,
+		period: BlockNumber,
+		period_count: u32,
+		per_period: Balance,
+	) -> DispatchResult {
+		Vesting::vested_transfer(
+			RawOrigin::Signed(from.clone()).into(),
+			<Runtime as frame_system::Config>::Lookup::unlookup(to.clone()),
+			orml_vesting::VestingSchedule {
+				start: RelaychainBlockNumberProvider::<Runtime>::current_block_number(),
+				period,
+				period_count,
+				per_period,
+			},
+		)
+	}
 }
 
 impl module_incentives::Config for Runtime {
@@ -1220,11 +1429,14 @@ impl module_incentives::Config for Runtime {
 	type RewardsSource = UnreleasedNativeVaultAccountId;
 	type StableCurrencyId = GetStableCurrencyId;
 	type AccumulatePeriod = AccumulatePeriod;
+	type LoyaltyPeriod = LoyaltyPeriod;
+	type NativeCurrencyId = GetNativeCurrencyId;
 	type UpdateOrigin = EnsureRootOrThreeFourthsGeneralCouncil;
 	type CDPTreasury = CdpTreasury;
 	type Currency = Currencies;
 	type DEX = Dex;
 	type EmergencyShutdown = EmergencyShutdown;
+	type VestedTransfer = IncentivesVestedTransfer;
 	type PalletId = IncentivesPalletId;
 	type WeightInfo = weights::module_incentives::WeightInfo<Runtime>;
 }
@@ -1233,6 +1445,40 @@ impl module_airdrop::Config for Runtime {
 	type Event = Event;
 }
 
+parameter_types! {
+	pub MinEarningBond: Balance = dollar(ACA);
+	pub const EarningUnbondingPeriod: BlockNumber = 28 * DAYS;
+	pub const InstantUnbondFee: Permill = Permill::from_percent(10);
+	pub const MaxUnbondingChunks: u32 = 10;
+	pub const EarningConvictionPeriod: BlockNumber = 28 * DAYS;
+	pub const EarningMaxConviction: u32 = 6;
+}
+
+impl module_earning::Config for Runtime {
+	type Event = Event;
+	type Currency = BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
+	type MinBond = MinEarningBond;
+	type UnbondingPeriod = EarningUnbondingPeriod;
+	type InstantUnbondFee = InstantUnbondFee;
+	type MaxUnbondingChunks = MaxUnbondingChunks;
+	type ConvictionPeriod = EarningConvictionPeriod;
+	type MaxConviction = EarningMaxConviction;
+	type OnBonded = ();
+	type OnUnbonded = ();
+	type WeightInfo = weights::module_earning::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const PaymentGracePeriod: BlockNumber = 3 * DAYS;
+}
+
+impl module_payments::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type GracePeriod = PaymentGracePeriod;
+	type WeightInfo = weights::module_payments::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const PolkadotBondingDuration: EraIndex = 7;
 	pub const EraLength: BlockNumber = DAYS;
@@ -1253,6 +1499,7 @@ parameter_types! {
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
 	pub DefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(10, 100);	// 1 : 10
 	pub PoolAccountIndexes: Vec<u32> = vec![1, 2, 3, 4];
+	pub const UnbondingReceiptClassId: u32 = 0;
 }
 
 impl module_staking_pool::Config for Runtime {
@@ -1267,6 +1514,8 @@ impl module_staking_pool::Config for Runtime {
 	type Nominees = NomineesElection;
 	type Bridge = PolkadotBridge;
 	type Currency = Currencies;
+	type UnbondingReceipt = NFT;
+	type UnbondingReceiptClassId = UnbondingReceiptClassId;
 }
 
 impl module_homa::Config for Runtime {
@@ -1274,6 +1523,21 @@ impl module_homa::Config for Runtime {
 	type WeightInfo = weights::module_homa::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const GetLiquidCrowdloanCurrencyId: CurrencyId = LCDOT;
+}
+
+impl module_liquid_crowdloan::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type RelayChainCurrencyId = GetStakingCurrencyId;
+	type LiquidCrowdloanCurrencyId = GetLiquidCrowdloanCurrencyId;
+	type Homa = StakingPool;
+	type GovernanceOrigin = EnsureRootOrHalfHomaCouncil;
+	type PalletId = LiquidCrowdloanPalletId;
+	type WeightInfo = weights::module_liquid_crowdloan::WeightInfo<Runtime>;
+}
+
 pub fn create_x2_parachain_multilocation(index: u16) -> MultiLocation {
 	MultiLocation::X2(
 		Junction::Parent,
@@ -1289,6 +1553,10 @@ parameter_types! {
 	pub RelaychainSovereignSubAccount: MultiLocation = create_x2_parachain_multilocation(RelaychainSubAccountId::HomaLite as u16);
 	pub MaxRewardPerEra: Permill = Permill::from_rational(500u32, 1_000_000u32); // 1.2 ^ (1/365) = 1.0004996359
 	pub MintFee: Balance = 20 * millicent(DOT); // 2x XCM fee on Kusama
+	pub const FastRedeemFeeShareToMinter: Permill = Permill::from_percent(80);
+	pub const MaximumRedeemRequestMatchesForMint: u32 = 20;
+	pub const RelaychainEraLength: BlockNumber = 6 * HOURS;
+	pub EstimatedRewardRatePerEra: Permill = Permill::from_rational(96u32, 1_000_000u32); // 1.15 ^ (1/1460) = 1.0000957307
 }
 impl module_homa_lite::Config for Runtime {
 	type Event = Event;
@@ -1303,6 +1571,12 @@ impl module_homa_lite::Config for Runtime {
 	type DefaultExchangeRate = DefaultExchangeRate;
 	type MaxRewardPerEra = MaxRewardPerEra;
 	type MintFee = MintFee;
+	type TreasuryAccount = TreasuryAccount;
+	type FastRedeemFeeShareToMinter = FastRedeemFeeShareToMinter;
+	type MaximumRedeemRequestMatchesForMint = MaximumRedeemRequestMatchesForMint;
+	type RewardsClaimer = ();
+	type EraLength = RelaychainEraLength;
+	type EstimatedRewardRatePerEra = EstimatedRewardRatePerEra;
 }
 
 parameter_types! {
@@ -1310,6 +1584,7 @@ parameter_types! {
 	pub const NominateesCount: u32 = 7;
 	pub const MaxUnlockingChunks: u32 = 7;
 	pub const NomineesElectionBondingDuration: EraIndex = 7;
+	pub const SlashedNomineeCooldown: EraIndex = 28;
 }
 
 impl module_nominees_election::Config for Runtime {
@@ -1322,6 +1597,8 @@ impl module_nominees_election::Config for Runtime {
 	type NominateesCount = NominateesCount;
 	type MaxUnlockingChunks = MaxUnlockingChunks;
 	type NomineeFilter = runtime_common::DummyNomineeFilter;
+	type PerformanceOrigin = EnsureRootOrHalfHomaCouncil;
+	type SlashedNomineeCooldown = SlashedNomineeCooldown;
 	type WeightInfo = weights::module_nominees_election::WeightInfo<Runtime>;
 }
 
@@ -1351,6 +1628,7 @@ parameter_types! {
 	pub CreateClassDeposit: Balance = 20 * dollar(ACA);
 	pub CreateTokenDeposit: Balance = 2 * dollar(ACA);
 	pub MaxAttributesBytes: u32 = 2048;
+	pub const MaxNftBatchItems: u32 = 100;
 }
 
 impl module_nft::Config for Runtime {
@@ -1361,6 +1639,8 @@ impl module_nft::Config for Runtime {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = MaxAttributesBytes;
+	type MaxBatchItems = MaxNftBatchItems;
+	type MultiCurrency = Currencies;
 	type WeightInfo = weights::module_nft::WeightInfo<Runtime>;
 }
 
@@ -1424,6 +1704,7 @@ impl InstanceFilter<Call> for ProxyType {
 				matches!(
 					c,
 					Call::Honzon(module_honzon::Call::adjust_loan(..))
+						| Call::Honzon(module_honzon::Call::adjust_loans_batch(..))
 						| Call::Honzon(module_honzon::Call::close_loan_has_debit_by_dex(..))
 				)
 			}
@@ -1492,6 +1773,7 @@ impl ecosystem_compound_cash::Config for Runtime {
 parameter_types! {
 	pub const ChainId: u64 = 595;
 	pub NetworkContractSource: H160 = H160::from_low_u64_be(0);
+	pub const BaseFeeHistoryLength: u32 = 1024;
 }
 
 #[cfg(feature = "with-ethereum-compatibility")]
@@ -1501,6 +1783,7 @@ parameter_types! {
 	pub const StorageDepositPerByte: Balance = 0;
 	pub const DeveloperDeposit: Balance = 0;
 	pub const DeploymentFee: Balance = 0;
+	pub const PublicationFee: Balance = 0;
 }
 
 #[cfg(not(feature = "with-ethereum-compatibility"))]
@@ -1510,6 +1793,7 @@ parameter_types! {
 	pub StorageDepositPerByte: Balance = deposit(0, 1);
 	pub DeveloperDeposit: Balance = dollar(ACA);
 	pub DeploymentFee: Balance = dollar(ACA);
+	pub PublicationFee: Balance = dollar(ACA);
 }
 
 pub type MultiCurrencyPrecompile = runtime_common::MultiCurrencyPrecompile<
@@ -1542,6 +1826,64 @@ pub type ScheduleCallPrecompile = runtime_common::ScheduleCallPrecompile<
 >;
 pub type DexPrecompile =
 	runtime_common::DexPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Dex>;
+pub type HomaPrecompile =
+	runtime_common::HomaPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, StakingPool>;
+pub type HonzonPrecompile =
+	runtime_common::HonzonPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Honzon>;
+pub type IncentivesPrecompile = runtime_common::IncentivesPrecompile<
+	AccountId,
+	EvmAddressMapping<Runtime>,
+	EvmCurrencyIdMapping<Runtime>,
+	Incentives,
+>;
+
+/// XCM transfers from EVM, which are filtered by `BaseCallFilter` as the
+/// corresponding `XTokens` calls.
+pub struct EvmXcmTransfer;
+impl orml_traits::XcmTransfer<AccountId, Balance, CurrencyId> for EvmXcmTransfer {
+	fn transfer(
+		who: AccountId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		dest: MultiLocation,
+		dest_weight: Weight,
+	) -> DispatchResult {
+		let call = Call::XTokens(orml_xtokens::Call::transfer(
+			currency_id,
+			amount,
+			Box::new(dest.clone()),
+			dest_weight,
+		));
+		if !BaseCallFilter::contains(&call) {
+			return Err(sp_runtime::DispatchError::Other("CallFiltered"));
+		}
+		<XTokens as orml_traits::XcmTransfer<_, _, _>>::transfer(who, currency_id, amount, dest, dest_weight)
+	}
+
+	fn transfer_multi_asset(
+		who: AccountId,
+		asset: MultiAsset,
+		dest: MultiLocation,
+		dest_weight: Weight,
+	) -> DispatchResult {
+		let call = Call::XTokens(orml_xtokens::Call::transfer_multiasset(
+			Box::new(asset.clone()),
+			Box::new(dest.clone()),
+			dest_weight,
+		));
+		if !BaseCallFilter::contains(&call) {
+			return Err(sp_runtime::DispatchError::Other("CallFiltered"));
+		}
+		<XTokens as orml_traits::XcmTransfer<_, _, _>>::transfer_multi_asset(who, asset, dest, dest_weight)
+	}
+}
+
+pub type XtokensPrecompile = runtime_common::XtokensPrecompile<
+	AccountId,
+	EvmAddressMapping<Runtime>,
+	EvmCurrencyIdMapping<Runtime>,
+	EvmXcmTransfer,
+>;
 
 #[cfg(feature = "with-ethereum-compatibility")]
 static ISTANBUL_CONFIG: evm::Config = evm::Config::istanbul();
@@ -1555,12 +1897,17 @@ impl module_evm::Config for Runtime {
 	type Event = Event;
 	type Precompiles = runtime_common::AllPrecompiles<
 		SystemContractsFilter,
+		module_transaction_pause::PausedPrecompileFilter<Runtime>,
 		MultiCurrencyPrecompile,
 		NFTPrecompile,
 		StateRentPrecompile,
 		OraclePrecompile,
 		ScheduleCallPrecompile,
 		DexPrecompile,
+		HomaPrecompile,
+		HonzonPrecompile,
+		IncentivesPrecompile,
+		XtokensPrecompile,
 	>;
 	type ChainId = ChainId;
 	type GasToWeight = GasToWeight;
@@ -1569,10 +1916,12 @@ impl module_evm::Config for Runtime {
 	type NetworkContractSource = NetworkContractSource;
 	type DeveloperDeposit = DeveloperDeposit;
 	type DeploymentFee = DeploymentFee;
+	type PublicationFee = PublicationFee;
 	type TreasuryAccount = TreasuryAccount;
 	type FreeDeploymentOrigin = EnsureRootOrHalfGeneralCouncil;
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
+	type BaseFeeHistoryLength = BaseFeeHistoryLength;
 	type WeightInfo = weights::module_evm::WeightInfo<Runtime>;
 
 	#[cfg(feature = "with-ethereum-compatibility")]
@@ -1679,6 +2028,10 @@ parameter_types! {
 
 pub type Barrier = (TakeWeightCredit, AllowTopLevelPaidExecutionFrom<Everything>);
 
+/// Builds the Polkadot relay chain calls for Homa and liquid crowdloan.
+pub type RelayChainCallBuilder =
+	module_relaychain_interface::CallBuilder<module_relaychain_interface::Polkadot, ParachainInfo>;
+
 pub struct ToTreasury;
 impl TakeRevenue for ToTreasury {
 	fn take_revenue(revenue: MultiAsset) {
@@ -1706,7 +2059,7 @@ impl xcm_executor::Config for XcmConfig {
 	type Barrier = Barrier;
 	type Weigher = FixedWeightBounds<UnitWeightCost, Call>;
 	// Only receiving DOT is handled, and all fees must be paid in DOT.
-	type Trader = FixedRateOfConcreteFungible<DotPerSecond, ToTreasury>;
+	type Trader = FixedRateOfConcreteFungible<FeePerSecondOf<Runtime, DotPerSecond>, ToTreasury>;
 	type ResponseHandler = (); // Don't handle responses for now.
 }
 
@@ -1746,7 +2099,7 @@ impl cumulus_pallet_xcm::Config for Runtime {
 
 impl cumulus_pallet_xcmp_queue::Config for Runtime {
 	type Event = Event;
-	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmExecutor = module_xcm_channel::MeteredXcmExecutor<Runtime, XcmExecutor<XcmConfig>>;
 	type ChannelInfo = ParachainSystem;
 }
 
@@ -1845,6 +2198,16 @@ impl orml_xtokens::Config for Runtime {
 	type BaseXcmWeight = BaseXcmWeight;
 }
 
+impl module_xcm_transfer::Config for Runtime {
+	type Event = Event;
+	type CurrencyIdConvert = CurrencyIdConvert;
+	type AccountIdToMultiLocation = AccountIdToMultiLocation;
+	type SelfLocation = SelfLocation;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type Weigher = FixedWeightBounds<UnitWeightCost, Call>;
+	type BaseXcmWeight = BaseXcmWeight;
+}
+
 impl orml_unknown_tokens::Config for Runtime {
 	type Event = Event;
 }
@@ -1854,6 +2217,18 @@ impl orml_xcm::Config for Runtime {
 	type SovereignOrigin = EnsureRootOrHalfGeneralCouncil;
 }
 
+impl module_asset_registry::Config for Runtime {
+	type Event = Event;
+	type RegisterOrigin = EnsureRootOrHalfGeneralCouncil;
+	type WeightInfo = weights::module_asset_registry::WeightInfo<Runtime>;
+}
+
+impl module_xcm_channel::Config for Runtime {
+	type Event = Event;
+	type UpdateOrigin = EnsureRootOrTwoThirdsTechnicalCommittee;
+	type WeightInfo = weights::module_xcm_channel::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const Precision: u128 = 1000000000000000000u128; // 18 decimals
 	pub const FeePrecision: u128 = 10000000000u128; // 10 decimals
@@ -1881,6 +2256,71 @@ impl nutsfinance_stable_asset::Config for Runtime {
 	type EnsurePoolAssetId = EnsurePoolAssetId;
 }
 
+pub struct StableAssetAdapter;
+impl module_support::StableAssetManager<AccountId, CurrencyId, Balance, nutsfinance_stable_asset::PoolId>
+	for StableAssetAdapter
+{
+	fn pool_asset(pool_id: nutsfinance_stable_asset::PoolId, i: u32) -> Option<CurrencyId> {
+		StableAsset::pools(pool_id).and_then(|pool_info| pool_info.assets.get(i as usize).copied())
+	}
+
+	fn get_swap_output_amount(
+		pool_id: nutsfinance_stable_asset::PoolId,
+		i: u32,
+		j: u32,
+		dx: Balance,
+	) -> Option<Balance> {
+		StableAsset::get_swap_output_amount(pool_id, i, j, dx).map(|swap_result| swap_result.dy)
+	}
+
+	fn swap(
+		who: &AccountId,
+		pool_id: nutsfinance_stable_asset::PoolId,
+		i: u32,
+		j: u32,
+		dx: Balance,
+		min_dy: Balance,
+	) -> DispatchResult {
+		StableAsset::swap(Origin::signed(who.clone()), pool_id, i, j, dx, min_dy)
+	}
+
+	fn mint(
+		who: &AccountId,
+		pool_id: nutsfinance_stable_asset::PoolId,
+		amounts: Vec<Balance>,
+		min_mint_amount: Balance,
+	) -> DispatchResult {
+		StableAsset::mint(Origin::signed(who.clone()), pool_id, amounts, min_mint_amount)
+	}
+
+	fn redeem_single(
+		who: &AccountId,
+		pool_id: nutsfinance_stable_asset::PoolId,
+		amount: Balance,
+		i: u32,
+		min_redeem_amount: Balance,
+	) -> DispatchResult {
+		StableAsset::redeem_single(Origin::signed(who.clone()), pool_id, amount, i, min_redeem_amount)
+	}
+}
+
+impl module_stable_asset_metapool::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type StableAsset = StableAssetAdapter;
+	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
+	type WeightInfo = weights::module_stable_asset_metapool::WeightInfo<Runtime>;
+}
+
+impl module_aggregated_dex::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type DEX = Dex;
+	type StableAsset = StableAssetAdapter;
+	type GovernanceOrigin = EnsureRootOrHalfGeneralCouncil;
+	type WeightInfo = weights::module_aggregated_dex::WeightInfo<Runtime>;
+}
+
 impl cumulus_pallet_aura_ext::Config for Runtime {}
 
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
@@ -1921,6 +2361,7 @@ impl Convert<(Call, SignedExtra), Result<EthereumTransactionMessage, InvalidTran
 					chain_id: ChainId::get(),
 					genesis: System::block_hash(0),
 					valid_until,
+					base_fee_per_gas: EVM::base_fee_per_gas(),
 				})
 			}
 			_ => Err(InvalidTransaction::BadProof),
@@ -1954,8 +2395,56 @@ pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, Call, SignedExtra>;
 /// Executive: handles dispatch to the various modules.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPallets, ()>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPallets,
+	OnRuntimeUpgrade,
+>;
+
+pub struct OnRuntimeUpgrade;
+impl frame_support::traits::OnRuntimeUpgrade for OnRuntimeUpgrade {
+	fn on_runtime_upgrade() -> u64 {
+		module_cdp_engine::migrations::v1::migrate::<Runtime>()
+			.saturating_add(module_evm_accounts::migrations::v1::migrate::<Runtime>())
+			.saturating_add(module_transaction_pause::migrations::v1::migrate::<Runtime>())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+		Self::set_temp_storage(
+			module_cdp_engine::migrations::v1::pre_migrate::<Runtime>()?,
+			"cdp_engine_v1",
+		);
+		Self::set_temp_storage(
+			module_evm_accounts::migrations::v1::pre_migrate::<Runtime>()?,
+			"evm_accounts_v1",
+		);
+		Self::set_temp_storage(
+			module_transaction_pause::migrations::v1::pre_migrate::<Runtime>()?,
+			"transaction_pause_v1",
+		);
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+		module_cdp_engine::migrations::v1::post_migrate::<Runtime>(
+			Self::get_temp_storage("cdp_engine_v1").ok_or("cdp_engine_v1 count is missing")?,
+		)?;
+		module_evm_accounts::migrations::v1::post_migrate::<Runtime>(
+			Self::get_temp_storage("evm_accounts_v1").ok_or("evm_accounts_v1 count is missing")?,
+		)?;
+		module_transaction_pause::migrations::v1::post_migrate::<Runtime>(
+			Self::get_temp_storage("transaction_pause_v1").ok_or("transaction_pause_v1 count is missing")?,
+		)?;
+		Ok(())
+	}
+}
 
 #[allow(clippy::large_enum_variant)]
 construct_runtime! {
@@ -1969,6 +2458,7 @@ construct_runtime! {
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent} = 1,
 		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>} = 2,
 		TransactionPause: module_transaction_pause::{Pallet, Call, Storage, Event<T>} = 3,
+		IdleScheduler: module_idle_scheduler::{Pallet, Call, Storage, Event<T>} = 4,
 
 		// Tokens & Related
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>} = 10,
@@ -2021,6 +2511,8 @@ construct_runtime! {
 		// Acala Core
 		Prices: module_prices::{Pallet, Storage, Call, Event<T>} = 110,
 		Dex: module_dex::{Pallet, Storage, Call, Event<T>, Config<T>} = 111,
+		DexOrders: module_dex_orders::{Pallet, Storage, Call, Event<T>} = 112,
+		AggregatedDex: module_aggregated_dex::{Pallet, Storage, Call, Event<T>} = 113,
 
 		// Honzon
 		AuctionManager: module_auction_manager::{Pallet, Storage, Call, Event<T>, ValidateUnsigned} = 120,
@@ -2029,6 +2521,10 @@ construct_runtime! {
 		CdpTreasury: module_cdp_treasury::{Pallet, Storage, Call, Config, Event<T>} = 123,
 		CdpEngine: module_cdp_engine::{Pallet, Storage, Call, Event<T>, Config, ValidateUnsigned} = 124,
 		EmergencyShutdown: module_emergency_shutdown::{Pallet, Storage, Call, Event<T>} = 125,
+		FlashMint: module_flash_mint::{Pallet, Storage, Call, Event<T>, Config} = 126,
+		Buyback: module_buyback::{Pallet, Storage, Call, Event<T>} = 127,
+		StabilityPool: module_stability_pool::{Pallet, Storage, Call, Event<T>} = 128,
+		Parameters: module_parameters::{Pallet, Storage, Call, Event<T>} = 129,
 
 		// Homa
 		Homa: module_homa::{Pallet, Call} = 130,
@@ -2037,11 +2533,14 @@ construct_runtime! {
 		PolkadotBridge: module_polkadot_bridge::{Pallet, Call, Storage} = 133,
 		HomaValidatorListModule: module_homa_validator_list::{Pallet, Call, Storage, Event<T>} = 134,
 		HomaLite: module_homa_lite::{Pallet, Call, Storage, Event<T>} = 135,
+		LiquidCrowdloan: module_liquid_crowdloan::{Pallet, Call, Storage, Event<T>} = 136,
 
 		// Acala Other
 		Incentives: module_incentives::{Pallet, Storage, Call, Event<T>} = 140,
-		NFT: module_nft::{Pallet, Call, Event<T>} = 141,
+		NFT: module_nft::{Pallet, Call, Storage, Event<T>} = 141,
 		AirDrop: module_airdrop::{Pallet, Call, Storage, Event<T>, Config<T>} = 142,
+		Earning: module_earning::{Pallet, Call, Storage, Event<T>} = 143,
+		Payments: module_payments::{Pallet, Call, Storage, Event<T>} = 144,
 
 		// Ecosystem modules
 		RenVmBridge: ecosystem_renvm_bridge::{Pallet, Call, Config, Storage, Event<T>, ValidateUnsigned} = 150,
@@ -2062,12 +2561,15 @@ construct_runtime! {
 		XTokens: orml_xtokens::{Pallet, Storage, Call, Event<T>} = 174,
 		UnknownTokens: orml_unknown_tokens::{Pallet, Storage, Event} = 175,
 		OrmlXcm: orml_xcm::{Pallet, Call, Event<T>} = 176,
+		AssetRegistry: module_asset_registry::{Pallet, Call, Storage, Event<T>} = 177,
+		XcmTransfer: module_xcm_transfer::{Pallet, Call, Event<T>} = 178,
+		XcmChannel: module_xcm_channel::{Pallet, Call, Storage, Event<T>} = 179,
 
 		// Smart contracts
 		EVM: module_evm::{Pallet, Config<T>, Call, Storage, Event<T>} = 180,
 		EVMBridge: module_evm_bridge::{Pallet} = 181,
 		EvmAccounts: module_evm_accounts::{Pallet, Call, Storage, Event<T>} = 182,
-		EvmManager: module_evm_manager::{Pallet, Storage} = 183,
+		EvmManager: module_evm_manager::{Pallet, Call, Storage, Event<T>} = 183,
 
 		// Collator support. the order of these 4 are important and shall not change.
 		Authorship: pallet_authorship::{Pallet, Call, Storage} = 190,
@@ -2079,6 +2581,7 @@ construct_runtime! {
 
 		// Stable asset
 		StableAsset: nutsfinance_stable_asset::{Pallet, Call, Storage, Event<T>} = 200,
+		StableAssetMetapool: module_stable_asset_metapool::{Pallet, Call, Storage, Event<T>} = 201,
 
 		// Dev
 		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>} = 255,
@@ -2184,6 +2687,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_transaction_payment_rpc_runtime_api::TransactionFeeApi<
+		Block,
+		Balance,
+		CurrencyId,
+	> for Runtime {
+		fn query_fee_in_currency(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			currency_id: CurrencyId,
+		) -> Option<Balance> {
+			TransactionPayment::query_fee_in_currency(uxt, len, currency_id)
+		}
+	}
+
 	impl orml_oracle_rpc_runtime_api::OracleApi<
 		Block,
 		DataProviderId,
@@ -2205,6 +2722,23 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl runtime_common::OracleFeedsApi<
+		Block,
+		DataProviderId,
+		CurrencyId,
+		AccountId,
+		Moment,
+	> for Runtime {
+		fn get_feed_details(provider_id: DataProviderId, key: CurrencyId) -> Option<runtime_common::FeedDetails<AccountId, Moment>> {
+			match provider_id {
+				DataProviderId::Acala => Some(
+					AggregatedCombineData::<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider, Prices>::feed_details(&key)
+				),
+				DataProviderId::Aggregated => None,
+			}
+		}
+	}
+
 	impl module_staking_pool_rpc_runtime_api::StakingPoolApi<
 		Block,
 		AccountId,
@@ -2221,6 +2755,293 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_cdp_engine_rpc_runtime_api::CDPEngineApi<
+		Block,
+		AccountId,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn get_position_risk(
+			account: AccountId,
+			currency_id: CurrencyId,
+		) -> Option<module_cdp_engine_rpc_runtime_api::PositionRisk<Balance>> {
+			let module_loans::Position { collateral, debit } = Loans::positions(currency_id, &account);
+			let price = CdpEngine::get_collateral_price(currency_id)?;
+
+			Some(module_cdp_engine_rpc_runtime_api::PositionRisk {
+				collateral_ratio: CdpEngine::calculate_collateral_ratio(currency_id, collateral, debit, price),
+				liquidation_price: CdpEngine::calculate_liquidation_price(currency_id, collateral, debit),
+				required_collateral: CdpEngine::calculate_required_collateral(currency_id, collateral, debit, price),
+				accumulated_interest: CdpEngine::get_accumulated_interest(currency_id, debit),
+			})
+		}
+
+		fn get_interest_accrued(
+			account: AccountId,
+			currency_id: CurrencyId,
+		) -> module_cdp_engine_rpc_runtime_api::InterestInfo<Balance> {
+			module_cdp_engine_rpc_runtime_api::InterestInfo {
+				accrued: CdpEngine::get_interest_accrued(&account, currency_id)
+			}
+		}
+	}
+
+	impl module_asset_registry_runtime_api::AssetRegistryApi<
+		Block,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn xcm_execution_fee(currency_id: CurrencyId, weight: u64) -> Option<Balance> {
+			let location = CurrencyIdConvert::convert(currency_id)?;
+			let (dot_location, fee_per_second) = FeePerSecondOf::<Runtime, DotPerSecond>::get();
+			if location == dot_location {
+				Some(AssetRegistry::execution_fee(fee_per_second, weight))
+			} else {
+				None
+			}
+		}
+	}
+
+	impl module_xcm_channel_runtime_api::XcmChannelApi<
+		Block,
+		module_xcm_channel::ChannelMetrics,
+	> for Runtime {
+		fn channel_metrics(para_id: u32) -> module_xcm_channel::ChannelMetrics {
+			XcmChannel::metrics(ParaId::from(para_id))
+		}
+
+		fn is_channel_paused(para_id: u32) -> bool {
+			XcmChannel::is_paused(ParaId::from(para_id))
+		}
+	}
+
+	impl module_session_manager_runtime_api::SessionManagerApi<
+		Block,
+		BlockNumber,
+	> for Runtime {
+		fn session_duration_at(block_number: BlockNumber) -> BlockNumber {
+			SessionManager::session_duration_at(block_number)
+		}
+	}
+
+	impl module_dex_rpc_runtime_api::DexApi<
+		Block,
+		CurrencyId,
+		BlockNumber,
+		Balance,
+	> for Runtime {
+		fn get_twap(
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			window: BlockNumber,
+		) -> Option<ExchangeRate> {
+			Dex::get_twap(currency_id_a, currency_id_b, window)
+		}
+
+		fn get_swap_quote(
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			amount: Balance,
+			mode: module_support::SwapMode,
+		) -> Option<module_support::SwapQuote<CurrencyId, Balance>> {
+			AggregatedDex::get_best_swap_quote(supply_currency_id, target_currency_id, amount, mode)
+		}
+	}
+
+	impl module_asset_registry_runtime_api::PortfolioApi<
+		Block,
+		AccountId,
+		CurrencyId,
+		module_incentives::PoolId,
+		Balance,
+	> for Runtime {
+		fn get_portfolio(
+			account: AccountId,
+		) -> module_asset_registry_runtime_api::Portfolio<CurrencyId, module_incentives::PoolId, Balance> {
+			use module_asset_registry_runtime_api::*;
+			use module_support::{DEXManager, ExchangeRateProvider};
+
+			let mut portfolio = Portfolio::default();
+
+			let native = System::account(&account).data;
+			if !native.free.is_zero() || !native.reserved.is_zero() {
+				portfolio.balances.push(TokenBalance {
+					currency_id: GetNativeCurrencyId::get(),
+					free: native.free,
+					reserved: native.reserved,
+					frozen: native.misc_frozen.max(native.fee_frozen),
+				});
+			}
+
+			for (currency_id, data) in orml_tokens::Accounts::<Runtime>::iter_prefix(&account) {
+				let total = data.free.saturating_add(data.reserved);
+				if total.is_zero() {
+					continue;
+				}
+
+				if let Some((currency_id_0, currency_id_1)) = currency_id.split_dex_share_currency_id() {
+					let (pool_0, pool_1) = Dex::get_liquidity_pool(currency_id_0, currency_id_1);
+					let proportion = Ratio::checked_from_rational(total, Tokens::total_issuance(currency_id))
+						.unwrap_or_default();
+					portfolio.dex_shares.push(DexShareBalance {
+						currency_id,
+						balance: total,
+						underlying: vec![
+							CurrencyAmount {
+								currency_id: currency_id_0,
+								amount: proportion.saturating_mul_int(pool_0),
+							},
+							CurrencyAmount {
+								currency_id: currency_id_1,
+								amount: proportion.saturating_mul_int(pool_1),
+							},
+						],
+					});
+					continue;
+				}
+
+				if currency_id == GetLiquidCurrencyId::get() {
+					portfolio.liquid_staking = Some(LiquidStakingBalance {
+						liquid: CurrencyAmount {
+							currency_id,
+							amount: total,
+						},
+						staking: CurrencyAmount {
+							currency_id: GetStakingCurrencyId::get(),
+							amount: LiquidStakingExchangeRateProvider::get_exchange_rate().saturating_mul_int(total),
+						},
+					});
+				}
+				portfolio.balances.push(TokenBalance {
+					currency_id,
+					free: data.free,
+					reserved: data.reserved,
+					frozen: data.frozen,
+				});
+			}
+
+			for currency_id in CollateralCurrencyIds::get() {
+				let module_loans::Position { collateral, debit } = Loans::positions(currency_id, &account);
+				if !collateral.is_zero() || !debit.is_zero() {
+					portfolio.loans.push(LoanPosition {
+						collateral_currency_id: currency_id,
+						collateral,
+						debit,
+						debit_value: CdpEngine::get_debit_value(currency_id, debit),
+					});
+				}
+			}
+
+			for pool_id in orml_rewards::PoolInfos::<Runtime>::iter_keys() {
+				let (share, _) = Rewards::shares_and_withdrawn_rewards(&pool_id, &account);
+				if !share.is_zero() {
+					portfolio.incentive_stakes.push(IncentiveStake { pool_id, share });
+				}
+			}
+
+			portfolio
+		}
+	}
+
+	impl module_prices_rpc_runtime_api::PricesApi<
+		Block,
+		CurrencyId,
+		BlockNumber,
+	> for Runtime {
+		fn get_feed_freshness(currency_id: CurrencyId) -> Option<BlockNumber> {
+			Prices::feed_freshness(currency_id)
+		}
+	}
+
+	impl module_incentives_rpc_runtime_api::IncentivesApi<
+		Block,
+		AccountId,
+		module_incentives::PoolId,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn get_pending_loyalty_bonus(pool_id: module_incentives::PoolId, who: AccountId) -> Vec<(CurrencyId, Balance)> {
+			Incentives::pending_loyalty_bonus(&pool_id, &who)
+		}
+	}
+
+	impl module_nft_rpc_runtime_api::NftApi<
+		Block,
+		u32,
+		module_nft::Properties,
+	> for Runtime {
+		fn query_class_properties(class_id: u32) -> Option<module_nft::Properties> {
+			NFT::class_properties(class_id)
+		}
+	}
+
+	impl runtime_common::events::EventsApi<Block> for Runtime {
+		fn query_events(pallet_index: Option<u8>, event_index: Option<u8>) -> Vec<runtime_common::events::EncodedEvent> {
+			runtime_common::events::query_events::<Runtime>(pallet_index, event_index)
+		}
+	}
+
+	impl runtime_common::yields::YieldsApi<Block> for Runtime {
+		fn get_yields() -> runtime_common::yields::Yields {
+			use module_support::ExchangeRateProvider;
+			use runtime_common::yields::{compounded_apy, incentive_pool_apr, Yields};
+
+			let now = System::block_number();
+			let incentive_pools = orml_rewards::PoolInfos::<Runtime>::iter()
+				.filter_map(|(pool_id, pool_info)| {
+					incentive_pool_apr::<module_prices::RealTimePriceProvider<Runtime>>(
+						pool_id,
+						pool_info.total_shares,
+						&Incentives::reward_amounts_per_period(pool_id, now),
+						365 * DAYS / AccumulatePeriod::get(),
+					)
+				})
+				.collect();
+
+			Yields {
+				liquid_exchange_rate: LiquidStakingExchangeRateProvider::get_exchange_rate(),
+				staking_apy: compounded_apy(EstimatedRewardRatePerEra::get().into(), 365 * DAYS / RelaychainEraLength::get()),
+				incentive_pools,
+			}
+		}
+	}
+
+	impl runtime_common::metrics::ProtocolMetricsApi<Block> for Runtime {
+		fn protocol_metrics() -> runtime_common::metrics::ProtocolMetrics {
+			use frame_support::traits::PalletInfoAccess;
+			use runtime_common::metrics::{storage_map_len, CollateralMetrics, ProtocolMetrics, XcmQueueDepth};
+
+			let collaterals = CollateralCurrencyIds::get()
+				.into_iter()
+				.map(|currency_id| CollateralMetrics {
+					currency_id,
+					open_cdps: module_loans::Positions::<Runtime>::iter_prefix_values(currency_id)
+						.filter(|position| !position.debit.is_zero())
+						.count() as u32,
+					total_debit_value: CdpEngine::get_debit_value(currency_id, Loans::total_positions(currency_id).debit),
+				})
+				.collect();
+			let now = Timestamp::now();
+			let oracle_feed_ages = <AggregatedDataProvider as DataProviderExtended<_, _>>::get_all_values()
+				.into_iter()
+				.filter_map(|(currency_id, price)| Some((currency_id, now.saturating_sub(price?.timestamp))))
+				.collect();
+
+			ProtocolMetrics {
+				collaterals,
+				active_auctions: module_auction_manager::CollateralAuctions::<Runtime>::iter_keys().count() as u32,
+				oracle_feed_ages,
+				homa_pending_redemptions: module_homa_lite::RedeemRequests::<Runtime>::iter_values()
+					.fold(Zero::zero(), |total: Balance, (amount, _)| total.saturating_add(amount)),
+				xcm_queue_depth: XcmQueueDepth {
+					inbound_xcmp: storage_map_len(XcmpQueue::name(), b"InboundXcmpMessages"),
+					outbound_xcmp: storage_map_len(XcmpQueue::name(), b"OutboundXcmpMessages"),
+					dmp_pages: storage_map_len(DmpQueue::name(), b"Pages"),
+				},
+			}
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn call(
 			from: H160,
@@ -2230,7 +3051,10 @@ impl_runtime_apis! {
 			gas_limit: u64,
 			storage_limit: u32,
 			estimate: bool,
+			state_overrides: Vec<(H160, StateOverride)>,
 		) -> Result<CallInfo, sp_runtime::DispatchError> {
+			EVM::apply_state_overrides(state_overrides)?;
+
 			let config = if estimate {
 				let mut config = <Runtime as module_evm::Config>::config().clone();
 				config.estimate = true;
@@ -2258,7 +3082,10 @@ impl_runtime_apis! {
 			gas_limit: u64,
 			storage_limit: u32,
 			estimate: bool,
+			state_overrides: Vec<(H160, StateOverride)>,
 		) -> Result<CreateInfo, sp_runtime::DispatchError> {
+			EVM::apply_state_overrides(state_overrides)?;
+
 			let config = if estimate {
 				let mut config = <Runtime as module_evm::Config>::config().clone();
 				config.estimate = true;
@@ -2307,6 +3134,40 @@ impl_runtime_apis! {
 
 			request.ok_or(sp_runtime::DispatchError::Other("Invalid parameter extrinsic, not evm Call"))
 		}
+
+		fn base_fee_per_gas() -> Balance {
+			EVM::base_fee_per_gas()
+		}
+
+		fn base_fee_history(block_count: u32) -> Vec<Balance> {
+			EVM::base_fee_history(block_count)
+		}
+
+		fn storage_statement(contract: H160) -> Option<ContractStorageStatement<Balance>> {
+			EVM::storage_statement(&contract)
+		}
+
+		fn trace_extrinsic(
+			extrinsics: Vec<<Block as BlockT>::Extrinsic>,
+			extrinsic: <Block as BlockT>::Extrinsic,
+			tracer: TracerConfig,
+		) -> Result<TraceOutcome, sp_runtime::DispatchError> {
+			#[cfg(feature = "tracing")]
+			{
+				for extrinsic in extrinsics {
+					let _ = Executive::apply_extrinsic(extrinsic);
+				}
+
+				let (_, outcome) = module_evm::runner::tracing::trace(tracer, || Executive::apply_extrinsic(extrinsic));
+				Ok(outcome)
+			}
+
+			#[cfg(not(feature = "tracing"))]
+			{
+				let _ = (extrinsics, extrinsic, tracer);
+				Err(sp_runtime::DispatchError::Other("EVM tracing is not enabled"))
+			}
+		}
 	}
 
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
@@ -2343,11 +3204,14 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, module_homa_lite, HomaLiteBench::<Runtime>);
 
 			orml_list_benchmark!(list, extra, module_dex, benchmarking::dex);
+			orml_list_benchmark!(list, extra, module_dex_orders, benchmarking::dex_orders);
+			orml_list_benchmark!(list, extra, module_aggregated_dex, benchmarking::aggregated_dex);
 			orml_list_benchmark!(list, extra, module_auction_manager, benchmarking::auction_manager);
 			orml_list_benchmark!(list, extra, module_cdp_engine, benchmarking::cdp_engine);
 			orml_list_benchmark!(list, extra, module_collator_selection, benchmarking::collator_selection);
 			orml_list_benchmark!(list, extra, module_nominees_election, benchmarking::nominees_election);
 			orml_list_benchmark!(list, extra, module_emergency_shutdown, benchmarking::emergency_shutdown);
+			orml_list_benchmark!(list, extra, module_flash_mint, benchmarking::flash_mint);
 			orml_list_benchmark!(list, extra, module_evm, benchmarking::evm);
 			orml_list_benchmark!(list, extra, module_honzon, benchmarking::honzon);
 			orml_list_benchmark!(list, extra, module_cdp_treasury, benchmarking::cdp_treasury);
@@ -2357,8 +3221,12 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, module_prices, benchmarking::prices);
 			orml_list_benchmark!(list, extra, module_evm_accounts, benchmarking::evm_accounts);
 			orml_list_benchmark!(list, extra, module_homa, benchmarking::homa);
+			orml_list_benchmark!(list, extra, module_liquid_crowdloan, benchmarking::liquid_crowdloan);
+			orml_list_benchmark!(list, extra, module_earning, benchmarking::earning);
+			orml_list_benchmark!(list, extra, module_payments, benchmarking::payments);
 			orml_list_benchmark!(list, extra, module_currencies, benchmarking::currencies);
 			orml_list_benchmark!(list, extra, module_session_manager, benchmarking::session_manager);
+			orml_list_benchmark!(list, extra, module_stable_asset_metapool, benchmarking::stable_asset_metapool);
 
 			orml_list_benchmark!(list, extra, orml_tokens, benchmarking::tokens);
 			orml_list_benchmark!(list, extra, orml_vesting, benchmarking::vesting);
@@ -2408,11 +3276,14 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, module_nft, NftBench::<Runtime>);
 			add_benchmark!(params, batches, module_homa_lite, HomaLiteBench::<Runtime>);
 			orml_add_benchmark!(params, batches, module_dex, benchmarking::dex);
+			orml_add_benchmark!(params, batches, module_dex_orders, benchmarking::dex_orders);
+			orml_add_benchmark!(params, batches, module_aggregated_dex, benchmarking::aggregated_dex);
 			orml_add_benchmark!(params, batches, module_auction_manager, benchmarking::auction_manager);
 			orml_add_benchmark!(params, batches, module_cdp_engine, benchmarking::cdp_engine);
 			orml_add_benchmark!(params, batches, module_collator_selection, benchmarking::collator_selection);
 			orml_add_benchmark!(params, batches, module_nominees_election, benchmarking::nominees_election);
 			orml_add_benchmark!(params, batches, module_emergency_shutdown, benchmarking::emergency_shutdown);
+			orml_add_benchmark!(params, batches, module_flash_mint, benchmarking::flash_mint);
 			orml_add_benchmark!(params, batches, module_evm, benchmarking::evm);
 			orml_add_benchmark!(params, batches, module_honzon, benchmarking::honzon);
 			orml_add_benchmark!(params, batches, module_cdp_treasury, benchmarking::cdp_treasury);
@@ -2422,8 +3293,12 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, module_prices, benchmarking::prices);
 			orml_add_benchmark!(params, batches, module_evm_accounts, benchmarking::evm_accounts);
 			orml_add_benchmark!(params, batches, module_homa, benchmarking::homa);
+			orml_add_benchmark!(params, batches, module_liquid_crowdloan, benchmarking::liquid_crowdloan);
+			orml_add_benchmark!(params, batches, module_earning, benchmarking::earning);
+			orml_add_benchmark!(params, batches, module_payments, benchmarking::payments);
 			orml_add_benchmark!(params, batches, module_currencies, benchmarking::currencies);
 			orml_add_benchmark!(params, batches, module_session_manager, benchmarking::session_manager);
+			orml_add_benchmark!(params, batches, module_stable_asset_metapool, benchmarking::stable_asset_metapool);
 
 			orml_add_benchmark!(params, batches, orml_tokens, benchmarking::tokens);
 			orml_add_benchmark!(params, batches, orml_vesting, benchmarking::vesting);
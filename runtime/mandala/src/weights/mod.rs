@@ -19,25 +19,39 @@
 //! A list of the different weight modules for our runtime.
 #![allow(clippy::unnecessary_cast)]
 
+pub mod module_aggregated_dex;
+pub mod module_asset_registry;
 pub mod module_auction_manager;
+pub mod module_buyback;
 pub mod module_cdp_engine;
 pub mod module_cdp_treasury;
 pub mod module_collator_selection;
 pub mod module_currencies;
 pub mod module_dex;
+pub mod module_dex_orders;
+pub mod module_earning;
 pub mod module_emergency_shutdown;
 pub mod module_evm;
 pub mod module_evm_accounts;
+pub mod module_evm_manager;
+pub mod module_flash_mint;
 pub mod module_homa;
 pub mod module_homa_lite;
 pub mod module_honzon;
+pub mod module_idle_scheduler;
 pub mod module_incentives;
+pub mod module_liquid_crowdloan;
 pub mod module_nft;
 pub mod module_nominees_election;
+pub mod module_parameters;
+pub mod module_payments;
 pub mod module_prices;
 pub mod module_session_manager;
+pub mod module_stability_pool;
+pub mod module_stable_asset_metapool;
 pub mod module_transaction_pause;
 pub mod module_transaction_payment;
+pub mod module_xcm_channel;
 
 pub mod orml_auction;
 pub mod orml_authority;
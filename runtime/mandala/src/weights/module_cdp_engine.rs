@@ -65,6 +65,14 @@ impl<T: frame_system::Config> module_cdp_engine::WeightInfo for WeightInfo<T> {
 		(22_422_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_maximum_debit_value_per_account() -> Weight {
+		(33_904_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_offchain_worker_params() -> Weight {
+		(22_422_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn liquidate_by_auction() -> Weight {
 		(358_911_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(28 as Weight))
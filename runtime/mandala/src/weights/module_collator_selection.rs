@@ -68,6 +68,14 @@ impl<T: frame_system::Config> module_collator_selection::WeightInfo for WeightIn
 			.saturating_add(T::DbWeight::get().reads(7 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	fn register_as_candidate_with_bond(c: u32, ) -> Weight {
+		(109_143_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((1_497_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 	fn register_candidate(c: u32, ) -> Weight {
 		(53_988_000 as Weight)
 			// Standard Error: 1_000
@@ -57,6 +57,24 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_exchange_fee() -> Weight {
+		(21_204_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_lbp_creator() -> Weight {
+		(19_871_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn create_lbp() -> Weight {
+		(98_746_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	fn end_lbp() -> Weight {
+		(86_213_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
 	fn list_provisioning() -> Weight {
 		(46_500_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
@@ -72,6 +90,16 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
+	fn abort_provisioning() -> Weight {
+		(27_842_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn extend_provisioning_deadline() -> Weight {
+		(24_315_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn add_provision() -> Weight {
 		(163_160_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(5 as Weight))
@@ -82,6 +110,11 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(7 as Weight))
 			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
+	fn claim_provisioning_refund() -> Weight {
+		(86_370_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
 	fn add_liquidity() -> Weight {
 		(238_442_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(9 as Weight))
@@ -92,6 +125,16 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(14 as Weight))
 			.saturating_add(T::DbWeight::get().writes(12 as Weight))
 	}
+	fn add_liquidity_single() -> Weight {
+		(243_117_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	fn add_liquidity_single_and_stake() -> Weight {
+		(321_545_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+	}
 	fn remove_liquidity() -> Weight {
 		(207_704_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(6 as Weight))
@@ -102,6 +145,16 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(14 as Weight))
 			.saturating_add(T::DbWeight::get().writes(12 as Weight))
 	}
+	fn migrate_liquidity() -> Weight {
+		(652_918_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(23 as Weight))
+			.saturating_add(T::DbWeight::get().writes(20 as Weight))
+	}
+	fn migrate_liquidity_by_unstake() -> Weight {
+		(790_114_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(27 as Weight))
+			.saturating_add(T::DbWeight::get().writes(24 as Weight))
+	}
 	fn swap_with_exact_supply(_u: u32, ) -> Weight {
 		(160_821_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(9 as Weight))
@@ -112,4 +165,9 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(9 as Weight))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
+	fn swap_with_best_path() -> Weight {
+		(205_117_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
 }
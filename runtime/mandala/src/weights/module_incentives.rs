@@ -69,6 +69,11 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	fn claim_rewards_vested() -> Weight {
+		(123_101_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 	fn update_incentive_rewards(c: u32, ) -> Weight {
 		(4_354_000 as Weight)
 			// Standard Error: 475_000
@@ -87,4 +92,16 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 			.saturating_add((17_687_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn update_reward_multipliers(c: u32, ) -> Weight {
+		(1_102_000 as Weight)
+			// Standard Error: 22_000
+			.saturating_add((2_046_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_vesting_payout_configs(c: u32, ) -> Weight {
+		(914_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 }